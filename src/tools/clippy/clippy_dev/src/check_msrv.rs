@@ -0,0 +1,227 @@
+//! Checks the list of lints of the `msrv` configuration in `clippy_lints/src/utils/conf.rs`.
+//!
+//! This list is the one table of MSRV aware lints: the lint documentation links these lints to the
+//! `msrv` configuration, and `#[clippy::msrv]` attributes only affect them. Every check of an
+//! `msrvs::` version has to gate at least one of the listed lints, and every listed lint has to
+//! depend on the MSRV.
+
+use crate::clippy_project_root;
+use crate::update_lints::declared_lint_names;
+use rustc_lexer::{tokenize, TokenKind};
+use std::collections::{HashMap, HashSet};
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Files which use `msrvs::` without emitting lints themselves.
+const IGNORED_FILES: &[&str] = &["lib.rs", "utils/conf.rs"];
+
+/// Runs the check, exiting with an error if the list contains unknown or duplicated lints or lints
+/// not depending on the MSRV, or misses the lints gated by an MSRV check.
+///
+/// # Panics
+///
+/// Panics if a source file can't be read.
+pub fn run() {
+    let root = clippy_project_root();
+    let conf_path = root.join("clippy_lints/src/utils/conf.rs");
+    let conf = fs::read_to_string(&conf_path).unwrap_or_else(|e| panic!("Cannot read {}: {e}", conf_path.display()));
+    let listed = msrv_lints(&conf).expect("the `msrv` configuration has no `Lint:` line");
+    let declared: HashSet<String> = declared_lint_names().into_iter().collect();
+
+    let mut failed = false;
+    let mut seen = HashSet::new();
+    for lint in &listed {
+        if !declared.contains(lint) {
+            println!("clippy_lints/src/utils/conf.rs: `{lint}` in the `msrv` lints is not a lint");
+            failed = true;
+        }
+        if !seen.insert(lint) {
+            println!("clippy_lints/src/utils/conf.rs: `{lint}` is listed twice in the `msrv` lints");
+            failed = true;
+        }
+    }
+
+    let src = root.join("clippy_lints/src");
+    let files: Vec<(PathBuf, String)> = WalkDir::new(&src)
+        .into_iter()
+        .map(Result::unwrap)
+        .filter(|f| f.path().extension() == Some(OsStr::new("rs")))
+        .map(|f| {
+            let contents =
+                fs::read_to_string(f.path()).unwrap_or_else(|e| panic!("Cannot read {}: {e}", f.path().display()));
+            (f.path().strip_prefix(&src).unwrap().to_path_buf(), contents)
+        })
+        .filter(|(path, _)| !IGNORED_FILES.iter().any(|ignored| path == Path::new(ignored)))
+        .collect();
+    let file_lints: HashMap<&Path, HashSet<&str>> = files
+        .iter()
+        .map(|(path, contents)| (path.as_path(), lints_in(&tokens(contents), &declared)))
+        .collect();
+
+    // The lints of files using the MSRV at all, to find listed lints which don't depend on it
+    let mut msrv_aware = HashSet::new();
+    for (path, contents) in &files {
+        let tokens = tokens(contents);
+        if tokens
+            .iter()
+            .any(|&(kind, content)| kind == TokenKind::Ident && matches!(content, "Msrv" | "msrvs"))
+        {
+            msrv_aware.extend(file_lints[path.as_path()].iter().copied());
+        }
+
+        for (i, alias) in msrv_checks(&tokens) {
+            let module_lints = |module: &str| {
+                let dir = path.parent().unwrap();
+                [dir.join(format!("{module}.rs")), dir.join(module).join("mod.rs")]
+                    .into_iter()
+                    .find_map(|module_path| file_lints.get(module_path.as_path()))
+            };
+            let lints =
+                gated_lints(&tokens, i, &declared, module_lints).unwrap_or_else(|| file_lints[path.as_path()].clone());
+            if !lints.iter().any(|lint| listed.iter().any(|l| l == lint)) {
+                let mut lints: Vec<_> = lints.into_iter().collect();
+                lints.sort_unstable();
+                println!(
+                    "clippy_lints/src/{}: the check of `msrvs::{alias}` gates none of the `msrv` lints: {}",
+                    path.display(),
+                    lints.join(", "),
+                );
+                failed = true;
+            }
+        }
+    }
+    for lint in &listed {
+        if declared.contains(lint) && !msrv_aware.contains(lint.as_str()) {
+            println!("clippy_lints/src/utils/conf.rs: `{lint}` is in the `msrv` lints, but doesn't depend on the MSRV");
+            failed = true;
+        }
+    }
+
+    if failed {
+        println!("fix the `Lint:` line of the `msrv` configuration in clippy_lints/src/utils/conf.rs");
+        std::process::exit(1);
+    }
+}
+
+/// Finds the MSRV checks, i.e. the uses of `msrvs` aliases, returning the index of their first
+/// token and the alias.
+fn msrv_checks<'a>(tokens: &[(TokenKind, &'a str)]) -> Vec<(usize, &'a str)> {
+    tokens
+        .windows(4)
+        .enumerate()
+        .filter_map(|(i, w)| match *w {
+            [
+                (TokenKind::Ident, "msrvs"),
+                (TokenKind::Colon, _),
+                (TokenKind::Colon, _),
+                (TokenKind::Ident, alias),
+            ] if alias
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_') =>
+            {
+                Some((i, alias))
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Gets the lints gated by the MSRV check starting at `start`. These are the lints referenced by
+/// the condition and the block following it, or, if there are none, by the rest of the enclosing
+/// block. Calls to `module::function` also reference the lints of `module`.
+///
+/// Returns `None` if neither references a lint.
+fn gated_lints<'a>(
+    tokens: &[(TokenKind, &'a str)],
+    start: usize,
+    declared: &HashSet<String>,
+    module_lints: impl Fn(&str) -> Option<&'a HashSet<&'a str>>,
+) -> Option<HashSet<&'a str>> {
+    let enclosing_end = block_end(tokens, start);
+    let following_block = tokens[start..enclosing_end]
+        .iter()
+        .position(|&(kind, _)| matches!(kind, TokenKind::OpenBrace | TokenKind::Semi))
+        .filter(|&i| tokens[start + i].0 == TokenKind::OpenBrace)
+        .map(|i| &tokens[start..enclosing_end.min(block_end(tokens, start + i + 1) + 1)]);
+
+    following_block
+        .into_iter()
+        .chain([&tokens[start..enclosing_end]])
+        .map(|region| {
+            let mut lints = lints_in(region, declared);
+            for w in region.windows(5) {
+                if let [
+                    (TokenKind::Ident, module),
+                    (TokenKind::Colon, _),
+                    (TokenKind::Colon, _),
+                    (TokenKind::Ident, _),
+                    (TokenKind::OpenParen, _),
+                ] = *w
+                    && let Some(module_lints) = module_lints(module)
+                {
+                    lints.extend(module_lints.iter().copied());
+                }
+            }
+            lints
+        })
+        .find(|lints| !lints.is_empty())
+}
+
+/// Returns the index of the brace closing the block containing the token at `start`.
+fn block_end(tokens: &[(TokenKind, &str)], start: usize) -> usize {
+    let mut depth = 0usize;
+    for (i, &(kind, _)) in tokens.iter().enumerate().skip(start) {
+        match kind {
+            TokenKind::OpenBrace => depth += 1,
+            TokenKind::CloseBrace if depth == 0 => return i,
+            TokenKind::CloseBrace => depth -= 1,
+            _ => {},
+        }
+    }
+    tokens.len()
+}
+
+/// Gets the declared lints referenced by the tokens.
+fn lints_in<'a>(tokens: &[(TokenKind, &'a str)], declared: &HashSet<String>) -> HashSet<&'a str> {
+    tokens
+        .iter()
+        .filter(|&&(kind, content)| kind == TokenKind::Ident && declared.contains(content))
+        .map(|&(_, content)| content)
+        .collect()
+}
+
+/// Parses the lints of the `Lint:` line of the `msrv` configuration.
+fn msrv_lints(conf: &str) -> Option<Vec<String>> {
+    let mut lints = None;
+    for line in conf.lines().map(str::trim) {
+        if let Some(list) = line.strip_prefix("/// Lint:") {
+            lints = Some(list);
+        } else if line.starts_with("(msrv:") {
+            let list = lints?.trim().trim_end_matches('.');
+            return Some(list.split(',').map(|lint| lint.trim().to_owned()).collect());
+        } else if !line.starts_with("///") {
+            lints = None;
+        }
+    }
+    None
+}
+
+/// Returns the tokens of the file with their contents, without whitespace and comments.
+fn tokens(contents: &str) -> Vec<(TokenKind, &str)> {
+    let mut pos = 0;
+    tokenize(contents)
+        .map(|t| {
+            let content = &contents[pos..pos + t.len as usize];
+            pos += t.len as usize;
+            (t.kind, content)
+        })
+        .filter(|(kind, _)| {
+            !matches!(
+                kind,
+                TokenKind::Whitespace | TokenKind::LineComment { .. } | TokenKind::BlockComment { .. }
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,145 @@
+//! Checks the `PathLookup` statics declared in `clippy_lints` and `clippy_utils`.
+//!
+//! Only unused and duplicated lookups are found here. Lookups which don't resolve are reported by
+//! the `invalid_paths` internal lint when running `cargo dev dogfood`.
+
+use crate::clippy_project_root;
+use rustc_lexer::{tokenize, LiteralKind, TokenKind};
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+struct Registration {
+    name: String,
+    path: Vec<String>,
+    file: PathBuf,
+    line: usize,
+}
+
+struct Token<'a> {
+    kind: TokenKind,
+    content: &'a str,
+    line: usize,
+}
+
+/// Runs the check, exiting with an error if any lookup is unused or declared twice.
+///
+/// # Panics
+///
+/// Panics if a source file can't be read.
+pub fn run() {
+    let root = clippy_project_root();
+    let mut registrations = Vec::new();
+    let mut ident_counts = HashMap::new();
+    for dir in ["clippy_lints/src", "clippy_utils/src"] {
+        for file in WalkDir::new(root.join(dir))
+            .into_iter()
+            .map(Result::unwrap)
+            .filter(|f| f.path().extension() == Some(OsStr::new("rs")))
+        {
+            let contents = fs::read_to_string(file.path())
+                .unwrap_or_else(|e| panic!("Cannot read {}: {e}", file.path().display()));
+            let file = file.path().strip_prefix(&root).unwrap();
+            parse_file(&contents, file, &mut registrations, &mut ident_counts);
+        }
+    }
+
+    let mut failed = false;
+    let mut seen: HashMap<&[String], &Registration> = HashMap::new();
+    for reg in &registrations {
+        // The declaration is the first occurrence
+        if ident_counts.get(&reg.name).copied().unwrap_or(0) < 2 {
+            println!("{}:{}: `{}` is never used", reg.file.display(), reg.line, reg.name);
+            failed = true;
+        }
+        if let Some(first) = seen.insert(&reg.path, reg) {
+            println!(
+                "{}:{}: `{}` is already declared as `{}` at {}:{}",
+                reg.file.display(),
+                reg.line,
+                reg.path.join("::"),
+                first.name,
+                first.file.display(),
+                first.line,
+            );
+            failed = true;
+        }
+    }
+
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+/// Finds all `static NAME: PathLookup = PathLookup::...(&[...]` declarations and counts the uses of
+/// each identifier in the file.
+fn parse_file(
+    contents: &str,
+    file: &Path,
+    registrations: &mut Vec<Registration>,
+    ident_counts: &mut HashMap<String, usize>,
+) {
+    let mut pos = 0;
+    let mut line = 1;
+    let tokens: Vec<_> = tokenize(contents)
+        .map(|t| {
+            let content = &contents[pos..pos + t.len as usize];
+            let token = Token {
+                kind: t.kind,
+                content,
+                line,
+            };
+            pos += t.len as usize;
+            line += content.matches('\n').count();
+            token
+        })
+        .filter(|t| {
+            !matches!(
+                t.kind,
+                TokenKind::Whitespace | TokenKind::LineComment { .. } | TokenKind::BlockComment { .. }
+            )
+        })
+        .collect();
+
+    for (i, token) in tokens.iter().enumerate() {
+        if token.kind != TokenKind::Ident {
+            continue;
+        }
+        *ident_counts.entry(token.content.to_owned()).or_default() += 1;
+
+        if token.content == "static"
+            && let [name, colon, ty, ..] = &tokens[i + 1..]
+            && name.kind == TokenKind::Ident
+            && colon.kind == TokenKind::Colon
+            && ty.content == "PathLookup"
+        {
+            registrations.push(Registration {
+                name: name.content.to_owned(),
+                path: parse_path(&tokens[i + 4..]),
+                file: file.to_owned(),
+                line: name.line,
+            });
+        }
+    }
+}
+
+/// Collects the string literals of the first array in the tokens.
+fn parse_path(tokens: &[Token<'_>]) -> Vec<String> {
+    tokens
+        .iter()
+        .skip_while(|t| t.kind != TokenKind::OpenBracket)
+        .take_while(|t| t.kind != TokenKind::CloseBracket)
+        .filter(|t| {
+            matches!(
+                t.kind,
+                TokenKind::Literal {
+                    kind: LiteralKind::Str { .. },
+                    ..
+                }
+            )
+        })
+        .map(|t| t.content.trim_matches('"').to_owned())
+        .collect()
+}
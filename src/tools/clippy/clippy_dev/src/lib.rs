@@ -13,6 +13,8 @@
 use std::path::PathBuf;
 
 pub mod bless;
+pub mod check_msrv;
+pub mod check_paths;
 pub mod dogfood;
 pub mod fmt;
 pub mod lint;
@@ -3,7 +3,7 @@
 #![warn(rust_2018_idioms, unused_lifetimes)]
 
 use clap::{Arg, ArgAction, ArgMatches, Command};
-use clippy_dev::{bless, dogfood, fmt, lint, new_lint, serve, setup, update_lints};
+use clippy_dev::{bless, check_msrv, check_paths, dogfood, fmt, lint, new_lint, serve, setup, update_lints};
 use indoc::indoc;
 
 fn main() {
@@ -99,6 +99,8 @@ fn main() {
             let reason = matches.get_one("reason");
             update_lints::deprecate(name, reason);
         },
+        Some(("check_paths", _)) => check_paths::run(),
+        Some(("check_msrv", _)) => check_msrv::run(),
         _ => {},
     }
 }
@@ -196,6 +198,7 @@ fn get_clap_config() -> ArgMatches {
                             "restriction",
                             "cargo",
                             "nursery",
+                            "semver",
                             "internal",
                             "internal_warn",
                         ]),
@@ -320,6 +323,8 @@ fn get_clap_config() -> ArgMatches {
                     .short('r')
                     .help("The reason for deprecation"),
             ]),
+            Command::new("check_paths").about("Checks for unused or duplicated `PathLookup`s"),
+            Command::new("check_msrv").about("Checks that all lints gated on the MSRV are documented as such"),
         ])
         .get_matches()
 }
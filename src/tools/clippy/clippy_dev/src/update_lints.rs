@@ -808,6 +808,11 @@ fn gather_all() -> (Vec<Lint>, Vec<DeprecatedLint>, Vec<RenamedLint>) {
     (lints, deprecated_lints, renamed_lints)
 }
 
+/// Returns the names of all lints declared in `clippy_lints`, in upper case.
+pub(crate) fn declared_lint_names() -> Vec<String> {
+    gather_all().0.into_iter().map(|lint| lint.name.to_uppercase()).collect()
+}
+
 fn clippy_lints_src_files() -> impl Iterator<Item = (PathBuf, DirEntry)> {
     let root_path = clippy_project_root().join("clippy_lints/src");
     let iter = WalkDir::new(&root_path).into_iter();
@@ -1,10 +1,14 @@
+use crate::mutable_debug_assertion::has_mut_arg;
 use clippy_utils::consts::{constant, Constant};
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_note};
 use clippy_utils::macros::{find_assert_args, root_macro_call_first_node, PanicExpn};
-use rustc_hir::Expr;
+use clippy_utils::visitors::for_each_expr;
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, Mutability};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
-use rustc_span::sym;
+use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -27,7 +31,41 @@
     "`assert!(true)` / `assert!(false)` will be optimized out by the compiler, and should probably be replaced by a `panic!()` or `unreachable!()`"
 }
 
-declare_lint_pass!(AssertionsOnConstants => [ASSERTIONS_ON_CONSTANTS]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `assert!` and `debug_assert!` calls whose condition mutates something, e.g.
+    /// `assert!(queue.pop().is_some())`. The mutable borrows in `debug_assert!`s are left to
+    /// `debug_assert_with_mut_call`.
+    ///
+    /// ### Why is this bad?
+    /// The condition of a `debug_assert!` is not evaluated unless debug assertions are enabled,
+    /// so the side effects only happen in debug builds. The condition of an `assert!` is always
+    /// evaluated, but its side effects are silently lost if the assertion is later changed to a
+    /// `debug_assert!`.
+    ///
+    /// ### Known problems
+    /// Only assignments and mutable borrows in the condition itself are detected, not the side
+    /// effects of the functions it calls, e.g. printing, or mutations through shared references,
+    /// e.g. of a `Cell`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let mut queue = vec![1];
+    /// assert!(queue.pop().is_some());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let mut queue = vec![1];
+    /// let popped = queue.pop();
+    /// assert!(popped.is_some());
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub ASSERT_ON_CONSTANTS_EXTENDED,
+    restriction,
+    "assertions whose condition has side effects"
+}
+
+declare_lint_pass!(AssertionsOnConstants => [ASSERTIONS_ON_CONSTANTS, ASSERT_ON_CONSTANTS_EXTENDED]);
 
 impl<'tcx> LateLintPass<'tcx> for AssertionsOnConstants {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
@@ -38,7 +76,10 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
             _ => return,
         };
         let Some((condition, panic_expn)) = find_assert_args(cx, e, macro_call.expn) else { return };
-        let Some((Constant::Bool(val), _)) = constant(cx, cx.typeck_results(), condition) else { return };
+        let Some((Constant::Bool(val), _)) = constant(cx, cx.typeck_results(), condition) else {
+            check_side_effects(cx, macro_call.span, condition, is_debug);
+            return;
+        };
         if val {
             span_lint_and_help(
                 cx,
@@ -67,3 +108,54 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         }
     }
 }
+
+fn check_side_effects<'tcx>(cx: &LateContext<'tcx>, span: Span, condition: &'tcx Expr<'_>, is_debug: bool) {
+    // `debug_assert_with_mut_call` lints the mutable borrows
+    if !has_side_effects(cx, condition) || (is_debug && has_mut_arg(cx, condition)) {
+        return;
+    }
+    let (name, note) = if is_debug {
+        (
+            "debug_assert",
+            "the condition is only evaluated when debug assertions are enabled",
+        )
+    } else {
+        (
+            "assert",
+            "the side effects will be lost if this is changed to a `debug_assert!`",
+        )
+    };
+    span_lint_and_note(
+        cx,
+        ASSERT_ON_CONSTANTS_EXTENDED,
+        span,
+        &format!("the condition of this `{name}!` has side effects"),
+        Some(condition.span),
+        note,
+    );
+}
+
+/// Checks if the expression assigns to or mutably borrows something, e.g. to call a method taking
+/// `&mut self`.
+fn has_side_effects<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> bool {
+    let typeck = cx.typeck_results();
+    for_each_expr(e, |e| {
+        let borrows_mut = typeck.expr_adjustments(e).iter().any(|adjustment| {
+            matches!(
+                adjustment.kind,
+                Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Mut { .. }))
+            )
+        });
+        if borrows_mut
+            || matches!(
+                e.kind,
+                ExprKind::Assign(..) | ExprKind::AssignOp(..) | ExprKind::AddrOf(_, Mutability::Mut, _)
+            )
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
@@ -0,0 +1,123 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::source::{snippet, snippet_with_applicability};
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{
+    FnRetTy, GenericBound, GenericBounds, IsAsync, ItemKind, LangItem, Term, TraitItem, TraitItemKind, TraitRef, Ty,
+    TyKind, TypeBindingKind,
+};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for methods of public traits which are `async fn`, or return an `impl Future`
+    /// without a `Send` bound.
+    ///
+    /// ### Why is this bad?
+    /// Generic code calling such a method can't require the returned future to be `Send`, so it
+    /// can't spawn it on a multithreaded executor, even if every implementation returns a `Send`
+    /// future. Desugaring the method to `fn foo(..) -> impl Future<Output = T> + Send` requires
+    /// implementations to return `Send` futures instead.
+    ///
+    /// ### Known problems
+    /// Requiring `Send` rules out implementations holding values which aren't `Send` across an
+    /// `await`. If that's intended, allow this lint and document the decision on the trait.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// pub trait Store {
+    ///     async fn get(&self, key: u64) -> Vec<u8>;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// pub trait Store {
+    ///     fn get(&self, key: u64) -> impl Future<Output = Vec<u8>> + Send;
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub ASYNC_FN_IN_TRAIT_WITHOUT_SEND_BOUND,
+    pedantic,
+    "public trait methods returning futures which can't be required to be `Send`"
+}
+declare_lint_pass!(AsyncFnInTraitWithoutSendBound => [ASYNC_FN_IN_TRAIT_WITHOUT_SEND_BOUND]);
+
+impl<'tcx> LateLintPass<'tcx> for AsyncFnInTraitWithoutSendBound {
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
+        if let TraitItemKind::Fn(sig, _) = &item.kind
+            && let FnRetTy::Return(ret_ty) = sig.decl.output
+            && let TyKind::OpaqueDef(opaque_id, _, true) = ret_ty.kind
+            && let ItemKind::OpaqueTy(opaque) = &cx.tcx.hir().item(opaque_id).kind
+            && !item.span.from_expansion()
+            && cx
+                .effective_visibilities
+                .is_exported(cx.tcx.hir().get_parent_item(item.hir_id()).def_id)
+            && !has_send_bound(cx, opaque.bounds)
+        {
+            let msg = "callers can't require the future returned by this method to be `Send`";
+            if sig.header.asyncness == IsAsync::Async {
+                let Some(output) = async_fn_output(opaque.bounds) else {
+                    return;
+                };
+                let output = if let TyKind::Tup([]) = output.kind {
+                    "()".into()
+                } else {
+                    snippet(cx, output.span, "..")
+                };
+                span_lint_and_help(
+                    cx,
+                    ASYNC_FN_IN_TRAIT_WITHOUT_SEND_BOUND,
+                    sig.span,
+                    msg,
+                    None,
+                    &format!(
+                        "consider desugaring it to `fn {}(..) -> impl Future<Output = {output}> + Send`",
+                        item.ident
+                    ),
+                );
+            } else if is_future(cx, opaque.bounds) {
+                let mut app = Applicability::MaybeIncorrect;
+                let ret = snippet_with_applicability(cx, ret_ty.span, "..", &mut app);
+                span_lint_and_sugg(
+                    cx,
+                    ASYNC_FN_IN_TRAIT_WITHOUT_SEND_BOUND,
+                    ret_ty.span,
+                    msg,
+                    "add a `Send` bound",
+                    format!("{ret} + Send"),
+                    app,
+                );
+            }
+        }
+    }
+}
+
+fn has_send_bound(cx: &LateContext<'_>, bounds: GenericBounds<'_>) -> bool {
+    bound_traits(bounds).any(|id| cx.tcx.is_diagnostic_item(sym::Send, id))
+}
+
+fn is_future(cx: &LateContext<'_>, bounds: GenericBounds<'_>) -> bool {
+    bound_traits(bounds).any(|id| Some(id) == cx.tcx.lang_items().future_trait())
+}
+
+fn bound_traits<'a>(bounds: GenericBounds<'a>) -> impl Iterator<Item = DefId> + 'a {
+    bounds
+        .iter()
+        .filter_map(|bound| bound.trait_ref().and_then(TraitRef::trait_def_id))
+}
+
+/// Returns the output type of the future returned by an `async fn`.
+fn async_fn_output<'tcx>(bounds: GenericBounds<'tcx>) -> Option<&'tcx Ty<'tcx>> {
+    bounds.iter().find_map(|bound| {
+        if let GenericBound::LangItemTrait(LangItem::Future, _, _, args) = bound
+            && let [binding] = args.bindings
+            && let TypeBindingKind::Equality { term: Term::Ty(output) } = binding.kind
+        {
+            Some(output)
+        } else {
+            None
+        }
+    })
+}
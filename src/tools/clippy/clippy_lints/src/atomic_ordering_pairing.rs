@@ -0,0 +1,204 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_hir_and_then};
+use clippy_utils::get_parent_expr;
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, HirId, QPath, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for atomic orderings which don't synchronize with anything:
+    /// - `store`s with `Release` ordering to a struct field or `static` which is only ever loaded
+    ///   with `Relaxed` ordering within the crate,
+    /// - results of `fetch_add` and `fetch_sub` with `Relaxed` ordering being compared, as in
+    ///   reference counting.
+    ///
+    /// ### Why is this bad?
+    /// A `Release` store only makes the writes before it visible to a thread which loads the
+    /// stored value with `Acquire` ordering. Without such a load, either the `Release` is
+    /// unnecessary, or the loads are missing the `Acquire` they need to see the data.
+    ///
+    /// Deciding what to do based on a counter, e.g. freeing a shared value once its reference
+    /// count drops to zero, requires the accesses to the shared value to be ordered with the
+    /// update of the counter, which `Relaxed` doesn't do.
+    ///
+    /// ### Known problems
+    /// Loads in other crates are not seen. Comparing a counter doesn't always mean that it's
+    /// used for synchronization, e.g. when checking a limit.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// static READY: AtomicBool = AtomicBool::new(false);
+    ///
+    /// fn publish() {
+    ///     READY.store(true, Ordering::Release);
+    /// }
+    ///
+    /// fn is_ready() -> bool {
+    ///     READY.load(Ordering::Relaxed)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// static READY: AtomicBool = AtomicBool::new(false);
+    ///
+    /// fn publish() {
+    ///     READY.store(true, Ordering::Release);
+    /// }
+    ///
+    /// fn is_ready() -> bool {
+    ///     READY.load(Ordering::Acquire)
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub ATOMIC_ORDERING_PAIRING,
+    nursery,
+    "atomic orderings which don't synchronize with anything"
+}
+
+#[derive(Default)]
+struct AtomicAccesses {
+    /// The `Release` stores.
+    release_stores: Vec<(HirId, Span)>,
+    /// The first access reading the value, whatever its ordering.
+    first_read: Option<Span>,
+    /// Whether any access reading the value uses `Acquire` ordering or stronger.
+    acquires: bool,
+}
+
+#[derive(Default)]
+pub struct AtomicOrderingPairing {
+    /// The accesses to each atomic field or `static`, in the order they were first accessed.
+    accesses: FxIndexMap<DefId, AtomicAccesses>,
+}
+
+impl_lint_pass!(AtomicOrderingPairing => [ATOMIC_ORDERING_PAIRING]);
+
+impl<'tcx> LateLintPass<'tcx> for AtomicOrderingPairing {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let Some((method, recv, args)) = atomic_method_call(cx, expr) else {
+            return;
+        };
+        if expr.span.from_expansion() {
+            return;
+        }
+        let orderings: Vec<_> = args.iter().filter_map(|arg| match_ordering(cx, arg)).collect();
+
+        if matches!(method.as_str(), "fetch_add" | "fetch_sub")
+            && orderings == [sym::Relaxed]
+            && let Some(parent) = get_parent_expr(cx, expr)
+            && let ExprKind::Binary(op, ..) = parent.kind
+            && op.node.is_comparison()
+        {
+            span_lint_and_help(
+                cx,
+                ATOMIC_ORDERING_PAIRING,
+                parent.span,
+                &format!("the result of a `Relaxed` `{method}` is used for synchronization"),
+                None,
+                "`Relaxed` doesn't order other memory accesses with this one, consider using `AcqRel`",
+            );
+        }
+
+        let Some(atomic) = accessed_atomic(cx, recv) else {
+            return;
+        };
+        let accesses = self.accesses.entry(atomic).or_default();
+        if method.as_str() == "store" {
+            if orderings == [sym::Release] {
+                accesses.release_stores.push((expr.hir_id, expr.span));
+            }
+        } else {
+            accesses.first_read.get_or_insert(expr.span);
+            accesses.acquires |= orderings
+                .iter()
+                .any(|&ordering| matches!(ordering, sym::Acquire | sym::AcqRel | sym::SeqCst));
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for accesses in self.accesses.values() {
+            let Some(read) = accesses.first_read else {
+                continue;
+            };
+            if accesses.acquires {
+                continue;
+            }
+            for &(hir_id, span) in &accesses.release_stores {
+                span_lint_hir_and_then(
+                    cx,
+                    ATOMIC_ORDERING_PAIRING,
+                    hir_id,
+                    span,
+                    "this `Release` store is never paired with an `Acquire` load",
+                    |diag| {
+                        diag.span_note(read, "the atomic is only read with `Relaxed` ordering, e.g. here");
+                        diag.help(
+                            "consider loading with `Acquire`, or storing with `Relaxed` if nothing needs synchronizing",
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the name, receiver and arguments of a call to an inherent method of an atomic type.
+fn atomic_method_call<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+) -> Option<(Symbol, &'tcx Expr<'tcx>, &'tcx [Expr<'tcx>])> {
+    if let ExprKind::MethodCall(path, recv, args, _) = expr.kind
+        && let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+        && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
+        && cx.tcx.trait_id_of_impl(impl_id).is_none()
+        && let Some(adt) = cx.tcx.type_of(impl_id).subst_identity().ty_adt_def()
+        && cx.tcx.is_diagnostic_item(sym::atomic_mod, cx.tcx.parent(adt.did()))
+    {
+        Some((path.ident.name, recv, args))
+    } else {
+        None
+    }
+}
+
+fn match_ordering(cx: &LateContext<'_>, arg: &Expr<'_>) -> Option<Symbol> {
+    let ExprKind::Path(ref qpath) = arg.kind else {
+        return None;
+    };
+    let did = cx.qpath_res(qpath, arg.hir_id).opt_def_id()?;
+    let atomic_ordering = cx.tcx.get_diagnostic_item(sym::Ordering)?;
+    let parent = cx.tcx.parent(did);
+    // The path may resolve to the constructor of the variant
+    if parent != atomic_ordering && cx.tcx.opt_parent(parent) != Some(atomic_ordering) {
+        return None;
+    }
+    Some(cx.tcx.item_name(did))
+}
+
+/// Returns the local struct field or `static` `recv` refers to.
+fn accessed_atomic(cx: &LateContext<'_>, mut recv: &Expr<'_>) -> Option<DefId> {
+    while let ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(UnOp::Deref, inner) = recv.kind {
+        recv = inner;
+    }
+    let atomic = match recv.kind {
+        ExprKind::Field(base, _) => {
+            let adt = cx.typeck_results().expr_ty_adjusted(base).peel_refs().ty_adt_def()?;
+            if !adt.is_struct() {
+                return None;
+            }
+            let index = cx.typeck_results().field_index(recv.hir_id);
+            adt.non_enum_variant().fields[index].did
+        },
+        ExprKind::Path(QPath::Resolved(None, path)) => match path.res {
+            Res::Def(DefKind::Static(_), did) => did,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    atomic.is_local().then_some(atomic)
+}
@@ -1,5 +1,6 @@
 //! checks for attributes
 
+use clippy_utils::cfg::CfgPredicate;
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::macros::{is_panic, macro_backtrace};
 use clippy_utils::msrvs::{self, Msrv};
@@ -16,8 +17,12 @@
 use rustc_session::{declare_lint_pass, declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
 use rustc_span::symbol::Symbol;
+use rustc_span::edit_distance::find_best_match_for_name;
 use rustc_span::{sym, DUMMY_SP};
+use rustc_target::spec::{Target, TargetTriple, TARGETS};
 use semver::Version;
+use std::collections::BTreeSet;
+use std::iter;
 
 static UNIX_SYSTEMS: &[&str] = &[
     "android",
@@ -81,6 +86,7 @@
     /// * deprecated
     /// * unreachable_pub
     /// * unused_imports
+    /// * clippy::cyclic_module_imports
     /// * clippy::enum_glob_use
     /// * clippy::macro_use_imports
     /// * clippy::wildcard_imports
@@ -232,11 +238,15 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for cfg attributes having operating systems used in target family position.
+    /// Checks for cfg attributes having operating systems or the `wasm` target family used in
+    /// target family position, e.g. `cfg(linux)` or `cfg(wasm)`. Also checks
+    /// for `all` predicates requiring different values of the same target option, e.g.
+    /// `all(target_os = "linux", target_os = "windows")`, and for misspelled values of
+    /// `target_os`, `target_family` and `target_env`.
     ///
     /// ### Why is this bad?
-    /// The configuration option will not be recognised and the related item will not be included
-    /// by the conditional compilation engine.
+    /// The configuration option will not be recognised, or the predicate will never hold, and the
+    /// related item will not be included by the conditional compilation engine.
     ///
     /// ### Example
     /// ```rust
@@ -260,7 +270,7 @@
     #[clippy::version = "1.45.0"]
     pub MISMATCHED_TARGET_OS,
     correctness,
-    "usage of `cfg(operating_system)` instead of `cfg(target_os = \"operating_system\")`"
+    "usage of `cfg(operating_system)` instead of `cfg(target_os = \"operating_system\")`, and other impossible target `cfg`s"
 }
 
 declare_clippy_lint! {
@@ -374,6 +384,7 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
                                                     s.as_str(),
                                                     "wildcard_imports"
                                                         | "enum_glob_use"
+                                                        | "cyclic_module_imports"
                                                         | "redundant_pub_crate"
                                                         | "macro_use_imports"
                                                         | "unsafe_removed_from_name"
@@ -597,7 +608,18 @@ fn is_word(nmi: &NestedMetaItem, expected: Symbol) -> bool {
 }
 
 pub struct EarlyAttributes {
-    pub msrv: Msrv,
+    msrv: Msrv,
+    /// Computed on the first `cfg` checking the value of a target option.
+    target_cfg_values: Option<TargetCfgValues>,
+}
+
+impl EarlyAttributes {
+    pub fn new(msrv: Msrv) -> Self {
+        Self {
+            msrv,
+            target_cfg_values: None,
+        }
+    }
 }
 
 impl_lint_pass!(EarlyAttributes => [
@@ -613,7 +635,7 @@ fn check_item(&mut self, cx: &EarlyContext<'_>, item: &rustc_ast::Item) {
 
     fn check_attribute(&mut self, cx: &EarlyContext<'_>, attr: &Attribute) {
         check_deprecated_cfg_attr(cx, attr, &self.msrv);
-        check_mismatched_target_os(cx, attr);
+        check_mismatched_target_os(cx, attr, &mut self.target_cfg_values);
     }
 
     extract_msrv_attr!(EarlyContext);
@@ -690,7 +712,7 @@ fn check_deprecated_cfg_attr(cx: &EarlyContext<'_>, attr: &Attribute, msrv: &Msr
     }
 }
 
-fn check_mismatched_target_os(cx: &EarlyContext<'_>, attr: &Attribute) {
+fn check_mismatched_target_os(cx: &EarlyContext<'_>, attr: &Attribute, values: &mut Option<TargetCfgValues>) {
     fn find_os(name: &str) -> Option<&'static str> {
         UNIX_SYSTEMS
             .iter()
@@ -703,55 +725,172 @@ fn is_unix(name: &str) -> bool {
         UNIX_SYSTEMS.iter().any(|&os| os == name)
     }
 
-    fn find_mismatched_target_os(items: &[NestedMetaItem]) -> Vec<(&str, Span)> {
-        let mut mismatched = Vec::new();
+    // The predicate of `cfg` and the condition of `cfg_attr`
+    if !attr.has_name(sym::cfg) && !attr.has_name(sym::cfg_attr) {
+        return;
+    }
+    let Some(predicate) = attr
+        .meta_item_list()
+        .and_then(|list| list.first().map(CfgPredicate::parse))
+    else {
+        return;
+    };
+    let mut mismatched = Vec::new();
+    predicate.for_each_option(&mut |name, value, span| match value {
+        None => {
+            // Other target option values, e.g. `gnu`, are also likely names of custom `--cfg`s
+            if let Some(os) = find_os(name.as_str()) {
+                mismatched.push((os, span));
+            } else if name.as_str() == "wasm" {
+                span_lint_and_sugg(
+                    cx,
+                    MISMATCHED_TARGET_OS,
+                    span,
+                    "target family used in target family position",
+                    "try",
+                    format!("target_family = \"{name}\""),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        },
+        Some(value) => {
+            let values = values.get_or_insert_with(|| TargetCfgValues::new(cx));
+            check_target_cfg_value(cx, values, name, value, span);
+        },
+    });
 
-        for item in items {
-            if let NestedMetaItem::MetaItem(meta) = item {
-                match &meta.kind {
-                    MetaItemKind::List(list) => {
-                        mismatched.extend(find_mismatched_target_os(list));
-                    },
-                    MetaItemKind::Word => {
-                        if_chain! {
-                            if let Some(ident) = meta.ident();
-                            if let Some(os) = find_os(ident.name.as_str());
-                            then {
-                                mismatched.push((os, ident.span));
-                            }
-                        }
-                    },
-                    MetaItemKind::NameValue(..) => {},
+    if !mismatched.is_empty() {
+        let mess = "operating system used in target family position";
+
+        span_lint_and_then(cx, MISMATCHED_TARGET_OS, attr.span, mess, |diag| {
+            // Avoid showing the unix suggestion multiple times in case
+            // we have more than one mismatch for unix-like systems
+            let mut unix_suggested = false;
+
+            for (os, span) in mismatched {
+                let sugg = format!("target_os = \"{os}\"");
+                diag.span_suggestion(span, "try", sugg, Applicability::MaybeIncorrect);
+
+                if !unix_suggested && is_unix(os) {
+                    diag.help("did you mean `unix`?");
+                    unix_suggested = true;
                 }
             }
-        }
+        });
+    }
+
+    check_conflicting_target_cfgs(cx, &predicate);
+}
 
-        mismatched
+/// Target options which have exactly one value on every target.
+const SINGLE_VALUED_TARGET_CFGS: [Symbol; 7] = [
+    sym::target_os,
+    sym::target_env,
+    sym::target_abi,
+    sym::target_arch,
+    sym::target_vendor,
+    sym::target_endian,
+    sym::target_pointer_width,
+];
+
+/// Checks for `all` predicates requiring two different values of a single valued target option.
+fn check_conflicting_target_cfgs(cx: &EarlyContext<'_>, predicate: &CfgPredicate) {
+    match predicate {
+        CfgPredicate::All(list) => {
+            for (i, first) in list.iter().enumerate() {
+                if let &CfgPredicate::Cfg {
+                    name,
+                    value: Some(first_value),
+                    span: first_span,
+                } = first
+                    && SINGLE_VALUED_TARGET_CFGS.contains(&name)
+                    && let Some(second_span) = list[i + 1..].iter().find_map(|second| match *second {
+                        CfgPredicate::Cfg {
+                            name: second_name,
+                            value: Some(second_value),
+                            span,
+                        } if second_name == name && second_value != first_value => Some(span),
+                        _ => None,
+                    })
+                {
+                    span_lint_and_then(
+                        cx,
+                        MISMATCHED_TARGET_OS,
+                        vec![first_span, second_span],
+                        &format!("`all` requiring different values of `{name}` never holds"),
+                        |diag| {
+                            diag.help("did you mean `any`?");
+                        },
+                    );
+                    break;
+                }
+            }
+            list.iter().for_each(|predicate| check_conflicting_target_cfgs(cx, predicate));
+        },
+        CfgPredicate::Any(list) => list.iter().for_each(|predicate| check_conflicting_target_cfgs(cx, predicate)),
+        CfgPredicate::Not(inner) => check_conflicting_target_cfgs(cx, inner),
+        _ => {},
     }
+}
 
-    if_chain! {
-        if attr.has_name(sym::cfg);
-        if let Some(list) = attr.meta_item_list();
-        let mismatched = find_mismatched_target_os(&list);
-        if !mismatched.is_empty();
-        then {
-            let mess = "operating system used in target family position";
+/// Checks for a misspelled value of `target_os`, `target_family` or `target_env`.
+fn check_target_cfg_value(cx: &EarlyContext<'_>, values: &TargetCfgValues, name: Symbol, value: Symbol, span: Span) {
+    let Some(known) = values.of_key(name) else {
+        return;
+    };
+    if known.iter().any(|known| known == value.as_str()) {
+        return;
+    }
+    let candidates: Vec<_> = known.iter().map(|known| Symbol::intern(known)).collect();
+    if let Some(sugg) = find_best_match_for_name(&candidates, value, None) {
+        span_lint_and_sugg(
+            cx,
+            MISMATCHED_TARGET_OS,
+            span,
+            &format!("unknown `{name}` value `{value}`"),
+            "there is a value with a similar name",
+            format!("{name} = \"{sugg}\""),
+            Applicability::MaybeIncorrect,
+        );
+    }
+}
 
-            span_lint_and_then(cx, MISMATCHED_TARGET_OS, attr.span, mess, |diag| {
-                // Avoid showing the unix suggestion multiple times in case
-                // we have more than one mismatch for unix-like systems
-                let mut unix_suggested = false;
+/// The values of `target_os`, `target_family` and `target_env` of the compiler's builtin targets
+/// and of the current target.
+struct TargetCfgValues {
+    os: Vec<String>,
+    family: Vec<String>,
+    env: Vec<String>,
+}
 
-                for (os, span) in mismatched {
-                    let sugg = format!("target_os = \"{os}\"");
-                    diag.span_suggestion(span, "try", sugg, Applicability::MaybeIncorrect);
+impl TargetCfgValues {
+    fn new(cx: &EarlyContext<'_>) -> Self {
+        let mut os = BTreeSet::new();
+        let mut family = BTreeSet::new();
+        let mut env = BTreeSet::new();
+        let current = &cx.sess().target;
+        for target in TARGETS
+            .iter()
+            .map(|target| Target::expect_builtin(&TargetTriple::from_triple(target)))
+            .chain(iter::once(current.clone()))
+        {
+            os.insert(target.options.os.to_string());
+            family.extend(target.options.families.iter().map(ToString::to_string));
+            env.insert(target.options.env.to_string());
+        }
+        Self {
+            os: os.into_iter().collect(),
+            family: family.into_iter().collect(),
+            env: env.into_iter().filter(|env| !env.is_empty()).collect(),
+        }
+    }
 
-                    if !unix_suggested && is_unix(os) {
-                        diag.help("did you mean `unix`?");
-                        unix_suggested = true;
-                    }
-                }
-            });
+    fn of_key(&self, key: Symbol) -> Option<&[String]> {
+        match key {
+            sym::target_os => Some(&self.os),
+            sym::target_family => Some(&self.family),
+            sym::target_env => Some(&self.env),
+            _ => None,
         }
     }
 }
@@ -1,5 +1,5 @@
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::{match_def_path, paths};
+use clippy_utils::paths::{self, PathLookup};
 use rustc_data_structures::fx::FxHashMap;
 use rustc_hir::def_id::DefId;
 use rustc_hir::{AsyncGeneratorKind, Body, BodyId, GeneratorKind};
@@ -169,6 +169,9 @@
 
 impl_lint_pass!(AwaitHolding => [AWAIT_HOLDING_LOCK, AWAIT_HOLDING_REFCELL_REF, AWAIT_HOLDING_INVALID_TYPE]);
 
+static REFCELL_REF: PathLookup = PathLookup::new(&["core", "cell", "Ref"]);
+static REFCELL_REFMUT: PathLookup = PathLookup::new(&["core", "cell", "RefMut"]);
+
 #[derive(Debug)]
 pub struct AwaitHolding {
     conf_invalid_types: Vec<DisallowedPath>,
@@ -274,11 +277,11 @@ fn is_mutex_guard(cx: &LateContext<'_>, def_id: DefId) -> bool {
     cx.tcx.is_diagnostic_item(sym::MutexGuard, def_id)
         || cx.tcx.is_diagnostic_item(sym::RwLockReadGuard, def_id)
         || cx.tcx.is_diagnostic_item(sym::RwLockWriteGuard, def_id)
-        || match_def_path(cx, def_id, &paths::PARKING_LOT_MUTEX_GUARD)
-        || match_def_path(cx, def_id, &paths::PARKING_LOT_RWLOCK_READ_GUARD)
-        || match_def_path(cx, def_id, &paths::PARKING_LOT_RWLOCK_WRITE_GUARD)
+        || paths::PARKING_LOT_MUTEX_GUARD.matches(cx, def_id)
+        || paths::PARKING_LOT_RWLOCK_READ_GUARD.matches(cx, def_id)
+        || paths::PARKING_LOT_RWLOCK_WRITE_GUARD.matches(cx, def_id)
 }
 
 fn is_refcell_ref(cx: &LateContext<'_>, def_id: DefId) -> bool {
-    match_def_path(cx, def_id, &paths::REFCELL_REF) || match_def_path(cx, def_id, &paths::REFCELL_REFMUT)
+    REFCELL_REF.matches(cx, def_id) || REFCELL_REFMUT.matches(cx, def_id)
 }
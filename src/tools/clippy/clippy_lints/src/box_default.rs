@@ -71,7 +71,7 @@ fn is_plain_default(arg_path: &Expr<'_>) -> bool {
     // we need to match the actual path so we don't match e.g. "u8::default"
     if let ExprKind::Path(QPath::Resolved(None, path)) = &arg_path.kind {
         // avoid generic parameters
-        match_path(path, &paths::DEFAULT_TRAIT_METHOD) && path.segments.iter().all(|seg| seg.args.is_none())
+        match_path(path, paths::DEFAULT_TRAIT_METHOD.path()) && path.segments.iter().all(|seg| seg.args.is_none())
     } else {
         false
     }
@@ -0,0 +1,140 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::macro_backtrace;
+use clippy_utils::{def_path_def_ids, fn_def_id};
+use rustc_hir::def_id::{DefIdMap, DefIdSet};
+use rustc_hir::{Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::DesugaringKind;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for futures which aren't cancellation safe being used as a branch of a
+    /// `select!`-like macro. The methods returning such futures are listed in the
+    /// `cancellation-unsafe-methods` configuration, and the macros in `select-macros`. Both
+    /// default to the ones from `tokio` and `futures`.
+    ///
+    /// ### Why is this bad?
+    /// When another branch completes first, the macro drops the future. Any progress it already
+    /// made is lost, e.g. `write_all` may have written part of the buffer, with no way of
+    /// knowing how much. Inside a loop, the next iteration then starts over from scratch.
+    ///
+    /// ### Known problems
+    /// Only futures passed to the macro directly are checked, not the ones awaited inside an
+    /// `async` block passed to it. Dropping the future may be intended, e.g. to implement a
+    /// timeout.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// loop {
+    ///     tokio::select! {
+    ///         res = socket.write_all(&buf) => res?,
+    ///         _ = shutdown.recv() => break,
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let write = socket.write_all(&buf);
+    /// tokio::pin!(write);
+    /// loop {
+    ///     tokio::select! {
+    ///         res = &mut write => res?,
+    ///         _ = shutdown.recv() => break,
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub CANCELLATION_UNSAFE_AWAIT,
+    pedantic,
+    "using a future which isn't cancellation safe as a branch of `select!`"
+}
+
+pub struct CancellationUnsafeAwait {
+    conf_methods: Vec<String>,
+    conf_macros: Vec<String>,
+    methods: DefIdMap<usize>,
+    macros: DefIdSet,
+}
+
+impl CancellationUnsafeAwait {
+    pub fn new(conf_methods: Vec<String>, conf_macros: Vec<String>) -> Self {
+        Self {
+            conf_methods,
+            conf_macros,
+            methods: DefIdMap::default(),
+            macros: DefIdSet::default(),
+        }
+    }
+}
+
+impl_lint_pass!(CancellationUnsafeAwait => [CANCELLATION_UNSAFE_AWAIT]);
+
+impl<'tcx> LateLintPass<'tcx> for CancellationUnsafeAwait {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for (index, path) in self.conf_methods.iter().enumerate() {
+            let segs: Vec<_> = path.split("::").collect();
+            for id in def_path_def_ids(cx, &segs) {
+                self.methods.insert(id, index);
+            }
+        }
+        for path in &self.conf_macros {
+            let segs: Vec<_> = path.split("::").collect();
+            self.macros.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if !matches!(expr.kind, ExprKind::Call(..) | ExprKind::MethodCall(..))
+            || self.methods.is_empty()
+            || self.macros.is_empty()
+            || expr.span.from_expansion()
+        {
+            return;
+        }
+        let Some(&index) = fn_def_id(cx, expr).and_then(|id| self.methods.get(&id)) else {
+            return;
+        };
+        let Some(macro_name) = self.enclosing_select_macro(cx, expr) else {
+            return;
+        };
+
+        span_lint_and_then(
+            cx,
+            CANCELLATION_UNSAFE_AWAIT,
+            expr.span,
+            &format!(
+                "the future returned by `{}` isn't cancellation safe, but is used as a branch of `{macro_name}!`",
+                self.conf_methods[index]
+            ),
+            |diag| {
+                diag.note("if another branch completes first, the future is dropped and its progress is lost");
+                diag.help(format!(
+                    "consider creating and pinning the future before `{macro_name}!`, and using `&mut` to it as the branch"
+                ));
+            },
+        );
+    }
+}
+
+impl CancellationUnsafeAwait {
+    /// Returns the name of the select macro `expr` is passed to, unless `expr` is awaited in user
+    /// code first, as it is in the handler of a branch.
+    fn enclosing_select_macro(&self, cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<String> {
+        for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+            match node {
+                Node::Expr(parent) if parent.span.from_expansion() => {
+                    if parent.span.is_desugaring(DesugaringKind::Await) {
+                        return None;
+                    }
+                    return macro_backtrace(parent.span)
+                        .find(|macro_call| self.macros.contains(&macro_call.def_id))
+                        .map(|macro_call| cx.tcx.item_name(macro_call.def_id).to_string());
+                },
+                Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) | Node::AnonConst(_) => return None,
+                _ => {},
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,117 @@
+use clippy_utils::consts::constant;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_isize_or_usize;
+use clippy_utils::value_range::value_range;
+use clippy_utils::visitors::{for_each_expr, is_local_used};
+use clippy_utils::{expr_or_init, path_to_local};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, Node, StmtKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::Ty;
+
+use super::{utils, CAST_KNOWN_TRUNCATION};
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    cast_expr: &'tcx Expr<'tcx>,
+    cast_from: Ty<'tcx>,
+    cast_to: Ty<'tcx>,
+) {
+    // Casting a constant is often done on purpose to get its bit pattern, e.g. `0xFFFF_FFFF as i32`
+    if !cast_from.is_integral()
+        || !cast_to.is_integral()
+        || is_isize_or_usize(cast_to)
+        || utils::int_ty_to_nbits(cast_to, cx.tcx) >= utils::int_ty_to_nbits(cast_from, cx.tcx)
+        || constant(cx, cx.typeck_results(), cast_expr).is_some()
+    {
+        return;
+    }
+    let Some(range) = value_range(cx, cast_expr) else {
+        return;
+    };
+    if !range.reached || range.fits_into(cx.tcx, cast_to) || is_refined_by_condition(cx, expr, cast_expr) {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        CAST_KNOWN_TRUNCATION,
+        expr.span,
+        &format!("casting `{cast_from}` to `{cast_to}` truncates some of the values it is used with"),
+        |diag| {
+            let value = snippet(cx, cast_expr.span, "..");
+            if range.min == range.max {
+                diag.note(format!(
+                    "`{value}` is `{}`, which doesn't fit into `{cast_to}`",
+                    range.min
+                ));
+            } else {
+                diag.note(format!(
+                    "`{value}` takes every value in `{}..={}`, not all of which fit into `{cast_to}`",
+                    range.min, range.max
+                ));
+            }
+            diag.help(format!(
+                "use `{cast_to}::try_from` to handle the values which don't fit, or widen the target type"
+            ));
+        },
+    );
+}
+
+/// Checks if a condition on one of the locals the casted expression is computed from decides
+/// whether the cast is reached, e.g. `if i < 256 { v.push(i as u8) }` or `if i > 255 { break }`.
+/// The range of the local then doesn't tell which values actually get casted.
+fn is_refined_by_condition<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, cast_expr: &'tcx Expr<'tcx>) -> bool {
+    let mut locals = Vec::new();
+    for e in [cast_expr, expr_or_init(cx, cast_expr)] {
+        for_each_expr(e, |e| {
+            if let Some(id) = path_to_local(e) {
+                locals.push(id);
+            }
+            ControlFlow::<()>::Continue(())
+        });
+    }
+    let uses_locals = |e: &'tcx Expr<'tcx>| locals.iter().any(|&id| is_local_used(cx, e, id));
+
+    let mut child_id = expr.hir_id;
+    for (parent_id, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(parent) => match parent.kind {
+                ExprKind::If(cond, ..) if cond.hir_id != child_id && uses_locals(cond) => return true,
+                ExprKind::Match(scrutinee, ..) if scrutinee.hir_id != child_id && uses_locals(scrutinee) => {
+                    return true;
+                },
+                _ => {},
+            },
+            Node::Arm(arm) if arm.guard.is_some() => return true,
+            Node::Block(block) => {
+                // Conditional early exits before the cast, e.g. `if i > 255 { continue }`
+                let preceding = block.stmts.iter().take_while(|stmt| stmt.hir_id != child_id);
+                if preceding
+                    .filter_map(|stmt| match stmt.kind {
+                        StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+                        StmtKind::Local(local) => local.init,
+                        StmtKind::Item(_) => None,
+                    })
+                    .any(|e| contains_condition_on(e, &uses_locals))
+                {
+                    return true;
+                }
+            },
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) => break,
+            _ => {},
+        }
+        child_id = parent_id;
+    }
+    false
+}
+
+fn contains_condition_on<'tcx>(e: &'tcx Expr<'tcx>, uses_locals: &impl Fn(&'tcx Expr<'tcx>) -> bool) -> bool {
+    for_each_expr(e, |e| match e.kind {
+        ExprKind::If(cond, ..) | ExprKind::Match(cond, ..) if uses_locals(cond) => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+}
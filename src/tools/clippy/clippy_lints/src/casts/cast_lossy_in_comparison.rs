@@ -0,0 +1,89 @@
+use clippy_utils::consts::constant;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::get_parent_expr;
+use clippy_utils::source::snippet;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_isize_or_usize;
+use clippy_utils::value_range::value_range;
+use rustc_ast::ast::{LitIntType, LitKind};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::Ty;
+
+use super::cast_possible_truncation::apply_reductions;
+use super::{utils, CAST_LOSSY_IN_COMPARISON};
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    cast_expr: &'tcx Expr<'_>,
+    cast_from: Ty<'tcx>,
+    cast_to: Ty<'tcx>,
+) {
+    if !cast_from.is_integral() || !cast_to.is_integral() || is_isize_or_usize(cast_to) {
+        return;
+    }
+    let Some(parent) = get_parent_expr(cx, expr) else {
+        return;
+    };
+    let ExprKind::Binary(op, lhs, rhs) = parent.kind else {
+        return;
+    };
+    if !op.node.is_comparison() || parent.span.from_expansion() {
+        return;
+    }
+
+    // `usize` and `isize` are assumed to have at least 32 bits
+    let from_nbits = if is_isize_or_usize(cast_from) {
+        32
+    } else {
+        utils::int_ty_to_nbits(cast_from, cx.tcx)
+    };
+    let to_nbits = utils::int_ty_to_nbits(cast_to, cx.tcx);
+    if from_nbits <= to_nbits
+        || apply_reductions(cx, from_nbits, cast_expr, cast_from.is_signed()) <= to_nbits
+        || constant(cx, cx.typeck_results(), cast_expr).is_some()
+        || value_range(cx, cast_expr).map_or(false, |range| range.fits_into(cx.tcx, cast_to))
+    {
+        return;
+    }
+
+    let cast_is_lhs = lhs.hir_id == expr.hir_id;
+    let other = if cast_is_lhs { rhs } else { lhs };
+    span_lint_and_then(
+        cx,
+        CAST_LOSSY_IN_COMPARISON,
+        parent.span,
+        &format!("comparing a value truncated by a cast to `{cast_to}`"),
+        |diag| {
+            diag.note(format!(
+                "`{cast_from}` values which don't fit into `{cast_to}` wrap around before the comparison"
+            ));
+            let value = Sugg::hir(cx, cast_expr, "..");
+            // `From<cast_to>` is implemented for `cast_from`
+            if !is_isize_or_usize(cast_from) && (cast_from.is_signed() || !cast_to.is_signed()) {
+                let other = if let ExprKind::Lit(lit) = &other.kind
+                    && let LitKind::Int(_, LitIntType::Unsuffixed) = lit.node
+                {
+                    snippet(cx, other.span, "..").into_owned()
+                } else {
+                    format!("{cast_from}::from({})", snippet(cx, other.span, ".."))
+                };
+                // A cast operand would be parsed as the start of generic arguments by `<`
+                let value = value.maybe_par().to_string();
+                let (lhs, rhs) = if cast_is_lhs { (value, other) } else { (other, value) };
+                diag.span_suggestion(
+                    parent.span,
+                    format!("compare the values as `{cast_from}` instead"),
+                    format!("{lhs} {} {rhs}", op.node.as_str()),
+                    Applicability::MaybeIncorrect,
+                );
+            } else {
+                diag.help(format!(
+                    "use `{cast_to}::try_from({value})` to handle the values which don't fit"
+                ));
+            }
+        },
+    );
+}
@@ -3,6 +3,7 @@
 use clippy_utils::expr_or_init;
 use clippy_utils::source::snippet;
 use clippy_utils::ty::{get_discriminant_value, is_isize_or_usize};
+use clippy_utils::value_range::value_range;
 use rustc_errors::{Applicability, SuggestionStyle};
 use rustc_hir::def::{DefKind, Res};
 use rustc_hir::{BinOpKind, Expr, ExprKind};
@@ -25,7 +26,7 @@ fn get_constant_bits(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<u64> {
     constant_int(cx, expr).map(|c| u64::from(128 - c.leading_zeros()))
 }
 
-fn apply_reductions(cx: &LateContext<'_>, nbits: u64, expr: &Expr<'_>, signed: bool) -> u64 {
+pub(super) fn apply_reductions(cx: &LateContext<'_>, nbits: u64, expr: &Expr<'_>, signed: bool) -> u64 {
     match expr_or_init(cx, expr).kind {
         ExprKind::Cast(inner, _) => apply_reductions(cx, nbits, inner, signed),
         ExprKind::Block(block, _) => block.expr.map_or(nbits, |e| apply_reductions(cx, nbits, e, signed)),
@@ -77,12 +78,12 @@ fn apply_reductions(cx: &LateContext<'_>, nbits: u64, expr: &Expr<'_>, signed: b
     }
 }
 
-pub(super) fn check(
-    cx: &LateContext<'_>,
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
     expr: &Expr<'_>,
-    cast_expr: &Expr<'_>,
+    cast_expr: &'tcx Expr<'_>,
     cast_from: Ty<'_>,
-    cast_to: Ty<'_>,
+    cast_to: Ty<'tcx>,
     cast_to_span: Span,
 ) {
     let msg = match (cast_from.kind(), cast_to.is_integral()) {
@@ -111,6 +112,12 @@ pub(super) fn check(
             if !should_lint {
                 return;
             }
+            // Constants are still linted, the cast was most likely written for other values
+            if constant(cx, cx.typeck_results(), cast_expr).is_none()
+                && value_range(cx, cast_expr).map_or(false, |range| range.fits_into(cx.tcx, cast_to))
+            {
+                return;
+            }
 
             format!("casting `{cast_from}` to `{cast_to}` may truncate the value{suffix}",)
         },
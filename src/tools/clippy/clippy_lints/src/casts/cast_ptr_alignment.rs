@@ -1,6 +1,7 @@
 use clippy_utils::diagnostics::span_lint;
+use clippy_utils::paths::{self, PathLookup};
 use clippy_utils::ty::is_c_void;
-use clippy_utils::{get_parent_expr, is_hir_ty_cfg_dependant, match_any_def_paths, paths};
+use clippy_utils::{get_parent_expr, is_hir_ty_cfg_dependant};
 use rustc_hir::{Expr, ExprKind, GenericArg};
 use rustc_lint::LateContext;
 use rustc_middle::ty::layout::LayoutOf;
@@ -8,6 +9,9 @@
 
 use super::CAST_PTR_ALIGNMENT;
 
+static PTR_UNALIGNED_VOLATILE_LOAD: PathLookup = PathLookup::new(&["core", "intrinsics", "unaligned_volatile_load"]);
+static PTR_UNALIGNED_VOLATILE_STORE: PathLookup = PathLookup::new(&["core", "intrinsics", "unaligned_volatile_store"]);
+
 pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>) {
     if let ExprKind::Cast(cast_expr, cast_to) = expr.kind {
         if is_hir_ty_cfg_dependant(cx, cast_to) {
@@ -74,15 +78,15 @@ fn is_used_as_unaligned(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
             }
         },
         ExprKind::Call(func, [arg, ..]) if arg.hir_id == e.hir_id => {
-            static PATHS: &[&[&str]] = &[
-                paths::PTR_READ_UNALIGNED.as_slice(),
-                paths::PTR_WRITE_UNALIGNED.as_slice(),
-                paths::PTR_UNALIGNED_VOLATILE_LOAD.as_slice(),
-                paths::PTR_UNALIGNED_VOLATILE_STORE.as_slice(),
+            static PATHS: &[&PathLookup] = &[
+                &paths::PTR_READ_UNALIGNED,
+                &paths::PTR_WRITE_UNALIGNED,
+                &PTR_UNALIGNED_VOLATILE_LOAD,
+                &PTR_UNALIGNED_VOLATILE_STORE,
             ];
             if let ExprKind::Path(path) = &func.kind
                 && let Some(def_id) = cx.qpath_res(path, func.hir_id).opt_def_id()
-                && match_any_def_paths(cx, def_id, PATHS).is_some()
+                && PATHS.iter().any(|lookup| lookup.matches(cx, def_id))
             {
                 true
             } else {
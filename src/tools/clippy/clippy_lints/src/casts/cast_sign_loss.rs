@@ -1,6 +1,6 @@
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::span_lint;
-use clippy_utils::{method_chain_args, sext};
+use clippy_utils::{match_method_chain, sext};
 use if_chain::if_chain;
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::LateContext;
@@ -44,8 +44,8 @@ fn should_lint(cx: &LateContext<'_>, cast_op: &Expr<'_>, cast_from: Ty<'_>, cast
 
                 if_chain! {
                     if method_name == "unwrap";
-                    if let Some(arglist) = method_chain_args(cast_op, &["unwrap"]);
-                    if let ExprKind::MethodCall(inner_path, ..) = &arglist[0].0.kind;
+                    if let Some([unwrap]) = match_method_chain(cast_op, ["unwrap"]);
+                    if let ExprKind::MethodCall(inner_path, ..) = &unwrap.receiver.kind;
                     then {
                         method_name = inner_path.ident.name.as_str();
                     }
@@ -3,7 +3,9 @@
 mod borrow_as_ptr;
 mod cast_abs_to_unsigned;
 mod cast_enum_constructor;
+mod cast_known_truncation;
 mod cast_lossless;
+mod cast_lossy_in_comparison;
 mod cast_nan_to_int;
 mod cast_possible_truncation;
 mod cast_possible_wrap;
@@ -83,6 +85,10 @@
     /// default. It suggests user either explicitly ignore the lint,
     /// or use `try_from()` and handle the truncation, default, or panic explicitly.
     ///
+    /// Casts of values which are known to fit into the target type aren't linted, e.g.
+    /// `(x % 1000) as u16`, `x.count_ones() as u8`, or the counter of `for i in 0..200` cast to
+    /// `u8`.
+    ///
     /// ### Why is this bad?
     /// In some problem domains, it is good practice to avoid
     /// truncation. This lint can be activated to help assess where additional
@@ -663,6 +669,62 @@
     "casting a known floating-point NaN into an integer"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for comparisons where one side is truncated by an `as` cast to the type of the
+    /// other side, e.g. `a as u8 == b` where `a: u32` and `b: u8`.
+    ///
+    /// ### Why is this bad?
+    /// Values which don't fit into the smaller type wrap around, so `256_u32 as u8 == 0` is
+    /// `true`. Comparing in the wider type, or converting with `try_from`, handles them.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn is_expected(a: u32, b: u8) -> bool {
+    ///     a as u8 == b
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn is_expected(a: u32, b: u8) -> bool {
+    ///     a == u32::from(b)
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub CAST_LOSSY_IN_COMPARISON,
+    suspicious,
+    "comparing a value truncated by an `as` cast"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for integer casts which truncate at least one of the values the casted expression
+    /// is known to take, e.g. the counter of a `for` loop over a constant range, or a local
+    /// initialized with a constant. Casts which are only reached depending on a condition on the
+    /// value, e.g. `if i < 256 { .. }`, are not linted.
+    ///
+    /// ### Why is this bad?
+    /// Unlike `cast_possible_truncation`, which lints every cast to a smaller type, the
+    /// truncation is known to happen here, so the value is silently wrapped around.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// for i in 0..300 {
+    ///     buf.push(i as u8);
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// for i in 0..=255 {
+    ///     buf.push(i as u8);
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub CAST_KNOWN_TRUNCATION,
+    correctness,
+    "casts which truncate a value the casted expression is known to take"
+}
+
 pub struct Casts {
     msrv: Msrv,
 }
@@ -697,6 +759,8 @@ pub fn new(msrv: Msrv) -> Self {
     CAST_SLICE_FROM_RAW_PARTS,
     AS_PTR_CAST_MUT,
     CAST_NAN_TO_INT,
+    CAST_LOSSY_IN_COMPARISON,
+    CAST_KNOWN_TRUNCATION,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Casts {
@@ -729,6 +793,8 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
 
             if cast_to.is_numeric() && !in_external_macro(cx.sess(), expr.span) {
                 cast_possible_truncation::check(cx, expr, cast_expr, cast_from, cast_to, cast_to_hir.span);
+                cast_lossy_in_comparison::check(cx, expr, cast_expr, cast_from, cast_to);
+                cast_known_truncation::check(cx, expr, cast_expr, cast_from, cast_to);
                 if cast_from.is_numeric() {
                     cast_possible_wrap::check(cx, expr, cast_from, cast_to);
                     cast_precision_loss::check(cx, expr, cast_from, cast_to);
@@ -0,0 +1,122 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{eq_expr_value, higher};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, Mutability};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::adjustment::{Adjust, AutoBorrow, AutoBorrowMutability};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `if map.contains_key(&k)` where the body then unwraps `map.get(&k)`.
+    ///
+    /// ### Why is this bad?
+    /// The key is looked up twice. `get` already returns `None` if the key is missing, so the
+    /// check and the `unwrap` can both be replaced with an `if let`.
+    ///
+    /// For `contains_key` followed by `insert`, see `map_entry`.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # let map: HashMap<u32, u32> = HashMap::new();
+    /// # let k = 0;
+    /// if map.contains_key(&k) {
+    ///     println!("{}", map.get(&k).unwrap());
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # let map: HashMap<u32, u32> = HashMap::new();
+    /// # let k = 0;
+    /// if let Some(v) = map.get(&k) {
+    ///     println!("{v}");
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub CHAINED_CONTAINS_THEN_GET,
+    perf,
+    "checking `contains_key` on a map before unwrapping `get` with the same key"
+}
+declare_lint_pass!(ChainedContainsThenGet => [CHAINED_CONTAINS_THEN_GET]);
+
+impl<'tcx> LateLintPass<'tcx> for ChainedContainsThenGet {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(higher::If { cond, then, .. }) = higher::If::hir(expr)
+            && !expr.span.from_expansion()
+            && let ExprKind::MethodCall(path, map, [key], _) = cond.kind
+            && path.ident.as_str() == "contains_key"
+            && is_map(cx, map)
+            && let Some(get_span) = find_get_unwrap(cx, then, map, key)
+        {
+            span_lint_and_help(
+                cx,
+                CHAINED_CONTAINS_THEN_GET,
+                cond.span,
+                "checking `contains_key` before unwrapping `get` with the same key",
+                Some(get_span),
+                &format!(
+                    "use `if let Some(..) = {}.get({})` instead",
+                    snippet(cx, map.span, ".."),
+                    snippet(cx, key.span, ".."),
+                ),
+            );
+        }
+    }
+}
+
+/// Finds the first `map.get(key).unwrap()` or `.expect(..)` in `then`, as long as neither `map`
+/// nor `key` may have been modified before it.
+fn find_get_unwrap<'tcx>(cx: &LateContext<'tcx>, then: &'tcx Expr<'_>, map: &Expr<'_>, key: &Expr<'_>) -> Option<Span> {
+    let key_place = match key.kind {
+        ExprKind::AddrOf(_, _, inner) => inner,
+        _ => key,
+    };
+    for_each_expr(then, |e| {
+        if let ExprKind::MethodCall(path, get_call, _, _) = e.kind
+            && matches!(path.ident.as_str(), "unwrap" | "expect")
+            && let ExprKind::MethodCall(get_path, get_map, [get_key], _) = get_call.kind
+            && get_path.ident.as_str() == "get"
+            && eq_expr_value(cx, get_map, map)
+            && eq_expr_value(cx, get_key, key)
+        {
+            ControlFlow::Break(Some(e.span))
+        } else if is_mutation_of(cx, e, map) || is_mutation_of(cx, e, key_place) {
+            ControlFlow::Break(None)
+        } else if matches!(e.kind, ExprKind::Closure(_) | ExprKind::Loop(..)) {
+            // Closures can be called, and loops repeated, after a mutation which comes later
+            ControlFlow::Break(None)
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .flatten()
+}
+
+fn is_map(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let ty = cx.typeck_results().expr_ty(e).peel_refs();
+    is_type_diagnostic_item(cx, ty, sym::HashMap) || is_type_diagnostic_item(cx, ty, sym::BTreeMap)
+}
+
+fn is_mutation_of(cx: &LateContext<'_>, e: &Expr<'_>, target: &Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::AddrOf(_, Mutability::Mut, inner) | ExprKind::Assign(inner, ..) | ExprKind::AssignOp(_, inner, _) => {
+            eq_expr_value(cx, inner, target)
+        },
+        ExprKind::MethodCall(_, recv, ..) => {
+            eq_expr_value(cx, recv, target)
+                && cx.typeck_results().expr_adjustments(recv).iter().any(|adjust| {
+                    matches!(
+                        adjust.kind,
+                        Adjust::Borrow(AutoBorrow::Ref(_, AutoBorrowMutability::Mut { .. }))
+                    )
+                })
+        },
+        _ => false,
+    }
+}
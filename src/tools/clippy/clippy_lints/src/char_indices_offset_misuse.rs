@@ -0,0 +1,179 @@
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::visitors::{for_each_expr, is_local_used};
+use clippy_utils::{eq_expr_value, higher};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, BorrowKind, Expr, ExprKind, HirId, LangItem, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for byte offsets from `char_indices()` on one string being used to index or slice
+    /// another string, and for `s.chars().count()` being compared with `s.len()`.
+    ///
+    /// ### Why is this bad?
+    /// The offsets returned by `char_indices()` are byte offsets which are only guaranteed to be
+    /// on a char boundary in the string they came from. Slicing a different string with them
+    /// panics as soon as one of the strings contains a multi-byte character.
+    ///
+    /// The number of `char`s in a string is only equal to its length in bytes if the string is
+    /// ASCII. `is_ascii()` says so directly, and doesn't need to decode the string.
+    ///
+    /// ### Known problems
+    /// Only offsets bound by the pattern of a `for` loop over `char_indices()` are followed. The
+    /// other string may be known to have the same contents.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let (a, b) = ("aé", "aé");
+    /// for (i, _) in a.char_indices() {
+    ///     println!("{}", &b[i..]);
+    /// }
+    /// if a.chars().count() == a.len() {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let (a, b) = ("aé", "aé");
+    /// for (i, _) in b.char_indices() {
+    ///     println!("{}", &b[i..]);
+    /// }
+    /// if a.is_ascii() {}
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub CHAR_INDICES_OFFSET_MISUSE,
+    suspicious,
+    "using byte offsets or lengths of a string as if they were in `char`s or belonged to another string"
+}
+declare_lint_pass!(CharIndicesOffsetMisuse => [CHAR_INDICES_OFFSET_MISUSE]);
+
+impl<'tcx> LateLintPass<'tcx> for CharIndicesOffsetMisuse {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(higher::ForLoop {
+            pat, arg, body, span, ..
+        }) = higher::ForLoop::hir(expr)
+            && let PatKind::Tuple([offset_pat, _], _) = pat.kind
+            && let PatKind::Binding(_, offset_id, _, None) = offset_pat.kind
+            && let ExprKind::MethodCall(path, src, [], _) = arg.kind
+            && path.ident.name.as_str() == "char_indices"
+            && is_str_like(cx, src)
+            && !span.from_expansion()
+        {
+            check_offset_uses(cx, body, offset_id, src);
+        } else if let ExprKind::Binary(op, lhs, rhs) = expr.kind
+            && matches!(op.node, BinOpKind::Eq | BinOpKind::Ne)
+            && !expr.span.from_expansion()
+        {
+            check_count_eq_len(cx, expr, op.node, lhs, rhs);
+        }
+    }
+}
+
+/// Lints every place in `body` where a string other than `src` is sliced with `offset_id`.
+fn check_offset_uses<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'tcx>, offset_id: HirId, src: &'tcx Expr<'tcx>) {
+    let src = peel_str_conversions(src);
+    let _: Option<!> = for_each_expr(body, |e| {
+        let indexed = match e.kind {
+            ExprKind::Index(base, index) if is_local_used(cx, index, offset_id) => base,
+            ExprKind::MethodCall(path, recv, [arg], _)
+                if matches!(
+                    path.ident.name.as_str(),
+                    "get" | "get_mut" | "split_at" | "split_at_mut"
+                ) && is_local_used(cx, arg, offset_id) =>
+            {
+                recv
+            },
+            _ => return ControlFlow::Continue(()),
+        };
+        if is_str_like(cx, indexed) && !eq_expr_value(cx, peel_str_conversions(indexed), src) {
+            span_lint_and_then(
+                cx,
+                CHAR_INDICES_OFFSET_MISUSE,
+                e.span,
+                "byte offset from `char_indices` used on a different string",
+                |diag| {
+                    diag.span_note(src.span, "the offset is only a char boundary in this string");
+                },
+            );
+        }
+        ControlFlow::Continue(())
+    });
+}
+
+/// Lints `s.chars().count() == s.len()` and the reverse.
+fn check_count_eq_len<'tcx>(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    op: BinOpKind,
+    lhs: &'tcx Expr<'tcx>,
+    rhs: &'tcx Expr<'tcx>,
+) {
+    let Some(s) = chars_count_recv(lhs)
+        .zip(len_recv(rhs))
+        .or_else(|| chars_count_recv(rhs).zip(len_recv(lhs)))
+        .filter(|(counted, measured)| eq_expr_value(cx, counted, measured))
+        .map(|(counted, _)| counted)
+    else {
+        return;
+    };
+    if !is_str_like(cx, s) {
+        return;
+    }
+
+    let mut app = Applicability::MachineApplicable;
+    let recv = Sugg::hir_with_context(cx, s, expr.span.ctxt(), "..", &mut app).maybe_par();
+    let sugg = if op == BinOpKind::Eq {
+        format!("{recv}.is_ascii()")
+    } else {
+        format!("!{recv}.is_ascii()")
+    };
+    span_lint_and_sugg(
+        cx,
+        CHAR_INDICES_OFFSET_MISUSE,
+        expr.span,
+        "comparing the number of `char`s in a string with its length in bytes",
+        "they are only equal if the string is ASCII, check that directly",
+        sugg,
+        app,
+    );
+}
+
+fn chars_count_recv<'tcx>(e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::MethodCall(count, chars, [], _) = e.kind
+        && count.ident.name.as_str() == "count"
+        && let ExprKind::MethodCall(path, recv, [], _) = chars.kind
+        && path.ident.name.as_str() == "chars"
+    {
+        Some(recv)
+    } else {
+        None
+    }
+}
+
+fn len_recv<'tcx>(e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::MethodCall(path, recv, [], _) = e.kind
+        && path.ident.name.as_str() == "len"
+    {
+        Some(recv)
+    } else {
+        None
+    }
+}
+
+/// Removes borrows, derefs and `as_str()` calls which don't change which string is used.
+fn peel_str_conversions<'tcx>(mut e: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    loop {
+        e = match e.kind {
+            ExprKind::AddrOf(BorrowKind::Ref, _, inner) | ExprKind::Unary(UnOp::Deref, inner) => inner,
+            ExprKind::MethodCall(path, recv, [], _) if path.ident.name.as_str() == "as_str" => recv,
+            _ => return e,
+        };
+    }
+}
+
+fn is_str_like(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let ty = cx.typeck_results().expr_ty(e).peel_refs();
+    ty.is_str() || is_type_lang_item(cx, ty, LangItem::String)
+}
@@ -0,0 +1,183 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{
+    eq_expr_value, get_enclosing_loop_or_multi_call_closure, in_constant, is_trait_method, path_to_local,
+};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, HirId, LangItem, Mutability, Node, UnOp};
+use rustc_hir_typeck::expr_use_visitor::{Delegate, ExprUseVisitor, PlaceBase, PlaceWithHirId};
+use rustc_infer::infer::TyCtxtInferExt;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::mir::FakeReadCause;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a clone of a collection or a `String` being assigned to a place which already
+    /// holds a value, e.g. `a = b.clone()`.
+    ///
+    /// ### Why is this bad?
+    /// The old value is dropped along with its allocation, and the clone allocates anew.
+    /// `a.clone_from(&b)` can reuse the allocation of `a` if it's large enough.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let mut a = vec![1, 2, 3];
+    /// let b = vec![4, 5, 6];
+    /// a = b.clone();
+    /// # drop(a);
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let mut a = vec![1, 2, 3];
+    /// let b = vec![4, 5, 6];
+    /// a.clone_from(&b);
+    /// # drop(a);
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub CLONE_INTO_UNINITIALIZED_REUSE,
+    perf,
+    "assigning a clone to an initialized value instead of using `clone_from`"
+}
+
+declare_lint_pass!(CloneIntoUninitializedReuse => [CLONE_INTO_UNINITIALIZED_REUSE]);
+
+/// Types whose `Clone::clone_from` reuses the allocation.
+const ALLOCATING_TYPES: [Symbol; 7] = [
+    sym::Vec,
+    sym::VecDeque,
+    sym::HashMap,
+    sym::HashSet,
+    sym::BTreeMap,
+    sym::BTreeSet,
+    sym::BinaryHeap,
+];
+
+impl<'tcx> LateLintPass<'tcx> for CloneIntoUninitializedReuse {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Assign(lhs, rhs, _) = expr.kind
+            && let ExprKind::MethodCall(_, recv, [], _) = rhs.kind
+            && is_trait_method(cx, rhs, sym::Clone)
+            && !expr.span.from_expansion()
+            && !in_constant(cx, expr.hir_id)
+            && is_allocating(cx, cx.typeck_results().expr_ty(rhs))
+            && is_initialized(cx, lhs)
+            // `a.clone_from(&a)` doesn't borrow check, neither does `v.clone_from(&v[0])`
+            && !eq_expr_value(cx, lhs, recv)
+            && !root_local(lhs).map_or(false, |id| is_local_used(cx, recv, id))
+        {
+            // The value might have been moved out, e.g. `drop(a); a = b.clone();`, in which case
+            // there's nothing to call `clone_from` on
+            let mut applicability = if root_local(lhs).map_or(false, |id| is_moved_before(cx, id, expr)) {
+                Applicability::MaybeIncorrect
+            } else {
+                Applicability::MachineApplicable
+            };
+            // `*a = b.clone()` where `a: &mut T` becomes `a.clone_from(..)`
+            let dest = match lhs.kind {
+                ExprKind::Unary(UnOp::Deref, inner)
+                    if matches!(
+                        cx.typeck_results().expr_ty(inner).kind(),
+                        ty::Ref(_, _, Mutability::Mut)
+                    ) =>
+                {
+                    Sugg::hir_with_applicability(cx, inner, "..", &mut applicability)
+                },
+                _ => Sugg::hir_with_applicability(cx, lhs, "..", &mut applicability),
+            };
+            let src = Sugg::hir_with_applicability(cx, recv, "..", &mut applicability);
+            let src = if cx.typeck_results().expr_ty(recv).is_ref() {
+                src
+            } else {
+                src.addr()
+            };
+            span_lint_and_sugg(
+                cx,
+                CLONE_INTO_UNINITIALIZED_REUSE,
+                expr.span,
+                "assigning the result of `Clone::clone()` to an existing value",
+                "use `clone_from` to reuse the existing allocation",
+                format!("{}.clone_from({src})", dest.maybe_par()),
+                applicability,
+            );
+        }
+    }
+}
+
+fn is_allocating(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    is_type_lang_item(cx, ty, LangItem::String)
+        || ALLOCATING_TYPES
+            .iter()
+            .any(|&diag_item| is_type_diagnostic_item(cx, ty, diag_item))
+}
+
+/// Checks if the place `e` always holds a value when it's assigned to. Only locals declared
+/// without an initializer might not, e.g. `let a; a = b.clone();`.
+fn is_initialized(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let Some(id) = path_to_local(e) else {
+        return true;
+    };
+    match cx.tcx.hir().get_parent(id) {
+        Node::Local(local) => local.init.is_some(),
+        // Parameters
+        Node::Param(_) => true,
+        _ => false,
+    }
+}
+
+/// Gets the local the place `e` is a part of, e.g. `a` for `a.b[0]`.
+fn root_local(mut e: &Expr<'_>) -> Option<HirId> {
+    loop {
+        match e.kind {
+            ExprKind::Field(base, _) | ExprKind::Index(base, _) | ExprKind::Unary(UnOp::Deref, base) => e = base,
+            _ => return path_to_local(e),
+        }
+    }
+}
+
+/// Checks if the local `id`, or a part of it, might be moved out when `expr` is reached, i.e. it's
+/// moved before `expr`, or anywhere if `expr` is in a loop.
+fn is_moved_before<'tcx>(cx: &LateContext<'tcx>, id: HirId, expr: &Expr<'_>) -> bool {
+    let Some(body_id) = cx.enclosing_body else {
+        return true;
+    };
+    let mut delegate = MovedLocals::default();
+    let infcx = cx.tcx.infer_ctxt().build();
+    ExprUseVisitor::new(
+        &mut delegate,
+        &infcx,
+        cx.tcx.hir().body_owner_def_id(body_id),
+        cx.param_env,
+        cx.typeck_results(),
+    )
+    .consume_body(cx.tcx.hir().body(body_id));
+    let in_loop = get_enclosing_loop_or_multi_call_closure(cx, expr).is_some();
+    delegate
+        .moved
+        .iter()
+        .any(|&(moved_id, at)| moved_id == id && (in_loop || cx.tcx.hir().span(at).lo() < expr.span.lo()))
+}
+
+#[derive(Default)]
+struct MovedLocals {
+    /// The moved locals along with the expressions moving them.
+    moved: Vec<(HirId, HirId)>,
+}
+
+impl<'tcx> Delegate<'tcx> for MovedLocals {
+    fn consume(&mut self, cmt: &PlaceWithHirId<'tcx>, diag_expr_id: HirId) {
+        if let PlaceBase::Local(id) = cmt.place.base {
+            self.moved.push((id, diag_expr_id));
+        }
+    }
+
+    fn borrow(&mut self, _: &PlaceWithHirId<'tcx>, _: HirId, _: ty::BorrowKind) {}
+
+    fn mutate(&mut self, _: &PlaceWithHirId<'tcx>, _: HirId) {}
+
+    fn fake_read(&mut self, _: &PlaceWithHirId<'tcx>, _: FakeReadCause, _: HirId) {}
+}
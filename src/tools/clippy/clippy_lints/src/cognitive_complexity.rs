@@ -1,19 +1,20 @@
 //! calculate cognitive complexity and warn about overly complex functions
 
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::visitors::for_each_expr;
 use clippy_utils::{get_async_fn_body, is_async_fn, LimitStack};
-use core::ops::ControlFlow;
 use rustc_ast::ast::Attribute;
-use rustc_hir::intravisit::FnKind;
-use rustc_hir::{Body, Expr, ExprKind, FnDecl};
+use rustc_hir::intravisit::{walk_expr, FnKind, Visitor};
+use rustc_hir::{Body, Expr, ExprKind, FnDecl, LoopSource, MatchSource};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::def_id::LocalDefId;
 use rustc_span::source_map::Span;
 use rustc_span::{sym, BytePos};
+use serde::Deserialize;
+use std::fmt::{self, Write};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -27,6 +28,12 @@
     /// Sometimes it's hard to find a way to reduce the
     /// complexity.
     ///
+    /// Code expanded from macros of other crates doesn't count towards the complexity,
+    /// but the arguments passed to them do. An `.await` doesn't add to the score either,
+    /// as it doesn't branch the control flow of the function any more than a call does.
+    /// How much each construct adds to the score can be configured with
+    /// `cognitive-complexity-weights`.
+    ///
     /// ### Example
     /// You'll see it when you get the warning.
     #[clippy::version = "1.35.0"]
@@ -35,15 +42,45 @@
     "functions that should be split up into multiple functions"
 }
 
+/// How much each construct adds to the cognitive complexity of a function.
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields, rename_all = "kebab-case")]
+pub struct CognitiveComplexityWeights {
+    /// `if` and `if let` expressions, including `else if` branches
+    pub if_expr: u64,
+    /// `match` expressions with more than one arm
+    pub match_expr: u64,
+    /// each match arm with a guard
+    pub match_guard: u64,
+    /// `loop`, `while` and `for` loops
+    pub loop_expr: u64,
+    /// `?` operators
+    pub question_mark: u64,
+}
+
+impl Default for CognitiveComplexityWeights {
+    fn default() -> Self {
+        Self {
+            if_expr: 1,
+            match_expr: 1,
+            match_guard: 1,
+            loop_expr: 1,
+            question_mark: 0,
+        }
+    }
+}
+
 pub struct CognitiveComplexity {
     limit: LimitStack,
+    weights: CognitiveComplexityWeights,
 }
 
 impl CognitiveComplexity {
     #[must_use]
-    pub fn new(limit: u64) -> Self {
+    pub fn new(limit: u64, weights: CognitiveComplexityWeights) -> Self {
         Self {
             limit: LimitStack::new(limit),
+            weights,
         }
     }
 }
@@ -64,37 +101,34 @@ fn check<'tcx>(
             return;
         }
 
-        let mut cc = 1u64;
-        let mut returns = 0u64;
-        let _: Option<!> = for_each_expr(expr, |e| {
-            match e.kind {
-                ExprKind::If(_, _, _) => {
-                    cc += 1;
-                },
-                ExprKind::Match(_, arms, _) => {
-                    if arms.len() > 1 {
-                        cc += 1;
-                    }
-                    cc += arms.iter().filter(|arm| arm.guard.is_some()).count() as u64;
-                },
-                ExprKind::Ret(_) => returns += 1,
-                _ => {},
-            }
-            ControlFlow::Continue(())
-        });
+        let mut visitor = ComplexityVisitor {
+            cx,
+            counts: ConstructCounts::default(),
+        };
+        visitor.visit_expr(expr);
+        let counts = visitor.counts;
+
+        let weights = self.weights;
+        let parts = [
+            (weights.if_expr * counts.ifs, "`if`"),
+            (weights.match_expr * counts.matches, "`match`"),
+            (weights.match_guard * counts.match_guards, "match guards"),
+            (weights.loop_expr * counts.loops, "loops"),
+            (weights.question_mark * counts.question_marks, "`?`"),
+        ];
+        let mut cc = 1 + parts.iter().map(|(score, _)| score).sum::<u64>();
 
         let ret_ty = cx.typeck_results().node_type(expr.hir_id);
         let ret_adjust = if is_type_diagnostic_item(cx, ret_ty, sym::Result) {
-            returns
+            counts.returns
         } else {
             #[expect(clippy::integer_division)]
-            (returns / 2)
+            (counts.returns / 2)
         };
 
         // prevent degenerate cases where unreachable code contains `return` statements
-        if cc >= ret_adjust {
-            cc -= ret_adjust;
-        }
+        let ret_adjust = if cc >= ret_adjust { ret_adjust } else { 0 };
+        cc -= ret_adjust;
 
         if cc > self.limit.limit() {
             let fn_span = match kind {
@@ -118,7 +152,15 @@ fn check<'tcx>(
                 },
             };
 
-            span_lint_and_help(
+            let mut breakdown = String::from("1 (base)");
+            for (score, construct) in parts.into_iter().filter(|&(score, _)| score > 0) {
+                let _: fmt::Result = write!(breakdown, " + {score} ({construct})");
+            }
+            if ret_adjust > 0 {
+                let _: fmt::Result = write!(breakdown, " - {ret_adjust} (early returns)");
+            }
+
+            span_lint_and_then(
                 cx,
                 COGNITIVE_COMPLEXITY,
                 fn_span,
@@ -126,13 +168,86 @@ fn check<'tcx>(
                     "the function has a cognitive complexity of ({cc}/{})",
                     self.limit.limit()
                 ),
-                None,
-                "you could split it up into multiple smaller functions",
+                |diag| {
+                    diag.help("you could split it up into multiple smaller functions");
+                    diag.note(format!("the score is made up of {breakdown}"));
+                },
             );
         }
     }
 }
 
+/// The number of times each construct contributing to the complexity appears in a function.
+#[derive(Default)]
+struct ConstructCounts {
+    ifs: u64,
+    matches: u64,
+    match_guards: u64,
+    loops: u64,
+    question_marks: u64,
+    returns: u64,
+}
+
+/// Counts the constructs written by the user. Code expanded from external macros and the
+/// desugaring of `?` or `.await` isn't counted, but the user-written code passed to them is.
+/// Closures are checked on their own.
+struct ComplexityVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    counts: ConstructCounts,
+}
+
+impl<'tcx> Visitor<'tcx> for ComplexityVisitor<'_, 'tcx> {
+    fn visit_expr(&mut self, e: &'tcx Expr<'tcx>) {
+        match e.kind {
+            ExprKind::Match(scrutinee, _, MatchSource::TryDesugar) => {
+                self.counts.question_marks += 1;
+                // the arms only contain the desugared early return
+                self.visit_expr(scrutinee);
+                return;
+            },
+            // the arms only contain the polling loop, which would otherwise count as a loop and a
+            // `match`, i.e. +2 for a plain `.await`
+            ExprKind::Match(scrutinee, _, MatchSource::AwaitDesugar) => {
+                self.visit_expr(scrutinee);
+                return;
+            },
+            _ => {},
+        }
+        if in_external_macro(self.cx.sess(), e.span) {
+            walk_expr(self, e);
+            return;
+        }
+
+        match e.kind {
+            ExprKind::If(..) => self.counts.ifs += 1,
+            ExprKind::Match(_, _, MatchSource::ForLoopDesugar) => {},
+            ExprKind::Match(_, arms, _) => {
+                if arms.len() > 1 {
+                    self.counts.matches += 1;
+                }
+                self.counts.match_guards += arms.iter().filter(|arm| arm.guard.is_some()).count() as u64;
+            },
+            ExprKind::Loop(block, _, LoopSource::While, _) => {
+                self.counts.loops += 1;
+                // don't count the `if` the loop condition is desugared to
+                if let Some(Expr {
+                    kind: ExprKind::If(cond, then, _),
+                    ..
+                }) = block.expr
+                {
+                    self.visit_expr(cond);
+                    self.visit_expr(then);
+                    return;
+                }
+            },
+            ExprKind::Loop(..) => self.counts.loops += 1,
+            ExprKind::Ret(_) => self.counts.returns += 1,
+            _ => {},
+        }
+        walk_expr(self, e);
+    }
+}
+
 impl<'tcx> LateLintPass<'tcx> for CognitiveComplexity {
     fn check_fn(
         &mut self,
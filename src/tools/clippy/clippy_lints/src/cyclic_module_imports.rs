@@ -0,0 +1,185 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet, FxIndexMap};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{HirId, Item, ItemKind, UseKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+use std::collections::hash_map::Entry;
+use std::collections::VecDeque;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for modules of the current crate that import items from each other,
+    /// either directly or through a chain of other modules.
+    ///
+    /// ### Why is this bad?
+    /// Modules depending on each other in a cycle usually signal tangled layering.
+    /// None of the modules in the cycle can be understood, tested or moved on its own.
+    ///
+    /// ### Known problems
+    /// Imports from an enclosing module (e.g. `use super::*`) are ignored, since
+    /// child modules are commonly written that way and re-exported by their parent.
+    ///
+    /// ### Example
+    /// ```rust
+    /// mod client {
+    ///     use crate::server::Response;
+    ///     pub struct Request;
+    /// }
+    ///
+    /// mod server {
+    ///     use crate::client::Request;
+    ///     pub struct Response;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// mod protocol {
+    ///     pub struct Request;
+    ///     pub struct Response;
+    /// }
+    ///
+    /// mod client {
+    ///     use crate::protocol::{Request, Response};
+    /// }
+    ///
+    /// mod server {
+    ///     use crate::protocol::{Request, Response};
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub CYCLIC_MODULE_IMPORTS,
+    nursery,
+    "modules of the same crate importing from each other in a cycle"
+}
+
+/// A `use` item importing from another module of the crate.
+struct Import {
+    source: LocalDefId,
+    target: LocalDefId,
+    hir_id: HirId,
+    span: Span,
+}
+
+pub struct CyclicModuleImports {
+    threshold: u64,
+    /// The imports of each module, in the order they were encountered.
+    imports: FxIndexMap<LocalDefId, Vec<Import>>,
+}
+
+impl CyclicModuleImports {
+    #[must_use]
+    pub fn new(threshold: u64) -> Self {
+        Self {
+            threshold,
+            imports: FxIndexMap::default(),
+        }
+    }
+}
+
+impl_lint_pass!(CyclicModuleImports => [CYCLIC_MODULE_IMPORTS]);
+
+impl<'tcx> LateLintPass<'tcx> for CyclicModuleImports {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        let ItemKind::Use(path, kind) = item.kind else { return };
+        if kind == UseKind::ListStem || item.span.from_expansion() {
+            return;
+        }
+
+        let source = cx.tcx.parent_module(item.hir_id());
+        for res in &path.res {
+            let Res::Def(def_kind, def_id) = *res else { continue };
+            let Some(def_id) = def_id.as_local() else { continue };
+            let target = if def_kind == DefKind::Mod {
+                def_id
+            } else {
+                cx.tcx.parent_module_from_def_id(def_id)
+            };
+
+            // Importing from an enclosing module can't start a cycle on its own, see "Known problems".
+            if target == source || cx.tcx.is_descendant_of(source.to_def_id(), target.to_def_id()) {
+                continue;
+            }
+
+            let imports = self.imports.entry(source).or_default();
+            if !imports.iter().any(|import| import.target == target) {
+                imports.push(Import {
+                    source,
+                    target,
+                    hir_id: item.hir_id(),
+                    span: item.span,
+                });
+            }
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        // Each cycle is only reported once, starting from the first module found in it. The cycles
+        // found from the other modules in it are the same modules in a rotated order.
+        let mut reported = FxHashSet::default();
+
+        for &start in self.imports.keys() {
+            let Some(cycle) = shortest_cycle(&self.imports, start) else { continue };
+            let mut members: Vec<_> = cycle.iter().map(|import| import.target).collect();
+            members.sort_unstable_by_key(|module| module.local_def_index);
+            if !reported.insert(members) {
+                continue;
+            }
+
+            if (cycle.len() as u64) < self.threshold {
+                continue;
+            }
+
+            let path = cycle
+                .iter()
+                .map(|import| format!("`{}`", cx.tcx.def_path_str(import.target.to_def_id())))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            let first = cycle[0];
+            span_lint_hir_and_then(
+                cx,
+                CYCLIC_MODULE_IMPORTS,
+                first.hir_id,
+                first.span,
+                "this import is part of a module import cycle",
+                |diag| {
+                    diag.note(format!(
+                        "the cycle is `{}` -> {path}",
+                        cx.tcx.def_path_str(start.to_def_id())
+                    ));
+                },
+            );
+        }
+    }
+}
+
+/// Finds the shortest chain of imports leading from the module `start` back to itself.
+fn shortest_cycle(imports: &FxIndexMap<LocalDefId, Vec<Import>>, start: LocalDefId) -> Option<Vec<&Import>> {
+    // The import through which each module was first reached.
+    let mut reached_by: FxHashMap<LocalDefId, &Import> = FxHashMap::default();
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(module) = queue.pop_front() {
+        for import in imports.get(&module).into_iter().flatten() {
+            if import.target == start {
+                let mut cycle = vec![import];
+                let mut current = module;
+                while current != start {
+                    let import = reached_by[&current];
+                    cycle.push(import);
+                    current = import.source;
+                }
+                cycle.reverse();
+                return Some(cycle);
+            }
+            if let Entry::Vacant(e) = reached_by.entry(import.target) {
+                e.insert(import);
+                queue.push_back(import.target);
+            }
+        }
+    }
+
+    None
+}
@@ -0,0 +1,125 @@
+use crate::dbg_macro::DBG_MACRO;
+use crate::panic_unimplemented::{TODO, UNIMPLEMENTED, UNREACHABLE};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::root_macro_call_first_node;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::{def_path_def_ids, is_in_test, is_lint_allowed};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def_id::DefId;
+use rustc_hir::Expr;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for macros which are only meant to be used during development outside of tests:
+    /// `dbg!`, `todo!`, `unimplemented!` and `unreachable!`, as well as the macros listed in the
+    /// `debug-only-macros` configuration.
+    ///
+    /// `unreachable!` with a message explaining why the code can't be reached can be allowed
+    /// with the `allow-unreachable-with-message` configuration.
+    ///
+    /// The macros are always allowed in tests. `dbg!`, `todo!`, `unimplemented!` and
+    /// `unreachable!` aren't linted where [`dbg_macro`](#dbg_macro), [`todo`](#todo),
+    /// [`unimplemented`](#unimplemented) or [`unreachable`](#unreachable) respectively is
+    /// enabled, since these already report them.
+    ///
+    /// ### Why is this bad?
+    /// Code that is released shouldn't print debugging output or panic because it's unfinished.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// fn parse(input: &str) -> Config {
+    ///     dbg!(input);
+    ///     todo!()
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub DBG_OR_TODO_IN_RELEASE_PATH,
+    restriction,
+    "development-only macros like `dbg!` or `todo!` outside of tests"
+}
+
+pub struct DbgOrTodoInReleasePath {
+    allow_unreachable_with_message: bool,
+    conf_debug_only_macros: Vec<String>,
+    /// The macros of `conf_debug_only_macros` along with their configured path.
+    debug_only_macros: FxHashMap<DefId, usize>,
+}
+
+impl DbgOrTodoInReleasePath {
+    #[must_use]
+    pub fn new(allow_unreachable_with_message: bool, conf_debug_only_macros: Vec<String>) -> Self {
+        Self {
+            allow_unreachable_with_message,
+            conf_debug_only_macros,
+            debug_only_macros: FxHashMap::default(),
+        }
+    }
+}
+
+impl_lint_pass!(DbgOrTodoInReleasePath => [DBG_OR_TODO_IN_RELEASE_PATH]);
+
+impl<'tcx> LateLintPass<'tcx> for DbgOrTodoInReleasePath {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for (index, path) in self.conf_debug_only_macros.iter().enumerate() {
+            let segs: Vec<_> = path.split("::").collect();
+            for id in def_path_def_ids(cx, &segs) {
+                self.debug_only_macros.insert(id, index);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let Some(macro_call) = root_macro_call_first_node(cx, expr) else {
+            return;
+        };
+        let name = if let Some(&index) = self.debug_only_macros.get(&macro_call.def_id) {
+            let path = &self.conf_debug_only_macros[index];
+            path.rsplit("::").next().unwrap_or(path)
+        } else {
+            let (name, lint) = match cx.tcx.get_diagnostic_name(macro_call.def_id) {
+                Some(sym::dbg_macro) => ("dbg", DBG_MACRO),
+                Some(sym::todo_macro) => ("todo", TODO),
+                Some(sym::unimplemented_macro) => ("unimplemented", UNIMPLEMENTED),
+                Some(sym::unreachable_macro) => {
+                    if self.allow_unreachable_with_message && has_arguments(cx, macro_call.span) {
+                        return;
+                    }
+                    ("unreachable", UNREACHABLE)
+                },
+                _ => return,
+            };
+            // Already reported by the lint for this macro
+            if !is_lint_allowed(cx, lint, expr.hir_id) {
+                return;
+            }
+            name
+        };
+        if is_in_test(cx.tcx, expr.hir_id) {
+            return;
+        }
+
+        span_lint_and_then(
+            cx,
+            DBG_OR_TODO_IN_RELEASE_PATH,
+            macro_call.span,
+            &format!("`{name}!` is only meant to be used during development"),
+            |diag| {
+                if name == "dbg" {
+                    diag.help("remove the invocation before committing it to a version control system");
+                }
+            },
+        );
+    }
+}
+
+/// Checks if the macro call at `span` has any arguments, e.g. `unreachable!("message")`.
+fn has_arguments(cx: &LateContext<'_>, span: Span) -> bool {
+    snippet_opt(cx, span).map_or(false, |snippet| {
+        snippet.split_once(['(', '[', '{']).map_or(false, |(_, args)| {
+            args.len() > 1 && !args[..args.len() - 1].trim().is_empty()
+        })
+    })
+}
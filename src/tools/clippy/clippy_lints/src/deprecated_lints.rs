@@ -215,3 +215,15 @@ macro_rules! declare_deprecated_lint {
     pub WRONG_PUB_SELF_CONVENTION,
     "set the `avoid-breaking-exported-api` config option to `false` to enable the `wrong_self_convention` lint for public items"
 }
+
+declare_deprecated_lint! {
+    /// ### What it does
+    /// Nothing. This lint has been deprecated.
+    ///
+    /// ### Deprecation reason
+    /// The `suffix-style` config option was added, which makes
+    /// `literal_suffix_style` lint separated literal suffixes.
+    #[clippy::version = "1.70.0"]
+    pub SEPARATED_LITERAL_SUFFIX,
+    "set the `suffix-style` config option to `\"unseparated\"` to make the `literal_suffix_style` lint check for separated literal suffixes"
+}
@@ -1,9 +1,9 @@
 use clippy_utils::attrs::is_doc_hidden;
-use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_note, span_lint_and_then};
-use clippy_utils::macros::{is_panic, root_macro_call_first_node};
+use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_then};
+use clippy_utils::panic_analysis::{fn_panic_sources, PanicKind, PanicSourcesCache};
 use clippy_utils::source::{first_line_of_span, snippet_with_applicability};
 use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
-use clippy_utils::{is_entrypoint_fn, method_chain_args, return_ty};
+use clippy_utils::{is_entrypoint_fn, return_ty};
 use if_chain::if_chain;
 use itertools::Itertools;
 use pulldown_cmark::Event::{
@@ -18,10 +18,7 @@
 use rustc_errors::emitter::EmitterWriter;
 use rustc_errors::{Applicability, Handler, SuggestionStyle, TerminalUrl};
 use rustc_hir as hir;
-use rustc_hir::intravisit::{self, Visitor};
-use rustc_hir::{AnonConst, Expr};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_middle::hir::nested_filter;
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty;
 use rustc_parse::maybe_new_parser_from_source_str;
@@ -147,6 +144,10 @@
     /// Checks the doc comments of publicly visible functions that
     /// may panic and warns if there is no `# Panics` section.
     ///
+    /// Calls to functions of the same crate are checked one level deep.
+    /// Assertions which only run in debug builds, like `debug_assert!`, are
+    /// ignored.
+    ///
     /// ### Why is this bad?
     /// Documenting the scenarios in which panicking occurs
     /// can help callers who do not want to panic to avoid those situations.
@@ -261,11 +262,46 @@
     "`pub fn` or `pub trait` with `# Safety` docs"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the doc comments of publicly visible functions which can't
+    /// panic and warns if there is a `# Panics` section.
+    ///
+    /// A function is considered to possibly panic if it contains any call
+    /// which isn't to a constructor or to a crate-local function which can't
+    /// panic, or any indexing, integer arithmetic or overloaded operator.
+    ///
+    /// ### Why is this bad?
+    /// The section describes panics which can't happen, and is likely
+    /// outdated.
+    ///
+    /// ### Examples
+    /// ```rust
+    /// /// # Panics
+    /// ///
+    /// /// Panics if `x` is negative.
+    /// pub fn is_positive(x: i32) -> bool {
+    ///     x > 0
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub fn is_positive(x: i32) -> bool {
+    ///     x > 0
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub UNNECESSARY_PANICS_DOC,
+    restriction,
+    "`pub fn` which can't panic with `# Panics` docs"
+}
+
 #[expect(clippy::module_name_repetitions)]
 #[derive(Clone)]
 pub struct DocMarkdown {
     valid_idents: FxHashSet<String>,
     in_trait_impl: bool,
+    panic_sources: PanicSourcesCache,
 }
 
 impl DocMarkdown {
@@ -273,6 +309,7 @@ pub fn new(valid_idents: FxHashSet<String>) -> Self {
         Self {
             valid_idents,
             in_trait_impl: false,
+            panic_sources: PanicSourcesCache::default(),
         }
     }
 }
@@ -284,6 +321,7 @@ pub fn new(valid_idents: FxHashSet<String>) -> Self {
     MISSING_ERRORS_DOC,
     MISSING_PANICS_DOC,
     NEEDLESS_DOCTEST_MAIN,
+    UNNECESSARY_PANICS_DOC,
     UNNECESSARY_SAFETY_DOC,
 ]);
 
@@ -299,14 +337,7 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'_>) {
         match item.kind {
             hir::ItemKind::Fn(ref sig, _, body_id) => {
                 if !(is_entrypoint_fn(cx, item.owner_id.to_def_id()) || in_external_macro(cx.tcx.sess, item.span)) {
-                    let body = cx.tcx.hir().body(body_id);
-                    let mut fpu = FindPanicUnwrap {
-                        cx,
-                        typeck_results: cx.tcx.typeck(item.owner_id.def_id),
-                        panic_span: None,
-                    };
-                    fpu.visit_expr(body.value);
-                    lint_for_missing_headers(cx, item.owner_id, sig, headers, Some(body_id), fpu.panic_span);
+                    lint_for_missing_headers(cx, &mut self.panic_sources, item.owner_id, sig, headers, Some(body_id));
                 }
             },
             hir::ItemKind::Impl(impl_) => {
@@ -342,7 +373,7 @@ fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::TraitIte
         let Some(headers) = check_attrs(cx, &self.valid_idents, attrs) else { return };
         if let hir::TraitItemKind::Fn(ref sig, ..) = item.kind {
             if !in_external_macro(cx.tcx.sess, item.span) {
-                lint_for_missing_headers(cx, item.owner_id, sig, headers, None, None);
+                lint_for_missing_headers(cx, &mut self.panic_sources, item.owner_id, sig, headers, None);
             }
         }
     }
@@ -354,25 +385,18 @@ fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::ImplItem<
             return;
         }
         if let hir::ImplItemKind::Fn(ref sig, body_id) = item.kind {
-            let body = cx.tcx.hir().body(body_id);
-            let mut fpu = FindPanicUnwrap {
-                cx,
-                typeck_results: cx.tcx.typeck(item.owner_id.def_id),
-                panic_span: None,
-            };
-            fpu.visit_expr(body.value);
-            lint_for_missing_headers(cx, item.owner_id, sig, headers, Some(body_id), fpu.panic_span);
+            lint_for_missing_headers(cx, &mut self.panic_sources, item.owner_id, sig, headers, Some(body_id));
         }
     }
 }
 
 fn lint_for_missing_headers(
     cx: &LateContext<'_>,
+    panic_sources: &mut PanicSourcesCache,
     owner_id: hir::OwnerId,
     sig: &hir::FnSig<'_>,
     headers: DocHeaders,
     body_id: Option<hir::BodyId>,
-    panic_span: Option<Span>,
 ) {
     if !cx.effective_visibilities.is_exported(owner_id.def_id) {
         return; // Private functions do not require doc comments
@@ -404,15 +428,35 @@ fn lint_for_missing_headers(
         ),
         _ => (),
     }
-    if !headers.panics && panic_span.is_some() {
-        span_lint_and_note(
-            cx,
-            MISSING_PANICS_DOC,
-            span,
-            "docs for function which may panic missing `# Panics` section",
-            panic_span,
-            "first possible panic found here",
-        );
+    if body_id.is_some() {
+        let panics = fn_panic_sources(cx, panic_sources, owner_id.to_def_id(), 1);
+        if !headers.panics
+            && let Some(panic) = panics
+                .iter()
+                .find(|panic| matches!(panic.kind, PanicKind::Explicit | PanicKind::Unwrap))
+        {
+            span_lint_and_then(
+                cx,
+                MISSING_PANICS_DOC,
+                span,
+                "docs for function which may panic missing `# Panics` section",
+                |diag| {
+                    if let Some(callee_span) = panic.callee_span {
+                        diag.span_note(panic.span, "first possible panic found in this call");
+                        diag.span_note(callee_span, "which may panic here");
+                    } else {
+                        diag.span_note(panic.span, "first possible panic found here");
+                    }
+                },
+            );
+        } else if headers.panics && panics.is_empty() {
+            span_lint(
+                cx,
+                UNNECESSARY_PANICS_DOC,
+                span,
+                "docs for function which can't panic have unnecessary `# Panics` section",
+            );
+        }
     }
     if !headers.errors {
         if is_type_diagnostic_item(cx, return_ty(cx, owner_id), sym::Result) {
@@ -887,50 +931,3 @@ fn has_hyphen(s: &str) -> bool {
         );
     }
 }
-
-struct FindPanicUnwrap<'a, 'tcx> {
-    cx: &'a LateContext<'tcx>,
-    panic_span: Option<Span>,
-    typeck_results: &'tcx ty::TypeckResults<'tcx>,
-}
-
-impl<'a, 'tcx> Visitor<'tcx> for FindPanicUnwrap<'a, 'tcx> {
-    type NestedFilter = nested_filter::OnlyBodies;
-
-    fn visit_expr(&mut self, expr: &'tcx Expr<'_>) {
-        if self.panic_span.is_some() {
-            return;
-        }
-
-        if let Some(macro_call) = root_macro_call_first_node(self.cx, expr) {
-            if is_panic(self.cx, macro_call.def_id)
-                || matches!(
-                    self.cx.tcx.item_name(macro_call.def_id).as_str(),
-                    "assert" | "assert_eq" | "assert_ne" | "todo"
-                )
-            {
-                self.panic_span = Some(macro_call.span);
-            }
-        }
-
-        // check for `unwrap`
-        if let Some(arglists) = method_chain_args(expr, &["unwrap"]) {
-            let receiver_ty = self.typeck_results.expr_ty(arglists[0].0).peel_refs();
-            if is_type_diagnostic_item(self.cx, receiver_ty, sym::Option)
-                || is_type_diagnostic_item(self.cx, receiver_ty, sym::Result)
-            {
-                self.panic_span = Some(expr.span);
-            }
-        }
-
-        // and check sub-expressions
-        intravisit::walk_expr(self, expr);
-    }
-
-    // Panics in const blocks will cause compilation to fail.
-    fn visit_anon_const(&mut self, _: &'tcx AnonConst) {}
-
-    fn nested_visit_map(&mut self) -> Self::Map {
-        self.cx.tcx.hir()
-    }
-}
@@ -1,8 +1,9 @@
 use clippy_utils::higher;
+use clippy_utils::paths::PathLookup;
 use clippy_utils::{
     can_move_expr_to_closure_no_visit,
     diagnostics::span_lint_and_sugg,
-    is_expr_final_block_expr, is_expr_used_or_unified, match_def_path, paths, peel_hir_expr_while,
+    is_expr_final_block_expr, is_expr_used_or_unified, peel_hir_expr_while,
     source::{reindent_multiline, snippet_indent, snippet_with_applicability, snippet_with_context},
     SpanlessEq,
 };
@@ -62,6 +63,13 @@
 
 declare_lint_pass!(HashMapPass => [MAP_ENTRY]);
 
+static BTREEMAP_CONTAINS_KEY: PathLookup =
+    PathLookup::new(&["alloc", "collections", "btree", "map", "BTreeMap", "contains_key"]);
+static BTREEMAP_INSERT: PathLookup = PathLookup::new(&["alloc", "collections", "btree", "map", "BTreeMap", "insert"]);
+static HASHMAP_CONTAINS_KEY: PathLookup =
+    PathLookup::new(&["std", "collections", "hash", "map", "HashMap", "contains_key"]);
+static HASHMAP_INSERT: PathLookup = PathLookup::new(&["std", "collections", "hash", "map", "HashMap", "insert"]);
+
 impl<'tcx> LateLintPass<'tcx> for HashMapPass {
     #[expect(clippy::too_many_lines)]
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
@@ -248,9 +256,9 @@ fn try_parse_contains<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'_>) -> Optio
                 key,
                 call_ctxt: expr.span.ctxt(),
             };
-            if match_def_path(cx, id, &paths::BTREEMAP_CONTAINS_KEY) {
+            if BTREEMAP_CONTAINS_KEY.matches(cx, id) {
                 Some((MapType::BTree, expr))
-            } else if match_def_path(cx, id, &paths::HASHMAP_CONTAINS_KEY) {
+            } else if HASHMAP_CONTAINS_KEY.matches(cx, id) {
                 Some((MapType::Hash, expr))
             } else {
                 None
@@ -268,7 +276,7 @@ struct InsertExpr<'tcx> {
 fn try_parse_insert<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<InsertExpr<'tcx>> {
     if let ExprKind::MethodCall(_, map, [key, value], _) = expr.kind {
         let id = cx.typeck_results().type_dependent_def_id(expr.hir_id)?;
-        if match_def_path(cx, id, &paths::BTREEMAP_INSERT) || match_def_path(cx, id, &paths::HASHMAP_INSERT) {
+        if BTREEMAP_INSERT.matches(cx, id) || HASHMAP_INSERT.matches(cx, id) {
             Some(InsertExpr { map, key, value })
         } else {
             None
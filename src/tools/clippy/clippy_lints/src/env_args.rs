@@ -0,0 +1,236 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::lint_scope::relative_path;
+use clippy_utils::paths::PathLookup;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{find_binding_init, get_parent_expr, higher, is_integer_literal, path_to_local, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, HirId, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for common mistakes when reading the command line arguments:
+    /// * `std::env::args().nth(0)`, which is the path of the executable rather than the first
+    ///   argument
+    /// * indexing the collected arguments, e.g. `args().collect::<Vec<_>>()[1]`, without checking
+    ///   how many there are
+    /// * unwrapping the conversion of an argument from `std::env::args_os()` to a `str`
+    ///
+    /// Examples, which often keep the argument handling short on purpose, can be allowed with
+    /// the `allow-args-positional-skip-in-examples` configuration.
+    ///
+    /// ### Why is this bad?
+    /// The first item of `args()` is usually the path of the executable, so the arguments start
+    /// at index 1. A missing argument panics with an index out of bounds instead of telling the
+    /// user what's missing. `args_os()` is used to support arguments which aren't valid Unicode,
+    /// and unwrapping the conversion panics on exactly those.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// let file = std::env::args().nth(0).expect("usage: prog FILE");
+    /// let args: Vec<String> = std::env::args().collect();
+    /// let count = &args[2];
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// let file = std::env::args().nth(1).expect("usage: prog FILE COUNT");
+    /// let count = std::env::args().nth(2).expect("usage: prog FILE COUNT");
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub ARGS_POSITIONAL_SKIP,
+    suspicious,
+    "reading the command line arguments at the wrong position or without handling errors"
+}
+
+pub struct EnvArgs {
+    allow_in_examples: bool,
+    /// Set in `check_crate` if the crate is linted at all.
+    enabled: bool,
+}
+
+impl EnvArgs {
+    #[must_use]
+    pub fn new(allow_in_examples: bool) -> Self {
+        Self {
+            allow_in_examples,
+            enabled: true,
+        }
+    }
+}
+
+impl_lint_pass!(EnvArgs => [ARGS_POSITIONAL_SKIP]);
+
+static ENV_ARGS: PathLookup = PathLookup::new(&["std", "env", "args"]);
+static ENV_ARGS_OS: PathLookup = PathLookup::new(&["std", "env", "args_os"]);
+
+impl<'tcx> LateLintPass<'tcx> for EnvArgs {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        // Cargo builds each example from a file in the `examples` directory of the package
+        self.enabled = !(self.allow_in_examples
+            && cx
+                .sess()
+                .local_crate_source_file()
+                .map_or(false, |path| relative_path(cx.sess(), &path).starts_with("examples/")));
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if !self.enabled || expr.span.from_expansion() {
+            return;
+        }
+        match expr.kind {
+            ExprKind::MethodCall(path, recv, [arg], _)
+                if path.ident.as_str() == "nth" && is_integer_literal(arg, 0) && args_call(cx, recv).is_some() =>
+            {
+                span_lint_and_then(
+                    cx,
+                    ARGS_POSITIONAL_SKIP,
+                    expr.span,
+                    "the first item of the command line arguments is the path of the executable",
+                    |diag| {
+                        let mut applicability = Applicability::MaybeIncorrect;
+                        let recv = snippet_with_applicability(cx, recv.span, "..", &mut applicability);
+                        diag.span_suggestion(
+                            expr.span,
+                            "if you meant to get the first argument, skip the executable",
+                            format!("{recv}.nth(1)"),
+                            applicability,
+                        );
+                        diag.help("if you meant to get the executable, consider using `std::env::current_exe()`");
+                    },
+                );
+            },
+            ExprKind::Index(base, index) => {
+                if let ExprKind::Lit(lit) = &index.kind
+                    && let LitKind::Int(n, _) = lit.node
+                    && n >= 1
+                    && is_unchecked_args_vec(cx, base)
+                {
+                    span_lint_and_help(
+                        cx,
+                        ARGS_POSITIONAL_SKIP,
+                        expr.span,
+                        "indexing the command line arguments without checking how many there are",
+                        None,
+                        &format!("use `.get({n})` or check `.len()` first to tell the user which argument is missing"),
+                    );
+                }
+            },
+            ExprKind::MethodCall(path, recv, _, _) if matches!(path.ident.as_str(), "unwrap" | "expect") => {
+                if let ExprKind::MethodCall(convert, arg, [], _) = recv.kind
+                    && matches!(convert.ident.as_str(), "to_str" | "into_string")
+                    && is_from_args_os(cx, arg)
+                {
+                    span_lint_and_help(
+                        cx,
+                        ARGS_POSITIONAL_SKIP,
+                        expr.span,
+                        "unwrapping the conversion of an argument from `args_os()` to a string",
+                        None,
+                        "handle arguments which aren't valid Unicode, or use `std::env::args()` if they should panic",
+                    );
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+/// Gets the name of the function if `e` is a call to `std::env::args` or `std::env::args_os`.
+pub(crate) fn args_call(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<&'static str> {
+    let ExprKind::Call(fun, []) = e.kind else {
+        return None;
+    };
+    if ENV_ARGS.matches_path(cx, fun) {
+        Some("args")
+    } else if ENV_ARGS_OS.matches_path(cx, fun) {
+        Some("args_os")
+    } else {
+        None
+    }
+}
+
+/// Checks if `e` is `args().collect()`, or a local initialized with it which is only ever
+/// indexed.
+fn is_unchecked_args_vec<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> bool {
+    let is_collected_args = |e: &Expr<'_>| {
+        matches!(e.kind, ExprKind::MethodCall(path, recv, [], _)
+            if path.ident.name == sym!(collect) && args_call(cx, recv).is_some())
+    };
+    if is_collected_args(e) {
+        return true;
+    }
+    let Some(local) = path_to_local(e) else {
+        return false;
+    };
+    if !find_binding_init(cx, local).map_or(false, is_collected_args) {
+        return false;
+    }
+    let Some(body_id) = cx.enclosing_body else {
+        return false;
+    };
+    // Any other use, e.g. `args.len()` or `match args.as_slice()`, might check the length
+    for_each_expr(cx.tcx.hir().body(body_id).value, |e| {
+        let is_indexed = matches!(
+            get_parent_expr(cx, e),
+            Some(Expr { kind: ExprKind::Index(base, _), .. }) if base.hir_id == e.hir_id
+        );
+        if path_to_local_id(e, local) && !is_indexed {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_none()
+}
+
+/// Checks if `e` is an item of `args_os()`, e.g. `args_os().nth(1).unwrap()` or the pattern of
+/// `for arg in args_os()`.
+fn is_from_args_os<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> bool {
+    match e.kind {
+        ExprKind::Call(..) => args_call(cx, e) == Some("args_os"),
+        ExprKind::MethodCall(_, recv, ..) | ExprKind::Index(recv, _) | ExprKind::AddrOf(_, _, recv) => {
+            is_from_args_os(cx, recv)
+        },
+        ExprKind::Path(_) => path_to_local(e).map_or(false, |local| is_binding_from_args_os(cx, local)),
+        _ => false,
+    }
+}
+
+fn is_binding_from_args_os(cx: &LateContext<'_>, local: HirId) -> bool {
+    if let Some(init) = find_binding_init(cx, local) {
+        return is_from_args_os(cx, init);
+    }
+    let hir = cx.tcx.hir();
+    if let Node::Param(_) = hir.get_parent(local) {
+        // A closure parameter, e.g. `args_os().map(|arg| ..)`
+        hir.parent_iter(local)
+            .find_map(|(_, node)| match node {
+                Node::Expr(
+                    closure @ Expr {
+                        kind: ExprKind::Closure(_),
+                        ..
+                    },
+                ) => Some(closure),
+                _ => None,
+            })
+            .and_then(|closure| get_parent_expr(cx, closure))
+            .map_or(
+                false,
+                |call| matches!(call.kind, ExprKind::MethodCall(_, recv, ..) if is_from_args_os(cx, recv)),
+            )
+    } else {
+        // The pattern of a `for` loop, which is nested in the desugaring
+        hir.parent_iter(local)
+            .find_map(|(_, node)| match node {
+                Node::Expr(e) => higher::ForLoop::hir(e),
+                _ => None,
+            })
+            .map_or(false, |for_loop| {
+                for_loop.pat.hir_id == local && is_from_args_os(cx, for_loop.arg)
+            })
+    }
+}
@@ -1,12 +1,16 @@
-use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::source::indent_of;
 use clippy_utils::{get_parent_as_impl, has_repr_attr, is_bool};
+use rustc_errors::Applicability;
 use rustc_hir::intravisit::FnKind;
-use rustc_hir::{Body, FnDecl, Item, ItemKind, TraitFn, TraitItem, TraitItemKind, Ty};
+use rustc_hir::{Body, FnDecl, HirId, Item, ItemKind, Node, PatKind, TraitFn, TraitItem, TraitItemKind, Ty};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::def_id::LocalDefId;
+use rustc_span::symbol::{kw, Ident};
 use rustc_span::Span;
 use rustc_target::spec::abi::Abi;
+use std::fmt::{self, Write};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -114,20 +118,99 @@ fn too_many_bools<'tcx>(&self, tys: impl Iterator<Item = &'tcx Ty<'tcx>>, kind:
         }
     }
 
-    fn check_fn_sig(&self, cx: &LateContext<'_>, fn_decl: &FnDecl<'_>, span: Span) {
+    fn check_fn_sig(
+        &self,
+        cx: &LateContext<'_>,
+        fn_decl: &FnDecl<'_>,
+        span: Span,
+        hir_id: HirId,
+        params: &[Option<Ident>],
+    ) {
         if !span.from_expansion() && self.too_many_bools(fn_decl.inputs.iter(), Kind::Fn) {
-            span_lint_and_help(
+            span_lint_and_then(
                 cx,
                 FN_PARAMS_EXCESSIVE_BOOLS,
                 span,
                 &format!("more than {} bools in function parameters", self.max_fn_params_bools),
-                None,
-                "consider refactoring bools into two-variant enums",
+                |diag| {
+                    let help = "consider refactoring bools into two-variant enums";
+                    match enum_suggestion(cx, fn_decl, hir_id, params) {
+                        Some(sugg) => diag.multipart_suggestion(help, sugg, Applicability::Unspecified),
+                        None => diag.help(help),
+                    };
+                },
             );
         }
     }
 }
 
+/// Generates a two-variant enum named after each `bool` parameter and uses it as the parameter's
+/// type. Returns `None` if a `bool` parameter isn't bound to a plain name.
+fn enum_suggestion(
+    cx: &LateContext<'_>,
+    fn_decl: &FnDecl<'_>,
+    hir_id: HirId,
+    params: &[Option<Ident>],
+) -> Option<Vec<(Span, String)>> {
+    let hir = cx.tcx.hir();
+    // Enums can't be declared within `impl` or `trait` blocks, so they go right before those
+    let item_id = match hir.get(hir_id) {
+        Node::ImplItem(_) | Node::TraitItem(_) => hir.get_parent_item(hir_id).into(),
+        _ => hir_id,
+    };
+    // Put them before any attributes or doc comments of the item
+    let first_span = hir
+        .attrs(item_id)
+        .first()
+        .map_or_else(|| hir.span(item_id), |attr| attr.span);
+    let insert_at = first_span.shrink_to_lo();
+    let indent = " ".repeat(indent_of(cx, first_span)?);
+
+    let mut enums = String::new();
+    let mut sugg = Vec::new();
+    for (ty, param) in fn_decl.inputs.iter().zip(params) {
+        if is_bool(ty) {
+            let param = param.filter(|ident| ident.name != kw::Underscore)?;
+            let name = to_camel_case(param.as_str());
+            let (yes, no) = variant_names(param.as_str());
+            if name.is_empty() || yes.is_empty() {
+                return None;
+            }
+            let _: fmt::Result = write!(enums, "enum {name} {{ {yes}, {no} }}\n{indent}");
+            sugg.push((ty.span, name));
+        }
+    }
+    sugg.push((insert_at, enums));
+
+    Some(sugg)
+}
+
+/// Derives the names of the two variants from the name of the parameter, e.g. `Round` and
+/// `NotRound` for `is_round`, or `WithLid` and `WithoutLid` for `has_lid`.
+fn variant_names(param: &str) -> (String, String) {
+    if let Some(rest) = param.strip_prefix("has_") {
+        let base = to_camel_case(rest);
+        (format!("With{base}"), format!("Without{base}"))
+    } else {
+        let base = to_camel_case(param.strip_prefix("is_").unwrap_or(param));
+        (base.clone(), format!("Not{base}"))
+    }
+}
+
+/// Converts a `snake_case` name to `CamelCase`, e.g. `is_round` to `IsRound`.
+fn to_camel_case(name: &str) -> String {
+    name.split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
 impl_lint_pass!(ExcessiveBools => [STRUCT_EXCESSIVE_BOOLS, FN_PARAMS_EXCESSIVE_BOOLS]);
 
 impl<'tcx> LateLintPass<'tcx> for ExcessiveBools {
@@ -155,10 +238,11 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
 
     fn check_trait_item(&mut self, cx: &LateContext<'tcx>, trait_item: &'tcx TraitItem<'tcx>) {
         // functions with a body are already checked by `check_fn`
-        if let TraitItemKind::Fn(fn_sig, TraitFn::Required(_)) = &trait_item.kind
+        if let TraitItemKind::Fn(fn_sig, TraitFn::Required(param_names)) = &trait_item.kind
             && fn_sig.header.abi == Abi::Rust
             {
-            self.check_fn_sig(cx, fn_sig.decl, fn_sig.span);
+            let params: Vec<_> = param_names.iter().copied().map(Some).collect();
+            self.check_fn_sig(cx, fn_sig.decl, fn_sig.span, trait_item.hir_id(), &params);
         }
     }
 
@@ -167,7 +251,7 @@ fn check_fn(
         cx: &LateContext<'tcx>,
         fn_kind: FnKind<'tcx>,
         fn_decl: &'tcx FnDecl<'tcx>,
-        _: &'tcx Body<'tcx>,
+        body: &'tcx Body<'tcx>,
         span: Span,
         def_id: LocalDefId,
     ) {
@@ -179,7 +263,15 @@ fn check_fn(
                     |impl_item| impl_item.of_trait.is_none()
                 )
             {
-            self.check_fn_sig(cx, fn_decl, span);
+            let params: Vec<_> = body
+                .params
+                .iter()
+                .map(|param| match param.pat.kind {
+                    PatKind::Binding(_, _, ident, None) => Some(ident),
+                    _ => None,
+                })
+                .collect();
+            self.check_fn_sig(cx, fn_decl, span, hir_id, &params);
         }
     }
 }
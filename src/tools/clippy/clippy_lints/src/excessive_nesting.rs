@@ -0,0 +1,139 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use rustc_ast::ast::{Block, Expr, ExprKind, Item, NodeId};
+use rustc_ast::visit::{walk_expr, FnKind, Visitor};
+use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions whose control flow is nested more deeply than a configurable
+    /// threshold. Every `if` chain, `match`, loop, closure and `async` block counts as one level.
+    ///
+    /// ### Why is this bad?
+    /// Deeply nested code is hard to read, as the reader has to keep every enclosing
+    /// condition in mind. It can usually be flattened with early returns, `let`-`else`,
+    /// or by extracting parts of it into separate functions.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// fn process(items: &[Option<u32>]) {
+    ///     for item in items {
+    ///         if let Some(item) = item {
+    ///             match item {
+    ///                 // ...and a few more levels
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// fn process(items: &[Option<u32>]) {
+    ///     for item in items {
+    ///         let Some(item) = item else { continue };
+    ///         process_one(item);
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub EXCESSIVE_NESTING,
+    pedantic,
+    "functions with deeply nested control flow"
+}
+
+pub struct ExcessiveNesting {
+    threshold: u64,
+}
+
+impl ExcessiveNesting {
+    #[must_use]
+    pub fn new(threshold: u64) -> Self {
+        Self { threshold }
+    }
+}
+
+impl_lint_pass!(ExcessiveNesting => [EXCESSIVE_NESTING]);
+
+impl EarlyLintPass for ExcessiveNesting {
+    fn check_fn(&mut self, cx: &EarlyContext<'_>, kind: FnKind<'_>, _: Span, _: NodeId) {
+        // Closures are checked as part of their enclosing function
+        let FnKind::Fn(_, _, _, _, _, Some(block)) = kind else { return };
+        if in_external_macro(cx.sess(), block.span) {
+            return;
+        }
+
+        let mut visitor = NestingVisitor {
+            threshold: self.threshold,
+            depth: 0,
+            too_deep: Vec::new(),
+        };
+        visitor.visit_block(block);
+
+        for span in visitor.too_deep {
+            span_lint_and_help(
+                cx,
+                EXCESSIVE_NESTING,
+                span,
+                &format!("this block is nested more than {} levels deep", self.threshold),
+                None,
+                "try flattening the code with early returns, or extract parts of it into separate functions",
+            );
+        }
+    }
+}
+
+struct NestingVisitor {
+    threshold: u64,
+    depth: u64,
+    /// The outermost constructs exceeding the threshold.
+    too_deep: Vec<Span>,
+}
+
+impl NestingVisitor {
+    fn nest(&mut self, span: Span, walk: impl FnOnce(&mut Self)) {
+        self.depth += 1;
+        if self.depth > self.threshold {
+            self.too_deep.push(span);
+        } else {
+            walk(self);
+        }
+        self.depth -= 1;
+    }
+
+    /// Walks an `if` together with its `else if` branches, which all share a single level.
+    fn walk_if_chain(&mut self, cond: &Expr, then: &Block, els: Option<&Expr>) {
+        self.visit_expr(cond);
+        self.visit_block(then);
+        if let Some(els) = els {
+            match &els.kind {
+                ExprKind::If(cond, then, els) => self.walk_if_chain(cond, then, els.as_deref()),
+                _ => self.visit_expr(els),
+            }
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for NestingVisitor {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        match &expr.kind {
+            ExprKind::If(cond, then, els) => {
+                self.nest(expr.span, |this| this.walk_if_chain(cond, then, els.as_deref()));
+            },
+            ExprKind::While(..)
+            | ExprKind::ForLoop(..)
+            | ExprKind::Loop(..)
+            | ExprKind::Match(..)
+            | ExprKind::Closure(..)
+            | ExprKind::Async(..) => self.nest(expr.span, |this| walk_expr(this, expr)),
+            _ => walk_expr(self, expr),
+        }
+    }
+
+    // Nested items are checked on their own
+    fn visit_item(&mut self, _: &'ast Item) {}
+}
@@ -0,0 +1,124 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::last_path_segment;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::paths;
+use clippy_utils::sugg::Sugg;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for exit codes computed by casting a `bool`, e.g. `std::process::exit(ok as i32)`.
+    ///
+    /// ### Why is this bad?
+    /// `true` becomes `1`, which signals failure, and `false` becomes `0`, which signals success.
+    /// Whether that's intended depends on what the `bool` means, and it's easy to get it
+    /// backwards as in `exit(ok as i32)`. Writing the codes explicitly, or returning
+    /// `ExitCode::SUCCESS` or `ExitCode::FAILURE` from `main`, says what's meant.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # let failed = false;
+    /// std::process::exit(failed as i32);
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # let failed = false;
+    /// std::process::exit(if failed { 1 } else { 0 });
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub EXIT_CODE_FROM_BOOL_CAST,
+    suspicious,
+    "exiting with a `bool` cast to an exit code"
+}
+
+pub struct ExitCodeFromBoolCast {
+    msrv: Msrv,
+}
+
+impl ExitCodeFromBoolCast {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(ExitCodeFromBoolCast => [EXIT_CODE_FROM_BOOL_CAST]);
+
+/// Words in a condition's name which mean it holds on success.
+const SUCCESS_WORDS: [&str; 6] = ["ok", "success", "successful", "succeeded", "passed", "valid"];
+/// Words in a condition's name which mean it holds on failure.
+const FAILURE_WORDS: [&str; 7] = ["err", "error", "errors", "fail", "failed", "failure", "invalid"];
+
+impl<'tcx> LateLintPass<'tcx> for ExitCodeFromBoolCast {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Call(fun, [arg]) = expr.kind
+            && paths::PROCESS_EXIT.matches_path(cx, fun)
+            && let ExprKind::Cast(cond, _) = arg.kind
+            && cx.typeck_results().expr_ty(cond).is_bool()
+            && !expr.span.from_expansion()
+        {
+            let means_success = means_success(cond);
+            let msg = if means_success {
+                "exiting with a failure code when a success condition holds"
+            } else {
+                "exiting with a `bool` cast to an exit code"
+            };
+            span_lint_and_then(cx, EXIT_CODE_FROM_BOOL_CAST, expr.span, msg, |diag| {
+                let mut applicability = Applicability::MachineApplicable;
+                // `if !failed { 0 } else { 1 }` reads better as `if failed { 1 } else { 0 }`
+                let (cond, codes) = match cond.kind {
+                    ExprKind::Unary(UnOp::Not, inner) => (inner, ["0", "1"]),
+                    _ => (cond, ["1", "0"]),
+                };
+                let [then_code, else_code] = if means_success { [codes[1], codes[0]] } else { codes };
+                let cond_sugg = Sugg::hir_with_applicability(cx, cond, "..", &mut applicability);
+                let sugg = format!("if {cond_sugg} {{ {then_code} }} else {{ {else_code} }}");
+                diag.note("`true` becomes `1`, which signals failure, and `false` becomes `0`, which signals success");
+                if means_success {
+                    diag.span_suggestion(
+                        arg.span,
+                        "if the condition means success, swap the exit codes",
+                        sugg,
+                        Applicability::MaybeIncorrect,
+                    );
+                } else {
+                    diag.span_suggestion(arg.span, "write the exit codes explicitly", sugg, applicability);
+                }
+                if self.msrv.meets(msrvs::EXIT_CODE) {
+                    diag.help("consider returning `ExitCode::SUCCESS` or `ExitCode::FAILURE` from `main` instead");
+                }
+            });
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Guesses from the names in `cond` if it holds on success, e.g. for `ok` or `res.is_ok()`.
+fn means_success(cond: &Expr<'_>) -> bool {
+    success_polarity(cond) == Some(true)
+}
+
+/// Gets whether `cond` holds on success (`Some(true)`) or on failure (`Some(false)`), if its name
+/// says so.
+fn success_polarity(cond: &Expr<'_>) -> Option<bool> {
+    let name = match cond.kind {
+        ExprKind::Unary(UnOp::Not, inner) => return success_polarity(inner).map(|success| !success),
+        ExprKind::Path(ref qpath) => last_path_segment(qpath).ident,
+        ExprKind::MethodCall(path, ..) => path.ident,
+        ExprKind::Field(_, ident) => ident,
+        _ => return None,
+    };
+    let name = name.as_str().to_ascii_lowercase();
+    let has_word = |words: &[&str]| name.split('_').any(|word| words.contains(&word));
+    if has_word(&SUCCESS_WORDS) {
+        Some(true)
+    } else if has_word(&FAILURE_WORDS) {
+        Some(false)
+    } else {
+        None
+    }
+}
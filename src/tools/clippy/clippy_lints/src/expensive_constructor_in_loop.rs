@@ -0,0 +1,147 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{def_path_def_ids, fn_def_id, is_trait_method, path_to_local};
+use core::ops::ControlFlow;
+use rustc_hir::def_id::DefIdMap;
+use rustc_hir::{Expr, ExprKind, Node};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to expensive constructors inside loops, and inside closures passed to
+    /// iterator adapters. The constructors are listed in the `expensive-constructors`
+    /// configuration, which defaults to the constructors of the `regex` crate.
+    ///
+    /// Calls whose arguments depend on values created inside the loop are not linted, as they
+    /// can't be moved out of it.
+    ///
+    /// ### Known problems
+    /// Only loops and iterator adapters are detected as repeatedly executed code. Functions which
+    /// are called repeatedly, e.g. the handlers of a web server called for every request, aren't
+    /// recognized.
+    ///
+    /// ### Why is this bad?
+    /// The value is built again on every iteration, even though it's the same every time.
+    /// Compiling a regex, for example, is much slower than using it.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// for line in lines {
+    ///     let re = Regex::new(r"^\d+$").unwrap();
+    ///     if re.is_match(line) {
+    ///         // ..
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let re = Regex::new(r"^\d+$").unwrap();
+    /// for line in lines {
+    ///     if re.is_match(line) {
+    ///         // ..
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub EXPENSIVE_CONSTRUCTOR_IN_LOOP,
+    perf,
+    "calling an expensive constructor with the same arguments on every iteration of a loop"
+}
+
+pub struct ExpensiveConstructorInLoop {
+    conf_constructors: Vec<String>,
+    constructors: DefIdMap<usize>,
+}
+
+impl ExpensiveConstructorInLoop {
+    pub fn new(conf_constructors: Vec<String>) -> Self {
+        Self {
+            conf_constructors,
+            constructors: DefIdMap::default(),
+        }
+    }
+}
+
+impl_lint_pass!(ExpensiveConstructorInLoop => [EXPENSIVE_CONSTRUCTOR_IN_LOOP]);
+
+impl<'tcx> LateLintPass<'tcx> for ExpensiveConstructorInLoop {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for (index, path) in self.conf_constructors.iter().enumerate() {
+            let segs: Vec<_> = path.split("::").collect();
+            for id in def_path_def_ids(cx, &segs) {
+                self.constructors.insert(id, index);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if !matches!(expr.kind, ExprKind::Call(..) | ExprKind::MethodCall(..))
+            || self.constructors.is_empty()
+            || in_external_macro(cx.sess(), expr.span)
+        {
+            return;
+        }
+        let Some(&index) = fn_def_id(cx, expr).and_then(|id| self.constructors.get(&id)) else {
+            return;
+        };
+        let Some((hot_span, context)) = enclosing_hot_context(cx, expr) else {
+            return;
+        };
+        if uses_local_defined_in(cx, expr, hot_span) {
+            return;
+        }
+
+        span_lint_and_help(
+            cx,
+            EXPENSIVE_CONSTRUCTOR_IN_LOOP,
+            expr.span,
+            &format!("`{}` is called {context}", self.conf_constructors[index]),
+            None,
+            "consider creating the value once beforehand, or lazily in a `static` using `OnceLock`",
+        );
+    }
+}
+
+/// Finds the innermost loop, or closure passed to an iterator adapter, containing `expr`. Stops at
+/// any other closure as it's unknown how often it's called.
+fn enclosing_hot_context(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<(Span, &'static str)> {
+    let mut parents = cx.tcx.hir().parent_iter(expr.hir_id);
+    while let Some((_, node)) = parents.next() {
+        match node {
+            Node::Expr(e) => match e.kind {
+                ExprKind::Loop(..) => return Some((e.span, "inside a loop")),
+                ExprKind::Closure(..) => {
+                    return if let Some((_, Node::Expr(call))) = parents.next()
+                        && let ExprKind::MethodCall(..) = call.kind
+                        && is_trait_method(cx, call, sym::Iterator)
+                    {
+                        Some((e.span, "for every item of an iterator"))
+                    } else {
+                        None
+                    };
+                },
+                _ => {},
+            },
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) | Node::AnonConst(_) => return None,
+            _ => {},
+        }
+    }
+    None
+}
+
+/// Checks whether the arguments of the call use a local defined within `span`.
+fn uses_local_defined_in(cx: &LateContext<'_>, expr: &Expr<'_>, span: Span) -> bool {
+    for_each_expr(expr, |e| {
+        if let Some(id) = path_to_local(e)
+            && span.contains(cx.tcx.hir().span(id))
+        {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
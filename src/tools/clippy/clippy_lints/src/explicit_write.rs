@@ -1,7 +1,8 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::is_expn_of;
 use clippy_utils::macros::FormatArgsExpn;
+use clippy_utils::paths::PathLookup;
 use clippy_utils::source::snippet_with_applicability;
-use clippy_utils::{is_expn_of, match_function_call, paths};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::def::Res;
@@ -41,6 +42,9 @@
 
 declare_lint_pass!(ExplicitWrite => [EXPLICIT_WRITE]);
 
+static STDERR: PathLookup = PathLookup::new(&["std", "io", "stdio", "stderr"]);
+static STDOUT: PathLookup = PathLookup::new(&["std", "io", "stdio", "stdout"]);
+
 impl<'tcx> LateLintPass<'tcx> for ExplicitWrite {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if_chain! {
@@ -51,9 +55,9 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
             if let ExprKind::MethodCall(write_fun, write_recv, [write_arg], _) = look_in_block(cx, &write_call.kind);
             if write_fun.ident.name == sym!(write_fmt);
             // match calls to std::io::stdout() / std::io::stderr ()
-            if let Some(dest_name) = if match_function_call(cx, write_recv, &paths::STDOUT).is_some() {
+            if let Some(dest_name) = if STDOUT.call_args(cx, write_recv).is_some() {
                 Some("stdout")
-            } else if match_function_call(cx, write_recv, &paths::STDERR).is_some() {
+            } else if STDERR.call_args(cx, write_recv).is_some() {
                 Some("stderr")
             } else {
                 None
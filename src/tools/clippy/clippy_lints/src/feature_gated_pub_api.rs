@@ -0,0 +1,159 @@
+use clippy_utils::cfg::required_features;
+use clippy_utils::diagnostics::span_lint_and_note;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::{walk_path, FnKind, Visitor};
+use rustc_hir::{Body, FieldDef, FnDecl, FnRetTy, HirId, Item, ItemKind, Path};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for public items which are only compiled with a cargo feature enabled, but are
+    /// used in the signature of a public item which is compiled without it. Also checks for
+    /// `impl` blocks which are compiled without the features their self type or trait require.
+    ///
+    /// ### Why is this bad?
+    /// The crate fails to build without the feature, which is easy to miss if it is part of the
+    /// default features or if the crate is only ever tested with all features enabled.
+    ///
+    /// ### Known problems
+    /// Only the items which are compiled are checked, so the lint has to be run with the
+    /// features enabled, e.g. with `--all-features`. Features required through an `any` or a
+    /// `not` predicate are ignored.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// #[cfg(feature = "serde")]
+    /// pub struct Config;
+    ///
+    /// pub fn load() -> Config {
+    ///     // ..
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// #[cfg(feature = "serde")]
+    /// pub struct Config;
+    ///
+    /// #[cfg(feature = "serde")]
+    /// pub fn load() -> Config {
+    ///     // ..
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub FEATURE_GATED_PUB_API_WITHOUT_DOC_CFG,
+    suspicious,
+    "public items using items which require more cargo features than they do"
+}
+
+declare_lint_pass!(FeatureGatedPubApi => [FEATURE_GATED_PUB_API_WITHOUT_DOC_CFG]);
+
+impl<'tcx> LateLintPass<'tcx> for FeatureGatedPubApi {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Impl(impl_) = item.kind
+            && !item.span.from_expansion()
+        {
+            let mut v = LocalPaths::default();
+            v.visit_ty(impl_.self_ty);
+            if let Some(trait_ref) = &impl_.of_trait {
+                v.visit_trait_ref(trait_ref);
+            }
+            check_paths(cx, item.owner_id.def_id, "this `impl` block", &v.paths);
+        }
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        _: &'tcx Body<'_>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if matches!(kind, FnKind::Closure)
+            || in_external_macro(cx.sess(), span)
+            || !cx.effective_visibilities.is_exported(def_id)
+        {
+            return;
+        }
+        let mut v = LocalPaths::default();
+        for ty in decl.inputs {
+            v.visit_ty(ty);
+        }
+        if let FnRetTy::Return(ty) = decl.output {
+            v.visit_ty(ty);
+        }
+        check_paths(cx, def_id, "the signature of this function", &v.paths);
+    }
+
+    fn check_field_def(&mut self, cx: &LateContext<'tcx>, field: &'tcx FieldDef<'_>) {
+        if field.span.from_expansion() || !cx.effective_visibilities.is_exported(field.def_id) {
+            return;
+        }
+        let mut v = LocalPaths::default();
+        v.visit_ty(field.ty);
+        check_paths(cx, field.def_id, "the type of this field", &v.paths);
+    }
+}
+
+/// Lints the paths to items which require features `user` isn't gated on.
+fn check_paths(cx: &LateContext<'_>, user: LocalDefId, what: &str, paths: &[(Span, LocalDefId)]) {
+    if paths.is_empty() {
+        return;
+    }
+    let available = required_features(cx.tcx, user);
+    for &(span, def_id) in paths {
+        let mut missing: Vec<Symbol> = required_features(cx.tcx, def_id)
+            .into_iter()
+            .filter(|feature| !available.contains(feature))
+            .collect();
+        if missing.is_empty() {
+            continue;
+        }
+        missing.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        let features = missing
+            .iter()
+            .map(|feature| format!("`{feature}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        span_lint_and_note(
+            cx,
+            FEATURE_GATED_PUB_API_WITHOUT_DOC_CFG,
+            span,
+            &format!(
+                "`{}` requires the feature(s) {features}, but {what} doesn't",
+                cx.tcx.item_name(def_id.to_def_id())
+            ),
+            Some(cx.tcx.def_span(def_id)),
+            "the crate won't build without the feature(s); gate both on the same features",
+        );
+    }
+}
+
+/// Collects the paths to local types and traits, each one only once.
+#[derive(Default)]
+struct LocalPaths {
+    seen: FxHashSet<LocalDefId>,
+    paths: Vec<(Span, LocalDefId)>,
+}
+
+impl<'tcx> Visitor<'tcx> for LocalPaths {
+    fn visit_path(&mut self, path: &Path<'tcx>, _: HirId) {
+        if let Res::Def(
+            DefKind::Struct | DefKind::Enum | DefKind::Union | DefKind::TyAlias | DefKind::Trait | DefKind::TraitAlias,
+            def_id,
+        ) = path.res
+            && let Some(def_id) = def_id.as_local()
+            && !path.span.from_expansion()
+            && self.seen.insert(def_id)
+        {
+            self.paths.push((path.span, def_id));
+        }
+        walk_path(self, path);
+    }
+}
@@ -0,0 +1,274 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::paths::{self, PathLookup};
+use clippy_utils::source::snippet;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{expr_or_init, SpanlessEq};
+use core::ops::ControlFlow;
+use rustc_hir::{Block, Expr, ExprKind, MatchSource, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `std::fs::copy` directly followed by `std::fs::remove_file` of the copied file.
+    ///
+    /// ### Why is this bad?
+    /// `std::fs::rename` moves the file without copying its contents, and can't leave both or
+    /// neither of the files behind when interrupted.
+    ///
+    /// ### Known problems
+    /// `rename` fails when the files are on different filesystems, which copying doesn't.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # fn f() -> std::io::Result<()> {
+    /// std::fs::copy("a.txt", "b.txt")?;
+    /// std::fs::remove_file("a.txt")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # fn f() -> std::io::Result<()> {
+    /// std::fs::rename("a.txt", "b.txt")?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub MANUAL_FS_RENAME,
+    pedantic,
+    "copying a file and removing the original instead of using `std::fs::rename`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `is_ok` or `is_err` called on the result of `std::fs::metadata` or
+    /// `Path::metadata` to check whether a path exists.
+    ///
+    /// ### Why is this bad?
+    /// Any error, e.g. missing permissions, is taken as the path not existing. The check is also
+    /// racy: the path can be created or removed right after it.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// if std::fs::metadata("config.toml").is_ok() {
+    ///     // ..
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # fn f() -> std::io::Result<()> {
+    /// if std::path::Path::new("config.toml").try_exists()? {
+    ///     // ..
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub METADATA_EXISTENCE_CHECK,
+    pedantic,
+    "checking whether a path exists with `metadata(..).is_ok()`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `std::fs::create_dir` on a path joined to a parent path which is built in the
+    /// same function, but never created there.
+    ///
+    /// ### Why is this bad?
+    /// `create_dir` fails if the parent directory doesn't exist. As the parent is built right
+    /// before, `create_dir_all` was likely intended.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # use std::path::Path;
+    /// # fn f(base: &Path) -> std::io::Result<()> {
+    /// let cache = base.join("cache");
+    /// std::fs::create_dir(cache.join("images"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # use std::path::Path;
+    /// # fn f(base: &Path) -> std::io::Result<()> {
+    /// let cache = base.join("cache");
+    /// std::fs::create_dir_all(cache.join("images"))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub CREATE_DIR_NESTED,
+    suspicious,
+    "calling `std::fs::create_dir` on a path whose parent is built but never created"
+}
+
+pub struct Filesystem {
+    msrv: Msrv,
+}
+
+impl Filesystem {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self { msrv }
+    }
+}
+
+impl_lint_pass!(Filesystem => [MANUAL_FS_RENAME, METADATA_EXISTENCE_CHECK, CREATE_DIR_NESTED]);
+
+static FS_COPY: PathLookup = PathLookup::new(&["std", "fs", "copy"]);
+static FS_CREATE_DIR_ALL: PathLookup = PathLookup::new(&["std", "fs", "create_dir_all"]);
+static FS_METADATA: PathLookup = PathLookup::new(&["std", "fs", "metadata"]);
+static FS_REMOVE_FILE: PathLookup = PathLookup::new(&["std", "fs", "remove_file"]);
+static PATH_JOIN: PathLookup = PathLookup::new(&["std", "path", "Path", "join"]);
+static PATH_METADATA: PathLookup = PathLookup::new(&["std", "path", "Path", "metadata"]);
+
+impl<'tcx> LateLintPass<'tcx> for Filesystem {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        if self.msrv.meets(msrvs::PATH_TRY_EXISTS) {
+            check_metadata_existence(cx, expr);
+        }
+        if let Some([path]) = paths::FS_CREATE_DIR.call_args(cx, expr) {
+            check_create_dir(cx, expr, path);
+        }
+    }
+
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        for [copy_stmt, remove_stmt] in block.stmts.array_windows::<2>() {
+            if let Some(copy) = stmt_call(copy_stmt)
+                && let Some([from, to]) = FS_COPY.call_args(cx, copy)
+                && let Some(remove) = stmt_call(remove_stmt)
+                && let Some([removed]) = FS_REMOVE_FILE.call_args(cx, remove)
+                && is_same_path(cx, from, removed)
+                && !copy_stmt.span.from_expansion()
+                && !remove_stmt.span.from_expansion()
+            {
+                span_lint_and_help(
+                    cx,
+                    MANUAL_FS_RENAME,
+                    copy_stmt.span.to(remove_stmt.span),
+                    "copying a file and removing the original",
+                    None,
+                    &format!(
+                        "use `std::fs::rename({}, {})` unless the files can be on different filesystems",
+                        snippet(cx, from.span, ".."),
+                        snippet(cx, to.span, ".."),
+                    ),
+                );
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+fn check_metadata_existence<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+    if let ExprKind::MethodCall(name, recv, [], _) = expr.kind
+        && let method @ ("is_ok" | "is_err") = name.ident.as_str()
+        && (FS_METADATA.call_args(cx, recv).is_some()
+            || cx
+                .typeck_results()
+                .type_dependent_def_id(recv.hir_id)
+                .map_or(false, |def_id| PATH_METADATA.matches(cx, def_id)))
+    {
+        span_lint_and_then(
+            cx,
+            METADATA_EXISTENCE_CHECK,
+            expr.span,
+            &format!("checking whether a path exists with `metadata(..).{method}()`"),
+            |diag| {
+                diag.note("any error is taken as the path not existing");
+                diag.help("use `Path::try_exists` to handle the other errors");
+            },
+        );
+    }
+}
+
+fn check_create_dir<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>, path: &'tcx Expr<'tcx>) {
+    if let Some(parent) = joined_parent(cx, path)
+        // The parent is built here, so it's likely not created yet
+        && joined_parent(cx, parent).is_some()
+        && let Some(body_id) = cx.enclosing_body
+        && for_each_expr(cx.tcx.hir().body(body_id).value, |e| {
+            if let Some([created]) = paths::FS_CREATE_DIR.call_args(cx, e)
+                .or_else(|| FS_CREATE_DIR_ALL.call_args(cx, e))
+                && is_same_path(cx, created, parent)
+            {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+        .is_none()
+    {
+        span_lint_and_then(
+            cx,
+            CREATE_DIR_NESTED,
+            expr.span,
+            "calling `std::fs::create_dir` on a path whose parent is never created",
+            |diag| {
+                diag.span_note(path_source(cx, parent).span, "the parent path is built here");
+                diag.help("use `std::fs::create_dir_all` to also create the parent directories");
+            },
+        );
+    }
+}
+
+/// Gets the call of an expression statement, looking through `?`, `unwrap` and `expect`.
+fn stmt_call<'tcx>(stmt: &'tcx Stmt<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let (StmtKind::Expr(mut e) | StmtKind::Semi(mut e)) = stmt.kind else {
+        return None;
+    };
+    loop {
+        e = match e.kind {
+            // `x?` is desugared to `match Try::branch(x) { .. }`
+            ExprKind::Match(scrutinee, _, MatchSource::TryDesugar) => match scrutinee.kind {
+                ExprKind::Call(_, [inner]) => inner,
+                _ => return None,
+            },
+            ExprKind::MethodCall(name, recv, _, _) if matches!(name.ident.as_str(), "unwrap" | "expect") => recv,
+            ExprKind::Call(..) => return Some(e),
+            _ => return None,
+        };
+    }
+}
+
+/// Gets the expression building the path `e`, looking through borrows, conversions between path
+/// types and immutable locals.
+fn path_source<'tcx>(cx: &LateContext<'tcx>, mut e: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    loop {
+        e = expr_or_init(cx, e.peel_borrows());
+        match e.kind {
+            ExprKind::MethodCall(name, recv, [], _)
+                if matches!(name.ident.as_str(), "as_path" | "as_ref" | "to_path_buf" | "clone") =>
+            {
+                e = recv;
+            },
+            _ => return e,
+        }
+    }
+}
+
+/// Checks if the paths built by `a` and `b` are the same.
+fn is_same_path<'tcx>(cx: &LateContext<'tcx>, a: &'tcx Expr<'tcx>, b: &'tcx Expr<'tcx>) -> bool {
+    SpanlessEq::new(cx).eq_expr(path_source(cx, a), path_source(cx, b))
+}
+
+/// If the path `e` is built by `Path::join`, gets the path it's joined to.
+fn joined_parent<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let e = path_source(cx, e);
+    if let ExprKind::MethodCall(_, parent, [_], _) = e.kind
+        && cx
+            .typeck_results()
+            .type_dependent_def_id(e.hir_id)
+            .map_or(false, |def_id| PATH_JOIN.matches(cx, def_id))
+    {
+        Some(parent)
+    } else {
+        None
+    }
+}
@@ -1,4 +1,4 @@
-use clippy_utils::{diagnostics::span_lint_and_then, is_in_test_function};
+use clippy_utils::{diagnostics::span_lint_and_then, is_in_test};
 
 use rustc_hir::{intravisit::FnKind, Body, HirId};
 use rustc_lint::LateContext;
@@ -7,8 +7,7 @@
 use super::IMPL_TRAIT_IN_PARAMS;
 
 pub(super) fn check_fn<'tcx>(cx: &LateContext<'_>, kind: &'tcx FnKind<'_>, body: &'tcx Body<'_>, hir_id: HirId) {
-    if cx.tcx.visibility(cx.tcx.hir().body_owner_def_id(body.id())).is_public() && !is_in_test_function(cx.tcx, hir_id)
-    {
+    if cx.tcx.visibility(cx.tcx.hir().body_owner_def_id(body.id())).is_public() && !is_in_test(cx.tcx, hir_id) {
         if let FnKind::ItemFn(ident, generics, _) = kind {
             for param in generics.params {
                 if param.is_impl_trait() {
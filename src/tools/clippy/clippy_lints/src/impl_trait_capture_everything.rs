@@ -0,0 +1,129 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::intravisit::Visitor;
+use rustc_hir::{GenericBound, Item, ItemKind, Lifetime, LifetimeName, OpaqueTyOrigin};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, TypeVisitableExt};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions returning `impl Trait` with a lifetime bound (e.g. `+ 'a` or `+ '_`)
+    /// on a lifetime the returned value doesn't actually borrow from.
+    ///
+    /// ### Why is this bad?
+    /// A lifetime bound makes the returned `impl Trait` capture that lifetime. Callers
+    /// then have to keep the corresponding argument borrowed for as long as they use the
+    /// returned value, even though it doesn't borrow from it, which leads to confusing
+    /// borrow checker errors in downstream code.
+    ///
+    /// ### Known problems
+    /// Only lifetime bounds are checked. Lifetimes captured through the trait bounds
+    /// themselves (e.g. `impl Iterator<Item = &'a u8>`) are assumed to be needed.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn shout(name: &str) -> impl std::fmt::Display + '_ {
+    ///     name.to_uppercase()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn shout(name: &str) -> impl std::fmt::Display {
+    ///     name.to_uppercase()
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub IMPL_TRAIT_CAPTURE_EVERYTHING,
+    pedantic,
+    "`impl Trait` return types capturing lifetimes the returned value doesn't borrow from"
+}
+
+declare_lint_pass!(ImplTraitCaptureEverything => [IMPL_TRAIT_CAPTURE_EVERYTHING]);
+
+impl<'tcx> LateLintPass<'tcx> for ImplTraitCaptureEverything {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        let ItemKind::OpaqueTy(opaque) = &item.kind else { return };
+        // `async fn`s need to capture all of their arguments
+        if !matches!(opaque.origin, OpaqueTyOrigin::FnReturn(_))
+            || opaque.in_trait
+            || in_external_macro(cx.sess(), item.span)
+        {
+            return;
+        }
+
+        let hidden_ty = cx.tcx.type_of(item.owner_id).subst_identity();
+        if hidden_ty.references_error() {
+            return;
+        }
+        // The hidden type refers to the lifetimes captured by the opaque type through its own
+        // lifetime parameters, which are also what the bounds resolve to.
+        let borrowed: FxHashSet<_> = hidden_ty
+            .walk()
+            .filter_map(|arg| match arg.unpack() {
+                ty::GenericArgKind::Lifetime(region) => match *region {
+                    ty::ReEarlyBound(region) => Some(region.def_id),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+
+        let mut in_trait_bounds = LifetimeCollector::default();
+        for bound in opaque.bounds {
+            if !matches!(bound, GenericBound::Outlives(_)) {
+                in_trait_bounds.visit_param_bound(bound);
+            }
+        }
+
+        for (i, bound) in opaque.bounds.iter().enumerate() {
+            if let GenericBound::Outlives(lifetime) = bound
+                && let LifetimeName::Param(def_id) = lifetime.res
+                && !borrowed.contains(&def_id.to_def_id())
+                && !in_trait_bounds.0.contains(&def_id)
+            {
+                // Remove the bound along with the `+` joining it to its neighbour
+                let removal = if i == 0 {
+                    opaque.bounds.get(1).map(|next| bound.span().until(next.span()))
+                } else {
+                    Some(opaque.bounds[i - 1].span().shrink_to_hi().to(bound.span()))
+                };
+
+                span_lint_and_then(
+                    cx,
+                    IMPL_TRAIT_CAPTURE_EVERYTHING,
+                    lifetime.ident.span,
+                    &format!(
+                        "this `impl Trait` captures lifetime `{}`, but the returned value doesn't borrow from it",
+                        lifetime.ident
+                    ),
+                    |diag| {
+                        if let Some(removal) = removal {
+                            diag.span_suggestion(
+                                removal,
+                                "remove the lifetime bound",
+                                "",
+                                Applicability::MaybeIncorrect,
+                            );
+                        }
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Collects the lifetime parameters referred to within the visited bounds.
+#[derive(Default)]
+struct LifetimeCollector(FxHashSet<LocalDefId>);
+
+impl<'tcx> Visitor<'tcx> for LifetimeCollector {
+    fn visit_lifetime(&mut self, lifetime: &'tcx Lifetime) {
+        if let LifetimeName::Param(def_id) = lifetime.res {
+            self.0.insert(def_id);
+        }
+    }
+}
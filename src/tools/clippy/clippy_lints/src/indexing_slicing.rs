@@ -2,12 +2,14 @@
 
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
-use clippy_utils::higher;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{eq_expr_value, higher, is_in_test};
 use rustc_ast::ast::RangeLimits;
-use rustc_hir::{Expr, ExprKind};
+use rustc_hir::{BinOpKind, Expr, ExprKind, LangItem};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -44,10 +46,16 @@
     /// Checks for usage of indexing or slicing. Arrays are special cases, this lint
     /// does report on arrays if we can tell that slicing operations are in bounds and does not
     /// lint on constant `usize` indexing on arrays because that is handled by rustc's `const_err` lint.
+    /// Arrays indexed with an index which is always in bounds, e.g. `x[i % x.len()]`, or `x[i & 3]`
+    /// for an array of four elements, aren't linted either.
+    ///
+    /// Which kinds of indexing and slicing are checked can be configured with
+    /// `indexing-slicing-checks`, e.g. to only check the slicing of strings.
     ///
     /// ### Why is this bad?
     /// Indexing and slicing can panic at runtime and there are
-    /// safe alternatives.
+    /// safe alternatives. Slicing a string also panics if a bound isn't on a
+    /// char boundary.
     ///
     /// ### Example
     /// ```rust,no_run
@@ -73,6 +81,9 @@
     /// x.get(2);
     /// x.get(2..100);
     ///
+    /// // Slice patterns don't panic either
+    /// if let [_, _, third, ..] = x.as_slice() {}
+    ///
     /// y.get(10);
     /// y.get(10..100);
     /// ```
@@ -84,17 +95,31 @@
 
 impl_lint_pass!(IndexingSlicing => [INDEXING_SLICING, OUT_OF_BOUNDS_INDEXING]);
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct IndexingSlicing {
     suppress_restriction_lint_in_const: bool,
+    checks: Vec<String>,
+    allow_in_tests: bool,
 }
 
 impl IndexingSlicing {
-    pub fn new(suppress_restriction_lint_in_const: bool) -> Self {
+    pub fn new(suppress_restriction_lint_in_const: bool, checks: Vec<String>, allow_in_tests: bool) -> Self {
         Self {
             suppress_restriction_lint_in_const,
+            checks,
+            allow_in_tests,
         }
     }
+
+    fn enabled(&self, check: &str) -> bool {
+        self.checks.iter().any(|c| c == check)
+    }
+
+    /// Checks if `INDEXING_SLICING` is allowed in test code by the configuration, and `expr` is in
+    /// test code.
+    fn is_allowed_in_tests(&self, cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+        self.allow_in_tests && is_in_test(cx.tcx, expr.hir_id)
+    }
 }
 
 impl<'tcx> LateLintPass<'tcx> for IndexingSlicing {
@@ -155,16 +180,28 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     (None, None) => return, // [..] is ok.
                 };
 
+                let is_str = ty.is_str() || is_type_lang_item(cx, ty, LangItem::String);
+                if !self.enabled(if is_str { "str-slice" } else { "slice" }) || self.is_allowed_in_tests(cx, expr) {
+                    return;
+                }
+
                 span_lint_and_then(cx, INDEXING_SLICING, expr.span, "slicing may panic", |diag| {
                     diag.help(help_msg);
 
+                    if is_str {
+                        diag.note("slicing a string also panics if a bound isn't on a char boundary");
+                    }
+
                     if cx.tcx.hir().is_inside_const_context(expr.hir_id) {
                         diag.note(note);
                     }
                 });
             } else {
                 // Catchall non-range index, i.e., [n] or [n << m]
-                if let ty::Array(..) = ty.kind() {
+                if !self.enabled("index") || self.is_allowed_in_tests(cx, expr) {
+                    return;
+                }
+                if let ty::Array(_, s) = ty.kind() {
                     // Index is a const block.
                     if let ExprKind::ConstBlock(..) = index.kind {
                         return;
@@ -174,6 +211,11 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         // Let rustc's `const_err` lint handle constant `usize` indexing on arrays.
                         return;
                     }
+                    if let Some(size) = s.try_eval_target_usize(cx.tcx, cx.param_env)
+                        && is_index_in_bounds(cx, array, index, size.into())
+                    {
+                        return;
+                    }
                 }
 
                 span_lint_and_then(cx, INDEXING_SLICING, expr.span, "indexing may panic", |diag| {
@@ -188,6 +230,33 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
     }
 }
 
+/// Checks if `index` is always in the bounds of the array of length `size`, i.e. it's the remainder
+/// of a division by at most `size`, or masked with less than `size`.
+fn is_index_in_bounds(cx: &LateContext<'_>, array: &Expr<'_>, index: &Expr<'_>, size: u128) -> bool {
+    let const_int = |e: &Expr<'_>| match constant(cx, cx.typeck_results(), e) {
+        Some((Constant::Int(x), _)) => Some(x),
+        _ => None,
+    };
+    match index.kind {
+        ExprKind::Binary(op, _, divisor) if op.node == BinOpKind::Rem && size > 0 => {
+            const_int(divisor).map_or_else(|| is_len_of(cx, divisor, array), |divisor| divisor <= size)
+        },
+        ExprKind::Binary(op, lhs, rhs) if op.node == BinOpKind::BitAnd => const_int(lhs)
+            .or_else(|| const_int(rhs))
+            .map_or(false, |mask| mask < size),
+        _ => false,
+    }
+}
+
+/// Checks if `expr` is `array.len()`.
+fn is_len_of(cx: &LateContext<'_>, expr: &Expr<'_>, array: &Expr<'_>) -> bool {
+    if let ExprKind::MethodCall(path, recv, [], _) = expr.kind {
+        path.ident.name == sym::len && eq_expr_value(cx, recv, array)
+    } else {
+        false
+    }
+}
+
 /// Returns a tuple of options with the start and end (exclusive) values of
 /// the range. If the start or end is not constant, None is returned.
 fn to_const_range(cx: &LateContext<'_>, range: higher::Range<'_>, array_size: u128) -> (Option<u128>, Option<u128>) {
@@ -1,5 +1,5 @@
 use clippy_utils::diagnostics::span_lint;
-use clippy_utils::{match_function_call, paths};
+use clippy_utils::paths::PathLookup;
 use rustc_ast::{BorrowKind, LitKind};
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
@@ -28,9 +28,11 @@
 }
 declare_lint_pass!(InvalidUtf8InUnchecked => [INVALID_UTF8_IN_UNCHECKED]);
 
+static STR_FROM_UTF8_UNCHECKED: PathLookup = PathLookup::new(&["core", "str", "converts", "from_utf8_unchecked"]);
+
 impl<'tcx> LateLintPass<'tcx> for InvalidUtf8InUnchecked {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
-        if let Some([arg]) = match_function_call(cx, expr, &paths::STR_FROM_UTF8_UNCHECKED) {
+        if let Some([arg]) = STR_FROM_UTF8_UNCHECKED.call_args(cx, expr) {
             match &arg.kind {
                 ExprKind::Lit(Spanned { node: lit, .. }) => {
                     if let LitKind::ByteStr(bytes, _) = &lit
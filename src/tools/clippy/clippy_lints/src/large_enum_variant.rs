@@ -6,12 +6,13 @@
     ty::{approx_ty_size, is_copy, AdtVariantInfo},
 };
 use rustc_errors::Applicability;
-use rustc_hir::{Item, ItemKind};
+use rustc_hir::{EnumDef, Item, ItemKind, VariantData};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::lint::in_external_macro;
 use rustc_middle::ty::{Adt, Ty};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
+use std::fmt::Write;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -121,6 +122,10 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &Item<'tcx>) {
                             },
                         );
 
+                        if variants_size.len() > 2 {
+                            diag.note(variant_size_table(def, &variants_size));
+                        }
+
                         let fields = def.variants[variants_size[0].ind].data.fields();
                         let mut applicability = Applicability::MaybeIncorrect;
                         if is_copy(cx, ty) || maybe_copy(cx, ty) {
@@ -155,6 +160,33 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &Item<'tcx>) {
                                 })
                                 .collect();
 
+                            // A single field makes up most of the variant, suggest boxing either
+                            // just that field, or all fields of a tuple variant together
+                            if let [(span, boxed)] = &*sugg
+                                && fields.len() > 1
+                            {
+                                diag.span_suggestion(
+                                    *span,
+                                    "consider boxing only the large field to reduce the total size of the enum",
+                                    boxed.clone(),
+                                    Applicability::MaybeIncorrect,
+                                );
+                                if let VariantData::Tuple(..) = def.variants[variants_size[0].ind].data {
+                                    let tys = fields
+                                        .iter()
+                                        .map(|field| {
+                                            snippet_with_applicability(cx, field.ty.span, "..", &mut applicability)
+                                        })
+                                        .collect::<Vec<_>>();
+                                    diag.span_suggestion(
+                                        fields[0].ty.span.to(fields[fields.len() - 1].ty.span),
+                                        "or box all fields of the variant together",
+                                        format!("Box<({})>", tys.join(", ")),
+                                        Applicability::MaybeIncorrect,
+                                    );
+                                }
+                                return;
+                            }
                             if !sugg.is_empty() {
                                 diag.multipart_suggestion(help_text, sugg, Applicability::MaybeIncorrect);
                                 return;
@@ -168,6 +200,22 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &Item<'tcx>) {
     }
 }
 
+/// Lists the approximate size of each variant of the enum, in declaration order.
+fn variant_size_table(def: &EnumDef<'_>, variants_size: &[AdtVariantInfo]) -> String {
+    let mut table = String::from("the variants have the following sizes:");
+    for (i, variant) in def.variants.iter().enumerate() {
+        let Some(info) = variants_size.iter().find(|info| info.ind == i) else {
+            continue;
+        };
+        if info.fields_size.is_empty() {
+            write!(table, "\n`{}`: no data", variant.ident).unwrap();
+        } else {
+            write!(table, "\n`{}`: at least {} bytes", variant.ident, info.size).unwrap();
+        }
+    }
+    table
+}
+
 fn maybe_copy<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
     if let Adt(_def, substs) = ty.kind()
         && substs.types().next().is_some()
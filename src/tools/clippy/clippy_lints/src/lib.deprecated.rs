@@ -67,4 +67,8 @@
         "clippy::wrong_pub_self_convention",
         "set the `avoid-breaking-exported-api` config option to `false` to enable the `wrong_self_convention` lint for public items",
     );
+    store.register_removed(
+        "clippy::separated_literal_suffix",
+        "set the `suffix-style` config option to `\"unseparated\"` to make the `literal_suffix_style` lint check for separated literal suffixes",
+    );
 }
@@ -20,6 +20,7 @@
 #![warn(rustc::internal)]
 // Disable this rustc lint for now, as it was also done in rustc
 #![allow(rustc::potential_query_instability)]
+// FIXME: many existing lints still use `snippet` in `MachineApplicable` suggestions
 
 // FIXME: switch to something more ergonomic here, once available.
 // (Currently there is no way to opt into sysroot crates without `extern crate`.)
@@ -73,7 +74,9 @@
 mod asm_syntax;
 mod assertions_on_constants;
 mod assertions_on_result_states;
+mod async_fn_in_trait_without_send_bound;
 mod async_yields_async;
+mod atomic_ordering_pairing;
 mod attrs;
 mod await_holding_invalid;
 mod blocks_in_if_conditions;
@@ -82,9 +85,13 @@
 mod booleans;
 mod borrow_deref_ref;
 mod box_default;
+mod cancellation_unsafe_await;
 mod cargo;
 mod casts;
+mod chained_contains_then_get;
+mod char_indices_offset_misuse;
 mod checked_conversions;
+mod clone_into_uninitialized_reuse;
 mod cognitive_complexity;
 mod collapsible_if;
 mod collection_is_never_read;
@@ -93,7 +100,9 @@
 mod copy_iterator;
 mod crate_in_macro_def;
 mod create_dir;
+mod cyclic_module_imports;
 mod dbg_macro;
+mod dbg_or_todo_in_release_path;
 mod default;
 mod default_instead_of_iter_empty;
 mod default_numeric_fallback;
@@ -117,15 +126,21 @@
 mod entry;
 mod enum_clike;
 mod enum_variants;
+mod env_args;
 mod equatable_if_let;
 mod escape;
 mod eta_reduction;
 mod excessive_bools;
+mod excessive_nesting;
 mod exhaustive_items;
 mod exit;
+mod exit_code_from_bool_cast;
+mod expensive_constructor_in_loop;
 mod explicit_write;
 mod extra_unused_type_parameters;
 mod fallible_impl_from;
+mod feature_gated_pub_api;
+mod filesystem;
 mod float_literal;
 mod floating_point_arithmetic;
 mod fn_null_check;
@@ -142,6 +157,7 @@
 mod if_let_mutex;
 mod if_not_else;
 mod if_then_some_else_none;
+mod impl_trait_capture_everything;
 mod implicit_hasher;
 mod implicit_return;
 mod implicit_saturating_add;
@@ -170,18 +186,22 @@
 mod let_with_type_underscore;
 mod lifetimes;
 mod literal_representation;
+mod local_time_day_arithmetic;
 mod loops;
 mod macro_use;
 mod main_recursion;
+mod main_without_result;
 mod manual_assert;
 mod manual_async_fn;
 mod manual_bits;
 mod manual_clamp;
+mod manual_hash_one;
 mod manual_is_ascii_check;
 mod manual_let_else;
 mod manual_non_exhaustive;
 mod manual_rem_euclid;
 mod manual_retain;
+mod manual_retain_with_index_loop;
 mod manual_string_new;
 mod manual_strip;
 mod map_unit_fn;
@@ -196,6 +216,7 @@
 mod mismatching_type_param_order;
 mod missing_assert_message;
 mod missing_const_for_fn;
+mod missing_debug_for_public_error_types;
 mod missing_doc;
 mod missing_enforced_import_rename;
 mod missing_inline;
@@ -225,8 +246,10 @@
 mod no_effect;
 mod no_mangle_with_rust_abi;
 mod non_copy_const;
+mod non_exhaustive_struct_update_in_same_crate;
 mod non_expressive_names;
 mod non_octal_unix_permissions;
+mod non_portable_path_literal;
 mod non_send_fields_in_send_ty;
 mod nonstandard_macro_braces;
 mod octal_escapes;
@@ -234,6 +257,7 @@
 mod operators;
 mod option_env_unwrap;
 mod option_if_let_else;
+mod osstring_lossy_comparison;
 mod overflow_check_conditional;
 mod panic_in_result_fn;
 mod panic_unimplemented;
@@ -244,14 +268,17 @@
 mod pattern_type_mismatch;
 mod permissions_set_readonly_false;
 mod precedence;
+mod proc_macro_span_hygiene;
 mod ptr;
 mod ptr_offset_with_cast;
 mod pub_use;
+mod public_api;
 mod question_mark;
 mod question_mark_used;
 mod ranges;
 mod rc_clone_in_vec_init;
 mod read_zero_byte_vec;
+mod recursive_types;
 mod redundant_async_block;
 mod redundant_clone;
 mod redundant_closure_call;
@@ -263,6 +290,7 @@
 mod ref_option_ref;
 mod reference;
 mod regex;
+mod release_only_behavior;
 mod return_self_not_must_use;
 mod returns;
 mod same_name_method;
@@ -276,7 +304,9 @@
 mod single_component_path_imports;
 mod size_of_in_element_count;
 mod size_of_ref;
+mod sleep_in_loop_without_backoff;
 mod slow_vector_initialization;
+mod static_lazy_initialization_modernization;
 mod std_instead_of_core;
 mod strings;
 mod strlen_on_c_strings;
@@ -287,9 +317,14 @@
 mod swap_ptr_to_ref;
 mod tabs_in_doc_comments;
 mod temporary_assignment;
+mod test_fn_unwrap;
+mod test_module_not_cfg_test;
 mod to_digit_is_some;
+mod too_many_generic_params;
+mod tracing_or_log_in_hot_loop_with_format;
 mod trailing_empty_array;
 mod trait_bounds;
+mod trait_consistency;
 mod transmute;
 mod types;
 mod undocumented_unsafe_blocks;
@@ -316,6 +351,7 @@
 mod useless_conversion;
 mod vec;
 mod vec_init_then_push;
+mod vec_of_boxed_slices;
 mod wildcard_imports;
 mod write;
 mod zero_div_zero;
@@ -324,6 +360,7 @@
 
 use crate::utils::conf::{format_error, TryConf};
 pub use crate::utils::conf::{lookup_conf_file, Conf};
+pub use clippy_utils::lint_scope::allow_out_of_scope_lints;
 
 /// Register all pre expansion lints
 ///
@@ -338,7 +375,7 @@ pub fn register_pre_expansion_lints(store: &mut rustc_lint::LintStore, sess: &Se
     let msrv = Msrv::read(&conf.msrv, sess);
     let msrv = move || msrv.clone();
 
-    store.register_pre_expansion_pass(move || Box::new(attrs::EarlyAttributes { msrv: msrv() }));
+    store.register_pre_expansion_pass(move || Box::new(attrs::EarlyAttributes::new(msrv())));
 }
 
 #[doc(hidden)]
@@ -385,6 +422,7 @@ struct RegistrationGroups {
     pedantic: Vec<LintId>,
     perf: Vec<LintId>,
     restriction: Vec<LintId>,
+    semver: Vec<LintId>,
     style: Vec<LintId>,
     suspicious: Vec<LintId>,
     #[cfg(feature = "internal")]
@@ -402,6 +440,7 @@ fn register(self, store: &mut rustc_lint::LintStore) {
         store.register_group(true, "clippy::pedantic", Some("clippy_pedantic"), self.pedantic);
         store.register_group(true, "clippy::perf", Some("clippy_perf"), self.perf);
         store.register_group(true, "clippy::restriction", Some("clippy_restriction"), self.restriction);
+        store.register_group(true, "clippy::semver", Some("clippy_semver"), self.semver);
         store.register_group(true, "clippy::style", Some("clippy_style"), self.style);
         store.register_group(true, "clippy::suspicious", Some("clippy_suspicious"), self.suspicious);
         #[cfg(feature = "internal")]
@@ -418,6 +457,7 @@ pub(crate) enum LintCategory {
     Pedantic,
     Perf,
     Restriction,
+    Semver,
     Style,
     Suspicious,
     #[cfg(feature = "internal")]
@@ -440,6 +480,7 @@ fn group(self, groups: &mut RegistrationGroups) -> &mut Vec<LintId> {
             Pedantic => &mut groups.pedantic,
             Perf => &mut groups.perf,
             Restriction => &mut groups.restriction,
+            Semver => &mut groups.semver,
             Style => &mut groups.style,
             Suspicious => &mut groups.suspicious,
             #[cfg(feature = "internal")]
@@ -490,6 +531,20 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
 
     include!("lib.deprecated.rs");
 
+    let mut scopes = clippy_utils::lint_scope::LintScopes::default();
+    for scope in &conf.scope {
+        scopes.allow_in(store, scope.paths.clone(), &scope.allow);
+    }
+    for (lint, paths) in &conf.deny_only_in {
+        scopes.only_in(store, lint, paths);
+    }
+    clippy_utils::lint_scope::set_lint_scopes(scopes);
+    clippy_utils::generated_code::set_generated_code_config(clippy_utils::generated_code::GeneratedCodeConfig {
+        marker: conf.generated_code_marker.clone(),
+        marker_lines: conf.generated_code_marker_lines.try_into().unwrap_or(usize::MAX),
+        lint_groups: conf.generated_code_lint_groups.clone(),
+    });
+
     #[cfg(feature = "internal")]
     {
         if std::env::var("ENABLE_METADATA_COLLECTION").eq(&Ok("1".to_string())) {
@@ -518,11 +573,16 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
         store.register_late_pass(|_| Box::<utils::internal_lints::unnecessary_def_path::UnnecessaryDefPath>::default());
         store.register_late_pass(|_| Box::new(utils::internal_lints::outer_expn_data_pass::OuterExpnDataPass));
         store.register_late_pass(|_| Box::new(utils::internal_lints::msrv_attr_impl::MsrvAttrImpl));
+        store.register_late_pass(|_| Box::new(utils::internal_lints::suggestion_snippets::SuggestionSnippets));
+        store.register_late_pass(|_| {
+            Box::<utils::internal_lints::proc_macro_span_hygiene::ProcMacroSpanHygiene>::default()
+        });
     }
 
     let arithmetic_side_effects_allowed = conf.arithmetic_side_effects_allowed.clone();
     let arithmetic_side_effects_allowed_binary = conf.arithmetic_side_effects_allowed_binary.clone();
     let arithmetic_side_effects_allowed_unary = conf.arithmetic_side_effects_allowed_unary.clone();
+    let arithmetic_side_effects_allowed_paths = conf.arithmetic_side_effects_allowed_paths.clone();
     store.register_late_pass(move |_| {
         Box::new(operators::arithmetic_side_effects::ArithmeticSideEffects::new(
             arithmetic_side_effects_allowed
@@ -535,6 +595,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
                 .chain(arithmetic_side_effects_allowed_unary.iter())
                 .cloned()
                 .collect(),
+            arithmetic_side_effects_allowed_paths.clone(),
         ))
     });
     store.register_early_pass(|| Box::new(utils::format_args_collector::FormatArgsCollector));
@@ -546,7 +607,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
             await_holding_invalid_types.clone(),
         ))
     });
-    store.register_late_pass(|_| Box::new(serde_api::SerdeApi));
+    let serde_attribute_checks = conf.serde_attribute_checks.clone();
+    store.register_late_pass(move |_| Box::new(serde_api::SerdeApi::new(serde_attribute_checks.clone())));
     let vec_box_size_threshold = conf.vec_box_size_threshold;
     let type_complexity_threshold = conf.type_complexity_threshold;
     let avoid_breaking_exported_api = conf.avoid_breaking_exported_api;
@@ -586,8 +648,8 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     let msrv = Msrv::read(&conf.msrv, sess);
     let msrv = move || msrv.clone();
     let avoid_breaking_exported_api = conf.avoid_breaking_exported_api;
-    let allow_expect_in_tests = conf.allow_expect_in_tests;
-    let allow_unwrap_in_tests = conf.allow_unwrap_in_tests;
+    let allow_expect_in_tests = conf.is_allowed_in_tests(methods::EXPECT_USED);
+    let allow_unwrap_in_tests = conf.is_allowed_in_tests(methods::UNWRAP_USED);
     let suppress_restriction_lint_in_const = conf.suppress_restriction_lint_in_const;
     store.register_late_pass(move |_| Box::new(approx_const::ApproxConstant::new(msrv())));
     store.register_late_pass(move |_| {
@@ -632,7 +694,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|_| Box::new(entry::HashMapPass));
     store.register_late_pass(|_| Box::new(minmax::MinMaxPass));
     store.register_late_pass(|_| Box::new(zero_div_zero::ZeroDiv));
-    store.register_late_pass(|_| Box::new(mutex_atomic::Mutex));
+    store.register_late_pass(|_| Box::<mutex_atomic::Mutex>::default());
     store.register_late_pass(|_| Box::new(needless_update::NeedlessUpdate));
     store.register_late_pass(|_| Box::new(needless_borrowed_ref::NeedlessBorrowedRef));
     store.register_late_pass(|_| Box::new(borrow_deref_ref::BorrowDerefRef));
@@ -640,15 +702,29 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|_| Box::new(temporary_assignment::TemporaryAssignment));
     store.register_late_pass(move |_| Box::new(transmute::Transmute::new(msrv())));
     let cognitive_complexity_threshold = conf.cognitive_complexity_threshold;
+    let cognitive_complexity_weights = conf.cognitive_complexity_weights;
     store.register_late_pass(move |_| {
         Box::new(cognitive_complexity::CognitiveComplexity::new(
             cognitive_complexity_threshold,
+            cognitive_complexity_weights,
         ))
     });
     let too_large_for_stack = conf.too_large_for_stack;
     store.register_late_pass(move |_| Box::new(escape::BoxedLocal { too_large_for_stack }));
     store.register_late_pass(move |_| Box::new(vec::UselessVec { too_large_for_stack }));
-    store.register_late_pass(|_| Box::new(panic_unimplemented::PanicUnimplemented));
+    let panic_lints_allowed_in_tests: Vec<_> = [
+        panic_unimplemented::PANIC,
+        panic_unimplemented::TODO,
+        panic_unimplemented::UNIMPLEMENTED,
+        panic_unimplemented::UNREACHABLE,
+    ]
+    .into_iter()
+    .filter(|&lint| conf.is_allowed_in_tests(lint))
+    .map(LintId::of)
+    .collect();
+    store.register_late_pass(move |_| {
+        Box::new(panic_unimplemented::PanicUnimplemented::new(panic_lints_allowed_in_tests.clone()))
+    });
     store.register_late_pass(|_| Box::new(strings::StringLitAsBytes));
     store.register_late_pass(|_| Box::new(derive::Derive));
     store.register_late_pass(move |_| Box::new(derivable_impls::DerivableImpls::new(msrv())));
@@ -703,18 +779,22 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|_| Box::new(inline_fn_without_body::InlineFnWithoutBody));
     store.register_late_pass(|_| Box::<useless_conversion::UselessConversion>::default());
     store.register_late_pass(|_| Box::new(implicit_hasher::ImplicitHasher));
-    store.register_late_pass(|_| Box::new(fallible_impl_from::FallibleImplFrom));
+    store.register_late_pass(|_| Box::<fallible_impl_from::FallibleImplFrom>::default());
     store.register_late_pass(|_| Box::new(question_mark::QuestionMark));
     store.register_late_pass(|_| Box::new(question_mark_used::QuestionMarkUsed));
     store.register_early_pass(|| Box::new(suspicious_operation_groupings::SuspiciousOperationGroupings));
     store.register_late_pass(|_| Box::new(suspicious_trait_impl::SuspiciousImpl));
     store.register_late_pass(|_| Box::new(map_unit_fn::MapUnit));
     store.register_late_pass(|_| Box::new(inherent_impl::MultipleInherentImpl));
-    store.register_late_pass(|_| Box::new(neg_cmp_op_on_partial_ord::NoNegCompOpForPartialOrd));
+    store.register_late_pass(|_| Box::<neg_cmp_op_on_partial_ord::NoNegCompOpForPartialOrd<'_>>::default());
     store.register_late_pass(|_| Box::new(unwrap::Unwrap));
+    let indexing_slicing_checks = conf.indexing_slicing_checks.clone();
+    let allow_indexing_slicing_in_tests = conf.is_allowed_in_tests(indexing_slicing::INDEXING_SLICING);
     store.register_late_pass(move |_| {
         Box::new(indexing_slicing::IndexingSlicing::new(
             suppress_restriction_lint_in_const,
+            indexing_slicing_checks.clone(),
+            allow_indexing_slicing_in_tests,
         ))
     });
     store.register_late_pass(|_| Box::new(non_copy_const::NonCopyConst));
@@ -737,7 +817,11 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_early_pass(|| Box::new(else_if_without_else::ElseIfWithoutElse));
     store.register_early_pass(|| Box::new(int_plus_one::IntPlusOne));
     store.register_early_pass(|| Box::new(formatting::Formatting));
-    store.register_early_pass(|| Box::new(misc_early::MiscEarlyLints));
+    let suffix_style = conf.suffix_style;
+    let digit_grouping_min_digits = conf.digit_grouping_min_digits;
+    store.register_early_pass(move || {
+        Box::new(misc_early::MiscEarlyLints::new(suffix_style, digit_grouping_min_digits))
+    });
     store.register_early_pass(|| Box::new(redundant_closure_call::RedundantClosureCall));
     store.register_late_pass(|_| Box::new(redundant_closure_call::RedundantClosureCall));
     store.register_early_pass(|| Box::new(unused_unit::UnusedUnit));
@@ -833,7 +917,7 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|_| Box::new(strings::StrToString));
     store.register_late_pass(|_| Box::new(strings::StringToString));
     store.register_late_pass(|_| Box::new(zero_sized_map_values::ZeroSizedMapValues));
-    store.register_late_pass(|_| Box::<vec_init_then_push::VecInitThenPush>::default());
+    store.register_late_pass(|_| Box::new(vec_init_then_push::VecInitThenPush));
     store.register_late_pass(|_| Box::new(redundant_slicing::RedundantSlicing));
     store.register_late_pass(|_| Box::new(from_str_radix_10::FromStrRadix10));
     store.register_late_pass(move |_| Box::new(if_then_some_else_none::IfThenSomeElseNone::new(msrv())));
@@ -872,10 +956,13 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(move |_| Box::new(manual_bits::ManualBits::new(msrv())));
     store.register_late_pass(|_| Box::new(default_union_representation::DefaultUnionRepresentation));
     store.register_late_pass(|_| Box::<only_used_in_recursion::OnlyUsedInRecursion>::default());
-    let allow_dbg_in_tests = conf.allow_dbg_in_tests;
+    let allow_dbg_in_tests = conf.is_allowed_in_tests(dbg_macro::DBG_MACRO);
     store.register_late_pass(move |_| Box::new(dbg_macro::DbgMacro::new(allow_dbg_in_tests)));
-    let allow_print_in_tests = conf.allow_print_in_tests;
-    store.register_late_pass(move |_| Box::new(write::Write::new(allow_print_in_tests)));
+    let allow_print_stdout_in_tests = conf.is_allowed_in_tests(write::PRINT_STDOUT);
+    let allow_print_stderr_in_tests = conf.is_allowed_in_tests(write::PRINT_STDERR);
+    store.register_late_pass(move |_| {
+        Box::new(write::Write::new(allow_print_stdout_in_tests, allow_print_stderr_in_tests))
+    });
     let cargo_ignore_publish = conf.cargo_ignore_publish;
     store.register_late_pass(move |_| {
         Box::new(cargo::Cargo {
@@ -933,6 +1020,131 @@ pub fn register_plugins(store: &mut rustc_lint::LintStore, sess: &Session, conf:
     store.register_late_pass(|_| Box::new(missing_assert_message::MissingAssertMessage));
     store.register_early_pass(|| Box::new(redundant_async_block::RedundantAsyncBlock));
     store.register_late_pass(|_| Box::new(let_with_type_underscore::UnderscoreTyped));
+    let module_import_cycle_threshold = conf.module_import_cycle_threshold;
+    store.register_late_pass(move |_| {
+        Box::new(cyclic_module_imports::CyclicModuleImports::new(
+            module_import_cycle_threshold,
+        ))
+    });
+    let excessive_nesting_threshold = conf.excessive_nesting_threshold;
+    store.register_early_pass(move || Box::new(excessive_nesting::ExcessiveNesting::new(excessive_nesting_threshold)));
+    let too_many_generic_params_threshold = conf.too_many_generic_params_threshold;
+    store.register_late_pass(move |_| {
+        Box::new(too_many_generic_params::TooManyGenericParams::new(
+            too_many_generic_params_threshold,
+        ))
+    });
+    store.register_late_pass(|_| Box::new(impl_trait_capture_everything::ImplTraitCaptureEverything));
+    store.register_late_pass(|_| Box::new(chained_contains_then_get::ChainedContainsThenGet));
+    store.register_late_pass(|_| Box::new(vec_of_boxed_slices::VecOfBoxedSlices));
+    store.register_late_pass(|_| Box::new(char_indices_offset_misuse::CharIndicesOffsetMisuse));
+    let expensive_constructors = conf.expensive_constructors.clone();
+    store.register_late_pass(move |_| {
+        Box::new(expensive_constructor_in_loop::ExpensiveConstructorInLoop::new(
+            expensive_constructors.clone(),
+        ))
+    });
+    store.register_late_pass(|_| Box::new(async_fn_in_trait_without_send_bound::AsyncFnInTraitWithoutSendBound));
+    let cancellation_unsafe_methods = conf.cancellation_unsafe_methods.clone();
+    let select_macros = conf.select_macros.clone();
+    store.register_late_pass(move |_| {
+        Box::new(cancellation_unsafe_await::CancellationUnsafeAwait::new(
+            cancellation_unsafe_methods.clone(),
+            select_macros.clone(),
+        ))
+    });
+    let sleep_functions = conf.sleep_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(sleep_in_loop_without_backoff::SleepInLoopWithoutBackoff::new(
+            sleep_functions.clone(),
+        ))
+    });
+    store.register_late_pass(|_| Box::<atomic_ordering_pairing::AtomicOrderingPairing>::default());
+    store.register_late_pass(|_| Box::new(trait_consistency::TraitConsistency));
+    store.register_late_pass(|_| {
+        Box::new(non_exhaustive_struct_update_in_same_crate::NonExhaustiveStructUpdateInSameCrate)
+    });
+    store.register_late_pass(|_| {
+        Box::<missing_debug_for_public_error_types::MissingDebugForPublicErrorTypes>::default()
+    });
+    if let Some(baseline) = conf
+        .public_api_baseline
+        .as_deref()
+        .and_then(|path| public_api::read_baseline(sess, path))
+    {
+        store.register_late_pass(move |_| Box::new(public_api::PublicApiDiff::new(baseline.clone())));
+    }
+    if let Some(dir) = std::env::var_os("CLIPPY_EMIT_PUBLIC_API") {
+        store.register_late_pass(move |_| Box::new(public_api::EmitPublicApi::new(PathBuf::from(&dir))));
+    }
+    let lazy_initialization_paths = conf.lazy_initialization_paths.clone();
+    store.register_late_pass(move |_| {
+        Box::new(
+            static_lazy_initialization_modernization::StaticLazyInitializationModernization::new(
+                msrv(),
+                lazy_initialization_paths.clone(),
+            ),
+        )
+    });
+    store.register_late_pass(move |_| Box::new(manual_hash_one::ManualHashOne::new(msrv())));
+    store.register_late_pass(move |_| Box::new(filesystem::Filesystem::new(msrv())));
+    let portable_path_targets = conf.portable_path_targets.clone();
+    store.register_late_pass(move |_| {
+        Box::new(non_portable_path_literal::NonPortablePathLiteral::new(
+            portable_path_targets.clone(),
+        ))
+    });
+    let local_time_constructors = conf.local_time_constructors.clone();
+    let day_arithmetic_functions = conf.day_arithmetic_functions.clone();
+    store.register_late_pass(move |_| {
+        Box::new(local_time_day_arithmetic::LocalTimeDayArithmetic::new(
+            local_time_constructors.clone(),
+            day_arithmetic_functions.clone(),
+        ))
+    });
+    store.register_late_pass(|_| Box::new(recursive_types::RecursiveTypes));
+    store.register_late_pass(|_| Box::new(manual_retain_with_index_loop::ManualRetainWithIndexLoop));
+    store.register_late_pass(|_| Box::new(clone_into_uninitialized_reuse::CloneIntoUninitializedReuse));
+    store.register_late_pass(|_| Box::new(osstring_lossy_comparison::OsStringLossyComparison));
+    let allow_args_positional_skip_in_examples = conf.allow_args_positional_skip_in_examples;
+    store.register_late_pass(move |_| Box::new(env_args::EnvArgs::new(allow_args_positional_skip_in_examples)));
+    store.register_late_pass(move |_| Box::new(exit_code_from_bool_cast::ExitCodeFromBoolCast::new(msrv())));
+    let main_result_type = conf.main_result_type.clone();
+    store.register_late_pass(move |_| Box::new(main_without_result::MainWithoutResult::new(main_result_type.clone())));
+    let allow_unreachable_with_message = conf.allow_unreachable_with_message;
+    let debug_only_macros = conf.debug_only_macros.clone();
+    store.register_late_pass(move |_| {
+        Box::new(dbg_or_todo_in_release_path::DbgOrTodoInReleasePath::new(
+            allow_unreachable_with_message,
+            debug_only_macros.clone(),
+        ))
+    });
+    let logging_macros = conf.logging_macros.clone();
+    store.register_late_pass(move |_| {
+        Box::new(tracing_or_log_in_hot_loop_with_format::TracingOrLogInHotLoopWithFormat::new(logging_macros.clone()))
+    });
+    let logging_macros = conf.logging_macros.clone();
+    store
+        .register_late_pass(move |_| Box::new(release_only_behavior::ReleaseOnlyBehavior::new(logging_macros.clone())));
+    store.register_late_pass(|_| Box::new(feature_gated_pub_api::FeatureGatedPubApi));
+    let test_module_names = conf.test_module_names.clone();
+    store.register_late_pass(move |_| {
+        Box::new(test_module_not_cfg_test::TestModuleNotCfgTest::new(
+            test_module_names.clone(),
+        ))
+    });
+    let test_unwrap_threshold = conf.test_unwrap_threshold;
+    store.register_late_pass(move |_| Box::new(test_fn_unwrap::TestFnUnwrap::new(test_unwrap_threshold)));
+    let proc_macro_ident_constructors = conf.proc_macro_ident_constructors.clone();
+    let proc_macro_call_site_spans = conf.proc_macro_call_site_spans.clone();
+    let proc_macro_token_streams = conf.proc_macro_token_streams.clone();
+    store.register_late_pass(move |_| {
+        Box::new(proc_macro_span_hygiene::ProcMacroSpanHygiene::new(
+            proc_macro_ident_constructors.clone(),
+            proc_macro_call_site_spans.clone(),
+            proc_macro_token_streams.clone(),
+        ))
+    });
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
 
@@ -2,7 +2,7 @@
 //! floating-point literal expressions.
 
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::numeric_literal::{NumericLiteral, Radix};
+use clippy_utils::numeric_literal::{reformat_int_lit, NumericLiteral, Radix};
 use clippy_utils::source::snippet_opt;
 use if_chain::if_chain;
 use rustc_ast::ast::{Expr, ExprKind, LitKind};
@@ -303,7 +303,15 @@ fn check_lit(self, cx: &EarlyContext<'_>, lit: token::Lit, span: Span) {
                         }
                     };
                     if should_warn {
-                        warning_type.display(num_lit.format(), cx, span);
+                        // Integers are re-rendered from their value, so only the suffix is taken from the source
+                        let uppercase = num_lit.integer.bytes().any(|b| b.is_ascii_uppercase());
+                        let suggestion = match warning_type {
+                            WarningType::InconsistentDigitGrouping => {
+                                reformat_int_lit(&src, &lit_kind, num_lit.radix, uppercase)
+                            },
+                            _ => None,
+                        };
+                        warning_type.display(suggestion.unwrap_or_else(|| num_lit.format()), cx, span);
                     }
                 }
             }
@@ -0,0 +1,137 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::for_each_expr_with_closures;
+use clippy_utils::{def_path_def_ids, fn_def_id};
+use core::ops::ControlFlow;
+use rustc_hir::def_id::{DefIdMap, LocalDefId};
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, ExprKind, FnDecl};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions which get the current local or naive time and also build a duration
+    /// of whole days, e.g. `chrono::Local::now()` and `chrono::Duration::days`.
+    ///
+    /// The functions are listed in the `local-time-constructors` and `day-arithmetic-functions`
+    /// configurations, which come with the ones of `chrono` and `time`.
+    ///
+    /// ### Why is this bad?
+    /// A day in local time isn't always 24 hours long: adding a fixed duration of days across a
+    /// daylight saving time change is off by an hour.
+    ///
+    /// ### Known problems
+    /// The duration isn't checked to be added to the local time, it only has to be built in the
+    /// same function.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let tomorrow = chrono::Local::now() + chrono::Duration::days(1);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let tomorrow = chrono::Local::now().checked_add_days(chrono::Days::new(1));
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub LOCAL_TIME_DAY_ARITHMETIC,
+    pedantic,
+    "adding a fixed duration of days to the current local time"
+}
+
+pub struct LocalTimeDayArithmetic {
+    conf_constructors: Vec<String>,
+    conf_operations: Vec<String>,
+    constructors: DefIdMap<usize>,
+    operations: DefIdMap<usize>,
+}
+
+impl LocalTimeDayArithmetic {
+    pub fn new(conf_constructors: Vec<String>, conf_operations: Vec<String>) -> Self {
+        Self {
+            conf_constructors,
+            conf_operations,
+            constructors: DefIdMap::default(),
+            operations: DefIdMap::default(),
+        }
+    }
+}
+
+impl_lint_pass!(LocalTimeDayArithmetic => [LOCAL_TIME_DAY_ARITHMETIC]);
+
+impl<'tcx> LateLintPass<'tcx> for LocalTimeDayArithmetic {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        resolve_paths(cx, &self.conf_constructors, &mut self.constructors);
+        resolve_paths(cx, &self.conf_operations, &mut self.operations);
+    }
+
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        _: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        _: Span,
+        _: LocalDefId,
+    ) {
+        // Closures are checked with the function containing them
+        if matches!(kind, FnKind::Closure) || self.constructors.is_empty() || self.operations.is_empty() {
+            return;
+        }
+
+        let mut constructor = None;
+        let mut operation = None;
+        let _: Option<!> = for_each_expr_with_closures(cx, body.value, |e| {
+            if matches!(e.kind, ExprKind::Call(..) | ExprKind::MethodCall(..))
+                && !in_external_macro(cx.sess(), e.span)
+                && let Some(id) = fn_def_id(cx, e)
+            {
+                if constructor.is_none()
+                    && let Some(&index) = self.constructors.get(&id)
+                {
+                    constructor = Some((e.span, index));
+                } else if operation.is_none()
+                    && let Some(&index) = self.operations.get(&id)
+                {
+                    operation = Some((e.span, index));
+                }
+            }
+            ControlFlow::Continue(())
+        });
+
+        if let Some((constructor_span, constructor)) = constructor
+            && let Some((operation_span, operation)) = operation
+        {
+            span_lint_and_then(
+                cx,
+                LOCAL_TIME_DAY_ARITHMETIC,
+                constructor_span,
+                &format!(
+                    "getting the local time with `{}` in a function doing arithmetic with days",
+                    self.conf_constructors[constructor],
+                ),
+                |diag| {
+                    diag.span_note(
+                        operation_span,
+                        format!(
+                            "a duration of days is built here with `{}`",
+                            self.conf_operations[operation]
+                        ),
+                    );
+                    diag.note("a day isn't 24 hours long when the daylight saving time changes");
+                    diag.help("add calendar days instead, or compute the time in UTC");
+                },
+            );
+        }
+    }
+}
+
+fn resolve_paths(cx: &LateContext<'_>, paths: &[String], ids: &mut DefIdMap<usize>) {
+    for (index, path) in paths.iter().enumerate() {
+        let segs: Vec<_> = path.split("::").collect();
+        for id in def_path_def_ids(cx, &segs) {
+            ids.insert(id, index);
+        }
+    }
+}
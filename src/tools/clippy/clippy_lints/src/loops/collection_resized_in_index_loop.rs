@@ -0,0 +1,77 @@
+use super::COLLECTION_RESIZED_IN_INDEX_LOOP;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{eq_expr_value, higher};
+use core::ops::ControlFlow;
+use rustc_hir::{Expr, ExprKind, HirId};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+/// Detects collections changing their length inside a `for` loop over their indices, e.g.
+/// `for i in 0..v.len() { v.remove(i); }`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'_>, body: &'tcx Expr<'_>, span: Span) {
+    let Some(higher::Range { end: Some(end), .. }) = higher::Range::hir(arg) else {
+        return;
+    };
+    let ExprKind::MethodCall(len, collection, [], _) = end.kind else {
+        return;
+    };
+    let collection_ty = cx.typeck_results().expr_ty(collection).peel_refs();
+    if len.ident.as_str() != "len"
+        || span.from_expansion()
+        || !(is_type_diagnostic_item(cx, collection_ty, sym::Vec)
+            || is_type_diagnostic_item(cx, collection_ty, sym::VecDeque))
+    {
+        return;
+    }
+
+    // Leaving the loop right after the mutation, e.g. to remove the first match, is fine. A `break`
+    // out of a loop or block inside the body doesn't leave it.
+    let leaves_body = |target: HirId| !cx.tcx.hir().parent_id_iter(target).any(|id| id == body.hir_id);
+    let exits = for_each_expr(body, |e| match e.kind {
+        ExprKind::Break(dest, _) if dest.target_id.map_or(true, leaves_body) => ControlFlow::Break(()),
+        ExprKind::Ret(_) => ControlFlow::Break(()),
+        _ => ControlFlow::Continue(()),
+    });
+    if exits.is_some() {
+        return;
+    }
+    let Some((mutation, method)) = for_each_expr(body, |e| match e.kind {
+        ExprKind::MethodCall(method, recv, ..)
+            if matches!(
+                method.ident.as_str(),
+                "push" | "push_back" | "push_front" | "insert" | "remove" | "swap_remove" | "clear" | "truncate"
+            ) && eq_expr_value(cx, recv, collection) =>
+        {
+            ControlFlow::Break((e, method.ident.as_str()))
+        },
+        _ => ControlFlow::Continue(()),
+    }) else {
+        return;
+    };
+
+    let collection = snippet(cx, collection.span, "..");
+    span_lint_and_then(
+        cx,
+        COLLECTION_RESIZED_IN_INDEX_LOOP,
+        span.with_hi(arg.span.hi()),
+        &format!("`{collection}` changes its length while the loop iterates over its indices"),
+        |diag| {
+            diag.span_note(mutation.span, format!("`{collection}` is mutated here"));
+            diag.note("the range of indices is only computed once, so elements are skipped or indexed out of bounds");
+            match method {
+                "remove" | "swap_remove" => diag.help(format!(
+                    "use `{collection}.retain(..)` to remove the elements which don't match a condition"
+                )),
+                "clear" | "truncate" => diag.help(format!(
+                    "iterate over `{collection}.drain(..)` to take the elements out of `{collection}`"
+                )),
+                _ => diag.help(format!(
+                    "collect the new elements separately and add them with `{collection}.extend(..)` after the loop"
+                )),
+            };
+        },
+    );
+}
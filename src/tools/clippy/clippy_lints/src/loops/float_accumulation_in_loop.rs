@@ -0,0 +1,89 @@
+use super::FLOAT_ACCUMULATION_IN_LOOP;
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::{for_each_expr, Descend};
+use clippy_utils::{higher, path_to_local};
+use core::ops::ControlFlow;
+use rustc_ast::ast::RangeLimits;
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, FloatTy};
+
+/// Loops with at most this many iterations don't lose enough precision to be linted.
+const MAX_SHORT_LOOP_ITERATIONS: u128 = 1000;
+
+/// Detects float accumulators summed with `+=` in a loop. `arg` is the iterated expression of a
+/// `for` loop, or `None` for a `while` loop.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, arg: Option<&'tcx Expr<'_>>, body: &'tcx Expr<'_>) {
+    if arg.map_or(false, |arg| is_short(cx, arg)) {
+        return;
+    }
+
+    let mut accumulators: Vec<HirId> = Vec::new();
+    let _: Option<!> = for_each_expr(body, |e| {
+        // Nested loops which are linted on their own report the accumulators they contain
+        if let Some(higher::ForLoop { arg, .. }) = higher::ForLoop::hir(e)
+            && !is_short(cx, arg)
+        {
+            return ControlFlow::Continue(Descend::No);
+        }
+        if higher::While::hir(e).is_some() {
+            return ControlFlow::Continue(Descend::No);
+        }
+        if let ExprKind::AssignOp(op, acc, value) = e.kind
+            && op.node == BinOpKind::Add
+            && !e.span.from_expansion()
+            && let Some(acc_id) = path_to_local(acc)
+            && !accumulators.contains(&acc_id)
+            // The sum has to outlive an iteration
+            && !body.span.contains(cx.tcx.hir().span(acc_id))
+            && let ty::Float(float_ty) = cx.typeck_results().expr_ty(acc).kind()
+        {
+            accumulators.push(acc_id);
+            emit_lint(cx, e, *float_ty, value);
+        }
+        ControlFlow::Continue(Descend::Yes)
+    });
+}
+
+fn emit_lint(cx: &LateContext<'_>, expr: &Expr<'_>, float_ty: FloatTy, value: &Expr<'_>) {
+    let narrowed = float_ty == FloatTy::F32
+        && matches!(value.kind, ExprKind::Cast(inner, _)
+            if *cx.typeck_results().expr_ty(inner).kind() == ty::Float(FloatTy::F64));
+
+    let msg = if narrowed {
+        "accumulating `f64` values cast to `f32` in a loop"
+    } else {
+        "accumulating floats with `+=` in a loop"
+    };
+    span_lint_and_then(cx, FLOAT_ACCUMULATION_IN_LOOP, expr.span, msg, |diag| {
+        diag.note("every addition rounds the sum, and the error grows with the number of iterations");
+        if narrowed {
+            diag.help("accumulate into an `f64` and cast the sum to `f32` after the loop");
+        } else {
+            diag.help("consider compensated (Kahan) summation");
+            diag.note("`Iterator::sum` doesn't compensate for the rounding either");
+        }
+    });
+}
+
+/// Checks if the `for` loop over `arg` is known to run for few iterations.
+fn is_short(cx: &LateContext<'_>, arg: &Expr<'_>) -> bool {
+    if let Some(higher::Range {
+        start: Some(start),
+        end: Some(end),
+        limits,
+    }) = higher::Range::hir(arg)
+        && let Some((Constant::Int(start), _)) = constant(cx, cx.typeck_results(), start)
+        && let Some((Constant::Int(end), _)) = constant(cx, cx.typeck_results(), end)
+    {
+        let len = end.saturating_sub(start) + u128::from(limits == RangeLimits::Closed);
+        return len <= MAX_SHORT_LOOP_ITERATIONS;
+    }
+    match cx.typeck_results().expr_ty(arg).peel_refs().kind() {
+        ty::Array(_, len) => len
+            .try_eval_target_usize(cx.tcx, cx.param_env)
+            .map_or(false, |len| u128::from(len) <= MAX_SHORT_LOOP_ITERATIONS),
+        _ => false,
+    }
+}
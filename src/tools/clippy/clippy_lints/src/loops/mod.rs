@@ -1,7 +1,9 @@
+mod collection_resized_in_index_loop;
 mod empty_loop;
 mod explicit_counter_loop;
 mod explicit_into_iter_loop;
 mod explicit_iter_loop;
+mod float_accumulation_in_loop;
 mod for_kv_map;
 mod iter_next_loop;
 mod manual_find;
@@ -575,6 +577,77 @@
     "manual implementation of `Iterator::find`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for floats summed with `+=` in a loop of unknown or large length, especially `f32`
+    /// sums of `f64` values cast with `as`.
+    ///
+    /// ### Why is this bad?
+    /// Every addition rounds the sum to the precision of the float type. Over many iterations the
+    /// rounding errors add up, and once the sum is large small values are lost entirely. Casting
+    /// `f64` values to `f32` throws away precision that was available.
+    ///
+    /// ### Known problems
+    /// Whether the loss of precision matters depends on the values and the number of iterations,
+    /// which are usually unknown.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let values: Vec<f64> = vec![];
+    /// let mut sum = 0.0_f32;
+    /// for v in &values {
+    ///     sum += *v as f32;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let values: Vec<f64> = vec![];
+    /// let mut sum = 0.0_f64;
+    /// for v in &values {
+    ///     sum += *v;
+    /// }
+    /// let sum = sum as f32;
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub FLOAT_ACCUMULATION_IN_LOOP,
+    nursery,
+    "accumulating floats with `+=` in a long loop"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `for` loops over the indices of a `Vec` or `VecDeque`, e.g.
+    /// `for i in 0..v.len()`, which add or remove elements of the same collection.
+    ///
+    /// ### Why is this bad?
+    /// The range of indices is only computed once. After removing an element the following one
+    /// moves to the current index and is skipped, and the last indices are out of bounds. Elements
+    /// added inside the loop aren't visited.
+    ///
+    /// ### Known problems
+    /// Loops containing a `return` or a `break` out of the loop aren't linted, as leaving the loop
+    /// right after the mutation is fine.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let mut v = vec![1, 2, 3];
+    /// for i in 0..v.len() {
+    ///     if v[i] % 2 == 0 {
+    ///         v.remove(i);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let mut v = vec![1, 2, 3];
+    /// v.retain(|x| x % 2 != 0);
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub COLLECTION_RESIZED_IN_INDEX_LOOP,
+    suspicious,
+    "adding or removing elements of a collection while looping over its indices"
+}
+
 declare_lint_pass!(Loops => [
     MANUAL_MEMCPY,
     MANUAL_FLATTEN,
@@ -594,6 +667,8 @@
     SINGLE_ELEMENT_LOOP,
     MISSING_SPIN_LOOP,
     MANUAL_FIND,
+    FLOAT_ACCUMULATION_IN_LOOP,
+    COLLECTION_RESIZED_IN_INDEX_LOOP,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Loops {
@@ -643,6 +718,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let Some(higher::While { condition, body }) = higher::While::hir(expr) {
             while_immutable_condition::check(cx, condition, body);
             missing_spin_loop::check(cx, condition, body);
+            float_accumulation_in_loop::check(cx, None, body);
         }
     }
 }
@@ -663,10 +739,12 @@ fn check_for_loop<'tcx>(
     check_for_loop_arg(cx, pat, arg);
     for_kv_map::check(cx, pat, arg, body);
     mut_range_bound::check(cx, arg, body);
+    collection_resized_in_index_loop::check(cx, arg, body, span);
     single_element_loop::check(cx, pat, arg, body, expr);
     same_item_push::check(cx, pat, arg, body, expr);
     manual_flatten::check(cx, pat, arg, body, span);
     manual_find::check(cx, pat, arg, body, span, expr);
+    float_accumulation_in_loop::check(cx, Some(arg), body);
 }
 
 fn check_for_loop_arg(cx: &LateContext<'_>, _: &Pat<'_>, arg: &Expr<'_>) {
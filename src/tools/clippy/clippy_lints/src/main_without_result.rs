@@ -0,0 +1,97 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::is_entrypoint_fn;
+use clippy_utils::result_unwraps::ResultUnwraps;
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, ExprKind, FnDecl, FnRetTy};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::Span;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `fn main()` without a return type unwrapping `Result`s with `unwrap` or
+    /// `expect`.
+    ///
+    /// The return type suggested instead can be set with the `main-result-type` configuration,
+    /// e.g. to `anyhow::Result<()>`.
+    ///
+    /// ### Why is this bad?
+    /// Returning the error from `main` with `?` reports it without the panic message around it,
+    /// and makes the code read like the rest of the program's error handling.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// fn main() {
+    ///     let config = std::fs::read_to_string("config.toml").unwrap();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let config = std::fs::read_to_string("config.toml")?;
+    ///     Ok(())
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub MAIN_WITHOUT_RESULT,
+    restriction,
+    "`fn main()` unwrapping `Result`s instead of returning them"
+}
+
+pub struct MainWithoutResult {
+    result_type: String,
+}
+
+impl MainWithoutResult {
+    #[must_use]
+    pub fn new(result_type: String) -> Self {
+        Self { result_type }
+    }
+}
+
+impl_lint_pass!(MainWithoutResult => [MAIN_WITHOUT_RESULT]);
+
+impl<'tcx> LateLintPass<'tcx> for MainWithoutResult {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if let FnKind::ItemFn(..) = kind
+            && let FnRetTy::DefaultReturn(ret_span) = decl.output
+            && is_entrypoint_fn(cx, def_id.to_def_id())
+            && !span.from_expansion()
+            && let Some(sig) = cx.tcx.hir().get_by_def_id(def_id).fn_sig()
+            && let ExprKind::Block(block, _) = body.value.kind
+        {
+            let unwraps = ResultUnwraps::collect(cx, block, cx.tcx.types.unit);
+            if unwraps.spans.is_empty() {
+                return;
+            }
+
+            span_lint_and_then(
+                cx,
+                MAIN_WITHOUT_RESULT,
+                sig.span,
+                "`main` unwraps `Result`s instead of returning them",
+                |diag| {
+                    if let Some(sugg) = unwraps.return_result_sugg(cx, ret_span, &self.result_type) {
+                        diag.multipart_suggestion(
+                            "return a `Result` from `main` and use `?` instead",
+                            sugg,
+                            Applicability::MaybeIncorrect,
+                        );
+                    } else {
+                        diag.span_note(unwraps.spans, "the `Result`s are unwrapped here");
+                    }
+                },
+            );
+        }
+    }
+}
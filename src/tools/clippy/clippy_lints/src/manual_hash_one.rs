@@ -0,0 +1,207 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::paths::PathLookup;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::stmt_seq::{find_stmt_seqs, BlockItem, StmtPat};
+use clippy_utils::visitors::for_each_expr_with_closures;
+use clippy_utils::{is_trait_method, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, Block, Expr, ExprKind, HirId, PatKind, Stmt, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a hasher being built with `BuildHasher::build_hasher`, used to hash a single
+    /// value and then finished.
+    ///
+    /// ### Why is this bad?
+    /// `BuildHasher::hash_one` does the same in a single call.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// use std::collections::hash_map::RandomState;
+    /// use std::hash::{BuildHasher, Hash, Hasher};
+    ///
+    /// let s = RandomState::new();
+    /// let mut hasher = s.build_hasher();
+    /// value.hash(&mut hasher);
+    /// let hash = hasher.finish();
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// use std::collections::hash_map::RandomState;
+    /// use std::hash::BuildHasher;
+    ///
+    /// let s = RandomState::new();
+    /// let hash = s.hash_one(&value);
+    /// ```
+    ///
+    /// ### Known problems
+    /// `hash_one` is only stable since Rust 1.71, before that the lint is only emitted in crates
+    /// which enable the `build_hasher_simple_hash_one` feature.
+    #[clippy::version = "1.70.0"]
+    pub MANUAL_HASH_ONE,
+    style,
+    "manual implementations of `BuildHasher::hash_one`"
+}
+
+pub struct ManualHashOne {
+    msrv: Msrv,
+}
+
+impl ManualHashOne {
+    #[must_use]
+    pub fn new(msrv: Msrv) -> Self {
+        Self { msrv }
+    }
+
+    /// Checks if `hash_one` can be used, it has to be allowed by the MSRV, and either be stable
+    /// or have its feature enabled.
+    fn hash_one_available(&self, cx: &LateContext<'_>) -> bool {
+        self.msrv.meets(msrvs::BUILD_HASHER_HASH_ONE)
+            && (cx.tcx.features().active(Symbol::intern("build_hasher_simple_hash_one"))
+                || BUILD_HASHER_HASH_ONE.get(cx).iter().any(|&def_id| {
+                    cx.tcx
+                        .lookup_stability(def_id)
+                        .map_or(false, |stability| stability.is_stable())
+                }))
+    }
+}
+
+impl_lint_pass!(ManualHashOne => [MANUAL_HASH_ONE]);
+
+static BUILD_HASHER_BUILD_HASHER: PathLookup = PathLookup::new(&["core", "hash", "BuildHasher", "build_hasher"]);
+static BUILD_HASHER_HASH_ONE: PathLookup = PathLookup::new(&["core", "hash", "BuildHasher", "hash_one"]);
+static HASHER_FINISH: PathLookup = PathLookup::new(&["core", "hash", "Hasher", "finish"]);
+
+impl<'tcx> LateLintPass<'tcx> for ManualHashOne {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.span.from_expansion() || !self.hash_one_available(cx) {
+            return;
+        }
+
+        let pats = [
+            // `let mut hasher = build_hasher.build_hasher();`
+            StmtPat::one(|caps, item| {
+                if let BlockItem::Stmt(Stmt {
+                    kind: StmtKind::Local(local),
+                    ..
+                }) = item
+                    && let PatKind::Binding(BindingAnnotation::MUT, hasher, ..) = local.pat.kind
+                    && local.ty.is_none()
+                    && local.els.is_none()
+                    && let Some(init) = local.init
+                    && let ExprKind::MethodCall(_, build_hasher, [], _) = init.kind
+                    && is_method_call_to(cx, init, &BUILD_HASHER_BUILD_HASHER)
+                    // `hash_one` requires `Self: Sized`
+                    && cx.typeck_results().expr_ty(build_hasher).peel_refs().is_sized(cx.tcx, cx.param_env)
+                {
+                    caps.bind("hasher", hasher);
+                    true
+                } else {
+                    false
+                }
+            }),
+            // `value.hash(&mut hasher);`
+            StmtPat::one(|caps, item| {
+                if let BlockItem::Stmt(Stmt {
+                    kind: StmtKind::Semi(hash_expr),
+                    ..
+                }) = item
+                    && let ExprKind::MethodCall(seg, _, [hasher_arg], _) = hash_expr.kind
+                    && seg.ident.name == sym::hash
+                    && is_trait_method(cx, hash_expr, sym::Hash)
+                    && let ExprKind::AddrOf(_, _, hasher_ref) = hasher_arg.kind
+                {
+                    path_to_local_id(hasher_ref, caps.local("hasher"))
+                } else {
+                    false
+                }
+            }),
+            // `let hash = hasher.finish();` or `hasher.finish()` as the tail expression
+            StmtPat::one(|caps, item| {
+                if let BlockItem::Stmt(Stmt {
+                    kind: StmtKind::Local(_),
+                    ..
+                })
+                | BlockItem::Tail(_) = item
+                    && let Some(finish_expr) = item.expr()
+                    && let ExprKind::MethodCall(_, recv, [], _) = finish_expr.kind
+                {
+                    path_to_local_id(recv, caps.local("hasher")) && is_method_call_to(cx, finish_expr, &HASHER_FINISH)
+                } else {
+                    false
+                }
+            }),
+        ];
+
+        for m in find_stmt_seqs(cx, block, &pats) {
+            let [build_item, hash_item, finish_item] = m.items().collect::<Vec<_>>()[..] else {
+                continue;
+            };
+            if let Some(Expr {
+                kind: ExprKind::MethodCall(_, build_hasher, ..),
+                ..
+            }) = build_item.expr()
+                && let Some(Expr {
+                    kind: ExprKind::MethodCall(_, value, ..),
+                    ..
+                }) = hash_item.expr()
+                && let Some(finish_expr) = finish_item.expr()
+                && count_uses(cx, block, m.captures.local("hasher")) == 2
+                && !m.items().any(|item| item.span().from_expansion())
+            {
+                span_lint_and_then(
+                    cx,
+                    MANUAL_HASH_ONE,
+                    build_item.span().to(finish_expr.span),
+                    "manual implementation of `BuildHasher::hash_one`",
+                    |diag| {
+                        if let Some(build_hasher) = snippet_opt(cx, build_hasher.span)
+                            && let Some(value_snippet) = snippet_opt(cx, value.span)
+                        {
+                            let value_snippet = if matches!(cx.typeck_results().expr_ty(value).kind(), ty::Ref(..)) {
+                                value_snippet
+                            } else {
+                                format!("&{value_snippet}")
+                            };
+                            diag.multipart_suggestion(
+                                "try",
+                                vec![
+                                    (build_item.span().until(finish_item.span()), String::new()),
+                                    (finish_expr.span, format!("{build_hasher}.hash_one({value_snippet})")),
+                                ],
+                                Applicability::MachineApplicable,
+                            );
+                        }
+                    },
+                );
+            }
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Checks if `expr` is a method call resolving to one of the items of `lookup`.
+fn is_method_call_to(cx: &LateContext<'_>, expr: &Expr<'_>, lookup: &PathLookup) -> bool {
+    cx.typeck_results()
+        .type_dependent_def_id(expr.hir_id)
+        .map_or(false, |def_id| lookup.matches(cx, def_id))
+}
+
+/// Counts the uses of the local `id` in `block`.
+fn count_uses<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>, id: HirId) -> usize {
+    let mut count = 0;
+    for_each_expr_with_closures(cx, block, |e| {
+        if path_to_local_id(e, id) {
+            count += 1;
+        }
+        ControlFlow::<()>::Continue(())
+    });
+    count
+}
@@ -6,15 +6,17 @@
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::visitors::{Descend, Visitable};
 use if_chain::if_chain;
-use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::{walk_expr, Visitor};
-use rustc_hir::{Expr, ExprKind, HirId, ItemId, Local, MatchSource, Pat, PatKind, QPath, Stmt, StmtKind, Ty};
+use rustc_hir::{
+    BindingAnnotation, Expr, ExprKind, HirId, ItemId, Local, MatchSource, Pat, PatKind, QPath, Stmt, StmtKind, Ty,
+};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_session::{declare_tool_lint, impl_lint_pass};
-use rustc_span::symbol::sym;
-use rustc_span::Span;
+use rustc_span::symbol::{sym, Symbol};
+use rustc_span::{Pos, Span};
 use serde::Deserialize;
 use std::ops::ControlFlow;
 
@@ -69,7 +71,7 @@ pub fn new(msrv: Msrv, matches_behaviour: MatchLintBehaviour) -> Self {
 
 impl<'tcx> LateLintPass<'tcx> for ManualLetElse {
     fn check_stmt(&mut self, cx: &LateContext<'_>, stmt: &'tcx Stmt<'tcx>) {
-        let if_let_or_match = if_chain! {
+        let (local, if_let_or_match) = if_chain! {
             if self.msrv.meets(msrvs::LET_ELSE);
             if !in_external_macro(cx.sess(), stmt.span);
             if let StmtKind::Local(local) = stmt.kind;
@@ -79,7 +81,7 @@ fn check_stmt(&mut self, cx: &LateContext<'_>, stmt: &'tcx Stmt<'tcx>) {
             if init.span.ctxt() == stmt.span.ctxt();
             if let Some(if_let_or_match) = IfLetOrMatch::parse(cx, init);
             then {
-                if_let_or_match
+                (local, if_let_or_match)
             } else {
                 return;
             }
@@ -87,11 +89,11 @@ fn check_stmt(&mut self, cx: &LateContext<'_>, stmt: &'tcx Stmt<'tcx>) {
 
         match if_let_or_match {
             IfLetOrMatch::IfLet(if_let_expr, let_pat, if_then, if_else) => if_chain! {
-                if expr_is_simple_identity(let_pat, if_then);
+                if let Some(binding_map) = simple_identity_map(local.pat, let_pat, if_then);
                 if let Some(if_else) = if_else;
                 if expr_diverges(cx, if_else);
                 then {
-                    emit_manual_let_else(cx, stmt.span, if_let_expr, let_pat, if_else);
+                    emit_manual_let_else(cx, stmt.span, if_let_expr, &binding_map, let_pat, if_else);
                 }
             },
             IfLetOrMatch::Match(match_expr, arms, source) => {
@@ -124,11 +126,9 @@ fn check_stmt(&mut self, cx: &LateContext<'_>, stmt: &'tcx Stmt<'tcx>) {
                     return;
                 }
                 let pat_arm = &arms[1 - idx];
-                if !expr_is_simple_identity(pat_arm.pat, pat_arm.body) {
-                    return;
-                }
+                let Some(binding_map) = simple_identity_map(local.pat, pat_arm.pat, pat_arm.body) else { return };
 
-                emit_manual_let_else(cx, stmt.span, match_expr, pat_arm.pat, diverging_arm.body);
+                emit_manual_let_else(cx, stmt.span, match_expr, &binding_map, pat_arm.pat, diverging_arm.body);
             },
         }
     }
@@ -136,7 +136,14 @@ fn check_stmt(&mut self, cx: &LateContext<'_>, stmt: &'tcx Stmt<'tcx>) {
     extract_msrv_attr!(LateContext);
 }
 
-fn emit_manual_let_else(cx: &LateContext<'_>, span: Span, expr: &Expr<'_>, pat: &Pat<'_>, else_body: &Expr<'_>) {
+fn emit_manual_let_else(
+    cx: &LateContext<'_>,
+    span: Span,
+    expr: &Expr<'_>,
+    binding_map: &FxHashMap<Symbol, &Pat<'_>>,
+    pat: &Pat<'_>,
+    else_body: &Expr<'_>,
+) {
     span_lint_and_then(
         cx,
         MANUAL_LET_ELSE,
@@ -144,13 +151,15 @@ fn emit_manual_let_else(cx: &LateContext<'_>, span: Span, expr: &Expr<'_>, pat:
         "this could be rewritten as `let...else`",
         |diag| {
             // This is far from perfect, for example there needs to be:
-            // * mut additions for the bindings
-            // * renamings of the bindings
             // * unused binding collision detection with existing ones
             // * putting patterns with at the top level | inside ()
             // for this to be machine applicable.
             let mut app = Applicability::HasPlaceholders;
-            let (sn_pat, _) = snippet_with_context(cx, pat.span, span.ctxt(), "", &mut app);
+            let sn_pat = replace_bindings(cx, span, binding_map, pat, &mut app).unwrap_or_else(|| {
+                snippet_with_context(cx, pat.span, span.ctxt(), "", &mut app)
+                    .0
+                    .into_owned()
+            });
             let (sn_expr, _) = snippet_with_context(cx, expr.span, span.ctxt(), "", &mut app);
             let (sn_else, _) = snippet_with_context(cx, else_body.span, span.ctxt(), "", &mut app);
 
@@ -162,7 +171,7 @@ fn emit_manual_let_else(cx: &LateContext<'_>, span: Span, expr: &Expr<'_>, pat:
             let sn_bl = if matches!(pat.kind, PatKind::Or(..)) {
                 format!("({sn_pat})")
             } else {
-                sn_pat.into_owned()
+                sn_pat
             };
             let sugg = format!("let {sn_bl} = {sn_expr} else {else_bl};");
             diag.span_suggestion(span, "consider writing", sugg, app);
@@ -308,37 +317,121 @@ fn pat_allowed_for_else(cx: &LateContext<'_>, pat: &'_ Pat<'_>, check_types: boo
     !has_disallowed
 }
 
-/// Checks if the passed block is a simple identity referring to bindings created by the pattern
-fn expr_is_simple_identity(pat: &'_ Pat<'_>, expr: &'_ Expr<'_>) -> bool {
+/// Checks if the passed block is a simple identity referring to bindings created by the pattern,
+/// and maps each of these bindings to the part of the `let` pattern it gets assigned to.
+///
+/// For `let (a, b) = if let Some((x, y)) = e { (y, x) } else { .. }`, this maps `y` to `a` and
+/// `x` to `b`.
+fn simple_identity_map<'a, 'hir>(
+    local_pat: &'a Pat<'hir>,
+    pat: &'_ Pat<'_>,
+    expr: &'_ Expr<'_>,
+) -> Option<FxHashMap<Symbol, &'a Pat<'hir>>> {
     // We support patterns with multiple bindings and tuples, like:
     //   let ... = if let (Some(foo), bar) = g() { (foo, bar) } else { ... }
     let peeled = peel_blocks(expr);
-    let paths = match peeled.kind {
-        ExprKind::Tup(exprs) | ExprKind::Array(exprs) => exprs,
-        ExprKind::Path(_) => std::slice::from_ref(peeled),
-        _ => return false,
+    let (local_pats, paths) = match (&local_pat.kind, &peeled.kind) {
+        // Nothing is bound if the block evaluates to `()`
+        (_, ExprKind::Tup([])) => (Some(&[][..]), &[][..]),
+        (PatKind::Tuple(local_pats, dot_dot), ExprKind::Tup(exprs))
+            if dot_dot.as_opt_usize().is_none() && local_pats.len() == exprs.len() =>
+        {
+            (Some(*local_pats), *exprs)
+        },
+        (PatKind::Slice(local_pats, None, []), ExprKind::Array(exprs)) if local_pats.len() == exprs.len() => {
+            (Some(*local_pats), *exprs)
+        },
+        // The `let` pattern doesn't destructure the value, so the bindings of the pattern are kept
+        (_, ExprKind::Tup(exprs) | ExprKind::Array(exprs)) => (None, *exprs),
+        (_, ExprKind::Path(_)) => (Some(std::slice::from_ref(local_pat)), std::slice::from_ref(peeled)),
+        _ => return None,
     };
     let mut pat_bindings = FxHashSet::default();
     pat.each_binding_or_first(&mut |_ann, _hir_id, _sp, ident| {
         pat_bindings.insert(ident);
     });
-    if pat_bindings.len() < paths.len() {
-        return false;
-    }
-    for path in paths {
+    let mut binding_map = FxHashMap::default();
+    for (i, path) in paths.iter().enumerate() {
         if_chain! {
             if let ExprKind::Path(QPath::Resolved(_ty, path)) = path.kind;
             if let [path_seg] = path.segments;
+            if pat_bindings.remove(&path_seg.ident);
             then {
-                if !pat_bindings.remove(&path_seg.ident) {
-                    return false;
+                if let Some(local_pats) = local_pats {
+                    binding_map.insert(path_seg.ident.name, &local_pats[i]);
                 }
             } else {
-                return false;
+                return None;
+            }
+        }
+    }
+    Some(binding_map)
+}
+
+/// Renders the pattern with its bindings replaced by the parts of the `let` pattern they are
+/// mapped to, e.g. `Some(v_some)` becomes `Some(mut v)` for `let mut v = if let Some(v_some) = ..`.
+fn replace_bindings(
+    cx: &LateContext<'_>,
+    span: Span,
+    binding_map: &FxHashMap<Symbol, &Pat<'_>>,
+    pat: &Pat<'_>,
+    app: &mut Applicability,
+) -> Option<String> {
+    if pat.span.ctxt() != span.ctxt() {
+        return None;
+    }
+
+    let mut replacements = Vec::new();
+    let mut failed = false;
+    pat.walk_always(|pat| match pat.kind {
+        PatKind::Binding(ann, _, ident, sub) => {
+            let Some(local_pat) = binding_map.get(&ident.name) else { return };
+            match local_pat.kind {
+                // Keep the binding mode of the pattern, just rename the binding
+                PatKind::Binding(BindingAnnotation::NONE, _, local_ident, None) => {
+                    replacements.push((ident.span, local_ident.to_string()));
+                },
+                _ if ann == BindingAnnotation::NONE && sub.is_none() => {
+                    let (sn_local, _) = snippet_with_context(cx, local_pat.span, span.ctxt(), "", app);
+                    replacements.push((pat.span, sn_local.into_owned()));
+                },
+                _ => failed = true,
             }
+        },
+        // Field shorthands need to be expanded when the binding is renamed
+        PatKind::Struct(_, fields, _) => {
+            for field in fields.iter().filter(|field| field.is_shorthand) {
+                if let PatKind::Binding(_, _, ident, _) = field.pat.kind
+                    && let Some(local_pat) = binding_map.get(&ident.name)
+                    && !matches!(
+                        local_pat.kind,
+                        PatKind::Binding(_, _, local_ident, None) if local_ident.name == ident.name
+                    )
+                {
+                    replacements.push((field.span.shrink_to_lo(), format!("{ident}: ")));
+                }
+            }
+        },
+        _ => {},
+    });
+    if failed {
+        return None;
+    }
+
+    let (sn_pat, _) = snippet_with_context(cx, pat.span, span.ctxt(), "", app);
+    replacements.sort_by_key(|(sp, _)| (sp.lo(), sp.hi()));
+    let mut rendered = String::new();
+    let mut pos = pat.span.lo();
+    for (sp, replacement) in replacements {
+        if sp.lo() < pos || sp.hi() > pat.span.hi() || sp.ctxt() != pat.span.ctxt() {
+            return None;
         }
+        rendered.push_str(sn_pat.get((pos - pat.span.lo()).to_usize()..(sp.lo() - pat.span.lo()).to_usize())?);
+        rendered.push_str(&replacement);
+        pos = sp.hi();
     }
-    true
+    rendered.push_str(sn_pat.get((pos - pat.span.lo()).to_usize()..)?);
+    Some(rendered)
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize)]
@@ -0,0 +1,264 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::{snippet, snippet_opt};
+use clippy_utils::stmt_seq::{find_stmt_seqs, BlockItem, StmtPat};
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::{for_each_expr_with_closures, is_local_used, Descend};
+use clippy_utils::{eq_expr_value, get_parent_expr, higher, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, BindingAnnotation, Block, Expr, ExprKind, HirId, PatKind, Stmt, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Pos, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for elements being removed from a `Vec` or `VecDeque` in a `while` loop over its
+    /// indices, which only advances the index if the current element is kept.
+    ///
+    /// The same can be written with `filter` and `collect`, which is linted by
+    /// [`manual_retain`](#manual_retain).
+    ///
+    /// ### Why is this bad?
+    /// `retain` is simpler. Every `remove` shifts all of the following elements, so the loop takes
+    /// quadratic time, while `retain` moves each element at most once.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let mut v = vec![1, 2, 3];
+    /// let mut i = 0;
+    /// while i < v.len() {
+    ///     if v[i] % 2 == 0 {
+    ///         v.remove(i);
+    ///     } else {
+    ///         i += 1;
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let mut v = vec![1, 2, 3];
+    /// v.retain(|x| *x % 2 != 0);
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub MANUAL_RETAIN_WITH_INDEX_LOOP,
+    perf,
+    "removing elements in a `while` loop over their indices instead of using `retain`"
+}
+
+declare_lint_pass!(ManualRetainWithIndexLoop => [MANUAL_RETAIN_WITH_INDEX_LOOP]);
+
+/// The parts of `while i < v.len() { if pred { v.remove(i); } else { i += 1; } }`.
+struct RemoveLoop<'tcx> {
+    collection: &'tcx Expr<'tcx>,
+    pred: &'tcx Expr<'tcx>,
+}
+
+impl<'tcx> LateLintPass<'tcx> for ManualRetainWithIndexLoop {
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        if block.span.from_expansion() {
+            return;
+        }
+
+        let pats = [
+            // `let mut i = 0;`
+            StmtPat::one(|caps, item| {
+                if let BlockItem::Stmt(Stmt {
+                    kind: StmtKind::Local(local),
+                    ..
+                }) = item
+                    && let PatKind::Binding(BindingAnnotation::MUT, index, ..) = local.pat.kind
+                    && local.els.is_none()
+                    && let Some(init) = local.init
+                    && let ExprKind::Lit(lit) = &init.kind
+                    && let LitKind::Int(0, _) = lit.node
+                {
+                    caps.bind("index", index);
+                    true
+                } else {
+                    false
+                }
+            }),
+            // `while i < v.len() { .. }`
+            StmtPat::one(|caps, item| {
+                item.expr()
+                    .and_then(|e| remove_loop(cx, e, caps.local("index")))
+                    .is_some()
+            }),
+        ];
+
+        for m in find_stmt_seqs(cx, block, &pats) {
+            let [let_item, loop_item] = m.items().collect::<Vec<_>>()[..] else {
+                continue;
+            };
+            let index = m.captures.local("index");
+            let Some(RemoveLoop { collection, pred }) = loop_item.expr().and_then(|e| remove_loop(cx, e, index)) else {
+                continue;
+            };
+            // The index has to be unused after the loop, and the `if` condition may only use it to
+            // get the current element
+            let used_later = block
+                .stmts
+                .iter()
+                .filter(|stmt| stmt.span.lo() > loop_item.span().hi())
+                .any(|stmt| is_local_used(cx, stmt, index))
+                || block.expr.map_or(false, |e| {
+                    e.span.lo() > loop_item.span().hi() && is_local_used(cx, e, index)
+                });
+            if used_later || m.items().any(|item| item.span().from_expansion()) {
+                continue;
+            }
+            let Some(replacements) = element_uses(cx, pred, collection, index) else {
+                continue;
+            };
+
+            span_lint_and_then(
+                cx,
+                MANUAL_RETAIN_WITH_INDEX_LOOP,
+                let_item.span().to(loop_item.span()),
+                "removing elements in a `while` loop over their indices",
+                |diag| {
+                    if let Some(cond) = negated_closure_body(cx, pred, replacements) {
+                        diag.span_suggestion(
+                            let_item.span().to(loop_item.span()),
+                            "use `retain` instead",
+                            format!("{}.retain(|x| {cond});", snippet(cx, collection.span, "..")),
+                            Applicability::MaybeIncorrect,
+                        );
+                    }
+                },
+            );
+        }
+    }
+}
+
+/// Matches `while i < v.len() { if pred { v.remove(i); } else { i += 1; } }`, where `i` is the
+/// local `index` and `v` is a `Vec` or `VecDeque`.
+fn remove_loop<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>, index: HirId) -> Option<RemoveLoop<'tcx>> {
+    let higher::While { condition, body } = higher::While::hir(e)?;
+    let ExprKind::Binary(op, lhs, len) = condition.kind else {
+        return None;
+    };
+    let ExprKind::MethodCall(len_path, collection, [], _) = len.kind else {
+        return None;
+    };
+    let collection_ty = cx.typeck_results().expr_ty(collection).peel_refs();
+    if op.node != BinOpKind::Lt
+        || !path_to_local_id(lhs, index)
+        || len_path.ident.as_str() != "len"
+        || !(is_type_diagnostic_item(cx, collection_ty, sym::Vec)
+            || is_type_diagnostic_item(cx, collection_ty, sym::VecDeque))
+    {
+        return None;
+    }
+
+    let higher::If {
+        cond: pred,
+        then,
+        r#else: Some(r#else),
+    } = higher::If::hir(single_expr(body)?)?
+    else {
+        return None;
+    };
+    if let ExprKind::MethodCall(remove, recv, [arg], _) = single_expr(then)?.kind
+        && remove.ident.as_str() == "remove"
+        && eq_expr_value(cx, recv, collection)
+        && path_to_local_id(arg, index)
+        && let ExprKind::AssignOp(op, target, one) = single_expr(r#else)?.kind
+        && op.node == BinOpKind::Add
+        && path_to_local_id(target, index)
+        && let ExprKind::Lit(lit) = &one.kind
+        && let LitKind::Int(1, _) = lit.node
+    {
+        Some(RemoveLoop { collection, pred })
+    } else {
+        None
+    }
+}
+
+/// Gets the only expression of a block containing a single statement or a tail expression.
+fn single_expr<'tcx>(e: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let ExprKind::Block(block, _) = e.kind else {
+        return None;
+    };
+    match (block.stmts, block.expr) {
+        ([], Some(e)) => Some(e),
+        ([stmt], None) => match stmt.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Finds the uses of the current element `v[i]` in `pred`, along with the text replacing them in
+/// the closure passed to `retain`. Returns `None` if `pred` uses the collection or the index in
+/// any other way.
+fn element_uses<'tcx>(
+    cx: &LateContext<'tcx>,
+    pred: &'tcx Expr<'tcx>,
+    collection: &'tcx Expr<'tcx>,
+    index: HirId,
+) -> Option<Vec<(Span, &'static str)>> {
+    let mut replacements = Vec::new();
+    let other_use = for_each_expr_with_closures(cx, pred, |e| {
+        if let ExprKind::Index(base, idx) = e.kind
+            && eq_expr_value(cx, base, collection)
+            && path_to_local_id(idx, index)
+        {
+            // The closure gets a reference to the element
+            let replacement = match get_parent_expr(cx, e) {
+                Some(
+                    parent @ Expr {
+                        kind: ExprKind::AddrOf(..),
+                        ..
+                    },
+                ) => (parent.span, "x"),
+                Some(Expr {
+                    kind: ExprKind::MethodCall(_, recv, ..) | ExprKind::Field(recv, _),
+                    ..
+                }) if recv.hir_id == e.hir_id => (e.span, "x"),
+                _ => (e.span, "*x"),
+            };
+            replacements.push(replacement);
+            ControlFlow::Continue(Descend::No)
+        } else if path_to_local_id(e, index) || eq_expr_value(cx, e, collection) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(Descend::Yes)
+        }
+    });
+    other_use.is_none().then_some(replacements)
+}
+
+/// Builds the negation of `pred` with the uses of the current element replaced.
+fn negated_closure_body(cx: &LateContext<'_>, pred: &Expr<'_>, mut replacements: Vec<(Span, &str)>) -> Option<String> {
+    let (expr, prefix, suffix) = match pred.kind {
+        ExprKind::Unary(UnOp::Not, inner) => (inner, "", ""),
+        ExprKind::Binary(op, ..) if matches!(op.node, BinOpKind::Eq | BinOpKind::Ne) => {
+            replacements.push((op.span, if op.node == BinOpKind::Eq { "!=" } else { "==" }));
+            (pred, "", "")
+        },
+        ExprKind::MethodCall(..) | ExprKind::Call(..) | ExprKind::Path(..) | ExprKind::Field(..) => (pred, "!", ""),
+        _ => (pred, "!(", ")"),
+    };
+    if expr.span.from_expansion() {
+        return None;
+    }
+
+    let text = snippet_opt(cx, expr.span)?;
+    let start = expr.span.lo();
+    replacements.sort_by_key(|(span, _)| span.lo());
+    let mut body = String::from(prefix);
+    let mut pos = 0;
+    for (span, replacement) in replacements {
+        let lo = (span.lo() - start).to_usize();
+        body.push_str(text.get(pos..lo)?);
+        body.push_str(replacement);
+        pos = (span.hi() - start).to_usize();
+    }
+    body.push_str(text.get(pos..)?);
+    body.push_str(suffix);
+    Some(body)
+}
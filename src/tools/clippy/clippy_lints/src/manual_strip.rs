@@ -1,9 +1,10 @@
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{multispan_sugg, span_lint_and_then};
 use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::paths::PathLookup;
 use clippy_utils::source::snippet;
 use clippy_utils::usage::mutated_variables;
-use clippy_utils::{eq_expr_value, higher, match_def_path, paths};
+use clippy_utils::{eq_expr_value, higher};
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
 use rustc_hir::def::Res;
@@ -60,6 +61,10 @@ pub fn new(msrv: Msrv) -> Self {
 
 impl_lint_pass!(ManualStrip => [MANUAL_STRIP]);
 
+static STR_ENDS_WITH: PathLookup = PathLookup::new(&["str", "ends_with"]);
+static STR_LEN: PathLookup = PathLookup::new(&["str", "len"]);
+static STR_STARTS_WITH: PathLookup = PathLookup::new(&["str", "starts_with"]);
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum StripKind {
     Prefix,
@@ -78,9 +83,9 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
             if let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(cond.hir_id);
             if let ExprKind::Path(target_path) = &target_arg.kind;
             then {
-                let strip_kind = if match_def_path(cx, method_def_id, &paths::STR_STARTS_WITH) {
+                let strip_kind = if STR_STARTS_WITH.matches(cx, method_def_id) {
                     StripKind::Prefix
-                } else if match_def_path(cx, method_def_id, &paths::STR_ENDS_WITH) {
+                } else if STR_ENDS_WITH.matches(cx, method_def_id) {
                     StripKind::Suffix
                 } else {
                     return;
@@ -133,7 +138,7 @@ fn len_arg<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<&'tcx E
     if_chain! {
         if let ExprKind::MethodCall(_, arg, [], _) = expr.kind;
         if let Some(method_def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id);
-        if match_def_path(cx, method_def_id, &paths::STR_LEN);
+        if STR_LEN.matches(cx, method_def_id);
         then {
             Some(arg)
         } else {
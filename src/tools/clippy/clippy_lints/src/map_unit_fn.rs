@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::{snippet, snippet_with_applicability, snippet_with_context};
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{iter_input_pats, method_chain_args};
+use clippy_utils::{iter_input_pats, match_method_chain};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
@@ -262,8 +262,8 @@ fn check_stmt(&mut self, cx: &LateContext<'_>, stmt: &hir::Stmt<'_>) {
         }
 
         if let hir::StmtKind::Semi(expr) = stmt.kind {
-            if let Some(arglists) = method_chain_args(expr, &["map"]) {
-                lint_map_unit_fn(cx, stmt, expr, arglists[0]);
+            if let Some([map]) = match_method_chain(expr, ["map"]) {
+                lint_map_unit_fn(cx, stmt, expr, (map.receiver, map.args));
             }
         }
     }
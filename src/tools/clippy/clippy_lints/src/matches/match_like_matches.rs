@@ -1,11 +1,10 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::is_wild;
-use clippy_utils::source::snippet_with_applicability;
-use clippy_utils::span_contains_comment;
+use clippy_utils::source::{snippet_with_applicability, sugg_with_comments};
 use rustc_ast::{Attribute, LitKind};
 use rustc_errors::Applicability;
 use rustc_hir::{Arm, BorrowKind, Expr, ExprKind, Guard, Pat};
-use rustc_lint::{LateContext, LintContext};
+use rustc_lint::LateContext;
 use rustc_middle::ty;
 use rustc_span::source_map::Spanned;
 
@@ -77,7 +76,6 @@ fn find_matches_sugg<'a, 'b, I>(
         >,
 {
     if_chain! {
-        if !span_contains_comment(cx.sess().source_map(), expr.span);
         if iter.len() >= 2;
         if cx.typeck_results().expr_ty(expr).is_bool();
         if let Some((_, last_pat_opt, last_expr, _)) = iter.next_back();
@@ -124,17 +122,22 @@ fn find_matches_sugg<'a, 'b, I>(
                     ex_new = ex_inner;
                 }
             };
+            let sugg = format!(
+                "{}matches!({}, {pat_and_guard})",
+                if b0 { "" } else { "!" },
+                snippet_with_applicability(cx, ex_new.span, "..", &mut applicability),
+            );
+            // The arms' comments can only be kept in front of the `matches!` call
+            if sugg_with_comments(cx, expr.span, &sugg).is_none() {
+                return false;
+            }
             span_lint_and_sugg(
                 cx,
                 MATCH_LIKE_MATCHES_MACRO,
                 expr.span,
                 &format!("{} expression looks like `matches!` macro", if is_if_let { "if let .. else" } else { "match" }),
                 "try this",
-                format!(
-                    "{}matches!({}, {pat_and_guard})",
-                    if b0 { "" } else { "!" },
-                    snippet_with_applicability(cx, ex_new.span, "..", &mut applicability),
-                ),
+                sugg,
                 applicability,
             );
             true
@@ -928,6 +928,37 @@
     "reimplementation of `filter`"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Lint for `matches!` calls checking for a single variant of `Option`, `Result`,
+    /// `std::task::Poll` or `std::net::IpAddr` without looking at its contents.
+    ///
+    /// ### Why is this bad?
+    /// It's more concise and clear to just use the proper
+    /// utility function
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let x = Some(42);
+    /// # let r = Ok::<i32, i32>(42);
+    /// matches!(x, Some(_));
+    /// matches!(x, None);
+    /// matches!(r, Err(_));
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x = Some(42);
+    /// # let r = Ok::<i32, i32>(42);
+    /// x.is_some();
+    /// x.is_none();
+    /// r.is_err();
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub REDUNDANT_PATTERN_MATCHING_IN_MATCHES_MACRO,
+    style,
+    "use the proper utility function instead of `matches!`"
+}
+
 #[derive(Default)]
 pub struct Matches {
     msrv: Msrv,
@@ -970,10 +1001,17 @@ pub fn new(msrv: Msrv) -> Self {
     TRY_ERR,
     MANUAL_MAP,
     MANUAL_FILTER,
+    REDUNDANT_PATTERN_MATCHING_IN_MATCHES_MACRO,
 ]);
 
 impl<'tcx> LateLintPass<'tcx> for Matches {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        // `matches!` is an external macro, so this has to come before the check below
+        if let ExprKind::Match(ex, arms, MatchSource::Normal) = expr.kind
+            && expr.span.from_expansion()
+        {
+            redundant_pattern_match::check_matches_macro(cx, expr, ex, arms);
+        }
         if in_external_macro(cx.sess(), expr.span) {
             return;
         }
@@ -1,5 +1,6 @@
-use super::REDUNDANT_PATTERN_MATCHING;
-use clippy_utils::diagnostics::span_lint_and_then;
+use super::{REDUNDANT_PATTERN_MATCHING, REDUNDANT_PATTERN_MATCHING_IN_MATCHES_MACRO};
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::macros::macro_backtrace;
 use clippy_utils::source::snippet;
 use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::{is_type_diagnostic_item, needs_ordered_drop};
@@ -60,52 +61,7 @@ fn find_sugg_for_if_let<'tcx>(
         _ => let_pat,
     };
     let op_ty = cx.typeck_results().expr_ty(let_expr);
-    // Determine which function should be used, and the type contained by the corresponding
-    // variant.
-    let (good_method, inner_ty) = match check_pat.kind {
-        PatKind::TupleStruct(ref qpath, [sub_pat], _) => {
-            if let PatKind::Wild = sub_pat.kind {
-                let res = cx.typeck_results().qpath_res(qpath, check_pat.hir_id);
-                let Some(id) = res.opt_def_id().map(|ctor_id| cx.tcx.parent(ctor_id)) else { return };
-                let lang_items = cx.tcx.lang_items();
-                if Some(id) == lang_items.result_ok_variant() {
-                    ("is_ok()", try_get_generic_ty(op_ty, 0).unwrap_or(op_ty))
-                } else if Some(id) == lang_items.result_err_variant() {
-                    ("is_err()", try_get_generic_ty(op_ty, 1).unwrap_or(op_ty))
-                } else if Some(id) == lang_items.option_some_variant() {
-                    ("is_some()", op_ty)
-                } else if Some(id) == lang_items.poll_ready_variant() {
-                    ("is_ready()", op_ty)
-                } else if is_pat_variant(cx, check_pat, qpath, Item::Diag(sym::IpAddr, sym!(V4))) {
-                    ("is_ipv4()", op_ty)
-                } else if is_pat_variant(cx, check_pat, qpath, Item::Diag(sym::IpAddr, sym!(V6))) {
-                    ("is_ipv6()", op_ty)
-                } else {
-                    return;
-                }
-            } else {
-                return;
-            }
-        },
-        PatKind::Path(ref path) => {
-            if let Res::Def(DefKind::Ctor(..), ctor_id) = cx.qpath_res(path, check_pat.hir_id)
-                && let Some(variant_id) = cx.tcx.opt_parent(ctor_id)
-            {
-                let method = if cx.tcx.lang_items().option_none_variant() == Some(variant_id) {
-                    "is_none()"
-                } else if cx.tcx.lang_items().poll_pending_variant() == Some(variant_id) {
-                    "is_pending()"
-                } else {
-                    return;
-                };
-                // `None` and `Pending` don't have an inner type.
-                (method, cx.tcx.types.unit)
-            } else {
-                return;
-            }
-        },
-        _ => return,
-    };
+    let Some((good_method, inner_ty)) = find_method_and_type(cx, check_pat, op_ty) else { return };
 
     // If this is the last expression in a block or there is an else clause then the whole
     // type needs to be considered, not just the inner type of the branch being matched on.
@@ -179,6 +135,101 @@ fn find_sugg_for_if_let<'tcx>(
     );
 }
 
+/// Determines which function should be used for the pattern, and the type contained by the
+/// corresponding variant.
+fn find_method_and_type<'tcx>(
+    cx: &LateContext<'tcx>,
+    check_pat: &Pat<'_>,
+    op_ty: Ty<'tcx>,
+) -> Option<(&'static str, Ty<'tcx>)> {
+    match check_pat.kind {
+        PatKind::TupleStruct(ref qpath, [sub_pat], _) => {
+            if let PatKind::Wild = sub_pat.kind {
+                let res = cx.typeck_results().qpath_res(qpath, check_pat.hir_id);
+                let id = res.opt_def_id().map(|ctor_id| cx.tcx.parent(ctor_id))?;
+                let lang_items = cx.tcx.lang_items();
+                if Some(id) == lang_items.result_ok_variant() {
+                    Some(("is_ok()", try_get_generic_ty(op_ty, 0).unwrap_or(op_ty)))
+                } else if Some(id) == lang_items.result_err_variant() {
+                    Some(("is_err()", try_get_generic_ty(op_ty, 1).unwrap_or(op_ty)))
+                } else if Some(id) == lang_items.option_some_variant() {
+                    Some(("is_some()", op_ty))
+                } else if Some(id) == lang_items.poll_ready_variant() {
+                    Some(("is_ready()", op_ty))
+                } else if is_pat_variant(cx, check_pat, qpath, Item::Diag(sym::IpAddr, sym!(V4))) {
+                    Some(("is_ipv4()", op_ty))
+                } else if is_pat_variant(cx, check_pat, qpath, Item::Diag(sym::IpAddr, sym!(V6))) {
+                    Some(("is_ipv6()", op_ty))
+                } else {
+                    None
+                }
+            } else {
+                None
+            }
+        },
+        PatKind::Path(ref path) => {
+            if let Res::Def(DefKind::Ctor(..), ctor_id) = cx.qpath_res(path, check_pat.hir_id)
+                && let Some(variant_id) = cx.tcx.opt_parent(ctor_id)
+            {
+                let method = if cx.tcx.lang_items().option_none_variant() == Some(variant_id) {
+                    "is_none()"
+                } else if cx.tcx.lang_items().poll_pending_variant() == Some(variant_id) {
+                    "is_pending()"
+                } else {
+                    return None;
+                };
+                // `None` and `Pending` don't have an inner type.
+                Some((method, cx.tcx.types.unit))
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Checks for `matches!` calls testing for a single variant without binding anything, e.g.
+/// `matches!(x, Some(_))`, which expand to `match x { Some(_) => true, _ => false }`.
+pub(super) fn check_matches_macro<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    op: &'tcx Expr<'_>,
+    arms: &[Arm<'_>],
+) {
+    if let [arm, wild_arm] = arms
+        && arm.guard.is_none()
+        && let PatKind::Wild = wild_arm.pat.kind
+        && let Some(macro_call) = macro_backtrace(expr.span).next()
+        && cx.tcx.is_diagnostic_item(sym::matches_macro, macro_call.def_id)
+        && !macro_call.span.from_expansion()
+    {
+        // also look inside refs
+        let check_pat = match arm.pat.kind {
+            PatKind::Ref(inner, _mutability) => inner,
+            _ => arm.pat,
+        };
+        let op_ty = cx.typeck_results().expr_ty(op);
+        let Some((good_method, _)) = find_method_and_type(cx, check_pat, op_ty) else { return };
+
+        let result_expr = match &op.kind {
+            ExprKind::AddrOf(_, _, borrowed) => borrowed,
+            _ => op,
+        };
+        let mut app = Applicability::MachineApplicable;
+        let sugg = Sugg::hir_with_context(cx, result_expr, macro_call.span.ctxt(), "_", &mut app).maybe_par();
+
+        span_lint_and_sugg(
+            cx,
+            REDUNDANT_PATTERN_MATCHING_IN_MATCHES_MACRO,
+            macro_call.span,
+            &format!("redundant pattern matching, consider using `{good_method}`"),
+            "try this",
+            format!("{sugg}.{good_method}"),
+            app,
+        );
+    }
+}
+
 pub(super) fn check_match<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, op: &Expr<'_>, arms: &[Arm<'_>]) {
     if arms.len() == 2 {
         let node_pair = (&arms[0].pat.kind, &arms[1].pat.kind);
@@ -1,6 +1,6 @@
 use super::{contains_return, BIND_INSTEAD_OF_MAP};
 use clippy_utils::diagnostics::{multispan_sugg_with_applicability, span_lint_and_sugg, span_lint_and_then};
-use clippy_utils::source::{snippet, snippet_with_macro_callsite};
+use clippy_utils::source::{snippet, snippet_with_applicability, snippet_with_macro_callsite};
 use clippy_utils::{peel_blocks, visitors::find_all_ret_expressions};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -162,14 +162,15 @@ fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>, arg:
             // `_.and_then(Some)` case, which is no-op.
             hir::ExprKind::Path(QPath::Resolved(_, path)) if Self::is_variant(cx, path.res) => {
                 if let Some(msg) = Self::no_op_msg(cx) {
+                    let mut app = Applicability::MachineApplicable;
                     span_lint_and_sugg(
                         cx,
                         BIND_INSTEAD_OF_MAP,
                         expr.span,
                         &msg,
                         "use the expression directly",
-                        snippet(cx, recv.span, "..").into(),
-                        Applicability::MachineApplicable,
+                        snippet_with_applicability(cx, recv.span, "..", &mut app).into(),
+                        app,
                     );
                 }
                 true
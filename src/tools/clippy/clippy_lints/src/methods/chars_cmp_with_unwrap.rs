@@ -1,5 +1,5 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::method_chain_args;
+use clippy_utils::match_method_chain;
 use clippy_utils::source::snippet_with_applicability;
 use if_chain::if_chain;
 use rustc_ast::ast;
@@ -9,15 +9,15 @@
 use rustc_lint::Lint;
 
 /// Wrapper fn for `CHARS_NEXT_CMP` and `CHARS_LAST_CMP` lints with `unwrap()`.
-pub(super) fn check(
+pub(super) fn check<const N: usize>(
     cx: &LateContext<'_>,
     info: &crate::methods::BinaryExprInfo<'_>,
-    chain_methods: &[&str],
+    chain_methods: [&str; N],
     lint: &'static Lint,
     suggest: &str,
 ) -> bool {
     if_chain! {
-        if let Some(args) = method_chain_args(info.chain, chain_methods);
+        if let Some(chars) = match_method_chain(info.chain, chain_methods).and_then(|links| links.first().copied());
         if let hir::ExprKind::Lit(ref lit) = info.other.kind;
         if let ast::LitKind::Char(c) = lit.node;
         then {
@@ -30,7 +30,7 @@ pub(super) fn check(
                 "like this",
                 format!("{}{}.{suggest}('{}')",
                         if info.eq { "" } else { "!" },
-                        snippet_with_applicability(cx, args[0].0.span, "..", &mut applicability),
+                        snippet_with_applicability(cx, chars.receiver.span, "..", &mut applicability),
                         c.escape_default()),
                 applicability,
             );
@@ -0,0 +1,166 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::{root_macro_call_first_node, FormatArgsExpn};
+use clippy_utils::paths::{self, PathLookup};
+use clippy_utils::source::{snippet, snippet_with_context};
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::COMMAND_ARG_SPACE_SPLIT;
+
+static COMMAND_NEW: PathLookup = PathLookup::new(&["std", "process", "Command", "new"]);
+
+/// lint for `command.args(s.split(' '))`
+pub(super) fn check_args<'tcx>(cx: &LateContext<'tcx>, recv: &'tcx Expr<'_>, arg: &'tcx Expr<'_>) {
+    let ty = cx.typeck_results().expr_ty(recv).peel_refs();
+
+    if paths::STD_PROCESS_COMMAND.matches_ty(cx, ty)
+        && let ExprKind::MethodCall(name, split_recv, [pat], _) = arg.kind
+        && name.ident.as_str() == "split"
+        && is_space(pat)
+    {
+        span_lint_and_then(
+            cx,
+            COMMAND_ARG_SPACE_SPLIT,
+            arg.span,
+            "splitting the arguments of a command on spaces",
+            |diag| {
+                if let Some(s) = str_lit(split_recv) {
+                    diag.span_suggestion(
+                        arg.span,
+                        "pass the arguments separately",
+                        format!("{:?}", words(&s)),
+                        Applicability::MaybeIncorrect,
+                    );
+                } else {
+                    diag.note("arguments which contain spaces themselves are split as well");
+                    diag.help("pass each argument separately");
+                }
+            },
+        );
+    }
+}
+
+/// lint for `Command::new("ls -l")` and `Command::new(format!("{bin} {flag}"))`
+pub(super) fn check_new<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    func: &Expr<'_>,
+    args: &'tcx [Expr<'tcx>],
+) {
+    let [program] = args else {
+        return;
+    };
+    if !COMMAND_NEW.matches_path(cx, func) {
+        return;
+    }
+    let words = if let Some(s) = str_lit(program) {
+        // Paths to programs may contain spaces, e.g. `C:\Program Files\..`
+        if !s.contains(' ') || s.contains(['\\', '/']) {
+            return;
+        }
+        words(&s).iter().map(|word| format!("{word:?}")).collect()
+    } else if let Some(words) = format_words(cx, program) {
+        words
+    } else {
+        return;
+    };
+    let [program_word, arg_words @ ..] = &*words else {
+        return;
+    };
+    if arg_words.is_empty() {
+        return;
+    }
+
+    span_lint_and_then(
+        cx,
+        COMMAND_ARG_SPACE_SPLIT,
+        expr.span,
+        "the program of a command contains spaces",
+        |diag| {
+            let args: String = arg_words.iter().map(|word| format!(".arg({word})")).collect();
+            diag.span_suggestion(
+                expr.span,
+                "pass the arguments with `arg`",
+                format!("{}({program_word}){args}", snippet(cx, func.span, "..")),
+                Applicability::MaybeIncorrect,
+            );
+        },
+    );
+}
+
+/// Splits the string formatted by the `format!` call `expr` at its spaces, returning the words as
+/// the snippets of the arguments or as string literals. Returns `None` if a word is made of both
+/// text and an argument, or if no space separates two words.
+fn format_words<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<Vec<String>> {
+    let expr = match expr.kind {
+        ExprKind::AddrOf(_, _, inner) => inner,
+        _ => expr,
+    };
+    let macro_call = root_macro_call_first_node(cx, expr)?;
+    if !cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id) {
+        return None;
+    }
+    let format_args = FormatArgsExpn::find_nested(cx, expr, macro_call.expn)?;
+
+    let mut words = Vec::new();
+    // Whether the last word may continue, i.e. wasn't followed by a space yet
+    let mut open = false;
+    let parts = &format_args.format_string.parts;
+    for i in 0..parts.len().max(format_args.args.len()) {
+        if let Some(part) = parts.get(i) {
+            let part = part.as_str();
+            for (j, word) in part.split(' ').enumerate() {
+                if j > 0 {
+                    open = false;
+                }
+                if word.is_empty() {
+                    continue;
+                }
+                if open {
+                    return None;
+                }
+                words.push(format!("{word:?}"));
+                open = true;
+            }
+        }
+        if let Some(arg) = format_args.args.get(i) {
+            if open || !arg.format.is_default() {
+                return None;
+            }
+            let ctxt = macro_call.span.ctxt();
+            let value = snippet_with_context(cx, arg.param.value.span, ctxt, "..", &mut Applicability::Unspecified).0;
+            words.push(value.into_owned());
+            open = true;
+        }
+    }
+    Some(words)
+}
+
+fn is_space(pat: &Expr<'_>) -> bool {
+    if let ExprKind::Lit(lit) = &pat.kind {
+        match lit.node {
+            LitKind::Char(c) => c == ' ',
+            LitKind::Str(s, _) => s.as_str() == " ",
+            _ => false,
+        }
+    } else {
+        false
+    }
+}
+
+fn str_lit(expr: &Expr<'_>) -> Option<String> {
+    if let ExprKind::Lit(lit) = &expr.kind
+        && let LitKind::Str(s, _) = lit.node
+    {
+        Some(s.as_str().to_owned())
+    } else {
+        None
+    }
+}
+
+fn words(s: &str) -> Vec<&str> {
+    s.split(' ').filter(|word| !word.is_empty()).collect()
+}
@@ -0,0 +1,77 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::eager_or_lazy::switch_to_lazy_eval;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{contains_return, is_default_equivalent, is_default_equivalent_call};
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span};
+
+use super::ENTRY_OR_INSERT_WITH_DEFAULT;
+
+/// Checks `entry.or_insert(..)` and `entry.or_insert_with(..)`. `name` is the method name.
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    name: &str,
+    recv: &'tcx Expr<'_>,
+    arg: &'tcx Expr<'_>,
+    method_span: Span,
+) {
+    let recv_ty = cx.typeck_results().expr_ty(recv);
+    if !is_type_diagnostic_item(cx, recv_ty, sym::HashMapEntry)
+        && !is_type_diagnostic_item(cx, recv_ty, sym::BTreeEntry)
+    {
+        return;
+    }
+    let span = method_span.with_hi(expr.span.hi());
+
+    let inserts_default = match name {
+        "or_insert" => matches!(arg.kind, ExprKind::Call(..)) && is_default_equivalent(cx, arg),
+        "or_insert_with" => is_default_equivalent_call(cx, arg) || is_default_closure(cx, arg),
+        _ => return,
+    };
+    if inserts_default {
+        span_lint_and_sugg(
+            cx,
+            ENTRY_OR_INSERT_WITH_DEFAULT,
+            span,
+            &format!("use of `{name}` to insert the default value"),
+            "try this",
+            "or_default()".to_owned(),
+            Applicability::MachineApplicable,
+        );
+    } else if name == "or_insert"
+        && let ExprKind::Call(fun, fun_args) = arg.kind
+        && switch_to_lazy_eval(cx, arg)
+        && !contains_return(arg)
+    {
+        let mut app = Applicability::MaybeIncorrect;
+        let ctxt = expr.span.ctxt();
+        let sugg = if fun_args.is_empty() {
+            snippet_with_context(cx, fun.span, ctxt, "..", &mut app).0.into_owned()
+        } else {
+            format!("|| {}", snippet_with_context(cx, arg.span, ctxt, "..", &mut app).0)
+        };
+        span_lint_and_sugg(
+            cx,
+            ENTRY_OR_INSERT_WITH_DEFAULT,
+            span,
+            "use of `or_insert` followed by a function call",
+            "try this",
+            format!("or_insert_with({sugg})"),
+            app,
+        );
+    }
+}
+
+/// Checks for `|| T::default()` and equivalents.
+fn is_default_closure(cx: &LateContext<'_>, arg: &Expr<'_>) -> bool {
+    if let ExprKind::Closure(&Closure { body, .. }) = arg.kind {
+        let body = cx.tcx.hir().body(body);
+        body.params.is_empty() && is_default_equivalent(cx, body.value)
+    } else {
+        false
+    }
+}
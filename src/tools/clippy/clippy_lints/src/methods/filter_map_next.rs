@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg};
 use clippy_utils::is_trait_method;
 use clippy_utils::msrvs::{self, Msrv};
-use clippy_utils::source::snippet;
+use clippy_utils::source::snippet_with_applicability;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_lint::LateContext;
@@ -23,9 +23,10 @@ pub(super) fn check<'tcx>(
 
         let msg = "called `filter_map(..).next()` on an `Iterator`. This is more succinctly expressed by calling \
                    `.find_map(..)` instead";
-        let filter_snippet = snippet(cx, arg.span, "..");
+        let mut app = Applicability::MachineApplicable;
+        let filter_snippet = snippet_with_applicability(cx, arg.span, "..", &mut app);
         if filter_snippet.lines().count() <= 1 {
-            let iter_snippet = snippet(cx, recv.span, "..");
+            let iter_snippet = snippet_with_applicability(cx, recv.span, "..", &mut app);
             span_lint_and_sugg(
                 cx,
                 FILTER_MAP_NEXT,
@@ -33,7 +34,7 @@ pub(super) fn check<'tcx>(
                 msg,
                 "try this",
                 format!("{iter_snippet}.find_map({filter_snippet})"),
-                Applicability::MachineApplicable,
+                app,
             );
         } else {
             span_lint(cx, FILTER_MAP_NEXT, expr.span, msg);
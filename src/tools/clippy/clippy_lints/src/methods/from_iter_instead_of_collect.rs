@@ -1,7 +1,8 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::paths::PathLookup;
 use clippy_utils::source::snippet_opt;
-use clippy_utils::ty::implements_trait;
-use clippy_utils::{is_expr_path_def_path, paths, sugg};
+use clippy_utils::sugg;
+use clippy_utils::ty::ImplementsTraitCache;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
@@ -11,14 +12,23 @@
 
 use super::FROM_ITER_INSTEAD_OF_COLLECT;
 
-pub(super) fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, args: &[hir::Expr<'_>], func: &hir::Expr<'_>) {
+static FROM_ITERATOR_METHOD: PathLookup =
+    PathLookup::new(&["core", "iter", "traits", "collect", "FromIterator", "from_iter"]);
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
+    expr: &hir::Expr<'_>,
+    args: &[hir::Expr<'_>],
+    func: &hir::Expr<'_>,
+) {
     if_chain! {
-        if is_expr_path_def_path(cx, func, &paths::FROM_ITERATOR_METHOD);
+        if FROM_ITERATOR_METHOD.matches_path(cx, func);
         let ty = cx.typeck_results().expr_ty(expr);
         let arg_ty = cx.typeck_results().expr_ty(&args[0]);
         if let Some(iter_id) = cx.tcx.get_diagnostic_item(sym::Iterator);
 
-        if implements_trait(cx, arg_ty, iter_id, &[]);
+        if trait_cache.implements_trait(cx, arg_ty, iter_id, &[]);
         then {
             // `expr` implements `FromIterator` trait
             let iter_expr = sugg::Sugg::hir(cx, &args[0], "..").maybe_par();
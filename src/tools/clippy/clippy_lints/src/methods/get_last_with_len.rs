@@ -1,4 +1,5 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::eval_order::is_expr_pure;
 use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::{is_integer_literal, SpanlessEq};
 use rustc_errors::Applicability;
@@ -10,7 +11,7 @@
 
 use super::GET_LAST_WITH_LEN;
 
-pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<'_>) {
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>, recv: &'tcx Expr<'_>, arg: &'tcx Expr<'_>) {
     // Argument to "get" is a subtraction
     if let ExprKind::Binary(
         Spanned {
@@ -29,7 +30,7 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg:
 
         // check that recv == lhs_recv `recv.get(lhs_recv.len() - 1)`
         && SpanlessEq::new(cx).eq_expr(recv, lhs_recv)
-        && !recv.can_have_side_effects()
+        && is_expr_pure(cx, recv)
     {
         let method = match cx.typeck_results().expr_ty_adjusted(recv).peel_refs().kind() {
             ty::Adt(def, _) if cx.tcx.is_diagnostic_item(sym::VecDeque, def.did()) => "back",
@@ -1,6 +1,6 @@
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::is_trait_method;
+use clippy_utils::{is_lint_allowed, is_trait_method};
 use clippy_utils::source::snippet_with_applicability;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -9,11 +9,14 @@
 use rustc_span::sym;
 
 use super::ITER_NTH_ZERO;
+use crate::env_args::{args_call, ARGS_POSITIONAL_SKIP};
 
 pub(super) fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, recv: &hir::Expr<'_>, arg: &hir::Expr<'_>) {
     if_chain! {
         if is_trait_method(cx, expr, sym::Iterator);
         if let Some((Constant::Int(0), _)) = constant(cx, cx.typeck_results(), arg);
+        // `args_positional_skip` explains that the first command line argument is the executable
+        if args_call(cx, recv).is_none() || is_lint_allowed(cx, ARGS_POSITIONAL_SKIP, expr.hir_id);
         then {
             let mut applicability = Applicability::MachineApplicable;
             span_lint_and_sugg(
@@ -1,6 +1,6 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::snippet_opt;
-use clippy_utils::ty::{implements_trait, is_copy};
+use clippy_utils::ty::{is_copy, ImplementsTraitCache};
 use rustc_errors::Applicability;
 use rustc_hir::Expr;
 use rustc_lint::LateContext;
@@ -12,6 +12,7 @@
 
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
     expr: &'tcx Expr<'_>,
     cloned_call: &'tcx Expr<'_>,
     cloned_recv: &'tcx Expr<'_>,
@@ -30,7 +31,7 @@ pub(super) fn check<'tcx>(
     {
         if needs_into_iter
             && let Some(into_iter_id) = cx.tcx.get_diagnostic_item(sym::IntoIterator)
-            && !implements_trait(cx, iter_assoc_ty, into_iter_id, &[])
+            && !trait_cache.implements_trait(cx, iter_assoc_ty, into_iter_id, &[])
         {
             return;
         }
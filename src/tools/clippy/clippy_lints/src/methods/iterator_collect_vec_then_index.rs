@@ -0,0 +1,173 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::{is_copy, is_type_diagnostic_item};
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{get_parent_expr, is_trait_method};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::{sym, Span};
+
+use super::ITERATOR_COLLECT_VEC_THEN_INDEX;
+
+/// Checks `iter.collect::<Vec<_>>().get(i)`, `.first()` and `.last()`. `name` is the name of the
+/// method called on the collected vector.
+pub(super) fn check_method<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    name: &str,
+    collect_expr: &'tcx Expr<'tcx>,
+    arg: Option<&'tcx Expr<'_>>,
+) {
+    let Some((collect_span, iter)) = collected_vec(cx, collect_expr) else {
+        return;
+    };
+    let mut app = Applicability::MachineApplicable;
+    let (sugg, consumes_all) = match (name, arg) {
+        ("get", Some(arg)) if cx.typeck_results().expr_ty(arg) == cx.tcx.types.usize => (
+            format!(
+                "nth({})",
+                snippet_with_context(cx, arg.span, expr.span.ctxt(), "..", &mut app).0
+            ),
+            false,
+        ),
+        ("first", None) => ("next()".to_owned(), false),
+        ("last", None) => ("last()".to_owned(), true),
+        _ => return,
+    };
+    // The iterator returns the element by value, which only matches `.get(i).copied()`
+    let copied = get_parent_expr(cx, expr).filter(|parent| {
+        matches!(
+            parent.kind,
+            ExprKind::MethodCall(path, _, [], _) if matches!(path.ident.as_str(), "copied" | "cloned")
+        )
+    });
+    emit(
+        cx,
+        collect_span.with_hi(copied.unwrap_or(expr).span.hi()),
+        &format!("collecting into a `Vec` only to call `{name}` on it"),
+        &sugg,
+        copied.is_some() && (consumes_all || !has_side_effects(cx, iter)),
+        app,
+    );
+}
+
+/// Checks `iter.collect::<Vec<_>>()[i]`.
+pub(super) fn check_index<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    collect_expr: &'tcx Expr<'tcx>,
+    index: &'tcx Expr<'_>,
+) {
+    if let Some((collect_span, iter)) = collected_vec(cx, collect_expr)
+        && cx.typeck_results().expr_ty(index) == cx.tcx.types.usize
+    {
+        let mut app = Applicability::MachineApplicable;
+        let index = snippet_with_context(cx, index.span, expr.span.ctxt(), "..", &mut app).0;
+        // The element is only copied out of the vector if it's `Copy` and not borrowed
+        let by_value = is_copy(cx, cx.typeck_results().expr_ty(expr))
+            && !get_parent_expr(cx, expr).map_or(false, |parent| matches!(parent.kind, ExprKind::AddrOf(..)));
+        emit(
+            cx,
+            collect_span.with_hi(expr.span.hi()),
+            "collecting into a `Vec` only to index it",
+            &format!("nth({index}).unwrap()"),
+            by_value && !has_side_effects(cx, iter),
+            app,
+        );
+    }
+}
+
+/// Emits the lint, with a suggestion only if `suggest` is set, i.e. if the suggestion keeps the
+/// type of the expression and doesn't skip any side effects of the iterator.
+fn emit(cx: &LateContext<'_>, span: Span, msg: &str, sugg: &str, suggest: bool, app: Applicability) {
+    if suggest {
+        span_lint_and_sugg(
+            cx,
+            ITERATOR_COLLECT_VEC_THEN_INDEX,
+            span,
+            msg,
+            "take the element from the iterator instead",
+            sugg.to_owned(),
+            app,
+        );
+    } else {
+        span_lint_and_help(
+            cx,
+            ITERATOR_COLLECT_VEC_THEN_INDEX,
+            span,
+            msg,
+            None,
+            &format!("take the element from the iterator with `{sugg}` instead"),
+        );
+    }
+}
+
+/// If `expr` is a call to `Iterator::collect` creating a `Vec`, returns the span of the method
+/// name and the collected iterator.
+///
+/// The vector is a temporary here, so nothing else can use it.
+fn collected_vec<'tcx>(cx: &LateContext<'_>, expr: &'tcx Expr<'tcx>) -> Option<(Span, &'tcx Expr<'tcx>)> {
+    if let ExprKind::MethodCall(path, recv, [], _) = expr.kind
+        && path.ident.name.as_str() == "collect"
+        && !recv.span.from_expansion()
+        && is_trait_method(cx, expr, sym::Iterator)
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::Vec)
+    {
+        Some((path.ident.span, recv))
+    } else {
+        None
+    }
+}
+
+/// Checks if the adapters of the iterator might have side effects, which are skipped if the
+/// iterator isn't run to the end. Only closures without calls or assignments are known not to.
+fn has_side_effects<'tcx>(cx: &LateContext<'tcx>, mut iter: &'tcx Expr<'tcx>) -> bool {
+    while let ExprKind::MethodCall(_, recv, args, _) = iter.kind {
+        for arg in args {
+            match arg.kind {
+                ExprKind::Closure(&Closure { body, .. }) => {
+                    if closure_has_side_effects(cx, cx.tcx.hir().body(body).value) {
+                        return true;
+                    }
+                },
+                _ if matches!(
+                    cx.typeck_results().expr_ty(arg).kind(),
+                    ty::FnDef(..) | ty::FnPtr(_) | ty::Closure(..)
+                ) =>
+                {
+                    return true;
+                },
+                _ => {},
+            }
+        }
+        iter = recv;
+    }
+    false
+}
+
+fn closure_has_side_effects<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Expr<'tcx>) -> bool {
+    let typeck = cx.typeck_results();
+    for_each_expr(body, |e| match e.kind {
+        ExprKind::Call(..)
+        | ExprKind::MethodCall(..)
+        | ExprKind::Assign(..)
+        | ExprKind::AssignOp(..)
+        | ExprKind::InlineAsm(_) => ControlFlow::Break(()),
+        // Overloaded operators call arbitrary code, except for the ones on references to primitives
+        ExprKind::Binary(_, lhs, rhs)
+            if typeck.is_method_call(e)
+                && !(typeck.expr_ty(lhs).peel_refs().is_primitive()
+                    && typeck.expr_ty(rhs).peel_refs().is_primitive()) =>
+        {
+            ControlFlow::Break(())
+        },
+        ExprKind::Unary(_, inner) if typeck.is_method_call(e) && !typeck.expr_ty(inner).peel_refs().is_primitive() => {
+            ControlFlow::Break(())
+        },
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+}
@@ -1,11 +1,14 @@
 use clippy_utils::consts::{constant, Constant};
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::is_trait_method;
+use clippy_utils::diagnostics::{span_lint, span_lint_and_note};
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{is_trait_method, path_to_local, path_to_local_id};
+use core::ops::ControlFlow;
 use rustc_hir as hir;
+use rustc_hir::{ExprKind, HirId, PatKind};
 use rustc_lint::LateContext;
 use rustc_span::sym;
 
-use super::ITERATOR_STEP_BY_ZERO;
+use super::{ITERATOR_STEP_BY_ZERO, ITERATOR_STEP_BY_ZERO_RUNTIME};
 
 pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &hir::Expr<'_>, arg: &'tcx hir::Expr<'_>) {
     if is_trait_method(cx, expr, sym::Iterator) {
@@ -16,6 +19,48 @@ pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &hir::Expr<'_>, arg: &'t
                 expr.span,
                 "`Iterator::step_by(0)` will panic at runtime",
             );
+        } else if let Some(id) = path_to_local(arg)
+            && let Some(body_id) = cx.enclosing_body
+            && let body = cx.tcx.hir().body(body_id)
+            && let Some(param) = body
+                .params
+                .iter()
+                .find(|param| matches!(param.pat.kind, PatKind::Binding(_, param_id, _, None) if param_id == id))
+            && !is_guarded(body.value, id)
+        {
+            span_lint_and_note(
+                cx,
+                ITERATOR_STEP_BY_ZERO_RUNTIME,
+                expr.span,
+                "`Iterator::step_by` is called with a step that is never checked against zero",
+                Some(param.span),
+                "`step_by` will panic at runtime if this parameter is zero",
+            );
         }
     }
 }
+
+/// Checks if the local is compared against something, or matched on, anywhere in the body.
+fn is_guarded<'tcx>(body: &'tcx hir::Expr<'tcx>, id: HirId) -> bool {
+    let is_local = |e: &hir::Expr<'_>| {
+        let mut e = e;
+        while let ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(hir::UnOp::Deref, inner) = e.kind {
+            e = inner;
+        }
+        path_to_local_id(e, id)
+    };
+
+    for_each_expr(body, |e| match e.kind {
+        ExprKind::Binary(op, lhs, rhs) if op.node.is_comparison() && (is_local(lhs) || is_local(rhs)) => {
+            ControlFlow::Break(())
+        },
+        // `assert_eq!` and friends match on a tuple of references to their arguments
+        ExprKind::Match(scrutinee, ..) | ExprKind::Let(&hir::Let { init: scrutinee, .. })
+            if is_local(scrutinee) || matches!(scrutinee.kind, ExprKind::Tup(elems) if elems.iter().any(is_local)) =>
+        {
+            ControlFlow::Break(())
+        },
+        _ => ControlFlow::Continue(()),
+    })
+    .is_some()
+}
@@ -0,0 +1,63 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::msrvs::Msrv;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{is_item_usable, path_to_local_id};
+use rustc_errors::Applicability;
+use rustc_hir::{BindingAnnotation, CaptureBy, Closure, Expr, ExprKind, PatKind, StmtKind};
+use rustc_lint::LateContext;
+use rustc_span::{sym, Span, Symbol};
+
+use super::MANUAL_RESULT_INSPECT;
+
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    recv: &Expr<'_>,
+    map_arg: &Expr<'_>,
+    name: &str,
+    name_span: Span,
+    msrv: &Msrv,
+) {
+    let replacement = if name == "map" { "inspect" } else { "inspect_err" };
+    if is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(recv), sym::Result)
+        && let ExprKind::Closure(&Closure {
+            capture_clause, body, ..
+        }) = map_arg.kind
+        && let closure_body = cx.tcx.hir().body(body)
+        && let [param] = closure_body.params
+        && let PatKind::Binding(BindingAnnotation::NONE, id, ident, None) = param.pat.kind
+        && let ExprKind::Block(block, None) = closure_body.value.kind
+        && let [stmt] = block.stmts
+        && let StmtKind::Semi(effect) | StmtKind::Expr(effect) = stmt.kind
+        && let Some(ret) = block.expr
+        && path_to_local_id(ret, id)
+        && let ctxt = expr.span.ctxt()
+        && closure_body.value.span.ctxt() == ctxt
+        && let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+        && let Some(impl_id) = cx.tcx.impl_of_method(method_id)
+        && let Some(inspect) = cx
+            .tcx
+            .associated_items(impl_id)
+            .filter_by_name_unhygienic(Symbol::intern(replacement))
+            .next()
+        && is_item_usable(cx, inspect.def_id, msrv)
+    {
+        let mut app = Applicability::MaybeIncorrect;
+        let (effect, _) = snippet_with_context(cx, effect.span, ctxt, "..", &mut app);
+        let capture = if capture_clause == CaptureBy::Value {
+            "move "
+        } else {
+            ""
+        };
+        span_lint_and_sugg(
+            cx,
+            MANUAL_RESULT_INSPECT,
+            name_span.with_hi(expr.span.hi()),
+            &format!("manual implementation of `Result::{replacement}`"),
+            &format!("use `{replacement}` instead"),
+            format!("{replacement}({capture}|{ident}| {effect})"),
+            app,
+        );
+    }
+}
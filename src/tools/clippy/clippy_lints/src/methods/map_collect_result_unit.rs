@@ -1,5 +1,5 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::source::snippet;
+use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::is_type_diagnostic_item;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -20,6 +20,7 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, iter: &hir::Expr
         if result_t.is_unit();
         // get parts for snippet
         then {
+            let mut app = Applicability::MachineApplicable;
             span_lint_and_sugg(
                 cx,
                 MAP_COLLECT_RESULT_UNIT,
@@ -28,10 +29,10 @@ pub(super) fn check(cx: &LateContext<'_>, expr: &hir::Expr<'_>, iter: &hir::Expr
                 "try this",
                 format!(
                     "{}.try_for_each({})",
-                    snippet(cx, iter.span, ".."),
-                    snippet(cx, map_fn.span, "..")
+                    snippet_with_applicability(cx, iter.span, "..", &mut app),
+                    snippet_with_applicability(cx, map_fn.span, "..", &mut app)
                 ),
-                Applicability::MachineApplicable,
+                app,
             );
         }
     }
@@ -1,6 +1,6 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg};
 use clippy_utils::msrvs::{self, Msrv};
-use clippy_utils::source::snippet;
+use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::usage::mutated_variables;
 use rustc_errors::Applicability;
@@ -50,14 +50,15 @@ pub(super) fn check<'tcx>(
             `.map_or_else(<g>, <f>)` instead"
         };
         // get snippets for args to map() and unwrap_or_else()
-        let map_snippet = snippet(cx, map_arg.span, "..");
-        let unwrap_snippet = snippet(cx, unwrap_arg.span, "..");
+        let mut app = Applicability::MachineApplicable;
+        let map_snippet = snippet_with_applicability(cx, map_arg.span, "..", &mut app);
+        let unwrap_snippet = snippet_with_applicability(cx, unwrap_arg.span, "..", &mut app);
         // lint, with note if neither arg is > 1 line and both map() and
         // unwrap_or_else() have the same span
         let multiline = map_snippet.lines().count() > 1 || unwrap_snippet.lines().count() > 1;
         let same_span = map_arg.span.ctxt() == unwrap_arg.span.ctxt();
         if same_span && !multiline {
-            let var_snippet = snippet(cx, recv.span, "..");
+            let var_snippet = snippet_with_applicability(cx, recv.span, "..", &mut app);
             span_lint_and_sugg(
                 cx,
                 MAP_UNWRAP_OR,
@@ -65,7 +66,7 @@ pub(super) fn check<'tcx>(
                 msg,
                 "try this",
                 format!("{var_snippet}.map_or_else({unwrap_snippet}, {map_snippet})"),
-                Applicability::MachineApplicable,
+                app,
             );
             return true;
         } else if same_span && multiline {
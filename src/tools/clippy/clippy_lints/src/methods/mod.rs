@@ -13,6 +13,8 @@
 mod clone_on_ref_ptr;
 mod cloned_instead_of_copied;
 mod collapsible_str_replace;
+mod command_arg_space_split;
+mod entry_or_insert_with_default;
 mod err_expect;
 mod expect_fun_call;
 mod expect_used;
@@ -43,8 +45,10 @@
 mod iter_overeager_cloned;
 mod iter_skip_next;
 mod iter_with_drain;
+mod iterator_collect_vec_then_index;
 mod iterator_step_by_zero;
 mod manual_ok_or;
+mod manual_result_inspect;
 mod manual_saturating_arithmetic;
 mod manual_str_repeat;
 mod map_clone;
@@ -66,17 +70,22 @@
 mod option_map_unwrap_or;
 mod or_fun_call;
 mod or_then_unwrap;
+mod parse_then_unwrap_radix_suggestion;
 mod path_buf_push_overwrite;
 mod range_zip_with_len;
+mod read_line_without_trim;
 mod repeat_once;
 mod search_is_some;
 mod seek_from_current;
 mod seek_to_start_instead_of_rewind;
+mod single_char_add_assign;
 mod single_char_add_str;
+mod single_char_format;
 mod single_char_insert_string;
 mod single_char_pattern;
 mod single_char_push_string;
 mod skip_while_next;
+mod split_whitespace_manual;
 mod stable_sort_primitive;
 mod str_splitn;
 mod string_extend_chars;
@@ -84,6 +93,8 @@
 mod suspicious_map;
 mod suspicious_splitn;
 mod suspicious_to_owned;
+mod to_vec_then_into_iter_by_value;
+mod try_into_unwrap_on_provably_fitting_values;
 mod uninit_assumed_init;
 mod unit_hash;
 mod unnecessary_filter_map;
@@ -106,7 +117,9 @@
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
 use clippy_utils::msrvs::{self, Msrv};
-use clippy_utils::ty::{contains_ty_adt_constructor_opaque, implements_trait, is_copy, is_type_diagnostic_item};
+use clippy_utils::ty::{
+    contains_ty_adt_constructor_opaque, implements_trait, is_copy, is_type_diagnostic_item, ImplementsTraitCache,
+};
 use clippy_utils::{contains_return, is_bool, is_trait_method, iter_input_pats, return_ty};
 use if_chain::if_chain;
 use rustc_hir as hir;
@@ -1121,7 +1134,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for calling `.step_by(0)` on iterators which panics.
+    /// Checks for calling `.step_by(0)` on iterators which panics. Steps that are constants
+    /// evaluating to zero, like `usize::MIN` or a cast enum discriminant, are caught as well.
     ///
     /// ### Why is this bad?
     /// This very much looks like an oversight. Use `panic!()` instead if you
@@ -1786,21 +1800,31 @@
     /// Warns when using `push_str`/`insert_str` with a single-character string literal
     /// where `push`/`insert` with a `char` would work fine.
     ///
+    /// Also warns on `s += &c.to_string()` and `format!("{a}{b}")` where `c`, `a` and `b`
+    /// are `char`s.
+    ///
     /// ### Why is this bad?
-    /// It's less clear that we are pushing a single character.
+    /// It's less clear that we are pushing a single character. Converting a `char` to a
+    /// `String` first also allocates needlessly.
     ///
     /// ### Example
     /// ```rust
     /// # let mut string = String::new();
+    /// # let (a, b) = ('a', 'b');
     /// string.insert_str(0, "R");
     /// string.push_str("R");
+    /// string += &a.to_string();
+    /// let joined = format!("{a}{b}");
     /// ```
     ///
     /// Use instead:
     /// ```rust
     /// # let mut string = String::new();
+    /// # let (a, b) = ('a', 'b');
     /// string.insert(0, 'R');
     /// string.push('R');
+    /// string.push(a);
+    /// let joined = String::from_iter([a, b]);
     /// ```
     #[clippy::version = "1.49.0"]
     pub SINGLE_CHAR_ADD_STR,
@@ -3190,14 +3214,309 @@
     "single command line argument that looks like it should be multiple arguments"
 }
 
-pub struct Methods {
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `map` and `map_err` calls on `Result` whose closure only runs a side effect
+    /// and then returns its argument unchanged.
+    ///
+    /// ### Why is this bad?
+    /// `Result::inspect` and `Result::inspect_err` express this directly, without having to
+    /// take the value and pass it back.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let value = "42".parse::<u32>().map_err(|e| {
+    ///     eprintln!("failed to parse: {e}");
+    ///     e
+    /// });
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let value = "42".parse::<u32>().inspect_err(|e| eprintln!("failed to parse: {e}"));
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub MANUAL_RESULT_INSPECT,
+    complexity,
+    "using `map` or `map_err` on a `Result` to only run a side effect"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calling `.step_by(step)` on iterators, where `step` is a function parameter
+    /// that is never compared against anything or matched on.
+    ///
+    /// ### Why is this bad?
+    /// `step_by` panics if the step is zero. When the step is passed in by the caller, nothing
+    /// prevents it from being zero.
+    ///
+    /// ### Known problems
+    /// Any comparison of the parameter is considered a guard, even if it doesn't rule out zero.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn every_nth(values: &[u32], n: usize) -> Vec<u32> {
+    ///     values.iter().copied().step_by(n).collect()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn every_nth(values: &[u32], n: usize) -> Vec<u32> {
+    ///     assert!(n > 0, "`n` must not be zero");
+    ///     values.iter().copied().step_by(n).collect()
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub ITERATOR_STEP_BY_ZERO_RUNTIME,
+    pedantic,
+    "using `Iterator::step_by` with a step passed in by the caller that is never checked against zero"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for collecting an iterator into a `Vec` only to index it, or to call `get`,
+    /// `first` or `last` on it.
+    ///
+    /// ### Why is this bad?
+    /// The vector is allocated and filled only to take a single element out of it. `nth`, `next`
+    /// and `last` take the element directly from the iterator.
+    ///
+    /// ### Known problems
+    /// Unlike the vector, `nth` and `next` don't run the iterator past the element, and they return
+    /// the element by value instead of a reference to it. A fix is only suggested if neither makes
+    /// a difference, e.g. for `.get(i).copied()` on an iterator whose closures don't call anything.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let names = ["a", "b", "c"];
+    /// let second = names.iter().map(|s| s.len()).collect::<Vec<_>>()[1];
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let names = ["a", "b", "c"];
+    /// let second = names.iter().map(|s| s.len()).nth(1).unwrap();
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub ITERATOR_COLLECT_VEC_THEN_INDEX,
+    perf,
+    "collecting an iterator into a `Vec` only to take a single element out of it"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `or_insert` and `or_insert_with` calls on a map `Entry` which insert the default
+    /// value, or which call a function eagerly. Unlike `or_fun_call`, this also covers
+    /// `or_insert_with` calls which produce the default value.
+    ///
+    /// ### Why is this bad?
+    /// `or_default` is shorter and states the intent. A function call passed to `or_insert` is
+    /// evaluated even if the key is already present, `or_insert_with` only calls it when needed.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # fn expensive() -> u32 { 0 }
+    /// let mut map: HashMap<u32, Vec<u32>> = HashMap::new();
+    /// map.entry(0).or_insert(Vec::new()).push(1);
+    /// let mut counts: HashMap<u32, u32> = HashMap::new();
+    /// *counts.entry(0).or_insert(expensive()) += 1;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::collections::HashMap;
+    /// # fn expensive() -> u32 { 0 }
+    /// let mut map: HashMap<u32, Vec<u32>> = HashMap::new();
+    /// map.entry(0).or_default().push(1);
+    /// let mut counts: HashMap<u32, u32> = HashMap::new();
+    /// *counts.entry(0).or_insert_with(expensive) += 1;
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub ENTRY_OR_INSERT_WITH_DEFAULT,
+    pedantic,
+    "using `or_insert` or `or_insert_with` on a map entry where `or_default` or a lazy closure would do"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `split` on whitespace followed by a `filter` removing the empty parts, which is
+    /// what `split_whitespace` and `split_ascii_whitespace` do.
+    ///
+    /// ### Why is this bad?
+    /// The dedicated methods are shorter and state the intent.
+    ///
+    /// ### Known problems
+    /// `split(' ')` only splits on spaces, while `split_whitespace` splits on any whitespace. The
+    /// suggestion is only machine applicable when the split pattern is a whitespace predicate.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let s = "a  b c";
+    /// let words = s.split(char::is_whitespace).filter(|w| !w.is_empty());
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let s = "a  b c";
+    /// let words = s.split_whitespace();
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub SPLIT_WHITESPACE_MANUAL,
+    style,
+    "splitting on whitespace and filtering out empty parts instead of using `split_whitespace`"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a string read with `read_line` which is then parsed or compared to a string
+    /// literal without trimming the trailing newline first.
+    ///
+    /// ### Why is this bad?
+    /// `read_line` keeps the newline at the end of the line in the buffer. Parsing a number or a
+    /// `bool` from it then fails, and comparing it to a string without a newline is always false.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// let mut input = String::new();
+    /// std::io::stdin().read_line(&mut input).expect("failed to read a line");
+    /// let num: i32 = input.parse().expect("not a number");
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// let mut input = String::new();
+    /// std::io::stdin().read_line(&mut input).expect("failed to read a line");
+    /// let num: i32 = input.trim_end().parse().expect("not a number");
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub READ_LINE_WITHOUT_TRIM,
+    correctness,
+    "parsing or comparing a string read with `read_line` without trimming the trailing newline"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for parsing which can be avoided:
+    /// - `T::from_str_radix(&format!("{:x}", n), 16)`, formatting an integer and parsing it back
+    ///   in the same radix, also for octal and binary
+    /// - `"1.5".parse::<f64>().unwrap()` or `.expect(..)` on a string literal, which can be
+    ///   written as a float literal
+    ///
+    /// Parsing with radix 10 is linted by `from_str_radix_10`.
+    ///
+    /// ### Why is this bad?
+    /// The round trip through a string allocates and can only give back the original value, or
+    /// fail on negative values. A float literal is checked at compile time.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let n: u32 = 42;
+    /// let m = u32::from_str_radix(&format!("{n:x}"), 16).unwrap();
+    /// let f = "1.5".parse::<f64>().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let n: u32 = 42;
+    /// let m = n;
+    /// let f = 1.5_f64;
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub PARSE_THEN_UNWRAP_RADIX_SUGGESTION,
+    complexity,
+    "parsing an integer formatted in the same radix, or a float from a string literal"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for the arguments of a `std::process::Command` being passed as a string split on
+    /// spaces, or as part of the program.
+    ///
+    /// ### Why is this bad?
+    /// `Command` doesn't run the program through a shell. The whole string passed to
+    /// `Command::new` is the name of the program, so it isn't found if it contains arguments.
+    /// Splitting a string on spaces also splits arguments which contain spaces themselves, e.g.
+    /// paths.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// # let path = "my file.txt";
+    /// std::process::Command::new("ls -l").spawn().unwrap();
+    /// std::process::Command::new("cat").args(format!("-n {path}").split(' ')).spawn().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// # let path = "my file.txt";
+    /// std::process::Command::new("ls").arg("-l").spawn().unwrap();
+    /// std::process::Command::new("cat").arg("-n").arg(path).spawn().unwrap();
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub COMMAND_ARG_SPACE_SPLIT,
+    suspicious,
+    "passing the arguments of a `Command` in a string containing spaces"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `T::try_from(x).unwrap()` and `x.try_into().unwrap()`, or `.expect(..)`, where
+    /// the integer `x` provably fits into `T`, e.g. because it is a constant, the counter of a
+    /// `for` loop over a constant range, or the remainder of a division by a constant.
+    ///
+    /// ### Why is this bad?
+    /// The conversion can't fail, so the `unwrap` is dead code which suggests otherwise to the
+    /// reader. `T::from(x)` states that the conversion is lossless, and an `as` cast with a
+    /// comment explaining why the value fits avoids the panicking path.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::convert::TryFrom;
+    /// # let x: u64 = 1234;
+    /// let digit = u8::try_from(x % 10).unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x: u64 = 1234;
+    /// // `x % 10` is always in `0..=9`
+    /// let digit = (x % 10) as u8;
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub TRY_INTO_UNWRAP_ON_PROVABLY_FITTING_VALUES,
+    pedantic,
+    "unwrapping the result of an integer conversion which can't fail"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `x.to_vec().into_iter()` and `v.clone().into_iter()`, where `v` is a `Vec` or
+    /// `VecDeque`, if the collection isn't used while the iterator is.
+    ///
+    /// `x.to_vec().into_iter()` is only linted if `unnecessary_to_owned` is allowed, which lints
+    /// it as well, but without checking how the iterator is used.
+    ///
+    /// ### Why is this bad?
+    /// The elements are copied into a new allocation only to be moved out of it one by one.
+    /// `iter().copied()` or `iter().cloned()` yields the same values without the allocation.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let sum: i32 = v.clone().into_iter().sum();
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// let v = vec![1, 2, 3];
+    /// let sum: i32 = v.iter().copied().sum();
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub TO_VEC_THEN_INTO_ITER_BY_VALUE,
+    perf,
+    "copying a collection only to iterate over it by value"
+}
+
+pub struct Methods<'tcx> {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
     allow_expect_in_tests: bool,
     allow_unwrap_in_tests: bool,
+    trait_cache: ImplementsTraitCache<'tcx>,
 }
 
-impl Methods {
+impl Methods<'_> {
     #[must_use]
     pub fn new(
         avoid_breaking_exported_api: bool,
@@ -3210,11 +3529,12 @@ pub fn new(
             msrv,
             allow_expect_in_tests,
             allow_unwrap_in_tests,
+            trait_cache: ImplementsTraitCache::default(),
         }
     }
 }
 
-impl_lint_pass!(Methods => [
+impl_lint_pass!(Methods<'_> => [
     UNWRAP_USED,
     EXPECT_USED,
     SHOULD_IMPLEMENT_TRAIT,
@@ -3318,6 +3638,16 @@ pub fn new(
     SEEK_TO_START_INSTEAD_OF_REWIND,
     NEEDLESS_COLLECT,
     SUSPICIOUS_COMMAND_ARG_SPACE,
+    MANUAL_RESULT_INSPECT,
+    ITERATOR_STEP_BY_ZERO_RUNTIME,
+    ITERATOR_COLLECT_VEC_THEN_INDEX,
+    ENTRY_OR_INSERT_WITH_DEFAULT,
+    SPLIT_WHITESPACE_MANUAL,
+    READ_LINE_WITHOUT_TRIM,
+    PARSE_THEN_UNWRAP_RADIX_SUGGESTION,
+    COMMAND_ARG_SPACE_SPLIT,
+    TRY_INTO_UNWRAP_ON_PROVABLY_FITTING_VALUES,
+    TO_VEC_THEN_INTO_ITER_BY_VALUE,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -3333,8 +3663,11 @@ fn method_call<'tcx>(
     None
 }
 
-impl<'tcx> LateLintPass<'tcx> for Methods {
+impl<'tcx> LateLintPass<'tcx> for Methods<'tcx> {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
+        // `format!` calls are macro expansions, check them before bailing out below
+        single_char_format::check(cx, expr);
+
         if expr.span.from_expansion() {
             return;
         }
@@ -3343,11 +3676,21 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
 
         match expr.kind {
             hir::ExprKind::Call(func, args) => {
-                from_iter_instead_of_collect::check(cx, expr, args, func);
+                from_iter_instead_of_collect::check(cx, &mut self.trait_cache, expr, args, func);
+                parse_then_unwrap_radix_suggestion::check_from_str_radix(cx, expr, func, args);
+                command_arg_space_split::check_new(cx, expr, func, args);
             },
             hir::ExprKind::MethodCall(method_call, receiver, args, _) => {
                 let method_span = method_call.ident.span;
-                or_fun_call::check(cx, expr, method_span, method_call.ident.as_str(), receiver, args);
+                or_fun_call::check(
+                    cx,
+                    &mut self.trait_cache,
+                    expr,
+                    method_span,
+                    method_call.ident.as_str(),
+                    receiver,
+                    args,
+                );
                 expect_fun_call::check(cx, expr, method_span, method_call.ident.as_str(), receiver, args);
                 clone_on_copy::check(cx, expr, method_call.ident.name, receiver, args);
                 clone_on_ref_ptr::check(cx, expr, method_call.ident.name, receiver, args);
@@ -3355,7 +3698,18 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
                 single_char_add_str::check(cx, expr, receiver, args);
                 into_iter_on_ref::check(cx, expr, method_span, method_call.ident.name, receiver);
                 single_char_pattern::check(cx, expr, method_call.ident.name, receiver, args);
-                unnecessary_to_owned::check(cx, expr, method_call.ident.name, receiver, args, &self.msrv);
+                unnecessary_to_owned::check(
+                    cx,
+                    &mut self.trait_cache,
+                    expr,
+                    method_call.ident.name,
+                    receiver,
+                    args,
+                    &self.msrv,
+                );
+            },
+            hir::ExprKind::Index(base, index) => {
+                iterator_collect_vec_then_index::check_index(cx, expr, base, index);
             },
             hir::ExprKind::Binary(op, lhs, rhs) if op.node == hir::BinOpKind::Eq || op.node == hir::BinOpKind::Ne => {
                 let mut info = BinaryExprInfo {
@@ -3366,6 +3720,9 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'_>) {
                 };
                 lint_binary_expr_with_method_call(cx, &mut info);
             },
+            hir::ExprKind::AssignOp(op, lhs, rhs) => {
+                single_char_add_assign::check(cx, expr, op, lhs, rhs);
+            },
             _ => (),
         }
     }
@@ -3510,9 +3867,9 @@ fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>
     extract_msrv_attr!(LateContext);
 }
 
-impl Methods {
+impl<'tcx> Methods<'tcx> {
     #[allow(clippy::too_many_lines)]
-    fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+    fn check_methods(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if let Some((name, recv, args, span, call_span)) = method_call(expr) {
             match (name, args) {
                 ("add" | "offset" | "sub" | "wrapping_offset" | "wrapping_add" | "wrapping_sub", [_arg]) => {
@@ -3528,6 +3885,9 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("arg", [arg]) => {
                     suspicious_command_arg_space::check(cx, recv, arg, span);
                 }
+                ("args", [arg]) => {
+                    command_arg_space_split::check_args(cx, recv, arg);
+                },
                 ("as_deref" | "as_deref_mut", []) => {
                     needless_option_as_deref::check(cx, expr, recv, name);
                 },
@@ -3553,7 +3913,9 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     }
                 },
                 ("count", []) if is_trait_method(cx, expr, sym::Iterator) => match method_call(recv) {
-                    Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(cx, expr, recv, recv2, true, false),
+                    Some(("cloned", recv2, [], _, _)) => {
+                        iter_overeager_cloned::check(cx, &mut self.trait_cache, expr, recv, recv2, true, false)
+                    },
                     Some((name2 @ ("into_iter" | "iter" | "iter_mut"), recv2, [], _, _)) => {
                         iter_count::check(cx, expr, recv2, name2);
                     },
@@ -3570,16 +3932,26 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         case_sensitive_file_extension_comparisons::check(cx, expr, span, recv, arg);
                     }
                 },
-                ("expect", [_]) => match method_call(recv) {
-                    Some(("ok", recv, [], _, _)) => ok_expect::check(cx, expr, recv),
-                    Some(("err", recv, [], err_span, _)) => err_expect::check(cx, expr, recv, span, err_span, &self.msrv),
-                    _ => expect_used::check(cx, expr, recv, false, self.allow_expect_in_tests),
+                ("expect", [_]) => {
+                    try_into_unwrap_on_provably_fitting_values::check(cx, expr, recv, name);
+                    match method_call(recv) {
+                        Some(("ok", recv, [], _, _)) => ok_expect::check(cx, expr, recv),
+                        Some(("err", recv, [], err_span, _)) => {
+                            err_expect::check(cx, expr, recv, span, err_span, &self.msrv);
+                        },
+                        Some(("parse", parse_recv, [], _, _)) => {
+                            parse_then_unwrap_radix_suggestion::check_parse_literal(cx, expr, parse_recv, name);
+                            expect_used::check(cx, expr, recv, false, self.allow_expect_in_tests);
+                        },
+                        _ => expect_used::check(cx, expr, recv, false, self.allow_expect_in_tests),
+                    }
                 },
                 ("expect_err", [_]) => expect_used::check(cx, expr, recv, true, self.allow_expect_in_tests),
                 ("extend", [arg]) => {
                     string_extend_chars::check(cx, expr, recv, arg);
                     extend_with_drain::check(cx, expr, recv, arg);
                 },
+                ("filter", [_]) => split_whitespace_manual::check(cx, expr),
                 ("filter_map", [arg]) => {
                     unnecessary_filter_map::check(cx, expr, arg, name);
                     filter_map_identity::check(cx, expr, arg, span);
@@ -3587,13 +3959,16 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("find_map", [arg]) => {
                     unnecessary_filter_map::check(cx, expr, arg, name);
                 },
+                ("first", []) => iterator_collect_vec_then_index::check_method(cx, expr, name, recv, None),
                 ("flat_map", [arg]) => {
                     flat_map_identity::check(cx, expr, arg, span);
                     flat_map_option::check(cx, expr, arg, span);
                 },
                 ("flatten", []) => match method_call(recv) {
                     Some(("map", recv, [map_arg], map_span, _)) => map_flatten::check(cx, expr, recv, map_arg, map_span),
-                    Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(cx, expr, recv, recv2, false, true),
+                    Some(("cloned", recv2, [], _, _)) => {
+                        iter_overeager_cloned::check(cx, &mut self.trait_cache, expr, recv, recv2, false, true)
+                    },
                     _ => {},
                 },
                 ("fold", [init, acc]) => unnecessary_fold::check(cx, expr, init, acc, span),
@@ -3605,6 +3980,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("get", [arg]) => {
                     get_first::check(cx, expr, recv, arg);
                     get_last_with_len::check(cx, expr, recv, arg);
+                    iterator_collect_vec_then_index::check_method(cx, expr, name, recv, Some(arg));
                 },
                 ("get_or_insert_with", [arg]) => unnecessary_lazy_eval::check(cx, expr, recv, arg, "get_or_insert"),
                 ("hash", [arg]) => {
@@ -3616,6 +3992,9 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("is_some", []) => check_is_some_is_none(cx, expr, recv, true),
                 ("iter" | "iter_mut" | "into_iter", []) => {
                     iter_on_single_or_empty_collections::check(cx, expr, name, recv);
+                    if name == "into_iter" {
+                        to_vec_then_into_iter_by_value::check(cx, &mut self.trait_cache, expr, recv);
+                    }
                 },
                 ("join", [join_arg]) => {
                     if let Some(("collect", _, _, span, _)) = method_call(recv) {
@@ -3625,9 +4004,10 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("last", []) | ("skip", [_]) => {
                     if let Some((name2, recv2, args2, _span2, _)) = method_call(recv) {
                         if let ("cloned", []) = (name2, args2) {
-                            iter_overeager_cloned::check(cx, expr, recv, recv2, false, false);
+                            iter_overeager_cloned::check(cx, &mut self.trait_cache, expr, recv, recv2, false, false);
                         }
                     }
+                    iterator_collect_vec_then_index::check_method(cx, expr, name, recv, args.first());
                 },
                 ("lock", []) => {
                     mut_mutex_lock::check(cx, expr, recv, span);
@@ -3655,6 +4035,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         }
                     }
                     map_identity::check(cx, expr, recv, m_arg, name, span);
+                    manual_result_inspect::check(cx, expr, recv, m_arg, name, span, &self.msrv);
                 },
                 ("map_or", [def, map]) => {
                     option_map_or_none::check(cx, expr, recv, def, map);
@@ -3663,8 +4044,10 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("next", []) => {
                     if let Some((name2, recv2, args2, _, _)) = method_call(recv) {
                         match (name2, args2) {
-                            ("cloned", []) => iter_overeager_cloned::check(cx, expr, recv, recv2, false, false),
-                            ("filter", [arg]) => filter_next::check(cx, expr, recv2, arg),
+                            ("cloned", []) => {
+                                iter_overeager_cloned::check(cx, &mut self.trait_cache, expr, recv, recv2, false, false)
+                            },
+                            ("filter", [arg]) => filter_next::check(cx, &mut self.trait_cache, expr, recv2, arg),
                             ("filter_map", [arg]) => filter_map_next::check(cx, expr, recv2, arg, &self.msrv),
                             ("iter", []) => iter_next_slice::check(cx, expr, recv2),
                             ("skip", [arg]) => iter_skip_next::check(cx, expr, recv2, arg),
@@ -3675,7 +4058,9 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 },
                 ("nth", [n_arg]) => match method_call(recv) {
                     Some(("bytes", recv2, [], _, _)) => bytes_nth::check(cx, expr, recv2, n_arg),
-                    Some(("cloned", recv2, [], _, _)) => iter_overeager_cloned::check(cx, expr, recv, recv2, false, false),
+                    Some(("cloned", recv2, [], _, _)) => {
+                        iter_overeager_cloned::check(cx, &mut self.trait_cache, expr, recv, recv2, false, false)
+                    },
                     Some(("iter", recv2, [], _, _)) => iter_nth::check(cx, expr, recv2, recv, n_arg, false),
                     Some(("iter_mut", recv2, [], _, _)) => iter_nth::check(cx, expr, recv2, recv, n_arg, true),
                     _ => iter_nth_zero::check(cx, expr, recv, n_arg),
@@ -3684,6 +4069,9 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("open", [_]) => {
                     open_options::check(cx, expr, recv);
                 },
+                ("or_insert" | "or_insert_with", [arg]) => {
+                    entry_or_insert_with_default::check(cx, expr, name, recv, arg, span);
+                },
                 ("or_else", [arg]) => {
                     if !bind_instead_of_map::ResultOrElseErrInfo::check(cx, expr, recv, arg) {
                         unnecessary_lazy_eval::check(cx, expr, recv, arg, "or");
@@ -3692,6 +4080,9 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("push", [arg]) => {
                     path_buf_push_overwrite::check(cx, expr, arg);
                 },
+                ("read_line", [arg]) => {
+                    read_line_without_trim::check(cx, expr, arg);
+                },
                 ("read_to_end", [_]) => {
                     verbose_file_reads::check(cx, expr, recv, verbose_file_reads::READ_TO_END_MSG);
                 },
@@ -3717,20 +4108,20 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 },
                 ("seek", [arg]) => {
                     if self.msrv.meets(msrvs::SEEK_FROM_CURRENT) {
-                        seek_from_current::check(cx, expr, recv, arg);
+                        seek_from_current::check(cx, &mut self.trait_cache, expr, recv, arg);
                     }
                     if self.msrv.meets(msrvs::SEEK_REWIND) {
-                        seek_to_start_instead_of_rewind::check(cx, expr, recv, arg, span);
+                        seek_to_start_instead_of_rewind::check(cx, &mut self.trait_cache, expr, recv, arg, span);
                     }
                 },
                 ("sort", []) => {
                     stable_sort_primitive::check(cx, expr, recv);
                 },
                 ("sort_by", [arg]) => {
-                    unnecessary_sort_by::check(cx, expr, recv, arg, false);
+                    unnecessary_sort_by::check(cx, &mut self.trait_cache, expr, recv, arg, false);
                 },
                 ("sort_unstable_by", [arg]) => {
-                    unnecessary_sort_by::check(cx, expr, recv, arg, true);
+                    unnecessary_sort_by::check(cx, &mut self.trait_cache, expr, recv, arg, true);
                 },
                 ("splitn" | "rsplitn", [count_arg, pat_arg]) => {
                     if let Some((Constant::Int(count), _)) = constant(cx, cx.typeck_results(), count_arg) {
@@ -3747,7 +4138,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 ("take", [_arg]) => {
                     if let Some((name2, recv2, args2, _span2, _)) = method_call(recv) {
                         if let ("cloned", []) = (name2, args2) {
-                            iter_overeager_cloned::check(cx, expr, recv, recv2, false, false);
+                            iter_overeager_cloned::check(cx, &mut self.trait_cache, expr, recv, recv2, false, false);
                         }
                     }
                 },
@@ -3760,13 +4151,14 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 },
                 ("to_owned", []) => {
                     if !suspicious_to_owned::check(cx, expr, recv) {
-                        implicit_clone::check(cx, name, expr, recv);
+                        implicit_clone::check(cx, &mut self.trait_cache, name, expr, recv);
                     }
                 },
                 ("to_os_string" | "to_path_buf" | "to_vec", []) => {
-                    implicit_clone::check(cx, name, expr, recv);
+                    implicit_clone::check(cx, &mut self.trait_cache, name, expr, recv);
                 },
                 ("unwrap", []) => {
+                    try_into_unwrap_on_provably_fitting_values::check(cx, expr, recv, name);
                     match method_call(recv) {
                         Some(("get", recv, [get_arg], _, _)) => {
                             get_unwrap::check(cx, expr, recv, get_arg, false);
@@ -3777,6 +4169,9 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                         Some(("or", recv, [or_arg], or_span, _)) => {
                             or_then_unwrap::check(cx, expr, recv, or_arg, or_span);
                         },
+                        Some(("parse", parse_recv, [], _, _)) => {
+                            parse_then_unwrap_radix_suggestion::check_parse_literal(cx, expr, parse_recv, name);
+                        },
                         _ => {},
                     }
                     unwrap_used::check(cx, expr, recv, false, self.allow_unwrap_in_tests);
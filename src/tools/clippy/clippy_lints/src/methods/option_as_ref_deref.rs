@@ -1,8 +1,9 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::paths::PathLookup;
 use clippy_utils::source::snippet;
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{match_def_path, path_to_local_id, paths, peel_blocks};
+use clippy_utils::{path_to_local_id, peel_blocks};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
@@ -12,6 +13,15 @@
 
 use super::OPTION_AS_REF_DEREF;
 
+static CSTRING_AS_C_STR: PathLookup = PathLookup::new(&["alloc", "ffi", "c_str", "CString", "as_c_str"]);
+static DEREF_MUT_TRAIT_METHOD: PathLookup = PathLookup::new(&["core", "ops", "deref", "DerefMut", "deref_mut"]);
+static OS_STRING_AS_OS_STR: PathLookup = PathLookup::new(&["std", "ffi", "os_str", "OsString", "as_os_str"]);
+static PATH_BUF_AS_PATH: PathLookup = PathLookup::new(&["std", "path", "PathBuf", "as_path"]);
+static STRING_AS_MUT_STR: PathLookup = PathLookup::new(&["alloc", "string", "String", "as_mut_str"]);
+static STRING_AS_STR: PathLookup = PathLookup::new(&["alloc", "string", "String", "as_str"]);
+static VEC_AS_MUT_SLICE: PathLookup = PathLookup::new(&["alloc", "vec", "Vec", "as_mut_slice"]);
+static VEC_AS_SLICE: PathLookup = PathLookup::new(&["alloc", "vec", "Vec", "as_slice"]);
+
 /// lint use of `_.as_ref().map(Deref::deref)` for `Option`s
 pub(super) fn check(
     cx: &LateContext<'_>,
@@ -32,15 +42,15 @@ pub(super) fn check(
         return;
     }
 
-    let deref_aliases: [&[&str]; 8] = [
-        &paths::DEREF_MUT_TRAIT_METHOD,
-        &paths::CSTRING_AS_C_STR,
-        &paths::OS_STRING_AS_OS_STR,
-        &paths::PATH_BUF_AS_PATH,
-        &paths::STRING_AS_STR,
-        &paths::STRING_AS_MUT_STR,
-        &paths::VEC_AS_SLICE,
-        &paths::VEC_AS_MUT_SLICE,
+    let deref_aliases: [&PathLookup; 8] = [
+        &DEREF_MUT_TRAIT_METHOD,
+        &CSTRING_AS_C_STR,
+        &OS_STRING_AS_OS_STR,
+        &PATH_BUF_AS_PATH,
+        &STRING_AS_STR,
+        &STRING_AS_MUT_STR,
+        &VEC_AS_SLICE,
+        &VEC_AS_MUT_SLICE,
     ];
 
     let is_deref = match map_arg.kind {
@@ -49,7 +59,7 @@ pub(super) fn check(
                 .opt_def_id()
                 .map_or(false, |fun_def_id| {
                     cx.tcx.is_diagnostic_item(sym::deref_method, fun_def_id)
-                        || deref_aliases.iter().any(|path| match_def_path(cx, fun_def_id, path))
+                        || deref_aliases.iter().any(|lookup| lookup.matches(cx, fun_def_id))
                 })
         },
         hir::ExprKind::Closure(&hir::Closure { body, .. }) => {
@@ -70,7 +80,7 @@ pub(super) fn check(
                         then {
                             let method_did = cx.typeck_results().type_dependent_def_id(closure_expr.hir_id).unwrap();
                             cx.tcx.is_diagnostic_item(sym::deref_method, method_did)
-                                || deref_aliases.iter().any(|path| match_def_path(cx, method_did, path))
+                                || deref_aliases.iter().any(|lookup| lookup.matches(cx, method_did))
                         } else {
                             false
                         }
@@ -1,5 +1,5 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::source::snippet;
+use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::{is_res_lang_ctor, path_def_id, path_res};
 use rustc_errors::Applicability;
@@ -57,17 +57,18 @@ pub(super) fn check<'tcx>(
 
     let f_arg_is_some = is_res_lang_ctor(cx, path_res(cx, map_arg), OptionSome);
 
+    let mut app = Applicability::MachineApplicable;
     if is_option {
-        let self_snippet = snippet(cx, recv.span, "..");
+        let self_snippet = snippet_with_applicability(cx, recv.span, "..", &mut app);
         if_chain! {
             if let hir::ExprKind::Closure(&hir::Closure { body, fn_decl_span, .. }) = map_arg.kind;
-            let arg_snippet = snippet(cx, fn_decl_span, "..");
+            let arg_snippet = snippet_with_applicability(cx, fn_decl_span, "..", &mut app);
             let body = cx.tcx.hir().body(body);
             if let Some((func, [arg_char])) = reduce_unit_expression(body.value);
             if let Some(id) = path_def_id(cx, func).map(|ctor_id| cx.tcx.parent(ctor_id));
             if Some(id) == cx.tcx.lang_items().option_some_variant();
             then {
-                let func_snippet = snippet(cx, arg_char.span, "..");
+                let func_snippet = snippet_with_applicability(cx, arg_char.span, "..", &mut app);
                 let msg = "called `map_or(None, ..)` on an `Option` value. This can be done more directly by calling \
                    `map(..)` instead";
                 return span_lint_and_sugg(
@@ -77,12 +78,12 @@ pub(super) fn check<'tcx>(
                     msg,
                     "try using `map` instead",
                     format!("{self_snippet}.map({arg_snippet} {func_snippet})"),
-                    Applicability::MachineApplicable,
+                    app,
                 );
             }
         }
 
-        let func_snippet = snippet(cx, map_arg.span, "..");
+        let func_snippet = snippet_with_applicability(cx, map_arg.span, "..", &mut app);
         let msg = "called `map_or(None, ..)` on an `Option` value. This can be done more directly by calling \
                        `and_then(..)` instead";
         span_lint_and_sugg(
@@ -92,12 +93,12 @@ pub(super) fn check<'tcx>(
             msg,
             "try using `and_then` instead",
             format!("{self_snippet}.and_then({func_snippet})"),
-            Applicability::MachineApplicable,
+            app,
         );
     } else if f_arg_is_some {
         let msg = "called `map_or(None, Some)` on a `Result` value. This can be done more directly by calling \
                        `ok()` instead";
-        let self_snippet = snippet(cx, recv.span, "..");
+        let self_snippet = snippet_with_applicability(cx, recv.span, "..", &mut app);
         span_lint_and_sugg(
             cx,
             RESULT_MAP_OR_INTO_OPTION,
@@ -105,7 +106,7 @@ pub(super) fn check<'tcx>(
             msg,
             "try using `ok` instead",
             format!("{self_snippet}.ok()"),
-            Applicability::MachineApplicable,
+            app,
         );
     }
 }
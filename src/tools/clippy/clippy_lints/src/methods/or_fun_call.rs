@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::eager_or_lazy::switch_to_lazy_eval;
 use clippy_utils::source::{snippet, snippet_with_macro_callsite};
-use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use clippy_utils::ty::{is_type_diagnostic_item, ImplementsTraitCache};
 use clippy_utils::{contains_return, is_trait_item, last_path_segment};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -17,6 +17,7 @@
 #[allow(clippy::too_many_lines)]
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
     expr: &hir::Expr<'_>,
     method_span: Span,
     name: &str,
@@ -26,8 +27,9 @@ pub(super) fn check<'tcx>(
     /// Checks for `unwrap_or(T::new())`, `unwrap_or(T::default())`,
     /// `or_insert(T::new())` or `or_insert(T::default())`.
     #[allow(clippy::too_many_arguments)]
-    fn check_unwrap_or_default(
-        cx: &LateContext<'_>,
+    fn check_unwrap_or_default<'tcx>(
+        cx: &LateContext<'tcx>,
+        trait_cache: &mut ImplementsTraitCache<'tcx>,
         name: &str,
         fun: &hir::Expr<'_>,
         arg: &hir::Expr<'_>,
@@ -37,9 +39,9 @@ fn check_unwrap_or_default(
     ) -> bool {
         let is_default_default = || is_trait_item(cx, fun, sym::Default);
 
-        let implements_default = |arg, default_trait_id| {
+        let mut implements_default = |arg, default_trait_id| {
             let arg_ty = cx.typeck_results().expr_ty(arg);
-            implements_trait(cx, arg_ty, default_trait_id, &[])
+            trait_cache.implements_trait(cx, arg_ty, default_trait_id, &[])
         };
 
         if_chain! {
@@ -182,7 +184,7 @@ fn check_general_case<'tcx>(
         match inner_arg.kind {
             hir::ExprKind::Call(fun, or_args) => {
                 let or_has_args = !or_args.is_empty();
-                if !check_unwrap_or_default(cx, name, fun, arg, or_has_args, expr.span, method_span) {
+                if !check_unwrap_or_default(cx, trait_cache, name, fun, arg, or_has_args, expr.span, method_span) {
                     let fun_span = if or_has_args { None } else { Some(fun.span) };
                     check_general_case(cx, name, method_span, receiver, arg, None, expr.span, fun_span);
                 }
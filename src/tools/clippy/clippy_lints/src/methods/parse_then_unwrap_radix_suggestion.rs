@@ -0,0 +1,118 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::macros::{root_macro_call_first_node, FormatArgsExpn};
+use clippy_utils::source::snippet_with_context;
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::def::Res;
+use rustc_hir::{Expr, ExprKind, PrimTy, QPath, TyKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty;
+use rustc_span::symbol::kw;
+use rustc_span::{sym, Symbol};
+
+use super::PARSE_THEN_UNWRAP_RADIX_SUGGESTION;
+
+/// lint for `T::from_str_radix(&format!("{:x}", n), 16)` and the like
+pub(super) fn check_from_str_radix<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'tcx>,
+    func: &Expr<'_>,
+    args: &'tcx [Expr<'tcx>],
+) {
+    if let ExprKind::Path(QPath::TypeRelative(ty, segment)) = func.kind
+        && segment.ident.as_str() == "from_str_radix"
+        && let TyKind::Path(ty_qpath) = &ty.kind
+        && let Res::PrimTy(prim_ty @ (PrimTy::Int(_) | PrimTy::Uint(_))) = cx.qpath_res(ty_qpath, ty.hir_id)
+        && let [src, radix] = args
+        && let ExprKind::Lit(lit) = &radix.kind
+        && let LitKind::Int(radix, _) = lit.node
+        && let ExprKind::AddrOf(_, _, formatted) = src.kind
+        && let Some(macro_call) = root_macro_call_first_node(cx, formatted)
+        && cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id)
+        && let Some(format_args) = FormatArgsExpn::find_nested(cx, formatted, macro_call.expn)
+        && let [arg] = &*format_args.args
+        && format_args.format_string.parts.iter().all(|part| *part == kw::Empty)
+        && arg.format.is_default_for_trait()
+        && format_radix(arg.format.r#trait) == Some(radix)
+        && cx.typeck_results().expr_ty(arg.param.value).is_integral()
+    {
+        let ctxt = macro_call.span.ctxt();
+        let value = snippet_with_context(cx, arg.param.value.span, ctxt, "..", &mut Applicability::Unspecified).0;
+        span_lint_and_help(
+            cx,
+            PARSE_THEN_UNWRAP_RADIX_SUGGESTION,
+            expr.span,
+            &format!("formatting an integer in radix {radix} and parsing it back in the same radix"),
+            None,
+            &format!(
+                "use `{value}` directly, converting it with `{}::try_from` if needed",
+                prim_ty.name_str()
+            ),
+        );
+    }
+}
+
+/// lint for `"1.5".parse::<f64>().unwrap()`
+pub(super) fn check_parse_literal(cx: &LateContext<'_>, expr: &Expr<'_>, parse_recv: &Expr<'_>, method: &str) {
+    if let ExprKind::Lit(lit) = &parse_recv.kind
+        && let LitKind::Str(s, _) = lit.node
+        && !expr.span.from_expansion()
+        && let ty::Float(float_ty) = cx.typeck_results().expr_ty(expr).kind()
+        && is_float_literal(s.as_str())
+        // A literal of an infinite value doesn't compile
+        && match float_ty {
+            ty::FloatTy::F32 => s.as_str().parse::<f32>().map_or(false, f32::is_finite),
+            ty::FloatTy::F64 => s.as_str().parse::<f64>().map_or(false, f64::is_finite),
+        }
+    {
+        span_lint_and_sugg(
+            cx,
+            PARSE_THEN_UNWRAP_RADIX_SUGGESTION,
+            expr.span,
+            &format!("parsing a string literal with `parse` and `{method}`"),
+            "use a float literal",
+            format!("{s}_{}", float_ty.name_str()),
+            Applicability::MachineApplicable,
+        );
+    }
+}
+
+/// Gets the radix a number is formatted in with the format trait `format_trait`.
+fn format_radix(format_trait: Symbol) -> Option<u128> {
+    match format_trait.as_str() {
+        "LowerHex" | "UpperHex" => Some(16),
+        "Octal" => Some(8),
+        "Binary" => Some(2),
+        _ => None,
+    }
+}
+
+/// Checks if `s` is written like a Rust float literal without suffix, i.e. digits optionally
+/// followed by a fractional part and an exponent.
+fn is_float_literal(s: &str) -> bool {
+    fn digits(s: &str) -> (&str, &str) {
+        s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()))
+    }
+
+    let (int, rest) = digits(s);
+    if int.is_empty() {
+        return false;
+    }
+    let rest = match rest.strip_prefix('.') {
+        Some(rest) => {
+            let (frac, rest) = digits(rest);
+            if frac.is_empty() {
+                return false;
+            }
+            rest
+        },
+        None => rest,
+    };
+    match rest.strip_prefix(['e', 'E']) {
+        Some(exp) => {
+            let (exp, rest) = digits(exp.strip_prefix(['+', '-']).unwrap_or(exp));
+            !exp.is_empty() && rest.is_empty()
+        },
+        None => rest.is_empty(),
+    }
+}
@@ -0,0 +1,77 @@
+use std::ops::ControlFlow;
+
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::paths::PathLookup;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_local_use_after_expr;
+use clippy_utils::{get_parent_expr, path_to_local};
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+
+use super::READ_LINE_WITHOUT_TRIM;
+
+static STDIN_READ_LINE: PathLookup = PathLookup::new(&["std", "io", "stdio", "Stdin", "read_line"]);
+static BUF_READ_READ_LINE: PathLookup = PathLookup::new(&["std", "io", "BufRead", "read_line"]);
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, arg: &'tcx Expr<'_>) {
+    if let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
+        && (STDIN_READ_LINE.matches(cx, def_id) || BUF_READ_READ_LINE.matches(cx, def_id))
+        && let ExprKind::AddrOf(_, _, buf) = arg.kind
+        && let Some(local_id) = path_to_local(buf)
+    {
+        // Only the first use is checked, later ones might see a trimmed or cleared buffer
+        for_each_local_use_after_expr(cx, local_id, expr.hir_id, |use_expr| {
+            if let Some(parent) = get_parent_expr(cx, use_expr) {
+                check_use(cx, expr, use_expr, parent);
+            }
+            ControlFlow::<()>::Break(())
+        });
+    }
+}
+
+fn check_use(cx: &LateContext<'_>, read_line: &Expr<'_>, buf: &Expr<'_>, parent: &Expr<'_>) {
+    let msg = if let ExprKind::MethodCall(name, recv, [], _) = parent.kind
+        && name.ident.as_str() == "parse"
+        && recv.hir_id == buf.hir_id
+        && let parse_ty = cx.typeck_results().expr_ty(parent)
+        && is_type_diagnostic_item(cx, parse_ty, sym::Result)
+        && let ty::Adt(_, substs) = parse_ty.kind()
+        && parse_fails_on_trailing_newline(substs.type_at(0))
+    {
+        "calling `.parse()` on a string read with `read_line` without trimming the trailing newline"
+    } else if let ExprKind::Binary(op, lhs, rhs) = parent.kind
+        && matches!(op.node, BinOpKind::Eq | BinOpKind::Ne)
+        && let other = if lhs.hir_id == buf.hir_id { rhs } else { lhs }
+        && let ExprKind::Lit(lit) = &other.kind
+        && let LitKind::Str(s, _) = lit.node
+        && !s.as_str().ends_with('\n')
+    {
+        "comparing a string read with `read_line` to a string without a trailing newline"
+    } else {
+        return;
+    };
+
+    span_lint_and_then(cx, READ_LINE_WITHOUT_TRIM, parent.span, msg, |diag| {
+        diag.span_note(
+            read_line.span,
+            "`read_line` keeps the trailing newline character in the buffer",
+        );
+        let mut app = Applicability::MachineApplicable;
+        diag.span_suggestion(
+            buf.span,
+            "trim the trailing whitespace",
+            format!("{}.trim_end()", snippet_with_applicability(cx, buf.span, "..", &mut app)),
+            app,
+        );
+    });
+}
+
+/// Checks if parsing `ty` fails on a trailing newline. This is the case for all primitives.
+fn parse_fails_on_trailing_newline(ty: Ty<'_>) -> bool {
+    matches!(ty.kind(), ty::Int(_) | ty::Uint(_) | ty::Float(_) | ty::Bool | ty::Char)
+}
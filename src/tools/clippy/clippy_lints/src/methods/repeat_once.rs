@@ -1,6 +1,6 @@
 use clippy_utils::consts::{constant_context, Constant};
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::source::snippet;
+use clippy_utils::source::snippet_with_applicability;
 use clippy_utils::ty::is_type_lang_item;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, LangItem};
@@ -16,6 +16,7 @@ pub(super) fn check<'tcx>(
 ) {
     if constant_context(cx, cx.typeck_results()).expr(repeat_arg) == Some(Constant::Int(1)) {
         let ty = cx.typeck_results().expr_ty(recv).peel_refs();
+        let mut app = Applicability::MachineApplicable;
         if ty.is_str() {
             span_lint_and_sugg(
                 cx,
@@ -23,8 +24,8 @@ pub(super) fn check<'tcx>(
                 expr.span,
                 "calling `repeat(1)` on str",
                 "consider using `.to_string()` instead",
-                format!("{}.to_string()", snippet(cx, recv.span, r#""...""#)),
-                Applicability::MachineApplicable,
+                format!("{}.to_string()", snippet_with_applicability(cx, recv.span, r#""...""#, &mut app)),
+                app,
             );
         } else if ty.builtin_index().is_some() {
             span_lint_and_sugg(
@@ -33,8 +34,8 @@ pub(super) fn check<'tcx>(
                 expr.span,
                 "calling `repeat(1)` on slice",
                 "consider using `.to_vec()` instead",
-                format!("{}.to_vec()", snippet(cx, recv.span, r#""...""#)),
-                Applicability::MachineApplicable,
+                format!("{}.to_vec()", snippet_with_applicability(cx, recv.span, r#""...""#, &mut app)),
+                app,
             );
         } else if is_type_lang_item(cx, ty, LangItem::String) {
             span_lint_and_sugg(
@@ -43,8 +44,8 @@ pub(super) fn check<'tcx>(
                 expr.span,
                 "calling `repeat(1)` on a string literal",
                 "consider using `.clone()` instead",
-                format!("{}.clone()", snippet(cx, recv.span, r#""...""#)),
-                Applicability::MachineApplicable,
+                format!("{}.clone()", snippet_with_applicability(cx, recv.span, r#""...""#, &mut app)),
+                app,
             );
         }
     }
@@ -3,18 +3,24 @@
 use rustc_hir::{Expr, ExprKind};
 use rustc_lint::LateContext;
 
-use clippy_utils::{
-    diagnostics::span_lint_and_sugg, get_trait_def_id, match_def_path, paths, source::snippet_with_applicability,
-    ty::implements_trait,
-};
+use clippy_utils::paths::{self, PathLookup};
+use clippy_utils::{diagnostics::span_lint_and_sugg, source::snippet_with_applicability, ty::ImplementsTraitCache};
 
 use super::SEEK_FROM_CURRENT;
 
-pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>, arg: &'tcx Expr<'_>) {
+static STD_IO_SEEK_FROM_CURRENT: PathLookup = PathLookup::new(&["std", "io", "SeekFrom", "Current"]);
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
+    expr: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+    arg: &'tcx Expr<'_>,
+) {
     let ty = cx.typeck_results().expr_ty(recv);
 
-    if let Some(def_id) = get_trait_def_id(cx, &paths::STD_IO_SEEK) {
-        if implements_trait(cx, ty, def_id, &[]) && arg_is_seek_from_current(cx, arg) {
+    if let Some(def_id) = paths::STD_IO_SEEK.first(cx) {
+        if trait_cache.implements_trait(cx, ty, def_id, &[]) && arg_is_seek_from_current(cx, arg) {
             let mut applicability = Applicability::MachineApplicable;
             let snip = snippet_with_applicability(cx, recv.span, "..", &mut applicability);
 
@@ -35,7 +41,7 @@ fn arg_is_seek_from_current<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>)
     if let ExprKind::Call(f, args) = expr.kind &&
         let ExprKind::Path(ref path) = f.kind &&
         let Some(def_id) = cx.qpath_res(path, f.hir_id).opt_def_id() &&
-        match_def_path(cx, def_id, &paths::STD_IO_SEEK_FROM_CURRENT) {
+        STD_IO_SEEK_FROM_CURRENT.matches(cx, def_id) {
         // check if argument of `SeekFrom::Current` is `0`
         if args.len() == 1 &&
             let ExprKind::Lit(ref lit) = args[0].kind &&
@@ -1,6 +1,7 @@
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::ty::implements_trait;
-use clippy_utils::{get_trait_def_id, is_expr_used_or_unified, match_def_path, paths};
+use clippy_utils::is_expr_used_or_unified;
+use clippy_utils::paths::{self, PathLookup};
+use clippy_utils::ty::ImplementsTraitCache;
 use rustc_ast::ast::{LitIntType, LitKind};
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
@@ -9,8 +10,11 @@
 
 use super::SEEK_TO_START_INSTEAD_OF_REWIND;
 
+static STD_IO_SEEKFROM_START: PathLookup = PathLookup::new(&["std", "io", "SeekFrom", "Start"]);
+
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
     expr: &'tcx Expr<'_>,
     recv: &'tcx Expr<'_>,
     arg: &'tcx Expr<'_>,
@@ -23,12 +27,12 @@ pub(super) fn check<'tcx>(
         return;
     }
 
-    if let Some(seek_trait_id) = get_trait_def_id(cx, &paths::STD_IO_SEEK) &&
-        implements_trait(cx, ty, seek_trait_id, &[]) &&
+    if let Some(seek_trait_id) = paths::STD_IO_SEEK.first(cx) &&
+        trait_cache.implements_trait(cx, ty, seek_trait_id, &[]) &&
         let ExprKind::Call(func, args1) = arg.kind &&
         let ExprKind::Path(ref path) = func.kind &&
         let Some(def_id) = cx.qpath_res(path, func.hir_id).opt_def_id() &&
-        match_def_path(cx, def_id, &paths::STD_IO_SEEKFROM_START) &&
+        STD_IO_SEEKFROM_START.matches(cx, def_id) &&
         args1.len() == 1 &&
         let ExprKind::Lit(ref lit) = args1[0].kind &&
         let LitKind::Int(0, LitIntType::Unsuffixed) = lit.node
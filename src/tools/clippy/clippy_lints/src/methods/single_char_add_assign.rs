@@ -0,0 +1,43 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::is_trait_method;
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_lang_item;
+use rustc_errors::Applicability;
+use rustc_hir::{self as hir, BinOpKind, BorrowKind, ExprKind, LangItem, Mutability};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::SINGLE_CHAR_ADD_STR;
+
+/// lint for `s += &c.to_string()` where `c` is a `char`
+pub(super) fn check(
+    cx: &LateContext<'_>,
+    expr: &hir::Expr<'_>,
+    op: hir::BinOp,
+    lhs: &hir::Expr<'_>,
+    rhs: &hir::Expr<'_>,
+) {
+    if op.node == BinOpKind::Add
+        && is_type_lang_item(cx, cx.typeck_results().expr_ty(lhs), LangItem::String)
+        && let ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, to_string) = rhs.kind
+        && let ExprKind::MethodCall(path, ch, [], _) = to_string.kind
+        && path.ident.name == sym::to_string
+        && is_trait_method(cx, to_string, sym::ToString)
+        && cx.typeck_results().expr_ty(ch).is_char()
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        let ctxt = expr.span.ctxt();
+        let string = Sugg::hir_with_context(cx, lhs, ctxt, "..", &mut applicability).maybe_par();
+        let ch = snippet_with_context(cx, ch.span, ctxt, "..", &mut applicability).0;
+        span_lint_and_sugg(
+            cx,
+            SINGLE_CHAR_ADD_STR,
+            expr.span,
+            "appending a `char` converted to a string with `+=`",
+            "consider using `push` with the character",
+            format!("{string}.push({ch})"),
+            applicability,
+        );
+    }
+}
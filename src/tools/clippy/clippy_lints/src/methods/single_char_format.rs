@@ -0,0 +1,43 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::macros::{root_macro_call_first_node, FormatArgsExpn};
+use clippy_utils::source::snippet_with_context;
+use rustc_errors::Applicability;
+use rustc_hir as hir;
+use rustc_lint::LateContext;
+use rustc_span::sym;
+use rustc_span::symbol::kw;
+
+use super::SINGLE_CHAR_ADD_STR;
+
+/// lint for `format!("{x}{y}")` where both `x` and `y` are `char`s
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
+    if let Some(macro_call) = root_macro_call_first_node(cx, expr)
+        && !macro_call.span.from_expansion()
+        && cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id)
+        && let Some(format_args) = FormatArgsExpn::find_nested(cx, expr, macro_call.expn)
+        && let [first, second] = &*format_args.args
+        && format_args.format_string.parts.iter().all(|part| *part == kw::Empty)
+        && [first, second]
+            .iter()
+            .all(|arg| arg.format.is_default() && cx.typeck_results().expr_ty(arg.param.value).is_char())
+    {
+        // `FromIterator` is only in the prelude since the 2021 edition
+        let mut applicability = if cx.tcx.sess.edition().rust_2021() {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        let ctxt = macro_call.span.ctxt();
+        let first = snippet_with_context(cx, first.param.value.span, ctxt, "..", &mut applicability).0;
+        let second = snippet_with_context(cx, second.param.value.span, ctxt, "..", &mut applicability).0;
+        span_lint_and_sugg(
+            cx,
+            SINGLE_CHAR_ADD_STR,
+            macro_call.span,
+            "calling `format!()` to join two `char`s",
+            "consider collecting the characters instead",
+            format!("String::from_iter([{first}, {second}])"),
+            applicability,
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::{is_trait_method, method_chain, path_def_id, path_to_local_id, peel_blocks};
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{Closure, Expr, ExprKind, LangItem, PatKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::Ty;
+use rustc_span::sym;
+
+use super::SPLIT_WHITESPACE_MANUAL;
+
+/// Checks `s.split(..).filter(|p| !p.is_empty())` where `expr` is the call to `filter`.
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+    if let Some([split, filter]) = method_chain!(cx, expr, [
+        split(_) where |ty: Ty<'tcx>| {
+            let ty = ty.peel_refs();
+            ty.is_str() || is_type_lang_item(cx, ty, LangItem::String)
+        },
+        filter(_),
+    ]) && let Some((replacement, exact)) = whitespace_split(cx, &split.args[0])
+        && is_non_empty_filter(cx, &filter.args[0])
+        && is_trait_method(cx, expr, sym::Iterator)
+    {
+        let app = if exact {
+            Applicability::MachineApplicable
+        } else {
+            Applicability::MaybeIncorrect
+        };
+        let span = split.name_span.with_hi(expr.span.hi());
+        span_lint_and_then(
+            cx,
+            SPLIT_WHITESPACE_MANUAL,
+            span,
+            &format!("manual implementation of `{replacement}`"),
+            |diag| {
+                diag.span_suggestion(span, "try", format!("{replacement}()"), app);
+                if !exact {
+                    diag.note(format!(
+                        "`{replacement}` also splits on other whitespace, such as tabs and newlines"
+                    ));
+                }
+            },
+        );
+    }
+}
+
+/// Returns the method the pattern of `split` corresponds to, and whether the pattern matches
+/// exactly the same characters.
+fn whitespace_split(cx: &LateContext<'_>, pat: &Expr<'_>) -> Option<(&'static str, bool)> {
+    match pat.kind {
+        ExprKind::Lit(ref lit) if matches!(lit.node, LitKind::Char(' ')) => Some(("split_whitespace", false)),
+        ExprKind::Closure(&Closure { body, .. }) => {
+            let body = cx.tcx.hir().body(body);
+            if let [param] = body.params
+                && let PatKind::Binding(_, param_id, _, None) = param.pat.kind
+                && let ExprKind::MethodCall(path, recv, [], _) = peel_blocks(body.value).kind
+                && path_to_local_id(recv, param_id)
+                && cx.typeck_results().expr_ty_adjusted(recv).peel_refs().is_char()
+            {
+                match path.ident.name.as_str() {
+                    "is_whitespace" => Some(("split_whitespace", true)),
+                    "is_ascii_whitespace" => Some(("split_ascii_whitespace", true)),
+                    _ => None,
+                }
+            } else {
+                None
+            }
+        },
+        _ => {
+            let def_id = path_def_id(cx, pat)?;
+            let impl_id = cx.tcx.impl_of_method(def_id)?;
+            (cx.tcx.item_name(def_id).as_str() == "is_whitespace" && cx.tcx.type_of(impl_id).subst_identity().is_char())
+                .then_some(("split_whitespace", true))
+        },
+    }
+}
+
+/// Checks for `|p| !p.is_empty()`, including through references.
+fn is_non_empty_filter(cx: &LateContext<'_>, arg: &Expr<'_>) -> bool {
+    let ExprKind::Closure(&Closure { body, .. }) = arg.kind else {
+        return false;
+    };
+    let body = cx.tcx.hir().body(body);
+    let [param] = body.params else {
+        return false;
+    };
+    let mut pat = param.pat;
+    while let PatKind::Ref(inner, _) = pat.kind {
+        pat = inner;
+    }
+    if let PatKind::Binding(_, param_id, _, None) = pat.kind
+        && let ExprKind::Unary(UnOp::Not, negated) = peel_blocks(body.value).kind
+        && let ExprKind::MethodCall(path, mut recv, [], _) = negated.kind
+        && path.ident.name.as_str() == "is_empty"
+    {
+        while let ExprKind::Unary(UnOp::Deref, inner) = recv.kind {
+            recv = inner;
+        }
+        path_to_local_id(recv, param_id)
+    } else {
+        false
+    }
+}
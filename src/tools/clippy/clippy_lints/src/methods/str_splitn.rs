@@ -1,10 +1,11 @@
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
 use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::paths::PathLookup;
 use clippy_utils::source::snippet_with_context;
 use clippy_utils::usage::local_used_after_expr;
 use clippy_utils::visitors::{for_each_expr_with_closures, Descend};
-use clippy_utils::{is_diag_item_method, match_def_path, path_to_local_id, paths};
+use clippy_utils::{is_diag_item_method, path_to_local_id};
 use core::ops::ControlFlow;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
@@ -17,6 +18,8 @@
 
 use super::{MANUAL_SPLIT_ONCE, NEEDLESS_SPLITN};
 
+static ITERTOOLS_NEXT_TUPLE: PathLookup = PathLookup::new(&["itertools", "Itertools", "next_tuple"]);
+
 pub(super) fn check(
     cx: &LateContext<'_>,
     method_name: &str,
@@ -299,7 +302,7 @@ fn parse_iter_usage<'tcx>(
                 ("next", []) if cx.tcx.trait_of_item(did) == Some(iter_id) => (IterUsageKind::Nth(0), e.span),
                 ("next_tuple", []) => {
                     return if_chain! {
-                        if match_def_path(cx, did, &paths::ITERTOOLS_NEXT_TUPLE);
+                        if ITERTOOLS_NEXT_TUPLE.matches(cx, did);
                         if let ty::Adt(adt_def, subs) = cx.typeck_results().expr_ty(e).kind();
                         if cx.tcx.is_diagnostic_item(sym::Option, adt_def.did());
                         if let ty::Tuple(subs) = subs.type_at(0).kind();
@@ -0,0 +1,116 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::ty::{get_iterator_item_ty, is_copy, is_type_diagnostic_item, ImplementsTraitCache};
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{get_parent_expr, higher, is_lint_allowed, is_trait_method, path_to_local};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind, HirId, Node};
+use rustc_lint::LateContext;
+use rustc_span::sym;
+
+use super::implicit_clone::is_clone_like;
+use super::{TO_VEC_THEN_INTO_ITER_BY_VALUE, UNNECESSARY_TO_OWNED};
+
+/// lint for `x.to_vec().into_iter()` and `x.clone().into_iter()` where `x` is a `Vec`
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
+    expr: &'tcx Expr<'_>,
+    recv: &'tcx Expr<'_>,
+) {
+    let ExprKind::MethodCall(copy_path, collection, [], _) = recv.kind else {
+        return;
+    };
+    let method = copy_path.ident.as_str();
+    let is_copy_of_collection = match method {
+        // `unnecessary_to_owned` already suggests the same without checking the uses of the iterator
+        "to_vec" => {
+            is_lint_allowed(cx, UNNECESSARY_TO_OWNED, expr.hir_id)
+                && cx
+                    .typeck_results()
+                    .type_dependent_def_id(recv.hir_id)
+                    .map_or(false, |id| is_clone_like(cx, method, id))
+        },
+        "clone" => {
+            let ty = cx.typeck_results().expr_ty(collection);
+            is_trait_method(cx, recv, sym::Clone)
+                && (is_type_diagnostic_item(cx, ty, sym::Vec) || is_type_diagnostic_item(cx, ty, sym::VecDeque))
+        },
+        _ => false,
+    };
+    if !is_copy_of_collection || expr.span.from_expansion() {
+        return;
+    }
+    let Some(item_ty) = get_iterator_item_ty(cx, cx.typeck_results().expr_ty(expr)) else {
+        return;
+    };
+    if !borrow_is_unobservable(cx, trait_cache, expr, root_local(collection)) {
+        return;
+    }
+
+    let mut applicability = Applicability::MachineApplicable;
+    let collection = snippet_with_applicability(cx, collection.span, "..", &mut applicability);
+    let adapter = if is_copy(cx, item_ty) { "copied" } else { "cloned" };
+    span_lint_and_sugg(
+        cx,
+        TO_VEC_THEN_INTO_ITER_BY_VALUE,
+        expr.span,
+        &format!("`{method}` allocates a new collection only to iterate over it by value"),
+        &format!("iterate over references and `{adapter}` instead"),
+        format!("{collection}.iter().{adapter}()"),
+        applicability,
+    );
+}
+
+/// Checks if borrowing the collection for as long as the iterator `expr` is used instead of
+/// copying it is fine. This is the case if the iterator is consumed in the same expression, or
+/// iterated by a `for` loop not using `local`, and no adapter in between uses `local`.
+fn borrow_is_unobservable<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
+    expr: &'tcx Expr<'_>,
+    local: Option<HirId>,
+) -> bool {
+    let uses_local = |e: &'tcx Expr<'tcx>| local.map_or(false, |id| is_local_used(cx, e, id));
+
+    // Walk up the method chain
+    let mut iter: &'tcx Expr<'tcx> = expr;
+    while let Some(parent) = get_parent_expr(cx, iter)
+        && let ExprKind::MethodCall(_, recv, args, _) = parent.kind
+        && recv.hir_id == iter.hir_id
+    {
+        if args.iter().any(uses_local) {
+            return false;
+        }
+        iter = parent;
+    }
+
+    let Some(iterator_trait) = cx.tcx.get_diagnostic_item(sym::Iterator) else {
+        return false;
+    };
+    if !trait_cache.implements_trait(cx, cx.typeck_results().expr_ty(iter), iterator_trait, &[]) {
+        // Consumed, e.g. by `collect` or `sum`
+        return true;
+    }
+    // The `for` loop desugaring calls `IntoIterator::into_iter` on the iterator
+    cx.tcx
+        .hir()
+        .parent_iter(iter.hir_id)
+        .take(3)
+        .find_map(|(_, node)| match node {
+            Node::Expr(e) => higher::ForLoop::hir(e),
+            _ => None,
+        })
+        .map_or(false, |for_loop| {
+            for_loop.arg.hir_id == iter.hir_id && !uses_local(for_loop.body)
+        })
+}
+
+/// Gets the local `e` is a field of, or `e` itself.
+fn root_local(e: &Expr<'_>) -> Option<HirId> {
+    let mut e = e;
+    while let ExprKind::Field(base, _) = e.kind {
+        e = base;
+    }
+    path_to_local(e)
+}
@@ -0,0 +1,111 @@
+use clippy_utils::comparisons::int_range;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_def_id;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_isize_or_usize;
+use clippy_utils::value_range::{value_range, ValueRange};
+use rustc_errors::Applicability;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+
+use super::TRY_INTO_UNWRAP_ON_PROVABLY_FITTING_VALUES;
+
+/// lint for `T::try_from(x).unwrap()` and `x.try_into().unwrap()` where `x` always fits into `T`
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>, recv: &'tcx Expr<'_>, method: &str) {
+    if expr.span.from_expansion() {
+        return;
+    }
+    let Some(value) = converted_value(cx, recv) else {
+        return;
+    };
+    let ty::Adt(_, substs) = cx.typeck_results().expr_ty(recv).kind() else {
+        return;
+    };
+    let from_ty = cx.typeck_results().expr_ty(value);
+    let to_ty = substs.type_at(0);
+    if !from_ty.is_integral() || !to_ty.is_integral() {
+        return;
+    }
+    let Some(range) = value_range(cx, value).or_else(|| ValueRange::of_ty(cx.tcx, from_ty)) else {
+        return;
+    };
+    if !range.fits_into(cx.tcx, to_ty) {
+        return;
+    }
+    let ValueRange { min, max, .. } = range;
+
+    span_lint_and_then(
+        cx,
+        TRY_INTO_UNWRAP_ON_PROVABLY_FITTING_VALUES,
+        expr.span,
+        &format!("this conversion to `{to_ty}` can't fail, so `{method}` never panics"),
+        |diag| {
+            let value_sugg = Sugg::hir(cx, value, "..");
+            if min == max {
+                diag.note(format!("`{value_sugg}` is always `{min}`, which fits into `{to_ty}`"));
+            } else {
+                diag.note(format!(
+                    "`{value_sugg}` is always in `{min}..={max}`, which fits into `{to_ty}`"
+                ));
+            }
+            if has_lossless_from(cx, from_ty, to_ty) {
+                diag.span_suggestion(
+                    expr.span,
+                    format!("use `{to_ty}::from` instead"),
+                    format!("{to_ty}::from({value_sugg})"),
+                    Applicability::MaybeIncorrect,
+                );
+            } else {
+                diag.span_suggestion(
+                    expr.span,
+                    "use a commented `as` cast instead",
+                    value_sugg.as_ty(to_ty).to_string(),
+                    Applicability::MaybeIncorrect,
+                );
+            }
+        },
+    );
+}
+
+/// Gets `x` from `T::try_from(x)` or `x.try_into()`.
+fn converted_value<'tcx>(cx: &LateContext<'tcx>, recv: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    let (def_id, value) = match recv.kind {
+        ExprKind::Call(func, [value]) => (path_def_id(cx, func)?, value),
+        ExprKind::MethodCall(_, value, [], _) => (cx.typeck_results().type_dependent_def_id(recv.hir_id)?, value),
+        _ => return None,
+    };
+    let trait_id = cx.tcx.trait_of_item(def_id)?;
+    match cx.tcx.get_diagnostic_name(trait_id)? {
+        sym::TryFrom if matches!(recv.kind, ExprKind::Call(..)) => Some(value),
+        sym::TryInto if matches!(recv.kind, ExprKind::MethodCall(..)) => Some(value),
+        _ => None,
+    }
+}
+
+/// Checks if `From<from_ty>` is implemented for `to_ty`.
+fn has_lossless_from<'tcx>(cx: &LateContext<'tcx>, from_ty: Ty<'tcx>, to_ty: Ty<'tcx>) -> bool {
+    if from_ty == to_ty {
+        return true;
+    }
+    // `From` is only implemented between pointer-sized and other integers if the conversion is
+    // lossless on 16-bit targets
+    if is_isize_or_usize(from_ty) {
+        return false;
+    }
+    let (Some((from_min, from_max)), Some((to_min, to_max))) = (int_range(cx.tcx, from_ty), int_range(cx.tcx, to_ty))
+    else {
+        return false;
+    };
+    let (to_min, to_max) = if is_isize_or_usize(to_ty) {
+        if to_ty.is_signed() {
+            (i128::from(i16::MIN), i128::from(i16::MAX))
+        } else {
+            (0, i128::from(u16::MAX))
+        }
+    } else {
+        (to_min, to_max)
+    };
+    to_min <= from_min && from_max <= to_max
+}
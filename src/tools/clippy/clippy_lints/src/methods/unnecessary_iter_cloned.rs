@@ -2,7 +2,7 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::higher::ForLoop;
 use clippy_utils::source::snippet_opt;
-use clippy_utils::ty::{get_iterator_item_ty, implements_trait};
+use clippy_utils::ty::{get_iterator_item_ty, ImplementsTraitCache};
 use clippy_utils::{fn_def_id, get_parent_expr};
 use rustc_errors::Applicability;
 use rustc_hir::{def_id::DefId, Expr, ExprKind};
@@ -11,13 +11,19 @@
 
 use super::UNNECESSARY_TO_OWNED;
 
-pub fn check(cx: &LateContext<'_>, expr: &Expr<'_>, method_name: Symbol, receiver: &Expr<'_>) -> bool {
+pub fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
+    expr: &Expr<'_>,
+    method_name: Symbol,
+    receiver: &Expr<'_>,
+) -> bool {
     if_chain! {
         if let Some(parent) = get_parent_expr(cx, expr);
         if let Some(callee_def_id) = fn_def_id(cx, parent);
         if is_into_iter(cx, callee_def_id);
         then {
-            check_for_loop_iter(cx, parent, method_name, receiver, false)
+            check_for_loop_iter(cx, trait_cache, parent, method_name, receiver, false)
         } else {
             false
         }
@@ -28,8 +34,9 @@ pub fn check(cx: &LateContext<'_>, expr: &Expr<'_>, method_name: Symbol, receive
 /// iterated-over items could be iterated over by reference. The reason why `check` above does not
 /// include this code directly is so that it can be called from
 /// `unnecessary_into_owned::check_into_iter_call_arg`.
-pub fn check_for_loop_iter(
-    cx: &LateContext<'_>,
+pub fn check_for_loop_iter<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
     expr: &Expr<'_>,
     method_name: Symbol,
     receiver: &Expr<'_>,
@@ -48,12 +55,12 @@ pub fn check_for_loop_iter(
 
                 if let Some(iterator_trait_id) = cx.tcx.get_diagnostic_item(sym::Iterator);
                 let receiver_ty = cx.typeck_results().expr_ty(receiver);
-                if implements_trait(cx, receiver_ty, iterator_trait_id, &[]);
+                if trait_cache.implements_trait(cx, receiver_ty, iterator_trait_id, &[]);
                 if let Some(iter_item_ty) = get_iterator_item_ty(cx, receiver_ty);
 
                 if let Some(into_iterator_trait_id) = cx.tcx.get_diagnostic_item(sym::IntoIterator);
                 let collection_ty = cx.typeck_results().expr_ty(collection);
-                if implements_trait(cx, collection_ty, into_iterator_trait_id, &[]);
+                if trait_cache.implements_trait(cx, collection_ty, into_iterator_trait_id, &[]);
                 if let Some(into_iter_item_ty) = cx.get_associated_type(collection_ty, into_iterator_trait_id, "Item");
 
                 if iter_item_ty == into_iter_item_ty;
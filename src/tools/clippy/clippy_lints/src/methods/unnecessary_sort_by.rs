@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::is_trait_method;
 use clippy_utils::sugg::Sugg;
-use clippy_utils::ty::implements_trait;
+use clippy_utils::ty::ImplementsTraitCache;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{Closure, Expr, ExprKind, Mutability, Param, Pat, PatKind, Path, PathSegment, QPath};
@@ -118,7 +118,13 @@ fn mirrored_exprs(a_expr: &Expr<'_>, a_ident: &Ident, b_expr: &Expr<'_>, b_ident
     }
 }
 
-fn detect_lint(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Expr<'_>) -> Option<LintTrigger> {
+fn detect_lint<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
+    expr: &Expr<'_>,
+    recv: &Expr<'_>,
+    arg: &Expr<'_>,
+) -> Option<LintTrigger> {
     if_chain! {
         if let Some(method_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id);
         if let Some(impl_id) = cx.tcx.impl_of_method(method_id);
@@ -153,7 +159,7 @@ fn detect_lint(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>, arg: &Exp
                 })) = &left_expr.kind;
                 if left_name == left_ident;
                 if cx.tcx.get_diagnostic_item(sym::Ord).map_or(false, |id| {
-                    implements_trait(cx, cx.typeck_results().expr_ty(left_expr), id, &[])
+                    trait_cache.implements_trait(cx, cx.typeck_results().expr_ty(left_expr), id, &[])
                 });
                 then {
                     return Some(LintTrigger::Sort(SortDetection { vec_name }));
@@ -181,12 +187,13 @@ fn expr_borrows(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
 
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
     expr: &'tcx Expr<'_>,
     recv: &'tcx Expr<'_>,
     arg: &'tcx Expr<'_>,
     is_unstable: bool,
 ) {
-    match detect_lint(cx, expr, recv, arg) {
+    match detect_lint(cx, trait_cache, expr, recv, arg) {
         Some(LintTrigger::SortByKey(trigger)) => span_lint_and_sugg(
             cx,
             UNNECESSARY_SORT_BY,
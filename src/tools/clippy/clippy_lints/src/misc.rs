@@ -1,5 +1,5 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_sugg, span_lint_hir_and_then};
-use clippy_utils::source::{snippet, snippet_opt};
+use clippy_utils::source::{snippet, snippet_opt, snippet_with_applicability};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::FnKind;
@@ -193,8 +193,9 @@ fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
                 } else {
                     ("", sugg_init.addr())
                 };
+                let mut app = Applicability::MachineApplicable;
                 let tyopt = if let Some(ty) = local.ty {
-                    format!(": &{mutopt}{ty}", ty=snippet(cx, ty.span, ".."))
+                    format!(": &{mutopt}{ty}", ty=snippet_with_applicability(cx, ty.span, "..", &mut app))
                 } else {
                     String::new()
                 };
@@ -210,9 +211,9 @@ fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
                             "try",
                             format!(
                                 "let {name}{tyopt} = {initref};",
-                                name=snippet(cx, name.span, ".."),
+                                name=snippet_with_applicability(cx, name.span, "..", &mut app),
                             ),
-                            Applicability::MachineApplicable,
+                            app,
                         );
                     }
                 );
@@ -1,36 +1,86 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::numeric_literal::NumericLiteral;
 use rustc_errors::Applicability;
 use rustc_lint::EarlyContext;
 use rustc_span::Span;
 
-use super::{SEPARATED_LITERAL_SUFFIX, UNSEPARATED_LITERAL_SUFFIX};
+use super::{SuffixStyle, LITERAL_SUFFIX_STYLE};
 
-pub(super) fn check(cx: &EarlyContext<'_>, lit_span: Span, lit_snip: &str, suffix: &str, sugg_type: &str) {
-    let Some(maybe_last_sep_idx) = lit_snip.len().checked_sub(suffix.len() + 1) else {
-        return; // It's useless so shouldn't lint.
+pub(super) fn check(
+    cx: &EarlyContext<'_>,
+    lit_span: Span,
+    lit_snip: &str,
+    suffix: &str,
+    float: bool,
+    style: SuffixStyle,
+    digit_grouping_min_digits: u64,
+) {
+    let Some(unsuffixed) = lit_snip.strip_suffix(suffix) else {
+        return;
     };
-    // Do not lint when literal is unsuffixed.
+    let (digits, separated) = match unsuffixed.strip_suffix('_') {
+        Some(digits) if !suffix.is_empty() => (digits, true),
+        _ => (unsuffixed, false),
+    };
+    let lit = NumericLiteral::new(digits, None, float);
+
+    let wrong_suffix = !suffix.is_empty() && separated != (style == SuffixStyle::Separated);
+    let ungrouped = digit_grouping_min_digits != 0
+        && !lit.integer.contains('_')
+        && lit.integer.len() as u64 >= digit_grouping_min_digits;
+    if !wrong_suffix && !ungrouped {
+        return;
+    }
+
+    let mut sugg = String::from(lit.prefix.unwrap_or_default());
+    if ungrouped {
+        NumericLiteral::group_digits(&mut sugg, lit.integer, lit.radix.suggest_grouping(), true, false);
+    } else {
+        sugg.push_str(lit.integer);
+    }
+    if let Some(fraction) = lit.fraction {
+        sugg.push('.');
+        sugg.push_str(fraction);
+    }
+    // The suffix of a float with an exponent goes after the exponent, e.g. `1e3_f64`
+    if let Some((separator, exponent)) = lit.exponent {
+        sugg.push_str(separator);
+        sugg.push_str(exponent);
+    }
     if !suffix.is_empty() {
-        if lit_snip.as_bytes()[maybe_last_sep_idx] == b'_' {
-            span_lint_and_sugg(
-                cx,
-                SEPARATED_LITERAL_SUFFIX,
-                lit_span,
-                &format!("{sugg_type} type suffix should not be separated by an underscore"),
-                "remove the underscore",
-                format!("{}{suffix}", &lit_snip[..maybe_last_sep_idx]),
-                Applicability::MachineApplicable,
-            );
-        } else {
-            span_lint_and_sugg(
-                cx,
-                UNSEPARATED_LITERAL_SUFFIX,
-                lit_span,
-                &format!("{sugg_type} type suffix should be separated by an underscore"),
-                "add an underscore",
-                format!("{}_{suffix}", &lit_snip[..=maybe_last_sep_idx]),
-                Applicability::MachineApplicable,
-            );
+        if style == SuffixStyle::Separated {
+            sugg.push('_');
         }
+        sugg.push_str(suffix);
     }
+
+    let sugg_type = if float { "float" } else { "integer" };
+    let (msg, help) = match (wrong_suffix, style) {
+        (true, SuffixStyle::Separated) => (
+            format!("{sugg_type} type suffix should be separated by an underscore"),
+            "add an underscore",
+        ),
+        (true, SuffixStyle::Unseparated) => (
+            format!("{sugg_type} type suffix should not be separated by an underscore"),
+            "remove the underscore",
+        ),
+        (false, _) => (
+            format!("long {sugg_type} literal without digit grouping"),
+            "group the digits",
+        ),
+    };
+    let help = if wrong_suffix && ungrouped {
+        format!("{help} and group the digits")
+    } else {
+        help.to_owned()
+    };
+    span_lint_and_sugg(
+        cx,
+        LITERAL_SUFFIX_STYLE,
+        lit_span,
+        &msg,
+        &help,
+        sugg,
+        Applicability::MachineApplicable,
+    );
 }
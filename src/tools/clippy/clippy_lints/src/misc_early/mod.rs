@@ -15,8 +15,9 @@
 use rustc_data_structures::fx::FxHashMap;
 use rustc_lint::{EarlyContext, EarlyLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::source_map::Span;
+use serde::Deserialize;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -132,10 +133,12 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Warns if literal suffixes are not separated by an
-    /// underscore.
-    /// To enforce unseparated literal suffix style,
-    /// see the `separated_literal_suffix` lint.
+    /// Warns if literal suffixes are not written in the style set by the `suffix-style`
+    /// configuration, i.e. separated by an underscore (`123_i32`, the default) or not (`123i32`).
+    ///
+    /// If the `digit-grouping-min-digits` configuration is set (it is `0`, i.e. off, by default),
+    /// it also warns on literals whose integer part has at least that many digits and isn't
+    /// grouped by underscores, including hexadecimal literals and floats with an exponent.
     ///
     /// ### Why is this bad?
     /// Suffix style should be consistent.
@@ -153,38 +156,10 @@
     /// 123832_i32
     /// # ;
     /// ```
-    #[clippy::version = "pre 1.29.0"]
-    pub UNSEPARATED_LITERAL_SUFFIX,
-    restriction,
-    "literals whose suffix is not separated by an underscore"
-}
-
-declare_clippy_lint! {
-    /// ### What it does
-    /// Warns if literal suffixes are separated by an underscore.
-    /// To enforce separated literal suffix style,
-    /// see the `unseparated_literal_suffix` lint.
-    ///
-    /// ### Why is this bad?
-    /// Suffix style should be consistent.
-    ///
-    /// ### Example
-    /// ```rust
-    /// # let _ =
-    /// 123832_i32
-    /// # ;
-    /// ```
-    ///
-    /// Use instead:
-    /// ```rust
-    /// # let _ =
-    /// 123832i32
-    /// # ;
-    /// ```
-    #[clippy::version = "1.58.0"]
-    pub SEPARATED_LITERAL_SUFFIX,
+    #[clippy::version = "1.70.0"]
+    pub LITERAL_SUFFIX_STYLE,
     restriction,
-    "literals whose suffix is separated by an underscore"
+    "literals whose suffix is not written in the configured style"
 }
 
 declare_clippy_lint! {
@@ -318,13 +293,37 @@
     "tuple patterns with a wildcard pattern (`_`) is next to a rest pattern (`..`)"
 }
 
-declare_lint_pass!(MiscEarlyLints => [
+/// Whether literal suffixes should be separated by an underscore.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SuffixStyle {
+    /// `123_i32`
+    Separated,
+    /// `123i32`
+    Unseparated,
+}
+
+pub struct MiscEarlyLints {
+    suffix_style: SuffixStyle,
+    digit_grouping_min_digits: u64,
+}
+
+impl MiscEarlyLints {
+    #[must_use]
+    pub fn new(suffix_style: SuffixStyle, digit_grouping_min_digits: u64) -> Self {
+        Self {
+            suffix_style,
+            digit_grouping_min_digits,
+        }
+    }
+}
+
+impl_lint_pass!(MiscEarlyLints => [
     UNNEEDED_FIELD_PATTERN,
     DUPLICATE_UNDERSCORE_ARGUMENT,
     DOUBLE_NEG,
     MIXED_CASE_HEX_LITERALS,
-    UNSEPARATED_LITERAL_SUFFIX,
-    SEPARATED_LITERAL_SUFFIX,
+    LITERAL_SUFFIX_STYLE,
     ZERO_PREFIXED_LITERAL,
     BUILTIN_TYPE_SHADOW,
     REDUNDANT_PATTERN,
@@ -376,14 +375,14 @@ fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
         }
 
         if let ExprKind::Lit(lit) = expr.kind {
-            MiscEarlyLints::check_lit(cx, lit, expr.span);
+            self.check_lit(cx, lit, expr.span);
         }
         double_neg::check(cx, expr);
     }
 }
 
 impl MiscEarlyLints {
-    fn check_lit(cx: &EarlyContext<'_>, lit: token::Lit, span: Span) {
+    fn check_lit(&self, cx: &EarlyContext<'_>, lit: token::Lit, span: Span) {
         // We test if first character in snippet is a number, because the snippet could be an expansion
         // from a built-in macro like `line!()` or a proc-macro like `#[wasm_bindgen]`.
         // Note that this check also covers special case that `line!()` is eagerly expanded by compiler.
@@ -401,7 +400,15 @@ fn check_lit(cx: &EarlyContext<'_>, lit: token::Lit, span: Span) {
                 LitIntType::Unsigned(ty) => ty.name_str(),
                 LitIntType::Unsuffixed => "",
             };
-            literal_suffix::check(cx, span, &lit_snip, suffix, "integer");
+            literal_suffix::check(
+                cx,
+                span,
+                &lit_snip,
+                suffix,
+                false,
+                self.suffix_style,
+                self.digit_grouping_min_digits,
+            );
             if lit_snip.starts_with("0x") {
                 mixed_case_hex_literals::check(cx, span, suffix, &lit_snip);
             } else if lit_snip.starts_with("0b") || lit_snip.starts_with("0o") {
@@ -409,9 +416,20 @@ fn check_lit(cx: &EarlyContext<'_>, lit: token::Lit, span: Span) {
             } else if value != 0 && lit_snip.starts_with('0') {
                 zero_prefixed_literal::check(cx, span, &lit_snip);
             }
-        } else if let Ok(LitKind::Float(_, LitFloatType::Suffixed(float_ty))) = lit_kind {
-            let suffix = float_ty.name_str();
-            literal_suffix::check(cx, span, &lit_snip, suffix, "float");
+        } else if let Ok(LitKind::Float(_, lit_float_type)) = lit_kind {
+            let suffix = match lit_float_type {
+                LitFloatType::Suffixed(float_ty) => float_ty.name_str(),
+                LitFloatType::Unsuffixed => "",
+            };
+            literal_suffix::check(
+                cx,
+                span,
+                &lit_snip,
+                suffix,
+                true,
+                self.suffix_style,
+                self.digit_grouping_min_digits,
+            );
         }
     }
 }
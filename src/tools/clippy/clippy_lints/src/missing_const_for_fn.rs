@@ -26,6 +26,15 @@
     /// on nightly. This lint does not consider all edge cases currently and the suggestions may be
     /// incorrect if you are using this lint on stable.
     ///
+    /// The function bodies are not checked with the const checker of `rustc`, but with a copy of
+    /// its former `const fn` checks kept in Clippy. These reject some constructs `rustc` accepts,
+    /// and may accept some it rejects, so the lint stays in `nursery`.
+    ///
+    /// The union field accesses, `transmute` calls, unsizing casts, trait bounds and function
+    /// pointers allowed in `const fn` are only accepted if the configured MSRV is recent enough.
+    /// Functions calling trait methods are never linted, as this requires
+    /// `#![feature(const_trait_impl)]`.
+    ///
     /// Also, the lint only runs one pass over the code. Consider these two non-const functions:
     ///
     /// ```rust
@@ -0,0 +1,127 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::ty::{implements_trait, is_type_diagnostic_item};
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_hir::def_id::LocalDefId;
+use rustc_hir::{FnDecl, ImplItem, ImplItemKind, Item, ItemKind, TraitItem, TraitItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for types of the crate which are the error type of a `Result` returned by an
+    /// exported function, but don't implement `Debug` or `Display`.
+    ///
+    /// This is a narrower version of rustc's `missing_debug_implementations` lint, which
+    /// requires `Debug` on all exported types.
+    ///
+    /// ### Why is this bad?
+    /// Calling `unwrap` or `expect` on a `Result` requires its error type to implement
+    /// `Debug`, and reporting the error to a user usually requires `Display`. Users of the crate
+    /// can't add these implementations themselves.
+    ///
+    /// ### Example
+    /// ```rust
+    /// pub struct ParseError;
+    ///
+    /// pub fn parse(input: &str) -> Result<u32, ParseError> {
+    ///     input.parse().map_err(|_| ParseError)
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[derive(Debug)]
+    /// pub struct ParseError;
+    ///
+    /// impl std::fmt::Display for ParseError {
+    ///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    ///         f.write_str("invalid number")
+    ///     }
+    /// }
+    ///
+    /// pub fn parse(input: &str) -> Result<u32, ParseError> {
+    ///     input.parse().map_err(|_| ParseError)
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub MISSING_DEBUG_FOR_PUBLIC_ERROR_TYPES,
+    pedantic,
+    "error types returned by exported functions which don't implement `Debug` or `Display`"
+}
+
+#[derive(Default)]
+pub struct MissingDebugForPublicErrorTypes {
+    /// The local error types, each with the first return type using it.
+    error_types: FxIndexMap<LocalDefId, Span>,
+}
+
+impl_lint_pass!(MissingDebugForPublicErrorTypes => [MISSING_DEBUG_FOR_PUBLIC_ERROR_TYPES]);
+
+impl<'tcx> LateLintPass<'tcx> for MissingDebugForPublicErrorTypes {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Fn(ref sig, ..) = item.kind {
+            self.check_fn_decl(cx, item.owner_id.def_id, sig.decl);
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'_>) {
+        if let ImplItemKind::Fn(ref sig, _) = item.kind {
+            self.check_fn_decl(cx, item.owner_id.def_id, sig.decl);
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
+        if let TraitItemKind::Fn(ref sig, _) = item.kind {
+            self.check_fn_decl(cx, item.owner_id.def_id, sig.decl);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        let debug = cx.tcx.get_diagnostic_item(sym::Debug);
+        let display = cx.tcx.get_diagnostic_item(sym::Display);
+        for (&def_id, &ret_span) in &self.error_types {
+            let ty = cx.tcx.type_of(def_id).subst_identity();
+            let missing: Vec<_> = [("Debug", debug), ("Display", display)]
+                .into_iter()
+                .filter_map(|(name, trait_id)| (!implements_trait(cx, ty, trait_id?, &[])).then_some(name))
+                .collect();
+            let missing = match *missing {
+                [] => continue,
+                [name] => format!("`{name}`"),
+                _ => "`Debug` or `Display`".to_string(),
+            };
+            span_lint_hir_and_then(
+                cx,
+                MISSING_DEBUG_FOR_PUBLIC_ERROR_TYPES,
+                cx.tcx.hir().local_def_id_to_hir_id(def_id),
+                cx.tcx.def_span(def_id),
+                &format!("this type is returned as an error by an exported function, but doesn't implement {missing}"),
+                |diag| {
+                    diag.span_note(ret_span, "it's returned as an error here");
+                    diag.help("users need `Debug` to `unwrap` the `Result`, and `Display` to report the error");
+                },
+            );
+        }
+    }
+}
+
+impl MissingDebugForPublicErrorTypes {
+    fn check_fn_decl(&mut self, cx: &LateContext<'_>, fn_def_id: LocalDefId, decl: &FnDecl<'_>) {
+        if !cx.effective_visibilities.is_exported(fn_def_id) || decl.output.span().from_expansion() {
+            return;
+        }
+        let ret_ty = cx
+            .tcx
+            .erase_late_bound_regions(cx.tcx.fn_sig(fn_def_id).subst_identity().output());
+        if let ty::Adt(_, substs) = ret_ty.kind()
+            && is_type_diagnostic_item(cx, ret_ty, sym::Result)
+            && let ty::Adt(adt, error_substs) = substs.type_at(1).kind()
+            // Whether a generic error type implements the traits depends on its parameters
+            && error_substs.is_empty()
+            && let Some(def_id) = adt.did().as_local()
+        {
+            self.error_types.entry(def_id).or_insert(decl.output.span());
+        }
+    }
+}
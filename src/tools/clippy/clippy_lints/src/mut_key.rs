@@ -1,4 +1,4 @@
-use clippy_utils::diagnostics::span_lint;
+use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::{def_path_def_ids, trait_ref_of_method};
 use rustc_data_structures::fx::FxHashSet;
 use rustc_hir as hir;
@@ -6,7 +6,7 @@
 use rustc_middle::ty::TypeVisitableExt;
 use rustc_middle::ty::{Adt, Array, Ref, Slice, Tuple, Ty};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
-use rustc_span::def_id::LocalDefId;
+use rustc_span::def_id::{DefId, LocalDefId};
 use rustc_span::source_map::Span;
 use rustc_span::symbol::sym;
 use std::iter;
@@ -28,18 +28,17 @@
     /// However, this lint is unable to recognize this, so it will often cause false positives in
     /// theses cases.  The `bytes` crate is a great example of this.
     ///
-    /// #### False Negatives
-    /// For custom `struct`s/`enum`s, this lint is unable to check for interior mutability behind
-    /// indirection.  For example, `struct BadKey<'a>(&'a Cell<usize>)` will be seen as immutable
-    /// and cause a false negative if its implementation of `Hash`/`Ord` accesses the `Cell`.
+    /// Types to ignore can be listed in the `ignore-interior-mutability` configuration; only their
+    /// type parameters are checked.
     ///
-    /// This lint does check a few cases for indirection.  Firstly, using some standard library
-    /// types (`Option`, `Result`, `Box`, `Rc`, `Arc`, `Vec`, `VecDeque`, `BTreeMap` and
-    /// `BTreeSet`) directly as keys (e.g. in `HashMap<Box<Cell<usize>>, ()>`) **will** trigger the
-    /// lint, because the impls of `Hash`/`Ord` for these types directly call `Hash`/`Ord` on their
-    /// contained type.
+    /// #### Indirection
+    /// The fields of custom `struct`s/`enum`s are checked, including the ones behind references
+    /// and some standard library types (`Option`, `Result`, `Box`, `Rc`, `Arc`, `Vec`,
+    /// `VecDeque`, `BTreeMap` and `BTreeSet`), whose impls of `Hash`/`Ord` directly call
+    /// `Hash`/`Ord` on their contained type. For example, both `HashMap<Box<Cell<usize>>, ()>` and
+    /// `HashMap<BadKey, ()>` with `struct BadKey(Arc<Cell<usize>>)` **will** trigger the lint.
     ///
-    /// Secondly, the implementations of `Hash` and `Ord` for raw pointers (`*const T` or `*mut T`)
+    /// However, the implementations of `Hash` and `Ord` for raw pointers (`*const T` or `*mut T`)
     /// apply only to the **address** of the contained value.  Therefore, interior mutability
     /// behind raw pointers (e.g. in `HashSet<*mut Cell<usize>>`) can't impact the value of `Hash`
     /// or `Ord`, and therefore will not trigger this link.  For more info, see issue
@@ -153,24 +152,72 @@ fn check_ty_<'tcx>(&self, cx: &LateContext<'tcx>, span: Span, ty: Ty<'tcx>) {
             let is_keyed_type = [sym::HashMap, sym::BTreeMap, sym::HashSet, sym::BTreeSet]
                 .iter()
                 .any(|diag_item| cx.tcx.is_diagnostic_item(*diag_item, def.did()));
-            if is_keyed_type && self.is_interior_mutable_type(cx, substs.type_at(0)) {
-                span_lint(cx, MUTABLE_KEY_TYPE, span, "mutable key type");
+            if !is_keyed_type {
+                return;
+            }
+            if let Some((path, mutable_ty)) = self.interior_mutability(cx, substs.type_at(0), &mut FxHashSet::default())
+            {
+                span_lint_and_then(cx, MUTABLE_KEY_TYPE, span, "mutable key type", |diag| {
+                    if !path.is_empty() {
+                        let fields: String = path.iter().rev().map(|(field, _)| format!(".{field}")).collect();
+                        let variants: Vec<_> = path
+                            .iter()
+                            .rev()
+                            .filter_map(|&(_, variant)| Some(format!("`{}`", cx.tcx.def_path_str(variant?))))
+                            .collect();
+                        let variants = match &*variants {
+                            [] => String::new(),
+                            [variant] => format!(" of the variant {variant}"),
+                            _ => format!(" of the variants {}", variants.join(", ")),
+                        };
+                        diag.note(format!(
+                            "the key type has interior mutability in its field `{fields}`{variants}, of type `{mutable_ty}`"
+                        ));
+                    }
+                });
             }
         }
     }
 
     /// Determines if a type contains interior mutability which would affect its implementation of
-    /// [`Hash`] or [`Ord`].
-    fn is_interior_mutable_type<'tcx>(&self, cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    /// [`Hash`] or [`Ord`]. If so, returns the path of fields leading to the interior mutable type,
+    /// innermost first and each with the enum variant it belongs to, along with that type.
+    ///
+    /// The fields of types from outside `std` are walked, so interior mutability behind a
+    /// newtype or an `Arc` is found. `visited` guards against recursive types.
+    fn interior_mutability<'tcx>(
+        &self,
+        cx: &LateContext<'tcx>,
+        ty: Ty<'tcx>,
+        visited: &mut FxHashSet<Ty<'tcx>>,
+    ) -> Option<(Vec<(String, Option<DefId>)>, Ty<'tcx>)> {
+        if !visited.insert(ty) {
+            return None;
+        }
         match *ty.kind() {
-            Ref(_, inner_ty, mutbl) => mutbl == hir::Mutability::Mut || self.is_interior_mutable_type(cx, inner_ty),
-            Slice(inner_ty) => self.is_interior_mutable_type(cx, inner_ty),
+            Ref(_, inner_ty, mutbl) => {
+                if mutbl == hir::Mutability::Mut {
+                    Some((Vec::new(), ty))
+                } else {
+                    self.interior_mutability(cx, inner_ty, visited)
+                }
+            },
+            Slice(inner_ty) => self.interior_mutability(cx, inner_ty, visited),
             Array(inner_ty, size) => {
-                size.try_eval_target_usize(cx.tcx, cx.param_env)
+                if size
+                    .try_eval_target_usize(cx.tcx, cx.param_env)
                     .map_or(true, |u| u != 0)
-                    && self.is_interior_mutable_type(cx, inner_ty)
+                {
+                    self.interior_mutability(cx, inner_ty, visited)
+                } else {
+                    None
+                }
             },
-            Tuple(fields) => fields.iter().any(|ty| self.is_interior_mutable_type(cx, ty)),
+            Tuple(fields) => fields.iter().enumerate().find_map(|(i, field_ty)| {
+                let (mut path, mutable_ty) = self.interior_mutability(cx, field_ty, visited)?;
+                path.push((i.to_string(), None));
+                Some((path, mutable_ty))
+            }),
             Adt(def, substs) => {
                 // Special case for collections in `std` who's impl of `Hash` or `Ord` delegates to
                 // that of their type parameters.  Note: we don't include `HashSet` and `HashMap`
@@ -192,14 +239,24 @@ fn is_interior_mutable_type<'tcx>(&self, cx: &LateContext<'tcx>, ty: Ty<'tcx>) -
                 let is_box = Some(def_id) == cx.tcx.lang_items().owned_box();
                 if is_std_collection || is_box || self.ignore_mut_def_ids.contains(&def_id) {
                     // The type is mutable if any of its type parameters are
-                    substs.types().any(|ty| self.is_interior_mutable_type(cx, ty))
-                } else {
-                    !ty.has_escaping_bound_vars()
+                    substs.types().find_map(|ty| self.interior_mutability(cx, ty, visited))
+                } else if matches!(cx.tcx.crate_name(def_id.krate), sym::std | sym::core | sym::alloc) {
+                    (!ty.has_escaping_bound_vars()
                         && cx.tcx.layout_of(cx.param_env.and(ty)).is_ok()
-                        && !ty.is_freeze(cx.tcx, cx.param_env)
+                        && !ty.is_freeze(cx.tcx, cx.param_env))
+                    .then(|| (Vec::new(), ty))
+                } else {
+                    def.variants().iter().find_map(|variant| {
+                        variant.fields.iter().find_map(|field| {
+                            let (mut path, mutable_ty) =
+                                self.interior_mutability(cx, field.ty(cx.tcx, substs), visited)?;
+                            path.push((field.name.to_string(), def.is_enum().then_some(variant.def_id)));
+                            Some((path, mutable_ty))
+                        })
+                    })
                 }
             },
-            _ => false,
+            _ => None,
         }
     }
 }
@@ -63,6 +63,13 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
     }
 }
 
+/// Checks if `DEBUG_ASSERT_WITH_MUT_CALL` lints `arg`, an argument of a `debug_assert!`.
+pub(crate) fn has_mut_arg<'tcx>(cx: &LateContext<'tcx>, arg: &'tcx Expr<'_>) -> bool {
+    let mut visitor = MutArgVisitor::new(cx);
+    visitor.visit_expr(arg);
+    visitor.expr_span().is_some()
+}
+
 struct MutArgVisitor<'a, 'tcx> {
     cx: &'a LateContext<'tcx>,
     expr_span: Option<Span>,
@@ -1,18 +1,25 @@
 //! Checks for uses of mutex where an atomic value could be used
 //!
-//! This lint is **allow** by default
+//! Both lints are **allow** by default, see their "Known problems" sections for why
 
-use clippy_utils::diagnostics::span_lint;
+use clippy_utils::diagnostics::{span_lint, span_lint_hir};
 use clippy_utils::ty::is_type_diagnostic_item;
-use rustc_hir::Expr;
-use rustc_lint::{LateContext, LateLintPass};
+use clippy_utils::visitors::for_each_expr_with_closures;
+use clippy_utils::{get_parent_expr, get_parent_node, path_def_id, path_to_local, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind, HirId, ItemKind, Node, PatKind, QPath, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass, Lint};
 use rustc_middle::ty::{self, Ty};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
-use rustc_span::sym;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for usages of `Mutex<X>` where an atomic will do.
+    /// Checks for usages of `Mutex<X>` where an atomic will do, i.e. where every lock of the
+    /// mutex only reads or writes the value once.
     ///
     /// ### Why is this bad?
     /// Using a mutex just to make access to a plain bool or
@@ -25,21 +32,27 @@
     /// an equivalent mutex. See [this issue](https://github.com/rust-lang/rust-clippy/issues/4295)'s commentary for more details.
     ///
     /// ### Known problems
-    /// This lint cannot detect if the mutex is actually used
-    /// for waiting before a critical section.
+    /// Only mutexes stored in a local, a struct field or a `static` are checked, and only if all
+    /// of their uses can be seen. A mutex which is only locked by code in other crates is not
+    /// linted.
+    ///
+    /// Locking and unlocking a mutex also orders the memory accesses around it. Code which relies
+    /// on that to publish other data still needs the mutex (or a fence), even though each lock
+    /// only touches the `bool` once, and the lint can't tell these cases apart. This is why the
+    /// lint is in the `restriction` group rather than `perf`.
     ///
     /// ### Example
     /// ```rust
-    /// # let y = true;
     /// # use std::sync::Mutex;
-    /// let x = Mutex::new(&y);
+    /// let x = Mutex::new(false);
+    /// *x.lock().unwrap() = true;
     /// ```
     ///
     /// Use instead:
     /// ```rust
-    /// # let y = true;
-    /// # use std::sync::atomic::AtomicBool;
-    /// let x = AtomicBool::new(y);
+    /// # use std::sync::atomic::{AtomicBool, Ordering};
+    /// let x = AtomicBool::new(false);
+    /// x.store(true, Ordering::SeqCst);
     /// ```
     #[clippy::version = "pre 1.29.0"]
     pub MUTEX_ATOMIC,
@@ -50,7 +63,7 @@
 declare_clippy_lint! {
     /// ### What it does
     /// Checks for usages of `Mutex<X>` where `X` is an integral
-    /// type.
+    /// type, and every lock of the mutex only reads or writes the value once.
     ///
     /// ### Why is this bad?
     /// Using a mutex just to make access to a plain integer
@@ -58,19 +71,25 @@
     /// shooting flies with cannons. `std::sync::atomic::AtomicUsize` is leaner and faster.
     ///
     /// ### Known problems
-    /// This lint cannot detect if the mutex is actually used
-    /// for waiting before a critical section.
+    /// Only mutexes stored in a local, a struct field or a `static` are checked, and only if all
+    /// of their uses can be seen.
+    ///
+    /// Like `mutex_atomic`, this can't tell whether the mutex also synchronizes accesses to other
+    /// data, e.g. a counter which is incremented after filling a buffer. It stays in `nursery`
+    /// until that is checked.
     ///
     /// ### Example
     /// ```rust
     /// # use std::sync::Mutex;
-    /// let x = Mutex::new(0usize);
+    /// let x = Mutex::new(0u32);
+    /// *x.lock().unwrap() += 1;
     /// ```
     ///
     /// Use instead:
     /// ```rust
-    /// # use std::sync::atomic::AtomicUsize;
-    /// let x = AtomicUsize::new(0usize);
+    /// # use std::sync::atomic::{AtomicU32, Ordering};
+    /// let x = AtomicU32::new(0);
+    /// x.fetch_add(1, Ordering::SeqCst);
     /// ```
     #[clippy::version = "pre 1.29.0"]
     pub MUTEX_INTEGER,
@@ -78,36 +97,377 @@
     "using a mutex for an integer type"
 }
 
-declare_lint_pass!(Mutex => [MUTEX_ATOMIC, MUTEX_INTEGER]);
+/// A `Mutex::new` call stored in a struct field or `static`, which can only be checked once all
+/// of the crate has been seen.
+struct Candidate {
+    place: DefId,
+    hir_id: HirId,
+    span: Span,
+    lint: &'static Lint,
+    msg: String,
+}
+
+#[derive(Default)]
+pub struct Mutex {
+    candidates: Vec<Candidate>,
+    /// Whether every use of a struct field or `static` holding a mutex is a lock which only
+    /// reads or writes the value once.
+    simple_places: FxHashMap<DefId, bool>,
+}
+
+impl_lint_pass!(Mutex => [MUTEX_ATOMIC, MUTEX_INTEGER]);
 
 impl<'tcx> LateLintPass<'tcx> for Mutex {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(place) = mutex_place_use(cx, expr) {
+            let simple = lock_is_simple(cx, expr);
+            *self.simple_places.entry(place).or_insert(true) &= simple;
+            return;
+        }
+
+        let ExprKind::Call(func, [_]) = expr.kind else {
+            return;
+        };
         let ty = cx.typeck_results().expr_ty(expr);
-        if let ty::Adt(_, subst) = ty.kind() {
-            if is_type_diagnostic_item(cx, ty, sym::Mutex) {
-                let mutex_param = subst.type_at(0);
-                if let Some(atomic_name) = get_atomic_name(mutex_param) {
-                    let msg = format!(
-                        "consider using an `{atomic_name}` instead of a `Mutex` here; if you just want the locking \
-                         behavior and not the internal type, consider using `Mutex<()>`"
-                    );
-                    match *mutex_param.kind() {
-                        ty::Uint(t) if t != ty::UintTy::Usize => span_lint(cx, MUTEX_INTEGER, expr.span, &msg),
-                        ty::Int(t) if t != ty::IntTy::Isize => span_lint(cx, MUTEX_INTEGER, expr.span, &msg),
-                        _ => span_lint(cx, MUTEX_ATOMIC, expr.span, &msg),
-                    };
+        if let ty::Adt(_, subst) = ty.kind()
+            && is_type_diagnostic_item(cx, ty, sym::Mutex)
+            && is_new(func)
+            && !expr.span.from_expansion()
+        {
+            let mutex_param = subst.type_at(0);
+            if let Some(atomic_name) = get_atomic_name(mutex_param) {
+                let msg = format!(
+                    "consider using an `{atomic_name}` instead of a `Mutex` here; if you just want the locking \
+                     behavior and not the internal type, consider using `Mutex<()>`"
+                );
+                let lint = match *mutex_param.kind() {
+                    ty::Uint(t) if t != ty::UintTy::Usize => MUTEX_INTEGER,
+                    ty::Int(t) if t != ty::IntTy::Isize => MUTEX_INTEGER,
+                    _ => MUTEX_ATOMIC,
+                };
+                match storage(cx, expr) {
+                    Some(Storage::Local(local_id)) => {
+                        if local_uses_are_simple(cx, local_id) {
+                            span_lint(cx, lint, expr.span, &msg);
+                        }
+                    },
+                    Some(Storage::Place(place)) => self.candidates.push(Candidate {
+                        place,
+                        hir_id: expr.hir_id,
+                        span: expr.span,
+                        lint,
+                        msg,
+                    }),
+                    None => {},
                 }
             }
         }
     }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for candidate in &self.candidates {
+            if self.simple_places.get(&candidate.place) == Some(&true)
+                && !cx.effective_visibilities.is_exported(candidate.place.expect_local())
+            {
+                span_lint_hir(cx, candidate.lint, candidate.hir_id, candidate.span, &candidate.msg);
+            }
+        }
+    }
 }
 
 fn get_atomic_name(ty: Ty<'_>) -> Option<&'static str> {
     match ty.kind() {
         ty::Bool => Some("AtomicBool"),
-        ty::Uint(_) => Some("AtomicUsize"),
-        ty::Int(_) => Some("AtomicIsize"),
+        ty::Uint(ty::UintTy::U8) => Some("AtomicU8"),
+        ty::Uint(ty::UintTy::U16) => Some("AtomicU16"),
+        ty::Uint(ty::UintTy::U32) => Some("AtomicU32"),
+        ty::Uint(ty::UintTy::U64) => Some("AtomicU64"),
+        ty::Uint(ty::UintTy::Usize) => Some("AtomicUsize"),
+        ty::Int(ty::IntTy::I8) => Some("AtomicI8"),
+        ty::Int(ty::IntTy::I16) => Some("AtomicI16"),
+        ty::Int(ty::IntTy::I32) => Some("AtomicI32"),
+        ty::Int(ty::IntTy::I64) => Some("AtomicI64"),
+        ty::Int(ty::IntTy::Isize) => Some("AtomicIsize"),
         ty::RawPtr(_) => Some("AtomicPtr"),
         _ => None,
     }
 }
+
+fn is_new(func: &Expr<'_>) -> bool {
+    matches!(func.kind, ExprKind::Path(QPath::TypeRelative(_, segment)) if segment.ident.name == sym::new)
+}
+
+enum Storage {
+    Local(HirId),
+    Place(DefId),
+}
+
+/// Finds where the mutex created by `expr` is stored, looking through `Arc::new`, `Rc::new` and
+/// `Box::new`.
+fn storage<'tcx>(cx: &LateContext<'tcx>, mut expr: &'tcx Expr<'tcx>) -> Option<Storage> {
+    loop {
+        match get_parent_node(cx.tcx, expr.hir_id)? {
+            Node::Expr(parent) => {
+                let ExprKind::Call(func, [_]) = parent.kind else {
+                    return None;
+                };
+                let ty = cx.typeck_results().expr_ty(parent);
+                let is_pointer = ty.is_box()
+                    || is_type_diagnostic_item(cx, ty, sym::Arc)
+                    || is_type_diagnostic_item(cx, ty, sym::Rc);
+                if !is_pointer || !is_new(func) {
+                    return None;
+                }
+                expr = parent;
+            },
+            Node::Local(local) => {
+                return if let PatKind::Binding(_, id, _, None) = local.pat.kind
+                    && local.els.is_none()
+                {
+                    Some(Storage::Local(id))
+                } else {
+                    None
+                };
+            },
+            Node::ExprField(field) => {
+                let Some(Node::Expr(struct_expr)) = get_parent_node(cx.tcx, field.hir_id) else {
+                    return None;
+                };
+                let ExprKind::Struct(qpath, ..) = struct_expr.kind else {
+                    return None;
+                };
+                let ty::Adt(adt, _) = cx.typeck_results().expr_ty(struct_expr).kind() else {
+                    return None;
+                };
+                let variant = adt.variant_of_res(cx.qpath_res(qpath, struct_expr.hir_id));
+                let field_def = variant.fields.iter().find(|f| f.name == field.ident.name)?;
+                return field_def.did.is_local().then_some(Storage::Place(field_def.did));
+            },
+            Node::Item(item) => {
+                return matches!(item.kind, ItemKind::Static(..)).then(|| Storage::Place(item.owner_id.to_def_id()));
+            },
+            _ => return None,
+        }
+    }
+}
+
+/// If `expr` refers to a local struct field or `static` holding a mutex of a type which could be
+/// an atomic, returns its `DefId`.
+fn mutex_place_use(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<DefId> {
+    let place = match expr.kind {
+        ExprKind::Field(base, _) => {
+            let adt = cx.typeck_results().expr_ty_adjusted(base).peel_refs().ty_adt_def()?;
+            if !adt.is_struct() {
+                return None;
+            }
+            adt.non_enum_variant().fields[cx.typeck_results().field_index(expr.hir_id)].did
+        },
+        ExprKind::Path(QPath::Resolved(None, path)) => match path.res {
+            Res::Def(DefKind::Static(_), did) => did,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let mut ty = cx.typeck_results().expr_ty(expr).peel_refs();
+    if ty.is_box() {
+        ty = ty.boxed_ty();
+    } else if let ty::Adt(_, subst) = ty.kind()
+        && (is_type_diagnostic_item(cx, ty, sym::Arc) || is_type_diagnostic_item(cx, ty, sym::Rc))
+    {
+        ty = subst.type_at(0);
+    }
+    if let ty::Adt(_, subst) = ty.kind()
+        && is_type_diagnostic_item(cx, ty, sym::Mutex)
+        && get_atomic_name(subst.type_at(0)).is_some()
+        && place.is_local()
+    {
+        Some(place)
+    } else {
+        None
+    }
+}
+
+/// Checks that the local holding a mutex is used at least once, and that all its uses are locks
+/// which only read or write the value once, or clones into other such locals.
+fn local_uses_are_simple(cx: &LateContext<'_>, local_id: HirId) -> bool {
+    let Some(body_id) = cx.enclosing_body else {
+        return false;
+    };
+    let body = cx.tcx.hir().body(body_id);
+    let mut locks = 0;
+    let mut aliases = Vec::new();
+    let complex = for_each_expr_with_closures(cx, body.value, |e| {
+        if !path_to_local_id(e, local_id) {
+            ControlFlow::Continue(())
+        } else if let Some(alias) = clone_alias(cx, e) {
+            aliases.push(alias);
+            ControlFlow::Continue(())
+        } else if lock_is_simple(cx, e) {
+            locks += 1;
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    })
+    .is_some();
+    !complex && (locks > 0 || !aliases.is_empty()) && aliases.into_iter().all(|alias| local_uses_are_simple(cx, alias))
+}
+
+/// If `e` is cloned into a new local, as in `let b = a.clone()` or `let b = Arc::clone(&a)`,
+/// returns the new local.
+fn clone_alias(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<HirId> {
+    let parent = get_parent_expr(cx, e)?;
+    let clone = match parent.kind {
+        ExprKind::MethodCall(path, _, [], _) if path.ident.name == sym::clone => parent,
+        ExprKind::AddrOf(_, _, _) => {
+            let call = get_parent_expr(cx, parent)?;
+            if let ExprKind::Call(func, [_]) = call.kind
+                && let ExprKind::Path(QPath::TypeRelative(_, segment)) = func.kind
+                && segment.ident.name == sym::clone
+            {
+                call
+            } else {
+                return None;
+            }
+        },
+        _ => return None,
+    };
+    if let Some(Node::Local(local)) = get_parent_node(cx.tcx, clone.hir_id)
+        && let PatKind::Binding(_, id, _, None) = local.pat.kind
+    {
+        Some(id)
+    } else {
+        None
+    }
+}
+
+/// Checks whether `e` is locked, and the value is only read or written once while the lock is
+/// held.
+fn lock_is_simple<'tcx>(cx: &LateContext<'tcx>, mut e: &'tcx Expr<'tcx>) -> bool {
+    let mut parent = get_parent_expr(cx, e);
+    while let Some(p) = parent
+        && let ExprKind::AddrOf(_, _, _) | ExprKind::Unary(UnOp::Deref, _) = p.kind
+    {
+        e = p;
+        parent = get_parent_expr(cx, p);
+    }
+    if let Some(lock) = parent
+        && let ExprKind::MethodCall(path, recv, [], _) = lock.kind
+        && recv.hir_id == e.hir_id
+        && path.ident.name.as_str() == "lock"
+        && let Some(guard) = get_parent_expr(cx, lock)
+        && let ExprKind::MethodCall(path, _, _, _) = guard.kind
+        && matches!(path.ident.name.as_str(), "unwrap" | "expect")
+    {
+        guard_use_is_simple(cx, guard)
+    } else {
+        false
+    }
+}
+
+fn guard_use_is_simple(cx: &LateContext<'_>, guard: &Expr<'_>) -> bool {
+    match get_parent_node(cx.tcx, guard.hir_id) {
+        Some(Node::Expr(deref)) => {
+            matches!(deref.kind, ExprKind::Unary(UnOp::Deref, _)) && deref_access_is_simple(cx, deref)
+        },
+        Some(Node::Local(local)) => {
+            if let PatKind::Binding(_, guard_id, _, None) = local.pat.kind
+                && local.els.is_none()
+                && let Some(Node::Stmt(stmt)) = get_parent_node(cx.tcx, local.hir_id)
+                && let Some(Node::Block(block)) = get_parent_node(cx.tcx, stmt.hir_id)
+                && let Some(index) = block.stmts.iter().position(|s| s.hir_id == stmt.hir_id)
+            {
+                bound_guard_is_simple(cx, block, index, guard_id)
+            } else {
+                false
+            }
+        },
+        _ => false,
+    }
+}
+
+/// Checks that the guard bound by the statement at `index` is used exactly once, in the
+/// following statement, and is dropped right after.
+fn bound_guard_is_simple<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    index: usize,
+    guard_id: HirId,
+) -> bool {
+    let mut rest = block.stmts[index + 1..]
+        .iter()
+        .map(|stmt| match stmt.kind {
+            StmtKind::Local(local) => local.init,
+            StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+            StmtKind::Item(_) => None,
+        })
+        .chain(block.expr.map(Some));
+    let Some(Some(next)) = rest.next() else {
+        return false;
+    };
+    match (rest.next(), rest.next()) {
+        (None, _) => {},
+        (Some(Some(drop)), None) if is_drop_of(cx, drop, guard_id) => {},
+        _ => return false,
+    }
+
+    let mut uses = Vec::new();
+    for_each_expr_with_closures(cx, next, |e| {
+        if path_to_local_id(e, guard_id) {
+            uses.push(e);
+        }
+        ControlFlow::<!, _>::Continue(())
+    });
+    if let [guard] = *uses
+        && let Some(deref) = get_parent_expr(cx, guard)
+        && let ExprKind::Unary(UnOp::Deref, _) = deref.kind
+    {
+        deref_access_is_simple(cx, deref)
+    } else {
+        false
+    }
+}
+
+fn is_drop_of(cx: &LateContext<'_>, e: &Expr<'_>, local_id: HirId) -> bool {
+    if let ExprKind::Call(func, [arg]) = e.kind
+        && path_to_local(arg) == Some(local_id)
+        && let Some(def_id) = path_def_id(cx, func)
+    {
+        cx.tcx.is_diagnostic_item(sym::mem_drop, def_id)
+    } else {
+        false
+    }
+}
+
+/// Checks that `*guard` is a single read, write, or an operation an atomic can do in one step,
+/// and that a temporary guard isn't kept alive by a `match`.
+fn deref_access_is_simple(cx: &LateContext<'_>, deref: &Expr<'_>) -> bool {
+    match get_parent_expr(cx, deref).map(|p| &p.kind) {
+        Some(ExprKind::Assign(lhs, ..)) if lhs.hir_id == deref.hir_id => true,
+        Some(ExprKind::AssignOp(op, lhs, _)) if lhs.hir_id == deref.hir_id => matches!(
+            op.node,
+            BinOpKind::Add | BinOpKind::Sub | BinOpKind::BitAnd | BinOpKind::BitOr | BinOpKind::BitXor
+        ),
+        Some(ExprKind::AddrOf(..)) => false,
+        Some(ExprKind::MethodCall(_, recv, ..)) if recv.hir_id == deref.hir_id => false,
+        _ => {
+            // Temporaries live until the end of the enclosing statement, unless they are part of
+            // an `if` condition. A `match` or `if let` keeps them alive for all of its arms.
+            let mut child = deref.hir_id;
+            for (id, node) in cx.tcx.hir().parent_iter(deref.hir_id) {
+                match node {
+                    Node::Expr(e) => match e.kind {
+                        ExprKind::Match(scrutinee, ..) if scrutinee.hir_id == child => return false,
+                        ExprKind::Let(let_expr) if let_expr.init.hir_id == child => return false,
+                        ExprKind::If(cond, ..) if cond.hir_id == child => return true,
+                        _ => {},
+                    },
+                    Node::Block(_) => {},
+                    _ => return true,
+                }
+                child = id;
+            }
+            true
+        },
+    }
+}
@@ -1,10 +1,11 @@
 use clippy_utils::diagnostics::{multispan_sugg, span_lint_and_then};
+use clippy_utils::is_self;
+use clippy_utils::paths::PathLookup;
 use clippy_utils::ptr::get_spans;
 use clippy_utils::source::{snippet, snippet_opt};
 use clippy_utils::ty::{
     implements_trait, implements_trait_with_env, is_copy, is_type_diagnostic_item, is_type_lang_item,
 };
-use clippy_utils::{get_trait_def_id, is_self, paths};
 use if_chain::if_chain;
 use rustc_ast::ast::Attribute;
 use rustc_data_structures::fx::FxHashSet;
@@ -64,6 +65,8 @@
 
 declare_lint_pass!(NeedlessPassByValue => [NEEDLESS_PASS_BY_VALUE]);
 
+static RANGE_ARGUMENT_TRAIT: PathLookup = PathLookup::new(&["core", "ops", "RangeBounds"]);
+
 macro_rules! need {
     ($e: expr) => {
         if let Some(x) = $e {
@@ -117,7 +120,7 @@ fn check_fn(
             need!(cx.tcx.lang_items().fn_trait()),
             need!(cx.tcx.lang_items().fn_once_trait()),
             need!(cx.tcx.lang_items().fn_mut_trait()),
-            need!(get_trait_def_id(cx, &paths::RANGE_ARGUMENT_TRAIT)),
+            need!(RANGE_ARGUMENT_TRAIT.first(cx)),
         ];
 
         let sized_trait = need!(cx.tcx.lang_items().sized_trait());
@@ -1,6 +1,6 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::path_res;
-use clippy_utils::source::snippet;
+use clippy_utils::source::snippet_with_applicability;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
@@ -130,14 +130,15 @@ fn check(cx: &LateContext<'_>, expr: &Expr<'_>) {
         let inner_ty = cx.typeck_results().expr_ty(inner_expr);
         if expr_ty == inner_ty;
         then {
+            let mut app = Applicability::MachineApplicable;
             span_lint_and_sugg(
                 cx,
                 NEEDLESS_QUESTION_MARK,
                 expr.span,
                 "question mark operator is useless here",
                 &format!("try removing question mark and `{sugg_remove}`"),
-                format!("{}", snippet(cx, inner_expr.span, r#""...""#)),
-                Applicability::MachineApplicable,
+                format!("{}", snippet_with_applicability(cx, inner_expr.span, r#""...""#, &mut app)),
+                app,
             );
         }
     }
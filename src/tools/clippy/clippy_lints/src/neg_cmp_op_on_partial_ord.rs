@@ -1,10 +1,10 @@
 use clippy_utils::diagnostics::span_lint;
-use clippy_utils::ty::implements_trait;
+use clippy_utils::ty::ImplementsTraitCache;
 use if_chain::if_chain;
 use rustc_hir::{BinOpKind, Expr, ExprKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 use rustc_span::sym;
 
 declare_clippy_lint! {
@@ -42,9 +42,14 @@
     "The use of negated comparison operators on partially ordered types may produce confusing code."
 }
 
-declare_lint_pass!(NoNegCompOpForPartialOrd => [NEG_CMP_OP_ON_PARTIAL_ORD]);
+#[derive(Default)]
+pub struct NoNegCompOpForPartialOrd<'tcx> {
+    trait_cache: ImplementsTraitCache<'tcx>,
+}
+
+impl_lint_pass!(NoNegCompOpForPartialOrd<'_> => [NEG_CMP_OP_ON_PARTIAL_ORD]);
 
-impl<'tcx> LateLintPass<'tcx> for NoNegCompOpForPartialOrd {
+impl<'tcx> LateLintPass<'tcx> for NoNegCompOpForPartialOrd<'tcx> {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if_chain! {
             if !in_external_macro(cx.sess(), expr.span);
@@ -57,7 +62,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
 
                 let implements_ord = {
                     if let Some(id) = cx.tcx.get_diagnostic_item(sym::Ord) {
-                        implements_trait(cx, ty, id, &[])
+                        self.trait_cache.implements_trait(cx, ty, id, &[])
                     } else {
                         return;
                     }
@@ -65,7 +70,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
 
                 let implements_partial_ord = {
                     if let Some(id) = cx.tcx.lang_items().partial_ord_trait() {
-                        implements_trait(cx, ty, id, &[ty.into()])
+                        self.trait_cache.implements_trait(cx, ty, id, &[ty.into()])
                     } else {
                         return;
                     }
@@ -0,0 +1,108 @@
+use clippy_utils::diagnostics::{span_lint_and_then, span_lint_hir_and_then};
+use rustc_hir::{Expr, ExprKind, HirId, Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for:
+    /// - struct update syntax (`..base`) with an exported `#[non_exhaustive]` struct of the same
+    ///   crate,
+    /// - `#[non_exhaustive]` on structs, enums and variants which aren't exported.
+    ///
+    /// ### Why is this bad?
+    /// Outside of its crate, a `#[non_exhaustive]` struct can't be created with a struct
+    /// expression, not even with `..Default::default()`. Using it inside the crate, e.g. in a
+    /// macro or in examples copied from it, suggests that users can do the same, which they
+    /// can't.
+    ///
+    /// `#[non_exhaustive]` has no effect inside the defining crate, so on a type which isn't
+    /// visible outside of it, the attribute does nothing.
+    ///
+    /// ### Known problems
+    /// Struct update syntax inside the crate is often fine, e.g. in private constructors.
+    ///
+    /// ### Example
+    /// ```rust
+    /// #[non_exhaustive]
+    /// pub(crate) struct Options {
+    ///     pub verbose: bool,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub(crate) struct Options {
+    ///     pub verbose: bool,
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub NON_EXHAUSTIVE_STRUCT_UPDATE_IN_SAME_CRATE,
+    pedantic,
+    "struct update syntax with exported `#[non_exhaustive]` types, and `#[non_exhaustive]` on private types"
+}
+declare_lint_pass!(NonExhaustiveStructUpdateInSameCrate => [NON_EXHAUSTIVE_STRUCT_UPDATE_IN_SAME_CRATE]);
+
+impl<'tcx> LateLintPass<'tcx> for NonExhaustiveStructUpdateInSameCrate {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if item.span.from_expansion() || cx.effective_visibilities.is_exported(item.owner_id.def_id) {
+            return;
+        }
+        match item.kind {
+            ItemKind::Struct(..) => check_attr(cx, item.hir_id()),
+            ItemKind::Enum(ref def, _) => {
+                check_attr(cx, item.hir_id());
+                for variant in def.variants {
+                    check_attr(cx, variant.hir_id);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Struct(_, _, Some(_)) = expr.kind
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(adt) = cx.typeck_results().expr_ty(expr).ty_adt_def()
+            && adt.is_struct()
+            && let Some(def_id) = adt.did().as_local()
+            && cx.effective_visibilities.is_exported(def_id)
+            && adt.non_enum_variant().is_field_list_non_exhaustive()
+        {
+            span_lint_and_then(
+                cx,
+                NON_EXHAUSTIVE_STRUCT_UPDATE_IN_SAME_CRATE,
+                expr.span,
+                &format!(
+                    "`{}` is `#[non_exhaustive]`, so struct update syntax only works for it inside this crate",
+                    cx.tcx.item_name(adt.did())
+                ),
+                |diag| {
+                    diag.help("users of this crate need a constructor or a builder to create it");
+                },
+            );
+        }
+    }
+}
+
+fn check_attr(cx: &LateContext<'_>, hir_id: HirId) {
+    if let Some(attr) = cx
+        .tcx
+        .hir()
+        .attrs(hir_id)
+        .iter()
+        .find(|attr| attr.has_name(sym::non_exhaustive))
+    {
+        span_lint_hir_and_then(
+            cx,
+            NON_EXHAUSTIVE_STRUCT_UPDATE_IN_SAME_CRATE,
+            hir_id,
+            attr.span,
+            "`#[non_exhaustive]` has no effect, because this isn't visible outside of this crate",
+            |diag| {
+                diag.help("remove the attribute");
+            },
+        );
+    }
+}
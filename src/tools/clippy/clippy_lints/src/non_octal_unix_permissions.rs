@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::paths::{self, PathLookup};
 use clippy_utils::source::{snippet_opt, snippet_with_applicability};
-use clippy_utils::ty::{is_type_diagnostic_item, match_type};
-use clippy_utils::{match_def_path, paths};
+use clippy_utils::ty::is_type_diagnostic_item;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind};
@@ -41,6 +41,9 @@
 
 declare_lint_pass!(NonOctalUnixPermissions => [NON_OCTAL_UNIX_PERMISSIONS]);
 
+#[cfg_attr(not(unix), allow(clippy::invalid_paths))]
+static PERMISSIONS_FROM_MODE: PathLookup = PathLookup::new(&["std", "os", "unix", "fs", "PermissionsExt", "from_mode"]);
+
 impl<'tcx> LateLintPass<'tcx> for NonOctalUnixPermissions {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
         match &expr.kind {
@@ -49,9 +52,9 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
 
                 if_chain! {
                     if (path.ident.name == sym!(mode)
-                        && (match_type(cx, obj_ty, &paths::OPEN_OPTIONS)
+                        && (paths::OPEN_OPTIONS.matches_ty(cx, obj_ty)
                             || is_type_diagnostic_item(cx, obj_ty, sym::DirBuilder)))
-                        || (path.ident.name == sym!(set_mode) && match_type(cx, obj_ty, &paths::PERMISSIONS));
+                        || (path.ident.name == sym!(set_mode) && paths::PERMISSIONS.matches_ty(cx, obj_ty));
                     if let ExprKind::Lit(_) = param.kind;
                     if param.span.ctxt() == expr.span.ctxt();
 
@@ -70,7 +73,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
                 if_chain! {
                     if let ExprKind::Path(ref path) = func.kind;
                     if let Some(def_id) = cx.qpath_res(path, func.hir_id).opt_def_id();
-                    if match_def_path(cx, def_id, &paths::PERMISSIONS_FROM_MODE);
+                    if PERMISSIONS_FROM_MODE.matches(cx, def_id);
                     if let ExprKind::Lit(_) = param.kind;
                     if param.span.ctxt() == expr.span.ctxt();
                     if let Some(snip) = snippet_opt(cx, param.span);
@@ -0,0 +1,152 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::is_passed_as_path;
+use rustc_ast::ast::{LitKind, NestedMetaItem};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::{Expr, ExprKind, HirId};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for string literals or constants passed as paths which only work on some
+    /// platforms: paths containing backslashes or starting with a drive letter only work on
+    /// Windows, and paths starting with `/tmp`, `/home`, `/etc`, `/usr` or `/var` only work on
+    /// Unix.
+    ///
+    /// Which platforms the code has to work on can be configured with `portable-path-targets`.
+    /// Code in items with a `#[cfg]` attribute on the target OS or family isn't checked.
+    ///
+    /// ### Why is this bad?
+    /// On other platforms the path doesn't exist, or, for backslashes, isn't even split into its
+    /// components.
+    ///
+    /// ### Known problems
+    /// Code only run after checking the platform at runtime, e.g. with `cfg!(windows)`, is linted.
+    ///
+    /// ### Example
+    /// ```rust,no_run
+    /// let config = std::fs::read_to_string("config\\app.toml");
+    /// let log = std::fs::File::create("/tmp/app.log");
+    /// ```
+    /// Use instead:
+    /// ```rust,no_run
+    /// let config = std::fs::read_to_string(std::path::Path::new("config").join("app.toml"));
+    /// let log = std::fs::File::create(std::env::temp_dir().join("app.log"));
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub NON_PORTABLE_PATH_LITERAL,
+    pedantic,
+    "string literals used as paths which only work on some platforms"
+}
+
+pub struct NonPortablePathLiteral {
+    targets: Vec<String>,
+}
+
+impl NonPortablePathLiteral {
+    pub fn new(targets: Vec<String>) -> Self {
+        Self { targets }
+    }
+
+    fn targets(&self, target: &str) -> bool {
+        self.targets.iter().any(|t| t == target)
+    }
+
+    /// Gets the platform the path `s` only works on and how to make it portable, if it's linted
+    /// for the configured targets.
+    fn non_portable(&self, s: &str) -> Option<(&'static str, &'static str)> {
+        const RELATIVE: &str = "use a path relative to a configurable or known directory";
+
+        if self.targets("unix") {
+            if let [drive, b':', b'\\' | b'/', ..] = s.as_bytes()
+                && drive.is_ascii_alphabetic()
+            {
+                return Some(("Windows", RELATIVE));
+            }
+            if s.contains('\\') {
+                return Some((
+                    "Windows",
+                    "join the components with `Path::join` or use `/` as separator",
+                ));
+            }
+        }
+        if self.targets("windows") {
+            let starts_with_dir = |dir: &str| {
+                s.strip_prefix(dir)
+                    .map_or(false, |rest| rest.is_empty() || rest.starts_with('/'))
+            };
+            if starts_with_dir("/tmp") {
+                return Some(("Unix", "use `std::env::temp_dir`"));
+            }
+            if ["/home", "/etc", "/usr", "/var"].into_iter().any(starts_with_dir) {
+                return Some(("Unix", RELATIVE));
+            }
+        }
+        None
+    }
+}
+
+impl_lint_pass!(NonPortablePathLiteral => [NON_PORTABLE_PATH_LITERAL]);
+
+impl<'tcx> LateLintPass<'tcx> for NonPortablePathLiteral {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if !expr.span.from_expansion()
+            && let Some(path) = path_string(cx, expr)
+            && let Some((platform, help)) = self.non_portable(&path)
+            && is_passed_as_path(cx, expr)
+            && !is_in_platform_cfg(cx, expr.hir_id)
+        {
+            span_lint_and_help(
+                cx,
+                NON_PORTABLE_PATH_LITERAL,
+                expr.span,
+                &format!("this path only works on {platform}"),
+                None,
+                help,
+            );
+        }
+    }
+}
+
+/// Gets the value of a string literal, or of a path to a constant string.
+fn path_string(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<String> {
+    match expr.kind {
+        ExprKind::Lit(ref lit) => match lit.node {
+            LitKind::Str(s, _) => Some(s.to_string()),
+            _ => None,
+        },
+        ExprKind::Path(ref qpath)
+            if matches!(
+                cx.qpath_res(qpath, expr.hir_id),
+                Res::Def(DefKind::Const | DefKind::AssocConst, _)
+            ) =>
+        {
+            match constant(cx, cx.typeck_results(), expr) {
+                Some((Constant::Str(s), _)) => Some(s),
+                _ => None,
+            }
+        },
+        _ => None,
+    }
+}
+
+/// Checks if `id` is in an item only compiled for some platforms, e.g. with `#[cfg(windows)]`.
+fn is_in_platform_cfg(cx: &LateContext<'_>, id: HirId) -> bool {
+    fn is_platform_cfg(item: &NestedMetaItem) -> bool {
+        [sym::unix, sym::windows, sym::target_os, sym::target_family]
+            .into_iter()
+            .any(|name| item.has_name(name))
+            || item
+                .meta_item_list()
+                .map_or(false, |items| items.iter().any(is_platform_cfg))
+    }
+    cx.tcx
+        .hir()
+        .parent_iter(id)
+        .flat_map(|(parent_id, _)| cx.tcx.hir().attrs(parent_id))
+        .filter(|attr| attr.has_name(sym::cfg))
+        .filter_map(|attr| attr.meta_item_list())
+        .any(|items| items.iter().any(is_platform_cfg))
+}
@@ -2,12 +2,12 @@
 use rustc_lint::LateContext;
 use rustc_middle::ty;
 
-use clippy_utils::comparisons::{normalize_comparison, Rel};
+use clippy_utils::comparisons::{compare_ranges, int_range, normalize_comparison, Rel};
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::span_lint_and_help;
 use clippy_utils::source::snippet;
 use clippy_utils::ty::is_isize_or_usize;
-use clippy_utils::{clip, int_bits, unsext};
+use clippy_utils::{clip, int_bits, sext, unsext};
 
 use super::ABSURD_EXTREME_COMPARISONS;
 
@@ -43,6 +43,18 @@ pub(super) fn check<'tcx>(
         );
 
         span_lint_and_help(cx, ABSURD_EXTREME_COMPARISONS, expr.span, msg, None, &help);
+    } else if let Some((culprit, (min, max), result)) = detect_cast_range_comparison(cx, op, lhs, rhs) {
+        span_lint_and_help(
+            cx,
+            ABSURD_EXTREME_COMPARISONS,
+            expr.span,
+            &format!("this comparison with a cast value is always {result}"),
+            None,
+            &format!(
+                "because `{}` is always in `{min}..={max}`, this comparison is always {result}",
+                snippet(cx, culprit.span, "x"),
+            ),
+        );
     }
 }
 
@@ -139,3 +151,66 @@ fn detect_extreme_expr<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Op
     };
     Some(ExtremeExpr { which, expr })
 }
+
+/// Detects comparisons of a constant to a cast whose result is always the same, because the cast
+/// value only covers a part of the target type, e.g. `(x as u8) as u32 > 300`. Returns the cast,
+/// the range of its values and the result.
+fn detect_cast_range_comparison<'tcx>(
+    cx: &LateContext<'tcx>,
+    op: BinOpKind,
+    lhs: &'tcx Expr<'_>,
+    rhs: &'tcx Expr<'_>,
+) -> Option<(&'tcx Expr<'tcx>, (i128, i128), bool)> {
+    let typeck = cx.typeck_results();
+    if typeck.expr_ty(lhs) != typeck.expr_ty(rhs)
+        || is_cast_between_fixed_and_target(cx, lhs)
+        || is_cast_between_fixed_and_target(cx, rhs)
+    {
+        return None;
+    }
+    let (rel, lhs, rhs) = normalize_comparison(op, lhs, rhs)?;
+    let int_constant = |e| match (constant(cx, typeck, e)?.0, typeck.expr_ty(e).kind()) {
+        (Constant::Int(value), &ty::Int(ity)) => Some(sext(cx.tcx, value, ity)),
+        (Constant::Int(value), ty::Uint(_)) => i128::try_from(value).ok(),
+        _ => None,
+    };
+
+    if let Some(range) = cast_range(cx, lhs)
+        && let Some(value) = int_constant(rhs)
+    {
+        Some((lhs, range, compare_ranges(rel, range, (value, value))?))
+    } else if let Some(range) = cast_range(cx, rhs)
+        && let Some(value) = int_constant(lhs)
+    {
+        Some((rhs, range, compare_ranges(rel, (value, value), range)?))
+    } else {
+        None
+    }
+}
+
+/// Gets the range of values of a cast which doesn't cover its whole type: the range of the source
+/// type of a widening cast, or the discriminants of a fieldless enum.
+fn cast_range<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) -> Option<(i128, i128)> {
+    let ExprKind::Cast(inner, _) = expr.kind else {
+        return None;
+    };
+    if constant(cx, cx.typeck_results(), expr).is_some() {
+        return None;
+    }
+    let inner_ty = cx.typeck_results().expr_ty(inner);
+    let range = match inner_ty.kind() {
+        ty::Adt(adt, _) if adt.is_enum() => adt
+            .discriminants(cx.tcx)
+            .map(|(_, discr)| match *discr.ty.kind() {
+                ty::Int(ity) => sext(cx.tcx, discr.val, ity),
+                _ => i128::try_from(discr.val).unwrap_or(i128::MAX),
+            })
+            .fold(None, |range, value| match range {
+                Some((min, max)) => Some((value.min(min), value.max(max))),
+                None => Some((value, value)),
+            })?,
+        _ => cast_range(cx, inner).or_else(|| int_range(cx.tcx, inner_ty))?,
+    };
+    let (min, max) = int_range(cx.tcx, cx.typeck_results().expr_ty(expr))?;
+    (min <= range.0 && range.1 <= max && (min, max) != range).then_some(range)
+}
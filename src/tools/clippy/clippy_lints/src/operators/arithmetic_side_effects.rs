@@ -7,6 +7,7 @@
 use rustc_ast as ast;
 use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_hir as hir;
+use rustc_hir::def_id::LocalDefId;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::Ty;
 use rustc_session::impl_lint_pass;
@@ -25,6 +26,10 @@
 pub struct ArithmeticSideEffects {
     allowed_binary: FxHashMap<String, FxHashSet<String>>,
     allowed_unary: FxHashSet<String>,
+    // Module path patterns, split into segments, where the lint is silenced
+    allowed_paths: Vec<Vec<String>>,
+    // Whether a module matches one of `allowed_paths`
+    allowed_modules: FxHashMap<LocalDefId, bool>,
     // Used to check whether expressions are constants, such as in enum discriminants and consts
     const_span: Option<Span>,
     expr_span: Option<Span>,
@@ -34,7 +39,11 @@ pub struct ArithmeticSideEffects {
 
 impl ArithmeticSideEffects {
     #[must_use]
-    pub fn new(user_allowed_binary: Vec<[String; 2]>, user_allowed_unary: Vec<String>) -> Self {
+    pub fn new(
+        user_allowed_binary: Vec<[String; 2]>,
+        user_allowed_unary: Vec<String>,
+        user_allowed_paths: Vec<String>,
+    ) -> Self {
         let mut allowed_binary: FxHashMap<String, FxHashSet<String>> = <_>::default();
         for [lhs, rhs] in user_allowed_binary.into_iter().chain(
             HARD_CODED_ALLOWED_BINARY
@@ -48,9 +57,15 @@ impl ArithmeticSideEffects {
             .into_iter()
             .chain(HARD_CODED_ALLOWED_UNARY.iter().copied().map(String::from))
             .collect();
+        let allowed_paths = user_allowed_paths
+            .iter()
+            .map(|path| path.split("::").map(String::from).collect())
+            .collect();
         Self {
             allowed_binary,
             allowed_unary,
+            allowed_paths,
+            allowed_modules: FxHashMap::default(),
             const_span: None,
             expr_span: None,
         }
@@ -85,6 +100,38 @@ fn has_allowed_unary(&self, ty: Ty<'_>) -> bool {
         self.allowed_unary.contains(ty_string_elem)
     }
 
+    /// Checks if the module containing `expr` matches one of the allowed module paths.
+    ///
+    /// Paths are relative to the crate root. A `*` segment matches any single module, and a
+    /// trailing `*` also matches the module itself and all modules nested in it.
+    fn is_in_allowed_path(&mut self, cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bool {
+        if self.allowed_paths.is_empty() {
+            return false;
+        }
+        let module = cx.tcx.parent_module(expr.hir_id);
+        let allowed_paths = &self.allowed_paths;
+        *self.allowed_modules.entry(module).or_insert_with(|| {
+            // Skip the crate name
+            let module_path: Vec<_> = cx.get_def_path(module.to_def_id()).into_iter().skip(1).collect();
+            allowed_paths.iter().any(|pattern| {
+                let (pattern, is_prefix) = match pattern.split_last() {
+                    Some((last, rest)) if last == "*" => (rest, true),
+                    _ => (pattern.as_slice(), false),
+                };
+                let len_matches = if is_prefix {
+                    module_path.len() >= pattern.len()
+                } else {
+                    module_path.len() == pattern.len()
+                };
+                len_matches
+                    && pattern
+                        .iter()
+                        .zip(&module_path)
+                        .all(|(expected, segment)| expected == "*" || segment.as_str() == expected)
+            })
+        })
+    }
+
     // For example, 8i32 or &i64::MAX.
     fn is_integral(ty: Ty<'_>) -> bool {
         ty.peel_refs().is_integral()
@@ -207,7 +254,7 @@ fn should_skip_expr(&mut self, cx: &LateContext<'_>, expr: &hir::Expr<'_>) -> bo
 
 impl<'tcx> LateLintPass<'tcx> for ArithmeticSideEffects {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &hir::Expr<'tcx>) {
-        if self.should_skip_expr(cx, expr) {
+        if self.should_skip_expr(cx, expr) || self.is_in_allowed_path(cx, expr) {
             return;
         }
         match &expr.kind {
@@ -1,7 +1,7 @@
 use clippy_utils::binop_traits;
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::snippet_opt;
-use clippy_utils::ty::implements_trait;
+use clippy_utils::ty::ImplementsTraitCache;
 use clippy_utils::visitors::for_each_expr;
 use clippy_utils::{eq_expr_value, trait_ref_of_method};
 use core::ops::ControlFlow;
@@ -18,12 +18,13 @@
 
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
     expr: &'tcx hir::Expr<'_>,
     assignee: &'tcx hir::Expr<'_>,
     e: &'tcx hir::Expr<'_>,
 ) {
     if let hir::ExprKind::Binary(op, l, r) = &e.kind {
-        let lint = |assignee: &hir::Expr<'_>, rhs: &hir::Expr<'_>| {
+        let mut lint = |assignee: &hir::Expr<'_>, rhs: &hir::Expr<'_>| {
             let ty = cx.typeck_results().expr_ty(assignee);
             let rty = cx.typeck_results().expr_ty(rhs);
             if_chain! {
@@ -32,7 +33,7 @@ pub(super) fn check<'tcx>(
                 let parent_fn = cx.tcx.hir().get_parent_item(e.hir_id).def_id;
                 if trait_ref_of_method(cx, parent_fn)
                     .map_or(true, |t| t.path.res.def_id() != trait_id);
-                if implements_trait(cx, ty, trait_id, &[rty.into()]);
+                if trait_cache.implements_trait(cx, ty, trait_id, &[rty.into()]);
                 then {
                     // Primitive types execute assign-ops right-to-left. Every other type is left-to-right.
                     if !(ty.is_primitive() && rty.is_primitive()) {
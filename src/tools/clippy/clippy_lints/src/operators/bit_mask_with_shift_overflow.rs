@@ -0,0 +1,67 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::snippet;
+use clippy_utils::value_range::value_range;
+use rustc_hir::{BinOpKind, Expr};
+use rustc_lint::LateContext;
+use rustc_middle::ty::layout::LayoutOf;
+
+use super::BIT_MASK_WITH_SHIFT_OVERFLOW;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    e: &'tcx Expr<'_>,
+    op: BinOpKind,
+    lhs: &'tcx Expr<'_>,
+    rhs: &'tcx Expr<'_>,
+) {
+    let method = match op {
+        BinOpKind::Shl => "shl",
+        BinOpKind::Shr => "shr",
+        _ => return,
+    };
+    let ty = cx.typeck_results().expr_ty(lhs);
+    if !ty.is_integral() || e.span.from_expansion() {
+        return;
+    }
+    let Ok(layout) = cx.layout_of(ty) else {
+        return;
+    };
+    let bits = i128::from(layout.size.bits());
+    let Some(range) = value_range(cx, rhs) else {
+        return;
+    };
+    if range.min >= 0 && range.max < bits {
+        return;
+    }
+
+    let always = range.min >= bits || range.max < 0;
+    span_lint_and_then(
+        cx,
+        BIT_MASK_WITH_SHIFT_OVERFLOW,
+        e.span,
+        if always {
+            "this shift always overflows"
+        } else {
+            "this shift can overflow"
+        },
+        |diag| {
+            let amount = snippet(cx, rhs.span, "..");
+            if range.min == range.max {
+                diag.note(format!(
+                    "`{ty}` only has {bits} bits, so it can't be shifted by {}",
+                    range.min
+                ));
+            } else {
+                diag.note(format!(
+                    "the shift amount `{amount}` can be in `{}..={}`, but `{ty}` only has {bits} bits",
+                    range.min, range.max
+                ));
+            }
+            diag.note("this panics in debug builds, and only shifts by the amount modulo the bit width otherwise");
+            diag.help(format!(
+                "use `checked_{method}` or `wrapping_{method}`, or mask the shift amount with `& {}`",
+                bits - 1
+            ));
+        },
+    );
+}
@@ -1,7 +1,8 @@
 use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::path_def_id;
+use clippy_utils::paths::PathLookup;
 use clippy_utils::source::snippet;
-use clippy_utils::ty::{implements_trait, is_copy};
-use clippy_utils::{match_def_path, path_def_id, paths};
+use clippy_utils::ty::{is_copy, ImplementsTraitCache};
 use rustc_errors::Applicability;
 use rustc_hir::{BinOpKind, Expr, ExprKind, UnOp};
 use rustc_lint::LateContext;
@@ -10,10 +11,18 @@
 
 use super::CMP_OWNED;
 
-pub(super) fn check(cx: &LateContext<'_>, op: BinOpKind, lhs: &Expr<'_>, rhs: &Expr<'_>) {
+static FROM_STR_METHOD: PathLookup = PathLookup::new(&["core", "str", "traits", "FromStr", "from_str"]);
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
+    op: BinOpKind,
+    lhs: &Expr<'_>,
+    rhs: &Expr<'_>,
+) {
     if op.is_comparison() {
-        check_op(cx, lhs, rhs, true);
-        check_op(cx, rhs, lhs, false);
+        check_op(cx, trait_cache, lhs, rhs, true);
+        check_op(cx, trait_cache, rhs, lhs, false);
     }
 }
 
@@ -28,14 +37,25 @@ fn is_implemented(&self) -> bool {
     }
 }
 
-fn symmetric_partial_eq<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, other: Ty<'tcx>) -> Option<EqImpl> {
+fn symmetric_partial_eq<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
+    ty: Ty<'tcx>,
+    other: Ty<'tcx>,
+) -> Option<EqImpl> {
     cx.tcx.lang_items().eq_trait().map(|def_id| EqImpl {
-        ty_eq_other: implements_trait(cx, ty, def_id, &[other.into()]),
-        other_eq_ty: implements_trait(cx, other, def_id, &[ty.into()]),
+        ty_eq_other: trait_cache.implements_trait(cx, ty, def_id, &[other.into()]),
+        other_eq_ty: trait_cache.implements_trait(cx, other, def_id, &[ty.into()]),
     })
 }
 
-fn check_op(cx: &LateContext<'_>, expr: &Expr<'_>, other: &Expr<'_>, left: bool) {
+fn check_op<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
+    expr: &Expr<'_>,
+    other: &Expr<'_>,
+    left: bool,
+) {
     let typeck = cx.typeck_results();
     let (arg, arg_span) = match expr.kind {
         ExprKind::MethodCall(_, arg, [], _)
@@ -50,7 +70,7 @@ fn check_op(cx: &LateContext<'_>, expr: &Expr<'_>, other: &Expr<'_>, left: bool)
         },
         ExprKind::Call(path, [arg])
             if path_def_id(cx, path).map_or(false, |did| {
-                if match_def_path(cx, did, &paths::FROM_STR_METHOD) {
+                if FROM_STR_METHOD.matches(cx, did) {
                     true
                 } else if cx.tcx.is_diagnostic_item(sym::from_fn, did) {
                     !is_copy(cx, typeck.expr_ty(expr))
@@ -67,10 +87,10 @@ fn check_op(cx: &LateContext<'_>, expr: &Expr<'_>, other: &Expr<'_>, left: bool)
     let arg_ty = typeck.expr_ty(arg);
     let other_ty = typeck.expr_ty(other);
 
-    let without_deref = symmetric_partial_eq(cx, arg_ty, other_ty).unwrap_or_default();
+    let without_deref = symmetric_partial_eq(cx, trait_cache, arg_ty, other_ty).unwrap_or_default();
     let with_deref = arg_ty
         .builtin_deref(true)
-        .and_then(|tam| symmetric_partial_eq(cx, tam.ty, other_ty))
+        .and_then(|tam| symmetric_partial_eq(cx, trait_cache, tam.ty, other_ty))
         .unwrap_or_default();
 
     if !with_deref.is_implemented() && !without_deref.is_implemented() {
@@ -1,5 +1,6 @@
 use clippy_utils::diagnostics::span_lint_and_then;
-use clippy_utils::{match_def_path, paths, sugg};
+use clippy_utils::paths::PathLookup;
+use clippy_utils::sugg;
 use if_chain::if_chain;
 use rustc_ast::util::parser::AssocOp;
 use rustc_errors::Applicability;
@@ -11,6 +12,9 @@
 
 use super::FLOAT_EQUALITY_WITHOUT_ABS;
 
+static F32_EPSILON: PathLookup = PathLookup::new(&["f32", "EPSILON"]);
+static F64_EPSILON: PathLookup = PathLookup::new(&["f64", "EPSILON"]);
+
 pub(crate) fn check<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx Expr<'_>,
@@ -38,7 +42,7 @@ pub(crate) fn check<'tcx>(
         // right hand side matches either f32::EPSILON or f64::EPSILON
         if let ExprKind::Path(ref epsilon_path) = rhs.kind;
         if let Res::Def(DefKind::AssocConst, def_id) = cx.qpath_res(epsilon_path, rhs.hir_id);
-        if match_def_path(cx, def_id, &paths::F32_EPSILON) || match_def_path(cx, def_id, &paths::F64_EPSILON);
+        if F32_EPSILON.matches(cx, def_id) || F64_EPSILON.matches(cx, def_id);
 
         // values of the subtractions on the left hand side are of the type float
         let t_val_l = cx.typeck_results().expr_ty(val_l);
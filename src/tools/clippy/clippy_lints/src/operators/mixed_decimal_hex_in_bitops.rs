@@ -0,0 +1,115 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::get_parent_expr;
+use clippy_utils::numeric_literal::{reformat_int_lit, NumericLiteral, Radix};
+use clippy_utils::source::snippet_opt;
+use rustc_ast::ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+
+use super::MIXED_DECIMAL_HEX_IN_BITOPS;
+
+/// An integer literal which is an operand of a bitwise operation.
+struct Operand<'a> {
+    span: Span,
+    snippet: String,
+    lit_kind: &'a LitKind,
+    value: u128,
+    radix: Radix,
+}
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, op: BinOpKind) {
+    if !is_bitop(op) || e.span.from_expansion() {
+        return;
+    }
+    // Only check the outermost operation, which contains all of the operands
+    if let Some(parent) = get_parent_expr(cx, e)
+        && let ExprKind::Binary(parent_op, ..) = parent.kind
+        && is_bitop(parent_op.node)
+    {
+        return;
+    }
+
+    let mut operands = Vec::new();
+    collect_operands(cx, e, &mut operands);
+    let has_radix = |radix| operands.iter().any(|operand| operand.radix == radix);
+    let radix = if has_radix(Radix::Hexadecimal) {
+        Radix::Hexadecimal
+    } else if has_radix(Radix::Binary) {
+        Radix::Binary
+    } else {
+        return;
+    };
+    // Single digits read the same in every radix
+    let decimals: Vec<_> = operands
+        .iter()
+        .filter(|operand| operand.radix == Radix::Decimal && operand.value >= 10)
+        .collect();
+    if decimals.is_empty() {
+        return;
+    }
+    let uppercase = operands
+        .iter()
+        .any(|operand| operand.radix == Radix::Hexadecimal && operand.snippet.chars().any(|c| c.is_ascii_uppercase()));
+
+    let radix_name = if radix == Radix::Hexadecimal {
+        "hexadecimal"
+    } else {
+        "binary"
+    };
+    span_lint_and_then(
+        cx,
+        MIXED_DECIMAL_HEX_IN_BITOPS,
+        decimals.iter().map(|operand| operand.span).collect::<Vec<_>>(),
+        &format!("decimal literal in a bitwise operation with {radix_name} literals"),
+        |diag| {
+            let sugg = decimals
+                .iter()
+                .filter_map(|operand| {
+                    let lit = reformat_int_lit(&operand.snippet, operand.lit_kind, radix, uppercase)?;
+                    Some((operand.span, lit))
+                })
+                .collect();
+            diag.multipart_suggestion(
+                format!("use {radix_name} literals consistently"),
+                sugg,
+                Applicability::MachineApplicable,
+            );
+        },
+    );
+}
+
+fn is_bitop(op: BinOpKind) -> bool {
+    matches!(op, BinOpKind::BitAnd | BinOpKind::BitOr | BinOpKind::BitXor)
+}
+
+/// Collects the integer literals among the operands of the nested bitwise operations in `e`.
+fn collect_operands<'a>(cx: &LateContext<'_>, e: &'a Expr<'_>, operands: &mut Vec<Operand<'a>>) {
+    match e.kind {
+        ExprKind::Binary(op, lhs, rhs) if is_bitop(op.node) => {
+            collect_operands(cx, lhs, operands);
+            collect_operands(cx, rhs, operands);
+        },
+        ExprKind::Lit(ref lit) if !e.span.from_expansion() => {
+            let LitKind::Int(value, _) = lit.node else {
+                return;
+            };
+            let Some(snippet) = snippet_opt(cx, e.span) else {
+                return;
+            };
+            let Some(num_lit) = NumericLiteral::from_lit_kind(&snippet, &lit.node) else {
+                return;
+            };
+            let radix = num_lit.radix;
+            operands.push(Operand {
+                span: e.span,
+                snippet,
+                lit_kind: &lit.node,
+                value,
+                radix,
+            });
+        },
+        _ => {},
+    }
+}
@@ -1,6 +1,7 @@
 mod absurd_extreme_comparisons;
 mod assign_op_pattern;
 mod bit_mask;
+mod bit_mask_with_shift_overflow;
 mod cmp_nan;
 mod cmp_owned;
 mod double_comparison;
@@ -12,9 +13,12 @@
 mod identity_op;
 mod integer_division;
 mod misrefactored_assign_op;
+mod mixed_decimal_hex_in_bitops;
 mod modulo_arithmetic;
 mod modulo_one;
+mod modulo_then_compare_range;
 mod needless_bitwise_bool;
+mod needless_string_allocation_in_comparison;
 mod numeric_arithmetic;
 mod op_ref;
 mod ptr_eq;
@@ -23,6 +27,7 @@
 
 pub(crate) mod arithmetic_side_effects;
 
+use clippy_utils::ty::ImplementsTraitCache;
 use rustc_hir::{Body, Expr, ExprKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_tool_lint, impl_lint_pass};
@@ -34,6 +39,9 @@
     /// case that is always true or always false. Only integer and boolean types are
     /// checked.
     ///
+    /// Casts whose values only cover a part of the target type are checked as well, e.g. a
+    /// `u8` widened to `u32` or a fieldless enum cast to its discriminant.
+    ///
     /// ### Why is this bad?
     /// An expression like `min <= x` may misleadingly imply
     /// that it is possible for `x` to be less than the minimum. Expressions like
@@ -52,6 +60,8 @@
     /// let vec: Vec<isize> = Vec::new();
     /// if vec.len() <= 0 {}
     /// if 100 > i32::MAX {}
+    /// # let x: u8 = 0;
+    /// if x as u32 > 300 {}
     /// ```
     #[clippy::version = "pre 1.29.0"]
     pub ABSURD_EXTREME_COMPARISONS,
@@ -68,7 +78,8 @@
     /// or can panic (`/`, `%`).
     ///
     /// Known safe built-in types like `Wrapping` or `Saturating`, floats, operations in constant
-    /// environments, allowed types and non-constant operations that won't overflow are ignored.
+    /// environments, allowed types, operations in allowed modules and non-constant operations that
+    /// won't overflow are ignored.
     ///
     /// ### Why is this bad?
     /// For integers, overflow will trigger a panic in debug builds or wrap the result in
@@ -780,11 +791,130 @@
     "explicit self-assignment"
 }
 
-pub struct Operators {
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for comparisons with a `String` built by `format!` which only contains a single
+    /// string argument, or only the format string itself.
+    ///
+    /// `to_string()`, `to_owned()` and `String::from` on one side of a comparison are linted by
+    /// `cmp_owned`.
+    ///
+    /// ### Why is this bad?
+    /// The `String` is allocated only to be compared and then dropped. The string it was built
+    /// from can be compared directly.
+    ///
+    /// Arguments which aren't strings are never linted, as their `Display` output may not agree
+    /// with their `PartialEq` implementation.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let name = "foo";
+    /// # let other = String::from("foo");
+    /// if format!("{name}") == other {}
+    /// if other == format!("bar") {}
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let name = "foo";
+    /// # let other = String::from("foo");
+    /// if name == other {}
+    /// if other == "bar" {}
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub NEEDLESS_STRING_ALLOCATION_IN_COMPARISON,
+    perf,
+    "allocating a `String` with `format!` only to compare it"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for comparisons of a remainder which always have the same result, because the
+    /// remainder of a division by `n` is always smaller than `n`, e.g. `x % n == n` or
+    /// `x % 10 > 10`.
+    ///
+    /// ### Why is this bad?
+    /// The comparison is always `true` or always `false`, which is likely a mistake, e.g. in the
+    /// divisor or in the compared value.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let x: u32 = 7;
+    /// if x % 10 == 10 {
+    ///     // ..
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let x: u32 = 7;
+    /// if x % 10 == 9 {
+    ///     // ..
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub MODULO_THEN_COMPARE_RANGE,
+    correctness,
+    "comparing a remainder to a value outside of its range"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for shifts by an amount which can be equal to or greater than the bit width of the
+    /// shifted type, or negative, e.g. `x >> 32` on a `u32`, or `1 << n` where `n` comes from
+    /// `for n in 0..=32`.
+    ///
+    /// ### Why is this bad?
+    /// Such a shift panics in debug builds. In release builds it shifts by the amount modulo the
+    /// bit width instead, so `1_u32 << 32` is `1` rather than `0`.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// for n in 0..=32 {
+    ///     let mask = 1_u32 << n;
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// for n in 0..=32 {
+    ///     let mask = 1_u32.checked_shl(n).unwrap_or(0);
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub BIT_MASK_WITH_SHIFT_OVERFLOW,
+    suspicious,
+    "shifting by an amount which can overflow the bit width"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for bitwise operations whose operands mix decimal literals with hexadecimal or
+    /// binary ones, e.g. `flags & 16 | 0x40`.
+    ///
+    /// ### Why is this bad?
+    /// Bit masks are easier to compare when they're written in the same radix, since the bits
+    /// which are set can be read off hexadecimal and binary literals, but not decimal ones.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let flags = 0u8;
+    /// let masked = flags & 16 | 0x40;
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # let flags = 0u8;
+    /// let masked = flags & 0x10 | 0x40;
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub MIXED_DECIMAL_HEX_IN_BITOPS,
+    pedantic,
+    "bitwise operations mixing decimal and hexadecimal or binary literals"
+}
+
+pub struct Operators<'tcx> {
     arithmetic_context: numeric_arithmetic::Context,
     verbose_bit_mask_threshold: u64,
+    trait_cache: ImplementsTraitCache<'tcx>,
 }
-impl_lint_pass!(Operators => [
+impl_lint_pass!(Operators<'_> => [
     ABSURD_EXTREME_COMPARISONS,
     ARITHMETIC_SIDE_EFFECTS,
     INTEGER_ARITHMETIC,
@@ -811,16 +941,21 @@ pub struct Operators {
     NEEDLESS_BITWISE_BOOL,
     PTR_EQ,
     SELF_ASSIGNMENT,
+    NEEDLESS_STRING_ALLOCATION_IN_COMPARISON,
+    MODULO_THEN_COMPARE_RANGE,
+    BIT_MASK_WITH_SHIFT_OVERFLOW,
+    MIXED_DECIMAL_HEX_IN_BITOPS,
 ]);
-impl Operators {
+impl Operators<'_> {
     pub fn new(verbose_bit_mask_threshold: u64) -> Self {
         Self {
             arithmetic_context: numeric_arithmetic::Context::default(),
             verbose_bit_mask_threshold,
+            trait_cache: ImplementsTraitCache::default(),
         }
     }
 }
-impl<'tcx> LateLintPass<'tcx> for Operators {
+impl<'tcx> LateLintPass<'tcx> for Operators<'tcx> {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         eq_op::check_assert(cx, e);
         match e.kind {
@@ -829,33 +964,38 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
                     absurd_extreme_comparisons::check(cx, e, op.node, lhs, rhs);
                     if !(macro_with_not_op(lhs) || macro_with_not_op(rhs)) {
                         eq_op::check(cx, e, op.node, lhs, rhs);
-                        op_ref::check(cx, e, op.node, lhs, rhs);
+                        op_ref::check(cx, &mut self.trait_cache, e, op.node, lhs, rhs);
                     }
                     erasing_op::check(cx, e, op.node, lhs, rhs);
                     identity_op::check(cx, e, op.node, lhs, rhs);
                     needless_bitwise_bool::check(cx, e, op.node, lhs, rhs);
+                    needless_string_allocation_in_comparison::check(cx, &mut self.trait_cache, e, op.node, lhs, rhs);
                     ptr_eq::check(cx, e, op.node, lhs, rhs);
+                    mixed_decimal_hex_in_bitops::check(cx, e, op.node);
                 }
                 self.arithmetic_context.check_binary(cx, e, op.node, lhs, rhs);
                 bit_mask::check(cx, e, op.node, lhs, rhs);
+                bit_mask_with_shift_overflow::check(cx, e, op.node, lhs, rhs);
                 verbose_bit_mask::check(cx, e, op.node, lhs, rhs, self.verbose_bit_mask_threshold);
                 double_comparison::check(cx, op.node, lhs, rhs, e.span);
                 duration_subsec::check(cx, e, op.node, lhs, rhs);
                 float_equality_without_abs::check(cx, e, op.node, lhs, rhs);
                 integer_division::check(cx, e, op.node, lhs, rhs);
                 cmp_nan::check(cx, e, op.node, lhs, rhs);
-                cmp_owned::check(cx, op.node, lhs, rhs);
+                cmp_owned::check(cx, &mut self.trait_cache, op.node, lhs, rhs);
                 float_cmp::check(cx, e, op.node, lhs, rhs);
                 modulo_one::check(cx, e, op.node, rhs);
+                modulo_then_compare_range::check(cx, e, op.node, lhs, rhs);
                 modulo_arithmetic::check(cx, e, op.node, lhs, rhs);
             },
             ExprKind::AssignOp(op, lhs, rhs) => {
                 self.arithmetic_context.check_binary(cx, e, op.node, lhs, rhs);
                 misrefactored_assign_op::check(cx, e, op.node, lhs, rhs);
+                bit_mask_with_shift_overflow::check(cx, e, op.node, lhs, rhs);
                 modulo_arithmetic::check(cx, e, op.node, lhs, rhs);
             },
             ExprKind::Assign(lhs, rhs, _) => {
-                assign_op_pattern::check(cx, e, lhs, rhs);
+                assign_op_pattern::check(cx, &mut self.trait_cache, e, lhs, rhs);
                 self_assignment::check(cx, e, lhs, rhs);
             },
             ExprKind::Unary(op, arg) => {
@@ -0,0 +1,118 @@
+use clippy_utils::comparisons::{compare_ranges, int_range, normalize_comparison, Rel};
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::source::snippet;
+use clippy_utils::{eq_expr_value, sext};
+use rustc_hir::{BinOpKind, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+
+use super::MODULO_THEN_COMPARE_RANGE;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    expr: &'tcx Expr<'_>,
+    op: BinOpKind,
+    lhs: &'tcx Expr<'_>,
+    rhs: &'tcx Expr<'_>,
+) {
+    let Some((rel, left, right)) = normalize_comparison(op, lhs, rhs) else {
+        return;
+    };
+    if let Some((result, help)) =
+        check_side(cx, rel, left, right, true).or_else(|| check_side(cx, rel, right, left, false))
+    {
+        span_lint_and_help(
+            cx,
+            MODULO_THEN_COMPARE_RANGE,
+            expr.span,
+            &format!("this comparison of a remainder is always {result}"),
+            None,
+            &help,
+        );
+    }
+}
+
+/// Checks the comparison of the remainder `rem` to `other`, where `rem_is_lhs` tells on which
+/// side of `rel` the remainder is. Returns the result of the comparison and an explanation.
+fn check_side<'tcx>(
+    cx: &LateContext<'tcx>,
+    rel: Rel,
+    rem: &'tcx Expr<'_>,
+    other: &'tcx Expr<'_>,
+    rem_is_lhs: bool,
+) -> Option<(bool, String)> {
+    let ExprKind::Binary(op, _, divisor) = rem.kind else {
+        return None;
+    };
+    let ty = cx.typeck_results().expr_ty(rem);
+    if op.node != BinOpKind::Rem || !ty.is_integral() {
+        return None;
+    }
+
+    let divisor_value = int_constant(cx, divisor, ty).and_then(i128::checked_abs);
+    // `x % 1` is linted by `modulo_one`
+    if divisor_value.map_or(false, |n| n <= 1) {
+        return None;
+    }
+
+    // `x % n == n`
+    if matches!(rel, Rel::Eq | Rel::Ne) && eq_expr_value(cx, divisor, other) {
+        return Some((
+            rel == Rel::Ne,
+            format!(
+                "the remainder of a division by `{}` is always smaller than it",
+                snippet(cx, divisor.span, "..")
+            ),
+        ));
+    }
+
+    let divisor = divisor_value?;
+    let value = int_constant(cx, other, ty)?;
+    let rem_range = if ty.is_signed() {
+        (1 - divisor, divisor - 1)
+    } else {
+        (0, divisor - 1)
+    };
+    let other_range = (value, value);
+    let (lhs, rhs) = if rem_is_lhs {
+        (rem_range, other_range)
+    } else {
+        (other_range, rem_range)
+    };
+    let result = compare_ranges(rel, lhs, rhs)?;
+
+    // Comparisons which are vacuous for any value of the type are linted by
+    // `absurd_extreme_comparisons`
+    let ty_range = int_range(cx.tcx, ty)?;
+    let vacuous_for_ty = if rem_is_lhs {
+        compare_ranges(rel, ty_range, other_range)
+    } else {
+        compare_ranges(rel, other_range, ty_range)
+    };
+    if vacuous_for_ty.is_some() {
+        return None;
+    }
+
+    Some((
+        result,
+        format!(
+            "`{}` is always in `{}..={}`",
+            snippet(cx, rem.span, ".."),
+            rem_range.0,
+            rem_range.1
+        ),
+    ))
+}
+
+/// Evaluates `e` to an integer constant of type `ty`.
+fn int_constant<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, ty: Ty<'tcx>) -> Option<i128> {
+    let Constant::Int(value) = constant(cx, cx.typeck_results(), e)?.0 else {
+        return None;
+    };
+    match *ty.kind() {
+        ty::Int(ity) => Some(sext(cx.tcx, value, ity)),
+        ty::Uint(_) => i128::try_from(value).ok(),
+        _ => None,
+    }
+}
@@ -0,0 +1,102 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::macros::{root_macro_call_first_node, FormatArgsExpn};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::{is_type_lang_item, ImplementsTraitCache};
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, LangItem};
+use rustc_lint::LateContext;
+use rustc_middle::ty::Ty;
+use rustc_span::sym;
+use rustc_span::symbol::kw;
+
+use super::NEEDLESS_STRING_ALLOCATION_IN_COMPARISON;
+
+pub(super) fn check<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
+    e: &'tcx Expr<'_>,
+    op: BinOpKind,
+    lhs: &'tcx Expr<'_>,
+    rhs: &'tcx Expr<'_>,
+) {
+    if matches!(op, BinOpKind::Eq | BinOpKind::Ne) {
+        check_op(cx, trait_cache, e, op, lhs, rhs, true);
+        check_op(cx, trait_cache, e, op, rhs, lhs, false);
+    }
+}
+
+/// Checks whether `alloc` is a `format!` call producing the same string as one of its arguments,
+/// or as its format string. `left` is whether `alloc` is the left hand side of the comparison.
+fn check_op<'tcx>(
+    cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
+    e: &'tcx Expr<'_>,
+    op: BinOpKind,
+    alloc: &'tcx Expr<'_>,
+    other: &'tcx Expr<'_>,
+    left: bool,
+) {
+    let typeck = cx.typeck_results();
+    let other_ty = typeck.expr_ty(other);
+    let Some(macro_call) = root_macro_call_first_node(cx, alloc) else {
+        return;
+    };
+    if !cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id) || !is_str_like(cx, other_ty) {
+        return;
+    }
+    let Some(format_args) = FormatArgsExpn::find_nested(cx, alloc, macro_call.expn) else {
+        return;
+    };
+
+    let mut applicability = Applicability::MachineApplicable;
+    let ctxt = e.span.ctxt();
+    // Only strings are compared, for anything else `Display` may not agree with `PartialEq`
+    let (replacement, replacement_ty) = match (&*format_args.args, &*format_args.format_string.parts) {
+        ([], [_]) => (
+            format_args.format_string.snippet.replace("{{", "{").replace("}}", "}"),
+            cx.tcx.mk_static_str(),
+        ),
+        ([arg], [kw::Empty]) if arg.format.is_default() && is_str_like(cx, typeck.expr_ty(arg.param.value)) => (
+            snippet_with_context(cx, arg.param.value.span, ctxt, "..", &mut applicability)
+                .0
+                .into_owned(),
+            typeck.expr_ty(arg.param.value),
+        ),
+        _ => return,
+    };
+
+    let Some(eq_trait) = cx.tcx.lang_items().eq_trait() else {
+        return;
+    };
+    let (lhs_ty, rhs_ty) = if left {
+        (replacement_ty, other_ty)
+    } else {
+        (other_ty, replacement_ty)
+    };
+    if !trait_cache.implements_trait(cx, lhs_ty, eq_trait, &[rhs_ty.into()]) {
+        return;
+    }
+
+    let other = snippet_with_context(cx, other.span, ctxt, "..", &mut applicability).0;
+    let op = op.as_str();
+    let sugg = if left {
+        format!("{replacement} {op} {other}")
+    } else {
+        format!("{other} {op} {replacement}")
+    };
+    span_lint_and_sugg(
+        cx,
+        NEEDLESS_STRING_ALLOCATION_IN_COMPARISON,
+        e.span,
+        "allocating a `String` only to compare it",
+        "compare without allocating",
+        sugg,
+        applicability,
+    );
+}
+
+/// Checks for `str`, `String` and references to them.
+fn is_str_like<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
+    let ty = ty.peel_refs();
+    ty.is_str() || is_type_lang_item(cx, ty, LangItem::String)
+}
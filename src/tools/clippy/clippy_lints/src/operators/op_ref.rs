@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::{multispan_sugg, span_lint_and_then};
 use clippy_utils::get_enclosing_block;
 use clippy_utils::source::snippet;
-use clippy_utils::ty::{implements_trait, is_copy};
+use clippy_utils::ty::{is_copy, ImplementsTraitCache};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{def::Res, def_id::DefId, BinOpKind, BorrowKind, Expr, ExprKind, GenericArg, ItemKind, QPath, TyKind};
@@ -13,6 +13,7 @@
 #[expect(clippy::similar_names, clippy::too_many_lines)]
 pub(crate) fn check<'tcx>(
     cx: &LateContext<'tcx>,
+    trait_cache: &mut ImplementsTraitCache<'tcx>,
     e: &'tcx Expr<'_>,
     op: BinOpKind,
     left: &'tcx Expr<'_>,
@@ -54,7 +55,7 @@ pub(crate) fn check<'tcx>(
                     }
                 }
                 // either operator autorefs or both args are copyable
-                if (requires_ref || (lcpy && rcpy)) && implements_trait(cx, lty, trait_id, &[rty.into()]) {
+                if (requires_ref || (lcpy && rcpy)) && trait_cache.implements_trait(cx, lty, trait_id, &[rty.into()]) {
                     span_lint_and_then(
                         cx,
                         OP_REF,
@@ -72,7 +73,7 @@ pub(crate) fn check<'tcx>(
                     );
                 } else if lcpy
                     && !rcpy
-                    && implements_trait(cx, lty, trait_id, &[cx.typeck_results().expr_ty(right).into()])
+                    && trait_cache.implements_trait(cx, lty, trait_id, &[cx.typeck_results().expr_ty(right).into()])
                 {
                     span_lint_and_then(
                         cx,
@@ -91,7 +92,7 @@ pub(crate) fn check<'tcx>(
                     );
                 } else if !lcpy
                     && rcpy
-                    && implements_trait(cx, cx.typeck_results().expr_ty(left), trait_id, &[rty.into()])
+                    && trait_cache.implements_trait(cx, cx.typeck_results().expr_ty(left), trait_id, &[rty.into()])
                 {
                     span_lint_and_then(
                         cx,
@@ -123,7 +124,7 @@ pub(crate) fn check<'tcx>(
                 }
                 let lcpy = is_copy(cx, lty);
                 if (requires_ref || lcpy)
-                    && implements_trait(cx, lty, trait_id, &[cx.typeck_results().expr_ty(right).into()])
+                    && trait_cache.implements_trait(cx, lty, trait_id, &[cx.typeck_results().expr_ty(right).into()])
                 {
                     span_lint_and_then(
                         cx,
@@ -155,7 +156,7 @@ pub(crate) fn check<'tcx>(
                 }
                 let rcpy = is_copy(cx, rty);
                 if (requires_ref || rcpy)
-                    && implements_trait(cx, cx.typeck_results().expr_ty(left), trait_id, &[rty.into()])
+                    && trait_cache.implements_trait(cx, cx.typeck_results().expr_ty(left), trait_id, &[rty.into()])
                 {
                     span_lint_and_then(cx, OP_REF, e.span, "taken reference of right operand", |diag| {
                         let rsnip = snippet(cx, r.span, "...").to_string();
@@ -0,0 +1,236 @@
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_and_then};
+use clippy_utils::macros::{root_macro_call_first_node, FormatArgsExpn};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_ast::LitKind;
+use rustc_errors::Applicability;
+use rustc_hir::{BinOpKind, Expr, ExprKind, MatchSource, PatKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::symbol::kw;
+use rustc_span::{sym, Span, SyntaxContext};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for paths and OS strings being compared to strings after a lossy conversion, i.e.
+    /// `path.to_string_lossy() == "literal"`, `path.display().to_string() == "literal"` or
+    /// `format!("{}", path.display()) == "literal"`, as well as `match`es on such a conversion.
+    ///
+    /// ### Why is this bad?
+    /// The conversion allocates, and it replaces everything which isn't valid Unicode, i.e.
+    /// arbitrary bytes on Unix and unpaired surrogates on Windows, with `U+FFFD`. Different paths
+    /// can convert to the same string, so the comparison can match paths it shouldn't.
+    /// `OsStr` can be compared to a `str` directly, which is exact on all platforms.
+    ///
+    /// Checking the end of the converted string is usually meant to check the last components
+    /// of the path, which is what `Path::ends_with` does.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::path::Path;
+    /// # let path = Path::new("Cargo.toml");
+    /// if path.to_string_lossy() == "Cargo.toml" {
+    ///     // ..
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::path::Path;
+    /// # let path = Path::new("Cargo.toml");
+    /// if path.as_os_str() == "Cargo.toml" {
+    ///     // ..
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub OSSTRING_LOSSY_COMPARISON,
+    suspicious,
+    "comparing a lossily converted path or OS string to a string"
+}
+
+declare_lint_pass!(OsStringLossyComparison => [OSSTRING_LOSSY_COMPARISON]);
+
+/// A lossy conversion of a path or an OS string to a string.
+struct LossyString<'tcx> {
+    /// The converted path or OS string.
+    os_str: &'tcx Expr<'tcx>,
+    /// Whether `os_str` is a `Path` or `PathBuf`, rather than an `OsStr` or `OsString`.
+    is_path: bool,
+    /// The span of the whole conversion.
+    span: Span,
+    /// The context of the conversion, which differs from the one of `os_str` for `format!`.
+    ctxt: SyntaxContext,
+}
+
+impl<'tcx> LateLintPass<'tcx> for OsStringLossyComparison {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if expr.span.from_expansion() {
+            return;
+        }
+        match expr.kind {
+            ExprKind::Binary(op, lhs, rhs) if matches!(op.node, BinOpKind::Eq | BinOpKind::Ne) => {
+                let (conversion, lit) = if is_str_lit(rhs) {
+                    (lhs, rhs)
+                } else if is_str_lit(lhs) {
+                    (rhs, lhs)
+                } else {
+                    return;
+                };
+                if let Some(lossy) = lossy_string(cx, conversion) {
+                    lint_comparison(cx, &lossy, lit);
+                }
+            },
+            ExprKind::Match(scrutinee, arms, MatchSource::Normal)
+                if arms.iter().any(|arm| matches!(arm.pat.kind, PatKind::Lit(_))) =>
+            {
+                if let Some(lossy) = lossy_string(cx, peel_str_borrow(scrutinee)) {
+                    let mut applicability = Applicability::Unspecified;
+                    let os_str = as_os_str(cx, &lossy, &mut applicability);
+                    span_lint_and_help(
+                        cx,
+                        OSSTRING_LOSSY_COMPARISON,
+                        scrutinee.span,
+                        "matching on a lossy conversion to a string",
+                        None,
+                        &format!("compare `{os_str}` to each of the strings instead"),
+                    );
+                }
+            },
+            ExprKind::MethodCall(path, recv, [arg], _)
+                if matches!(path.ident.as_str(), "starts_with" | "ends_with") && is_str_lit(arg) =>
+            {
+                if let Some(lossy) = lossy_string(cx, recv)
+                    && lossy.is_path
+                {
+                    let method = path.ident.as_str();
+                    span_lint_and_then(
+                        cx,
+                        OSSTRING_LOSSY_COMPARISON,
+                        expr.span,
+                        &format!("calling `{method}` on a lossy conversion of a path"),
+                        |diag| {
+                            let mut applicability = Applicability::MaybeIncorrect;
+                            let path =
+                                snippet_with_context(cx, lossy.os_str.span, lossy.ctxt, "..", &mut applicability).0;
+                            let arg = snippet_with_context(cx, arg.span, expr.span.ctxt(), "..", &mut applicability).0;
+                            diag.span_suggestion(
+                                expr.span,
+                                format!("use `Path::{method}` to compare whole components"),
+                                format!("{path}.{method}({arg})"),
+                                applicability,
+                            );
+                            diag.note(format!("`Path::{method}` doesn't match parts of a component"));
+                        },
+                    );
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+fn lint_comparison(cx: &LateContext<'_>, lossy: &LossyString<'_>, lit: &Expr<'_>) {
+    span_lint_and_then(
+        cx,
+        OSSTRING_LOSSY_COMPARISON,
+        lossy.span,
+        "comparing a lossy conversion to a string",
+        |diag| {
+            // The comparison can only differ if the string contains the replacement character
+            let mut applicability = if let ExprKind::Lit(lit) = &lit.kind
+                && let LitKind::Str(s, _) = lit.node
+                && !s.as_str().contains('\u{FFFD}')
+            {
+                Applicability::MachineApplicable
+            } else {
+                Applicability::MaybeIncorrect
+            };
+            let os_str = as_os_str(cx, lossy, &mut applicability);
+            diag.span_suggestion(lossy.span, "compare the `OsStr` instead", os_str, applicability);
+            diag.note(
+                "the conversion replaces invalid Unicode with `U+FFFD`, which can make different paths compare equal",
+            );
+        },
+    );
+}
+
+/// Gets the `OsStr` of a lossy conversion to compare instead.
+fn as_os_str(cx: &LateContext<'_>, lossy: &LossyString<'_>, applicability: &mut Applicability) -> String {
+    let os_str = snippet_with_context(cx, lossy.os_str.span, lossy.ctxt, "..", applicability).0;
+    if lossy.is_path || !cx.typeck_results().expr_ty(lossy.os_str).is_ref() {
+        format!("{os_str}.as_os_str()")
+    } else {
+        os_str.into_owned()
+    }
+}
+
+/// Matches `x.to_string_lossy()`, `x.display().to_string()` and `format!("{}", x.display())`,
+/// where `x` is a `Path`, `PathBuf`, `OsStr` or `OsString`.
+fn lossy_string<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> Option<LossyString<'tcx>> {
+    let (display, span) = match e.kind {
+        ExprKind::MethodCall(path, os_str, [], _) if path.ident.as_str() == "to_string_lossy" => {
+            return os_str_kind(cx, os_str).map(|is_path| LossyString {
+                os_str,
+                is_path,
+                span: e.span,
+                ctxt: e.span.ctxt(),
+            });
+        },
+        ExprKind::MethodCall(path, display, [], _) if path.ident.name == sym::to_string => (display, e.span),
+        _ => {
+            let macro_call = root_macro_call_first_node(cx, e)?;
+            if !cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id) {
+                return None;
+            }
+            let format_args = FormatArgsExpn::find_nested(cx, e, macro_call.expn)?;
+            let [arg] = &*format_args.args else {
+                return None;
+            };
+            if format_args.format_string.parts != [kw::Empty] || !arg.format.is_default() {
+                return None;
+            }
+            (arg.param.value, macro_call.span)
+        },
+    };
+    if let ExprKind::MethodCall(path, os_str, [], _) = display.kind
+        && path.ident.as_str() == "display"
+        && os_str_kind(cx, os_str) == Some(true)
+    {
+        Some(LossyString {
+            os_str,
+            is_path: true,
+            span,
+            ctxt: span.ctxt(),
+        })
+    } else {
+        None
+    }
+}
+
+/// Checks if `e` is a path (`Some(true)`) or an OS string (`Some(false)`), possibly behind
+/// references.
+fn os_str_kind(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<bool> {
+    let ty = cx.typeck_results().expr_ty(e).peel_refs();
+    if is_type_diagnostic_item(cx, ty, sym::Path) || is_type_diagnostic_item(cx, ty, sym::PathBuf) {
+        Some(true)
+    } else if is_type_diagnostic_item(cx, ty, sym::OsStr) || is_type_diagnostic_item(cx, ty, sym::OsString) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// Removes the `&*` or `.as_ref()` used to match a string on string literals.
+fn peel_str_borrow<'tcx>(e: &'tcx Expr<'tcx>) -> &'tcx Expr<'tcx> {
+    match e.kind {
+        ExprKind::AddrOf(_, _, inner) => match inner.kind {
+            ExprKind::Unary(UnOp::Deref, inner) => inner,
+            _ => e,
+        },
+        ExprKind::MethodCall(path, recv, [], _) if matches!(path.ident.as_str(), "as_ref" | "as_str") => recv,
+        _ => e,
+    }
+}
+
+fn is_str_lit(e: &Expr<'_>) -> bool {
+    matches!(e.kind, ExprKind::Lit(ref lit) if matches!(lit.node, LitKind::Str(..)))
+}
@@ -1,8 +1,9 @@
 use clippy_utils::diagnostics::span_lint;
+use clippy_utils::is_in_test;
 use clippy_utils::macros::{is_panic, root_macro_call_first_node};
 use rustc_hir::Expr;
-use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_lint::{LateContext, LateLintPass, Lint, LintId};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -72,13 +73,28 @@
     "usage of the `unreachable!` macro"
 }
 
-declare_lint_pass!(PanicUnimplemented => [UNIMPLEMENTED, UNREACHABLE, TODO, PANIC]);
+pub struct PanicUnimplemented {
+    /// The lints of this pass which aren't emitted in test code.
+    allowed_in_tests: Vec<LintId>,
+}
+
+impl PanicUnimplemented {
+    pub fn new(allowed_in_tests: Vec<LintId>) -> Self {
+        Self { allowed_in_tests }
+    }
+
+    fn is_allowed_in_tests(&self, cx: &LateContext<'_>, lint: &'static Lint, expr: &Expr<'_>) -> bool {
+        self.allowed_in_tests.contains(&LintId::of(lint)) && is_in_test(cx.tcx, expr.hir_id)
+    }
+}
+
+impl_lint_pass!(PanicUnimplemented => [UNIMPLEMENTED, UNREACHABLE, TODO, PANIC]);
 
 impl<'tcx> LateLintPass<'tcx> for PanicUnimplemented {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         let Some(macro_call) = root_macro_call_first_node(cx, expr) else { return };
         if is_panic(cx, macro_call.def_id) {
-            if cx.tcx.hir().is_inside_const_context(expr.hir_id) {
+            if cx.tcx.hir().is_inside_const_context(expr.hir_id) || self.is_allowed_in_tests(cx, PANIC, expr) {
                 return;
             }
 
@@ -91,7 +107,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
             return;
         }
         match cx.tcx.item_name(macro_call.def_id).as_str() {
-            "todo" => {
+            "todo" if !self.is_allowed_in_tests(cx, TODO, expr) => {
                 span_lint(
                     cx,
                     TODO,
@@ -99,7 +115,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     "`todo` should not be present in production code",
                 );
             },
-            "unimplemented" => {
+            "unimplemented" if !self.is_allowed_in_tests(cx, UNIMPLEMENTED, expr) => {
                 span_lint(
                     cx,
                     UNIMPLEMENTED,
@@ -107,7 +123,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     "`unimplemented` should not be present in production code",
                 );
             },
-            "unreachable" => {
+            "unreachable" if !self.is_allowed_in_tests(cx, UNREACHABLE, expr) => {
                 span_lint(cx, UNREACHABLE, macro_call.span, "usage of the `unreachable!` macro");
             },
             _ => {},
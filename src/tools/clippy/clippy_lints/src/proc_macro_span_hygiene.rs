@@ -0,0 +1,197 @@
+use crate::utils::conf::{self, DisallowedPath};
+use clippy_utils::consts::constant;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::{fn_def_id, get_parent_expr};
+use rustc_hir::def_id::DefIdMap;
+use rustc_hir::{Expr, ExprKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass, Lint, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::config::CrateType;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// In proc-macro crates, checks for identifiers built from runtime strings with
+    /// `Span::call_site()`, and for token streams parsed from runtime strings with
+    /// `.parse().unwrap()`.
+    ///
+    /// The constructors, spans and token streams are configured with the
+    /// `proc-macro-ident-constructors`, `proc-macro-call-site-spans` and
+    /// `proc-macro-token-streams` options, like the paths of `disallowed-methods`, whose reasons
+    /// are shown with the warnings.
+    ///
+    /// ### Why is this bad?
+    /// The generated tokens lose the spans of the user's tokens they were built from. Errors in
+    /// the generated code point to the macro invocation instead of the user's identifiers, and
+    /// the identifiers may resolve differently than the user's.
+    ///
+    /// ### Known problems
+    /// An identifier built from a user identifier with the call site span may be what the macro
+    /// wants, e.g. to define an item the user can name.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let getter = Ident::new(&format!("get_{field}"), Span::call_site());
+    /// let tokens: TokenStream = format!("fn {getter}() {{}}").parse().unwrap();
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let getter = Ident::new(&format!("get_{field}"), field.span());
+    /// let tokens = quote! { fn #getter() {} };
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub PROC_MACRO_DERIVE_MISSING_SPAN_HYGIENE,
+    pedantic,
+    "tokens built from strings at runtime in proc-macros, without the spans of the user's tokens"
+}
+
+pub struct ProcMacroSpanHygiene {
+    apis: TokenApis,
+}
+
+impl ProcMacroSpanHygiene {
+    pub fn new(
+        conf_ident_constructors: Vec<DisallowedPath>,
+        conf_call_site_spans: Vec<DisallowedPath>,
+        conf_token_streams: Vec<DisallowedPath>,
+    ) -> Self {
+        Self {
+            apis: TokenApis::new(conf_ident_constructors, conf_call_site_spans, conf_token_streams),
+        }
+    }
+}
+
+impl_lint_pass!(ProcMacroSpanHygiene => [PROC_MACRO_DERIVE_MISSING_SPAN_HYGIENE]);
+
+impl<'tcx> LateLintPass<'tcx> for ProcMacroSpanHygiene {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        if cx.tcx.sess.crate_types().contains(&CrateType::ProcMacro) {
+            self.apis.resolve(cx);
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        self.apis.check_expr(cx, PROC_MACRO_DERIVE_MISSING_SPAN_HYGIENE, expr);
+    }
+}
+
+/// The APIs building tokens checked by `PROC_MACRO_DERIVE_MISSING_SPAN_HYGIENE` and its internal
+/// variant.
+pub(crate) struct TokenApis {
+    conf_ident_constructors: Vec<DisallowedPath>,
+    conf_call_site_spans: Vec<DisallowedPath>,
+    conf_token_streams: Vec<DisallowedPath>,
+    ident_constructors: DefIdMap<usize>,
+    call_site_spans: DefIdMap<usize>,
+    token_streams: DefIdMap<usize>,
+}
+
+impl TokenApis {
+    pub(crate) fn new(
+        conf_ident_constructors: Vec<DisallowedPath>,
+        conf_call_site_spans: Vec<DisallowedPath>,
+        conf_token_streams: Vec<DisallowedPath>,
+    ) -> Self {
+        Self {
+            conf_ident_constructors,
+            conf_call_site_spans,
+            conf_token_streams,
+            ident_constructors: DefIdMap::default(),
+            call_site_spans: DefIdMap::default(),
+            token_streams: DefIdMap::default(),
+        }
+    }
+
+    /// Resolves the paths. Until then nothing is linted.
+    pub(crate) fn resolve(&mut self, cx: &LateContext<'_>) {
+        self.ident_constructors = conf::resolve_paths(cx, &self.conf_ident_constructors);
+        self.call_site_spans = conf::resolve_paths(cx, &self.conf_call_site_spans);
+        self.token_streams = conf::resolve_paths(cx, &self.conf_token_streams);
+    }
+
+    pub(crate) fn check_expr(&self, cx: &LateContext<'_>, lint: &'static Lint, expr: &Expr<'_>) {
+        if (self.ident_constructors.is_empty() && self.token_streams.is_empty())
+            || in_external_macro(cx.sess(), expr.span)
+        {
+            return;
+        }
+        match expr.kind {
+            ExprKind::Call(_, [name, call_site])
+                if let Some(constructor_index) = called_index(cx, &self.ident_constructors, expr)
+                    && let Some(span_index) = called_index(cx, &self.call_site_spans, call_site)
+                    && !is_constant(cx, name) =>
+            {
+                span_lint_and_then(
+                    cx,
+                    lint,
+                    call_site.span,
+                    "identifier built at runtime with the call site span",
+                    |diag| {
+                        diag.help("if it is built from the user's identifier, use its span, e.g. `ident.span()`");
+                        for reason in [
+                            &self.conf_ident_constructors[constructor_index],
+                            &self.conf_call_site_spans[span_index],
+                        ]
+                        .into_iter()
+                        .filter_map(DisallowedPath::reason)
+                        {
+                            diag.note(reason);
+                        }
+                    },
+                );
+            },
+            ExprKind::MethodCall(path, recv, [], _)
+                if path.ident.as_str() == "parse"
+                    && !is_constant(cx, recv)
+                    && let Some(stream_index) = self.token_stream_result(cx, expr)
+                    && let Some(parent) = get_parent_expr(cx, expr)
+                    && let ExprKind::MethodCall(unwrap, ..) = parent.kind
+                    && matches!(unwrap.ident.as_str(), "unwrap" | "expect") =>
+            {
+                span_lint_and_then(
+                    cx,
+                    lint,
+                    expr.span.with_hi(parent.span.hi()),
+                    "token stream parsed from a string built at runtime",
+                    |diag| {
+                        diag.help("build the tokens with `quote!` instead, to keep the spans of the user's tokens");
+                        if let Some(reason) = self.conf_token_streams[stream_index].reason() {
+                            diag.note(reason);
+                        }
+                    },
+                );
+            },
+            _ => {},
+        }
+    }
+
+    /// If the type of `expr` is `Result<T, _>`, with `T` one of the token streams, returns the
+    /// index of its path.
+    fn token_stream_result(&self, cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<usize> {
+        let ty = cx.typeck_results().expr_ty(expr);
+        if let ty::Adt(_, substs) = ty.kind()
+            && is_type_diagnostic_item(cx, ty, sym::Result)
+            && let ty::Adt(stream, _) = substs.type_at(0).kind()
+        {
+            self.token_streams.get(&stream.did()).copied()
+        } else {
+            None
+        }
+    }
+}
+
+/// If `expr` calls one of the resolved functions, returns the index of its path.
+fn called_index(cx: &LateContext<'_>, fns: &DefIdMap<usize>, expr: &Expr<'_>) -> Option<usize> {
+    fn_def_id(cx, expr).and_then(|id| fns.get(&id).copied())
+}
+
+/// Checks if the string is known at compile time, looking through borrows.
+fn is_constant(cx: &LateContext<'_>, mut expr: &Expr<'_>) -> bool {
+    while let ExprKind::AddrOf(_, _, inner) | ExprKind::Unary(UnOp::Deref, inner) = expr.kind {
+        expr = inner;
+    }
+    matches!(expr.kind, ExprKind::Lit(_)) || constant(cx, cx.typeck_results(), expr).is_some()
+}
@@ -0,0 +1,72 @@
+use itertools::Itertools;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::LocalDefId;
+use rustc_lint::LateContext;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use serde::{Deserialize, Serialize};
+
+/// The public API of a crate, as written by `cargo clippy --emit-public-api`.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct PublicApi {
+    pub items: Vec<ApiItem>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct ApiItem {
+    /// The path of the item's definition, e.g. `module::Type::method`.
+    pub path: String,
+    /// The kind of the item, e.g. `function` or `struct`.
+    pub kind: String,
+    /// The type of constants, statics, type aliases and fields, and the signature of functions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    /// Whether the item is an enum with `#[non_exhaustive]`.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub non_exhaustive: bool,
+}
+
+/// Collects the exported items of the crate, sorted by their path. Paths are printed in full,
+/// since trimming them depends on the other items in scope.
+pub(super) fn collect(cx: &LateContext<'_>) -> Vec<(LocalDefId, ApiItem)> {
+    let mut items: Vec<_> = cx
+        .effective_visibilities
+        .iter()
+        .filter(|(&def_id, _)| cx.effective_visibilities.is_exported(def_id))
+        .filter_map(|(&def_id, _)| Some((def_id, with_no_trimmed_paths!(api_item(cx, def_id))?)))
+        .collect();
+    items.sort_by(|(_, a), (_, b)| a.path.cmp(&b.path));
+    items
+}
+
+fn api_item(cx: &LateContext<'_>, def_id: LocalDefId) -> Option<ApiItem> {
+    let tcx = cx.tcx;
+    // Items of trait implementations are part of the trait's API
+    if let Some(impl_id) = tcx.impl_of_method(def_id.to_def_id())
+        && tcx.trait_id_of_impl(impl_id).is_some()
+    {
+        return None;
+    }
+    let kind = tcx.def_kind(def_id);
+    let signature = match kind {
+        DefKind::Fn | DefKind::AssocFn => {
+            let mut signature = tcx.fn_sig(def_id).subst_identity().to_string();
+            let predicates = tcx.predicates_of(def_id).predicates;
+            if !predicates.is_empty() {
+                signature.push_str(" where ");
+                signature.push_str(&predicates.iter().map(|(predicate, _)| predicate).join(", "));
+            }
+            Some(signature)
+        },
+        DefKind::Const | DefKind::AssocConst | DefKind::Static(_) | DefKind::TyAlias | DefKind::Field => {
+            Some(tcx.type_of(def_id).subst_identity().to_string())
+        },
+        DefKind::Struct | DefKind::Enum | DefKind::Union | DefKind::Variant | DefKind::Trait | DefKind::AssocTy => None,
+        _ => return None,
+    };
+    Some(ApiItem {
+        path: tcx.def_path_str(def_id.to_def_id()),
+        kind: tcx.def_descr(def_id.to_def_id()).to_string(),
+        signature,
+        non_exhaustive: kind == DefKind::Enum && tcx.adt_def(def_id).is_variant_list_non_exhaustive(),
+    })
+}
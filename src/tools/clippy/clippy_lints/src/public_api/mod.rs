@@ -0,0 +1,272 @@
+mod collect;
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clippy_utils::diagnostics::{span_lint_and_help, span_lint_hir_and_then};
+use rustc_data_structures::fx::FxHashMap;
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_session::config::CrateType;
+use rustc_session::{declare_tool_lint, impl_lint_pass, Session};
+use rustc_span::def_id::LOCAL_CRATE;
+use rustc_span::{Symbol, DUMMY_SP};
+
+use collect::PublicApi;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for items of the public API baseline which the crate doesn't export anymore.
+    ///
+    /// The baseline is written by `cargo clippy --emit-public-api <dir>`, and set with the
+    /// `public-api-baseline` configuration option.
+    ///
+    /// ### Why is this bad?
+    /// Removing an item from the public API breaks users of the crate which use it, so it
+    /// requires a new major version.
+    ///
+    /// ### Known problems
+    /// Items are compared by the path of their definition, so moving an item to another module
+    /// is reported as a removal, even if it's re-exported at its old path.
+    ///
+    /// ### Example
+    /// With a baseline containing the function `parse`:
+    /// ```rust
+    /// pub fn parse_str(input: &str) -> Option<u32> {
+    ///     input.parse().ok()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub fn parse_str(input: &str) -> Option<u32> {
+    ///     input.parse().ok()
+    /// }
+    ///
+    /// #[deprecated = "use `parse_str` instead"]
+    /// pub fn parse(input: &str) -> Option<u32> {
+    ///     parse_str(input)
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub REMOVED_PUBLIC_ITEM,
+    semver,
+    "items of the public API baseline which the crate doesn't export anymore"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for exported items whose kind, type or signature differs from the public API
+    /// baseline.
+    ///
+    /// The baseline is written by `cargo clippy --emit-public-api <dir>`, and set with the
+    /// `public-api-baseline` configuration option.
+    ///
+    /// ### Why is this bad?
+    /// Changing the signature of a function, or the type of a field or a constant, usually breaks
+    /// users of the crate, so it requires a new major version.
+    ///
+    /// ### Known problems
+    /// Some changes are compatible, e.g. adding a defaulted type parameter, but are reported
+    /// as well.
+    ///
+    /// ### Example
+    /// With a baseline containing `fn(&str) -> Option<u32>` as the signature of `parse`:
+    /// ```rust
+    /// pub fn parse(input: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     input.parse()
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// pub fn parse(input: &str) -> Option<u32> {
+    ///     try_parse(input).ok()
+    /// }
+    ///
+    /// pub fn try_parse(input: &str) -> Result<u32, std::num::ParseIntError> {
+    ///     input.parse()
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub CHANGED_PUBLIC_SIGNATURE,
+    semver,
+    "exported items whose signature differs from the public API baseline"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for exported enums which are `#[non_exhaustive]` in the public API baseline,
+    /// but aren't anymore.
+    ///
+    /// The baseline is written by `cargo clippy --emit-public-api <dir>`, and set with the
+    /// `public-api-baseline` configuration option.
+    ///
+    /// ### Why is this bad?
+    /// Once users can match the enum exhaustively, adding a variant breaks their code. Removing
+    /// `#[non_exhaustive]` can't be undone without a new major version, so it should be a
+    /// deliberate decision.
+    ///
+    /// ### Example
+    /// With a baseline containing the `#[non_exhaustive]` enum `Format`:
+    /// ```rust
+    /// pub enum Format {
+    ///     Json,
+    ///     Toml,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// #[non_exhaustive]
+    /// pub enum Format {
+    ///     Json,
+    ///     Toml,
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub NEWLY_EXHAUSTIVE_ENUM,
+    semver,
+    "exported enums which are `#[non_exhaustive]` in the public API baseline, but aren't anymore"
+}
+
+/// Reads the public API baseline at `path`, relative to the crate's manifest directory, reporting
+/// errors to `sess`.
+pub fn read_baseline(sess: &Session, path: &str) -> Option<PublicApi> {
+    // Like `clippy.toml`, the baseline belongs to the crate, not to the directory Clippy runs in
+    let path = env::var_os("CARGO_MANIFEST_DIR").map_or_else(|| PathBuf::from(path), |dir| Path::new(&dir).join(path));
+    // Re-run Clippy when the baseline changes
+    sess.parse_sess
+        .file_depinfo
+        .borrow_mut()
+        .insert(Symbol::intern(&path.to_string_lossy()));
+    let baseline = fs::read_to_string(&path)
+        .map_err(|error| error.to_string())
+        .and_then(|baseline| serde_json::from_str(&baseline).map_err(|error| error.to_string()));
+    match baseline {
+        Ok(baseline) => Some(baseline),
+        Err(error) => {
+            sess.err(format!(
+                "error reading the public API baseline `{}`: {error}",
+                path.display()
+            ));
+            None
+        },
+    }
+}
+
+pub struct PublicApiDiff {
+    baseline: PublicApi,
+}
+
+impl PublicApiDiff {
+    pub fn new(baseline: PublicApi) -> Self {
+        Self { baseline }
+    }
+}
+
+impl_lint_pass!(PublicApiDiff => [REMOVED_PUBLIC_ITEM, CHANGED_PUBLIC_SIGNATURE, NEWLY_EXHAUSTIVE_ENUM]);
+
+impl<'tcx> LateLintPass<'tcx> for PublicApiDiff {
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        let current = collect::collect(cx);
+        let current: FxHashMap<_, _> = current
+            .iter()
+            .map(|(def_id, item)| (&*item.path, (*def_id, item)))
+            .collect();
+        for old in &self.baseline.items {
+            let Some(&(def_id, new)) = current.get(&*old.path) else {
+                // The item is gone, so there is nothing in the crate to point at
+                span_lint_and_help(
+                    cx,
+                    REMOVED_PUBLIC_ITEM,
+                    DUMMY_SP,
+                    &format!(
+                        "the {} `{}` of the public API baseline isn't exported anymore",
+                        old.kind, old.path
+                    ),
+                    None,
+                    "removing it requires a new major version, consider deprecating it instead",
+                );
+                continue;
+            };
+            let hir_id = cx.tcx.hir().local_def_id_to_hir_id(def_id);
+            let span = cx.tcx.def_span(def_id);
+            if new.kind != old.kind {
+                span_lint_hir_and_then(
+                    cx,
+                    CHANGED_PUBLIC_SIGNATURE,
+                    hir_id,
+                    span,
+                    &format!(
+                        "`{}` changed from {} to {} since the public API baseline",
+                        new.path, old.kind, new.kind
+                    ),
+                    |diag| {
+                        diag.help("changing the kind of an item requires a new major version");
+                    },
+                );
+            } else if new.signature != old.signature {
+                span_lint_hir_and_then(
+                    cx,
+                    CHANGED_PUBLIC_SIGNATURE,
+                    hir_id,
+                    span,
+                    &format!("the signature of `{}` differs from the public API baseline", new.path),
+                    |diag| {
+                        diag.note(format!(
+                            "the signature is `{}` in the baseline",
+                            old.signature.as_deref().unwrap_or_default()
+                        ));
+                        diag.note(format!("and `{}` now", new.signature.as_deref().unwrap_or_default()));
+                    },
+                );
+            }
+            if old.non_exhaustive && !new.non_exhaustive {
+                span_lint_hir_and_then(
+                    cx,
+                    NEWLY_EXHAUSTIVE_ENUM,
+                    hir_id,
+                    span,
+                    &format!(
+                        "`{}` is `#[non_exhaustive]` in the public API baseline, but not anymore",
+                        new.path
+                    ),
+                    |diag| {
+                        diag.help("adding variants will require a new major version");
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Writes the public API of library crates to `<dir>/<crate name>.json`. Enabled by
+/// `cargo clippy --emit-public-api <dir>`.
+pub struct EmitPublicApi {
+    dir: PathBuf,
+}
+
+impl EmitPublicApi {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+}
+
+impl_lint_pass!(EmitPublicApi => []);
+
+impl<'tcx> LateLintPass<'tcx> for EmitPublicApi {
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        // Test harnesses and binaries don't have a public API, and would overwrite the library's
+        if cx.sess().opts.test || cx.sess().crate_types().contains(&CrateType::Executable) {
+            return;
+        }
+        let api = PublicApi {
+            items: collect::collect(cx).into_iter().map(|(_, item)| item).collect(),
+        };
+        let path = self.dir.join(format!("{}.json", cx.tcx.crate_name(LOCAL_CRATE)));
+        let result = fs::create_dir_all(&self.dir)
+            .and_then(|_| fs::write(&path, serde_json::to_string_pretty(&api).unwrap() + "\n"));
+        if let Err(error) = result {
+            cx.sess()
+                .err(format!("error writing the public API to `{}`: {error}", path.display()));
+        }
+    }
+}
@@ -3,8 +3,7 @@
 use clippy_utils::last_path_segment;
 use clippy_utils::macros::root_macro_call_first_node;
 use clippy_utils::paths;
-use clippy_utils::source::{indent_of, snippet};
-use clippy_utils::ty::match_type;
+use clippy_utils::source::{indent_of, snippet, snippet_with_macro_args};
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, QPath, TyKind};
 use rustc_lint::{LateContext, LateLintPass};
@@ -86,8 +85,14 @@ fn emit_lint(cx: &LateContext<'_>, symbol: Symbol, lint_span: Span, elem: &Expr<
         lint_span,
         "initializing a reference-counted pointer in `vec![elem; len]`",
         |diag| {
+            let mut app = Applicability::HasPlaceholders;
             let len_snippet = snippet(cx, len.span, "..");
-            let elem_snippet = format!("{}(..)", snippet(cx, elem.span.with_hi(func_span.hi()), ".."));
+            let elem_snippet = if elem.span.ctxt() == lint_span.ctxt() {
+                format!("{}(..)", snippet(cx, elem.span.with_hi(func_span.hi()), ".."))
+            } else {
+                // The constructor isn't written by the user, so there's nothing to elide
+                snippet_with_macro_args(cx, elem, lint_span.ctxt(), "..", &mut app).into_owned()
+            };
             let indentation = " ".repeat(indent_of(cx, lint_span).unwrap_or(0));
             let loop_init_suggestion = loop_init_suggestion(&elem_snippet, len_snippet.as_ref(), &indentation);
             let extract_suggestion = extract_suggestion(&elem_snippet, len_snippet.as_ref(), &indentation);
@@ -97,7 +102,7 @@ fn emit_lint(cx: &LateContext<'_>, symbol: Symbol, lint_span: Span, elem: &Expr<
                 lint_span,
                 format!("consider initializing each `{symbol_name}` element individually"),
                 loop_init_suggestion,
-                Applicability::HasPlaceholders,
+                app,
             );
             diag.span_suggestion(
                 lint_span,
@@ -105,7 +110,7 @@ fn emit_lint(cx: &LateContext<'_>, symbol: Symbol, lint_span: Span, elem: &Expr<
                     "or if this is intentional, consider extracting the `{symbol_name}` initialization to a variable"
                 ),
                 extract_suggestion,
-                Applicability::HasPlaceholders,
+                app,
             );
         },
     );
@@ -129,7 +134,7 @@ fn ref_init(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<(Symbol, Span)> {
             }
 
             let ty_path = cx.typeck_results().expr_ty(expr);
-            if match_type(cx, ty_path, &paths::WEAK_RC) || match_type(cx, ty_path, &paths::WEAK_ARC) {
+            if paths::WEAK_RC.matches_ty(cx, ty_path) || paths::WEAK_ARC.matches_ty(cx, ty_path) {
                 return Some((Symbol::intern("Weak"), func.span));
             }
         }
@@ -0,0 +1,104 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::source::indent_of;
+use rustc_errors::{Applicability, MultiSpan};
+use rustc_hir::{Item, ItemKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, AdtDef, SubstsRef, Ty};
+use rustc_span::{sym, Span};
+
+use super::DROP_RECURSION_STACK_OVERFLOW;
+
+/// How a field owns values of the type it's declared in.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Nesting {
+    /// `Box<Self>`
+    Box,
+    /// `Option<Box<Self>>`
+    OptionBox,
+    /// `Vec<Self>`
+    Vec,
+}
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, item: &Item<'_>, adt: AdtDef<'tcx>, substs: SubstsRef<'tcx>) {
+    // Chains, which own a single value in every variant, are linted by
+    // `recursive_type_without_indirection_hint`
+    let is_tree = adt.variants().iter().any(|variant| {
+        let nested: Vec<_> = variant
+            .fields
+            .iter()
+            .filter_map(|field| nesting(cx, field.ty(cx.tcx, substs), adt))
+            .collect();
+        nested.len() > 1 || nested.contains(&Nesting::Vec)
+    });
+    if !is_tree {
+        return;
+    }
+    let fields: Vec<_> = adt
+        .all_fields()
+        .filter_map(|field| Some((field, nesting(cx, field.ty(cx.tcx, substs), adt)?)))
+        .collect();
+    let Some(&(field, kind)) = fields.first() else {
+        return;
+    };
+
+    let name = item.ident;
+    span_lint_and_then(
+        cx,
+        DROP_RECURSION_STACK_OVERFLOW,
+        name.span,
+        &format!("dropping a deeply nested `{name}` recurses once per level, which can overflow the stack"),
+        |diag| {
+            let spans: Vec<Span> = fields.iter().map(|(field, _)| cx.tcx.def_span(field.did)).collect();
+            let fields_desc = if spans.len() == 1 { "this field" } else { "these fields" };
+            diag.span_note(
+                MultiSpan::from_spans(spans),
+                format!(
+                    "`{name}` owns values of its own type through {fields_desc}, and relies on the generated drop glue"
+                ),
+            );
+
+            let msg = "implement `Drop` to take the nested values apart in a loop";
+            // The skeleton only handles a single `Vec<Self>` field, and `Drop` impls of generic types have
+            // to repeat all of the bounds
+            if fields.len() == 1
+                && kind == Nesting::Vec
+                && matches!(item.kind, ItemKind::Struct(..))
+                && !field.name.as_str().starts_with(|c: char| c.is_ascii_digit())
+                && cx.tcx.generics_of(item.owner_id).count() == 0
+            {
+                let indent = " ".repeat(indent_of(cx, item.span).unwrap_or(0));
+                let body = [
+                    format!("let mut stack = std::mem::take(&mut self.{});", field.name),
+                    "while let Some(mut node) = stack.pop() {".to_string(),
+                    format!("    stack.append(&mut node.{});", field.name),
+                ];
+                let mut sugg = format!("\n\n{indent}impl Drop for {name} {{\n{indent}    fn drop(&mut self) {{\n");
+                for line in body {
+                    sugg.push_str(&format!("{indent}        {line}\n"));
+                }
+                sugg.push_str(&format!("{indent}        }}\n{indent}    }}\n{indent}}}"));
+                diag.span_suggestion(item.span.shrink_to_hi(), msg, sugg, Applicability::MaybeIncorrect);
+            } else {
+                diag.help(msg);
+            }
+        },
+    );
+}
+
+/// Checks if `ty` is `Box<Self>`, `Option<Box<Self>>` or `Vec<Self>`, where `Self` is `adt`.
+fn nesting<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, adt: AdtDef<'tcx>) -> Option<Nesting> {
+    let is_self = |ty: Ty<'tcx>| matches!(ty.kind(), ty::Adt(def, _) if *def == adt);
+    let ty::Adt(def, substs) = ty.kind() else {
+        return None;
+    };
+    let inner = substs.types().next()?;
+    if def.is_box() {
+        is_self(inner).then_some(Nesting::Box)
+    } else {
+        match cx.tcx.get_diagnostic_name(def.did())? {
+            sym::Option if matches!(nesting(cx, inner, adt), Some(Nesting::Box)) => Some(Nesting::OptionBox),
+            sym::Vec if is_self(inner) => Some(Nesting::Vec),
+            _ => None,
+        }
+    }
+}
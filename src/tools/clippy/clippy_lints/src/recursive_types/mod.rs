@@ -0,0 +1,117 @@
+mod drop_recursion_stack_overflow;
+mod recursive_type_without_indirection_hint;
+
+use rustc_hir::{Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty;
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for types which own exactly one value of their own type through a pointer, e.g.
+    /// `next: Option<Box<Node>>`, without implementing `Drop`.
+    ///
+    /// Types which own several values of their own type, e.g. the children of a tree, are
+    /// linted by [`drop_recursion_stack_overflow`](#drop_recursion_stack_overflow) instead.
+    ///
+    /// ### Why is this bad?
+    /// Such a type forms a chain, e.g. a linked list. The generated drop glue drops the next
+    /// element before returning, so dropping a chain recurses once per element, and a long chain
+    /// overflows the stack.
+    ///
+    /// ### Example
+    /// ```rust
+    /// struct Node {
+    ///     value: i32,
+    ///     next: Option<Box<Node>>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Node {
+    ///     value: i32,
+    ///     next: Option<Box<Node>>,
+    /// }
+    ///
+    /// impl Drop for Node {
+    ///     fn drop(&mut self) {
+    ///         let mut next = self.next.take();
+    ///         while let Some(mut node) = next {
+    ///             next = node.next.take();
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub RECURSIVE_TYPE_WITHOUT_INDIRECTION_HINT,
+    nursery,
+    "types forming a chain of owned values whose drop recurses once per element"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for tree-like types which don't implement `Drop`, i.e. types owning several values
+    /// of their own type, through a field of type `Vec<Self>` or several fields of type
+    /// `Box<Self>` or `Option<Box<Self>>`.
+    ///
+    /// Types which own a single value of their own type, e.g. a linked list, are linted by
+    /// [`recursive_type_without_indirection_hint`](#recursive_type_without_indirection_hint)
+    /// instead.
+    ///
+    /// ### Why is this bad?
+    /// The generated drop glue drops the nested values recursively, so dropping a deeply nested
+    /// tree overflows the stack. A degenerate tree, e.g. one built from sorted input, is as deep
+    /// as it is large.
+    ///
+    /// ### Known problems
+    /// Trees which are kept balanced only grow logarithmically deep and can't overflow the stack.
+    ///
+    /// ### Example
+    /// ```rust
+    /// struct Tree {
+    ///     value: i32,
+    ///     children: Vec<Tree>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Tree {
+    ///     value: i32,
+    ///     children: Vec<Tree>,
+    /// }
+    ///
+    /// impl Drop for Tree {
+    ///     fn drop(&mut self) {
+    ///         let mut stack = std::mem::take(&mut self.children);
+    ///         while let Some(mut node) = stack.pop() {
+    ///             stack.append(&mut node.children);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub DROP_RECURSION_STACK_OVERFLOW,
+    pedantic,
+    "types owning values of their own type which are dropped recursively"
+}
+
+declare_lint_pass!(RecursiveTypes => [RECURSIVE_TYPE_WITHOUT_INDIRECTION_HINT, DROP_RECURSION_STACK_OVERFLOW]);
+
+impl<'tcx> LateLintPass<'tcx> for RecursiveTypes {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if !matches!(item.kind, ItemKind::Struct(..) | ItemKind::Enum(..)) || in_external_macro(cx.sess(), item.span) {
+            return;
+        }
+        let ty::Adt(adt, substs) = cx.tcx.type_of(item.owner_id).subst_identity().kind() else {
+            return;
+        };
+        if adt.has_dtor(cx.tcx) {
+            return;
+        }
+
+        // The two lints are disjoint: the first one only lints trees, the second one only chains
+        drop_recursion_stack_overflow::check(cx, item, *adt, substs);
+        recursive_type_without_indirection_hint::check(cx, item, *adt, substs);
+    }
+}
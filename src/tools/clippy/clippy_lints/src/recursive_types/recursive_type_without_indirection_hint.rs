@@ -0,0 +1,111 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::paths;
+use rustc_hir::def_id::DefId;
+use rustc_hir::Item;
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, AdtDef, SubstsRef, Ty};
+use rustc_span::sym;
+
+use super::RECURSIVE_TYPE_WITHOUT_INDIRECTION_HINT;
+
+/// How deep to look into the fields of other local types for the linted type.
+const MAX_DEPTH: usize = 4;
+
+pub(super) fn check<'tcx>(cx: &LateContext<'tcx>, item: &Item<'_>, adt: AdtDef<'tcx>, substs: SubstsRef<'tcx>) {
+    // The field owning the next element of the chain
+    let mut chain_field = None;
+    for variant in adt.variants() {
+        let mut edges = Edges {
+            target: adt.did(),
+            visited: Vec::new(),
+            single: 0,
+            in_collection: 0,
+        };
+        for field in &variant.fields {
+            let before = edges.single;
+            edges.walk(cx, field.ty(cx.tcx, substs), false);
+            if edges.single > before {
+                chain_field.get_or_insert(field.did);
+            }
+        }
+        if edges.single > 1 || edges.in_collection > 0 {
+            return;
+        }
+    }
+
+    if let Some(field) = chain_field {
+        let name = item.ident;
+        span_lint_and_then(
+            cx,
+            RECURSIVE_TYPE_WITHOUT_INDIRECTION_HINT,
+            name.span,
+            &format!("dropping a long chain of `{name}` recurses once per element, which can overflow the stack"),
+            |diag| {
+                diag.span_note(
+                    cx.tcx.def_span(field),
+                    format!("each `{name}` owns the next one through this field"),
+                );
+                diag.help("implement `Drop` to take the chain apart in a loop, or store the elements in a `Vec`");
+            },
+        );
+    }
+}
+
+/// Counts the ways a type owns values of the `target` type.
+struct Edges {
+    target: DefId,
+    visited: Vec<DefId>,
+    /// Values owned directly through a pointer, e.g. `Box<T>`
+    single: usize,
+    /// Values owned through a collection, e.g. `Vec<T>`
+    in_collection: usize,
+}
+
+impl Edges {
+    fn walk<'tcx>(&mut self, cx: &LateContext<'tcx>, ty: Ty<'tcx>, in_collection: bool) {
+        match *ty.kind() {
+            ty::Adt(def, _) if def.did() == self.target => {
+                if in_collection {
+                    self.in_collection += 1;
+                } else {
+                    self.single += 1;
+                }
+            },
+            ty::Adt(def, substs) if def.did().is_local() => {
+                if self.visited.len() < MAX_DEPTH && !self.visited.contains(&def.did()) {
+                    self.visited.push(def.did());
+                    for field in def.all_fields() {
+                        self.walk(cx, field.ty(cx.tcx, substs), in_collection);
+                    }
+                    self.visited.pop();
+                }
+            },
+            ty::Adt(def, substs) => {
+                // Weak pointers don't own the value
+                if paths::WEAK_RC.matches(cx, def.did()) || paths::WEAK_ARC.matches(cx, def.did()) {
+                    return;
+                }
+                let name = cx.tcx.get_diagnostic_name(def.did());
+                let owns_one = def.is_box()
+                    || matches!(
+                        name,
+                        Some(sym::Option | sym::Rc | sym::Arc | sym::RefCell | sym::Mutex | sym::RwLock)
+                    );
+                // Other types from the standard library are assumed to be collections, types from
+                // other crates are unknown
+                if owns_one || matches!(cx.tcx.crate_name(def.did().krate), sym::std | sym::alloc | sym::core) {
+                    for ty in substs.types() {
+                        self.walk(cx, ty, in_collection || !owns_one);
+                    }
+                }
+            },
+            ty::Array(ty, _) | ty::Slice(ty) => self.walk(cx, ty, true),
+            ty::Tuple(tys) => {
+                for ty in tys {
+                    self.walk(cx, ty, in_collection);
+                }
+            },
+            _ => {},
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use clippy_utils::{diagnostics::span_lint_and_sugg, source::snippet};
+use clippy_utils::{diagnostics::span_lint_and_sugg, source::snippet_with_applicability};
 use rustc_ast::ast::*;
 use rustc_ast::visit::Visitor as AstVisitor;
 use rustc_errors::Applicability;
@@ -48,14 +48,15 @@ fn check_expr(&mut self, cx: &EarlyContext<'_>, expr: &Expr) {
             !future.span.from_expansion() &&
             !await_in_expr(future)
         {
+            let mut app = Applicability::MachineApplicable;
             span_lint_and_sugg(
                 cx,
                 REDUNDANT_ASYNC_BLOCK,
                 expr.span,
                 "this async expression only awaits a single future",
                 "you can reduce it to",
-                snippet(cx, future.span, "..").into_owned(),
-                Applicability::MachineApplicable,
+                snippet_with_applicability(cx, future.span, "..", &mut app).into_owned(),
+                app,
             );
         }
     }
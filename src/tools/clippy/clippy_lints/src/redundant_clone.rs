@@ -1,8 +1,11 @@
 use clippy_utils::diagnostics::{span_lint_hir, span_lint_hir_and_then};
-use clippy_utils::mir::{visit_local_usage, LocalUsage, PossibleBorrowerMap};
+use clippy_utils::fn_has_unsatisfiable_preds;
+use clippy_utils::mir::{
+    visit_local_usage, DefSite, LocalLiveness, LocalUsage, PossibleBorrowerMap, ReachingDefinitions,
+};
+use clippy_utils::paths::{self, PathLookup};
 use clippy_utils::source::snippet_opt;
 use clippy_utils::ty::{has_drop, is_copy, is_type_diagnostic_item, is_type_lang_item, walk_ptrs_ty_depth};
-use clippy_utils::{fn_has_unsatisfiable_preds, match_def_path, paths};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::intravisit::FnKind;
@@ -63,6 +66,10 @@ macro_rules! unwrap_or_continue {
 
 declare_lint_pass!(RedundantClone => [REDUNDANT_CLONE]);
 
+static CLONE_TRAIT_METHOD: PathLookup = PathLookup::new(&["core", "clone", "Clone", "clone"]);
+static OS_STR_TO_OS_STRING: PathLookup = PathLookup::new(&["std", "ffi", "os_str", "OsStr", "to_os_string"]);
+static PATH_TO_PATH_BUF: PathLookup = PathLookup::new(&["std", "path", "Path", "to_path_buf"]);
+
 impl<'tcx> LateLintPass<'tcx> for RedundantClone {
     #[expect(clippy::too_many_lines)]
     fn check_fn(
@@ -82,6 +89,8 @@ fn check_fn(
         let mir = cx.tcx.optimized_mir(def_id.to_def_id());
 
         let mut possible_borrower = PossibleBorrowerMap::new(cx, mir);
+        let mut liveness = LocalLiveness::new(cx.tcx, mir);
+        let mut reaching_defs = None;
 
         for (bb, bbdata) in mir.basic_blocks.iter_enumerated() {
             let terminator = bbdata.terminator();
@@ -98,14 +107,12 @@ fn check_fn(
             let (fn_def_id, arg, arg_ty, clone_ret) =
                 unwrap_or_continue!(is_call_with_ref_arg(cx, mir, &terminator.kind));
 
-            let from_borrow = match_def_path(cx, fn_def_id, &paths::CLONE_TRAIT_METHOD)
-                || match_def_path(cx, fn_def_id, &paths::TO_OWNED_METHOD)
-                || (match_def_path(cx, fn_def_id, &paths::TO_STRING_METHOD)
-                    && is_type_lang_item(cx, arg_ty, LangItem::String));
+            let from_borrow = CLONE_TRAIT_METHOD.matches(cx, fn_def_id)
+                || paths::TO_OWNED_METHOD.matches(cx, fn_def_id)
+                || (paths::TO_STRING_METHOD.matches(cx, fn_def_id) && is_type_lang_item(cx, arg_ty, LangItem::String));
 
-            let from_deref = !from_borrow
-                && (match_def_path(cx, fn_def_id, &paths::PATH_TO_PATH_BUF)
-                    || match_def_path(cx, fn_def_id, &paths::OS_STR_TO_OS_STRING));
+            let from_deref =
+                !from_borrow && (PATH_TO_PATH_BUF.matches(cx, fn_def_id) || OS_STR_TO_OS_STRING.matches(cx, fn_def_id));
 
             if !from_borrow && !from_deref {
                 continue;
@@ -117,8 +124,11 @@ fn check_fn(
                 }
             }
 
+            let reaching_defs = reaching_defs.get_or_insert_with(|| ReachingDefinitions::new(mir));
+
             // `{ arg = &cloned; clone(move arg); }` or `{ arg = &cloned; to_path_buf(arg); }`
-            let (cloned, cannot_move_out) = unwrap_or_continue!(find_stmt_assigns_to(cx, mir, arg, from_borrow, bb));
+            let (cloned, cannot_move_out) =
+                unwrap_or_continue!(find_stmt_assigns_to(cx, mir, reaching_defs, arg, from_borrow, bb));
 
             let loc = mir::Location {
                 block: bb,
@@ -161,7 +171,7 @@ fn check_fn(
                 };
 
                 let (local, cannot_move_out) =
-                    unwrap_or_continue!(find_stmt_assigns_to(cx, mir, pred_arg, true, ps[0]));
+                    unwrap_or_continue!(find_stmt_assigns_to(cx, mir, reaching_defs, pred_arg, true, ps[0]));
                 let loc = mir::Location {
                     block: bb,
                     statement_index: mir.basic_blocks[bb].statements.len(),
@@ -191,7 +201,7 @@ fn check_fn(
                     clone_consumed_or_mutated: true,
                 }
             } else {
-                let clone_usage = visit_clone_usage(local, ret_local, mir, bb);
+                let clone_usage = visit_clone_usage(local, ret_local, mir, bb, &mut liveness);
                 if clone_usage.cloned_used && clone_usage.clone_consumed_or_mutated {
                     // cloned value is used, and the clone is modified or moved
                     continue;
@@ -279,22 +289,26 @@ fn is_call_with_ref_arg<'tcx>(
 
 type CannotMoveOut = bool;
 
-/// Finds the first `to = (&)from`, and returns
+/// Finds the only `to = (&)from` which reaches the terminator of `bb`, and returns
 /// ``Some((from, whether `from` cannot be moved out))``.
 fn find_stmt_assigns_to<'tcx>(
     cx: &LateContext<'tcx>,
     mir: &mir::Body<'tcx>,
+    reaching_defs: &ReachingDefinitions,
     to_local: mir::Local,
     by_ref: bool,
     bb: mir::BasicBlock,
 ) -> Option<(mir::Local, CannotMoveOut)> {
-    let rvalue = mir.basic_blocks[bb].statements.iter().rev().find_map(|stmt| {
-        if let mir::StatementKind::Assign(box (mir::Place { local, .. }, v)) = &stmt.kind {
-            return if *local == to_local { Some(v) } else { None };
-        }
-
-        None
-    })?;
+    let [DefSite::Assign(location)] = reaching_defs.reaching(to_local, mir.terminator_loc(bb))[..] else {
+        return None;
+    };
+    let mir::StatementKind::Assign(box (_, rvalue)) = &mir.basic_blocks[location.block]
+        .statements
+        .get(location.statement_index)?
+        .kind
+    else {
+        return None;
+    };
 
     match (by_ref, rvalue) {
         (true, mir::Rvalue::Ref(_, _, place)) | (false, mir::Rvalue::Use(mir::Operand::Copy(place))) => {
@@ -353,28 +367,31 @@ struct CloneUsage {
     clone_consumed_or_mutated: bool,
 }
 
-fn visit_clone_usage(cloned: mir::Local, clone: mir::Local, mir: &mir::Body<'_>, bb: mir::BasicBlock) -> CloneUsage {
+fn visit_clone_usage(
+    cloned: mir::Local,
+    clone: mir::Local,
+    mir: &mir::Body<'_>,
+    bb: mir::BasicBlock,
+    liveness: &mut LocalLiveness<'_, '_>,
+) -> CloneUsage {
+    let loc = mir::Location {
+        block: bb,
+        statement_index: mir.basic_blocks[bb].statements.len(),
+    };
     if let Some((
         LocalUsage {
-            local_use_locs: cloned_use_locs,
+            local_use_locs: _,
             local_consume_or_mutate_locs: cloned_consume_or_mutate_locs,
         },
         LocalUsage {
             local_use_locs: _,
             local_consume_or_mutate_locs: clone_consume_or_mutate_locs,
         },
-    )) = visit_local_usage(
-        &[cloned, clone],
-        mir,
-        mir::Location {
-            block: bb,
-            statement_index: mir.basic_blocks[bb].statements.len(),
-        },
-    )
-    .map(|mut vec| (vec.remove(0), vec.remove(0)))
+    )) = visit_local_usage(&[cloned, clone], mir, loc).map(|mut vec| (vec.remove(0), vec.remove(0)))
     {
         CloneUsage {
-            cloned_used: !cloned_use_locs.is_empty(),
+            // Uses after `cloned` is reassigned don't need the original value
+            cloned_used: liveness.is_live_after(cloned, loc),
             cloned_consume_or_mutate_loc: cloned_consume_or_mutate_locs.first().copied(),
             // Consider non-temporary clones consumed.
             // TODO: Actually check for mutation of non-temporaries.
@@ -2,8 +2,8 @@
 
 use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
+use clippy_utils::paths::PathLookup;
 use clippy_utils::source::snippet_opt;
-use clippy_utils::{match_def_path, paths};
 use if_chain::if_chain;
 use rustc_ast::ast::{LitKind, StrStyle};
 use rustc_hir::{BorrowKind, Expr, ExprKind};
@@ -57,6 +57,13 @@
 
 declare_lint_pass!(Regex => [INVALID_REGEX, TRIVIAL_REGEX]);
 
+static REGEX_NEW: PathLookup = PathLookup::new(&["regex", "re_unicode", "Regex", "new"]);
+static REGEX_BUILDER_NEW: PathLookup = PathLookup::new(&["regex", "re_builder", "unicode", "RegexBuilder", "new"]);
+static REGEX_SET_NEW: PathLookup = PathLookup::new(&["regex", "re_set", "unicode", "RegexSet", "new"]);
+static REGEX_BYTES_NEW: PathLookup = PathLookup::new(&["regex", "re_bytes", "Regex", "new"]);
+static REGEX_BYTES_BUILDER_NEW: PathLookup = PathLookup::new(&["regex", "re_builder", "bytes", "RegexBuilder", "new"]);
+static REGEX_BYTES_SET_NEW: PathLookup = PathLookup::new(&["regex", "re_set", "bytes", "RegexSet", "new"]);
+
 impl<'tcx> LateLintPass<'tcx> for Regex {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if_chain! {
@@ -64,15 +71,13 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
             if let ExprKind::Path(ref qpath) = fun.kind;
             if let Some(def_id) = cx.qpath_res(qpath, fun.hir_id).opt_def_id();
             then {
-                if match_def_path(cx, def_id, &paths::REGEX_NEW) ||
-                   match_def_path(cx, def_id, &paths::REGEX_BUILDER_NEW) {
+                if REGEX_NEW.matches(cx, def_id) || REGEX_BUILDER_NEW.matches(cx, def_id) {
                     check_regex(cx, arg, true);
-                } else if match_def_path(cx, def_id, &paths::REGEX_BYTES_NEW) ||
-                   match_def_path(cx, def_id, &paths::REGEX_BYTES_BUILDER_NEW) {
+                } else if REGEX_BYTES_NEW.matches(cx, def_id) || REGEX_BYTES_BUILDER_NEW.matches(cx, def_id) {
                     check_regex(cx, arg, false);
-                } else if match_def_path(cx, def_id, &paths::REGEX_SET_NEW) {
+                } else if REGEX_SET_NEW.matches(cx, def_id) {
                     check_set(cx, arg, true);
-                } else if match_def_path(cx, def_id, &paths::REGEX_BYTES_SET_NEW) {
+                } else if REGEX_BYTES_SET_NEW.matches(cx, def_id) {
                     check_set(cx, arg, false);
                 }
             }
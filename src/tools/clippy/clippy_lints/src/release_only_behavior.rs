@@ -0,0 +1,165 @@
+use clippy_utils::cfg::CfgPredicate;
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::macros::root_macro_call_first_node;
+use clippy_utils::source::snippet_opt;
+use clippy_utils::{def_path_def_ids, higher, is_direct_expn_of};
+use rustc_ast::{ast, NestedMetaItem};
+use rustc_errors::DiagnosticBuilder;
+use rustc_hir::def_id::DefIdSet;
+use rustc_hir::{Expr, ExprKind, StmtKind, UnOp};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_parse::parser::Parser;
+use rustc_parse::{maybe_new_parser_from_source_str, parse_in};
+use rustc_session::parse::ParseSess;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, FileName};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `if cfg!(debug_assertions) { .. } else { .. }` where both branches do more
+    /// than logging.
+    ///
+    /// Calls to `print!`, `println!`, `eprint!`, `eprintln!`, `dbg!` and the macros listed in
+    /// the `logging-macros` configuration are considered logging.
+    ///
+    /// ### Why is this bad?
+    /// The program behaves differently in debug and release builds, so a bug in the release
+    /// branch is not caught by running the tests or the program in debug mode.
+    ///
+    /// ### Known problems
+    /// Branches which do the same thing in a different way, e.g. a checked and an unchecked
+    /// version of an algorithm, are linted as well.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # fn validate_all() {}
+    /// # fn validate_sample() {}
+    /// if cfg!(debug_assertions) {
+    ///     validate_all();
+    /// } else {
+    ///     validate_sample();
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # fn validate_all() {}
+    /// validate_all();
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub RELEASE_ONLY_BEHAVIOR_UNDER_CFG_DEBUG_ASSERTIONS,
+    pedantic,
+    "`if cfg!(debug_assertions)` with diverging behavior in both branches"
+}
+
+pub struct ReleaseOnlyBehavior {
+    conf_logging_macros: Vec<String>,
+    logging_macros: DefIdSet,
+}
+
+impl ReleaseOnlyBehavior {
+    pub fn new(conf_logging_macros: Vec<String>) -> Self {
+        Self {
+            conf_logging_macros,
+            logging_macros: DefIdSet::default(),
+        }
+    }
+
+    fn is_logging_macro(&self, cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+        let Some(macro_call) = root_macro_call_first_node(cx, e) else {
+            return false;
+        };
+        self.logging_macros.contains(&macro_call.def_id)
+            || matches!(
+                cx.tcx.get_diagnostic_name(macro_call.def_id),
+                Some(
+                    sym::print_macro
+                        | sym::println_macro
+                        | sym::eprint_macro
+                        | sym::eprintln_macro
+                        | sym::dbg_macro
+                )
+            )
+    }
+
+    /// Checks if the branch `e` contains anything other than logging.
+    fn has_behavior(&self, cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+        let ExprKind::Block(block, _) = e.kind else {
+            // An `else if`
+            return true;
+        };
+        block.stmts.iter().any(|stmt| match stmt.kind {
+            StmtKind::Expr(e) | StmtKind::Semi(e) => !self.is_logging_macro(cx, e),
+            StmtKind::Local(_) => true,
+            StmtKind::Item(_) => false,
+        }) || block.expr.map_or(false, |e| !self.is_logging_macro(cx, e))
+    }
+}
+
+impl_lint_pass!(ReleaseOnlyBehavior => [RELEASE_ONLY_BEHAVIOR_UNDER_CFG_DEBUG_ASSERTIONS]);
+
+impl<'tcx> LateLintPass<'tcx> for ReleaseOnlyBehavior {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for path in &self.conf_logging_macros {
+            let segs: Vec<_> = path.split("::").collect();
+            self.logging_macros.extend(def_path_def_ids(cx, &segs));
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let Some(higher::If {
+            cond,
+            then,
+            r#else: Some(els),
+        }) = higher::If::hir(expr)
+            && !in_external_macro(cx.sess(), expr.span)
+            && is_cfg_debug_assertions(cx, cond)
+            && self.has_behavior(cx, then)
+            && self.has_behavior(cx, els)
+        {
+            span_lint_and_help(
+                cx,
+                RELEASE_ONLY_BEHAVIOR_UNDER_CFG_DEBUG_ASSERTIONS,
+                expr.span,
+                "debug and release builds behave differently here",
+                Some(cond.span),
+                "bugs in the release branch won't show up in debug builds; consider running the same code in both",
+            );
+        }
+    }
+}
+
+/// Checks if `e` is `cfg!(debug_assertions)` or one of its negations, `!cfg!(debug_assertions)`
+/// and `cfg!(not(debug_assertions))`.
+fn is_cfg_debug_assertions(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let e = match e.kind {
+        ExprKind::Unary(UnOp::Not, inner) => inner,
+        _ => e,
+    };
+    if let Some(call_span) = is_direct_expn_of(e.span, "cfg")
+        && let Some(snippet) = snippet_opt(cx, call_span)
+        && let Some(meta) = parse_cfg_arg(&cx.sess().parse_sess, snippet)
+    {
+        let predicate = match CfgPredicate::parse(&meta) {
+            CfgPredicate::Not(inner) => *inner,
+            predicate => predicate,
+        };
+        matches!(predicate, CfgPredicate::Cfg { name, value: None, .. } if name == sym::debug_assertions)
+    } else {
+        false
+    }
+}
+
+/// Parses the predicate of the `cfg!` call `call`, e.g. `debug_assertions` in
+/// `cfg!(debug_assertions)`.
+fn parse_cfg_arg(sess: &ParseSess, call: String) -> Option<NestedMetaItem> {
+    let mut parser = maybe_new_parser_from_source_str(sess, FileName::anon_source_code(&call), call).ok()?;
+    let expr = parser.parse_expr().map_err(DiagnosticBuilder::cancel).ok()?;
+    let ast::ExprKind::MacCall(mac) = &expr.kind else {
+        return None;
+    };
+    parse_in(sess, mac.args.tokens.clone(), "cfg", Parser::parse_meta_item)
+        .map_err(DiagnosticBuilder::cancel)
+        .ok()
+        .map(NestedMetaItem::MetaItem)
+}
@@ -1,8 +1,12 @@
-use clippy_utils::diagnostics::span_lint;
-use clippy_utils::{get_trait_def_id, paths};
-use rustc_hir::{Impl, Item, ItemKind};
+use clippy_utils::diagnostics::{span_lint, span_lint_hir_and_then};
+use clippy_utils::paths::{self, PathLookup};
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_ast::{Attribute, NestedMetaItem};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{HirId, Impl, ImplItemRef, Item, ItemKind, TraitRef, VariantData};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Symbol};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -21,39 +25,253 @@
     "various things that will negatively affect your serde experience"
 }
 
-declare_lint_pass!(SerdeApi => [SERDE_API_MISUSE]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for combinations of `#[serde(..)]` attributes which don't work as intended:
+    /// - `skip_serializing` or `skip_serializing_if` on a field without `default`, in a type
+    ///   which also implements `Deserialize`,
+    /// - `flatten` on a field of a struct with `deny_unknown_fields`,
+    /// - `rename` on a field or variant, with a name which doesn't follow the style of the
+    ///   `rename_all` of its type.
+    ///
+    /// Each of these checks can be disabled with the `serde-attribute-checks` configuration.
+    ///
+    /// ### Why is this bad?
+    /// A field which isn't serialized is missing when deserializing the output again, which fails
+    /// unless the field has a default value. `deny_unknown_fields` isn't supported in combination
+    /// with `flatten`. A name which doesn't follow the style of the other names is likely a
+    /// mistake.
+    ///
+    /// ### Known problems
+    /// The `Option` fields serde treats as optional anyway aren't linted.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Config {
+    ///     name: String,
+    ///     #[serde(skip_serializing_if = "Vec::is_empty")]
+    ///     plugins: Vec<String>,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Config {
+    ///     name: String,
+    ///     #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    ///     plugins: Vec<String>,
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub SERDE_ATTRIBUTE_MISUSE,
+    suspicious,
+    "combinations of `#[serde(..)]` attributes which don't work as intended"
+}
+
+pub struct SerdeApi {
+    attribute_checks: Vec<String>,
+}
+
+impl SerdeApi {
+    pub fn new(attribute_checks: Vec<String>) -> Self {
+        Self { attribute_checks }
+    }
+
+    fn enabled(&self, check: &str) -> bool {
+        self.attribute_checks.iter().any(|c| c == check)
+    }
+
+    fn check_struct(&self, cx: &LateContext<'_>, item: &Item<'_>, data: &VariantData<'_>) {
+        let container = serde_meta_items(cx, item.hir_id());
+        let find = |name: &str| container.iter().find(|meta| meta.name_or_empty().as_str() == name);
+        let deny_unknown_fields = find("deny_unknown_fields");
+        let rename_all = find("rename_all");
+        // the fields are required when deserializing, unless the container has a `default`
+        let fields_required = find("default").is_none() && implements_deserialize(cx, item.owner_id.to_def_id());
+
+        for field in data.fields() {
+            let metas = serde_meta_items(cx, field.hir_id);
+            let field_has = |name: &str| metas.iter().find(|meta| meta.name_or_empty().as_str() == name);
+
+            if fields_required
+                && self.enabled("skip-serializing-without-default")
+                && field_has("default").is_none()
+                && let Some(skip) = field_has("skip_serializing").or_else(|| field_has("skip_serializing_if"))
+                && !is_type_diagnostic_item(cx, cx.tcx.type_of(field.def_id).subst_identity(), sym::Option)
+            {
+                span_lint_hir_and_then(
+                    cx,
+                    SERDE_ATTRIBUTE_MISUSE,
+                    field.hir_id,
+                    skip.span(),
+                    "this field is skipped when serializing, but is required when deserializing",
+                    |diag| {
+                        diag.help("add `default` to the attribute, so the serialized value can be deserialized again");
+                    },
+                );
+            }
+
+            if self.enabled("deny-unknown-fields-with-flatten")
+                && let Some(deny_unknown_fields) = deny_unknown_fields
+                && let Some(flatten) = field_has("flatten")
+            {
+                span_lint_hir_and_then(
+                    cx,
+                    SERDE_ATTRIBUTE_MISUSE,
+                    field.hir_id,
+                    flatten.span(),
+                    "`flatten` is not supported in combination with `deny_unknown_fields`",
+                    |diag| {
+                        diag.span_note(deny_unknown_fields.span(), "`deny_unknown_fields` is set here");
+                    },
+                );
+            }
+
+            if let Some(rename_all) = rename_all {
+                self.check_rename(cx, field.hir_id, rename_all);
+            }
+        }
+    }
+
+    /// Checks that the `rename` of the field or variant `hir_id` follows the style of the
+    /// `rename_all` of its type
+    fn check_rename(&self, cx: &LateContext<'_>, hir_id: HirId, rename_all: &NestedMetaItem) {
+        if !self.enabled("rename-against-rename-all") {
+            return;
+        }
+        let Some(style) = rename_all.value_str() else {
+            return;
+        };
+        for rename in serde_meta_items(cx, hir_id)
+            .iter()
+            .filter(|meta| meta.name_or_empty().as_str() == "rename")
+        {
+            // either `rename = "name"`, or `rename(serialize = "name", deserialize = "name")`
+            let names: Vec<Symbol> = rename.value_str().map_or_else(
+                || {
+                    rename
+                        .meta_item_list()
+                        .unwrap_or_default()
+                        .iter()
+                        .filter_map(NestedMetaItem::value_str)
+                        .collect()
+                },
+                |name| vec![name],
+            );
+            if names
+                .iter()
+                .any(|name| follows_style(name.as_str(), style.as_str()) == Some(false))
+            {
+                span_lint_hir_and_then(
+                    cx,
+                    SERDE_ATTRIBUTE_MISUSE,
+                    hir_id,
+                    rename.span(),
+                    &format!("this `rename` doesn't follow the `{style}` style of `rename_all`"),
+                    |diag| {
+                        diag.span_note(rename_all.span(), "`rename_all` is set here");
+                    },
+                );
+            }
+        }
+    }
+}
+
+impl_lint_pass!(SerdeApi => [SERDE_API_MISUSE, SERDE_ATTRIBUTE_MISUSE]);
+
+static SERDE_DE_VISITOR: PathLookup = PathLookup::new(&["serde", "de", "Visitor"]);
 
 impl<'tcx> LateLintPass<'tcx> for SerdeApi {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
-        if let ItemKind::Impl(Impl {
-            of_trait: Some(ref trait_ref),
-            items,
-            ..
-        }) = item.kind
-        {
-            let did = trait_ref.path.res.def_id();
-            if let Some(visit_did) = get_trait_def_id(cx, &paths::SERDE_DE_VISITOR) {
-                if did == visit_did {
-                    let mut seen_str = None;
-                    let mut seen_string = None;
-                    for item in *items {
-                        match item.ident.as_str() {
-                            "visit_str" => seen_str = Some(item.span),
-                            "visit_string" => seen_string = Some(item.span),
-                            _ => {},
-                        }
-                    }
-                    if let Some(span) = seen_string {
-                        if seen_str.is_none() {
-                            span_lint(
-                                cx,
-                                SERDE_API_MISUSE,
-                                span,
-                                "you should not implement `visit_string` without also implementing `visit_str`",
-                            );
-                        }
+        match item.kind {
+            ItemKind::Struct(ref data, _) if !item.span.from_expansion() => self.check_struct(cx, item, data),
+            ItemKind::Enum(ref def, _) if !item.span.from_expansion() => {
+                if let Some(rename_all) = serde_meta_items(cx, item.hir_id())
+                    .into_iter()
+                    .find(|meta| meta.name_or_empty().as_str() == "rename_all")
+                {
+                    for variant in def.variants {
+                        self.check_rename(cx, variant.hir_id, &rename_all);
                     }
                 }
+            },
+            ItemKind::Impl(Impl {
+                of_trait: Some(ref trait_ref),
+                items,
+                ..
+            }) => check_visitor_impl(cx, trait_ref, items),
+            _ => {},
+        }
+    }
+}
+
+/// The items of the `#[serde(..)]` attributes of `hir_id`
+fn serde_meta_items(cx: &LateContext<'_>, hir_id: HirId) -> Vec<NestedMetaItem> {
+    cx.tcx
+        .hir()
+        .attrs(hir_id)
+        .iter()
+        .filter(|attr| attr.ident().map_or(false, |ident| ident.as_str() == "serde"))
+        .filter_map(Attribute::meta_item_list)
+        .flatten()
+        .collect()
+}
+
+fn implements_deserialize(cx: &LateContext<'_>, did: DefId) -> bool {
+    paths::SERDE_DESERIALIZE.first(cx).map_or(false, |deserialize| {
+        cx.tcx.all_impls(deserialize).any(|impl_did| {
+            cx.tcx
+                .type_of(impl_did)
+                .subst_identity()
+                .ty_adt_def()
+                .map_or(false, |adt| adt.did() == did)
+        })
+    })
+}
+
+/// Checks if `name` follows the `rename_all` style `style`, returns `None` for unknown styles
+fn follows_style(name: &str, style: &str) -> Option<bool> {
+    let has_upper = name.chars().any(char::is_uppercase);
+    let has_lower = name.chars().any(char::is_lowercase);
+    let starts_upper = name.chars().next().map_or(false, char::is_uppercase);
+    let has_underscore = name.contains('_');
+    let has_dash = name.contains('-');
+    Some(match style {
+        "lowercase" => !has_upper,
+        "UPPERCASE" => !has_lower,
+        "PascalCase" => starts_upper && !has_underscore && !has_dash,
+        "camelCase" => !starts_upper && !has_underscore && !has_dash,
+        "snake_case" => !has_upper && !has_dash,
+        "SCREAMING_SNAKE_CASE" => !has_lower && !has_dash,
+        "kebab-case" => !has_upper && !has_underscore,
+        "SCREAMING-KEBAB-CASE" => !has_lower && !has_underscore,
+        _ => return None,
+    })
+}
+
+fn check_visitor_impl(cx: &LateContext<'_>, trait_ref: &TraitRef<'_>, items: &[ImplItemRef]) {
+    let did = trait_ref.path.res.def_id();
+    if let Some(visit_did) = SERDE_DE_VISITOR.first(cx) {
+        if did == visit_did {
+            let mut seen_str = None;
+            let mut seen_string = None;
+            for item in items {
+                match item.ident.as_str() {
+                    "visit_str" => seen_str = Some(item.span),
+                    "visit_string" => seen_string = Some(item.span),
+                    _ => {},
+                }
+            }
+            if let Some(span) = seen_string {
+                if seen_str.is_none() {
+                    span_lint(
+                        cx,
+                        SERDE_API_MISUSE,
+                        span,
+                        "you should not implement `visit_string` without also implementing `visit_str`",
+                    );
+                }
             }
         }
     }
@@ -0,0 +1,203 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::ty::is_type_diagnostic_item;
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{def_path_def_ids, fn_def_id};
+use core::ops::ControlFlow;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefIdMap;
+use rustc_hir::{Expr, ExprKind, LoopSource, Node, QPath};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to sleep functions inside loops:
+    /// - sleeping for a constant duration in a `loop` or `while` loop which also calls a function
+    ///   returning a `Result`, i.e. a retry loop,
+    /// - sleeping for no time at all in any loop.
+    ///
+    /// The sleep functions are listed in the `sleep-functions` configuration.
+    ///
+    /// ### Why is this bad?
+    /// Retrying at a constant rate forever keeps hammering a service which is already failing,
+    /// and never gives up if it doesn't recover. Increasing the delay after every failed attempt
+    /// (exponential backoff), and limiting the number of attempts avoids both.
+    ///
+    /// Sleeping for a duration of zero doesn't wait for anything, so the loop keeps the thread
+    /// busy.
+    ///
+    /// ### Known problems
+    /// Any call returning a `Result` is taken as a sign of a retry loop, even if failing doesn't
+    /// cause another iteration. `for` loops are assumed to bound the number of attempts.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// loop {
+    ///     match connect(addr) {
+    ///         Ok(conn) => break conn,
+    ///         Err(_) => thread::sleep(Duration::from_secs(1)),
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let mut delay = Duration::from_millis(100);
+    /// for _ in 0..MAX_ATTEMPTS {
+    ///     match connect(addr) {
+    ///         Ok(conn) => return Ok(conn),
+    ///         Err(_) => {
+    ///             thread::sleep(delay);
+    ///             delay *= 2;
+    ///         },
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub SLEEP_IN_LOOP_WITHOUT_BACKOFF,
+    pedantic,
+    "sleeping for a constant or zero duration in a retry loop"
+}
+
+pub struct SleepInLoopWithoutBackoff {
+    conf_sleep_functions: Vec<String>,
+    sleep_functions: DefIdMap<usize>,
+}
+
+impl SleepInLoopWithoutBackoff {
+    pub fn new(conf_sleep_functions: Vec<String>) -> Self {
+        Self {
+            conf_sleep_functions,
+            sleep_functions: DefIdMap::default(),
+        }
+    }
+}
+
+impl_lint_pass!(SleepInLoopWithoutBackoff => [SLEEP_IN_LOOP_WITHOUT_BACKOFF]);
+
+impl<'tcx> LateLintPass<'tcx> for SleepInLoopWithoutBackoff {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for (index, path) in self.conf_sleep_functions.iter().enumerate() {
+            let segs: Vec<_> = path.split("::").collect();
+            for id in def_path_def_ids(cx, &segs) {
+                self.sleep_functions.insert(id, index);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let ExprKind::Call(_, [duration]) = expr.kind else {
+            return;
+        };
+        if self.sleep_functions.is_empty() || in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        let Some(&index) = fn_def_id(cx, expr).and_then(|id| self.sleep_functions.get(&id)) else {
+            return;
+        };
+        let Some(is_zero) = constant_duration(cx, duration) else {
+            return;
+        };
+        let Some((loop_expr, source)) = enclosing_loop(cx, expr) else {
+            return;
+        };
+        let name = &self.conf_sleep_functions[index];
+
+        if is_zero {
+            span_lint_and_then(
+                cx,
+                SLEEP_IN_LOOP_WITHOUT_BACKOFF,
+                expr.span,
+                &format!("calling `{name}` with a duration of zero in a loop"),
+                |diag| {
+                    diag.note("this doesn't wait for anything, so the loop keeps the thread busy");
+                    diag.help("consider blocking until there's something to do, or sleeping for longer");
+                },
+            );
+        } else if matches!(source, LoopSource::Loop | LoopSource::While)
+            && let Some(fallible) = fallible_call(cx, loop_expr, expr)
+        {
+            span_lint_and_then(
+                cx,
+                SLEEP_IN_LOOP_WITHOUT_BACKOFF,
+                expr.span,
+                &format!("calling `{name}` with a constant duration in a retry loop"),
+                |diag| {
+                    diag.span_note(fallible, "the loop retries this fallible call");
+                    diag.help(
+                        "consider increasing the duration after every attempt, and limiting the number of attempts",
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Finds the innermost loop containing `expr`, stopping at closures and items.
+fn enclosing_loop<'tcx>(cx: &LateContext<'tcx>, expr: &Expr<'_>) -> Option<(&'tcx Expr<'tcx>, LoopSource)> {
+    for (_, node) in cx.tcx.hir().parent_iter(expr.hir_id) {
+        match node {
+            Node::Expr(e) => match e.kind {
+                ExprKind::Loop(_, _, source, _) => return Some((e, source)),
+                ExprKind::Closure(..) => return None,
+                _ => {},
+            },
+            Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) | Node::AnonConst(_) => return None,
+            _ => {},
+        }
+    }
+    None
+}
+
+/// If `e` is a constant `Duration`, returns whether it's zero.
+fn constant_duration(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<bool> {
+    let typeck = cx.typeck_results();
+    if !is_type_diagnostic_item(cx, typeck.expr_ty(e), sym::Duration) {
+        return None;
+    }
+    match e.kind {
+        ExprKind::Call(func, args) => {
+            let ExprKind::Path(QPath::TypeRelative(_, segment)) = func.kind else {
+                return None;
+            };
+            if !matches!(
+                segment.ident.name.as_str(),
+                "new" | "from_secs" | "from_millis" | "from_micros" | "from_nanos" | "from_secs_f32" | "from_secs_f64"
+            ) {
+                return None;
+            }
+            let mut is_zero = true;
+            for arg in args {
+                is_zero &= match constant(cx, typeck, arg)?.0 {
+                    Constant::Int(n) => n == 0,
+                    Constant::F32(n) => n == 0.0,
+                    Constant::F64(n) => n == 0.0,
+                    _ => return None,
+                };
+            }
+            Some(is_zero)
+        },
+        ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, e.hir_id) {
+            Res::Def(DefKind::Const | DefKind::AssocConst, def_id) => Some(cx.tcx.item_name(def_id).as_str() == "ZERO"),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the span of a call in `loop_expr`, other than `sleep`, which returns a `Result`.
+fn fallible_call<'tcx>(cx: &LateContext<'tcx>, loop_expr: &'tcx Expr<'tcx>, sleep: &Expr<'_>) -> Option<Span> {
+    for_each_expr(loop_expr, |e| {
+        if matches!(e.kind, ExprKind::Call(..) | ExprKind::MethodCall(..))
+            && e.hir_id != sleep.hir_id
+            && !e.span.from_expansion()
+            && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(e), sym::Result)
+        {
+            ControlFlow::Break(e.span)
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+}
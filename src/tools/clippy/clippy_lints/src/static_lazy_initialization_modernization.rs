@@ -0,0 +1,341 @@
+use clippy_utils::def_path_def_ids;
+use clippy_utils::diagnostics::{span_lint_and_then, span_lint_hir_and_then};
+use clippy_utils::macros::root_macro_call;
+use clippy_utils::msrvs::{self, Msrv};
+use clippy_utils::source::{indent_of, snippet_opt, walk_span_to_context};
+use rustc_ast::{AttrArgs, AttrArgsEq, AttrKind};
+use rustc_data_structures::fx::{FxHashSet, FxIndexMap};
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind, FnRetTy, HirId, Item, ItemKind, Node, QPath, TyKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{Span, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for statics initialized with `lazy_static!` or `once_cell::sync::Lazy` which can use
+    /// `std::sync::LazyLock` (or `std::sync::OnceLock`, depending on the MSRV) instead.
+    ///
+    /// The paths of the checked macros and types can be configured with
+    /// `lazy-initialization-paths`. Configured macros must expand like `lazy_static!`.
+    ///
+    /// ### Why is this bad?
+    /// The standard library types do the same without an additional dependency.
+    ///
+    /// The lint is only emitted if the suggested type is stable, or if the crate enables its
+    /// feature (`once_cell` or `lazy_cell`).
+    ///
+    /// ### Known problems
+    /// Calls to `lazy_static::initialize` need to be replaced with `LazyLock::force`.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// use once_cell::sync::Lazy;
+    ///
+    /// static NAMES: Lazy<Vec<String>> = Lazy::new(|| vec!["a".to_string()]);
+    ///
+    /// lazy_static::lazy_static! {
+    ///     static ref NUMBERS: Vec<u32> = vec![1, 2, 3];
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// static NAMES: std::sync::LazyLock<Vec<String>> = std::sync::LazyLock::new(|| vec!["a".to_string()]);
+    ///
+    /// static NUMBERS: std::sync::LazyLock<Vec<u32>> = std::sync::LazyLock::new(|| vec![1, 2, 3]);
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub STATIC_LAZY_INITIALIZATION_MODERNIZATION,
+    pedantic,
+    "lazily initialized statics which can use `std::sync::LazyLock` or `std::sync::OnceLock`"
+}
+
+/// The statics of a `lazy_static!` call, rewritten to use `LazyLock`.
+struct LazyStaticCall {
+    /// The module containing the call.
+    hir_id: HirId,
+    macro_def_id: DefId,
+    meets_lazy_lock_msrv: bool,
+    statics: Option<Vec<String>>,
+}
+
+pub struct StaticLazyInitializationModernization {
+    msrv: Msrv,
+    conf_paths: Vec<String>,
+    def_ids: FxHashSet<DefId>,
+    lazy_static_calls: FxIndexMap<Span, LazyStaticCall>,
+    /// Whether `LazyLock` is stable or its feature is enabled
+    lazy_lock_usable: bool,
+    /// Whether `OnceLock` is stable or its feature is enabled
+    once_lock_usable: bool,
+}
+
+impl StaticLazyInitializationModernization {
+    pub fn new(msrv: Msrv, conf_paths: Vec<String>) -> Self {
+        Self {
+            msrv,
+            conf_paths,
+            def_ids: FxHashSet::default(),
+            lazy_static_calls: FxIndexMap::default(),
+            lazy_lock_usable: false,
+            once_lock_usable: false,
+        }
+    }
+
+    fn lazy_lock_available(&self) -> bool {
+        self.lazy_lock_usable && self.msrv.meets(msrvs::LAZY_LOCK)
+    }
+
+    fn once_lock_available(&self) -> bool {
+        self.once_lock_usable && self.msrv.meets(msrvs::ONCE_LOCK)
+    }
+
+    fn check_lazy_static(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
+        if let Some(macro_call) = root_macro_call(item.span)
+            && self.def_ids.contains(&macro_call.def_id)
+            && let ItemKind::Fn(ref sig, _, body_id) = item.kind
+            && item.ident.as_str() == "__static_ref_initialize"
+        {
+            let meets_lazy_lock_msrv = self.lazy_lock_available();
+            let call = self
+                .lazy_static_calls
+                .entry(macro_call.span)
+                .or_insert_with(|| LazyStaticCall {
+                    hir_id: cx.tcx.hir().local_def_id_to_hir_id(cx.tcx.parent_module(item.hir_id())),
+                    macro_def_id: macro_call.def_id,
+                    meets_lazy_lock_msrv,
+                    statics: Some(Vec::new()),
+                });
+            let rewritten = if let FnRetTy::Return(ret_ty) = sig.decl.output
+                && let ExprKind::Block(block, _) = cx.tcx.hir().body(body_id).value.kind
+                && let Some(init) = block.expr
+            {
+                let indent = " ".repeat(indent_of(cx, macro_call.span).unwrap_or(0));
+                rewrite_lazy_static(cx, item.hir_id(), ret_ty.span, init, &indent)
+            } else {
+                None
+            };
+            match (&mut call.statics, rewritten) {
+                (Some(statics), Some(rewritten)) => statics.push(rewritten),
+                (statics, _) => *statics = None,
+            }
+        }
+    }
+
+    fn check_lazy_type(&self, cx: &LateContext<'_>, item: &Item<'_>) {
+        if let ItemKind::Static(hir_ty, _, body_id) = item.kind
+            && !item.span.from_expansion()
+            && let ty::Adt(adt, _) = cx.tcx.type_of(item.owner_id).subst_identity().kind()
+            && self.def_ids.contains(&adt.did())
+        {
+            let path = cx.tcx.def_path_str(adt.did());
+            if self.lazy_lock_available() {
+                let init = cx.tcx.hir().body(body_id).value;
+                span_lint_and_then(
+                    cx,
+                    STATIC_LAZY_INITIALIZATION_MODERNIZATION,
+                    item.span,
+                    &format!("`{path}` can be replaced by `std::sync::LazyLock`"),
+                    |diag| {
+                        if let Some(suggestion) = lazy_lock_suggestion(cx, hir_ty, init) {
+                            diag.multipart_suggestion(
+                                "use `std::sync::LazyLock`",
+                                suggestion,
+                                Applicability::MaybeIncorrect,
+                            );
+                        } else {
+                            diag.help("use `std::sync::LazyLock`");
+                        }
+                    },
+                );
+            } else if self.once_lock_available() {
+                span_lint_and_then(
+                    cx,
+                    STATIC_LAZY_INITIALIZATION_MODERNIZATION,
+                    item.span,
+                    &format!("`{path}` can be replaced by `std::sync::OnceLock`"),
+                    |diag| {
+                        diag.help(
+                            "use `std::sync::OnceLock` and call `get_or_init` with the initializer where it is used",
+                        );
+                    },
+                );
+            }
+        }
+    }
+}
+
+impl_lint_pass!(StaticLazyInitializationModernization => [STATIC_LAZY_INITIALIZATION_MODERNIZATION]);
+
+impl<'tcx> LateLintPass<'tcx> for StaticLazyInitializationModernization {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        for path in &self.conf_paths {
+            let segments: Vec<_> = path.split("::").collect();
+            self.def_ids.extend(def_path_def_ids(cx, &segments));
+        }
+        self.lazy_lock_usable = is_usable(cx, &["std", "sync", "LazyLock"], &["once_cell", "lazy_cell"]);
+        self.once_lock_usable = is_usable(cx, &["std", "sync", "OnceLock"], &["once_cell"]);
+    }
+
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        if !self.once_lock_available() {
+            return;
+        }
+        self.check_lazy_static(cx, item);
+        self.check_lazy_type(cx, item);
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (span, call) in self.lazy_static_calls.drain(..) {
+            let name = cx.tcx.item_name(call.macro_def_id);
+            let (msg, help) = if call.meets_lazy_lock_msrv {
+                (
+                    format!("`{name}!` can be replaced by `std::sync::LazyLock`"),
+                    "use `std::sync::LazyLock` statics",
+                )
+            } else {
+                (
+                    format!("`{name}!` can be replaced by `std::sync::OnceLock`"),
+                    "use `std::sync::OnceLock` statics and call `get_or_init` with the initializer where they are used",
+                )
+            };
+            span_lint_hir_and_then(
+                cx,
+                STATIC_LAZY_INITIALIZATION_MODERNIZATION,
+                call.hir_id,
+                span,
+                &msg,
+                |diag| {
+                    if call.meets_lazy_lock_msrv
+                        && let Some(statics) = call.statics
+                    {
+                        let indent = " ".repeat(indent_of(cx, span).unwrap_or(0));
+                        diag.span_suggestion(
+                            span,
+                            help,
+                            statics.join(&format!("\n\n{indent}")),
+                            Applicability::MaybeIncorrect,
+                        );
+                    } else {
+                        diag.help(help);
+                    }
+                },
+            );
+        }
+    }
+
+    extract_msrv_attr!(LateContext);
+}
+
+/// Checks if the standard library item at `path` is stable, or one of the `features` it's
+/// available with is enabled.
+fn is_usable(cx: &LateContext<'_>, path: &[&str], features: &[&str]) -> bool {
+    let features_enabled = cx.tcx.features();
+    features
+        .iter()
+        .any(|&feature| features_enabled.active(Symbol::intern(feature)))
+        || def_path_def_ids(cx, path).any(|def_id| {
+            cx.tcx
+                .lookup_stability(def_id)
+                .map_or(false, |stability| stability.is_stable())
+        })
+}
+
+/// Rewrites the static of `lazy_static!` with the initializer function `init_fn`, returning the
+/// static as `LazyLock` with its attributes, indented by `indent`.
+fn rewrite_lazy_static(
+    cx: &LateContext<'_>,
+    init_fn: HirId,
+    ty_span: Span,
+    init: &Expr<'_>,
+    indent: &str,
+) -> Option<String> {
+    // The initializer function is nested in the `Deref` impl for the struct named like the static
+    let imp = cx.tcx.hir().parent_iter(init_fn).find_map(|(_, node)| match node {
+        Node::Item(Item {
+            kind: ItemKind::Impl(imp),
+            ..
+        }) => Some(imp),
+        _ => None,
+    })?;
+    let TyKind::Path(QPath::Resolved(None, path)) = imp.self_ty.kind else {
+        return None;
+    };
+    let Res::Def(DefKind::Struct, struct_id) = path.res else {
+        return None;
+    };
+    let Some(Node::Item(struct_item)) = cx.tcx.hir().find_by_def_id(struct_id.as_local()?) else {
+        return None;
+    };
+
+    let mut rewritten = String::new();
+    for attr in cx.tcx.hir().attrs(struct_item.hir_id()) {
+        // The attributes of the static are passed to the struct, along with the ones of the macro
+        let AttrKind::Normal(normal) = &attr.kind else {
+            continue;
+        };
+        // Attributes passed as `meta` fragments, like doc comments, only have the lowered literal
+        let span = match &normal.item.args {
+            AttrArgs::Eq(_, AttrArgsEq::Hir(lit)) => normal.item.path.span.to(lit.span),
+            _ => normal.item.span(),
+        };
+        if span.from_expansion() {
+            continue;
+        }
+        // Doc comments keep their span when passed to a macro, other attributes only the span of
+        // their contents
+        let snippet = snippet_opt(cx, span)?;
+        if snippet.starts_with("//") || snippet.starts_with("/*") {
+            rewritten.push_str(&snippet);
+        } else {
+            rewritten.push_str(&format!("#[{snippet}]"));
+        }
+        rewritten.push('\n');
+        rewritten.push_str(indent);
+    }
+    if !struct_item.vis_span.is_empty() {
+        rewritten.push_str(&snippet_opt(cx, struct_item.vis_span)?);
+        rewritten.push(' ');
+    }
+    rewritten.push_str(&format!(
+        "static {}: std::sync::LazyLock<{}> = std::sync::LazyLock::new(|| {});",
+        struct_item.ident,
+        snippet_opt(cx, ty_span)?,
+        // The initializer may itself be a macro call, like `vec![..]`
+        snippet_opt(cx, walk_span_to_context(init.span, ty_span.ctxt())?)?,
+    ));
+    Some(rewritten)
+}
+
+/// Replaces the type of a static with `LazyLock`, and the `new` call of its initializer with
+/// `LazyLock::new`.
+fn lazy_lock_suggestion(
+    cx: &LateContext<'_>,
+    hir_ty: &rustc_hir::Ty<'_>,
+    init: &Expr<'_>,
+) -> Option<Vec<(Span, String)>> {
+    let TyKind::Path(QPath::Resolved(None, path)) = hir_ty.kind else {
+        return None;
+    };
+    let args = match path.segments.last()?.args {
+        Some(args) if !args.args.is_empty() => snippet_opt(cx, args.span_ext)?,
+        _ => String::new(),
+    };
+    let ExprKind::Call(callee, [_]) = init.kind else {
+        return None;
+    };
+    let name = match callee.kind {
+        ExprKind::Path(QPath::Resolved(_, path)) => path.segments.last()?.ident,
+        ExprKind::Path(QPath::TypeRelative(_, segment)) => segment.ident,
+        _ => return None,
+    };
+    (name.as_str() == "new" && !callee.span.from_expansion()).then(|| {
+        vec![
+            (hir_ty.span, format!("std::sync::LazyLock{args}")),
+            (callee.span, "std::sync::LazyLock::new".to_string()),
+        ]
+    })
+}
@@ -1,8 +1,9 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help, span_lint_and_sugg};
+use clippy_utils::paths::PathLookup;
 use clippy_utils::source::{snippet, snippet_with_applicability};
-use clippy_utils::ty::is_type_lang_item;
+use clippy_utils::ty::{is_type_diagnostic_item, is_type_lang_item};
 use clippy_utils::{get_expr_use_or_unification_node, peel_blocks, SpanlessEq};
-use clippy_utils::{get_parent_expr, is_lint_allowed, match_function_call, method_calls, paths};
+use clippy_utils::{get_parent_expr, is_lint_allowed, method_calls};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::def_id::DefId;
@@ -144,6 +145,10 @@
 
 declare_lint_pass!(StringAdd => [STRING_ADD, STRING_ADD_ASSIGN, STRING_SLICE]);
 
+static STRING_FROM_UTF8: PathLookup = PathLookup::new(&["alloc", "string", "String", "from_utf8"]);
+static STRING_FROM_UTF8_LOSSY: PathLookup = PathLookup::new(&["alloc", "string", "String", "from_utf8_lossy"]);
+static STR_FROM_UTF8: PathLookup = PathLookup::new(&["core", "str", "converts", "from_utf8"]);
+
 impl<'tcx> LateLintPass<'tcx> for StringAdd {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
         if in_external_macro(cx.sess(), e.span) {
@@ -243,10 +248,69 @@ fn is_add(cx: &LateContext<'_>, src: &Expr<'_>, target: &Expr<'_>) -> bool {
     "casting string slices to byte slices and back"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for owned byte vectors and strings built from string or byte string literals in a
+    /// roundabout way: `Vec::from("lit".as_bytes())` and `"lit".to_string().into_bytes()` instead
+    /// of `b"lit".to_vec()`, and `String::from_utf8(b"lit".to_vec()).unwrap()` instead of
+    /// `String::from("lit")`.
+    ///
+    /// ### Why is this bad?
+    /// The conversions are harder to read than the literal they start from, and
+    /// `String::from_utf8` checks at runtime what is already known at compile time.
+    ///
+    /// ### Example
+    /// ```rust
+    /// let bytes = Vec::from("lit".as_bytes());
+    /// let string = String::from_utf8(b"lit".to_vec()).unwrap();
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// let bytes = b"lit".to_vec();
+    /// let string = String::from("lit");
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub STRING_LIT_AS_BYTES_TO_VEC,
+    nursery,
+    "converting between string and byte string literals to build an owned value"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for strings being converted to bytes only to be converted back with one of the
+    /// functions validating UTF-8: `String::from_utf8(s.into_bytes())`,
+    /// `std::str::from_utf8(s.as_bytes())` and `String::from_utf8_lossy(s.as_bytes())`.
+    ///
+    /// ### Why is this bad?
+    /// A string is always valid UTF-8, so the validation can't fail and only costs time.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # let s = String::new();
+    /// let t = String::from_utf8(s.into_bytes()).unwrap();
+    /// ```
+    ///
+    /// Use instead:
+    /// ```rust
+    /// # let s = String::new();
+    /// let t = s;
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub REDUNDANT_UTF8_ROUNDTRIP,
+    complexity,
+    "converting a string to bytes and validating them as UTF-8 again"
+}
+
 // Max length a b"foo" string can take
 const MAX_LENGTH_BYTE_STRING_LIT: usize = 32;
 
-declare_lint_pass!(StringLitAsBytes => [STRING_LIT_AS_BYTES, STRING_FROM_UTF8_AS_BYTES]);
+declare_lint_pass!(StringLitAsBytes => [
+    STRING_LIT_AS_BYTES,
+    STRING_FROM_UTF8_AS_BYTES,
+    STRING_LIT_AS_BYTES_TO_VEC,
+    REDUNDANT_UTF8_ROUNDTRIP,
+]);
 
 impl<'tcx> LateLintPass<'tcx> for StringLitAsBytes {
     #[expect(clippy::too_many_lines)]
@@ -255,7 +319,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
 
         if_chain! {
             // Find std::str::converts::from_utf8
-            if let Some(args) = match_function_call(cx, e, &paths::STR_FROM_UTF8);
+            if let Some(args) = STR_FROM_UTF8.call_args(cx, e);
 
             // Find string::as_bytes
             if let ExprKind::AddrOf(BorrowKind::Ref, _, args) = args[0].kind;
@@ -296,6 +360,11 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
             if path.ident.name == sym!(as_bytes);
             if let ExprKind::Lit(lit) = &receiver.kind;
             if let LitKind::Str(lit_content, _) = &lit.node;
+            // Linted as a whole by `string_lit_as_bytes_to_vec`
+            if !get_parent_expr(cx, e).map_or(false, |parent| {
+                vec_from_str_lit(cx, parent).is_some()
+                    && !is_lint_allowed(cx, STRING_LIT_AS_BYTES_TO_VEC, parent.hir_id)
+            });
             then {
                 let callsite = snippet(cx, receiver.span.source_callsite(), r#""foo""#);
                 let mut applicability = Applicability::MachineApplicable;
@@ -355,10 +424,15 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
             if !recv.span.from_expansion();
             then {
                 let mut applicability = Applicability::MachineApplicable;
+                let lint = if is_lint_allowed(cx, STRING_LIT_AS_BYTES_TO_VEC, e.hir_id) {
+                    STRING_LIT_AS_BYTES
+                } else {
+                    STRING_LIT_AS_BYTES_TO_VEC
+                };
 
                 span_lint_and_sugg(
                     cx,
-                    STRING_LIT_AS_BYTES,
+                    lint,
                     e.span,
                     "calling `into_bytes()` on a string literal",
                     "consider using a byte string literal instead",
@@ -370,6 +444,149 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
                 );
             }
         }
+
+        if let Some(lit) = vec_from_str_lit(cx, e) {
+            let mut applicability = Applicability::MachineApplicable;
+            span_lint_and_sugg(
+                cx,
+                STRING_LIT_AS_BYTES_TO_VEC,
+                e.span,
+                "converting the bytes of a string literal into a `Vec`",
+                "consider using a byte string literal instead",
+                format!(
+                    "b{}.to_vec()",
+                    snippet_with_applicability(cx, lit.span, r#""..""#, &mut applicability)
+                ),
+                applicability,
+            );
+        }
+
+        if_chain! {
+            if let ExprKind::MethodCall(path, recv, _, _) = &e.kind;
+            if matches!(path.ident.as_str(), "unwrap" | "expect");
+            if let Some([arg]) = STRING_FROM_UTF8.call_args(cx, recv);
+            if let ExprKind::MethodCall(path, lit_expr, [], _) = &arg.kind;
+            if path.ident.name == sym::to_vec;
+            if let ExprKind::Lit(lit) = &lit_expr.kind;
+            if let LitKind::ByteStr(bytes, _) = &lit.node;
+            // Escapes of non-ASCII bytes aren't allowed in string literals
+            if bytes.is_ascii();
+            if !e.span.from_expansion();
+            then {
+                let mut applicability = Applicability::MachineApplicable;
+                let lit = snippet_with_applicability(cx, lit_expr.span, r#"b"..""#, &mut applicability);
+                span_lint_and_sugg(
+                    cx,
+                    STRING_LIT_AS_BYTES_TO_VEC,
+                    e.span,
+                    "converting a byte string literal into a `String` at runtime",
+                    "consider using a string literal instead",
+                    format!("String::from({})", lit.strip_prefix('b').unwrap_or(&lit)),
+                    applicability,
+                );
+            }
+        }
+
+        check_utf8_roundtrip(cx, e);
+    }
+}
+
+/// Lints `String::from_utf8(s.into_bytes())`, `std::str::from_utf8(s.as_bytes())` and
+/// `String::from_utf8_lossy(s.as_bytes())`.
+fn check_utf8_roundtrip<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) {
+    let ExprKind::Call(fun, [arg]) = e.kind else {
+        return;
+    };
+    let ExprKind::MethodCall(to_bytes, s, [], _) = arg.kind else {
+        return;
+    };
+    let ExprKind::Path(ref qpath) = fun.kind else {
+        return;
+    };
+    let Some(fun_def_id) = cx.qpath_res(qpath, fun.hir_id).opt_def_id() else {
+        return;
+    };
+    if e.span.from_expansion() {
+        return;
+    }
+
+    let s_ty = cx.typeck_results().expr_ty(s);
+    let is_string = is_type_lang_item(cx, s_ty.peel_refs(), LangItem::String);
+    let is_str_ref = matches!(s_ty.kind(), ty::Ref(_, inner, _) if inner.is_str());
+    let mut applicability = Applicability::MachineApplicable;
+    let s = snippet_with_applicability(cx, s.span, "..", &mut applicability);
+    let borrowed = if is_string {
+        format!("{s}.as_str()")
+    } else {
+        s.to_string()
+    };
+    let (string, is_lossy) = match to_bytes.ident.as_str() {
+        "into_bytes" if STRING_FROM_UTF8.matches(cx, fun_def_id) && is_type_lang_item(cx, s_ty, LangItem::String) => {
+            (s.to_string(), false)
+        },
+        "as_bytes" if (is_string || is_str_ref) && STR_FROM_UTF8.matches(cx, fun_def_id) => (borrowed, false),
+        "as_bytes" if (is_string || is_str_ref) && STRING_FROM_UTF8_LOSSY.matches(cx, fun_def_id) => (borrowed, true),
+        _ => return,
+    };
+
+    let msg = "converting a string to bytes and validating them as UTF-8 again";
+    if is_lossy {
+        span_lint_and_sugg(
+            cx,
+            REDUNDANT_UTF8_ROUNDTRIP,
+            e.span,
+            msg,
+            "borrow the string instead",
+            format!("std::borrow::Cow::Borrowed({string})"),
+            applicability,
+        );
+    } else if let Some(parent) = get_parent_expr(cx, e)
+        && let ExprKind::MethodCall(path, recv, ..) = parent.kind
+        && recv.hir_id == e.hir_id
+        && matches!(path.ident.as_str(), "unwrap" | "expect")
+    {
+        span_lint_and_sugg(
+            cx,
+            REDUNDANT_UTF8_ROUNDTRIP,
+            parent.span,
+            msg,
+            "remove the conversion",
+            string,
+            applicability,
+        );
+    } else {
+        // The error type of `Ok(..)` might not be inferred
+        span_lint_and_sugg(
+            cx,
+            REDUNDANT_UTF8_ROUNDTRIP,
+            e.span,
+            msg,
+            "the conversion always succeeds",
+            format!("Ok({string})"),
+            Applicability::MaybeIncorrect,
+        );
+    }
+}
+
+/// Gets the string literal of `Vec::from("lit".as_bytes())` if it can be written as a byte string
+/// literal.
+fn vec_from_str_lit<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> Option<&'tcx Expr<'tcx>> {
+    if let ExprKind::Call(fun, [arg]) = e.kind
+        && let ExprKind::Path(ref qpath) = fun.kind
+        && let Some(fun_def_id) = cx.qpath_res(qpath, fun.hir_id).opt_def_id()
+        && cx.tcx.is_diagnostic_item(sym::from_fn, fun_def_id)
+        && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(e), sym::Vec)
+        && let ExprKind::MethodCall(path, lit_expr, [], _) = arg.kind
+        && path.ident.name == sym!(as_bytes)
+        && let ExprKind::Lit(lit) = &lit_expr.kind
+        && let rustc_ast::LitKind::Str(lit_content, _) = lit.node
+        && lit_content.as_str().is_ascii()
+        && lit_content.as_str().len() <= MAX_LENGTH_BYTE_STRING_LIT
+        && !e.span.from_expansion()
+    {
+        Some(lit_expr)
+    } else {
+        None
     }
 }
 
@@ -1,17 +1,23 @@
+use clippy_utils::consts::{constant, Constant};
 use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
-use clippy_utils::source::snippet_with_context;
+use clippy_utils::source::{snippet_opt, snippet_with_context, walk_span_to_context};
+use clippy_utils::stmt_seq::{find_stmt_seqs, BlockItem, StmtPat};
 use clippy_utils::sugg::Sugg;
 use clippy_utils::ty::is_type_diagnostic_item;
-use clippy_utils::{can_mut_borrow_both, eq_expr_value, in_constant, std_or_core};
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{
+    can_mut_borrow_both, eq_expr_value, in_constant, is_default_equivalent, is_trait_method, path_to_local, std_or_core,
+};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
-use rustc_hir::{BinOpKind, Block, Expr, ExprKind, PatKind, QPath, Stmt, StmtKind};
+use rustc_hir::{BinOpKind, Block, Expr, ExprKind, HirId, Mutability, PatKind, QPath, Stmt, StmtKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty;
 use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::source_map::Spanned;
 use rustc_span::SyntaxContext;
-use rustc_span::{sym, symbol::Ident, Span};
+use rustc_span::{sym, symbol::Ident, BytePos, Pos, Span};
+use std::cmp::Ordering;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -70,13 +76,46 @@
     "`foo = bar; bar = foo` sequence"
 }
 
-declare_lint_pass!(Swap => [MANUAL_SWAP, ALMOST_SWAPPED]);
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for a value behind a reference being cloned to keep its old value right before a
+    /// new value is assigned to it.
+    ///
+    /// Values which can be moved out of, e.g. local variables, aren't linted, as the clone can
+    /// simply be removed, see [`redundant_clone`](#redundant_clone).
+    ///
+    /// ### Why is this bad?
+    /// `std::mem::replace` returns the old value while assigning the new one, without cloning.
+    /// If the new value is the default, `std::mem::take` does the same.
+    ///
+    /// ### Example
+    /// ```rust
+    /// fn rename(name: &mut String) -> String {
+    ///     let old = name.clone();
+    ///     *name = String::from("b");
+    ///     old
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// fn rename(name: &mut String) -> String {
+    ///     std::mem::replace(name, String::from("b"))
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub SWAP_WITH_TEMPORARY,
+    complexity,
+    "cloning a value to keep it before assigning a new value instead of using `mem::replace`"
+}
+
+declare_lint_pass!(Swap => [MANUAL_SWAP, ALMOST_SWAPPED, SWAP_WITH_TEMPORARY]);
 
 impl<'tcx> LateLintPass<'tcx> for Swap {
     fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
         check_manual_swap(cx, block);
         check_suspicious_swap(cx, block);
         check_xor_swap(cx, block);
+        check_clone_then_assign(cx, block);
     }
 }
 
@@ -114,6 +153,33 @@ fn generate_swap_warning(cx: &LateContext<'_>, e1: &Expr<'_>, e2: &Expr<'_>, spa
                     applicability,
                 );
             }
+        } else if let Some((slice, [(lo, lo_elem), (hi, hi_elem)])) = split_at_index(cx, e1, e2)
+            && [e1, e2, slice].iter().all(|e| e.span.ctxt() == ctxt)
+            && let Some(slice_snip) = snippet_opt(cx, slice.span)
+            && let Some(lo_rest) = snippet_opt(cx, lo.span.with_lo(lo_elem.span.hi()))
+            && let Some(hi_rest) = snippet_opt(cx, hi.span.with_lo(hi_elem.span.hi()))
+            && let Some(lo_index) = int_index(cx, lo_elem)
+            && let Some(hi_index) = int_index(cx, hi_elem)
+            && let Some(sugg) = std_or_core(cx)
+        {
+            // Splitting the slice checks the bounds like the indexing did, and gives two disjoint
+            // borrows. The block keeps the halves out of the enclosing scope.
+            span_lint_and_sugg(
+                cx,
+                MANUAL_SWAP,
+                span,
+                &format!(
+                    "this looks like you are swapping `{}` and `{}` manually",
+                    Sugg::hir(cx, e1, ".."),
+                    Sugg::hir(cx, e2, ".."),
+                ),
+                "try",
+                format!(
+                    "{{ let (head, tail) = {slice_snip}.split_at_mut({hi_index}); \
+                    {sugg}::mem::swap(&mut head[{lo_index}]{lo_rest}, &mut tail[0]{hi_rest}); }}"
+                ),
+                Applicability::MaybeIncorrect,
+            );
         }
         return;
     }
@@ -297,3 +363,205 @@ fn extract_sides_of_xor_assign<'a, 'hir>(
         None
     }
 }
+
+/// Finds the elements of the same slice, array or `Vec` two places are projected from, if they are
+/// indexed with different constants, e.g. `v[0]` and `v[1]` for `v[0].a` and `v[1].a`. Returns
+/// the slice, then both places along with their element, ordered by the index.
+fn split_at_index<'a, 'hir>(
+    cx: &LateContext<'_>,
+    e1: &'a Expr<'hir>,
+    e2: &'a Expr<'hir>,
+) -> Option<(&'a Expr<'hir>, [(&'a Expr<'hir>, &'a Expr<'hir>); 2])> {
+    let elements = |e: &'a Expr<'hir>| {
+        let mut elements = Vec::new();
+        let mut e = e;
+        while let ExprKind::Field(base, _) | ExprKind::Index(base, _) = e.kind {
+            if let ExprKind::Index(..) = e.kind {
+                elements.push(e);
+            }
+            e = base;
+        }
+        elements.reverse();
+        elements
+    };
+    let (elem1, elem2) = elements(e1)
+        .into_iter()
+        .zip(elements(e2))
+        .find(|(elem1, elem2)| !eq_expr_value(cx, elem1, elem2))?;
+    let (&ExprKind::Index(slice, _), &ExprKind::Index(slice2, _)) = (&elem1.kind, &elem2.kind) else {
+        return None;
+    };
+    let ty = cx.typeck_results().expr_ty(slice).peel_refs();
+    if !eq_expr_value(cx, slice, slice2)
+        || !(matches!(ty.kind(), ty::Slice(_) | ty::Array(..)) || is_type_diagnostic_item(cx, ty, sym::Vec))
+    {
+        return None;
+    }
+    match int_index(cx, elem1)?.cmp(&int_index(cx, elem2)?) {
+        Ordering::Less => Some((slice, [(e1, elem1), (e2, elem2)])),
+        Ordering::Greater => Some((slice, [(e2, elem2), (e1, elem1)])),
+        Ordering::Equal => None,
+    }
+}
+
+/// Gets the constant index of the index expression `e`.
+fn int_index(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<u128> {
+    if let ExprKind::Index(_, index) = e.kind
+        && let (Constant::Int(index), _) = constant(cx, cx.typeck_results(), index)?
+    {
+        Some(index)
+    } else {
+        None
+    }
+}
+
+/// Implementation of the `SWAP_WITH_TEMPORARY` lint.
+fn check_clone_then_assign<'tcx>(cx: &LateContext<'tcx>, block: &'tcx Block<'_>) {
+    if block.span.from_expansion() {
+        return;
+    }
+
+    let pats = [
+        // `let old = x.clone();`
+        StmtPat::one(|caps, item| {
+            if let BlockItem::Stmt(Stmt {
+                kind: StmtKind::Local(local),
+                ..
+            }) = item
+                && let PatKind::Binding(_, old, _, None) = local.pat.kind
+                && let Some(init) = local.init
+                && let Some((_, _, root)) = cloned_place(cx, init)
+            {
+                caps.bind("old", old);
+                caps.bind("root", root);
+                true
+            } else {
+                false
+            }
+        }),
+        StmtPat::Unrelated,
+        // `x = new;`
+        StmtPat::one(|caps, item| {
+            if let BlockItem::Stmt(Stmt {
+                kind: StmtKind::Semi(assign),
+                ..
+            }) = item
+                && let ExprKind::Assign(place, new, _) = assign.kind
+            {
+                root_local(place) == Some(caps.local("root"))
+                    && !is_local_used(cx, new, caps.local("root"))
+                    && !is_local_used(cx, new, caps.local("old"))
+            } else {
+                false
+            }
+        }),
+    ];
+
+    for m in find_stmt_seqs(cx, block, &pats) {
+        let [clone_part, unrelated, assign_part] = &m.parts[..] else {
+            continue;
+        };
+        let ([clone_item], [assign_item]) = (&clone_part[..], &assign_part[..]) else {
+            continue;
+        };
+        let before_assign = unrelated.last().unwrap_or(clone_item);
+        if !clone_item.span().from_expansion()
+            && !assign_item.span().from_expansion()
+            // Statements between them may come from macro calls, e.g. `println!`, whose span doesn't
+            // include the semicolon. Only the whitespace before the assignment is removed.
+            && let Some(before_assign) = walk_span_to_context(before_assign.span(), block.span.ctxt())
+            && let Some(gap) = snippet_opt(cx, before_assign.between(assign_item.span()))
+            && let Some(init) = clone_item.expr()
+            && let Some((cloned, auto_deref, _)) = cloned_place(cx, init)
+            && let Some(Expr {
+                kind: ExprKind::Assign(place, new, _),
+                ..
+            }) = assign_item.expr()
+            && match place.kind {
+                ExprKind::Unary(UnOp::Deref, inner) if auto_deref => eq_expr_value(cx, cloned, inner),
+                _ => !auto_deref && eq_expr_value(cx, cloned, place),
+            }
+            // Otherwise the value can be moved out instead of cloned, see `redundant_clone`
+            && is_unmovable_place(cx, place)
+            && let Some(sugg) = std_or_core(cx)
+        {
+            let mut applicability = Applicability::MaybeIncorrect;
+            let place_sugg = Sugg::hir_with_applicability(cx, place, "..", &mut applicability);
+            // `*x` where `x: &mut T` can be passed as is
+            let dest = if auto_deref {
+                Sugg::hir_with_applicability(cx, cloned, "..", &mut applicability)
+            } else {
+                place_sugg.clone().mut_addr()
+            };
+            let assign_span = assign_item.span();
+            let removed_assign =
+                assign_span.with_lo(assign_span.lo() - BytePos::from_usize(gap.len() - gap.trim_end().len()));
+            let (method, replace) = if is_default_equivalent(cx, new) {
+                ("take", format!("{sugg}::mem::take({dest})"))
+            } else {
+                let new = Sugg::hir_with_applicability(cx, new, "..", &mut applicability);
+                ("replace", format!("{sugg}::mem::replace({dest}, {new})"))
+            };
+            span_lint_and_then(
+                cx,
+                SWAP_WITH_TEMPORARY,
+                clone_item.span().to(assign_item.span()),
+                &format!("`{place_sugg}` is cloned to keep its old value before assigning a new one"),
+                |diag| {
+                    diag.multipart_suggestion(
+                        format!("use `{sugg}::mem::{method}` instead"),
+                        vec![(init.span, replace), (removed_assign, String::new())],
+                        applicability,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Checks if `e` is `x.clone()`, where `x` is a local or projected from one. Returns `x`, whether
+/// the value behind `x: &mut T` is cloned, and the local.
+fn cloned_place<'a, 'tcx>(cx: &LateContext<'tcx>, e: &'a Expr<'tcx>) -> Option<(&'a Expr<'tcx>, bool, HirId)> {
+    if let ExprKind::MethodCall(_, recv, [], _) = e.kind
+        && is_trait_method(cx, e, sym::Clone)
+    {
+        let ty = cx.typeck_results().expr_ty(e);
+        let recv_ty = cx.typeck_results().expr_ty(recv);
+        let auto_deref = match *recv_ty.kind() {
+            ty::Ref(_, inner, Mutability::Mut) if inner == ty => true,
+            _ if recv_ty == ty => false,
+            _ => return None,
+        };
+        Some((recv, auto_deref, root_local(recv)?))
+    } else {
+        None
+    }
+}
+
+/// Gets the local `e` is projected from by fields and dereferences, or `e` itself.
+fn root_local(e: &Expr<'_>) -> Option<HirId> {
+    let mut e = e;
+    while let ExprKind::Field(base, _) | ExprKind::Unary(UnOp::Deref, base) = e.kind {
+        e = base;
+    }
+    path_to_local(e)
+}
+
+/// Checks if the value can't be moved out of the place `e`, as it's behind a reference or a field
+/// of a type implementing `Drop`.
+fn is_unmovable_place(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
+    let mut e = e;
+    loop {
+        match e.kind {
+            ExprKind::Unary(UnOp::Deref, _) => return true,
+            ExprKind::Field(base, _) => {
+                let base_ty = cx.typeck_results().expr_ty(base);
+                if base_ty.is_ref() || base_ty.ty_adt_def().map_or(false, |adt| adt.has_dtor(cx.tcx)) {
+                    return true;
+                }
+                e = base;
+            },
+            _ => return false,
+        }
+    }
+}
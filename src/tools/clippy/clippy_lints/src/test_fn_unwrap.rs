@@ -0,0 +1,128 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::is_test_function;
+use clippy_utils::result_unwraps::ResultUnwraps;
+use rustc_errors::Applicability;
+use rustc_hir::intravisit::FnKind;
+use rustc_hir::{Body, ExprKind, FnDecl, FnRetTy};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::def_id::LocalDefId;
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `unwrap()` and `expect()` on `Result`s in `#[test]` functions. In tests which
+    /// return a `Result`, every one of them is linted. In tests which return `()`, the test is
+    /// linted if it unwraps more `Result`s than the `test-unwrap-threshold` configuration.
+    ///
+    /// ### Why is this bad?
+    /// A test which returns the error with `?` fails with the error itself, while an unwrapped
+    /// error only shows up as a panic message.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// #[test]
+    /// fn parses() -> Result<(), ParseIntError> {
+    ///     let n: u32 = "12".parse().unwrap();
+    ///     assert_eq!(n, 12);
+    ///     Ok(())
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// #[test]
+    /// fn parses() -> Result<(), ParseIntError> {
+    ///     let n: u32 = "12".parse()?;
+    ///     assert_eq!(n, 12);
+    ///     Ok(())
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub TEST_FN_RETURNING_RESULT_WITH_UNWRAP_INSIDE,
+    pedantic,
+    "`unwrap()` on `Result`s in tests which could return the error instead"
+}
+
+pub struct TestFnUnwrap {
+    threshold: u64,
+}
+
+impl TestFnUnwrap {
+    pub fn new(threshold: u64) -> Self {
+        Self { threshold }
+    }
+}
+
+impl_lint_pass!(TestFnUnwrap => [TEST_FN_RETURNING_RESULT_WITH_UNWRAP_INSIDE]);
+
+impl<'tcx> LateLintPass<'tcx> for TestFnUnwrap {
+    fn check_fn(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        kind: FnKind<'tcx>,
+        decl: &'tcx FnDecl<'_>,
+        body: &'tcx Body<'_>,
+        span: Span,
+        def_id: LocalDefId,
+    ) {
+        if !matches!(kind, FnKind::ItemFn(..))
+            || span.from_expansion()
+            || !is_test_function(cx.tcx, def_id)
+            || cx.tcx.has_attr(def_id.to_def_id(), sym::should_panic)
+        {
+            return;
+        }
+        let ExprKind::Block(block, _) = body.value.kind else {
+            return;
+        };
+        let ret_ty = cx
+            .tcx
+            .erase_late_bound_regions(cx.tcx.fn_sig(def_id).subst_identity().output());
+
+        let unwraps = ResultUnwraps::collect(cx, block, ret_ty);
+        if unwraps.spans.is_empty() {
+            return;
+        }
+
+        if ret_ty.is_unit() {
+            if unwraps.spans.len() as u64 <= self.threshold {
+                return;
+            }
+            let FnRetTy::DefaultReturn(ret_span) = decl.output else {
+                return;
+            };
+            span_lint_and_then(
+                cx,
+                TEST_FN_RETURNING_RESULT_WITH_UNWRAP_INSIDE,
+                cx.tcx.def_span(def_id),
+                &format!("this test unwraps {} `Result`s", unwraps.spans.len()),
+                |diag| {
+                    if let Some(sugg) =
+                        unwraps.return_result_sugg(cx, ret_span, "Result<(), Box<dyn std::error::Error>>")
+                    {
+                        diag.multipart_suggestion(
+                            "return a `Result` from the test and use `?` instead",
+                            sugg,
+                            Applicability::MaybeIncorrect,
+                        );
+                    } else {
+                        diag.span_note(unwraps.spans, "the `Result`s are unwrapped here");
+                        diag.help("return a `Result` from the test and use `?` instead");
+                    }
+                },
+            );
+        } else {
+            for span in unwraps.spans {
+                span_lint_and_then(
+                    cx,
+                    TEST_FN_RETURNING_RESULT_WITH_UNWRAP_INSIDE,
+                    span,
+                    "unwrapping a `Result` in a test which returns a `Result`",
+                    |diag| {
+                        diag.span_suggestion(span, "return the error instead", "?", Applicability::MachineApplicable);
+                    },
+                );
+            }
+        }
+    }
+}
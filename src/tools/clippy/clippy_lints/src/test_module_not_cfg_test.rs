@@ -0,0 +1,129 @@
+use clippy_utils::cfg::{cfgs_along_def_path, CfgPredicate};
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::is_test_function;
+use clippy_utils::source::indent_of;
+use rustc_errors::Applicability;
+use rustc_hir::def::DefKind;
+use rustc_hir::{Item, ItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for modules containing `#[test]` functions which aren't gated behind
+    /// `#[cfg(test)]`.
+    ///
+    /// Only the modules whose name is listed in the `test-module-names` configuration are
+    /// checked.
+    ///
+    /// `#[test]` functions are removed when the crate isn't compiled as a test, so this lint
+    /// only emits warnings when Clippy checks the tests, e.g. with `cargo clippy --tests` or
+    /// `cargo clippy --all-targets`. A plain `cargo clippy` never triggers it.
+    ///
+    /// ### Why is this bad?
+    /// The module is compiled into non-test builds as well. Its helpers and imports end up in
+    /// the binary or cause unused warnings, and it can't use `dev-dependencies`.
+    ///
+    /// ### Known problems
+    /// Modules of integration tests, which are only ever compiled as tests, are linted as well.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// mod tests {
+    ///     #[test]
+    ///     fn it_works() {}
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// #[cfg(test)]
+    /// mod tests {
+    ///     #[test]
+    ///     fn it_works() {}
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub TEST_MODULE_NOT_CFG_TEST,
+    pedantic,
+    "test modules which aren't gated behind `#[cfg(test)]`"
+}
+
+pub struct TestModuleNotCfgTest {
+    test_module_names: Vec<String>,
+}
+
+impl TestModuleNotCfgTest {
+    pub fn new(test_module_names: Vec<String>) -> Self {
+        Self { test_module_names }
+    }
+}
+
+impl_lint_pass!(TestModuleNotCfgTest => [TEST_MODULE_NOT_CFG_TEST]);
+
+impl<'tcx> LateLintPass<'tcx> for TestModuleNotCfgTest {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Mod(module) = item.kind
+            && !item.span.from_expansion()
+            && self
+                .test_module_names
+                .iter()
+                .any(|pattern| matches_pattern(pattern, item.ident.as_str()))
+            && module.item_ids.iter().any(|id| {
+                cx.tcx.def_kind(id.owner_id) == DefKind::Fn && is_test_function(cx.tcx, id.owner_id.def_id)
+            })
+            && !is_gated_on_test(cx, item)
+        {
+            span_lint_and_then(
+                cx,
+                TEST_MODULE_NOT_CFG_TEST,
+                item.span.with_hi(item.ident.span.hi()),
+                "test module not gated behind `#[cfg(test)]`",
+                |diag| {
+                    let indent = " ".repeat(indent_of(cx, item.span).unwrap_or(0));
+                    diag.span_suggestion(
+                        item.span.shrink_to_lo(),
+                        "add the attribute",
+                        format!("#[cfg(test)]\n{indent}"),
+                        Applicability::MachineApplicable,
+                    );
+                },
+            );
+        }
+    }
+}
+
+/// Checks if the module or one of its parents has a `#[cfg]` involving `test`.
+fn is_gated_on_test(cx: &LateContext<'_>, item: &Item<'_>) -> bool {
+    cfgs_along_def_path(cx.tcx, item.owner_id.def_id)
+        .iter()
+        .map(CfgPredicate::parse)
+        .any(|predicate| {
+            let mut mentions_test = false;
+            predicate.for_each_option(&mut |name, value, _| mentions_test |= name == sym::test && value.is_none());
+            mentions_test
+        })
+}
+
+/// Matches `name` against `pattern`, in which a `*` matches any sequence of characters.
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts = parts.peekable();
+    if parts.peek().is_none() {
+        return rest.is_empty();
+    }
+    while let Some(part) = parts.next() {
+        if parts.peek().is_none() {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
@@ -0,0 +1,114 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::is_trait_impl_item;
+use rustc_hir::{GenericParamKind, Generics, ImplItem, ImplItemKind, Item, ItemKind, TraitItem, TraitItemKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for functions and types with too many generic type and const parameters.
+    /// Lifetime parameters and `impl Trait` in argument position are not counted.
+    ///
+    /// The maximum number of generic parameters is configurable and defaults to 5.
+    ///
+    /// ### Why is this bad?
+    /// Every generic parameter has to be understood, and often spelled out, by all users of
+    /// the item. Related parameters can usually be grouped behind a single trait with
+    /// associated types, or replaced by a configuration struct.
+    ///
+    /// ### Example
+    /// ```rust
+    /// struct Server<Listener, Codec, Handler, Logger, Metrics, Clock> {
+    ///     // ..
+    /// #   listener: Listener, codec: Codec, handler: Handler, logger: Logger, metrics: Metrics, clock: Clock,
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// trait Config {
+    ///     type Listener;
+    ///     type Codec;
+    ///     type Handler;
+    ///     type Logger;
+    ///     type Metrics;
+    ///     type Clock;
+    /// }
+    ///
+    /// struct Server<C: Config> {
+    ///     // ..
+    /// #   listener: C::Listener,
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub TOO_MANY_GENERIC_PARAMS,
+    pedantic,
+    "functions and types with too many generic parameters"
+}
+
+pub struct TooManyGenericParams {
+    threshold: u64,
+}
+
+impl TooManyGenericParams {
+    #[must_use]
+    pub fn new(threshold: u64) -> Self {
+        Self { threshold }
+    }
+
+    fn check_params(&self, cx: &LateContext<'_>, generics: &Generics<'_>, descr: &str) {
+        let count = generics
+            .params
+            .iter()
+            .filter(|param| {
+                matches!(
+                    param.kind,
+                    GenericParamKind::Type { synthetic: false, .. } | GenericParamKind::Const { .. }
+                )
+            })
+            .count() as u64;
+        if count > self.threshold && !generics.span.from_expansion() {
+            span_lint_and_help(
+                cx,
+                TOO_MANY_GENERIC_PARAMS,
+                generics.span,
+                &format!(
+                    "this {descr} has too many generic parameters ({count}/{})",
+                    self.threshold
+                ),
+                None,
+                "consider grouping the bounds into a trait with associated types, or using a configuration struct",
+            );
+        }
+    }
+}
+
+impl_lint_pass!(TooManyGenericParams => [TOO_MANY_GENERIC_PARAMS]);
+
+impl<'tcx> LateLintPass<'tcx> for TooManyGenericParams {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
+        match item.kind {
+            ItemKind::Fn(_, generics, _)
+            | ItemKind::TyAlias(_, generics)
+            | ItemKind::Enum(_, generics)
+            | ItemKind::Struct(_, generics)
+            | ItemKind::Union(_, generics)
+            | ItemKind::Trait(_, _, generics, _, _) => self.check_params(cx, generics, item.kind.descr()),
+            _ => {},
+        }
+    }
+
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'_>) {
+        // don't warn for trait implementations, the generics are dictated by the trait
+        if let ImplItemKind::Fn(..) = item.kind
+            && !is_trait_impl_item(cx, item.hir_id())
+        {
+            self.check_params(cx, item.generics, "method");
+        }
+    }
+
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx TraitItem<'_>) {
+        if let TraitItemKind::Fn(..) = item.kind {
+            self.check_params(cx, item.generics, "method");
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::{first_node_macro_backtrace, root_macro_call_first_node, FormatArg, FormatArgsExpn};
+use clippy_utils::source::snippet_with_context;
+use clippy_utils::visitors::{for_each_expr, Descend, Visitable};
+use clippy_utils::{def_path_def_ids, higher, is_trait_method};
+use core::ops::ControlFlow;
+use rustc_errors::Applicability;
+use rustc_hir::def_id::DefIdMap;
+use rustc_hir::{Expr, ExprKind, LoopSource};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_span::{sym, SyntaxContext};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for logging macros which are called on every iteration of a loop with arguments
+    /// formatted eagerly by `format!` or `to_string()`.
+    ///
+    /// The logging macros are listed in the `logging-macros` configuration.
+    ///
+    /// ### Why is this bad?
+    /// Logging macros only format their arguments if the message is actually logged, which is
+    /// usually not the case for the verbose levels used in loops. `format!` and `to_string()`
+    /// allocate and format the argument anyway, on every iteration.
+    ///
+    /// ### Known problems
+    /// Only the logging calls outside of any `if` or `match` in the body of a loop or of a closure
+    /// passed to an iterator adapter like `for_each` are checked, since the ones in a branch are
+    /// usually not run on every iteration.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// for item in &items {
+    ///     log::trace!("processing {}", format!("{}/{}", item.group, item.name));
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// for item in &items {
+    ///     log::trace!("processing {}", format_args!("{}/{}", item.group, item.name));
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub TRACING_OR_LOG_IN_HOT_LOOP_WITH_FORMAT,
+    pedantic,
+    "logging with eagerly formatted arguments on every iteration of a loop"
+}
+
+pub struct TracingOrLogInHotLoopWithFormat {
+    conf_logging_macros: Vec<String>,
+    logging_macros: DefIdMap<usize>,
+}
+
+impl TracingOrLogInHotLoopWithFormat {
+    pub fn new(conf_logging_macros: Vec<String>) -> Self {
+        Self {
+            conf_logging_macros,
+            logging_macros: DefIdMap::default(),
+        }
+    }
+}
+
+impl_lint_pass!(TracingOrLogInHotLoopWithFormat => [TRACING_OR_LOG_IN_HOT_LOOP_WITH_FORMAT]);
+
+/// Iterator adapters which call their closure for each item.
+const ITERATOR_ADAPTERS: [&str; 8] = [
+    "filter",
+    "filter_map",
+    "flat_map",
+    "for_each",
+    "inspect",
+    "map",
+    "try_for_each",
+    "take_while",
+];
+
+impl<'tcx> LateLintPass<'tcx> for TracingOrLogInHotLoopWithFormat {
+    fn check_crate(&mut self, cx: &LateContext<'_>) {
+        for (index, path) in self.conf_logging_macros.iter().enumerate() {
+            let segs: Vec<_> = path.split("::").collect();
+            for id in def_path_def_ids(cx, &segs) {
+                self.logging_macros.insert(id, index);
+            }
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if self.logging_macros.is_empty() || in_external_macro(cx.sess(), expr.span) {
+            return;
+        }
+        if let ExprKind::Loop(block, _, LoopSource::Loop, _) = expr.kind {
+            self.check_loop_body(cx, block);
+        } else if let Some(body) = loop_body(cx, expr) {
+            self.check_loop_body(cx, body);
+        }
+    }
+}
+
+impl TracingOrLogInHotLoopWithFormat {
+    /// Checks the logging calls which are run on every iteration of a loop with the body `body`.
+    fn check_loop_body<'tcx>(&self, cx: &LateContext<'tcx>, body: impl Visitable<'tcx>) {
+        for_each_expr(body, |e| {
+            if let Some(macro_call) = root_macro_call_first_node(cx, e) {
+                if let Some(&index) = self.logging_macros.get(&macro_call.def_id)
+                    && let Some(format_args) = FormatArgsExpn::find_nested(cx, e, macro_call.expn)
+                {
+                    let path = &self.conf_logging_macros[index];
+                    let name = path.rsplit("::").next().unwrap_or(path);
+                    for arg in &format_args.args {
+                        check_arg(cx, name, arg, macro_call.span.ctxt());
+                    }
+                }
+                return ControlFlow::<(), _>::Continue(Descend::No);
+            }
+            match e.kind {
+                // Branches aren't run on every iteration, and nested loops are checked on their own
+                ExprKind::If(..) | ExprKind::Match(..) | ExprKind::Loop(..) | ExprKind::Closure(..) => {
+                    ControlFlow::Continue(Descend::No)
+                },
+                _ => ControlFlow::Continue(Descend::Yes),
+            }
+        });
+    }
+}
+
+fn check_arg(cx: &LateContext<'_>, name: &str, arg: &FormatArg<'_>, ctxt: SyntaxContext) {
+    let value = arg.param.value;
+    // The argument is passed through the logging macro, so its root macro call is the logging macro
+    let (span, what, sugg) = if let Some(macro_call) = first_node_macro_backtrace(cx, value).next()
+        && cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id)
+    {
+        // `Arguments` ignores the width and other flags, which pad the `String` of `format!`
+        let sugg = arg.format.is_default().then(|| {
+            let mut applicability = Applicability::MaybeIncorrect;
+            let call = snippet_with_context(cx, macro_call.span, ctxt, "..", &mut applicability).0;
+            let args = call.strip_prefix("format!").unwrap_or(&call);
+            (macro_call.span, format!("format_args!{args}"), applicability)
+        });
+        (macro_call.span, "`format!`", sugg)
+    } else if let ExprKind::MethodCall(path, recv, [], _) = value.kind
+        && path.ident.name == sym::to_string
+        && is_trait_method(cx, value, sym::ToString)
+        && !value.span.from_expansion()
+    {
+        let sugg = arg.format.is_default().then(|| {
+            let mut applicability = Applicability::MachineApplicable;
+            let recv = snippet_with_context(cx, recv.span, ctxt, "..", &mut applicability).0;
+            (value.span, recv.into_owned(), applicability)
+        });
+        (value.span, "`to_string()`", sugg)
+    } else {
+        return;
+    };
+
+    span_lint_and_then(
+        cx,
+        TRACING_OR_LOG_IN_HOT_LOOP_WITH_FORMAT,
+        span,
+        &format!("argument of `{name}!` is formatted by {what} on every iteration of the loop"),
+        |diag| {
+            if let Some((span, sugg, applicability)) = sugg {
+                diag.span_suggestion(span, "let the logging macro format it lazily", sugg, applicability);
+            }
+            diag.help("or build the message outside of the loop");
+        },
+    );
+}
+
+/// Gets the body of a `for` or `while` loop, or of a closure passed to an iterator adapter like
+/// `for_each`.
+fn loop_body<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -> Option<&'tcx Expr<'tcx>> {
+    if let Some(for_loop) = higher::ForLoop::hir(expr) {
+        return Some(for_loop.body);
+    }
+    if let Some(while_loop) = higher::While::hir(expr) {
+        return Some(while_loop.body);
+    }
+    if let Some(while_let) = higher::WhileLet::hir(expr) {
+        return Some(while_let.if_then);
+    }
+    match expr.kind {
+        ExprKind::MethodCall(path, _, [arg], _)
+            if ITERATOR_ADAPTERS.contains(&path.ident.as_str()) && is_trait_method(cx, expr, sym::Iterator) =>
+        {
+            if let ExprKind::Closure(closure) = arg.kind {
+                Some(cx.tcx.hir().body(closure.body).value)
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
@@ -0,0 +1,35 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use rustc_hir::Item;
+use rustc_lint::LateContext;
+use rustc_middle::ty::AdtDef;
+use rustc_span::sym;
+
+use super::{compared_fields, related_impl, ComparedFields, HASH_USES_FIELD_IGNORED_IN_EQ};
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, adt: AdtDef<'_>) {
+    let ty = cx.tcx.type_of(item.owner_id).subst_identity();
+    if let Some(eq_trait) = cx.tcx.lang_items().eq_trait()
+        && let Some(eq_impl) = related_impl(cx, eq_trait, ty, adt)
+        // A derived `PartialEq` compares all fields
+        && let Some(ComparedFields::Manual(eq_fields)) = compared_fields(cx, eq_impl, sym::eq, adt)
+        && let Some(ComparedFields::Manual(hash_fields)) = compared_fields(cx, item.owner_id.def_id, sym::hash, adt)
+    {
+        for (index, hir_id) in hash_fields {
+            if eq_fields.contains_key(&index) {
+                continue;
+            }
+            let name = adt.non_enum_variant().fields[index].name;
+            span_lint_hir_and_then(
+                cx,
+                HASH_USES_FIELD_IGNORED_IN_EQ,
+                hir_id,
+                cx.tcx.hir().span(hir_id),
+                &format!("`Hash` uses the field `{name}`, which `PartialEq` doesn't compare"),
+                |diag| {
+                    diag.span_note(cx.tcx.def_span(eq_impl), "`PartialEq` is implemented here");
+                    diag.help("values which are equal must have the same hash, consider not hashing this field");
+                },
+            );
+        }
+    }
+}
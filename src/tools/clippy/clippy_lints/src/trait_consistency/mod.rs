@@ -0,0 +1,302 @@
+mod hash_uses_field_ignored_in_eq;
+mod ord_impl_inconsistent_with_eq;
+
+use clippy_utils::visitors::for_each_expr_with_closures;
+use clippy_utils::{get_parent_expr, path_to_local, path_to_local_id};
+use core::ops::ControlFlow;
+use rustc_data_structures::fx::FxIndexMap;
+use rustc_hir::def_id::{DefId, LocalDefId};
+use rustc_hir::{Body, ExprKind, HirId, ImplItemKind, Item, ItemKind, Node, PatKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{AdtDef, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, BytePos, Symbol};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for manual implementations of `Hash` which use a field of the struct that the
+    /// manual implementation of `PartialEq` for the same struct doesn't compare.
+    ///
+    /// ### Why is this bad?
+    /// Values which are equal must have the same hash, which `HashMap` and `HashSet` rely on.
+    /// If two values only differ in a field which `PartialEq` ignores, they are equal but will
+    /// most likely have different hashes, so looking up one won't find the other.
+    ///
+    /// The opposite, `PartialEq` comparing a field which `Hash` ignores, is fine and isn't
+    /// linted: values which are equal then still have the same hashed fields, so they have the
+    /// same hash. It only makes values which are not equal collide more often.
+    ///
+    /// ### Known problems
+    /// Only accesses to fields of `self` (and `other`) in the `hash` and `eq` methods are
+    /// seen. If either method uses `self` in any other way, e.g. calls a helper method on it,
+    /// it's not checked.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::hash::{Hash, Hasher};
+    /// struct User {
+    ///     id: u64,
+    ///     name: String,
+    /// }
+    ///
+    /// impl PartialEq for User {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.id == other.id
+    ///     }
+    /// }
+    ///
+    /// impl Hash for User {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.id.hash(state);
+    ///         self.name.hash(state);
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::hash::{Hash, Hasher};
+    /// # struct User {
+    /// #     id: u64,
+    /// #     name: String,
+    /// # }
+    /// # impl PartialEq for User {
+    /// #     fn eq(&self, other: &Self) -> bool {
+    /// #         self.id == other.id
+    /// #     }
+    /// # }
+    /// impl Hash for User {
+    ///     fn hash<H: Hasher>(&self, state: &mut H) {
+    ///         self.id.hash(state);
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub HASH_USES_FIELD_IGNORED_IN_EQ,
+    suspicious,
+    "manual `Hash` implementations using a field which `PartialEq` doesn't compare"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for manual implementations of `Ord` and `PartialOrd` for a struct which compare
+    /// other fields than the implementation of `PartialEq` for it, or the same fields as the
+    /// implementation of the other ordering trait, but in a different order.
+    ///
+    /// ### Why is this bad?
+    /// Values which are equal must compare as `Ordering::Equal`, and `partial_cmp` must agree
+    /// with `cmp`. Otherwise, e.g. sorting a `Vec` and then calling `dedup` on it leaves
+    /// duplicates in it, and `BTreeMap` can't find keys it contains.
+    ///
+    /// ### Known problems
+    /// Only accesses to fields of `self` and `other` in the `eq`, `cmp` and `partial_cmp`
+    /// methods are seen. If any of them uses `self` in any other way, e.g. when `partial_cmp`
+    /// returns `Some(self.cmp(other))`, it's not checked. The fields are assumed to be compared
+    /// in the order their comparisons are combined in, which is where they're written, or for a
+    /// comparison stored in a local, where the local is first used.
+    ///
+    /// ### Example
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// #[derive(PartialEq, Eq)]
+    /// struct Version {
+    ///     major: u32,
+    ///     minor: u32,
+    /// }
+    ///
+    /// impl Ord for Version {
+    ///     fn cmp(&self, other: &Self) -> Ordering {
+    ///         self.major.cmp(&other.major)
+    ///     }
+    /// }
+    /// # impl PartialOrd for Version {
+    /// #     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    /// #         Some(self.cmp(other))
+    /// #     }
+    /// # }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// # use std::cmp::Ordering;
+    /// # #[derive(PartialEq, Eq)]
+    /// # struct Version {
+    /// #     major: u32,
+    /// #     minor: u32,
+    /// # }
+    /// impl Ord for Version {
+    ///     fn cmp(&self, other: &Self) -> Ordering {
+    ///         self.major.cmp(&other.major).then(self.minor.cmp(&other.minor))
+    ///     }
+    /// }
+    /// # impl PartialOrd for Version {
+    /// #     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    /// #         Some(self.cmp(other))
+    /// #     }
+    /// # }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub ORD_IMPL_INCONSISTENT_WITH_EQ,
+    suspicious,
+    "manual `Ord` or `PartialOrd` implementations comparing other fields than `PartialEq`"
+}
+
+declare_lint_pass!(TraitConsistency => [HASH_USES_FIELD_IGNORED_IN_EQ, ORD_IMPL_INCONSISTENT_WITH_EQ]);
+
+impl<'tcx> LateLintPass<'tcx> for TraitConsistency {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let ItemKind::Impl(impl_) = item.kind
+            && let Some(trait_ref) = &impl_.of_trait
+            && let Some(trait_id) = trait_ref.trait_def_id()
+            && !item.span.from_expansion()
+            && !cx.tcx.has_attr(item.owner_id.to_def_id(), sym::automatically_derived)
+            && let Some(adt) = cx.tcx.type_of(item.owner_id).subst_identity().ty_adt_def()
+            && adt.is_struct()
+        {
+            match cx.tcx.get_diagnostic_name(trait_id) {
+                Some(sym::Hash) => hash_uses_field_ignored_in_eq::check(cx, item, adt),
+                Some(sym::Ord) => ord_impl_inconsistent_with_eq::check(cx, item, adt, OrdTrait::Ord),
+                _ if Some(trait_id) == cx.tcx.lang_items().partial_ord_trait() => {
+                    ord_impl_inconsistent_with_eq::check(cx, item, adt, OrdTrait::PartialOrd);
+                },
+                _ => {},
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OrdTrait {
+    Ord,
+    PartialOrd,
+}
+
+impl OrdTrait {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Ord => "Ord",
+            Self::PartialOrd => "PartialOrd",
+        }
+    }
+
+    fn method(self) -> Symbol {
+        match self {
+            Self::Ord => sym::cmp,
+            Self::PartialOrd => sym::partial_cmp,
+        }
+    }
+
+    fn trait_id(self, cx: &LateContext<'_>) -> Option<DefId> {
+        match self {
+            Self::Ord => cx.tcx.get_diagnostic_item(sym::Ord),
+            Self::PartialOrd => cx.tcx.lang_items().partial_ord_trait(),
+        }
+    }
+}
+
+/// The fields of a struct compared by a method of a trait implementation.
+enum ComparedFields {
+    /// The implementation is derived, so all fields are compared, in declaration order.
+    Derived,
+    /// The indices of the fields accessed by a manual implementation, from the most significant
+    /// one, each with its first access.
+    Manual(FxIndexMap<usize, HirId>),
+}
+
+/// Returns the local implementation of the trait `trait_id` for the struct `adt`, whose type
+/// parameters, if any, are `adt` as well.
+fn related_impl<'tcx>(cx: &LateContext<'tcx>, trait_id: DefId, ty: Ty<'tcx>, adt: AdtDef<'_>) -> Option<LocalDefId> {
+    let mut found = None;
+    cx.tcx.for_each_relevant_impl(trait_id, ty, |impl_id| {
+        if found.is_none()
+            && let Some(local_id) = impl_id.as_local()
+            && let Some(trait_ref) = cx.tcx.impl_trait_ref(impl_id)
+            && trait_ref
+                .subst_identity()
+                .substs
+                .types()
+                .all(|ty| ty.ty_adt_def() == Some(adt))
+        {
+            found = Some(local_id);
+        }
+    });
+    found
+}
+
+/// Returns the fields of `adt` compared by the method `method` of the implementation `impl_id`.
+/// Returns `None` if the implementation is manual, and the method either isn't there, or uses a
+/// parameter of type `adt` other than to access a field.
+fn compared_fields(
+    cx: &LateContext<'_>,
+    impl_id: LocalDefId,
+    method: Symbol,
+    adt: AdtDef<'_>,
+) -> Option<ComparedFields> {
+    if cx.tcx.has_attr(impl_id.to_def_id(), sym::automatically_derived) {
+        return Some(ComparedFields::Derived);
+    }
+    let ItemKind::Impl(impl_) = cx.tcx.hir().expect_item(impl_id).kind else {
+        return None;
+    };
+    let item_ref = impl_.items.iter().find(|item_ref| item_ref.ident.name == method)?;
+    let ImplItemKind::Fn(_, body_id) = cx.tcx.hir().impl_item(item_ref.id).kind else {
+        return None;
+    };
+    let body = cx.tcx.hir().body(body_id);
+    let typeck = cx.tcx.typeck_body(body_id);
+    let params: Vec<HirId> = body
+        .params
+        .iter()
+        .filter(|param| typeck.pat_ty(param.pat).peel_refs().ty_adt_def() == Some(adt))
+        .map(|param| param.pat.hir_id)
+        .collect();
+
+    let mut accesses = Vec::new();
+    let res = for_each_expr_with_closures(cx, body.value, |e| {
+        if let Some(local_id) = path_to_local(e)
+            && params.contains(&local_id)
+        {
+            if let Some(parent) = get_parent_expr(cx, e)
+                && let ExprKind::Field(base, _) = parent.kind
+                && base.hir_id == e.hir_id
+            {
+                accesses.push((typeck.field_index(parent.hir_id), parent.hir_id));
+            } else {
+                return ControlFlow::Break(());
+            }
+        }
+        ControlFlow::Continue(())
+    });
+    if res.is_some() {
+        return None;
+    }
+
+    // The sort is stable, so the accesses at the same position stay in source order
+    accesses.sort_by_cached_key(|&(_, hir_id)| combined_position(cx, body, hir_id));
+    let mut fields = FxIndexMap::default();
+    for (index, hir_id) in accesses {
+        fields.entry(index).or_insert(hir_id);
+    }
+    Some(ComparedFields::Manual(fields))
+}
+
+/// Gets the position at which the expression `hir_id` is combined with the other comparisons of
+/// `body`. This is where it is written, unless it's in the initializer of a local, e.g. `minor`
+/// in `let minor = ..; major.then(minor)`, which is combined where the local is first used.
+fn combined_position<'tcx>(cx: &LateContext<'tcx>, body: &'tcx Body<'_>, mut hir_id: HirId) -> BytePos {
+    let hir = cx.tcx.hir();
+    while let Some(local) = hir.parent_iter(hir_id).find_map(|(_, node)| match node {
+        Node::Local(local) => Some(local),
+        _ => None,
+    }) && let PatKind::Binding(_, binding_id, ..) = local.pat.kind
+        && local.init.map_or(false, |init| init.span.contains(hir.span(hir_id)))
+        && let Some(use_id) = for_each_expr_with_closures(cx, body.value, |e| {
+            if path_to_local_id(e, binding_id) {
+                ControlFlow::Break(e.hir_id)
+            } else {
+                ControlFlow::Continue(())
+            }
+        })
+    {
+        hir_id = use_id;
+    }
+    hir.span(hir_id).lo()
+}
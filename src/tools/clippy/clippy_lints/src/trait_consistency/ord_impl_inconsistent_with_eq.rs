@@ -0,0 +1,122 @@
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use rustc_hir::Item;
+use rustc_lint::LateContext;
+use rustc_middle::ty::AdtDef;
+use rustc_span::sym;
+
+use super::{compared_fields, related_impl, ComparedFields, OrdTrait, ORD_IMPL_INCONSISTENT_WITH_EQ};
+
+pub(super) fn check(cx: &LateContext<'_>, item: &Item<'_>, adt: AdtDef<'_>, ord_trait: OrdTrait) {
+    let Some(ComparedFields::Manual(ord_fields)) = compared_fields(cx, item.owner_id.def_id, ord_trait.method(), adt)
+    else {
+        return;
+    };
+    let ty = cx.tcx.type_of(item.owner_id).subst_identity();
+    let fields = &adt.non_enum_variant().fields;
+    let trait_name = ord_trait.name();
+
+    if let Some(eq_trait) = cx.tcx.lang_items().eq_trait()
+        && let Some(eq_impl) = related_impl(cx, eq_trait, ty, adt)
+        && let Some(eq_fields) = compared_fields(cx, eq_impl, sym::eq, adt)
+    {
+        // Derived implementations point at the `derive` attribute
+        let eq_note = cx.tcx.def_span(eq_impl).source_callsite();
+        for (&index, &hir_id) in &ord_fields {
+            if let ComparedFields::Manual(eq_fields) = &eq_fields
+                && !eq_fields.contains_key(&index)
+            {
+                span_lint_hir_and_then(
+                    cx,
+                    ORD_IMPL_INCONSISTENT_WITH_EQ,
+                    hir_id,
+                    cx.tcx.hir().span(hir_id),
+                    &format!(
+                        "`{trait_name}` compares the field `{}`, which `PartialEq` doesn't compare",
+                        fields[index].name
+                    ),
+                    |diag| {
+                        diag.span_note(eq_note, "`PartialEq` is implemented here");
+                        diag.help("values which are equal must compare as `Ordering::Equal`");
+                    },
+                );
+            }
+        }
+
+        let eq_only: Vec<_> = match &eq_fields {
+            ComparedFields::Derived => (0..fields.len())
+                .filter(|index| !ord_fields.contains_key(index))
+                .map(|index| (index, cx.tcx.def_span(fields[index].did)))
+                .collect(),
+            ComparedFields::Manual(eq_fields) => eq_fields
+                .iter()
+                .filter(|(index, _)| !ord_fields.contains_key(*index))
+                .map(|(&index, &hir_id)| (index, cx.tcx.hir().span(hir_id)))
+                .collect(),
+        };
+        if !eq_only.is_empty() {
+            let names: Vec<_> = eq_only
+                .iter()
+                .map(|&(index, _)| format!("`{}`", fields[index].name))
+                .collect();
+            let (fields_desc, it) = match &*names {
+                [name] => (format!("the field {name}"), "it"),
+                _ => (format!("the fields {}", names.join(", ")), "them"),
+            };
+            span_lint_hir_and_then(
+                cx,
+                ORD_IMPL_INCONSISTENT_WITH_EQ,
+                item.hir_id(),
+                cx.tcx.def_span(item.owner_id),
+                &format!("`PartialEq` compares {fields_desc}, which `{trait_name}` doesn't compare"),
+                |diag| {
+                    diag.span_note(
+                        eq_only.iter().map(|&(_, span)| span).collect::<Vec<_>>(),
+                        format!("`PartialEq` compares {it} here"),
+                    );
+                    diag.help("values which are equal must compare as `Ordering::Equal`");
+                },
+            );
+            // Only emit one diagnostic at the `impl`
+            return;
+        }
+    }
+
+    // Compare the order of significance with the other ordering trait. When both are manual, only
+    // lint the `Ord` implementation.
+    let other_trait = match ord_trait {
+        OrdTrait::Ord => OrdTrait::PartialOrd,
+        OrdTrait::PartialOrd => OrdTrait::Ord,
+    };
+    if let Some(other_trait_id) = other_trait.trait_id(cx)
+        && let Some(other_impl) = related_impl(cx, other_trait_id, ty, adt)
+        && let Some(other_fields) = compared_fields(cx, other_impl, other_trait.method(), adt)
+        && (ord_trait == OrdTrait::Ord || matches!(other_fields, ComparedFields::Derived))
+    {
+        let other_order: Vec<usize> = match other_fields {
+            ComparedFields::Derived => (0..fields.len()).collect(),
+            ComparedFields::Manual(other_fields) => other_fields.keys().copied().collect(),
+        };
+        let mut sorted_order = other_order.clone();
+        sorted_order.sort_unstable();
+        let mut sorted_ord_fields: Vec<usize> = ord_fields.keys().copied().collect();
+        sorted_ord_fields.sort_unstable();
+        // Different sets of fields are linted against `PartialEq` already
+        if sorted_order == sorted_ord_fields && !ord_fields.keys().copied().eq(other_order) {
+            let other_name = other_trait.name();
+            span_lint_hir_and_then(
+                cx,
+                ORD_IMPL_INCONSISTENT_WITH_EQ,
+                item.hir_id(),
+                cx.tcx.def_span(item.owner_id),
+                &format!("`{trait_name}` compares the fields in a different order than `{other_name}`"),
+                |diag| {
+                    diag.span_note(
+                        cx.tcx.def_span(other_impl).source_callsite(),
+                        format!("`{other_name}` is implemented here"),
+                    );
+                    diag.help("consider implementing `PartialOrd` by returning `Some(self.cmp(other))`");
+                },
+            );
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::last_path_segment;
-use clippy_utils::source::snippet;
+use clippy_utils::source::snippet_with_applicability;
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir::{self as hir, def_id::DefId, GenericArg, QPath, TyKind};
@@ -44,14 +44,15 @@ pub(super) fn check(
             if let Ok(ty_ty_size) = cx.layout_of(ty_ty).map(|l| l.size.bytes());
             if ty_ty_size < box_size_threshold;
             then {
+                let mut app = Applicability::MachineApplicable;
                 span_lint_and_sugg(
                     cx,
                     VEC_BOX,
                     hir_ty.span,
                     "`Vec<T>` is already on the heap, the boxing is unnecessary",
                     "try",
-                    format!("Vec<{}>", snippet(cx, boxed_ty.span, "..")),
-                    Applicability::MachineApplicable,
+                    format!("Vec<{}>", snippet_with_applicability(cx, boxed_ty.span, "..", &mut app)),
+                    app,
                 );
                 true
             } else {
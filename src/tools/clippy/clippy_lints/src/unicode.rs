@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::is_lint_allowed;
 use clippy_utils::macros::span_is_local;
-use clippy_utils::source::snippet;
+use clippy_utils::source::snippet_with_applicability;
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
 use rustc_hir::{Expr, ExprKind, HirId};
@@ -103,7 +103,8 @@ fn check_str(cx: &LateContext<'_>, span: Span, id: HirId) {
         return;
     }
 
-    let string = snippet(cx, span, "");
+    let mut app = Applicability::MachineApplicable;
+    let string = snippet_with_applicability(cx, span, "", &mut app);
     if string.chars().any(|c| ['\u{200B}', '\u{ad}', '\u{2060}'].contains(&c)) {
         span_lint_and_sugg(
             cx,
@@ -115,7 +116,7 @@ fn check_str(cx: &LateContext<'_>, span: Span, id: HirId) {
                 .replace('\u{200B}', "\\u{200B}")
                 .replace('\u{ad}', "\\u{AD}")
                 .replace('\u{2060}', "\\u{2060}"),
-            Applicability::MachineApplicable,
+            app,
         );
     }
 
@@ -131,7 +132,7 @@ fn check_str(cx: &LateContext<'_>, span: Span, id: HirId) {
             } else {
                 escape(string.nfc())
             },
-            Applicability::MachineApplicable,
+            app,
         );
     }
 
@@ -143,7 +144,7 @@ fn check_str(cx: &LateContext<'_>, span: Span, id: HirId) {
             "non-NFC Unicode sequence detected",
             "consider replacing the string with",
             string.nfc().collect::<String>(),
-            Applicability::MachineApplicable,
+            app,
         );
     }
 }
@@ -1,5 +1,5 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
-use clippy_utils::{match_def_path, paths};
+use clippy_utils::paths::PathLookup;
 use if_chain::if_chain;
 use rustc_hir::{BinOpKind, Expr, ExprKind};
 use rustc_lint::{LateContext, LateLintPass};
@@ -56,6 +56,10 @@
 
 declare_lint_pass!(UnnamedAddress => [FN_ADDRESS_COMPARISONS, VTABLE_ADDRESS_COMPARISONS]);
 
+static ARC_PTR_EQ: PathLookup = PathLookup::new(&["alloc", "sync", "Arc", "ptr_eq"]);
+static PTR_EQ: PathLookup = PathLookup::new(&["core", "ptr", "eq"]);
+static RC_PTR_EQ: PathLookup = PathLookup::new(&["alloc", "rc", "Rc", "ptr_eq"]);
+
 impl LateLintPass<'_> for UnnamedAddress {
     fn check_expr(&mut self, cx: &LateContext<'_>, expr: &Expr<'_>) {
         fn is_comparison(binop: BinOpKind) -> bool {
@@ -96,9 +100,9 @@ fn is_fn_def(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
             if let ExprKind::Call(func, [ref _left, ref _right]) = expr.kind;
             if let ExprKind::Path(ref func_qpath) = func.kind;
             if let Some(def_id) = cx.qpath_res(func_qpath, func.hir_id).opt_def_id();
-            if match_def_path(cx, def_id, &paths::PTR_EQ) ||
-                match_def_path(cx, def_id, &paths::RC_PTR_EQ) ||
-                match_def_path(cx, def_id, &paths::ARC_PTR_EQ);
+            if PTR_EQ.matches(cx, def_id) ||
+                RC_PTR_EQ.matches(cx, def_id) ||
+                ARC_PTR_EQ.matches(cx, def_id);
             let ty_param = cx.typeck_results().node_substs(func.hir_id).type_at(0);
             if ty_param.is_trait();
             then {
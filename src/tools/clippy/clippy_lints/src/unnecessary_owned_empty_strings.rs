@@ -1,5 +1,5 @@
+use clippy_utils::paths::PathLookup;
 use clippy_utils::{diagnostics::span_lint_and_sugg, ty::is_type_lang_item};
-use clippy_utils::{match_def_path, paths};
 use if_chain::if_chain;
 use rustc_ast::ast::LitKind;
 use rustc_errors::Applicability;
@@ -33,6 +33,8 @@
 }
 declare_lint_pass!(UnnecessaryOwnedEmptyStrings => [UNNECESSARY_OWNED_EMPTY_STRINGS]);
 
+static STRING_NEW: PathLookup = PathLookup::new(&["alloc", "string", "String", "new"]);
+
 impl<'tcx> LateLintPass<'tcx> for UnnecessaryOwnedEmptyStrings {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
         if_chain! {
@@ -43,7 +45,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
             if let ty::Ref(_, inner_str, _) = cx.typeck_results().expr_ty_adjusted(expr).kind();
             if inner_str.is_str();
             then {
-                if match_def_path(cx, fun_def_id, &paths::STRING_NEW) {
+                if STRING_NEW.matches(cx, fun_def_id) {
                      span_lint_and_sugg(
                             cx,
                             UNNECESSARY_OWNED_EMPTY_STRINGS,
@@ -1,5 +1,6 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
-use clippy_utils::{is_trait_method, is_try, match_trait_method, paths};
+use clippy_utils::paths::PathLookup;
+use clippy_utils::{is_trait_method, is_try};
 use rustc_hir as hir;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_session::{declare_lint_pass, declare_tool_lint};
@@ -45,6 +46,12 @@
 
 declare_lint_pass!(UnusedIoAmount => [UNUSED_IO_AMOUNT]);
 
+static FUTURES_IO_ASYNCREADEXT: PathLookup = PathLookup::new(&["futures_util", "io", "AsyncReadExt"]);
+static FUTURES_IO_ASYNCWRITEEXT: PathLookup = PathLookup::new(&["futures_util", "io", "AsyncWriteExt"]);
+static TOKIO_IO_ASYNCREADEXT: PathLookup = PathLookup::new(&["tokio", "io", "util", "async_read_ext", "AsyncReadExt"]);
+static TOKIO_IO_ASYNCWRITEEXT: PathLookup =
+    PathLookup::new(&["tokio", "io", "util", "async_write_ext", "AsyncWriteExt"]);
+
 impl<'tcx> LateLintPass<'tcx> for UnusedIoAmount {
     fn check_stmt(&mut self, cx: &LateContext<'_>, s: &hir::Stmt<'_>) {
         let (hir::StmtKind::Semi(expr) | hir::StmtKind::Expr(expr)) = s.kind else {
@@ -113,14 +120,14 @@ fn check_method_call(cx: &LateContext<'_>, call: &hir::Expr<'_>, expr: &hir::Exp
     if let hir::ExprKind::MethodCall(path, ..) = call.kind {
         let symbol = path.ident.as_str();
         let read_trait = if is_await {
-            match_trait_method(cx, call, &paths::FUTURES_IO_ASYNCREADEXT)
-                || match_trait_method(cx, call, &paths::TOKIO_IO_ASYNCREADEXT)
+            FUTURES_IO_ASYNCREADEXT.matches_trait_method(cx, call)
+                || TOKIO_IO_ASYNCREADEXT.matches_trait_method(cx, call)
         } else {
             is_trait_method(cx, call, sym::IoRead)
         };
         let write_trait = if is_await {
-            match_trait_method(cx, call, &paths::FUTURES_IO_ASYNCWRITEEXT)
-                || match_trait_method(cx, call, &paths::TOKIO_IO_ASYNCWRITEEXT)
+            FUTURES_IO_ASYNCWRITEEXT.matches_trait_method(cx, call)
+                || TOKIO_IO_ASYNCWRITEEXT.matches_trait_method(cx, call)
         } else {
             is_trait_method(cx, call, sym::IoWrite)
         };
@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::visitors::for_each_expr;
-use clippy_utils::{method_chain_args, return_ty};
+use clippy_utils::{match_method_chain, return_ty};
 use core::ops::ControlFlow;
 use if_chain::if_chain;
 use rustc_hir as hir;
@@ -80,8 +80,8 @@ fn lint_impl_body<'tcx>(cx: &LateContext<'tcx>, impl_span: Span, impl_item: &'tc
         let mut result = Vec::new();
         let _: Option<!> = for_each_expr(body.value, |e| {
             // check for `expect`
-            if let Some(arglists) = method_chain_args(e, &["expect"]) {
-                let receiver_ty = typeck.expr_ty(arglists[0].0).peel_refs();
+            if let Some([expect]) = match_method_chain(e, ["expect"]) {
+                let receiver_ty = typeck.expr_ty(expect.receiver).peel_refs();
                 if is_type_diagnostic_item(cx, receiver_ty, sym::Option)
                     || is_type_diagnostic_item(cx, receiver_ty, sym::Result)
                 {
@@ -90,8 +90,8 @@ fn lint_impl_body<'tcx>(cx: &LateContext<'tcx>, impl_span: Span, impl_item: &'tc
             }
 
             // check for `unwrap`
-            if let Some(arglists) = method_chain_args(e, &["unwrap"]) {
-                let receiver_ty = typeck.expr_ty(arglists[0].0).peel_refs();
+            if let Some([unwrap]) = match_method_chain(e, ["unwrap"]) {
+                let receiver_ty = typeck.expr_ty(unwrap.receiver).peel_refs();
                 if is_type_diagnostic_item(cx, receiver_ty, sym::Option)
                     || is_type_diagnostic_item(cx, receiver_ty, sym::Result)
                 {
@@ -2,6 +2,10 @@
 
 #![allow(clippy::module_name_repetitions)]
 
+use clippy_utils::lint_scope::normalize_lint_name;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def_id::DefIdMap;
+use rustc_lint::{LateContext, Lint};
 use serde::de::{Deserializer, IgnoredAny, IntoDeserializer, MapAccess, Visitor};
 use serde::Deserialize;
 use std::error::Error;
@@ -31,6 +35,11 @@
     "CamelCase",
 ];
 const DEFAULT_DISALLOWED_NAMES: &[&str] = &["foo", "baz", "quux"];
+pub(crate) const DEFAULT_PROC_MACRO_IDENT_CONSTRUCTORS: &[&str] =
+    &["proc_macro::Ident::new", "proc_macro2::Ident::new"];
+pub(crate) const DEFAULT_PROC_MACRO_CALL_SITE_SPANS: &[&str] =
+    &["proc_macro::Span::call_site", "proc_macro2::Span::call_site"];
+pub(crate) const DEFAULT_PROC_MACRO_TOKEN_STREAMS: &[&str] = &["proc_macro::TokenStream", "proc_macro2::TokenStream"];
 
 /// Holds information used by `MISSING_ENFORCED_IMPORT_RENAMES` lint.
 #[derive(Clone, Debug, Deserialize)]
@@ -63,6 +72,31 @@ pub fn reason(&self) -> Option<String> {
     }
 }
 
+/// The paths without reasons.
+pub(crate) fn simple_paths(paths: &[&str]) -> Vec<DisallowedPath> {
+    paths.iter().map(|&path| DisallowedPath::Simple(path.into())).collect()
+}
+
+/// Resolves the paths to the items they name, mapping their ids to the index of the path.
+pub(crate) fn resolve_paths(cx: &LateContext<'_>, paths: &[DisallowedPath]) -> DefIdMap<usize> {
+    let mut ids = DefIdMap::default();
+    for (index, path) in paths.iter().enumerate() {
+        let segs: Vec<_> = path.path().split("::").collect();
+        for id in clippy_utils::def_path_def_ids(cx, &segs) {
+            ids.insert(id, index);
+        }
+    }
+    ids
+}
+
+/// A set of paths in which some lints aren't emitted, from the `scope` configuration.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Scope {
+    pub paths: Vec<String>,
+    pub allow: Vec<String>,
+}
+
 /// Conf with parse errors
 #[derive(Default)]
 pub struct TryConf {
@@ -108,6 +142,8 @@ pub struct Conf {
         }
 
         mod defaults {
+            use super::*;
+
             $(pub fn $name() -> $ty { $default })*
         }
 
@@ -253,7 +289,7 @@ pub(crate) fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     ///
     /// Suppress lints whenever the suggested change would cause breakage for other crates.
     (avoid_breaking_exported_api: bool = true),
-    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN.
+    /// Lint: MANUAL_SPLIT_ONCE, MANUAL_STR_REPEAT, CLONED_INSTEAD_OF_COPIED, REDUNDANT_FIELD_NAMES, REDUNDANT_STATIC_LIFETIMES, FILTER_MAP_NEXT, CHECKED_CONVERSIONS, MANUAL_RANGE_CONTAINS, USE_SELF, MEM_REPLACE_WITH_DEFAULT, MANUAL_NON_EXHAUSTIVE, OPTION_AS_REF_DEREF, MAP_UNWRAP_OR, MATCH_LIKE_MATCHES_MACRO, MANUAL_STRIP, MISSING_CONST_FOR_FN, UNNESTED_OR_PATTERNS, FROM_OVER_INTO, PTR_AS_PTR, IF_THEN_SOME_ELSE_NONE, APPROX_CONSTANT, DEPRECATED_CFG_ATTR, INDEX_REFUTABLE_SLICE, MAP_CLONE, BORROW_AS_PTR, MANUAL_BITS, ERR_EXPECT, CAST_ABS_TO_UNSIGNED, UNINLINED_FORMAT_ARGS, MANUAL_CLAMP, MANUAL_LET_ELSE, UNCHECKED_DURATION_SUBTRACTION, COLLAPSIBLE_STR_REPLACE, SEEK_FROM_CURRENT, SEEK_TO_START_INSTEAD_OF_REWIND, UNNECESSARY_LAZY_EVALUATIONS, TRANSMUTE_PTR_TO_REF, ALMOST_COMPLETE_RANGE, NEEDLESS_BORROW, DERIVABLE_IMPLS, MANUAL_IS_ASCII_CHECK, MANUAL_REM_EUCLID, MANUAL_RETAIN, MANUAL_RESULT_INSPECT, STATIC_LAZY_INITIALIZATION_MODERNIZATION, CAST_LOSSLESS, CAST_SLICE_DIFFERENT_SIZES, CAST_SLICE_FROM_RAW_PARTS, IS_DIGIT_ASCII_RADIX, UNNECESSARY_TO_OWNED, MANUAL_HASH_ONE, METADATA_EXISTENCE_CHECK, EXIT_CODE_FROM_BOOL_CAST.
     ///
     /// The minimum rust version that the project supports
     (msrv: Option<String> = None),
@@ -266,6 +302,18 @@ pub(crate) fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     ///
     /// The maximum cognitive complexity a function can have
     (cognitive_complexity_threshold: u64 = 25),
+    /// Lint: COGNITIVE_COMPLEXITY.
+    ///
+    /// How much each construct adds to the cognitive complexity of a function. Keys that
+    /// are left out keep their default weight.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// cognitive-complexity-weights = { if-expr = 2, question-mark = 1 }
+    /// ```
+    (cognitive_complexity_weights: crate::cognitive_complexity::CognitiveComplexityWeights =
+        crate::cognitive_complexity::CognitiveComplexityWeights::default()),
     /// DEPRECATED LINT: CYCLOMATIC_COMPLEXITY.
     ///
     /// Use the Cognitive Complexity lint instead.
@@ -419,20 +467,26 @@ pub(crate) fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     (max_include_file_size: u64 = 1_000_000),
     /// Lint: EXPECT_USED.
     ///
-    /// Whether `expect` should be allowed in test functions or `#[cfg(test)]`
+    /// Whether `expect` should be allowed in test code, like listing `expect_used` in `allow-in-tests`
     (allow_expect_in_tests: bool = false),
     /// Lint: UNWRAP_USED.
     ///
-    /// Whether `unwrap` should be allowed in test functions or `#[cfg(test)]`
+    /// Whether `unwrap` should be allowed in test code, like listing `unwrap_used` in `allow-in-tests`
     (allow_unwrap_in_tests: bool = false),
     /// Lint: DBG_MACRO.
     ///
-    /// Whether `dbg!` should be allowed in test functions or `#[cfg(test)]`
+    /// Whether `dbg!` should be allowed in test code, like listing `dbg_macro` in `allow-in-tests`
     (allow_dbg_in_tests: bool = false),
     /// Lint: PRINT_STDOUT, PRINT_STDERR.
     ///
-    /// Whether print macros (ex. `println!`) should be allowed in test functions or `#[cfg(test)]`
+    /// Whether print macros (ex. `println!`) should be allowed in test code, like listing
+    /// `print_stdout` and `print_stderr` in `allow-in-tests`
     (allow_print_in_tests: bool = false),
+    /// Lint: ARGS_POSITIONAL_SKIP.
+    ///
+    /// Whether the command line arguments should be allowed to be read carelessly in the
+    /// examples of a crate, i.e. in the files of its `examples` directory
+    (allow_args_positional_skip_in_examples: bool = false),
     /// Lint: RESULT_LARGE_ERR.
     ///
     /// The maximum size of the `Err`-variant in a `Result` returned from a function
@@ -459,6 +513,241 @@ pub(crate) fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// Whether to **only** check for missing documentation in items visible within the current
     /// crate. For example, `pub(crate)` items.
     (missing_docs_in_crate_items: bool = false),
+    /// Lint: CYCLIC_MODULE_IMPORTS.
+    ///
+    /// The minimum number of modules an import cycle has to span to be linted
+    (module_import_cycle_threshold: u64 = 2),
+    /// Lint: EXCESSIVE_NESTING.
+    ///
+    /// The maximum nesting depth of `if`s, `match`es, loops and closures within a function
+    (excessive_nesting_threshold: u64 = 6),
+    /// Lint: TOO_MANY_GENERIC_PARAMS.
+    ///
+    /// The maximum number of generic type and const parameters a function or type can have
+    (too_many_generic_params_threshold: u64 = 5),
+    /// Lint: EXPENSIVE_CONSTRUCTOR_IN_LOOP.
+    ///
+    /// The list of constructors which are expensive to call and whose result can be reused, written
+    /// as fully qualified paths. Setting this replaces the default list.
+    (expensive_constructors: Vec<String> = Vec::from([
+        "regex::Regex::new".into(),
+        "regex::RegexSet::new".into(),
+        "regex::bytes::Regex::new".into(),
+        "regex::bytes::RegexSet::new".into(),
+    ])),
+    /// Lint: CANCELLATION_UNSAFE_AWAIT.
+    ///
+    /// The list of methods returning futures which aren't cancellation safe, written as fully
+    /// qualified paths. Setting this replaces the default list.
+    (cancellation_unsafe_methods: Vec<String> = Vec::from([
+        "tokio::io::AsyncBufReadExt::read_line".into(),
+        "tokio::io::AsyncReadExt::read_exact".into(),
+        "tokio::io::AsyncReadExt::read_to_end".into(),
+        "tokio::io::AsyncReadExt::read_to_string".into(),
+        "tokio::io::AsyncWriteExt::write_all".into(),
+    ])),
+    /// Lint: CANCELLATION_UNSAFE_AWAIT.
+    ///
+    /// The list of macros which poll several futures and drop the ones which didn't complete,
+    /// written as fully qualified paths. Setting this replaces the default list.
+    (select_macros: Vec<String> = Vec::from([
+        "tokio::select".into(),
+        "futures::select".into(),
+        "futures::select_biased".into(),
+    ])),
+    /// Lint: SLEEP_IN_LOOP_WITHOUT_BACKOFF.
+    ///
+    /// The list of functions which sleep for the `Duration` they're given, written as fully
+    /// qualified paths. Setting this replaces the default list.
+    (sleep_functions: Vec<String> = Vec::from([
+        "std::thread::sleep".into(),
+        "tokio::time::sleep".into(),
+        "async_std::task::sleep".into(),
+    ])),
+    /// Lint: SERDE_ATTRIBUTE_MISUSE.
+    ///
+    /// The checks of `#[serde(..)]` attributes to run. Remove a check from the list to disable it.
+    (serde_attribute_checks: Vec<String> = Vec::from([
+        "skip-serializing-without-default".into(),
+        "deny-unknown-fields-with-flatten".into(),
+        "rename-against-rename-all".into(),
+    ])),
+    /// Lint: REMOVED_PUBLIC_ITEM, CHANGED_PUBLIC_SIGNATURE, NEWLY_EXHAUSTIVE_ENUM.
+    ///
+    /// The path of the public API baseline to compare the crate against, as written by
+    /// `cargo clippy --emit-public-api <dir>`. Relative paths start at the crate's manifest
+    /// directory. The lints of the `clippy::semver` group only run if this is set.
+    (public_api_baseline: Option<String> = None),
+    /// Lint: INDEXING_SLICING.
+    ///
+    /// The kinds of indexing and slicing to lint: `index` for indexing with a single index, `slice`
+    /// for slicing with a range, and `str-slice` for slicing a `str` or `String`. Indexing and
+    /// slicing arrays with constants which are in bounds, or indexing them with an index which is
+    /// always in bounds, e.g. `x[i % x.len()]`, is never linted.
+    (indexing_slicing_checks: Vec<String> = Vec::from(["index".into(), "slice".into(), "str-slice".into()])),
+    /// Lint: ARITHMETIC_SIDE_EFFECTS.
+    ///
+    /// Suppress checking of operations in the modules matching the passed paths.
+    ///
+    /// Paths are relative to the crate root. A `*` segment matches any single module, and a
+    /// trailing `*` also matches the module itself and all modules nested in it.
+    ///
+    /// #### Example
+    ///
+    /// ```toml
+    /// arithmetic-side-effects-allowed-paths = ["tests::*", "*::fixed_point"]
+    /// ```
+    (arithmetic_side_effects_allowed_paths: Vec<String> = <_>::default()),
+    /// Lint: STATIC_LAZY_INITIALIZATION_MODERNIZATION.
+    ///
+    /// The paths of the lazy initialization macros and types to check. Macros must expand like
+    /// `lazy_static!`.
+    (lazy_initialization_paths: Vec<String> = Vec::from([
+        "lazy_static::lazy_static".into(),
+        "once_cell::sync::Lazy".into(),
+    ])),
+    /// Lint: NON_PORTABLE_PATH_LITERAL.
+    ///
+    /// The platforms the code has to work on: `unix` to lint paths which only work on Windows,
+    /// and `windows` to lint paths which only work on Unix.
+    (portable_path_targets: Vec<String> = Vec::from(["unix".into(), "windows".into()])),
+    /// Lint: LOCAL_TIME_DAY_ARITHMETIC.
+    ///
+    /// The list of functions which get the current local or naive time, written as fully
+    /// qualified paths. Setting this replaces the default list.
+    (local_time_constructors: Vec<String> = Vec::from([
+        "chrono::Local::now".into(),
+        "chrono::Local::today".into(),
+        "time::OffsetDateTime::now_local".into(),
+    ])),
+    /// Lint: LOCAL_TIME_DAY_ARITHMETIC.
+    ///
+    /// The list of functions which build a duration of whole days, written as fully qualified
+    /// paths. Setting this replaces the default list.
+    (day_arithmetic_functions: Vec<String> = Vec::from([
+        "chrono::Duration::days".into(),
+        "chrono::Duration::weeks".into(),
+        "time::Duration::days".into(),
+        "time::Duration::weeks".into(),
+    ])),
+    /// Lint: MAIN_WITHOUT_RESULT.
+    ///
+    /// The return type suggested for `main`, which has to support `?` on the errors
+    (main_result_type: String = "Result<(), Box<dyn std::error::Error>>".into()),
+    /// Lint: DBG_OR_TODO_IN_RELEASE_PATH.
+    ///
+    /// Additional macros which are only meant to be used during development, written as fully
+    /// qualified paths
+    (debug_only_macros: Vec<String> = Vec::new()),
+    /// Lint: DBG_OR_TODO_IN_RELEASE_PATH.
+    ///
+    /// Whether `unreachable!` should be allowed if it has a message
+    (allow_unreachable_with_message: bool = false),
+    /// Lint: TRACING_OR_LOG_IN_HOT_LOOP_WITH_FORMAT, RELEASE_ONLY_BEHAVIOR_UNDER_CFG_DEBUG_ASSERTIONS.
+    ///
+    /// The list of logging macros, whose arguments are formatted lazily, written as fully
+    /// qualified paths. Setting this replaces the default list.
+    (logging_macros: Vec<String> = Vec::from([
+        "log::trace".into(),
+        "log::debug".into(),
+        "log::info".into(),
+        "log::warn".into(),
+        "log::error".into(),
+        "tracing::trace".into(),
+        "tracing::debug".into(),
+        "tracing::info".into(),
+        "tracing::warn".into(),
+        "tracing::error".into(),
+    ])),
+    /// Lint: LITERAL_SUFFIX_STYLE.
+    ///
+    /// Whether literal suffixes should be `"separated"` by an underscore, e.g. `123_i32`, or
+    /// `"unseparated"`, e.g. `123i32`
+    (suffix_style: crate::misc_early::SuffixStyle = crate::misc_early::SuffixStyle::Separated),
+    /// Lint: LITERAL_SUFFIX_STYLE.
+    ///
+    /// The minimum number of digits in the integer part of a literal which have to be grouped by
+    /// underscores. `0` doesn't require any grouping
+    (digit_grouping_min_digits: u64 = 0),
+    /// Lint: TEST_MODULE_NOT_CFG_TEST.
+    ///
+    /// The names of the modules which are expected to contain tests. A `*` matches any sequence
+    /// of characters, e.g. `"test*"` matches `tests` and `test_utils`. The lint only checks them
+    /// when Clippy is run on the tests, e.g. with `cargo clippy --tests`
+    (test_module_names: Vec<String> = Vec::from(["tests".into(), "test".into()])),
+    /// Lint: TEST_FN_RETURNING_RESULT_WITH_UNWRAP_INSIDE.
+    ///
+    /// The maximum number of `Result`s a test returning `()` may unwrap
+    (test_unwrap_threshold: u64 = 3),
+    /// Lint: UNWRAP_USED, EXPECT_USED, DBG_MACRO, PRINT_STDOUT, PRINT_STDERR, INDEXING_SLICING, PANIC, TODO, UNIMPLEMENTED, UNREACHABLE.
+    ///
+    /// The lints which aren't emitted in test code, i.e. in `#[test]` functions, in items gated
+    /// behind `#[cfg(test)]` and in integration tests, e.g. `["unwrap_used", "indexing_slicing"]`.
+    /// Only the lints listed below support this
+    (allow_in_tests: Vec<String> = Vec::new()),
+    /// Lint: ALL.
+    ///
+    /// Sets of paths in which lints aren't emitted, e.g. generated code. Each `[[scope]]` table
+    /// has `paths`, globs relative to the crate's root directory in which `**` matches any number
+    /// of directories, and the lints to `allow` in them, `"all"` standing for every lint. E.g.
+    /// `paths = ["src/generated/**", "src/proto/*.rs"]` and `allow = ["all"]`. The lints are
+    /// allowed on each item of the files, so attributes on the items themselves take precedence
+    (scope: Vec<crate::utils::conf::Scope> = Vec::new()),
+    /// Lint: ALL.
+    ///
+    /// The paths to which lints are restricted, e.g. `{ unwrap_used = ["src/core/**"] }` only
+    /// emits `unwrap_used` in the files of `src/core`. The paths are globs, as in `scope`
+    (deny_only_in: FxHashMap<String, Vec<String>> = <_>::default()),
+    /// Lint: ALL.
+    ///
+    /// Files containing this marker in one of their first `generated-code-marker-lines` lines
+    /// are generated code, in which the lints of the `generated-code-lint-groups` aren't emitted
+    (generated_code_marker: String = "@generated".into()),
+    /// Lint: ALL.
+    ///
+    /// The number of lines at the start of a file which are searched for the
+    /// `generated-code-marker`
+    (generated_code_marker_lines: u64 = 5),
+    /// Lint: ALL.
+    ///
+    /// The lint groups which aren't emitted in generated code: in files containing the
+    /// `generated-code-marker`, in `#[automatically_derived]` items and in code expanded from
+    /// derive macros of other crates
+    (generated_code_lint_groups: Vec<String> = Vec::from(["style".into(), "pedantic".into()])),
+    /// Lint: PROC_MACRO_DERIVE_MISSING_SPAN_HYGIENE.
+    ///
+    /// The functions building an identifier from a string and a span, written as fully
+    /// qualified paths, like `disallowed-methods`. Setting this replaces the default list.
+    (proc_macro_ident_constructors: Vec<crate::utils::conf::DisallowedPath> =
+        simple_paths(DEFAULT_PROC_MACRO_IDENT_CONSTRUCTORS)),
+    /// Lint: PROC_MACRO_DERIVE_MISSING_SPAN_HYGIENE.
+    ///
+    /// The functions returning the call site span, written as fully qualified paths, like
+    /// `disallowed-methods`. Setting this replaces the default list.
+    (proc_macro_call_site_spans: Vec<crate::utils::conf::DisallowedPath> =
+        simple_paths(DEFAULT_PROC_MACRO_CALL_SITE_SPANS)),
+    /// Lint: PROC_MACRO_DERIVE_MISSING_SPAN_HYGIENE.
+    ///
+    /// The token stream types, written as fully qualified paths, like `disallowed-types`.
+    /// Setting this replaces the default list.
+    (proc_macro_token_streams: Vec<crate::utils::conf::DisallowedPath> =
+        simple_paths(DEFAULT_PROC_MACRO_TOKEN_STREAMS)),
+}
+
+impl Conf {
+    /// Checks if `lint` shouldn't be emitted in test code, because it's listed in `allow-in-tests`
+    /// or covered by one of the `allow-*-in-tests` options.
+    pub fn is_allowed_in_tests(&self, lint: &'static Lint) -> bool {
+        let name = normalize_lint_name(&lint.name_lower());
+        let allowed_by_option = match name.as_str() {
+            "unwrap_used" => self.allow_unwrap_in_tests,
+            "expect_used" => self.allow_expect_in_tests,
+            "dbg_macro" => self.allow_dbg_in_tests,
+            "print_stdout" | "print_stderr" => self.allow_print_in_tests,
+            _ => false,
+        };
+        allowed_by_option || self.allow_in_tests.iter().any(|allowed| normalize_lint_name(allowed) == name)
+    }
 }
 
 /// Search for the configuration file.
@@ -8,5 +8,7 @@
 pub mod metadata_collector;
 pub mod msrv_attr_impl;
 pub mod outer_expn_data_pass;
+pub mod proc_macro_span_hygiene;
 pub mod produce_ice;
+pub mod suggestion_snippets;
 pub mod unnecessary_def_path;
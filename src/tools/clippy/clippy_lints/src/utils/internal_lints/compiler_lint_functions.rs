@@ -1,6 +1,6 @@
 use clippy_utils::diagnostics::span_lint_and_help;
-use clippy_utils::ty::match_type;
-use clippy_utils::{is_lint_allowed, paths};
+use clippy_utils::is_lint_allowed;
+use clippy_utils::paths::PathLookup;
 use if_chain::if_chain;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_hir::{Expr, ExprKind};
@@ -32,6 +32,9 @@
 
 impl_lint_pass!(CompilerLintFunctions => [COMPILER_LINT_FUNCTIONS]);
 
+static EARLY_CONTEXT: PathLookup = PathLookup::new(&["rustc_lint", "EarlyContext"]);
+static LATE_CONTEXT: PathLookup = PathLookup::new(&["rustc_lint", "LateContext"]);
+
 #[derive(Clone, Default)]
 pub struct CompilerLintFunctions {
     map: FxHashMap<&'static str, &'static str>,
@@ -61,7 +64,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
             let fn_name = path.ident;
             if let Some(sugg) = self.map.get(fn_name.as_str());
             let ty = cx.typeck_results().expr_ty(self_arg).peel_refs();
-            if match_type(cx, ty, &paths::EARLY_CONTEXT) || match_type(cx, ty, &paths::LATE_CONTEXT);
+            if EARLY_CONTEXT.matches_ty(cx, ty) || LATE_CONTEXT.matches_ty(cx, ty);
             then {
                 span_lint_and_help(
                     cx,
@@ -1,8 +1,8 @@
 use clippy_utils::consts::{constant_simple, Constant};
 use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::is_expn_of;
+use clippy_utils::paths::{self, PathLookup};
 use clippy_utils::source::snippet;
-use clippy_utils::ty::match_type;
-use clippy_utils::{def_path_def_ids, is_expn_of, match_def_path, paths};
 use if_chain::if_chain;
 use rustc_data_structures::fx::FxHashMap;
 use rustc_errors::Applicability;
@@ -67,19 +67,28 @@ pub struct InterningDefinedSymbol {
 
 impl_lint_pass!(InterningDefinedSymbol => [INTERNING_DEFINED_SYMBOL, UNNECESSARY_SYMBOL_STR]);
 
+static IDENT: PathLookup = PathLookup::new(&["rustc_span", "symbol", "Ident"]);
+static IDENT_AS_STR: PathLookup = PathLookup::new(&["rustc_span", "symbol", "Ident", "as_str"]);
+static KW_MODULE: PathLookup = PathLookup::new(&["rustc_span", "symbol", "kw"]);
+static SYMBOL: PathLookup = PathLookup::new(&["rustc_span", "symbol", "Symbol"]);
+static SYMBOL_AS_STR: PathLookup = PathLookup::new(&["rustc_span", "symbol", "Symbol", "as_str"]);
+static SYMBOL_INTERN: PathLookup = PathLookup::new(&["rustc_span", "symbol", "Symbol", "intern"]);
+static SYMBOL_TO_IDENT_STRING: PathLookup = PathLookup::new(&["rustc_span", "symbol", "Symbol", "to_ident_string"]);
+static SYM_MODULE: PathLookup = PathLookup::new(&["rustc_span", "symbol", "sym"]);
+
 impl<'tcx> LateLintPass<'tcx> for InterningDefinedSymbol {
     fn check_crate(&mut self, cx: &LateContext<'_>) {
         if !self.symbol_map.is_empty() {
             return;
         }
 
-        for &module in &[&paths::KW_MODULE, &paths::SYM_MODULE] {
-            for def_id in def_path_def_ids(cx, module) {
+        for module in [&KW_MODULE, &SYM_MODULE] {
+            for &def_id in module.get(cx) {
                 for item in cx.tcx.module_children(def_id).iter() {
                     if_chain! {
                         if let Res::Def(DefKind::Const, item_def_id) = item.res;
                         let ty = cx.tcx.type_of(item_def_id).subst_identity();
-                        if match_type(cx, ty, &paths::SYMBOL);
+                        if SYMBOL.matches_ty(cx, ty);
                         if let Ok(ConstValue::Scalar(value)) = cx.tcx.const_eval_poly(item_def_id);
                         if let Ok(value) = value.to_u32();
                         then {
@@ -95,7 +104,7 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
         if_chain! {
             if let ExprKind::Call(func, [arg]) = &expr.kind;
             if let ty::FnDef(def_id, _) = cx.typeck_results().expr_ty(func).kind();
-            if match_def_path(cx, *def_id, &paths::SYMBOL_INTERN);
+            if SYMBOL_INTERN.matches(cx, *def_id);
             if let Some(Constant::Str(arg)) = constant_simple(cx, cx.typeck_results(), arg);
             let value = Symbol::intern(&arg).as_u32();
             if let Some(&def_id) = self.symbol_map.get(&value);
@@ -160,12 +169,8 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
 
 impl InterningDefinedSymbol {
     fn symbol_str_expr<'tcx>(&self, expr: &'tcx Expr<'tcx>, cx: &LateContext<'tcx>) -> Option<SymbolStrExpr<'tcx>> {
-        static IDENT_STR_PATHS: &[&[&str]] = &[&paths::IDENT_AS_STR, &paths::TO_STRING_METHOD];
-        static SYMBOL_STR_PATHS: &[&[&str]] = &[
-            &paths::SYMBOL_AS_STR,
-            &paths::SYMBOL_TO_IDENT_STRING,
-            &paths::TO_STRING_METHOD,
-        ];
+        static IDENT_STR_PATHS: &[&PathLookup] = &[&IDENT_AS_STR, &paths::TO_STRING_METHOD];
+        static SYMBOL_STR_PATHS: &[&PathLookup] = &[&SYMBOL_AS_STR, &SYMBOL_TO_IDENT_STRING, &paths::TO_STRING_METHOD];
         let call = if_chain! {
             if let ExprKind::AddrOf(_, _, e) = expr.kind;
             if let ExprKind::Unary(UnOp::Deref, e) = e.kind;
@@ -177,18 +182,18 @@ fn symbol_str_expr<'tcx>(&self, expr: &'tcx Expr<'tcx>, cx: &LateContext<'tcx>)
             if let Some(did) = cx.typeck_results().type_dependent_def_id(call.hir_id);
             let ty = cx.typeck_results().expr_ty(item);
             // ...on either an Ident or a Symbol
-            if let Some(is_ident) = if match_type(cx, ty, &paths::SYMBOL) {
+            if let Some(is_ident) = if SYMBOL.matches_ty(cx, ty) {
                 Some(false)
-            } else if match_type(cx, ty, &paths::IDENT) {
+            } else if IDENT.matches_ty(cx, ty) {
                 Some(true)
             } else {
                 None
             };
             // ...which converts it to a string
             let paths = if is_ident { IDENT_STR_PATHS } else { SYMBOL_STR_PATHS };
-            if let Some(path) = paths.iter().find(|path| match_def_path(cx, did, path));
+            if let Some(path) = paths.iter().find(|path| path.matches(cx, did));
             then {
-                let is_to_owned = path.last().unwrap().ends_with("string");
+                let is_to_owned = path.path().last().unwrap().ends_with("string");
                 return Some(SymbolStrExpr::Expr {
                     item,
                     is_ident,
@@ -1,10 +1,12 @@
 use clippy_utils::consts::{constant_simple, Constant};
 use clippy_utils::def_path_res;
 use clippy_utils::diagnostics::span_lint;
+use clippy_utils::paths::PathLookup;
 use if_chain::if_chain;
+use rustc_ast::LitKind;
 use rustc_hir as hir;
 use rustc_hir::def::DefKind;
-use rustc_hir::Item;
+use rustc_hir::{ExprKind, Item};
 use rustc_hir_analysis::hir_ty_to_ty;
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::ty::{self, fast_reject::SimplifiedType, FloatTy};
@@ -13,7 +15,7 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks the paths module for invalid paths.
+    /// Checks the paths module and `PathLookup` statics for invalid paths.
     ///
     /// ### Why is this bad?
     /// It indicates a bug in the code.
@@ -27,8 +29,15 @@
 
 declare_lint_pass!(InvalidPaths => [INVALID_PATHS]);
 
+static PATH_LOOKUP: PathLookup = PathLookup::new(&["clippy_utils", "paths", "PathLookup"]);
+
 impl<'tcx> LateLintPass<'tcx> for InvalidPaths {
     fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
+        if let hir::ItemKind::Static(ty, _, body_id) = item.kind {
+            check_path_lookup(cx, item, ty, body_id);
+            return;
+        }
+
         let local_def_id = &cx.tcx.parent_module(item.hir_id());
         let mod_name = &cx.tcx.item_name(local_def_id.to_def_id());
         if_chain! {
@@ -60,6 +69,30 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'_>) {
     }
 }
 
+fn check_path_lookup<'tcx>(cx: &LateContext<'tcx>, item: &'tcx Item<'_>, ty: &'tcx hir::Ty<'_>, body_id: hir::BodyId) {
+    if PATH_LOOKUP.matches_ty(cx, hir_ty_to_ty(cx.tcx, ty))
+        && let ExprKind::Call(_, [path_arg, ..]) = cx.tcx.hir().body(body_id).value.kind
+        && let ExprKind::AddrOf(_, _, path_arg) = path_arg.kind
+        && let ExprKind::Array(segments) = path_arg.kind
+        && let Some(path) = segments
+            .iter()
+            .map(|segment| match &segment.kind {
+                ExprKind::Lit(lit) => match lit.node {
+                    LitKind::Str(s, _) => Some(s),
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect::<Option<Vec<Symbol>>>()
+        // Internal lints do not know about all external crates
+        && let Some(&krate) = path.first()
+        && cx.tcx.crates(()).iter().any(|&num| cx.tcx.crate_name(num) == krate)
+        && !check_path(cx, &path.iter().map(Symbol::as_str).collect::<Vec<_>>())
+    {
+        span_lint(cx, INVALID_PATHS, item.span, "invalid path");
+    }
+}
+
 // This is not a complete resolver for paths. It works on all the paths currently used in the paths
 // module.  That's all it does and all it needs to do.
 pub fn check_path(cx: &LateContext<'_>, path: &[&str]) -> bool {
@@ -1,7 +1,7 @@
 use crate::utils::internal_lints::metadata_collector::is_deprecated_lint;
 use clippy_utils::diagnostics::{span_lint, span_lint_and_help};
 use clippy_utils::macros::root_macro_call_first_node;
-use clippy_utils::{is_lint_allowed, match_def_path, paths};
+use clippy_utils::{is_lint_allowed, paths};
 use if_chain::if_chain;
 use rustc_ast as ast;
 use rustc_ast::ast::LitKind;
@@ -266,7 +266,7 @@ pub(super) fn is_lint_ref_type(cx: &LateContext<'_>, ty: &hir::Ty<'_>) -> bool {
     {
         if let TyKind::Path(ref path) = inner.kind {
             if let Res::Def(DefKind::Struct, def_id) = cx.qpath_res(path, inner.hir_id) {
-                return match_def_path(cx, def_id, &paths::LINT);
+                return paths::LINT.matches(cx, def_id);
             }
         }
     }
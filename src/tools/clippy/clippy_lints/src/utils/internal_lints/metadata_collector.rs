@@ -11,8 +11,9 @@
 use crate::utils::internal_lints::lint_without_lint_pass::{extract_clippy_version_value, is_lint_ref_type};
 
 use clippy_utils::diagnostics::span_lint;
-use clippy_utils::ty::{match_type, walk_ptrs_ty_depth};
-use clippy_utils::{last_path_segment, match_def_path, match_function_call, match_path, paths};
+use clippy_utils::paths::{self, PathLookup};
+use clippy_utils::ty::walk_ptrs_ty_depth;
+use clippy_utils::{last_path_segment, match_def_path, match_function_call, match_path};
 use if_chain::if_chain;
 use itertools::Itertools;
 use rustc_ast as ast;
@@ -51,6 +52,9 @@
 const DEPRECATED_LINT_GROUP_STR: &str = "deprecated";
 /// This is the lint level for deprecated lints that will be displayed in the lint list
 const DEPRECATED_LINT_LEVEL: &str = "none";
+/// Used instead of the list of lints by configurations which apply to every lint, as in
+/// `Lint: ALL.`. They are documented on their own, not in the documentation of each lint.
+const ALL_LINTS: &str = "all";
 /// This array holds Clippy's lint groups with their corresponding default lint level. The
 /// lint level for deprecated lints is set in `DEPRECATED_LINT_LEVEL`.
 const DEFAULT_LINT_LEVELS: &[(&str, &str)] = &[
@@ -63,6 +67,7 @@
     ("perf", "warn"),
     ("cargo", "allow"),
     ("nursery", "allow"),
+    ("semver", "allow"),
 ];
 /// This prefix is in front of the lint groups in the lint store. The prefix will be trimmed
 /// to only keep the actual lint group in the output.
@@ -93,7 +98,17 @@
 ];
 const DEPRECATED_LINT_TYPE: [&str; 3] = ["clippy_lints", "deprecated_lints", "ClippyDeprecatedLint"];
 
-/// The index of the applicability name of `paths::APPLICABILITY_VALUES`
+static APPLICABILITY: PathLookup = PathLookup::new(&["rustc_lint_defs", "Applicability"]);
+static DIAGNOSTIC_BUILDER: PathLookup = PathLookup::new(&["rustc_errors", "diagnostic_builder", "DiagnosticBuilder"]);
+/// The `Applicability` variants, ordered from the lowest to the highest
+const APPLICABILITY_VALUES: [[&str; 3]; 4] = [
+    ["rustc_lint_defs", "Applicability", "Unspecified"],
+    ["rustc_lint_defs", "Applicability", "HasPlaceholders"],
+    ["rustc_lint_defs", "Applicability", "MaybeIncorrect"],
+    ["rustc_lint_defs", "Applicability", "MachineApplicable"],
+];
+
+/// The index of the applicability name of `APPLICABILITY_VALUES`
 const APPLICABILITY_NAME_INDEX: usize = 2;
 /// This applicability will be set for unresolved applicability values.
 const APPLICABILITY_UNRESOLVED_STR: &str = "Unresolved";
@@ -509,10 +524,7 @@ fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         let mut s = serializer.serialize_struct("ApplicabilityInfo", 2)?;
         s.serialize_field("is_multi_part_suggestion", &self.is_multi_part_suggestion)?;
         if let Some(index) = self.applicability {
-            s.serialize_field(
-                "applicability",
-                &paths::APPLICABILITY_VALUES[index][APPLICABILITY_NAME_INDEX],
-            )?;
+            s.serialize_field("applicability", &APPLICABILITY_VALUES[index][APPLICABILITY_NAME_INDEX])?;
         } else {
             s.serialize_field("applicability", APPLICABILITY_UNRESOLVED_STR)?;
         }
@@ -565,14 +577,21 @@ fn to_markdown_paragraph(&self) -> String {
                 .join("\n"),
             self.default,
             self.config_type,
-            self.lints
-                .iter()
-                .map(|name| name.to_string().split_whitespace().next().unwrap().to_string())
-                .map(|name| format!("* [{name}](https://rust-lang.github.io/rust-clippy/master/index.html#{name})"))
-                .join("\n"),
+            self.lints_to_markdown(),
         )
     }
 
+    fn lints_to_markdown(&self) -> String {
+        if self.lints.iter().any(|name| name == ALL_LINTS) {
+            return "Applies to every lint".to_string();
+        }
+        self.lints
+            .iter()
+            .map(|name| name.to_string().split_whitespace().next().unwrap().to_string())
+            .map(|name| format!("* [{name}](https://rust-lang.github.io/rust-clippy/master/index.html#{name})"))
+            .join("\n")
+    }
+
     fn to_markdown_table_entry(&self) -> String {
         format!("| [{}](#{}) | `{}` |", self.name, self.name, self.default)
     }
@@ -970,11 +989,11 @@ fn extract_emission_info<'hir>(
     for arg in args {
         let (arg_ty, _) = walk_ptrs_ty_depth(cx.typeck_results().expr_ty(arg));
 
-        if match_type(cx, arg_ty, &paths::LINT) {
+        if paths::LINT.matches_ty(cx, arg_ty) {
             // If we found the lint arg, extract the lint name
             let mut resolved_lints = resolve_lints(cx, arg);
             lints.append(&mut resolved_lints);
-        } else if match_type(cx, arg_ty, &paths::APPLICABILITY) {
+        } else if APPLICABILITY.matches_ty(cx, arg_ty) {
             applicability = resolve_applicability(cx, arg);
         } else if arg_ty.is_closure() {
             multi_part |= check_is_multi_part(cx, arg);
@@ -1043,7 +1062,7 @@ fn visit_expr(&mut self, expr: &'hir hir::Expr<'hir>) {
             if let QPath::Resolved(_, path) = qpath;
 
             let (expr_ty, _) = walk_ptrs_ty_depth(self.cx.typeck_results().expr_ty(expr));
-            if match_type(self.cx, expr_ty, &paths::LINT);
+            if paths::LINT.matches_ty(self.cx, expr_ty);
             then {
                 if let hir::def::Res::Def(DefKind::Static(..), _) = path.res {
                     let lint_name = last_path_segment(qpath).ident.name;
@@ -1063,7 +1082,7 @@ fn visit_expr(&mut self, expr: &'hir hir::Expr<'hir>) {
 /// This visitor finds the highest applicability value in the visited expressions
 struct ApplicabilityResolver<'a, 'hir> {
     cx: &'a LateContext<'hir>,
-    /// This is the index of highest `Applicability` for `paths::APPLICABILITY_VALUES`
+    /// This is the index of highest `Applicability` for `APPLICABILITY_VALUES`
     applicability_index: Option<usize>,
 }
 
@@ -1092,7 +1111,7 @@ fn nested_visit_map(&mut self) -> Self::Map {
     }
 
     fn visit_path(&mut self, path: &hir::Path<'hir>, _id: hir::HirId) {
-        for (index, enum_value) in paths::APPLICABILITY_VALUES.iter().enumerate() {
+        for (index, enum_value) in APPLICABILITY_VALUES.iter().enumerate() {
             if match_path(path, enum_value) {
                 self.add_new_index(index);
                 return;
@@ -1104,7 +1123,7 @@ fn visit_expr(&mut self, expr: &'hir hir::Expr<'hir>) {
         let (expr_ty, _) = walk_ptrs_ty_depth(self.cx.typeck_results().expr_ty(expr));
 
         if_chain! {
-            if match_type(self.cx, expr_ty, &paths::APPLICABILITY);
+            if APPLICABILITY.matches_ty(self.cx, expr_ty);
             if let Some(local) = get_parent_local(self.cx, expr);
             if let Some(local_init) = local.init;
             then {
@@ -1192,7 +1211,7 @@ fn visit_expr(&mut self, expr: &'hir hir::Expr<'hir>) {
             },
             ExprKind::MethodCall(path, recv, _, _arg_span) => {
                 let (self_ty, _) = walk_ptrs_ty_depth(self.cx.typeck_results().expr_ty(recv));
-                if match_type(self.cx, self_ty, &paths::DIAGNOSTIC_BUILDER) {
+                if DIAGNOSTIC_BUILDER.matches_ty(self.cx, self_ty) {
                     let called_method = path.ident.name.as_str().to_string();
                     for (method_name, is_multi_part) in &SUGGESTION_DIAGNOSTIC_BUILDER_METHODS {
                         if *method_name == called_method {
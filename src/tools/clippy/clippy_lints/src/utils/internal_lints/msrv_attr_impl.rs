@@ -1,7 +1,6 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
-use clippy_utils::source::snippet;
-use clippy_utils::ty::match_type;
-use clippy_utils::{match_def_path, paths};
+use clippy_utils::paths::PathLookup;
+use clippy_utils::source::{indent_of, snippet_with_applicability};
 use if_chain::if_chain;
 use rustc_errors::Applicability;
 use rustc_hir as hir;
@@ -21,6 +20,10 @@
 
 declare_lint_pass!(MsrvAttrImpl => [MISSING_MSRV_ATTR_IMPL]);
 
+static EARLY_LINT_PASS: PathLookup = PathLookup::new(&["rustc_lint", "passes", "EarlyLintPass"]);
+static LATE_LINT_PASS: PathLookup = PathLookup::new(&["rustc_lint", "passes", "LateLintPass"]);
+static MSRV: PathLookup = PathLookup::new(&["clippy_utils", "msrvs", "Msrv"]);
+
 impl LateLintPass<'_> for MsrvAttrImpl {
     fn check_item(&mut self, cx: &LateContext<'_>, item: &hir::Item<'_>) {
         if_chain! {
@@ -31,8 +34,8 @@ fn check_item(&mut self, cx: &LateContext<'_>, item: &hir::Item<'_>) {
                 ..
             }) = &item.kind;
             if let Some(lint_pass_trait_def_id) = lint_pass_trait_ref.trait_def_id();
-            let is_late_pass = match_def_path(cx, lint_pass_trait_def_id, &paths::LATE_LINT_PASS);
-            if is_late_pass || match_def_path(cx, lint_pass_trait_def_id, &paths::EARLY_LINT_PASS);
+            let is_late_pass = LATE_LINT_PASS.matches(cx, lint_pass_trait_def_id);
+            if is_late_pass || EARLY_LINT_PASS.matches(cx, lint_pass_trait_def_id);
             let self_ty = hir_ty_to_ty(cx.tcx, self_ty);
             if let ty::Adt(self_ty_def, _) = self_ty.kind();
             if self_ty_def.is_struct();
@@ -42,21 +45,24 @@ fn check_item(&mut self, cx: &LateContext<'_>, item: &hir::Item<'_>) {
                     .subst_identity()
                     .walk()
                     .filter(|t| matches!(t.unpack(), GenericArgKind::Type(_)))
-                    .any(|t| match_type(cx, t.expect_ty(), &paths::MSRV))
+                    .any(|t| MSRV.matches_ty(cx, t.expect_ty()))
             });
             if !items.iter().any(|item| item.ident.name == sym!(enter_lint_attrs));
             then {
                 let context = if is_late_pass { "LateContext" } else { "EarlyContext" };
                 let lint_pass = if is_late_pass { "LateLintPass" } else { "EarlyLintPass" };
                 let span = cx.sess().source_map().span_through_char(item.span, '{');
+                let mut app = Applicability::MachineApplicable;
+                let impl_snippet = snippet_with_applicability(cx, span, "..", &mut app);
+                let indent = " ".repeat(indent_of(cx, item.span).unwrap_or(0) + 4);
                 span_lint_and_sugg(
                     cx,
                     MISSING_MSRV_ATTR_IMPL,
                     span,
                     &format!("`extract_msrv_attr!` macro missing from `{lint_pass}` implementation"),
                     &format!("add `extract_msrv_attr!({context})` to the `{lint_pass}` implementation"),
-                    format!("{}\n    extract_msrv_attr!({context});", snippet(cx, span, "..")),
-                    Applicability::MachineApplicable,
+                    format!("{impl_snippet}\n{indent}extract_msrv_attr!({context});"),
+                    app,
                 );
             }
         }
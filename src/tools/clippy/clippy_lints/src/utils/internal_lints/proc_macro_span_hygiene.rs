@@ -0,0 +1,61 @@
+use crate::proc_macro_span_hygiene::TokenApis;
+use crate::utils::conf::{
+    simple_paths, DEFAULT_PROC_MACRO_CALL_SITE_SPANS, DEFAULT_PROC_MACRO_IDENT_CONSTRUCTORS,
+    DEFAULT_PROC_MACRO_TOKEN_STREAMS,
+};
+use rustc_hir::Expr;
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_tool_lint, impl_lint_pass};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// The internal variant of `proc_macro_derive_missing_span_hygiene`: checks for identifiers
+    /// built from runtime strings with `Span::call_site()`, and for token streams parsed from
+    /// runtime strings with `.parse().unwrap()`, with the `proc_macro` and `proc_macro2` APIs.
+    /// Unlike the user-facing lint, every crate is checked, not only proc-macro crates, as the
+    /// tokens may be built by helpers of the proc-macros.
+    ///
+    /// ### Why is this bad?
+    /// The generated tokens lose the spans of the user's tokens they were built from, so errors
+    /// point to the macro invocation instead of the user's identifiers.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let name = Ident::new(&format!("{name}_INFO"), Span::call_site());
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let name = Ident::new(&format!("{name}_INFO"), name.span());
+    /// ```
+    pub PROC_MACRO_MISSING_SPAN_HYGIENE,
+    internal,
+    "tokens built from strings at runtime, without the spans of the user's tokens"
+}
+
+pub struct ProcMacroSpanHygiene {
+    apis: TokenApis,
+}
+
+impl Default for ProcMacroSpanHygiene {
+    fn default() -> Self {
+        Self {
+            apis: TokenApis::new(
+                simple_paths(DEFAULT_PROC_MACRO_IDENT_CONSTRUCTORS),
+                simple_paths(DEFAULT_PROC_MACRO_CALL_SITE_SPANS),
+                simple_paths(DEFAULT_PROC_MACRO_TOKEN_STREAMS),
+            ),
+        }
+    }
+}
+
+impl_lint_pass!(ProcMacroSpanHygiene => [PROC_MACRO_MISSING_SPAN_HYGIENE]);
+
+impl<'tcx> LateLintPass<'tcx> for ProcMacroSpanHygiene {
+    fn check_crate(&mut self, cx: &LateContext<'tcx>) {
+        self.apis.resolve(cx);
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        self.apis.check_expr(cx, PROC_MACRO_MISSING_SPAN_HYGIENE, expr);
+    }
+}
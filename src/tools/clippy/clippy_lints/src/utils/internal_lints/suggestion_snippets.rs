@@ -0,0 +1,184 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::macros::{find_format_args, root_macro_call_first_node};
+use clippy_utils::visitors::for_each_expr;
+use clippy_utils::{expr_or_init, is_expr_path_def_path};
+use core::ops::ControlFlow;
+use rustc_ast::FormatArgsPiece;
+use rustc_hir::{Expr, ExprKind, QPath};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::{sym, Span};
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for suggestions built with `format!` from a string containing a line break, which
+    /// don't use any indentation.
+    ///
+    /// ### Why is this bad?
+    /// The lines after the first one start at the first column instead of being indented like
+    /// the code around them, so the fixed code is misformatted.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// span_lint_and_sugg(cx, LINT, span, msg, help, format!("{{\n{body}\n}}"), app);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let indent = snippet_indent(cx, span).unwrap_or_default();
+    /// span_lint_and_sugg(cx, LINT, span, msg, help, format!("{{\n{indent}{body}\n{indent}}}"), app);
+    /// ```
+    pub UNINDENTED_MULTILINE_SUGGESTION,
+    internal,
+    "multiline suggestions without indentation"
+}
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for `MachineApplicable` suggestions built from the result of
+    /// `clippy_utils::source::snippet`.
+    ///
+    /// ### Why is this bad?
+    /// `snippet` returns its default if the source isn't available, e.g. `".."`, which is
+    /// then applied to the code. `snippet_with_applicability` lowers the applicability in that
+    /// case.
+    ///
+    /// ### Example
+    /// ```rust,ignore
+    /// let recv = snippet(cx, recv.span, "..");
+    /// span_lint_and_sugg(cx, LINT, span, msg, help, format!("{recv}.len()"), Applicability::MachineApplicable);
+    /// ```
+    /// Use instead:
+    /// ```rust,ignore
+    /// let mut app = Applicability::MachineApplicable;
+    /// let recv = snippet_with_applicability(cx, recv.span, "..", &mut app);
+    /// span_lint_and_sugg(cx, LINT, span, msg, help, format!("{recv}.len()"), app);
+    /// ```
+    pub SNIPPET_IN_MACHINE_APPLICABLE_SUGGESTION,
+    internal,
+    "`MachineApplicable` suggestions built from `snippet`"
+}
+
+declare_lint_pass!(SuggestionSnippets => [UNINDENTED_MULTILINE_SUGGESTION, SNIPPET_IN_MACHINE_APPLICABLE_SUGGESTION]);
+
+impl<'tcx> LateLintPass<'tcx> for SuggestionSnippets {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let (sugg, applicability) = match expr.kind {
+            ExprKind::Call(func, [.., sugg, applicability])
+                if is_expr_path_def_path(cx, func, &["clippy_utils", "diagnostics", "span_lint_and_sugg"]) =>
+            {
+                (sugg, applicability)
+            },
+            ExprKind::MethodCall(path, _, [_, _, sugg, applicability], _)
+                if matches!(
+                    path.ident.as_str(),
+                    "span_suggestion" | "span_suggestion_verbose" | "span_suggestion_hidden" | "span_suggestion_short"
+                ) =>
+            {
+                (sugg, applicability)
+            },
+            _ => return,
+        };
+        let sugg = expr_or_init(cx, sugg);
+
+        if let Some(span) = unindented_line_break(cx, sugg) {
+            span_lint_and_help(
+                cx,
+                UNINDENTED_MULTILINE_SUGGESTION,
+                span,
+                "this suggestion has a line break, but no indentation",
+                None,
+                "indent the lines after the break, e.g. with `snippet_indent` or `reindent_multiline`",
+            );
+        }
+
+        if let ExprKind::Path(QPath::Resolved(_, path)) = applicability.kind
+            && path.segments.last().map_or(false, |seg| seg.ident.as_str() == "MachineApplicable")
+            && let Some(span) = snippet_call(cx, sugg)
+        {
+            span_lint_and_help(
+                cx,
+                SNIPPET_IN_MACHINE_APPLICABLE_SUGGESTION,
+                span,
+                "the result of `snippet` is used in a `MachineApplicable` suggestion",
+                None,
+                "use `snippet_with_applicability` to lower the applicability if the source isn't available",
+            );
+        }
+    }
+}
+
+/// The `clippy_utils::source` functions computing or applying an indentation
+const INDENT_HELPERS: [&[&str]; 5] = [
+    &["clippy_utils", "source", "indent_of"],
+    &["clippy_utils", "source", "snippet_indent"],
+    &["clippy_utils", "source", "reindent_multiline"],
+    &["clippy_utils", "source", "snippet_block"],
+    &["clippy_utils", "source", "snippet_block_with_applicability"],
+];
+
+/// Checks if `sugg` is a `format!` call whose format string contains a line break, and none of
+/// whose arguments is computed with one of the [`INDENT_HELPERS`]. Returns the span of the
+/// `format!` call.
+fn unindented_line_break(cx: &LateContext<'_>, sugg: &Expr<'_>) -> Option<Span> {
+    let macro_call = root_macro_call_first_node(cx, sugg)?;
+    if !cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id) {
+        return None;
+    }
+    let mut unindented = false;
+    find_format_args(cx, sugg, macro_call.expn, |format_args| {
+        let has_line_break = format_args
+            .template
+            .iter()
+            .any(|piece| matches!(piece, FormatArgsPiece::Literal(lit) if lit.as_str().contains('\n')));
+        unindented = has_line_break
+            && !format_args.arguments.all_args().iter().any(|arg| {
+                // the expressions of `format_args` are AST nodes, find the HIR arguments of the call
+                find_hir_arg(sugg, arg.expr.span).map_or(true, |arg| uses_indent_helper(cx, arg))
+            });
+    });
+    unindented.then_some(macro_call.span)
+}
+
+/// Finds the HIR expression of a `format!` argument with the span `span` in the expansion `sugg`
+fn find_hir_arg<'a>(sugg: &'a Expr<'a>, span: Span) -> Option<&'a Expr<'a>> {
+    for_each_expr(sugg, |e| {
+        if e.span == span {
+            ControlFlow::Break(e)
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+}
+
+/// Checks if `expr` calls one of the [`INDENT_HELPERS`], also looking into the initializers of
+/// the locals it uses.
+fn uses_indent_helper(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    for_each_expr(expr, |e| {
+        let init = expr_or_init(cx, e);
+        if let ExprKind::Call(func, _) = init.kind
+            && INDENT_HELPERS.iter().any(|path| is_expr_path_def_path(cx, func, path))
+        {
+            return ControlFlow::Break(());
+        }
+        if init.hir_id != e.hir_id && uses_indent_helper(cx, init) {
+            return ControlFlow::Break(());
+        }
+        ControlFlow::Continue(())
+    })
+    .is_some()
+}
+
+/// Finds a call to `clippy_utils::source::snippet` in `sugg`, or in the initializers of the
+/// locals it uses.
+fn snippet_call(cx: &LateContext<'_>, sugg: &Expr<'_>) -> Option<Span> {
+    for_each_expr(sugg, |e| {
+        let e = expr_or_init(cx, e);
+        if let ExprKind::Call(func, _) = e.kind
+            && is_expr_path_def_path(cx, func, &["clippy_utils", "source", "snippet"])
+        {
+            ControlFlow::Break(e.span)
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+}
@@ -1,17 +1,16 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::higher::{get_vec_init_kind, VecInitKind};
 use clippy_utils::source::snippet;
+use clippy_utils::stmt_seq::{find_stmt_seqs, BlockItem, StmtPat};
 use clippy_utils::visitors::for_each_local_use_after_expr;
 use clippy_utils::{get_parent_expr, path_to_local_id};
 use core::ops::ControlFlow;
 use rustc_errors::Applicability;
 use rustc_hir::def::Res;
-use rustc_hir::{
-    BindingAnnotation, Block, Expr, ExprKind, HirId, Local, Mutability, PatKind, QPath, Stmt, StmtKind, UnOp,
-};
+use rustc_hir::{BindingAnnotation, Block, ExprKind, HirId, Mutability, PatKind, QPath, StmtKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass, LintContext};
 use rustc_middle::lint::in_external_macro;
-use rustc_session::{declare_tool_lint, impl_lint_pass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
 use rustc_span::{Span, Symbol};
 
 declare_clippy_lint! {
@@ -44,12 +43,7 @@
     "`push` immediately after `Vec` creation"
 }
 
-impl_lint_pass!(VecInitThenPush => [VEC_INIT_THEN_PUSH]);
-
-#[derive(Default)]
-pub struct VecInitThenPush {
-    searcher: Option<VecPushSearcher>,
-}
+declare_lint_pass!(VecInitThenPush => [VEC_INIT_THEN_PUSH]);
 
 struct VecPushSearcher {
     local_id: HirId,
@@ -62,6 +56,52 @@ struct VecPushSearcher {
     last_push_expr: HirId,
 }
 impl VecPushSearcher {
+    /// Creates the searcher for a statement initializing a local with a new `Vec`.
+    fn from_init<'tcx>(cx: &LateContext<'tcx>, item: BlockItem<'tcx>) -> Option<Self> {
+        let BlockItem::Stmt(stmt) = item else {
+            return None;
+        };
+        if let StmtKind::Local(local) = stmt.kind
+            && let Some(init_expr) = local.init
+            && let PatKind::Binding(BindingAnnotation::MUT, id, name, None) = local.pat.kind
+            && !in_external_macro(cx.sess(), local.span)
+            && let Some(init) = get_vec_init_kind(cx, init_expr)
+            && !matches!(init, VecInitKind::WithExprCapacity(_))
+        {
+            Some(Self {
+                local_id: id,
+                init,
+                lhs_is_let: true,
+                name: name.name,
+                let_ty_span: local.ty.map(|ty| ty.span),
+                err_span: local.span,
+                found: 0,
+                last_push_expr: init_expr.hir_id,
+            })
+        } else if let StmtKind::Expr(expr) | StmtKind::Semi(expr) = stmt.kind
+            && let ExprKind::Assign(left, right, _) = expr.kind
+            && let ExprKind::Path(QPath::Resolved(None, path)) = left.kind
+            && let [name] = &path.segments
+            && let Res::Local(id) = path.res
+            && !in_external_macro(cx.sess(), expr.span)
+            && let Some(init) = get_vec_init_kind(cx, right)
+            && !matches!(init, VecInitKind::WithExprCapacity(_))
+        {
+            Some(Self {
+                local_id: id,
+                init,
+                lhs_is_let: false,
+                let_ty_span: None,
+                name: name.ident.name,
+                err_span: expr.span,
+                found: 0,
+                last_push_expr: expr.hir_id,
+            })
+        } else {
+            None
+        }
+    }
+
     fn display_err(&self, cx: &LateContext<'_>) {
         let required_pushes_before_extension = match self.init {
             _ if self.found == 0 => return,
@@ -151,75 +191,48 @@ fn display_err(&self, cx: &LateContext<'_>) {
 }
 
 impl<'tcx> LateLintPass<'tcx> for VecInitThenPush {
-    fn check_block(&mut self, _: &LateContext<'tcx>, _: &'tcx Block<'tcx>) {
-        self.searcher = None;
-    }
-
-    fn check_local(&mut self, cx: &LateContext<'tcx>, local: &'tcx Local<'tcx>) {
-        if let Some(init_expr) = local.init
-            && let PatKind::Binding(BindingAnnotation::MUT, id, name, None) = local.pat.kind
-            && !in_external_macro(cx.sess(), local.span)
-            && let Some(init) = get_vec_init_kind(cx, init_expr)
-            && !matches!(init, VecInitKind::WithExprCapacity(_))
-        {
-            self.searcher = Some(VecPushSearcher {
-                local_id: id,
-                init,
-                lhs_is_let: true,
-                name: name.name,
-                let_ty_span: local.ty.map(|ty| ty.span),
-                err_span: local.span,
-                found: 0,
-                last_push_expr: init_expr.hir_id,
-            });
-        }
-    }
-
-    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        if self.searcher.is_none()
-            && let ExprKind::Assign(left, right, _) = expr.kind
-            && let ExprKind::Path(QPath::Resolved(None, path)) = left.kind
-            && let [name] = &path.segments
-            && let Res::Local(id) = path.res
-            && !in_external_macro(cx.sess(), expr.span)
-            && let Some(init) = get_vec_init_kind(cx, right)
-            && !matches!(init, VecInitKind::WithExprCapacity(_))
-        {
-            self.searcher = Some(VecPushSearcher {
-                local_id: id,
-                init,
-                lhs_is_let: false,
-                let_ty_span: None,
-                name: name.ident.name,
-                err_span: expr.span,
-                found: 0,
-                last_push_expr: expr.hir_id,
-            });
-        }
-    }
+    fn check_block(&mut self, cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>) {
+        let pats = [
+            // `let mut v = Vec::new();` or `v = Vec::new();`
+            StmtPat::one(|caps, item| {
+                if let Some(searcher) = VecPushSearcher::from_init(cx, item) {
+                    caps.bind("vec", searcher.local_id);
+                    true
+                } else {
+                    false
+                }
+            }),
+            // `v.push(x);`
+            StmtPat::many(|caps, item| {
+                if let BlockItem::Stmt(stmt) = item
+                    && let StmtKind::Expr(expr) | StmtKind::Semi(expr) = stmt.kind
+                    && let ExprKind::MethodCall(name, self_arg, [_], _) = expr.kind
+                    && name.ident.as_str() == "push"
+                {
+                    path_to_local_id(self_arg, caps.local("vec"))
+                } else {
+                    false
+                }
+            }),
+        ];
 
-    fn check_stmt(&mut self, cx: &LateContext<'tcx>, stmt: &'tcx Stmt<'_>) {
-        if let Some(searcher) = self.searcher.take() {
-            if let StmtKind::Expr(expr) | StmtKind::Semi(expr) = stmt.kind
-                && let ExprKind::MethodCall(name, self_arg, [_], _) = expr.kind
-                && path_to_local_id(self_arg, searcher.local_id)
-                && name.ident.as_str() == "push"
+        for m in find_stmt_seqs(cx, block, &pats) {
+            let [init, pushes] = &m.parts[..] else {
+                continue;
+            };
+            if let [init] = init[..]
+                && let Some(searcher) = VecPushSearcher::from_init(cx, init)
+                && let Some(&last_push) = pushes.last()
+                && let Some(last_push_expr) = last_push.expr()
             {
-                self.searcher = Some(VecPushSearcher {
-                    found: searcher.found + 1,
-                    err_span: searcher.err_span.to(stmt.span),
-                    last_push_expr: expr.hir_id,
-                    .. searcher
-                });
-            } else {
-                searcher.display_err(cx);
+                VecPushSearcher {
+                    found: pushes.len() as u128,
+                    err_span: searcher.err_span.to(last_push.span()),
+                    last_push_expr: last_push_expr.hir_id,
+                    ..searcher
+                }
+                .display_err(cx);
             }
         }
     }
-
-    fn check_block_post(&mut self, cx: &LateContext<'tcx>, _: &'tcx Block<'tcx>) {
-        if let Some(searcher) = self.searcher.take() {
-            searcher.display_err(cx);
-        }
-    }
 }
@@ -0,0 +1,177 @@
+use clippy_utils::consts::{constant, Constant};
+use clippy_utils::diagnostics::{span_lint_and_note, span_lint_and_then};
+use clippy_utils::higher::VecArgs;
+use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_hir::{Expr, ExprKind, FieldDef, Node};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty::{self, GenericArgKind, Ty};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for nested heap allocations with a wasteful layout:
+    /// - struct fields of type `Vec<Vec<T>>` or `Vec<Box<[T]>>` which are initialized with inner
+    ///   collections of the same constant length,
+    /// - `Box<[Box<str>]>` anywhere in a field's type.
+    ///
+    /// ### Why is this bad?
+    /// Each inner collection is a separate allocation with its own pointer and length. When every
+    /// inner collection has the same length, `Vec<[T; N]>` stores all the elements in a single
+    /// allocation. A boxed slice of boxed strings likewise allocates every string separately.
+    ///
+    /// ### Known problems
+    /// Only the initializers of struct literals are checked for the inner length. The field may
+    /// still be given inner collections of a different length elsewhere.
+    ///
+    /// ### Example
+    /// ```rust
+    /// struct Grid {
+    ///     cells: Vec<Vec<u8>>,
+    /// }
+    ///
+    /// fn new_grid(rows: usize) -> Grid {
+    ///     Grid { cells: vec![vec![0; 8]; rows] }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// struct Grid {
+    ///     cells: Vec<[u8; 8]>,
+    /// }
+    ///
+    /// fn new_grid(rows: usize) -> Grid {
+    ///     Grid { cells: vec![[0; 8]; rows] }
+    /// }
+    /// ```
+    #[clippy::version = "1.70.0"]
+    pub VEC_OF_BOXED_SLICES,
+    pedantic,
+    "nested heap allocations which could be a single allocation"
+}
+declare_lint_pass!(VecOfBoxedSlices => [VEC_OF_BOXED_SLICES]);
+
+impl<'tcx> LateLintPass<'tcx> for VecOfBoxedSlices {
+    fn check_field_def(&mut self, cx: &LateContext<'tcx>, field: &'tcx FieldDef<'_>) {
+        if !field.span.from_expansion()
+            && contains_boxed_slice_of_boxed_str(cx.tcx.type_of(field.def_id).subst_identity())
+        {
+            span_lint_and_note(
+                cx,
+                VEC_OF_BOXED_SLICES,
+                field.ty.span,
+                "`Box<[Box<str>]>` allocates every string separately",
+                None,
+                "consider concatenating the strings into a single `String` and storing the ranges of each one",
+            );
+        }
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        if let ExprKind::Struct(qpath, fields, _) = expr.kind
+            && !expr.span.from_expansion()
+            && let ty::Adt(adt, _) = cx.typeck_results().expr_ty(expr).kind()
+        {
+            let variant = adt.variant_of_res(cx.qpath_res(qpath, expr.hir_id));
+            for field in fields {
+                if let Some(field_did) = variant
+                    .fields
+                    .iter()
+                    .find(|f| f.name == field.ident.name)
+                    .and_then(|f| f.did.as_local())
+                    && let Node::Field(field_def) = cx.tcx.hir().get_by_def_id(field_did)
+                    && let Some((inner, elem_ty)) = nested_collection(cx, cx.tcx.type_of(field_did).subst_identity())
+                    && let Some(len) = constant_inner_len(cx, field.expr)
+                {
+                    span_lint_and_then(
+                        cx,
+                        VEC_OF_BOXED_SLICES,
+                        field.expr.span.source_callsite(),
+                        &format!("every inner `{inner}` of this field has the same constant length"),
+                        |diag| {
+                            diag.span_help(
+                                field_def.ty.span,
+                                format!("consider storing the elements inline: `Vec<[{elem_ty}; {len}]>`"),
+                            );
+                            diag.note(format!("each inner `{inner}` is a separate heap allocation"));
+                        },
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// If `ty` is `Vec<Vec<T>>` or `Vec<Box<[T]>>`, returns the name of the inner collection and `T`.
+fn nested_collection<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> Option<(&'static str, Ty<'tcx>)> {
+    let ty::Adt(_, substs) = ty.kind() else {
+        return None;
+    };
+    if !is_type_diagnostic_item(cx, ty, sym::Vec) {
+        return None;
+    }
+    let inner = substs.type_at(0);
+    if is_type_diagnostic_item(cx, inner, sym::Vec)
+        && let ty::Adt(_, inner_substs) = inner.kind()
+    {
+        Some(("Vec", inner_substs.type_at(0)))
+    } else if inner.is_box()
+        && let ty::Slice(elem_ty) = inner.boxed_ty().kind()
+    {
+        Some(("Box<[_]>", *elem_ty))
+    } else {
+        None
+    }
+}
+
+fn contains_boxed_slice_of_boxed_str(ty: Ty<'_>) -> bool {
+    ty.walk().any(|arg| {
+        if let GenericArgKind::Type(ty) = arg.unpack() {
+            ty.is_box()
+                && matches!(ty.boxed_ty().kind(), ty::Slice(elem_ty) if elem_ty.is_box() && elem_ty.boxed_ty().is_str())
+        } else {
+            false
+        }
+    })
+}
+
+/// Returns the length shared by all the inner collections of `vec![inner; n]` or
+/// `vec![inner1, inner2, ..]`, if it's known to be a nonzero constant.
+fn constant_inner_len(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<u128> {
+    match VecArgs::hir(cx, e)? {
+        VecArgs::Repeat(inner, _) => constant_len(cx, inner),
+        VecArgs::Vec([first, rest @ ..]) => {
+            let len = constant_len(cx, first)?;
+            rest.iter().all(|e| constant_len(cx, e) == Some(len)).then_some(len)
+        },
+        VecArgs::Vec([]) => None,
+    }
+}
+
+/// Returns the length of `vec![x; N]`, `vec![a, b, ..]`, or a boxed array, if it's a nonzero
+/// constant.
+fn constant_len(cx: &LateContext<'_>, e: &Expr<'_>) -> Option<u128> {
+    let len = if let Some(vec_args) = VecArgs::hir(cx, e) {
+        match vec_args {
+            VecArgs::Repeat(_, len) => match constant(cx, cx.typeck_results(), len)? {
+                (Constant::Int(len), _) => len,
+                _ => return None,
+            },
+            VecArgs::Vec(elems) => elems.len() as u128,
+        }
+    } else if let ExprKind::MethodCall(path, recv, [], _) = e.kind
+        && path.ident.name.as_str() == "into_boxed_slice"
+    {
+        return constant_len(cx, recv);
+    } else {
+        let ty = cx.typeck_results().expr_ty(e);
+        if ty.is_box()
+            && let ty::Array(_, len) = ty.boxed_ty().kind()
+        {
+            len.try_eval_target_usize(cx.tcx, cx.param_env)?.into()
+        } else {
+            return None;
+        }
+    };
+    (len != 0).then_some(len)
+}
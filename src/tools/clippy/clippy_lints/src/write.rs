@@ -1,7 +1,7 @@
 use clippy_utils::diagnostics::{span_lint, span_lint_and_then};
+use clippy_utils::is_in_test;
 use clippy_utils::macros::{find_format_args, format_arg_removal_span, root_macro_call_first_node, MacroCall};
 use clippy_utils::source::{expand_past_previous_comma, snippet_opt};
-use clippy_utils::{is_in_cfg_test, is_in_test_function};
 use rustc_ast::token::LitKind;
 use rustc_ast::{FormatArgPosition, FormatArgs, FormatArgsPiece, FormatOptions, FormatPlaceholder, FormatTrait};
 use rustc_errors::Applicability;
@@ -234,13 +234,15 @@
 #[derive(Default)]
 pub struct Write {
     in_debug_impl: bool,
-    allow_print_in_tests: bool,
+    allow_print_stdout_in_tests: bool,
+    allow_print_stderr_in_tests: bool,
 }
 
 impl Write {
-    pub fn new(allow_print_in_tests: bool) -> Self {
+    pub fn new(allow_print_stdout_in_tests: bool, allow_print_stderr_in_tests: bool) -> Self {
         Self {
-            allow_print_in_tests,
+            allow_print_stdout_in_tests,
+            allow_print_stderr_in_tests,
             ..Default::default()
         }
     }
@@ -283,15 +285,14 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
             .as_ref()
             .map_or(false, |crate_name| crate_name == "build_script_build");
 
-        let allowed_in_tests = self.allow_print_in_tests
-            && (is_in_test_function(cx.tcx, expr.hir_id) || is_in_cfg_test(cx.tcx, expr.hir_id));
+        let allowed_in_tests = |allowed: bool| allowed && is_in_test(cx.tcx, expr.hir_id);
         match diag_name {
-            sym::print_macro | sym::println_macro if !allowed_in_tests => {
+            sym::print_macro | sym::println_macro if !allowed_in_tests(self.allow_print_stdout_in_tests) => {
                 if !is_build_script {
                     span_lint(cx, PRINT_STDOUT, macro_call.span, &format!("use of `{name}!`"));
                 }
             },
-            sym::eprint_macro | sym::eprintln_macro if !allowed_in_tests => {
+            sym::eprint_macro | sym::eprintln_macro if !allowed_in_tests(self.allow_print_stderr_in_tests) => {
                 span_lint(cx, PRINT_STDERR, macro_call.span, &format!("use of `{name}!`"));
             },
             sym::write_macro | sym::writeln_macro => {},
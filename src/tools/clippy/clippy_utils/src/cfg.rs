@@ -0,0 +1,113 @@
+//! Utilities for analyzing the `#[cfg]` conditions items are compiled under.
+
+use rustc_ast::{MetaItemKind, NestedMetaItem};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_hir::def_id::LocalDefId;
+use rustc_middle::ty::TyCtxt;
+use rustc_span::{sym, Span, Symbol};
+
+/// A parsed `cfg` predicate.
+#[derive(Debug, Clone)]
+pub enum CfgPredicate {
+    /// A predicate which doesn't parse, e.g. `foo(unix)` or `feature = 1`. rustc reports it.
+    Malformed,
+    /// A configuration option, e.g. `unix` or `feature = "serde"`.
+    Cfg {
+        name: Symbol,
+        value: Option<Symbol>,
+        span: Span,
+    },
+    All(Vec<CfgPredicate>),
+    Any(Vec<CfgPredicate>),
+    Not(Box<CfgPredicate>),
+}
+
+impl CfgPredicate {
+    /// Parses a predicate, e.g. the argument of `#[cfg(..)]`. The malformed parts of it are
+    /// `Malformed`, so that the rest can still be checked.
+    pub fn parse(item: &NestedMetaItem) -> Self {
+        let Some(meta) = item.meta_item() else {
+            return Self::Malformed;
+        };
+        let Some(name) = meta.ident().map(|ident| ident.name) else {
+            return Self::Malformed;
+        };
+        match &meta.kind {
+            MetaItemKind::Word => Self::Cfg {
+                name,
+                value: None,
+                span: meta.span,
+            },
+            MetaItemKind::NameValue(_) => meta.value_str().map_or(Self::Malformed, |value| Self::Cfg {
+                name,
+                value: Some(value),
+                span: meta.span,
+            }),
+            MetaItemKind::List(list) => match (name, &**list) {
+                (sym::all, _) => Self::All(list.iter().map(Self::parse).collect()),
+                (sym::any, _) => Self::Any(list.iter().map(Self::parse).collect()),
+                (sym::not, [inner]) => Self::Not(Box::new(Self::parse(inner))),
+                _ => Self::Malformed,
+            },
+        }
+    }
+
+    /// Calls `f` on each configuration option of the predicate, however deeply it is nested.
+    pub fn for_each_option(&self, f: &mut impl FnMut(Symbol, Option<Symbol>, Span)) {
+        match self {
+            Self::Malformed => {},
+            &Self::Cfg { name, value, span } => f(name, value, span),
+            Self::All(list) | Self::Any(list) => {
+                for predicate in list {
+                    predicate.for_each_option(f);
+                }
+            },
+            Self::Not(inner) => inner.for_each_option(f),
+        }
+    }
+}
+
+/// Collects the predicates of the `#[cfg]` attributes on the item and on all of its parents, from
+/// the innermost to the outermost.
+pub fn cfgs_along_def_path(tcx: TyCtxt<'_>, def_id: LocalDefId) -> Vec<NestedMetaItem> {
+    let mut cfgs = Vec::new();
+    let mut current = Some(def_id);
+    while let Some(id) = current {
+        for attr in tcx.get_attrs(id.to_def_id(), sym::cfg) {
+            if let Some(list) = attr.meta_item_list() {
+                cfgs.extend(list);
+            }
+        }
+        current = tcx.opt_local_parent(id);
+    }
+    cfgs
+}
+
+/// Collects the features which have to be enabled for the item to be compiled, i.e. the
+/// `feature = "..."` predicates of its `#[cfg]` attributes and of its parents' which aren't
+/// nested in an `any` or a `not`.
+pub fn required_features(tcx: TyCtxt<'_>, def_id: LocalDefId) -> FxHashSet<Symbol> {
+    fn collect(predicate: &CfgPredicate, features: &mut FxHashSet<Symbol>) {
+        match predicate {
+            CfgPredicate::Cfg {
+                name: sym::feature,
+                value: Some(feature),
+                ..
+            } => {
+                features.insert(*feature);
+            },
+            CfgPredicate::All(list) => {
+                for predicate in list {
+                    collect(predicate, features);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    let mut features = FxHashSet::default();
+    for predicate in cfgs_along_def_path(tcx, def_id).iter().map(CfgPredicate::parse) {
+        collect(&predicate, &mut features);
+    }
+    features
+}
@@ -2,7 +2,9 @@
 
 #![deny(clippy::missing_docs_in_private_items)]
 
+use crate::{clip, int_bits};
 use rustc_hir::{BinOpKind, Expr};
+use rustc_middle::ty::{self, Ty, TyCtxt};
 
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 /// Represent a normalized comparison operator.
@@ -34,3 +36,31 @@ pub fn normalize_comparison<'a>(
         _ => None,
     }
 }
+
+/// Evaluates `lhs rel rhs` where the values of both sides are only known to lie in the given
+/// inclusive ranges. Returns the result if it's the same for all values in the ranges.
+pub fn compare_ranges(rel: Rel, (lhs_min, lhs_max): (i128, i128), (rhs_min, rhs_max): (i128, i128)) -> Option<bool> {
+    match rel {
+        Rel::Lt if lhs_max < rhs_min => Some(true),
+        Rel::Lt if lhs_min >= rhs_max => Some(false),
+        Rel::Le if lhs_max <= rhs_min => Some(true),
+        Rel::Le if lhs_min > rhs_max => Some(false),
+        Rel::Eq | Rel::Ne if lhs_max < rhs_min || rhs_max < lhs_min => Some(rel == Rel::Ne),
+        Rel::Eq | Rel::Ne if lhs_min == lhs_max && rhs_min == rhs_max && lhs_min == rhs_min => Some(rel == Rel::Eq),
+        _ => None,
+    }
+}
+
+/// Gets the inclusive range of values of an integer or `bool` type. The maximum of `u128` is
+/// clamped to `i128::MAX`.
+pub fn int_range(tcx: TyCtxt<'_>, ty: Ty<'_>) -> Option<(i128, i128)> {
+    match *ty.kind() {
+        ty::Bool => Some((0, 1)),
+        ty::Int(ity) => {
+            let max = i128::MAX >> (128 - int_bits(tcx, ity));
+            Some((-max - 1, max))
+        },
+        ty::Uint(uty) => Some((0, i128::try_from(clip(tcx, u128::MAX, uty)).unwrap_or(i128::MAX))),
+        _ => None,
+    }
+}
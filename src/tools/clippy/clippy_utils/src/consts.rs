@@ -4,7 +4,8 @@
 use if_chain::if_chain;
 use rustc_ast::ast::{self, LitFloatType, LitKind};
 use rustc_data_structures::sync::Lrc;
-use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def::{CtorKind, CtorOf, DefKind, Res};
+use rustc_hir::def_id::DefId;
 use rustc_hir::{BinOp, BinOpKind, Block, Expr, ExprKind, HirId, Item, ItemKind, Node, QPath, UnOp};
 use rustc_lint::LateContext;
 use rustc_middle::mir;
@@ -347,6 +348,11 @@ pub fn expr(&mut self, e: &Expr<'_>) -> Option<Constant> {
             }),
             ExprKind::If(cond, then, ref otherwise) => self.ifthenelse(cond, then, *otherwise),
             ExprKind::Binary(op, left, right) => self.binop(op, left, right),
+            ExprKind::Call(callee, args) if !args.is_empty() => {
+                let ExprKind::Path(qpath) = &callee.kind else { return None };
+                let def_id = self.typeck_results.qpath_res(qpath, callee.hir_id).opt_def_id()?;
+                self.int_method_call(def_id, args, self.typeck_results.expr_ty(e))
+            },
             ExprKind::Call(callee, args) => {
                 // We only handle a few const functions for now.
                 if_chain! {
@@ -374,6 +380,15 @@ pub fn expr(&mut self, e: &Expr<'_>) -> Option<Constant> {
             },
             ExprKind::Index(arr, index) => self.index(arr, index),
             ExprKind::AddrOf(_, _, inner) => self.expr(inner).map(|r| Constant::Ref(Box::new(r))),
+            ExprKind::Cast(inner, _) => self.cast(inner, self.typeck_results.expr_ty(e)),
+            ExprKind::Field(base, field) => match self.expr(base)? {
+                Constant::Tuple(fields) => fields.into_iter().nth(field.as_str().parse().ok()?),
+                _ => None,
+            },
+            ExprKind::MethodCall(_, recv, args, _) => {
+                let def_id = self.typeck_results.type_dependent_def_id(e.hir_id)?;
+                self.int_method_call(def_id, iter::once(recv).chain(args), self.typeck_results.expr_ty(e))
+            },
             // TODO: add other expressions.
             _ => None,
         }
@@ -413,6 +428,97 @@ fn constant_negate(&self, o: &Constant, ty: Ty<'_>) -> Option<Constant> {
         }
     }
 
+    /// Evaluates a numeric cast, or the cast of a fieldless enum variant to an integer.
+    #[expect(
+        clippy::cast_possible_truncation,
+        clippy::cast_possible_wrap,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    fn cast(&mut self, inner: &Expr<'_>, ty: Ty<'_>) -> Option<Constant> {
+        let tcx = self.lcx.tcx;
+        let value = match self.expr(inner) {
+            Some(Constant::Int(value)) => match *self.typeck_results.expr_ty(inner).kind() {
+                ty::Int(ity) => FullInt::S(sext(tcx, value, ity)),
+                _ => FullInt::U(value),
+            },
+            Some(Constant::Bool(b)) => FullInt::U(u128::from(b)),
+            Some(Constant::Char(c)) => FullInt::U(u128::from(c)),
+            Some(Constant::F32(f)) => {
+                return match *ty.kind() {
+                    ty::Float(FloatTy::F32) => Some(Constant::F32(f)),
+                    ty::Float(FloatTy::F64) => Some(Constant::F64(f.into())),
+                    _ => None,
+                };
+            },
+            Some(Constant::F64(f)) => {
+                return match *ty.kind() {
+                    ty::Float(FloatTy::F32) => Some(Constant::F32(f as f32)),
+                    ty::Float(FloatTy::F64) => Some(Constant::F64(f)),
+                    _ => None,
+                };
+            },
+            Some(_) => return None,
+            None => self.discriminant(inner)?,
+        };
+        match (ty.kind(), value) {
+            (&ty::Int(ity), FullInt::S(value)) => Some(Constant::Int(unsext(tcx, value, ity))),
+            (&ty::Int(ity), FullInt::U(value)) => Some(Constant::Int(unsext(tcx, value as i128, ity))),
+            (&ty::Uint(ity), FullInt::S(value)) => Some(Constant::Int(clip(tcx, value as u128, ity))),
+            (&ty::Uint(ity), FullInt::U(value)) => Some(Constant::Int(clip(tcx, value, ity))),
+            (&ty::Float(FloatTy::F32), FullInt::S(value)) => Some(Constant::F32(value as f32)),
+            (&ty::Float(FloatTy::F32), FullInt::U(value)) => Some(Constant::F32(value as f32)),
+            (&ty::Float(FloatTy::F64), FullInt::S(value)) => Some(Constant::F64(value as f64)),
+            (&ty::Float(FloatTy::F64), FullInt::U(value)) => Some(Constant::F64(value as f64)),
+            _ => None,
+        }
+    }
+
+    /// Gets the discriminant of a path to a fieldless enum variant.
+    fn discriminant(&self, e: &Expr<'_>) -> Option<FullInt> {
+        let ExprKind::Path(ref qpath) = e.kind else { return None };
+        let Res::Def(DefKind::Ctor(CtorOf::Variant, CtorKind::Const), ctor_id) =
+            self.typeck_results.qpath_res(qpath, e.hir_id) else { return None };
+        let ty::Adt(adt, _) = self.typeck_results.expr_ty(e).kind() else { return None };
+        let discr = adt.discriminant_for_variant(self.lcx.tcx, adt.variant_index_with_ctor_id(ctor_id));
+        // `Discr::val` holds the bits of the enum's representation
+        Some(match *discr.ty.kind() {
+            ty::Int(ity) => FullInt::S(sext(self.lcx.tcx, discr.val, ity)),
+            _ => FullInt::U(discr.val),
+        })
+    }
+
+    /// Evaluates calls to a few const integer methods, e.g. `2u32.pow(3)` or `i32::abs(-1)`.
+    /// Overflowing results aren't folded.
+    fn int_method_call<'e>(
+        &mut self,
+        def_id: DefId,
+        args: impl IntoIterator<Item = &'e Expr<'e>>,
+        ty: Ty<'_>,
+    ) -> Option<Constant> {
+        let tcx = self.lcx.tcx;
+        let impl_id = tcx.impl_of_method(def_id)?;
+        if tcx.trait_id_of_impl(impl_id).is_some() || !tcx.type_of(impl_id).subst_identity().is_integral() {
+            return None;
+        }
+        let args = args.into_iter().map(|arg| self.expr(arg)).collect::<Option<Vec<_>>>()?;
+        let signed = |value: i128, ity| {
+            let bits = unsext(tcx, value, ity);
+            (sext(tcx, bits, ity) == value).then_some(Constant::Int(bits))
+        };
+        let unsigned = |value: u128, ity| (clip(tcx, value, ity) == value).then_some(Constant::Int(value));
+        match (tcx.item_name(def_id).as_str(), &*args, ty.kind()) {
+            ("pow", &[Constant::Int(base), Constant::Int(exp)], &ty::Int(ity)) => {
+                signed(sext(tcx, base, ity).checked_pow(exp.try_into().ok()?)?, ity)
+            },
+            ("pow", &[Constant::Int(base), Constant::Int(exp)], &ty::Uint(ity)) => {
+                unsigned(base.checked_pow(exp.try_into().ok()?)?, ity)
+            },
+            ("abs", &[Constant::Int(value)], &ty::Int(ity)) => signed(sext(tcx, value, ity).checked_abs()?, ity),
+            _ => None,
+        }
+    }
+
     /// Create `Some(Vec![..])` of all constants, unless there is any
     /// non-constant part.
     fn multi(&mut self, vec: &[Expr<'_>]) -> Option<Vec<Constant>> {
@@ -457,7 +563,21 @@ fn fetch_path(&mut self, qpath: &QPath<'_>, id: HirId, ty: Ty<'tcx>) -> Option<C
                     )
                     .ok()
                     .map(|val| rustc_middle::mir::ConstantKind::from_value(val, ty))?;
-                let result = miri_to_const(self.lcx.tcx, result);
+                // Aggregates can't be read back from the evaluated value, so fold the initializer of
+                // local constants instead. Trait constants are skipped, the impl may override them.
+                let result = miri_to_const(self.lcx.tcx, result).or_else(|| {
+                    let tcx = self.lcx.tcx;
+                    if tcx.trait_of_item(def_id).is_some()
+                        || tcx
+                            .impl_of_method(def_id)
+                            .and_then(|id| tcx.trait_id_of_impl(id))
+                            .is_some()
+                    {
+                        return None;
+                    }
+                    let body_id = tcx.hir().maybe_body_owned_by(def_id.as_local()?)?;
+                    constant_context(self.lcx, tcx.typeck_body(body_id)).expr(tcx.hir().body(body_id).value)
+                });
                 if result.is_some() {
                     self.needed_resolution = true;
                 }
@@ -473,11 +593,8 @@ fn index(&mut self, lhs: &'_ Expr<'_>, index: &'_ Expr<'_>) -> Option<Constant>
         let index = self.expr(index);
 
         match (lhs, index) {
-            (Some(Constant::Vec(vec)), Some(Constant::Int(index))) => match vec.get(index as usize) {
-                Some(Constant::F32(x)) => Some(Constant::F32(*x)),
-                Some(Constant::F64(x)) => Some(Constant::F64(*x)),
-                _ => None,
-            },
+            (Some(Constant::Vec(vec)), Some(Constant::Int(index))) => vec.into_iter().nth(index.try_into().ok()?),
+            (Some(Constant::Repeat(value, n)), Some(Constant::Int(index))) if index < u128::from(n) => Some(*value),
             (Some(Constant::Vec(vec)), _) => {
                 if !vec.is_empty() && vec.iter().all(|x| *x == vec[0]) {
                     match vec.get(0) {
@@ -8,9 +8,16 @@
 //! Thank you!
 //! ~The `INTERNAL_METADATA_COLLECTOR` lint
 
+use crate::any_parent_is_automatically_derived;
+use crate::generated_code::is_suppressed_in_generated_code;
+use crate::lint_scope::is_out_of_scope;
+use crate::source::sugg_with_comments;
 use rustc_errors::{Applicability, Diagnostic, MultiSpan};
 use rustc_hir::HirId;
-use rustc_lint::{LateContext, Lint, LintContext};
+use rustc_lexer::{tokenize, TokenKind};
+use rustc_lint::{EarlyContext, LateContext, Lint, LintContext};
+use rustc_session::Session;
+use rustc_span::hygiene::ExpnKind;
 use rustc_span::source_map::Span;
 use std::env;
 
@@ -28,6 +35,110 @@ fn docs_link(diag: &mut Diagnostic, lint: &'static Lint) {
     }
 }
 
+/// A lint context which knows about the node it is currently linting.
+pub trait ClippyLintContext: LintContext {
+    /// Checks if the node is in an `#[automatically_derived]` item.
+    fn is_automatically_derived(&self) -> bool;
+    /// Checks if the lint isn't emitted in the file of `span`, see [`is_out_of_scope`].
+    fn is_out_of_scope(&self, lint: &'static Lint, span: Option<Span>) -> bool;
+}
+
+impl ClippyLintContext for LateContext<'_> {
+    fn is_automatically_derived(&self) -> bool {
+        any_parent_is_automatically_derived(self.tcx, self.last_node_with_lint_attrs)
+    }
+
+    // The driver already allows the lint on the items of the file
+    fn is_out_of_scope(&self, _: &'static Lint, _: Option<Span>) -> bool {
+        false
+    }
+}
+
+// Derived items can only be seen after expansion
+impl ClippyLintContext for EarlyContext<'_> {
+    fn is_automatically_derived(&self) -> bool {
+        false
+    }
+
+    fn is_out_of_scope(&self, lint: &'static Lint, span: Option<Span>) -> bool {
+        is_out_of_scope(self.sess(), lint, span)
+    }
+}
+
+/// Checks if the lint shouldn't be emitted at `sp` for the node linted by `cx`.
+fn is_suppressed(cx: &impl ClippyLintContext, lint: &'static Lint, sp: &MultiSpan) -> bool {
+    let span = sp.primary_span();
+    cx.is_out_of_scope(lint, span)
+        || is_suppressed_in_generated_code(cx.sess(), cx.lints(), lint, span, || cx.is_automatically_derived())
+}
+
+/// Checks if the lint shouldn't be emitted at `sp` for the node `hir_id`.
+fn is_suppressed_hir(cx: &LateContext<'_>, lint: &'static Lint, hir_id: HirId, sp: &MultiSpan) -> bool {
+    is_suppressed_in_generated_code(cx.sess(), cx.lints(), lint, sp.primary_span(), || {
+        any_parent_is_automatically_derived(cx.tcx, hir_id)
+    })
+}
+
+/// Lowers `MachineApplicable` suggestions to `MaybeIncorrect` if applying them could break the
+/// code, so individual lints don't have to remember to check for this.
+fn downgrade_risky_suggestions(sess: &Session, diag: &mut Diagnostic) {
+    let Ok(suggestions) = &mut diag.suggestions else {
+        return;
+    };
+    for sugg in suggestions {
+        if sugg.applicability == Applicability::MachineApplicable
+            && sugg
+                .substitutions
+                .iter()
+                .flat_map(|subst| &subst.parts)
+                .any(|part| !is_safe_to_edit(sess, part.span))
+        {
+            sugg.applicability = Applicability::MaybeIncorrect;
+        }
+    }
+}
+
+/// Checks that the span doesn't come from a macro expansion or another crate, and that it doesn't
+/// contain `cfg`'d code which the lint couldn't have seen.
+fn is_safe_to_edit(sess: &Session, span: Span) -> bool {
+    if span.is_dummy() {
+        return true;
+    }
+    // Desugared code is still written by the user
+    if span.from_expansion() && !matches!(span.ctxt().outer_expn_data().kind, ExpnKind::Desugaring(_)) {
+        return false;
+    }
+    let sm = sess.source_map();
+    if sm.lookup_source_file(span.lo()).is_imported() {
+        return false;
+    }
+    sm.span_to_snippet(span).map_or(true, |snip| !contains_cfg_attr(&snip))
+}
+
+/// Checks if the source contains a `#[cfg(..)]` or `#![cfg(..)]` attribute.
+fn contains_cfg_attr(src: &str) -> bool {
+    let mut pos = 0;
+    let tokens: Vec<_> = tokenize(src)
+        .filter_map(|token| {
+            let text = &src[pos..pos + token.len as usize];
+            pos += token.len as usize;
+            (!matches!(
+                token.kind,
+                TokenKind::Whitespace | TokenKind::LineComment { .. } | TokenKind::BlockComment { .. }
+            ))
+            .then_some((token.kind, text))
+        })
+        .collect();
+    tokens.iter().enumerate().any(|(i, (kind, _))| {
+        let rest = &tokens[i + 1..];
+        let rest = match rest {
+            [(TokenKind::Bang, _), rest @ ..] => rest,
+            rest => rest,
+        };
+        *kind == TokenKind::Pound && matches!(rest, [(TokenKind::OpenBracket, _), (TokenKind::Ident, "cfg"), ..])
+    })
+}
+
 /// Emit a basic lint message with a `msg` and a `span`.
 ///
 /// This is the most primitive of our lint emission methods and can
@@ -45,7 +156,11 @@ fn docs_link(diag: &mut Diagnostic, lint: &'static Lint) {
 /// 17 |     std::mem::forget(seven);
 ///    |     ^^^^^^^^^^^^^^^^^^^^^^^
 /// ```
-pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: &str) {
+pub fn span_lint<T: ClippyLintContext>(cx: &T, lint: &'static Lint, sp: impl Into<MultiSpan>, msg: &str) {
+    let sp = sp.into();
+    if is_suppressed(cx, lint, &sp) {
+        return;
+    }
     cx.struct_span_lint(lint, sp, msg, |diag| {
         docs_link(diag, lint);
         diag
@@ -72,7 +187,7 @@ pub fn span_lint<T: LintContext>(cx: &T, lint: &'static Lint, sp: impl Into<Mult
 ///    |
 ///    = help: consider using `f64::NAN` if you would like a constant representing NaN
 /// ```
-pub fn span_lint_and_help<T: LintContext>(
+pub fn span_lint_and_help<T: ClippyLintContext>(
     cx: &T,
     lint: &'static Lint,
     span: impl Into<MultiSpan>,
@@ -80,6 +195,10 @@ pub fn span_lint_and_help<T: LintContext>(
     help_span: Option<Span>,
     help: &str,
 ) {
+    let span = span.into();
+    if is_suppressed(cx, lint, &span) {
+        return;
+    }
     cx.struct_span_lint(lint, span, msg, |diag| {
         if let Some(help_span) = help_span {
             diag.span_help(help_span, help);
@@ -114,7 +233,7 @@ pub fn span_lint_and_help<T: LintContext>(
 /// 10 |     forget(&SomeStruct);
 ///    |            ^^^^^^^^^^^
 /// ```
-pub fn span_lint_and_note<T: LintContext>(
+pub fn span_lint_and_note<T: ClippyLintContext>(
     cx: &T,
     lint: &'static Lint,
     span: impl Into<MultiSpan>,
@@ -122,6 +241,10 @@ pub fn span_lint_and_note<T: LintContext>(
     note_span: Option<Span>,
     note: &str,
 ) {
+    let span = span.into();
+    if is_suppressed(cx, lint, &span) {
+        return;
+    }
     cx.struct_span_lint(lint, span, msg, |diag| {
         if let Some(note_span) = note_span {
             diag.span_note(note_span, note);
@@ -136,21 +259,32 @@ pub fn span_lint_and_note<T: LintContext>(
 /// Like `span_lint` but allows to add notes, help and suggestions using a closure.
 ///
 /// If you need to customize your lint output a lot, use this function.
+///
+/// `MachineApplicable` suggestions become `MaybeIncorrect` if they edit code from a macro expansion
+/// or another crate, or code containing `cfg` attributes.
 /// If you change the signature, remember to update the internal lint `CollapsibleCalls`
 pub fn span_lint_and_then<C, S, F>(cx: &C, lint: &'static Lint, sp: S, msg: &str, f: F)
 where
-    C: LintContext,
+    C: ClippyLintContext,
     S: Into<MultiSpan>,
     F: FnOnce(&mut Diagnostic),
 {
+    let sp = sp.into();
+    if is_suppressed(cx, lint, &sp) {
+        return;
+    }
     cx.struct_span_lint(lint, sp, msg, |diag| {
         f(diag);
+        downgrade_risky_suggestions(cx.sess(), diag);
         docs_link(diag, lint);
         diag
     });
 }
 
 pub fn span_lint_hir(cx: &LateContext<'_>, lint: &'static Lint, hir_id: HirId, sp: Span, msg: &str) {
+    if is_suppressed_hir(cx, lint, hir_id, &sp.into()) {
+        return;
+    }
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, msg, |diag| {
         docs_link(diag, lint);
         diag
@@ -165,8 +299,13 @@ pub fn span_lint_hir_and_then(
     msg: &str,
     f: impl FnOnce(&mut Diagnostic),
 ) {
+    let sp = sp.into();
+    if is_suppressed_hir(cx, lint, hir_id, &sp) {
+        return;
+    }
     cx.tcx.struct_span_lint_hir(lint, hir_id, sp, msg, |diag| {
         f(diag);
+        downgrade_risky_suggestions(cx.sess(), diag);
         docs_link(diag, lint);
         diag
     });
@@ -178,6 +317,9 @@ pub fn span_lint_hir_and_then(
 /// In the example below, `help` is `"try"` and `sugg` is the suggested replacement `".any(|x| x >
 /// 2)"`.
 ///
+/// Comments within `sp` which would be lost are moved in front of the suggestion if possible (see
+/// [`sugg_with_comments`]). Otherwise a `MachineApplicable` suggestion becomes `MaybeIncorrect`.
+///
 /// If you change the signature, remember to update the internal lint `CollapsibleCalls`
 ///
 /// # Example
@@ -192,15 +334,21 @@ pub fn span_lint_hir_and_then(
 ///     = note: `-D fold-any` implied by `-D warnings`
 /// ```
 #[cfg_attr(feature = "internal", allow(clippy::collapsible_span_lint_calls))]
-pub fn span_lint_and_sugg<T: LintContext>(
+pub fn span_lint_and_sugg<T: ClippyLintContext>(
     cx: &T,
     lint: &'static Lint,
     sp: Span,
     msg: &str,
     help: &str,
     sugg: String,
-    applicability: Applicability,
+    mut applicability: Applicability,
 ) {
+    let sugg = sugg_with_comments(cx, sp, &sugg).unwrap_or_else(|| {
+        if applicability == Applicability::MachineApplicable {
+            applicability = Applicability::MaybeIncorrect;
+        }
+        sugg
+    });
     span_lint_and_then(cx, lint, sp, msg, |diag| {
         diag.span_suggestion(sp, help, sugg, applicability);
     });
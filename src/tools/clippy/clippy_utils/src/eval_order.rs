@@ -0,0 +1,131 @@
+//! Utilities for checking whether an expression's evaluation can be duplicated, removed or moved
+//! around without changing the program's behavior.
+//!
+//! See lints:
+//!  - get-last-with-len
+//!  - needless-bitwise-bool
+
+use crate::path_res;
+use crate::ty::{is_type_diagnostic_item, is_type_lang_item};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_expr, Visitor};
+use rustc_hir::{BorrowKind, Expr, ExprKind, LangItem, Mutability, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::sym;
+
+/// Checks if evaluating the expression has no side effects, and gives the same value each time as
+/// long as nothing it reads is written to in between.
+///
+/// This is conservative. The only functions which may be called are `const fn`s, constructors and
+/// a few getters from the standard library (e.g. `len` or `is_empty`), and the only overloaded
+/// operators are indexing and dereferencing of standard library types. Assignments, mutable
+/// borrows, dereferences of raw pointers and reads of mutable statics are rejected. Panics, e.g.
+/// from an out of bounds index, are not considered side effects.
+pub fn is_expr_pure<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>) -> bool {
+    struct V<'a, 'tcx> {
+        cx: &'a LateContext<'tcx>,
+        is_pure: bool,
+    }
+    impl<'tcx> Visitor<'tcx> for V<'_, 'tcx> {
+        fn visit_expr(&mut self, e: &'tcx Expr<'_>) {
+            if !self.is_pure {
+                return;
+            }
+            let typeck = self.cx.typeck_results();
+
+            // Auto-deref through a user defined `Deref` impl is a function call.
+            let mut ty = typeck.expr_ty(e);
+            for adjustment in typeck.expr_adjustments(e) {
+                if matches!(adjustment.kind, Adjust::Deref(Some(_))) && !is_std_adt(self.cx, ty) {
+                    self.is_pure = false;
+                    return;
+                }
+                ty = adjustment.target;
+            }
+
+            self.is_pure = match e.kind {
+                ExprKind::Call(callee, _) => match path_res(self.cx, callee) {
+                    Res::Def(DefKind::Ctor(..), _) | Res::SelfCtor(_) => true,
+                    Res::Def(DefKind::Fn | DefKind::AssocFn, id) => self.cx.tcx.is_const_fn_raw(id),
+                    _ => false,
+                },
+                ExprKind::MethodCall(..) => typeck.type_dependent_def_id(e.hir_id).map_or(false, |id| {
+                    self.cx.tcx.is_const_fn_raw(id) || is_pure_std_method(self.cx, id)
+                }),
+                ExprKind::Unary(UnOp::Deref, inner) if typeck.is_method_call(e) => {
+                    is_std_adt(self.cx, typeck.expr_ty(inner))
+                },
+                ExprKind::Unary(UnOp::Deref, inner) => !typeck.expr_ty(inner).is_unsafe_ptr(),
+                ExprKind::Unary(..) | ExprKind::Binary(..) => !typeck.is_method_call(e),
+                ExprKind::Index(base, _) if typeck.is_method_call(e) => {
+                    let base_ty = typeck.expr_ty(base).peel_refs();
+                    matches!(base_ty.kind(), ty::Slice(_) | ty::Str | ty::Array(..))
+                        || is_type_diagnostic_item(self.cx, base_ty, sym::Vec)
+                        || is_type_diagnostic_item(self.cx, base_ty, sym::VecDeque)
+                        || is_type_lang_item(self.cx, base_ty, LangItem::String)
+                },
+                ExprKind::Path(ref p) => !matches!(
+                    self.cx.qpath_res(p, e.hir_id),
+                    Res::Def(DefKind::Static(Mutability::Mut), _)
+                ),
+                ExprKind::AddrOf(BorrowKind::Ref, mutability, _) => mutability == Mutability::Not,
+                ExprKind::Block(block, _) => block.stmts.is_empty(),
+                ExprKind::Array(_)
+                | ExprKind::Cast(..)
+                | ExprKind::Closure(_)
+                | ExprKind::ConstBlock(_)
+                | ExprKind::DropTemps(_)
+                | ExprKind::Field(..)
+                | ExprKind::If(..)
+                | ExprKind::Index(..)
+                | ExprKind::Let(..)
+                | ExprKind::Lit(_)
+                | ExprKind::Match(..)
+                | ExprKind::Repeat(..)
+                | ExprKind::Struct(..)
+                | ExprKind::Tup(_)
+                | ExprKind::Type(..) => true,
+                _ => false,
+            };
+            if self.is_pure {
+                walk_expr(self, e);
+            }
+        }
+    }
+
+    let mut v = V { cx, is_pure: true };
+    v.visit_expr(e);
+    v.is_pure
+}
+
+fn is_std_crate(cx: &LateContext<'_>, id: DefId) -> bool {
+    matches!(cx.tcx.crate_name(id.krate), sym::std | sym::core | sym::alloc)
+}
+
+fn is_std_adt(cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+    matches!(ty.kind(), ty::Adt(def, _) if is_std_crate(cx, def.did()))
+}
+
+/// Checks if the function is an inherent method of a standard library type which only reads from
+/// its receiver and never calls into user code.
+fn is_pure_std_method(cx: &LateContext<'_>, id: DefId) -> bool {
+    let Some(impl_id) = cx.tcx.impl_of_method(id) else {
+        return false;
+    };
+    if !is_std_crate(cx, id) || cx.tcx.trait_id_of_impl(impl_id).is_some() {
+        return false;
+    }
+    match cx.tcx.item_name(id).as_str() {
+        "len" | "is_empty" | "capacity" | "is_some" | "is_none" | "is_ok" | "is_err" | "as_ref" | "as_slice"
+        | "as_str" | "as_bytes" | "as_ptr" | "is_ascii" | "is_char_boundary" => true,
+        // `HashMap::get` and friends hash the key
+        "get" | "first" | "last" => {
+            let self_ty = cx.tcx.type_of(impl_id).subst_identity();
+            matches!(self_ty.kind(), ty::Slice(_) | ty::Str) || is_type_diagnostic_item(cx, self_ty, sym::VecDeque)
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,96 @@
+//! Detection of generated code, in which the lints of some groups aren't emitted.
+
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_lint::{Lint, LintId, LintStore};
+use rustc_session::Session;
+use rustc_span::hygiene::{ExpnKind, MacroKind, SyntaxContext};
+use rustc_span::{FileName, Span};
+use std::sync::{Mutex, OnceLock};
+
+static CONFIG: OnceLock<GeneratedCodeConfig> = OnceLock::new();
+/// The lints of the groups in `CONFIG`.
+static SUPPRESSED_LINTS: OnceLock<FxHashSet<LintId>> = OnceLock::new();
+/// Whether each file contains the marker.
+static MARKED_FILES: OnceLock<Mutex<FxHashMap<FileName, bool>>> = OnceLock::new();
+
+/// Lints which check derived code on purpose.
+const DERIVE_LINTS: &[&str] = &[
+    "clippy::derive_ord_xor_partial_ord",
+    "clippy::derive_partial_eq_without_eq",
+    "clippy::derived_hash_with_manual_eq",
+    "clippy::unsafe_derive_deserialize",
+];
+
+/// Which code counts as generated, and the lint groups which aren't emitted in it.
+#[derive(Debug)]
+pub struct GeneratedCodeConfig {
+    /// Files containing the marker in one of their first `marker_lines` lines are generated.
+    pub marker: String,
+    pub marker_lines: usize,
+    /// The lint groups, without the `clippy::` prefix.
+    pub lint_groups: Vec<String>,
+}
+
+/// Sets the configuration. Only the first call has an effect.
+pub fn set_generated_code_config(config: GeneratedCodeConfig) {
+    let _ = CONFIG.set(config);
+}
+
+/// Checks if the lint shouldn't be emitted at `span` because it is in generated code. The node
+/// being linted is checked by `is_automatically_derived`.
+pub(crate) fn is_suppressed_in_generated_code(
+    sess: &Session,
+    store: &LintStore,
+    lint: &'static Lint,
+    span: Option<Span>,
+    is_automatically_derived: impl FnOnce() -> bool,
+) -> bool {
+    let Some(config) = CONFIG.get() else {
+        return false;
+    };
+    let suppressed = SUPPRESSED_LINTS.get_or_init(|| {
+        store
+            .get_lint_groups()
+            .filter(|(name, ..)| {
+                name.strip_prefix("clippy::")
+                    .map_or(false, |name| config.lint_groups.iter().any(|group| group == name))
+            })
+            .flat_map(|(_, lints, _)| lints)
+            .filter(|lint| !DERIVE_LINTS.contains(&&*lint.lint.name_lower()))
+            .collect()
+    });
+    if !suppressed.contains(&LintId::of(lint)) {
+        return false;
+    }
+    span.map_or(false, |span| is_from_external_derive(span) || is_in_marked_file(sess, config, span))
+        || is_automatically_derived()
+}
+
+/// Checks if the span comes from the expansion of a derive macro defined in another crate.
+pub fn is_from_external_derive(span: Span) -> bool {
+    let mut ctxt = span.ctxt();
+    while ctxt != SyntaxContext::root() {
+        let data = ctxt.outer_expn_data();
+        if matches!(data.kind, ExpnKind::Macro(MacroKind::Derive, _))
+            && data.macro_def_id.map_or(false, |def_id| !def_id.is_local())
+        {
+            return true;
+        }
+        ctxt = data.call_site.ctxt();
+    }
+    false
+}
+
+/// Checks if one of the first lines of the span's file contains the marker.
+fn is_in_marked_file(sess: &Session, config: &GeneratedCodeConfig, span: Span) -> bool {
+    let file = sess.source_map().lookup_source_file(span.lo());
+    let cache = MARKED_FILES.get_or_init(|| Mutex::new(FxHashMap::default()));
+    let mut cache = cache.lock().unwrap();
+    *cache.entry(file.name.clone()).or_insert_with(|| {
+        file.src.as_ref().map_or(false, |src| {
+            src.lines()
+                .take(config.marker_lines)
+                .any(|line| line.contains(&config.marker))
+        })
+    })
+}
@@ -3,8 +3,9 @@
 #![deny(clippy::missing_docs_in_private_items)]
 
 use crate::consts::{constant_simple, Constant};
+use crate::is_expn_of;
+use crate::paths::{self, PathLookup};
 use crate::ty::is_type_diagnostic_item;
-use crate::{is_expn_of, match_def_path, paths};
 use if_chain::if_chain;
 use rustc_ast::ast;
 use rustc_hir as hir;
@@ -272,6 +273,10 @@ pub enum VecArgs<'a> {
     Vec(&'a [hir::Expr<'a>]),
 }
 
+static SLICE_INTO_VEC: PathLookup = PathLookup::new(&["slice", "into_vec"]);
+static VEC_FROM_ELEM: PathLookup = PathLookup::new(&["alloc", "vec", "from_elem"]);
+static VEC_NEW: PathLookup = PathLookup::new(&["alloc", "vec", "Vec", "new"]);
+
 impl<'a> VecArgs<'a> {
     /// Returns the arguments of the `vec!` macro if this expression was expanded
     /// from `vec!`.
@@ -282,10 +287,10 @@ pub fn hir(cx: &LateContext<'_>, expr: &'a hir::Expr<'_>) -> Option<VecArgs<'a>>
             if is_expn_of(fun.span, "vec").is_some();
             if let Some(fun_def_id) = cx.qpath_res(qpath, fun.hir_id).opt_def_id();
             then {
-                return if match_def_path(cx, fun_def_id, &paths::VEC_FROM_ELEM) && args.len() == 2 {
+                return if VEC_FROM_ELEM.matches(cx, fun_def_id) && args.len() == 2 {
                     // `vec![elem; size]` case
                     Some(VecArgs::Repeat(&args[0], &args[1]))
-                } else if match_def_path(cx, fun_def_id, &paths::SLICE_INTO_VEC) && args.len() == 1 {
+                } else if SLICE_INTO_VEC.matches(cx, fun_def_id) && args.len() == 1 {
                     // `vec![a, b, c]` case
                     if let hir::ExprKind::Call(_, [arg]) = &args[0].kind
                         && let hir::ExprKind::Array(args) = arg.kind {
@@ -293,7 +298,7 @@ pub fn hir(cx: &LateContext<'_>, expr: &'a hir::Expr<'_>) -> Option<VecArgs<'a>>
                     } else {
                         None
                     }
-                } else if match_def_path(cx, fun_def_id, &paths::VEC_NEW) && args.is_empty() {
+                } else if VEC_NEW.matches(cx, fun_def_id) && args.is_empty() {
                     Some(VecArgs::Vec(&[]))
                 } else {
                     None
@@ -454,7 +459,7 @@ pub fn get_vec_init_kind<'tcx>(cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) -
                 };
             },
             ExprKind::Path(QPath::Resolved(_, path))
-                if match_def_path(cx, path.res.opt_def_id()?, &paths::DEFAULT_TRAIT_METHOD)
+                if paths::DEFAULT_TRAIT_METHOD.matches(cx, path.res.opt_def_id()?)
                     && is_type_diagnostic_item(cx, cx.typeck_results().expr_ty(expr), sym::Vec) =>
             {
                 return Some(VecInitKind::Default);
@@ -44,25 +44,33 @@
 
 pub mod ast_utils;
 pub mod attrs;
+pub mod cfg;
 mod check_proc_macro;
 pub mod comparisons;
 pub mod consts;
 pub mod diagnostics;
 pub mod eager_or_lazy;
+pub mod eval_order;
+pub mod generated_code;
 pub mod higher;
 mod hir_utils;
+pub mod lint_scope;
 pub mod macros;
 pub mod mir;
 pub mod msrvs;
 pub mod numeric_literal;
+pub mod panic_analysis;
 pub mod paths;
 pub mod ptr;
 pub mod qualify_min_const_fn;
+pub mod result_unwraps;
 pub mod source;
+pub mod stmt_seq;
 pub mod str_utils;
 pub mod sugg;
 pub mod ty;
 pub mod usage;
+pub mod value_range;
 pub mod visitors;
 
 pub use self::attrs::*;
@@ -107,6 +115,7 @@
     layout::IntegerExt, BorrowKind, ClosureKind, Ty, TyCtxt, TypeAndMut, TypeVisitableExt, UpvarCapture,
 };
 use rustc_middle::ty::{FloatTy, IntTy, UintTy};
+use rustc_semver::RustcVersion;
 use rustc_span::hygiene::{ExpnKind, MacroKind};
 use rustc_span::source_map::SourceMap;
 use rustc_span::sym;
@@ -115,7 +124,12 @@
 use rustc_target::abi::Integer;
 
 use crate::consts::{constant, Constant};
-use crate::ty::{can_partially_move_ty, expr_sig, is_copy, is_recursively_primitive_type, ty_is_fn_once_param};
+use crate::msrvs::Msrv;
+use crate::paths::PathLookup;
+use crate::ty::{
+    can_partially_move_ty, expr_sig, is_copy, is_recursively_primitive_type, is_type_diagnostic_item,
+    ty_is_fn_once_param,
+};
 use crate::visitors::for_each_expr;
 
 use rustc_middle::hir::nested_filter;
@@ -1200,34 +1214,82 @@ pub fn method_calls<'tcx>(expr: &'tcx Expr<'tcx>, max_depth: usize) -> (Vec<Symb
     (method_names, arg_lists, spans)
 }
 
-/// Matches an `Expr` against a chain of methods, and return the matched `Expr`s.
+/// A method call matched by [`match_method_chain`] or [`method_chain!`].
+#[derive(Clone, Copy, Debug)]
+pub struct ChainLink<'tcx> {
+    /// The whole method call expression.
+    pub expr: &'tcx Expr<'tcx>,
+    pub receiver: &'tcx Expr<'tcx>,
+    pub args: &'tcx [Expr<'tcx>],
+    /// The span of the method name.
+    pub name_span: Span,
+}
+
+/// Matches an `Expr` against a chain of method names, and returns the matched calls in the same
+/// order as `names`.
 ///
-/// For example, if `expr` represents the `.baz()` in `foo.bar().baz()`,
-/// `method_chain_args(expr, &["bar", "baz"])` will return a `Vec`
-/// containing the `Expr`s for
-/// `.bar()` and `.baz()`
-pub fn method_chain_args<'a>(expr: &'a Expr<'_>, methods: &[&str]) -> Option<Vec<(&'a Expr<'a>, &'a [Expr<'a>])>> {
+/// The names are checked starting with the outermost call, so this returns quickly for most
+/// expressions. Chains where a receiver or an argument comes from a macro expansion are not
+/// matched.
+///
+/// For example, if `expr` is `foo.bar().baz()`, `match_method_chain(expr, ["bar", "baz"])` returns
+/// the calls to `bar` and `baz`.
+pub fn match_method_chain<'tcx, const N: usize>(
+    expr: &'tcx Expr<'tcx>,
+    names: [&str; N],
+) -> Option<[ChainLink<'tcx>; N]> {
+    let mut links = [None; N];
     let mut current = expr;
-    let mut matched = Vec::with_capacity(methods.len());
-    for method_name in methods.iter().rev() {
-        // method chains are stored last -> first
-        if let ExprKind::MethodCall(path, receiver, args, _) = current.kind {
-            if path.ident.name.as_str() == *method_name {
-                if receiver.span.from_expansion() || args.iter().any(|e| e.span.from_expansion()) {
-                    return None;
-                }
-                matched.push((receiver, args)); // build up `matched` backwards
-                current = receiver; // go to parent expression
-            } else {
-                return None;
-            }
-        } else {
+    for (link, name) in links.iter_mut().zip(names).rev() {
+        let ExprKind::MethodCall(path, receiver, args, _) = current.kind else {
+            return None;
+        };
+        if path.ident.name.as_str() != name
+            || receiver.span.from_expansion()
+            || args.iter().any(|e| e.span.from_expansion())
+        {
             return None;
         }
+        *link = Some(ChainLink {
+            expr: current,
+            receiver,
+            args,
+            name_span: path.ident.span,
+        });
+        current = receiver;
     }
-    // Reverse `matched` so that it is in the same order as `methods`.
-    matched.reverse();
-    Some(matched)
+    Some(links.map(Option::unwrap))
+}
+
+/// Matches an `Expr` against a chain of method calls, and returns the matched calls as an
+/// `Option<[ChainLink; N]>` in source order.
+///
+/// Each call is written as the method name followed by a pattern for each argument, and may be
+/// followed by `where` and a predicate on the (unadjusted) type of its receiver. The predicates
+/// are only run once all the names have matched.
+///
+/// ```rust,ignore
+/// if let Some([iter, _, count]) = method_chain!(cx, expr, [
+///     iter() where |ty: Ty<'_>| is_type_diagnostic_item(cx, ty.peel_refs(), sym::Vec),
+///     filter(_),
+///     count(),
+/// ]) {
+///     // `iter.receiver` is the vector, `count.expr` is the whole chain
+/// }
+/// ```
+#[macro_export]
+macro_rules! method_chain {
+    ($cx:expr, $expr:expr, [$($name:ident($($arg:pat),* $(,)?) $(where $pred:expr)?),+ $(,)?]) => {
+        $crate::match_method_chain($expr, [$(stringify!($name)),+]).filter(|links| {
+            let mut links = links.iter();
+            let matched = $({
+                let link = links.next().unwrap();
+                matches!(link.args, [$($arg),*])
+                    $(&& ($pred)($cx.typeck_results().expr_ty(link.receiver)))?
+            })&&+;
+            matched
+        })
+    };
 }
 
 /// Returns `true` if the provided `def_id` is an entrypoint to a program.
@@ -1734,6 +1796,26 @@ pub fn is_lint_allowed(cx: &LateContext<'_>, lint: &'static Lint, id: HirId) ->
     cx.tcx.lint_level_at_node(lint, id).0 == Level::Allow
 }
 
+/// Checks if the library item can be used with the current MSRV and enabled features. Stable items
+/// have to be stable since a version the MSRV meets, unstable ones need their feature enabled.
+pub fn is_item_usable(cx: &LateContext<'_>, def_id: DefId, msrv: &Msrv) -> bool {
+    let Some(stability) = cx.tcx.lookup_stability(def_id) else { return true };
+    match stability.level {
+        rustc_attr::StabilityLevel::Stable { since, .. } => {
+            // `CURRENT_RUSTC_VERSION` can result in versions like `1.66.0-dev`, which aren't
+            // accepted by `rustc-semver`
+            let since = since.as_str().split('-').next().unwrap_or_default();
+            RustcVersion::parse(since).map_or(true, |since| msrv.meets(since))
+        },
+        rustc_attr::StabilityLevel::Unstable { .. } => cx
+            .tcx
+            .features()
+            .declared_lib_features
+            .iter()
+            .any(|&(feature, _)| feature == stability.feature),
+    }
+}
+
 pub fn strip_pat_refs<'hir>(mut pat: &'hir Pat<'hir>) -> &'hir Pat<'hir> {
     while let PatKind::Ref(subpat, _) = pat.kind {
         pat = subpat;
@@ -1946,6 +2028,8 @@ pub fn is_must_use_func_call(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
 /// Checks if an expression represents the identity function
 /// Only examines closures and `std::convert::identity`
 pub fn is_expr_identity_function(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    static CONVERT_IDENTITY: PathLookup = PathLookup::new(&["core", "convert", "identity"]);
+
     /// Checks if a function's body represents the identity function. Looks for bodies of the form:
     /// * `|x| x`
     /// * `|x| return x`
@@ -1987,7 +2071,7 @@ fn is_body_identity_function(cx: &LateContext<'_>, func: &Body<'_>) -> bool {
 
     match expr.kind {
         ExprKind::Closure(&Closure { body, .. }) => is_body_identity_function(cx, cx.tcx.hir().body(body)),
-        _ => path_def_id(cx, expr).map_or(false, |id| match_def_path(cx, id, &paths::CONVERT_IDENTITY)),
+        _ => path_def_id(cx, expr).map_or(false, |id| CONVERT_IDENTITY.matches(cx, id)),
     }
 }
 
@@ -2137,6 +2221,65 @@ pub fn fn_def_id(cx: &LateContext<'_>, expr: &Expr<'_>) -> Option<DefId> {
     }
 }
 
+/// Checks if `expr` is passed to a function as a path. This is the case for the argument of
+/// `Path::new` and of conversions into a `PathBuf`, and for arguments of type `&Path` or of a
+/// generic type bounded by `AsRef<Path>` or `Into<PathBuf>`, e.g. of `std::fs::read` or
+/// `Path::join`.
+pub fn is_passed_as_path(cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+    let Some(parent) = get_parent_expr(cx, expr) else {
+        return false;
+    };
+    let index = match parent.kind {
+        ExprKind::Call(_, args) => args.iter().position(|arg| arg.hir_id == expr.hir_id),
+        ExprKind::MethodCall(_, recv, args, _) => {
+            if recv.hir_id == expr.hir_id {
+                Some(0)
+            } else {
+                args.iter().position(|arg| arg.hir_id == expr.hir_id).map(|i| i + 1)
+            }
+        },
+        _ => None,
+    };
+    let (Some(index), Some(callee_id)) = (index, fn_def_id(cx, parent)) else {
+        return false;
+    };
+
+    let parent_ty = cx.typeck_results().expr_ty(parent);
+    let is_path = |ty: Ty<'_>| is_type_diagnostic_item(cx, ty, sym::Path);
+    let is_path_buf = |ty: Ty<'_>| is_type_diagnostic_item(cx, ty, sym::PathBuf);
+    if (is_path_buf(parent_ty) || is_path(parent_ty.peel_refs()))
+        && (cx.tcx.is_diagnostic_item(sym::from_fn, callee_id)
+            || is_diag_trait_item(cx, callee_id, sym::Into)
+            || (cx.tcx.item_name(callee_id) == sym::new && is_diag_item_method(cx, callee_id, sym::Path)))
+    {
+        return true;
+    }
+
+    let sig = cx.tcx.fn_sig(callee_id).subst_identity().skip_binder();
+    let Some(&param_ty) = sig.inputs().get(index) else {
+        return false;
+    };
+    if let rustc_ty::Ref(_, ty, _) = *param_ty.kind()
+        && is_path(ty)
+    {
+        return true;
+    }
+    // `P` or `&P` with `P: AsRef<Path>` or `P: Into<PathBuf>`
+    let param_ty = param_ty.peel_refs();
+    matches!(param_ty.kind(), rustc_ty::Param(_))
+        && cx.tcx.param_env(callee_id).caller_bounds().iter().any(|pred| {
+            if let rustc_ty::PredicateKind::Clause(rustc_ty::Clause::Trait(trait_pred)) = pred.kind().skip_binder()
+                && trait_pred.self_ty() == param_ty
+                && let Some(target) = trait_pred.trait_ref.substs.types().nth(1)
+            {
+                (cx.tcx.is_diagnostic_item(sym::AsRef, trait_pred.def_id()) && is_path(target))
+                    || (cx.tcx.is_diagnostic_item(sym::Into, trait_pred.def_id()) && is_path_buf(target))
+            } else {
+                false
+            }
+        })
+}
+
 /// Returns `Option<String>` where String is a textual representation of the type encapsulated in
 /// the slice iff the given expression is a slice of primitives (as defined in the
 /// `is_recursively_primitive_type` function) and `None` otherwise.
@@ -2368,6 +2511,16 @@ pub fn is_in_test_function(tcx: TyCtxt<'_>, id: hir::HirId) -> bool {
     })
 }
 
+/// Checks if the given function is a `#[test]` function
+///
+/// Note: Add `// compile-flags: --test` to UI tests with a `#[test]` function
+pub fn is_test_function(tcx: TyCtxt<'_>, fn_def_id: LocalDefId) -> bool {
+    let name = tcx.item_name(fn_def_id.to_def_id());
+    with_test_item_names(tcx, tcx.parent_module_from_def_id(fn_def_id), |names| {
+        names.binary_search(&name).is_ok()
+    })
+}
+
 /// Checks if the item containing the given `HirId` has `#[cfg(test)]` attribute applied
 ///
 /// Note: Add `// compile-flags: --test` to UI tests with a `#[cfg(test)]` function
@@ -2389,6 +2542,28 @@ fn is_cfg_test(attr: &Attribute) -> bool {
         .any(is_cfg_test)
 }
 
+/// Checks if the crate is an integration test, i.e. a test crate whose root file is in a `tests`
+/// directory, like `tests/foo.rs` or `tests/foo/main.rs`.
+pub fn is_integration_test_crate(tcx: TyCtxt<'_>) -> bool {
+    tcx.sess.opts.test
+        && tcx.sess.local_crate_source_file().map_or(false, |root| {
+            let mut dirs = root.ancestors().skip(1).map(std::path::Path::file_name);
+            let parent = dirs.next().flatten();
+            parent == Some("tests".as_ref())
+                || root.file_name() == Some("main.rs".as_ref()) && dirs.next().flatten() == Some("tests".as_ref())
+        })
+}
+
+/// Checks if the given `HirId` is in test code: in a `#[test]` function, in an item gated
+/// behind `#[cfg(test)]`, or anywhere in an integration test.
+///
+/// The `#[test]` functions of each module are looked up once and cached.
+///
+/// Note: Add `// compile-flags: --test` to UI tests with test code
+pub fn is_in_test(tcx: TyCtxt<'_>, id: hir::HirId) -> bool {
+    is_integration_test_crate(tcx) || is_in_test_function(tcx, id) || is_in_cfg_test(tcx, id)
+}
+
 /// Checks whether item either has `test` attribute applied, or
 /// is a module with `test` in its name.
 ///
@@ -0,0 +1,200 @@
+//! Restricting lints to some paths of a crate, from the `scope` and `deny-only-in`
+//! configurations.
+//!
+//! The driver overrides the `shallow_lint_levels_on` query to allow the lints on the items of
+//! the files in which they aren't emitted, so this works however the lint is emitted. The levels
+//! of early lints come from the AST instead, so the diagnostic helpers check their spans.
+
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
+use rustc_hir::{ItemLocalId, OwnerId};
+use rustc_lint::{Level, Lint, LintId, LintStore};
+use rustc_middle::lint::{LintLevelSource, ShallowLintLevelMap};
+use rustc_middle::ty::TyCtxt;
+use rustc_session::Session;
+use rustc_span::{FileName, Span};
+use std::env;
+use std::path::Path;
+use std::sync::OnceLock;
+
+static LINT_SCOPES: OnceLock<LintScopes> = OnceLock::new();
+
+/// The paths in which lints are emitted. Paths are relative to the directory of the crate's
+/// manifest, or to the working directory if it isn't built by cargo.
+#[derive(Debug, Default)]
+pub struct LintScopes {
+    /// Globs of paths and the lints which aren't emitted in them.
+    allowed: Vec<(Vec<String>, FxHashSet<LintId>)>,
+    /// The lints which are only emitted in the paths matching one of the globs.
+    only_in: FxHashMap<LintId, Vec<String>>,
+}
+
+impl LintScopes {
+    /// Doesn't emit the lints in the paths matching one of the globs. `all` stands for every
+    /// Clippy lint. Unknown lints are ignored.
+    pub fn allow_in(&mut self, store: &LintStore, globs: Vec<String>, lints: &[String]) {
+        let lints = if lints.iter().any(|lint| lint == "all") {
+            store
+                .get_lints()
+                .iter()
+                .filter(|lint| lint.name_lower().starts_with("clippy::"))
+                .map(|&lint| LintId::of(lint))
+                .collect()
+        } else {
+            lints.iter().flat_map(|lint| find_lints(store, lint)).collect()
+        };
+        self.allowed.push((globs, lints));
+    }
+
+    /// Only emits the lint in the paths matching one of the globs. Unknown lints are ignored.
+    pub fn only_in(&mut self, store: &LintStore, lint: &str, globs: &[String]) {
+        for lint in find_lints(store, lint) {
+            self.only_in.entry(lint).or_default().extend_from_slice(globs);
+        }
+    }
+
+    /// The lints which aren't emitted in the file at `path`.
+    #[allow(rustc::potential_query_instability)] // the lints are collected into a set
+    fn allowed_in(&self, path: &str) -> FxHashSet<LintId> {
+        let matches = |globs: &[String]| globs.iter().any(|glob| glob_matches(glob, path));
+        let allowed = self
+            .allowed
+            .iter()
+            .filter(|(globs, _)| matches(globs))
+            .flat_map(|(_, lints)| lints.iter().copied());
+        let not_only_in = self
+            .only_in
+            .iter()
+            .filter(|(_, globs)| !matches(globs))
+            .map(|(&lint, _)| lint);
+        allowed.chain(not_only_in).collect()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.allowed.is_empty() && self.only_in.is_empty()
+    }
+}
+
+/// Sets the scopes of the lints. Only the first call has an effect.
+pub fn set_lint_scopes(scopes: LintScopes) {
+    if !scopes.is_empty() {
+        let _ = LINT_SCOPES.set(scopes);
+    }
+}
+
+/// Converts a lint name from the configuration to the name of the lint without the tool, e.g.
+/// `clippy::unwrap-used` to `unwrap_used`.
+pub fn normalize_lint_name(name: &str) -> String {
+    name.strip_prefix("clippy::").unwrap_or(name).replace('-', "_")
+}
+
+fn find_lints(store: &LintStore, name: &str) -> Vec<LintId> {
+    store
+        .find_lints(&format!("clippy::{}", normalize_lint_name(name)))
+        .unwrap_or_default()
+}
+
+/// Allows the lints which aren't emitted in the file of `owner`, as if they were allowed by an
+/// attribute on it. Levels set by the attributes of `owner` itself and by `--force-warn` take
+/// precedence. Called by the driver after computing `levels` with the default provider.
+#[allow(rustc::potential_query_instability)] // the order the lints are allowed in doesn't matter
+pub fn allow_out_of_scope_lints(tcx: TyCtxt<'_>, owner: OwnerId, levels: &mut ShallowLintLevelMap) {
+    let Some(scopes) = LINT_SCOPES.get() else {
+        return;
+    };
+    let Some(path) = file_path(tcx.sess, tcx.def_span(owner)) else {
+        return;
+    };
+    let allowed = scopes.allowed_in(&path);
+    if allowed.is_empty() {
+        return;
+    }
+    let specs = levels.specs.get_mut_or_insert_default(ItemLocalId::from_u32(0));
+    for lint in allowed {
+        if !matches!(
+            specs.get(&lint),
+            Some((Level::ForceWarn(_), _) | (_, LintLevelSource::Node { .. }))
+        ) {
+            specs.insert(lint, (Level::Allow, LintLevelSource::Default));
+        }
+    }
+}
+
+/// Checks if the early lint shouldn't be emitted at `span` because of the path of its file.
+pub(crate) fn is_out_of_scope(sess: &Session, lint: &'static Lint, span: Option<Span>) -> bool {
+    let (Some(scopes), Some(span)) = (LINT_SCOPES.get(), span) else {
+        return false;
+    };
+    file_path(sess, span).map_or(false, |path| scopes.allowed_in(&path).contains(&LintId::of(lint)))
+}
+
+/// The path of the file of `span`, relative to the crate's manifest directory.
+fn file_path(sess: &Session, span: Span) -> Option<String> {
+    let FileName::Real(file) = sess.source_map().lookup_source_file(span.lo()).name.clone() else {
+        return None;
+    };
+    file.local_path().map(|path| relative_path(sess, path))
+}
+
+/// Makes the path relative to the crate's manifest directory, with `/` as the separator.
+pub fn relative_path(sess: &Session, path: &Path) -> String {
+    let working_dir = sess.opts.working_dir.local_path_if_available();
+    let path = working_dir.join(path);
+    let base = env::var_os("CARGO_MANIFEST_DIR").map_or_else(|| working_dir.to_path_buf(), Into::into);
+    let path = path.strip_prefix(&base).unwrap_or(&path);
+    path.components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Matches a `/` separated path against a glob, in which `**` matches any number of components,
+/// `*` matches any sequence of characters within a component and `?` matches one character.
+pub fn glob_matches(glob: &str, path: &str) -> bool {
+    fn match_components(glob: &[&str], path: &[&str]) -> bool {
+        match glob.split_first() {
+            None => path.is_empty(),
+            Some((&"**", glob_rest)) => (0..=path.len()).any(|i| match_components(glob_rest, &path[i..])),
+            Some((glob_first, glob_rest)) => path.split_first().map_or(false, |(first, rest)| {
+                let glob: Vec<char> = glob_first.chars().collect();
+                let name: Vec<char> = first.chars().collect();
+                match_chars(&glob, &name) && match_components(glob_rest, rest)
+            }),
+        }
+    }
+
+    fn match_chars(glob: &[char], name: &[char]) -> bool {
+        match (glob.split_first(), name.split_first()) {
+            (None, _) => name.is_empty(),
+            (Some(('*', glob_rest)), _) => (0..=name.len()).any(|i| match_chars(glob_rest, &name[i..])),
+            (Some((g, glob_rest)), Some((n, name_rest))) => (*g == '?' || g == n) && match_chars(glob_rest, name_rest),
+            (Some(_), None) => false,
+        }
+    }
+
+    fn split(s: &str) -> Vec<&str> {
+        s.split('/')
+            .filter(|component| !component.is_empty() && *component != ".")
+            .collect()
+    }
+
+    match_components(&split(glob), &split(path))
+}
+
+#[cfg(test)]
+mod test {
+    use super::glob_matches;
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("src/generated/**", "src/generated/a.rs"));
+        assert!(glob_matches("src/generated/**", "src/generated/a/b.rs"));
+        assert!(!glob_matches("src/generated/**", "src/lib.rs"));
+        assert!(glob_matches("src/proto/*.rs", "src/proto/msg.rs"));
+        assert!(!glob_matches("src/proto/*.rs", "src/proto/v1/msg.rs"));
+        assert!(!glob_matches("src/proto/*.rs", "src/proto/msg.txt"));
+        assert!(glob_matches("**/mod?.rs", "src/a/mod1.rs"));
+        assert!(glob_matches("./src/*", "src/lib.rs"));
+        assert!(glob_matches("src/**/tests.rs", "src/tests.rs"));
+        assert!(!glob_matches("src", "src/lib.rs"));
+    }
+}
@@ -0,0 +1,88 @@
+use super::transitive_relation::TransitiveRelation;
+use rustc_index::bit_set::HybridBitSet;
+use rustc_middle::mir::visit::Visitor;
+use rustc_middle::mir::{self, Body, Local, Location, Operand, Place, Rvalue, TerminatorKind};
+use rustc_middle::ty::{self, Ty, TyCtxt};
+
+/// Collects, for each local, the locals which may hold a pointer to it.
+///
+/// This is flow-insensitive: `b = &a` makes `b` an alias of `a` everywhere in the body. Pointers
+/// are followed through copies, moves, casts, aggregates and function calls returning a type
+/// which may hold one. For example, `b = &a; c = b;` makes both `b` and `c` aliases of `a`.
+pub struct LocalAliases {
+    relation: TransitiveRelation,
+    domain_size: usize,
+}
+
+impl LocalAliases {
+    pub fn new<'tcx>(tcx: TyCtxt<'tcx>, mir: &Body<'tcx>) -> Self {
+        let mut visitor = AliasVisitor {
+            tcx,
+            mir,
+            relation: TransitiveRelation::default(),
+        };
+        visitor.visit_body(mir);
+        Self {
+            relation: visitor.relation,
+            domain_size: mir.local_decls.len(),
+        }
+    }
+
+    /// Returns the locals which may point to `local`, not including `local` itself.
+    pub fn aliases_of(&self, local: Local) -> HybridBitSet<Local> {
+        let mut aliases = self.relation.reachable_from(local, self.domain_size);
+        aliases.remove(local);
+        aliases
+    }
+}
+
+struct AliasVisitor<'a, 'tcx> {
+    tcx: TyCtxt<'tcx>,
+    mir: &'a Body<'tcx>,
+    relation: TransitiveRelation,
+}
+
+impl<'tcx> AliasVisitor<'_, 'tcx> {
+    fn add_operand(&mut self, operand: &Operand<'tcx>, lhs: Local) {
+        if let Operand::Copy(place) | Operand::Move(place) = operand
+            && may_hold_pointer(place.ty(self.mir, self.tcx).ty)
+        {
+            self.relation.add(place.local, lhs);
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for AliasVisitor<'_, 'tcx> {
+    fn visit_assign(&mut self, place: &Place<'tcx>, rvalue: &Rvalue<'tcx>, _: Location) {
+        let lhs = place.local;
+        match rvalue {
+            Rvalue::Ref(_, _, borrowed) | Rvalue::AddressOf(_, borrowed) => self.relation.add(borrowed.local, lhs),
+            Rvalue::Use(operand) | Rvalue::Cast(_, operand, _) => self.add_operand(operand, lhs),
+            Rvalue::Aggregate(_, operands) => {
+                for operand in operands {
+                    self.add_operand(operand, lhs);
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn visit_terminator(&mut self, terminator: &mir::Terminator<'tcx>, _: Location) {
+        if let TerminatorKind::Call { args, destination, .. } = &terminator.kind
+            && may_hold_pointer(destination.ty(self.mir, self.tcx).ty)
+        {
+            for arg in args {
+                self.add_operand(arg, destination.local);
+            }
+        }
+    }
+}
+
+/// Checks if a value of the type may contain a reference or a raw pointer.
+fn may_hold_pointer(ty: Ty<'_>) -> bool {
+    ty.walk().any(|arg| match arg.unpack() {
+        ty::GenericArgKind::Type(ty) => ty.is_any_ptr(),
+        ty::GenericArgKind::Lifetime(_) => true,
+        ty::GenericArgKind::Const(_) => false,
+    })
+}
@@ -0,0 +1,137 @@
+use rustc_index::bit_set::ChunkedBitSet;
+use rustc_middle::mir::visit::{MutatingUseContext, PlaceContext, Visitor};
+use rustc_middle::mir::{self, BasicBlock, Body, Local, Location, Place};
+use rustc_middle::ty::TyCtxt;
+use rustc_mir_dataflow::{
+    Analysis, AnalysisDomain, Backward, CallReturnPlaces, GenKill, GenKillAnalysis, ResultsCursor,
+};
+
+/// Tracks which locals may still be used at each point of a body.
+///
+/// This is a live-variable analysis like `rustc_mir_dataflow::impls::MaybeLiveLocals`, except that
+/// dropping a local doesn't count as a use of it. Otherwise every local with drop glue would be
+/// live until the end of its scope.
+///
+/// Uses through references which already exist are not tracked, see
+/// [`LocalAliases`](super::LocalAliases) to find those references.
+pub struct LocalLiveness<'mir, 'tcx> {
+    cursor: ResultsCursor<'mir, 'tcx, MaybeUsedLocals>,
+}
+
+impl<'mir, 'tcx> LocalLiveness<'mir, 'tcx> {
+    pub fn new(tcx: TyCtxt<'tcx>, mir: &'mir Body<'tcx>) -> Self {
+        Self {
+            cursor: MaybeUsedLocals
+                .into_engine(tcx, mir)
+                .pass_name("clippy_liveness")
+                .iterate_to_fixpoint()
+                .into_results_cursor(mir),
+        }
+    }
+
+    /// Checks if the value `local` holds right after `location` may be used later on.
+    pub fn is_live_after(&mut self, local: Local, location: Location) -> bool {
+        // This is a backward analysis, so the state before the effect of `location` is the state
+        // after it in program order.
+        self.cursor.seek_before_primary_effect(location);
+        self.cursor.contains(local)
+    }
+
+    /// Checks if the value `local` holds right before `location` may be used at or after it.
+    pub fn is_live_before(&mut self, local: Local, location: Location) -> bool {
+        self.cursor.seek_after_primary_effect(location);
+        self.cursor.contains(local)
+    }
+}
+
+struct MaybeUsedLocals;
+
+impl<'tcx> AnalysisDomain<'tcx> for MaybeUsedLocals {
+    type Domain = ChunkedBitSet<Local>;
+    type Direction = Backward;
+
+    const NAME: &'static str = "clippy_liveness";
+
+    fn bottom_value(&self, body: &Body<'tcx>) -> Self::Domain {
+        // bottom = not live
+        ChunkedBitSet::new_empty(body.local_decls.len())
+    }
+
+    fn initialize_start_block(&self, _: &Body<'tcx>, _: &mut Self::Domain) {
+        // No locals are live until we observe a use
+    }
+}
+
+impl<'tcx> GenKillAnalysis<'tcx> for MaybeUsedLocals {
+    type Idx = Local;
+
+    fn statement_effect(&self, trans: &mut impl GenKill<Local>, statement: &mir::Statement<'tcx>, location: Location) {
+        TransferFunction(trans).visit_statement(statement, location);
+    }
+
+    fn terminator_effect(
+        &self,
+        trans: &mut impl GenKill<Local>,
+        terminator: &mir::Terminator<'tcx>,
+        location: Location,
+    ) {
+        TransferFunction(trans).visit_terminator(terminator, location);
+    }
+
+    fn call_return_effect(
+        &self,
+        trans: &mut impl GenKill<Local>,
+        _block: BasicBlock,
+        return_places: CallReturnPlaces<'_, 'tcx>,
+    ) {
+        return_places.for_each(|place| {
+            if let Some(local) = place.as_local() {
+                trans.kill(local);
+            }
+        });
+    }
+
+    fn yield_resume_effect(
+        &self,
+        trans: &mut impl GenKill<Local>,
+        _resume_block: BasicBlock,
+        resume_place: Place<'tcx>,
+    ) {
+        if let Some(local) = resume_place.as_local() {
+            trans.kill(local);
+        }
+    }
+}
+
+struct TransferFunction<'a, T>(&'a mut T);
+
+impl<'tcx, T: GenKill<Local>> Visitor<'tcx> for TransferFunction<'_, T> {
+    fn visit_place(&mut self, place: &Place<'tcx>, context: PlaceContext, _: Location) {
+        match context {
+            // Handled by `call_return_effect` and `yield_resume_effect`, unless the destination is
+            // behind a reference
+            PlaceContext::MutatingUse(
+                MutatingUseContext::Call | MutatingUseContext::AsmOutput | MutatingUseContext::Yield,
+            ) if !place.is_indirect() => {},
+            PlaceContext::MutatingUse(MutatingUseContext::Store | MutatingUseContext::Deinit)
+                if !place.is_indirect() =>
+            {
+                // Assigning to a field doesn't overwrite the whole local
+                if place.projection.is_empty() {
+                    self.0.kill(place.local);
+                }
+            },
+            // Setting the discriminant doesn't read the local, and drops aren't considered uses
+            PlaceContext::MutatingUse(MutatingUseContext::SetDiscriminant) if !place.is_indirect() => {},
+            PlaceContext::NonUse(_) | PlaceContext::MutatingUse(MutatingUseContext::Drop) => {},
+            _ => self.0.gen(place.local),
+        }
+
+        // Indices used in the projection are uses of their own
+        for elem in place.projection {
+            if let mir::ProjectionElem::Index(index) = elem {
+                self.0.gen(index);
+            }
+        }
+    }
+}
@@ -1,15 +1,26 @@
+use rustc_data_structures::graph::iterate::{CycleDetector, TriColorDepthFirstSearch};
 use rustc_hir::{Expr, HirId};
+use rustc_index::bit_set::{BitSet, HybridBitSet};
 use rustc_middle::mir::visit::{MutatingUseContext, NonMutatingUseContext, PlaceContext, Visitor};
 use rustc_middle::mir::{
-    traversal, Body, InlineAsmOperand, Local, Location, Place, StatementKind, TerminatorKind, START_BLOCK,
+    traversal, BasicBlock, Body, InlineAsmOperand, Local, Location, Place, StatementKind, TerminatorKind, START_BLOCK,
 };
 use rustc_middle::ty::TyCtxt;
 
+mod aliases;
+pub use aliases::LocalAliases;
+
+mod liveness;
+pub use liveness::LocalLiveness;
+
 mod possible_borrower;
 pub use possible_borrower::PossibleBorrowerMap;
 
 mod possible_origin;
 
+mod reaching_definitions;
+pub use reaching_definitions::{DefSite, ReachingDefinitions};
+
 mod transitive_relation;
 
 #[derive(Clone, Debug, Default)]
@@ -133,30 +144,107 @@ pub fn local_assignments(mir: &Body<'_>, local: Local) -> Vec<Location> {
     locations
 }
 
-// `is_local_assignment` is based on `is_place_assignment`:
-// https://github.com/rust-lang/rust/blob/b7413511dc85ec01ef4b91785f86614589ac6103/compiler/rustc_middle/src/mir/visit.rs#L1350
-fn is_local_assignment(mir: &Body<'_>, local: Local, location: Location) -> bool {
+/// Checks if the value of `local` may be changed after `location`, either directly or through one
+/// of its `aliases`. Reassigning a local which merely points to `local` doesn't count.
+///
+/// Mutations through shared references (e.g. `Cell::set`) are not detected.
+pub fn is_mutated_after(mir: &Body<'_>, aliases: &LocalAliases, local: Local, location: Location) -> bool {
+    let mut v = MutationVisitor {
+        local,
+        aliases: aliases.aliases_of(local),
+        found: false,
+    };
+
+    let data = &mir.basic_blocks[location.block];
+    for (statement_index, statement) in data.statements.iter().enumerate().skip(location.statement_index + 1) {
+        v.visit_statement(
+            statement,
+            Location {
+                block: location.block,
+                statement_index,
+            },
+        );
+    }
+    if location.statement_index < data.statements.len() {
+        v.visit_terminator(data.terminator(), mir.terminator_loc(location.block));
+    }
+
+    let mut seen = BitSet::new_empty(mir.basic_blocks.len());
+    let mut stack: Vec<BasicBlock> = data.terminator().successors().collect();
+    while let Some(block) = stack.pop() {
+        if v.found {
+            break;
+        }
+        if seen.insert(block) {
+            let data = &mir.basic_blocks[block];
+            v.visit_basic_block_data(block, data);
+            stack.extend(data.terminator().successors());
+        }
+    }
+    v.found
+}
+
+struct MutationVisitor {
+    local: Local,
+    aliases: HybridBitSet<Local>,
+    found: bool,
+}
+
+impl<'tcx> Visitor<'tcx> for MutationVisitor {
+    fn visit_place(&mut self, place: &Place<'tcx>, ctx: PlaceContext, _: Location) {
+        if let PlaceContext::MutatingUse(kind) = ctx
+            && !matches!(kind, MutatingUseContext::Drop | MutatingUseContext::Retag)
+            && (place.local == self.local || (place.is_indirect() && self.aliases.contains(place.local)))
+        {
+            self.found = true;
+        }
+    }
+}
+
+/// Checks if `block` is part of a loop.
+pub fn block_in_cycle(mir: &Body<'_>, block: BasicBlock) -> bool {
+    TriColorDepthFirstSearch::new(&mir.basic_blocks)
+        .run_from(block, &mut CycleDetector)
+        .is_some()
+}
+
+/// Calls `f` with each local which is overwritten as a whole at `location`.
+fn for_each_assigned_local(mir: &Body<'_>, location: Location, mut f: impl FnMut(Local)) {
     let Location { block, statement_index } = location;
     let basic_block = &mir.basic_blocks[block];
     if statement_index < basic_block.statements.len() {
         let statement = &basic_block.statements[statement_index];
-        if let StatementKind::Assign(box (place, _)) = statement.kind {
-            place.as_local() == Some(local)
-        } else {
-            false
+        if let StatementKind::Assign(box (place, _)) = statement.kind
+            && let Some(local) = place.as_local()
+        {
+            f(local);
         }
     } else {
         let terminator = basic_block.terminator();
         match &terminator.kind {
-            TerminatorKind::Call { destination, .. } => destination.as_local() == Some(local),
-            TerminatorKind::InlineAsm { operands, .. } => operands.iter().any(|operand| {
-                if let InlineAsmOperand::Out { place: Some(place), .. } = operand {
-                    place.as_local() == Some(local)
-                } else {
-                    false
+            TerminatorKind::Call { destination, .. } => {
+                if let Some(local) = destination.as_local() {
+                    f(local);
+                }
+            },
+            TerminatorKind::InlineAsm { operands, .. } => {
+                for operand in operands {
+                    if let InlineAsmOperand::Out { place: Some(place), .. } = operand
+                        && let Some(local) = place.as_local()
+                    {
+                        f(local);
+                    }
                 }
-            }),
-            _ => false,
+            },
+            _ => {},
         }
     }
 }
+
+// `is_local_assignment` is based on `is_place_assignment`:
+// https://github.com/rust-lang/rust/blob/b7413511dc85ec01ef4b91785f86614589ac6103/compiler/rustc_middle/src/mir/visit.rs#L1350
+fn is_local_assignment(mir: &Body<'_>, local: Local, location: Location) -> bool {
+    let mut found = false;
+    for_each_assigned_local(mir, location, |assigned| found |= assigned == local);
+    found
+}
@@ -0,0 +1,106 @@
+use super::for_each_assigned_local;
+use rustc_data_structures::work_queue::WorkQueue;
+use rustc_index::bit_set::BitSet;
+use rustc_index::vec::IndexVec;
+use rustc_middle::mir::{BasicBlock, Body, Local, Location, START_BLOCK};
+
+/// Where a local may have been given its current value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DefSite {
+    /// The local is an argument of the function and still holds the value it was passed.
+    Arg,
+    /// The local was overwritten at this location.
+    Assign(Location),
+}
+
+/// Computes which assignments of a local may reach each point of a body.
+///
+/// Only assignments to the whole local are definitions. Writes to one of its fields, or through a
+/// reference to it, are not tracked.
+pub struct ReachingDefinitions {
+    defs: Vec<(Local, DefSite)>,
+    /// Indices into `defs` of the definitions of each local.
+    defs_of: IndexVec<Local, Vec<usize>>,
+    /// Indices into `defs` of the definitions in each block, in statement order.
+    block_defs: IndexVec<BasicBlock, Vec<usize>>,
+    /// The definitions reaching the start of each block.
+    entry_sets: IndexVec<BasicBlock, BitSet<usize>>,
+}
+
+impl ReachingDefinitions {
+    pub fn new(mir: &Body<'_>) -> Self {
+        let mut defs = Vec::new();
+        let mut defs_of = IndexVec::from_elem(Vec::new(), &mir.local_decls);
+        let mut block_defs = IndexVec::from_elem_n(Vec::new(), mir.basic_blocks.len());
+
+        for arg in mir.args_iter() {
+            defs_of[arg].push(defs.len());
+            defs.push((arg, DefSite::Arg));
+        }
+        for (block, data) in mir.basic_blocks.iter_enumerated() {
+            for statement_index in 0..=data.statements.len() {
+                let location = Location { block, statement_index };
+                for_each_assigned_local(mir, location, |local| {
+                    defs_of[local].push(defs.len());
+                    block_defs[block].push(defs.len());
+                    defs.push((local, DefSite::Assign(location)));
+                });
+            }
+        }
+
+        let mut this = Self {
+            entry_sets: IndexVec::from_elem_n(BitSet::new_empty(defs.len()), mir.basic_blocks.len()),
+            defs,
+            defs_of,
+            block_defs,
+        };
+        for (i, &(_, site)) in this.defs.iter().enumerate() {
+            if site == DefSite::Arg {
+                this.entry_sets[START_BLOCK].insert(i);
+            }
+        }
+
+        let mut queue = WorkQueue::with_none(mir.basic_blocks.len());
+        for block in mir.basic_blocks.indices() {
+            queue.insert(block);
+        }
+        while let Some(block) = queue.pop() {
+            let mut state = this.entry_sets[block].clone();
+            this.apply_block_defs(&mut state, block, usize::MAX);
+            for succ in mir.basic_blocks[block].terminator().successors() {
+                if this.entry_sets[succ].union(&state) {
+                    queue.insert(succ);
+                }
+            }
+        }
+
+        this
+    }
+
+    /// Returns the definitions of `local` which may reach `location`, before its effect.
+    pub fn reaching(&self, local: Local, location: Location) -> Vec<DefSite> {
+        let mut state = self.entry_sets[location.block].clone();
+        self.apply_block_defs(&mut state, location.block, location.statement_index);
+        self.defs_of[local]
+            .iter()
+            .filter(|&&i| state.contains(i))
+            .map(|&i| self.defs[i].1)
+            .collect()
+    }
+
+    /// Applies the definitions of `block` located before `statement_index` to `state`.
+    fn apply_block_defs(&self, state: &mut BitSet<usize>, block: BasicBlock, statement_index: usize) {
+        for &i in &self.block_defs[block] {
+            let (local, DefSite::Assign(location)) = self.defs[i] else {
+                continue;
+            };
+            if location.statement_index >= statement_index {
+                break;
+            }
+            for &killed in &self.defs_of[local] {
+                state.remove(killed);
+            }
+            state.insert(i);
+        }
+    }
+}
@@ -19,10 +19,17 @@ macro_rules! msrv_aliases {
 
 // names may refer to stabilized feature flags or library items
 msrv_aliases! {
+    1,80,0 { LAZY_LOCK }
+    1,71,0 { BUILD_HASHER_HASH_ONE }
+    1,70,0 { ONCE_LOCK }
     1,65,0 { LET_ELSE }
+    1,63,0 { PATH_TRY_EXISTS }
     1,62,0 { BOOL_THEN_SOME, DEFAULT_ENUM_ATTRIBUTE }
+    1,61,0 { CONST_FN_TRAIT_BOUND, EXIT_CODE }
     1,58,0 { FORMAT_ARGS_CAPTURE, PATTERN_TRAIT_CHAR_ARRAY }
+    1,56,0 { CONST_FN_TRANSMUTE, CONST_FN_UNION }
     1,55,0 { SEEK_REWIND }
+    1,54,0 { CONST_FN_UNSIZE }
     1,53,0 { OR_PATTERNS, MANUAL_BITS, BTREE_MAP_RETAIN, BTREE_SET_RETAIN, ARRAY_INTO_ITERATOR }
     1,52,0 { STR_SPLIT_ONCE, REM_EUCLID_CONST }
     1,51,0 { BORROW_AS_PTR, SEEK_FROM_CURRENT, UNSIGNED_ABS }
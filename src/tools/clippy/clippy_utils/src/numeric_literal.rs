@@ -12,7 +12,7 @@ pub enum Radix {
 impl Radix {
     /// Returns a reasonable digit group size for this radix.
     #[must_use]
-    fn suggest_grouping(self) -> usize {
+    pub fn suggest_grouping(self) -> usize {
         match self {
             Self::Binary | Self::Hexadecimal => 4,
             Self::Octal | Self::Decimal => 3,
@@ -26,6 +26,42 @@ pub fn format(lit: &str, type_suffix: Option<&str>, float: bool) -> String {
     NumericLiteral::new(lit, type_suffix, float).format()
 }
 
+/// Formats `value` as an integer literal in `radix`, with its digits grouped, e.g. `0x1_0000`.
+/// The letter digits of hexadecimal literals are in uppercase if `uppercase` is set.
+#[must_use]
+pub fn format_in_radix(value: u128, radix: Radix, uppercase: bool) -> String {
+    let (prefix, digits) = match radix {
+        Radix::Binary => ("0b", format!("{value:b}")),
+        Radix::Octal => ("0o", format!("{value:o}")),
+        Radix::Decimal => ("", value.to_string()),
+        Radix::Hexadecimal if uppercase => ("0x", format!("{value:X}")),
+        Radix::Hexadecimal => ("0x", format!("{value:x}")),
+    };
+    let mut output = String::from(prefix);
+    NumericLiteral::group_digits(&mut output, &digits, radix.suggest_grouping(), true, false);
+    output
+}
+
+/// Re-renders the integer literal `src` of kind `lit_kind` in `radix`, with its digits grouped as
+/// by [`format_in_radix`]. The type suffix is kept as written, including a separating underscore.
+///
+/// Returns `None` if `lit_kind` is not an integer literal.
+#[must_use]
+pub fn reformat_int_lit(src: &str, lit_kind: &LitKind, radix: Radix, uppercase: bool) -> Option<String> {
+    let LitKind::Int(value, _) = *lit_kind else {
+        return None;
+    };
+    let (unsuffixed, suffix) = split_suffix(src, lit_kind);
+    let mut output = format_in_radix(value, radix, uppercase);
+    if let Some(suffix) = suffix {
+        if unsuffixed.ends_with('_') {
+            output.push('_');
+        }
+        output.push_str(suffix);
+    }
+    Some(output)
+}
+
 #[derive(Debug)]
 pub struct NumericLiteral<'a> {
     /// Which radix the literal was represented in.
@@ -245,3 +281,43 @@ fn lit_suffix_length(lit_kind: &LitKind) -> Option<usize> {
 
     suffix.map(str::len)
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rustc_ast::ast::{IntTy, UintTy};
+
+    #[test]
+    fn test_format_in_radix() {
+        assert_eq!(format_in_radix(0, Radix::Hexadecimal, false), "0x0");
+        assert_eq!(format_in_radix(255, Radix::Hexadecimal, true), "0xFF");
+        assert_eq!(format_in_radix(0x1_0000, Radix::Hexadecimal, false), "0x1_0000");
+        assert_eq!(format_in_radix(0b10_1010, Radix::Binary, false), "0b10_1010");
+        assert_eq!(format_in_radix(0o777, Radix::Octal, false), "0o777");
+        assert_eq!(format_in_radix(1_000_000, Radix::Decimal, false), "1_000_000");
+    }
+
+    #[test]
+    fn test_reformat_int_lit() {
+        let unsuffixed = LitKind::Int(255, LitIntType::Unsuffixed);
+        assert_eq!(
+            reformat_int_lit("255", &unsuffixed, Radix::Hexadecimal, false).as_deref(),
+            Some("0xff")
+        );
+        let unsigned = LitKind::Int(65535, LitIntType::Unsigned(UintTy::U32));
+        assert_eq!(
+            reformat_int_lit("65535u32", &unsigned, Radix::Hexadecimal, true).as_deref(),
+            Some("0xFFFFu32")
+        );
+        assert_eq!(
+            reformat_int_lit("65_535_u32", &unsigned, Radix::Binary, false).as_deref(),
+            Some("0b1111_1111_1111_1111_u32")
+        );
+        let signed = LitKind::Int(0x10, LitIntType::Signed(IntTy::I8));
+        assert_eq!(
+            reformat_int_lit("0x10_i8", &signed, Radix::Decimal, false).as_deref(),
+            Some("16_i8")
+        );
+        assert_eq!(reformat_int_lit("true", &LitKind::Bool(true), Radix::Decimal, false), None);
+    }
+}
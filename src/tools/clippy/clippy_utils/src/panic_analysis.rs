@@ -0,0 +1,248 @@
+//! Utilities for finding out whether a function may panic.
+//!
+//! The analysis is conservative: any call which isn't followed is assumed to panic, and each
+//! possible panic is classified with a [`PanicKind`], so lints can pick the sources they care
+//! about.
+
+use crate::macros::{is_panic, root_macro_call_first_node};
+use crate::ty::is_type_diagnostic_item;
+use rustc_data_structures::fx::FxHashMap;
+use rustc_hir::def::DefKind;
+use rustc_hir::def_id::DefId;
+use rustc_hir::intravisit::{walk_expr, Visitor};
+use rustc_hir::{AnonConst, BinOpKind, Expr, ExprKind, UnOp};
+use rustc_lint::LateContext;
+use rustc_middle::hir::nested_filter;
+use rustc_middle::ty::adjustment::Adjust;
+use rustc_middle::ty::TypeckResults;
+use rustc_span::{sym, Span};
+
+/// The reason why an expression may panic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PanicKind {
+    /// `panic!`, `todo!`, `assert!`, `assert_eq!` or `assert_ne!`.
+    Explicit,
+    /// `debug_assert!`, `debug_assert_eq!` or `debug_assert_ne!`, which only panic in debug builds.
+    DebugAssertion,
+    /// `unwrap` on `Option` or `Result`.
+    Unwrap,
+    /// `expect` on `Option` or `Result`.
+    Expect,
+    /// Indexing, built-in or overloaded.
+    Index,
+    /// Integer division or remainder, which panics when dividing by zero or on overflow.
+    Division,
+    /// Integer arithmetic which panics on overflow, if overflow checks are enabled.
+    Overflow,
+    /// A call to a function which isn't analyzed, like functions of other crates, closures,
+    /// overloaded operators and derefs, or functions beyond the call depth.
+    Call,
+}
+
+/// A possible panic in the body of a function.
+#[derive(Clone, Copy, Debug)]
+pub struct PanicSource {
+    pub kind: PanicKind,
+    /// The expression which may panic. For calls to analyzed functions, this is the call.
+    pub span: Span,
+    /// For calls to analyzed functions, the possible panic in the called function.
+    pub callee_span: Option<Span>,
+}
+
+/// The results of [`fn_panic_sources`] per function and depth. A lint pass keeps one for the
+/// crate it checks, so functions called from several places are only analyzed once.
+#[derive(Clone, Debug, Default)]
+pub struct PanicSourcesCache {
+    sources: FxHashMap<(DefId, usize), Vec<PanicSource>>,
+}
+
+/// Returns the possible panics in the body of the function `def_id`, in the order they appear.
+///
+/// Calls to functions of the current crate are followed up to `depth` calls deep; a possible
+/// panic in a called function is reported at the call, with the kind of the panic in the called
+/// function. A function without a body in the current crate is assumed to panic.
+pub fn fn_panic_sources(
+    cx: &LateContext<'_>,
+    cache: &mut PanicSourcesCache,
+    def_id: DefId,
+    depth: usize,
+) -> Vec<PanicSource> {
+    if let Some(sources) = cache.sources.get(&(def_id, depth)) {
+        return sources.clone();
+    }
+    let sources = if let Some(local_id) = def_id.as_local()
+        && let Some(body) = cx.tcx.hir().maybe_body_owned_by(local_id)
+    {
+        let mut v = PanicVisitor {
+            cx,
+            cache: &mut *cache,
+            typeck: cx.tcx.typeck_body(body),
+            depth,
+            sources: Vec::new(),
+        };
+        v.visit_expr(cx.tcx.hir().body(body).value);
+        v.sources
+    } else {
+        vec![PanicSource {
+            kind: PanicKind::Call,
+            span: cx.tcx.def_span(def_id),
+            callee_span: None,
+        }]
+    };
+    cache.sources.insert((def_id, depth), sources.clone());
+    sources
+}
+
+/// Checks whether the function `def_id` may panic, following calls to functions of the current
+/// crate up to `depth` calls deep. See [`fn_panic_sources`].
+pub fn fn_may_panic(cx: &LateContext<'_>, cache: &mut PanicSourcesCache, def_id: DefId, depth: usize) -> bool {
+    !fn_panic_sources(cx, cache, def_id, depth).is_empty()
+}
+
+struct PanicVisitor<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    cache: &'a mut PanicSourcesCache,
+    typeck: &'tcx TypeckResults<'tcx>,
+    depth: usize,
+    sources: Vec<PanicSource>,
+}
+
+impl PanicVisitor<'_, '_> {
+    fn push(&mut self, kind: PanicKind, span: Span) {
+        self.sources.push(PanicSource {
+            kind,
+            span,
+            callee_span: None,
+        });
+    }
+
+    fn callee(&self, e: &Expr<'_>) -> Option<DefId> {
+        match e.kind {
+            ExprKind::MethodCall(..) => self.typeck.type_dependent_def_id(e.hir_id),
+            ExprKind::Call(
+                &Expr {
+                    kind: ExprKind::Path(ref qpath),
+                    hir_id,
+                    ..
+                },
+                _,
+            ) => self.typeck.qpath_res(qpath, hir_id).opt_def_id(),
+            _ => None,
+        }
+    }
+
+    fn check_call(&mut self, e: &Expr<'_>) {
+        let Some(callee) = self.callee(e) else {
+            self.push(PanicKind::Call, e.span);
+            return;
+        };
+        if matches!(self.cx.tcx.def_kind(callee), DefKind::Ctor(..)) {
+            return;
+        }
+        if let ExprKind::MethodCall(path, receiver, ..) = e.kind
+            && matches!(path.ident.name, sym::unwrap | sym::expect)
+            && let receiver_ty = self.typeck.expr_ty(receiver).peel_refs()
+            && (is_type_diagnostic_item(self.cx, receiver_ty, sym::Option)
+                || is_type_diagnostic_item(self.cx, receiver_ty, sym::Result))
+        {
+            let kind = if path.ident.name == sym::unwrap {
+                PanicKind::Unwrap
+            } else {
+                PanicKind::Expect
+            };
+            self.push(kind, e.span);
+            return;
+        }
+        if self.depth > 0 && callee.is_local() {
+            let callee_sources = fn_panic_sources(self.cx, self.cache, callee, self.depth - 1);
+            let mut kinds = Vec::new();
+            for source in callee_sources {
+                // Report each kind once per call
+                if !kinds.contains(&source.kind) {
+                    kinds.push(source.kind);
+                    self.sources.push(PanicSource {
+                        kind: source.kind,
+                        span: e.span,
+                        callee_span: Some(source.span),
+                    });
+                }
+            }
+        } else {
+            self.push(PanicKind::Call, e.span);
+        }
+    }
+}
+
+impl<'tcx> Visitor<'tcx> for PanicVisitor<'_, 'tcx> {
+    type NestedFilter = nested_filter::OnlyBodies;
+
+    fn visit_expr(&mut self, e: &'tcx Expr<'_>) {
+        if let Some(macro_call) = root_macro_call_first_node(self.cx, e) {
+            let kind = match self.cx.tcx.get_diagnostic_name(macro_call.def_id) {
+                _ if is_panic(self.cx, macro_call.def_id) => Some(PanicKind::Explicit),
+                Some(sym::assert_macro | sym::assert_eq_macro | sym::assert_ne_macro | sym::todo_macro) => {
+                    Some(PanicKind::Explicit)
+                },
+                Some(sym::debug_assert_macro | sym::debug_assert_eq_macro | sym::debug_assert_ne_macro) => {
+                    Some(PanicKind::DebugAssertion)
+                },
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                // The expansion only adds the calls to the panic machinery
+                self.push(kind, macro_call.span);
+                return;
+            }
+        }
+
+        if self
+            .typeck
+            .expr_adjustments(e)
+            .iter()
+            .any(|adjustment| matches!(adjustment.kind, Adjust::Deref(Some(_))))
+        {
+            self.push(PanicKind::Call, e.span);
+        }
+
+        match e.kind {
+            ExprKind::Call(..) | ExprKind::MethodCall(..) => self.check_call(e),
+            ExprKind::Binary(op, lhs, _) | ExprKind::AssignOp(op, lhs, _) => {
+                if self.typeck.is_method_call(e) {
+                    self.push(PanicKind::Call, e.span);
+                } else if self.typeck.expr_ty(lhs).is_integral() {
+                    match op.node {
+                        BinOpKind::Div | BinOpKind::Rem => self.push(PanicKind::Division, e.span),
+                        BinOpKind::Add | BinOpKind::Sub | BinOpKind::Mul | BinOpKind::Shl | BinOpKind::Shr
+                            if self.cx.tcx.sess.overflow_checks() =>
+                        {
+                            self.push(PanicKind::Overflow, e.span);
+                        },
+                        _ => {},
+                    }
+                }
+            },
+            ExprKind::Unary(op, operand) => {
+                if self.typeck.is_method_call(e) {
+                    self.push(PanicKind::Call, e.span);
+                } else if op == UnOp::Neg
+                    && self.typeck.expr_ty(operand).is_integral()
+                    && self.cx.tcx.sess.overflow_checks()
+                {
+                    self.push(PanicKind::Overflow, e.span);
+                }
+            },
+            ExprKind::Index(..) => self.push(PanicKind::Index, e.span),
+            ExprKind::InlineAsm(_) => self.push(PanicKind::Call, e.span),
+            _ => {},
+        }
+
+        walk_expr(self, e);
+    }
+
+    // Panics in const blocks will cause compilation to fail.
+    fn visit_anon_const(&mut self, _: &'tcx AnonConst) {}
+
+    fn nested_visit_map(&mut self) -> Self::Map {
+        self.cx.tcx.hir()
+    }
+}
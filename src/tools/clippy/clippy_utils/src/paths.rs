@@ -3,156 +3,142 @@
 //!
 //! Whenever possible, please consider diagnostic items over hardcoded paths.
 //! See <https://github.com/rust-lang/rust-clippy/issues/5393> for more information.
+//!
+//! Paths are declared as a [`PathLookup`] next to the lint using them. The ones below are shared
+//! by several lints.
 
-#[cfg(feature = "internal")]
-pub const APPLICABILITY: [&str; 2] = ["rustc_lint_defs", "Applicability"];
-#[cfg(feature = "internal")]
-pub const APPLICABILITY_VALUES: [[&str; 3]; 4] = [
-    ["rustc_lint_defs", "Applicability", "Unspecified"],
-    ["rustc_lint_defs", "Applicability", "HasPlaceholders"],
-    ["rustc_lint_defs", "Applicability", "MaybeIncorrect"],
-    ["rustc_lint_defs", "Applicability", "MachineApplicable"],
-];
-#[cfg(feature = "internal")]
-pub const DIAGNOSTIC_BUILDER: [&str; 3] = ["rustc_errors", "diagnostic_builder", "DiagnosticBuilder"];
-pub const ARC_PTR_EQ: [&str; 4] = ["alloc", "sync", "Arc", "ptr_eq"];
-pub const BTREEMAP_CONTAINS_KEY: [&str; 6] = ["alloc", "collections", "btree", "map", "BTreeMap", "contains_key"];
-pub const BTREEMAP_INSERT: [&str; 6] = ["alloc", "collections", "btree", "map", "BTreeMap", "insert"];
-pub const BTREESET_ITER: [&str; 6] = ["alloc", "collections", "btree", "set", "BTreeSet", "iter"];
-pub const CLONE_TRAIT_METHOD: [&str; 4] = ["core", "clone", "Clone", "clone"];
-pub const CORE_ITER_CLONED: [&str; 6] = ["core", "iter", "traits", "iterator", "Iterator", "cloned"];
-pub const CORE_ITER_COPIED: [&str; 6] = ["core", "iter", "traits", "iterator", "Iterator", "copied"];
-pub const CORE_ITER_FILTER: [&str; 6] = ["core", "iter", "traits", "iterator", "Iterator", "filter"];
-pub const CSTRING_AS_C_STR: [&str; 5] = ["alloc", "ffi", "c_str", "CString", "as_c_str"];
-pub const DEFAULT_TRAIT_METHOD: [&str; 4] = ["core", "default", "Default", "default"];
-pub const DEREF_MUT_TRAIT_METHOD: [&str; 5] = ["core", "ops", "deref", "DerefMut", "deref_mut"];
-#[cfg(feature = "internal")]
-pub const EARLY_CONTEXT: [&str; 2] = ["rustc_lint", "EarlyContext"];
-#[cfg(feature = "internal")]
-pub const EARLY_LINT_PASS: [&str; 3] = ["rustc_lint", "passes", "EarlyLintPass"];
-pub const EXIT: [&str; 3] = ["std", "process", "exit"];
-pub const F32_EPSILON: [&str; 4] = ["core", "f32", "<impl f32>", "EPSILON"];
-pub const F64_EPSILON: [&str; 4] = ["core", "f64", "<impl f64>", "EPSILON"];
-pub const FROM_ITERATOR_METHOD: [&str; 6] = ["core", "iter", "traits", "collect", "FromIterator", "from_iter"];
-pub const FROM_STR_METHOD: [&str; 5] = ["core", "str", "traits", "FromStr", "from_str"];
-#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
-pub const FUTURES_IO_ASYNCREADEXT: [&str; 3] = ["futures_util", "io", "AsyncReadExt"];
-#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
-pub const FUTURES_IO_ASYNCWRITEEXT: [&str; 3] = ["futures_util", "io", "AsyncWriteExt"];
-pub const HASHMAP_CONTAINS_KEY: [&str; 6] = ["std", "collections", "hash", "map", "HashMap", "contains_key"];
-pub const HASHMAP_INSERT: [&str; 6] = ["std", "collections", "hash", "map", "HashMap", "insert"];
-pub const HASHSET_ITER: [&str; 6] = ["std", "collections", "hash", "set", "HashSet", "iter"];
-#[cfg(feature = "internal")]
-pub const IDENT: [&str; 3] = ["rustc_span", "symbol", "Ident"];
-#[cfg(feature = "internal")]
-pub const IDENT_AS_STR: [&str; 4] = ["rustc_span", "symbol", "Ident", "as_str"];
-pub const INSERT_STR: [&str; 4] = ["alloc", "string", "String", "insert_str"];
-pub const ITER_EMPTY: [&str; 5] = ["core", "iter", "sources", "empty", "Empty"];
-pub const ITERTOOLS_NEXT_TUPLE: [&str; 3] = ["itertools", "Itertools", "next_tuple"];
-#[cfg(feature = "internal")]
-pub const KW_MODULE: [&str; 3] = ["rustc_span", "symbol", "kw"];
-#[cfg(feature = "internal")]
-pub const LATE_CONTEXT: [&str; 2] = ["rustc_lint", "LateContext"];
-#[cfg(feature = "internal")]
-pub const LATE_LINT_PASS: [&str; 3] = ["rustc_lint", "passes", "LateLintPass"];
-#[cfg(feature = "internal")]
-pub const LINT: [&str; 2] = ["rustc_lint_defs", "Lint"];
-pub const MEM_SWAP: [&str; 3] = ["core", "mem", "swap"];
-#[cfg(feature = "internal")]
-pub const MSRV: [&str; 3] = ["clippy_utils", "msrvs", "Msrv"];
-pub const OPEN_OPTIONS: [&str; 3] = ["std", "fs", "OpenOptions"];
-pub const OS_STRING_AS_OS_STR: [&str; 5] = ["std", "ffi", "os_str", "OsString", "as_os_str"];
-pub const OS_STR_TO_OS_STRING: [&str; 5] = ["std", "ffi", "os_str", "OsStr", "to_os_string"];
-pub const PARKING_LOT_MUTEX_GUARD: [&str; 3] = ["lock_api", "mutex", "MutexGuard"];
-pub const PARKING_LOT_RWLOCK_READ_GUARD: [&str; 3] = ["lock_api", "rwlock", "RwLockReadGuard"];
-pub const PARKING_LOT_RWLOCK_WRITE_GUARD: [&str; 3] = ["lock_api", "rwlock", "RwLockWriteGuard"];
-pub const PATH_BUF_AS_PATH: [&str; 4] = ["std", "path", "PathBuf", "as_path"];
-pub const PATH_TO_PATH_BUF: [&str; 4] = ["std", "path", "Path", "to_path_buf"];
-pub const PEEKABLE: [&str; 5] = ["core", "iter", "adapters", "peekable", "Peekable"];
-pub const PERMISSIONS: [&str; 3] = ["std", "fs", "Permissions"];
-#[cfg_attr(not(unix), allow(clippy::invalid_paths))]
-pub const PERMISSIONS_FROM_MODE: [&str; 6] = ["std", "os", "unix", "fs", "PermissionsExt", "from_mode"];
-pub const PTR_COPY: [&str; 3] = ["core", "intrinsics", "copy"];
-pub const PTR_COPY_NONOVERLAPPING: [&str; 3] = ["core", "intrinsics", "copy_nonoverlapping"];
-pub const PTR_EQ: [&str; 3] = ["core", "ptr", "eq"];
-pub const PTR_SLICE_FROM_RAW_PARTS: [&str; 3] = ["core", "ptr", "slice_from_raw_parts"];
-pub const PTR_SLICE_FROM_RAW_PARTS_MUT: [&str; 3] = ["core", "ptr", "slice_from_raw_parts_mut"];
-pub const PTR_SWAP_NONOVERLAPPING: [&str; 3] = ["core", "ptr", "swap_nonoverlapping"];
-pub const PTR_READ: [&str; 3] = ["core", "ptr", "read"];
-pub const PTR_READ_UNALIGNED: [&str; 3] = ["core", "ptr", "read_unaligned"];
-pub const PTR_READ_VOLATILE: [&str; 3] = ["core", "ptr", "read_volatile"];
-pub const PTR_REPLACE: [&str; 3] = ["core", "ptr", "replace"];
-pub const PTR_SWAP: [&str; 3] = ["core", "ptr", "swap"];
-pub const PTR_UNALIGNED_VOLATILE_LOAD: [&str; 3] = ["core", "intrinsics", "unaligned_volatile_load"];
-pub const PTR_UNALIGNED_VOLATILE_STORE: [&str; 3] = ["core", "intrinsics", "unaligned_volatile_store"];
-pub const PTR_WRITE: [&str; 3] = ["core", "ptr", "write"];
-pub const PTR_WRITE_BYTES: [&str; 3] = ["core", "intrinsics", "write_bytes"];
-pub const PTR_WRITE_UNALIGNED: [&str; 3] = ["core", "ptr", "write_unaligned"];
-pub const PTR_WRITE_VOLATILE: [&str; 3] = ["core", "ptr", "write_volatile"];
-pub const PUSH_STR: [&str; 4] = ["alloc", "string", "String", "push_str"];
-pub const RANGE_ARGUMENT_TRAIT: [&str; 3] = ["core", "ops", "RangeBounds"];
-pub const RC_PTR_EQ: [&str; 4] = ["alloc", "rc", "Rc", "ptr_eq"];
-pub const REFCELL_REF: [&str; 3] = ["core", "cell", "Ref"];
-pub const REFCELL_REFMUT: [&str; 3] = ["core", "cell", "RefMut"];
-pub const REGEX_BUILDER_NEW: [&str; 5] = ["regex", "re_builder", "unicode", "RegexBuilder", "new"];
-pub const REGEX_BYTES_BUILDER_NEW: [&str; 5] = ["regex", "re_builder", "bytes", "RegexBuilder", "new"];
-pub const REGEX_BYTES_NEW: [&str; 4] = ["regex", "re_bytes", "Regex", "new"];
-pub const REGEX_BYTES_SET_NEW: [&str; 5] = ["regex", "re_set", "bytes", "RegexSet", "new"];
-pub const REGEX_NEW: [&str; 4] = ["regex", "re_unicode", "Regex", "new"];
-pub const REGEX_SET_NEW: [&str; 5] = ["regex", "re_set", "unicode", "RegexSet", "new"];
-pub const SERDE_DESERIALIZE: [&str; 3] = ["serde", "de", "Deserialize"];
-pub const SERDE_DE_VISITOR: [&str; 3] = ["serde", "de", "Visitor"];
-pub const SLICE_FROM_RAW_PARTS: [&str; 4] = ["core", "slice", "raw", "from_raw_parts"];
-pub const SLICE_FROM_RAW_PARTS_MUT: [&str; 4] = ["core", "slice", "raw", "from_raw_parts_mut"];
-pub const SLICE_GET: [&str; 4] = ["core", "slice", "<impl [T]>", "get"];
-pub const SLICE_INTO_VEC: [&str; 4] = ["alloc", "slice", "<impl [T]>", "into_vec"];
-pub const SLICE_INTO: [&str; 4] = ["core", "slice", "<impl [T]>", "iter"];
-pub const SLICE_ITER: [&str; 4] = ["core", "slice", "iter", "Iter"];
-pub const STDERR: [&str; 4] = ["std", "io", "stdio", "stderr"];
-pub const STDOUT: [&str; 4] = ["std", "io", "stdio", "stdout"];
-pub const CONVERT_IDENTITY: [&str; 3] = ["core", "convert", "identity"];
-pub const STD_FS_CREATE_DIR: [&str; 3] = ["std", "fs", "create_dir"];
-pub const STD_IO_SEEK: [&str; 3] = ["std", "io", "Seek"];
-pub const STD_IO_SEEK_FROM_CURRENT: [&str; 4] = ["std", "io", "SeekFrom", "Current"];
-pub const STD_IO_SEEKFROM_START: [&str; 4] = ["std", "io", "SeekFrom", "Start"];
-pub const STD_PROCESS_COMMAND: [&str; 3] = ["std", "process", "Command"];
-pub const STRING_AS_MUT_STR: [&str; 4] = ["alloc", "string", "String", "as_mut_str"];
-pub const STRING_AS_STR: [&str; 4] = ["alloc", "string", "String", "as_str"];
-pub const STRING_NEW: [&str; 4] = ["alloc", "string", "String", "new"];
-pub const STR_BYTES: [&str; 4] = ["core", "str", "<impl str>", "bytes"];
-pub const STR_CHARS: [&str; 4] = ["core", "str", "<impl str>", "chars"];
-pub const STR_ENDS_WITH: [&str; 4] = ["core", "str", "<impl str>", "ends_with"];
-pub const STR_FROM_UTF8: [&str; 4] = ["core", "str", "converts", "from_utf8"];
-pub const STR_FROM_UTF8_UNCHECKED: [&str; 4] = ["core", "str", "converts", "from_utf8_unchecked"];
-pub const STR_LEN: [&str; 4] = ["core", "str", "<impl str>", "len"];
-pub const STR_STARTS_WITH: [&str; 4] = ["core", "str", "<impl str>", "starts_with"];
-#[cfg(feature = "internal")]
-pub const SYMBOL: [&str; 3] = ["rustc_span", "symbol", "Symbol"];
-#[cfg(feature = "internal")]
-pub const SYMBOL_AS_STR: [&str; 4] = ["rustc_span", "symbol", "Symbol", "as_str"];
-#[cfg(feature = "internal")]
-pub const SYMBOL_INTERN: [&str; 4] = ["rustc_span", "symbol", "Symbol", "intern"];
-#[cfg(feature = "internal")]
-pub const SYMBOL_TO_IDENT_STRING: [&str; 4] = ["rustc_span", "symbol", "Symbol", "to_ident_string"];
-#[cfg(feature = "internal")]
-pub const SYM_MODULE: [&str; 3] = ["rustc_span", "symbol", "sym"];
-#[cfg(feature = "internal")]
-pub const SYNTAX_CONTEXT: [&str; 3] = ["rustc_span", "hygiene", "SyntaxContext"];
-pub const TO_OWNED_METHOD: [&str; 4] = ["alloc", "borrow", "ToOwned", "to_owned"];
-pub const TO_STRING_METHOD: [&str; 4] = ["alloc", "string", "ToString", "to_string"];
-#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
-pub const TOKIO_IO_ASYNCREADEXT: [&str; 5] = ["tokio", "io", "util", "async_read_ext", "AsyncReadExt"];
-#[expect(clippy::invalid_paths)] // internal lints do not know about all external crates
-pub const TOKIO_IO_ASYNCWRITEEXT: [&str; 5] = ["tokio", "io", "util", "async_write_ext", "AsyncWriteExt"];
-pub const TRY_FROM: [&str; 4] = ["core", "convert", "TryFrom", "try_from"];
-pub const VEC_AS_MUT_SLICE: [&str; 4] = ["alloc", "vec", "Vec", "as_mut_slice"];
-pub const VEC_AS_SLICE: [&str; 4] = ["alloc", "vec", "Vec", "as_slice"];
-pub const VEC_DEQUE_ITER: [&str; 5] = ["alloc", "collections", "vec_deque", "VecDeque", "iter"];
-pub const VEC_FROM_ELEM: [&str; 3] = ["alloc", "vec", "from_elem"];
-pub const VEC_NEW: [&str; 4] = ["alloc", "vec", "Vec", "new"];
-pub const VEC_RESIZE: [&str; 4] = ["alloc", "vec", "Vec", "resize"];
-pub const WEAK_ARC: [&str; 3] = ["alloc", "sync", "Weak"];
-pub const WEAK_RC: [&str; 3] = ["alloc", "rc", "Weak"];
-pub const PTR_NON_NULL: [&str; 4] = ["core", "ptr", "non_null", "NonNull"];
-pub const INSTANT_NOW: [&str; 4] = ["std", "time", "Instant", "now"];
-pub const INSTANT: [&str; 3] = ["std", "time", "Instant"];
+use crate::{def_path_def_ids, path_def_id, MaybePath};
+use rustc_hir::def_id::DefId;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::Symbol;
+use std::sync::OnceLock;
+
+/// An item from another crate which a lint needs to recognize.
+///
+/// Lints declare these as `static`s:
+///
+/// ```rust,ignore
+/// static REGEX_NEW: PathLookup = PathLookup::new(&["regex", "re_unicode", "Regex", "new"]);
+/// ```
+///
+/// The item is looked up the first time it's needed, through its diagnostic item if it has one
+/// and by its path otherwise, and the result is cached for the rest of the compilation session.
+/// A path may resolve to several items, e.g. when multiple versions of a crate are linked.
+///
+/// `cargo dev check_paths` reports lookups which are never used, and the `invalid_paths` internal
+/// lint reports paths which don't resolve.
+pub struct PathLookup {
+    path: &'static [&'static str],
+    diagnostic_item: Option<Symbol>,
+    def_ids: OnceLock<Vec<DefId>>,
+}
+
+impl PathLookup {
+    pub const fn new(path: &'static [&'static str]) -> Self {
+        Self {
+            path,
+            diagnostic_item: None,
+            def_ids: OnceLock::new(),
+        }
+    }
+
+    /// Creates a lookup which prefers the diagnostic item `name`, and only falls back to `path`
+    /// when it isn't defined, e.g. with an older standard library.
+    pub const fn with_diagnostic_item(path: &'static [&'static str], name: Symbol) -> Self {
+        Self {
+            path,
+            diagnostic_item: Some(name),
+            def_ids: OnceLock::new(),
+        }
+    }
+
+    pub fn path(&self) -> &'static [&'static str] {
+        self.path
+    }
+
+    /// Gets the items this resolves to. This is empty if the crate defining them isn't used.
+    pub fn get(&self, cx: &LateContext<'_>) -> &[DefId] {
+        self.def_ids.get_or_init(|| {
+            if let Some(name) = self.diagnostic_item
+                && let Some(def_id) = cx.tcx.get_diagnostic_item(name)
+            {
+                vec![def_id]
+            } else {
+                def_path_def_ids(cx, self.path).collect()
+            }
+        })
+    }
+
+    /// Checks if `def_id` is one of the items this resolves to.
+    pub fn matches(&self, cx: &LateContext<'_>, def_id: DefId) -> bool {
+        self.get(cx).contains(&def_id)
+    }
+
+    /// Checks if `maybe_path` is a path resolving to one of the items.
+    pub fn matches_path<'tcx>(&self, cx: &LateContext<'_>, maybe_path: &impl MaybePath<'tcx>) -> bool {
+        path_def_id(cx, maybe_path).map_or(false, |def_id| self.matches(cx, def_id))
+    }
+
+    /// Checks if `ty` is an ADT defined by one of the items.
+    pub fn matches_ty(&self, cx: &LateContext<'_>, ty: Ty<'_>) -> bool {
+        match ty.kind() {
+            ty::Adt(adt, _) => self.matches(cx, adt.did()),
+            _ => false,
+        }
+    }
+
+    /// Checks if `expr` is a method call resolving to a method of one of the traits.
+    pub fn matches_trait_method(&self, cx: &LateContext<'_>, expr: &Expr<'_>) -> bool {
+        cx.typeck_results()
+            .type_dependent_def_id(expr.hir_id)
+            .and_then(|id| cx.tcx.trait_of_item(id))
+            .map_or(false, |id| self.matches(cx, id))
+    }
+
+    /// Gets the first item this resolves to, e.g. the `DefId` of a trait to check it's implemented.
+    pub fn first(&self, cx: &LateContext<'_>) -> Option<DefId> {
+        self.get(cx).first().copied()
+    }
+
+    /// Returns the arguments if `expr` is a call to a path resolving to one of the items.
+    pub fn call_args<'tcx>(&self, cx: &LateContext<'_>, expr: &'tcx Expr<'_>) -> Option<&'tcx [Expr<'tcx>]> {
+        if let ExprKind::Call(fun, args) = expr.kind
+            && self.matches_path(cx, fun)
+        {
+            Some(args)
+        } else {
+            None
+        }
+    }
+}
+
+pub static DEFAULT_TRAIT_METHOD: PathLookup = PathLookup::new(&["core", "default", "Default", "default"]);
+pub static FS_CREATE_DIR: PathLookup = PathLookup::new(&["std", "fs", "create_dir"]);
+#[cfg(feature = "internal")]
+pub static LINT: PathLookup = PathLookup::new(&["rustc_lint_defs", "Lint"]);
+pub static OPEN_OPTIONS: PathLookup = PathLookup::new(&["std", "fs", "OpenOptions"]);
+pub static PARKING_LOT_MUTEX_GUARD: PathLookup = PathLookup::new(&["lock_api", "mutex", "MutexGuard"]);
+pub static PARKING_LOT_RWLOCK_READ_GUARD: PathLookup = PathLookup::new(&["lock_api", "rwlock", "RwLockReadGuard"]);
+pub static PARKING_LOT_RWLOCK_WRITE_GUARD: PathLookup = PathLookup::new(&["lock_api", "rwlock", "RwLockWriteGuard"]);
+pub static PERMISSIONS: PathLookup = PathLookup::new(&["std", "fs", "Permissions"]);
+pub static PROCESS_EXIT: PathLookup = PathLookup::new(&["std", "process", "exit"]);
+pub static PTR_COPY: PathLookup = PathLookup::new(&["core", "intrinsics", "copy"]);
+pub static PTR_COPY_NONOVERLAPPING: PathLookup = PathLookup::new(&["core", "intrinsics", "copy_nonoverlapping"]);
+pub static PTR_READ_UNALIGNED: PathLookup = PathLookup::new(&["core", "ptr", "read_unaligned"]);
+pub static PTR_SLICE_FROM_RAW_PARTS: PathLookup = PathLookup::new(&["core", "ptr", "slice_from_raw_parts"]);
+pub static PTR_SLICE_FROM_RAW_PARTS_MUT: PathLookup = PathLookup::new(&["core", "ptr", "slice_from_raw_parts_mut"]);
+pub static PTR_SWAP_NONOVERLAPPING: PathLookup = PathLookup::new(&["core", "ptr", "swap_nonoverlapping"]);
+pub static PTR_WRITE_BYTES: PathLookup = PathLookup::new(&["core", "intrinsics", "write_bytes"]);
+pub static PTR_WRITE_UNALIGNED: PathLookup = PathLookup::new(&["core", "ptr", "write_unaligned"]);
+pub static PUSH_STR: PathLookup = PathLookup::new(&["alloc", "string", "String", "push_str"]);
+pub static SERDE_DESERIALIZE: PathLookup = PathLookup::new(&["serde", "de", "Deserialize"]);
+pub static SLICE_FROM_RAW_PARTS: PathLookup = PathLookup::new(&["core", "slice", "raw", "from_raw_parts"]);
+pub static SLICE_FROM_RAW_PARTS_MUT: PathLookup = PathLookup::new(&["core", "slice", "raw", "from_raw_parts_mut"]);
+pub static STD_IO_SEEK: PathLookup = PathLookup::new(&["std", "io", "Seek"]);
+pub static STD_PROCESS_COMMAND: PathLookup = PathLookup::new(&["std", "process", "Command"]);
+pub static TO_OWNED_METHOD: PathLookup = PathLookup::new(&["alloc", "borrow", "ToOwned", "to_owned"]);
+pub static TO_STRING_METHOD: PathLookup = PathLookup::new(&["alloc", "string", "ToString", "to_string"]);
+pub static WEAK_ARC: PathLookup = PathLookup::new(&["alloc", "sync", "Weak"]);
+pub static WEAK_RC: PathLookup = PathLookup::new(&["alloc", "rc", "Weak"]);
@@ -0,0 +1,101 @@
+//! Finding the `unwrap()`s and `expect(..)`s of `Result`s in a function which could return the
+//! error with `?` instead.
+
+use crate::source::indent_of;
+use crate::ty::{implements_trait, is_type_diagnostic_item};
+use crate::visitors::{for_each_expr, Descend};
+use core::ops::ControlFlow;
+use rustc_hir::{Block, Expr, ExprKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty};
+use rustc_span::{sym, BytePos, Span};
+
+/// The unwrapped `Result`s in the body of a function.
+pub struct ResultUnwraps<'tcx> {
+    block: &'tcx Block<'tcx>,
+    /// The spans from the end of the receivers to the end of the calls, which `?` replaces rather
+    /// than the whole calls, as they can contain other calls.
+    pub spans: Vec<Span>,
+    /// Whether the function returns `()` and contains `return`s, which would have to return
+    /// `Ok(())` as well.
+    has_unit_return: bool,
+}
+
+impl<'tcx> ResultUnwraps<'tcx> {
+    /// Collects the unwrapped `Result`s in `block`, the body of a function returning `ret_ty`,
+    /// outside of closures. If `ret_ty` is `()`, only the `Result`s whose error can be converted
+    /// to a `Box<dyn Error>` are collected.
+    pub fn collect(cx: &LateContext<'tcx>, block: &'tcx Block<'tcx>, ret_ty: Ty<'tcx>) -> Self {
+        let mut spans = Vec::new();
+        let has_unit_return = for_each_expr(block, |e| match e.kind {
+            ExprKind::Ret(_) if ret_ty.is_unit() => ControlFlow::Break(()),
+            // `?` can't be used to return from the function in closures
+            ExprKind::Closure(_) => ControlFlow::Continue(Descend::No),
+            ExprKind::MethodCall(path, recv, ..)
+                if matches!(path.ident.as_str(), "unwrap" | "expect")
+                    && !e.span.from_expansion()
+                    && recv.span.ctxt() == e.span.ctxt()
+                    && is_question_mark_convertible(cx, recv, ret_ty) =>
+            {
+                spans.push(e.span.with_lo(recv.span.hi()));
+                ControlFlow::Continue(Descend::Yes)
+            },
+            _ => ControlFlow::Continue(Descend::Yes),
+        })
+        .is_some();
+        Self {
+            block,
+            spans,
+            has_unit_return,
+        }
+    }
+
+    /// Suggests to return `result_type` from the function, whose missing return type is at
+    /// `ret_span`, to replace the unwraps with `?` and to return `Ok(())` at the end. Returns
+    /// `None` if the function contains `return`s or its body comes from a macro.
+    pub fn return_result_sugg(
+        &self,
+        cx: &LateContext<'_>,
+        ret_span: Span,
+        result_type: &str,
+    ) -> Option<Vec<(Span, String)>> {
+        if self.has_unit_return || self.block.span.from_expansion() {
+            return None;
+        }
+        let mut sugg = vec![(ret_span, format!("-> {result_type} "))];
+        sugg.extend(self.spans.iter().map(|&span| (span, "?".into())));
+        if let Some(tail) = self.block.expr {
+            sugg.push((tail.span.shrink_to_hi(), ";".into()));
+        }
+        // On its own line before the closing brace
+        let close = self.block.span.with_lo(self.block.span.hi() - BytePos(1));
+        let indent = " ".repeat(indent_of(cx, close).unwrap_or(0));
+        sugg.push((close.shrink_to_lo(), format!("    Ok(())\n{indent}")));
+        Some(sugg)
+    }
+}
+
+/// Checks if `?` can be applied to `recv` in a function returning `ret_ty`, or in a function
+/// returning `Result<(), Box<dyn Error>>` if `ret_ty` is `()`.
+fn is_question_mark_convertible<'tcx>(cx: &LateContext<'tcx>, recv: &Expr<'_>, ret_ty: Ty<'tcx>) -> bool {
+    let ty = cx.typeck_results().expr_ty(recv);
+    let ty::Adt(_, substs) = ty.kind() else {
+        return false;
+    };
+    if !is_type_diagnostic_item(cx, ty, sym::Result) {
+        return false;
+    }
+    let err_ty = substs.type_at(1);
+    if ret_ty.is_unit() {
+        cx.tcx
+            .get_diagnostic_item(sym::Error)
+            .map_or(false, |error_trait| implements_trait(cx, err_ty, error_trait, &[]))
+    } else if let ty::Adt(_, ret_substs) = ret_ty.kind()
+        && is_type_diagnostic_item(cx, ret_ty, sym::Result)
+        && let Some(from_trait) = cx.tcx.get_diagnostic_item(sym::From)
+    {
+        implements_trait(cx, ret_substs.type_at(1), from_trait, &[err_ty.into()])
+    } else {
+        false
+    }
+}
@@ -2,11 +2,13 @@
 
 #![allow(clippy::module_name_repetitions)]
 
+use rustc_ast::util::parser::{PREC_POSTFIX, PREC_PREFIX};
 use rustc_errors::Applicability;
-use rustc_hir::{Expr, ExprKind};
+use rustc_hir::{BorrowKind, Expr, ExprKind};
+use rustc_lexer::{tokenize, TokenKind};
 use rustc_lint::{LateContext, LintContext};
 use rustc_session::Session;
-use rustc_span::hygiene;
+use rustc_span::hygiene::{self, ExpnKind, MacroKind};
 use rustc_span::source_map::{original_sp, SourceMap};
 use rustc_span::{BytePos, Pos, Span, SpanData, SyntaxContext, DUMMY_SP};
 use std::borrow::Cow;
@@ -93,6 +95,58 @@ pub fn snippet_indent<T: LintContext>(cx: &T, span: Span) -> Option<String> {
     })
 }
 
+/// Makes sure the comments within `span` survive replacing it with `sugg`.
+///
+/// Comments which don't already appear in `sugg` are moved in front of it, each on its own line.
+/// This is only done when `span` starts its line, as the comments would otherwise end up in the
+/// middle of the surrounding code. Returns `None` in that case.
+///
+/// ```rust,ignore
+/// let _ = match x {
+///     // comment
+///     'a' => true,
+///     _ => false,
+/// };
+/// // `None`, the comment would end up after `let _ =`
+/// ```
+pub fn sugg_with_comments<T: LintContext>(cx: &T, span: Span, sugg: &str) -> Option<String> {
+    if span.from_expansion() {
+        return Some(sugg.into());
+    }
+    let Some(snip) = snippet_opt(cx, span) else {
+        return Some(sugg.into());
+    };
+    let mut pos = 0;
+    let missing: Vec<_> = tokenize(&snip)
+        .filter_map(|token| {
+            let range = pos..pos + token.len as usize;
+            pos = range.end;
+            matches!(
+                token.kind,
+                TokenKind::LineComment { .. } | TokenKind::BlockComment { .. }
+            )
+            .then_some(&snip[range])
+        })
+        .filter(|comment| !sugg.contains(comment))
+        .collect();
+    if missing.is_empty() {
+        return Some(sugg.into());
+    }
+    if first_char_in_first_line(cx, span) != Some(span.lo()) {
+        return None;
+    }
+
+    let indent = snippet_indent(cx, span).unwrap_or_default();
+    let mut res = String::new();
+    for comment in missing {
+        res.push_str(comment);
+        res.push('\n');
+        res.push_str(&indent);
+    }
+    res.push_str(sugg);
+    Some(res)
+}
+
 // If the snippet is empty, it's an attribute that was inserted during macro
 // expansion and we want to ignore those, because they could come from external
 // sources that the user has no control over.
@@ -378,6 +432,171 @@ pub fn walk_span_to_context(span: Span, outer: SyntaxContext) -> Option<Span> {
     (outer_span.ctxt() == outer).then_some(outer_span)
 }
 
+/// A piece of code making up a snippet returned by [`snippet_pieces`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SnippetPiece {
+    /// Code written at the target context. This includes whole macro calls and desugared
+    /// expressions.
+    Source(String),
+    /// Code taken from the definition of a macro. It may refer to items which are only in scope
+    /// there.
+    MacroBody(String),
+}
+
+/// Reconstructs the code for `expr` as it could be written at the `outer` context.
+///
+/// Unlike [`snippet_with_context`] this doesn't fall back to the whole macro call when only part of
+/// the expression comes from the macro's arguments. Instead the parts written inside the macro's
+/// definition are rebuilt from its source, and the arguments are taken from the macro call. The
+/// pieces are returned in order, and adjacent pieces of the same kind are merged.
+///
+/// Returns `None` if some part of the expression can't be rebuilt, e.g. because it comes from a
+/// procedural macro or refers to a macro variable such as `$crate`.
+///
+/// Given the following
+///
+/// ```rust,ignore
+/// macro_rules! m { ($e:expr) => { std::rc::Rc::new($e) }; }
+/// vec![m!(x); 2];
+/// ```
+///
+/// With the element of the `vec!` call and the context of the `vec!` call this will return
+/// `[Source("m!(x)")]`. With the call to `Rc::new` inside of `m!` and the context of `m!`'s caller
+/// this will instead return `[MacroBody("std::rc::Rc::new("), Source("x"), MacroBody(")")]`.
+pub fn snippet_pieces(cx: &LateContext<'_>, expr: &Expr<'_>, outer: SyntaxContext) -> Option<Vec<SnippetPiece>> {
+    let parent_ctxt = cx.tcx.hir().span(cx.tcx.hir().parent_id(expr.hir_id)).ctxt();
+    let mut pieces = Vec::new();
+    push_snippet_pieces(cx, expr, parent_ctxt, outer, &mut pieces)?;
+    Some(pieces)
+}
+
+/// Same as [`snippet_pieces`], but joins the pieces into a single snippet. If the expression can't
+/// be rebuilt this falls back to [`snippet_with_context`].
+///
+/// Applicability is handled as in [`snippet_with_applicability`], except that code taken from a
+/// macro's definition sets it to `MaybeIncorrect`.
+pub fn snippet_with_macro_args<'a>(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    outer: SyntaxContext,
+    default: &'a str,
+    applicability: &mut Applicability,
+) -> Cow<'a, str> {
+    let Some(pieces) = snippet_pieces(cx, expr, outer) else {
+        return snippet_with_context(cx, expr.span, outer, default, applicability).0;
+    };
+    let mut snip = String::new();
+    for piece in pieces {
+        match piece {
+            SnippetPiece::Source(code) => snip.push_str(&code),
+            SnippetPiece::MacroBody(code) => {
+                if *applicability != Applicability::Unspecified {
+                    *applicability = Applicability::MaybeIncorrect;
+                }
+                snip.push_str(&code);
+            },
+        }
+    }
+    Cow::Owned(snip)
+}
+
+fn push_piece(pieces: &mut Vec<SnippetPiece>, piece: SnippetPiece) {
+    match (pieces.last_mut(), piece) {
+        (Some(SnippetPiece::Source(last)), SnippetPiece::Source(code))
+        | (Some(SnippetPiece::MacroBody(last)), SnippetPiece::MacroBody(code)) => last.push_str(&code),
+        (_, piece) => pieces.push(piece),
+    }
+}
+
+fn push_snippet_pieces(
+    cx: &LateContext<'_>,
+    expr: &Expr<'_>,
+    parent_ctxt: SyntaxContext,
+    outer: SyntaxContext,
+    pieces: &mut Vec<SnippetPiece>,
+) -> Option<()> {
+    let ctxt = expr.span.ctxt();
+    if ctxt == outer {
+        push_piece(pieces, SnippetPiece::Source(snippet_opt(cx, expr.span)?));
+        return Some(());
+    }
+    let expn_data = ctxt.outer_expn_data();
+    // The whole expression is the result of a macro called at the target context. If the parent
+    // comes from the same macro, e.g. when the macro's body calls `vec!`, only part of the macro's
+    // result is wanted.
+    if !parent_ctxt.outer_expn().is_descendant_of(ctxt.outer_expn()) && expn_data.call_site.ctxt() == outer {
+        push_piece(pieces, SnippetPiece::Source(snippet_opt(cx, expn_data.call_site)?));
+        return Some(());
+    }
+    // Only `macro_rules!` bodies can be copied. Spans from other expansions point back into the
+    // call site.
+    if !matches!(expn_data.kind, ExpnKind::Macro(MacroKind::Bang, _)) || expn_data.call_site.contains(expr.span) {
+        return None;
+    }
+
+    let body_piece = |span: Span| {
+        snippet_opt(cx, span)
+            .filter(|code| !code.contains('$'))
+            .map(SnippetPiece::MacroBody)
+    };
+    let push_child = |pieces: &mut Vec<SnippetPiece>, child: &Expr<'_>, min_prec: i8| {
+        let needs_par = child.precedence().order() < min_prec;
+        if needs_par {
+            push_piece(pieces, SnippetPiece::MacroBody("(".into()));
+        }
+        push_snippet_pieces(cx, child, ctxt, outer, pieces)?;
+        if needs_par {
+            push_piece(pieces, SnippetPiece::MacroBody(")".into()));
+        }
+        Some(())
+    };
+    let prec = expr.precedence().order();
+    match expr.kind {
+        ExprKind::Lit(_) | ExprKind::Path(_) => push_piece(pieces, body_piece(expr.span)?),
+        ExprKind::Call(callee, args) => {
+            push_child(pieces, callee, PREC_POSTFIX)?;
+            push_piece(pieces, SnippetPiece::MacroBody("(".into()));
+            for (i, arg) in args.iter().enumerate() {
+                if i != 0 {
+                    push_piece(pieces, SnippetPiece::MacroBody(", ".into()));
+                }
+                push_child(pieces, arg, i8::MIN)?;
+            }
+            push_piece(pieces, SnippetPiece::MacroBody(")".into()));
+        },
+        ExprKind::MethodCall(path, receiver, args, _) if path.args.is_none() => {
+            push_child(pieces, receiver, PREC_POSTFIX)?;
+            push_piece(pieces, SnippetPiece::MacroBody(format!(".{}(", path.ident)));
+            for (i, arg) in args.iter().enumerate() {
+                if i != 0 {
+                    push_piece(pieces, SnippetPiece::MacroBody(", ".into()));
+                }
+                push_child(pieces, arg, i8::MIN)?;
+            }
+            push_piece(pieces, SnippetPiece::MacroBody(")".into()));
+        },
+        ExprKind::Field(base, ident) => {
+            push_child(pieces, base, PREC_POSTFIX)?;
+            push_piece(pieces, SnippetPiece::MacroBody(format!(".{ident}")));
+        },
+        ExprKind::AddrOf(BorrowKind::Ref, mutability, inner) => {
+            push_piece(pieces, SnippetPiece::MacroBody(format!("&{}", mutability.prefix_str())));
+            push_child(pieces, inner, PREC_PREFIX)?;
+        },
+        ExprKind::Unary(op, inner) => {
+            push_piece(pieces, SnippetPiece::MacroBody(op.as_str().into()));
+            push_child(pieces, inner, PREC_PREFIX)?;
+        },
+        ExprKind::Binary(op, lhs, rhs) => {
+            push_child(pieces, lhs, prec)?;
+            push_piece(pieces, SnippetPiece::MacroBody(format!(" {} ", op.node.as_str())));
+            push_child(pieces, rhs, prec + 1)?;
+        },
+        _ => return None,
+    }
+    Some(())
+}
+
 /// Removes block comments from the given `Vec` of lines.
 ///
 /// # Examples
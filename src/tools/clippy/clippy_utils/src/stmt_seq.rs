@@ -0,0 +1,252 @@
+//! Utilities for matching sequences of statements in a block.
+//!
+//! A sequence is described by a list of [`StmtPat`]s which are matched against consecutive
+//! statements, with the tail expression of the block as the last item. Patterns can bind locals
+//! to names in [`Captures`], which later patterns use to refer to them. E.g. "a `let` binding of
+//! `x`, any statements not using `x`, then a method call on `x`" is written as:
+//!
+//! ```rust,ignore
+//! let pats = [
+//!     StmtPat::one(|caps, item| {
+//!         if let BlockItem::Stmt(Stmt { kind: StmtKind::Local(local), .. }) = item
+//!             && let PatKind::Binding(_, id, ..) = local.pat.kind
+//!         {
+//!             caps.bind("x", id);
+//!             true
+//!         } else {
+//!             false
+//!         }
+//!     }),
+//!     StmtPat::Unrelated,
+//!     StmtPat::one(|caps, item| {
+//!         matches!(item.expr(), Some(Expr { kind: ExprKind::MethodCall(_, recv, ..), .. })
+//!             if path_to_local_id(recv, caps.local("x")))
+//!     }),
+//! ];
+//! for m in find_stmt_seqs(cx, block, &pats) {
+//!     // `m.parts[2][0]` is the method call
+//! }
+//! ```
+//!
+//! See lints:
+//!  - manual-hash-one
+//!  - manual-retain-with-index-loop
+//!  - manual-swap
+//!  - vec-init-then-push
+
+use crate::visitors::is_local_used;
+use rustc_hir::{Block, Expr, HirId, Stmt, StmtKind};
+use rustc_lint::LateContext;
+use rustc_span::Span;
+
+/// A statement or the tail expression of a block.
+#[derive(Clone, Copy, Debug)]
+pub enum BlockItem<'tcx> {
+    Stmt(&'tcx Stmt<'tcx>),
+    Tail(&'tcx Expr<'tcx>),
+}
+
+impl<'tcx> BlockItem<'tcx> {
+    pub fn span(self) -> Span {
+        match self {
+            Self::Stmt(stmt) => stmt.span,
+            Self::Tail(expr) => expr.span,
+        }
+    }
+
+    /// Gets the expression of an expression statement or the tail expression, or the initializer
+    /// of a `let` statement.
+    pub fn expr(self) -> Option<&'tcx Expr<'tcx>> {
+        match self {
+            Self::Stmt(stmt) => match stmt.kind {
+                StmtKind::Expr(e) | StmtKind::Semi(e) => Some(e),
+                StmtKind::Local(local) => local.init,
+                StmtKind::Item(_) => None,
+            },
+            Self::Tail(expr) => Some(expr),
+        }
+    }
+
+    fn is_local_used(self, cx: &LateContext<'tcx>, id: HirId) -> bool {
+        match self {
+            Self::Stmt(stmt) => is_local_used(cx, stmt, id),
+            Self::Tail(expr) => is_local_used(cx, expr, id),
+        }
+    }
+}
+
+/// The locals bound by the patterns of a sequence, by name.
+#[derive(Clone, Debug, Default)]
+pub struct Captures {
+    locals: Vec<(&'static str, HirId)>,
+}
+
+impl Captures {
+    /// Binds `name` to the local `id`, replacing any previous binding of `name`.
+    pub fn bind(&mut self, name: &'static str, id: HirId) {
+        if let Some(local) = self.locals.iter_mut().find(|(n, _)| *n == name) {
+            local.1 = id;
+        } else {
+            self.locals.push((name, id));
+        }
+    }
+
+    pub fn get(&self, name: &str) -> Option<HirId> {
+        self.locals.iter().find(|(n, _)| *n == name).map(|&(_, id)| id)
+    }
+
+    /// Gets the local bound to `name`.
+    ///
+    /// # Panics
+    /// Panics if `name` isn't bound by one of the previous patterns.
+    #[track_caller]
+    pub fn local(&self, name: &str) -> HirId {
+        self.get(name)
+            .unwrap_or_else(|| panic!("`{name}` isn't bound by the previous patterns"))
+    }
+}
+
+type ItemPredicate<'p, 'tcx> = Box<dyn Fn(&mut Captures, BlockItem<'tcx>) -> bool + 'p>;
+
+/// A pattern matching a part of a sequence of statements.
+pub enum StmtPat<'p, 'tcx> {
+    /// Exactly one item for which the predicate returns `true`. The predicate may bind locals
+    /// even when it returns `false`, the bindings are discarded in that case.
+    One(ItemPredicate<'p, 'tcx>),
+    /// One or more consecutive items for which the predicate returns `true`. As many items as
+    /// possible are matched.
+    Many(ItemPredicate<'p, 'tcx>),
+    /// Zero or more statements which don't use any of the locals bound so far. As few statements
+    /// as possible are matched. This never matches the tail expression.
+    Unrelated,
+}
+
+impl<'p, 'tcx> StmtPat<'p, 'tcx> {
+    pub fn one(f: impl Fn(&mut Captures, BlockItem<'tcx>) -> bool + 'p) -> Self {
+        Self::One(Box::new(f))
+    }
+
+    pub fn many(f: impl Fn(&mut Captures, BlockItem<'tcx>) -> bool + 'p) -> Self {
+        Self::Many(Box::new(f))
+    }
+}
+
+/// A match of a sequence of patterns.
+#[derive(Debug)]
+pub struct StmtSeqMatch<'tcx> {
+    /// The items matched by each pattern, in the order of the patterns.
+    pub parts: Vec<Vec<BlockItem<'tcx>>>,
+    pub captures: Captures,
+}
+
+impl<'tcx> StmtSeqMatch<'tcx> {
+    /// Gets the items of the whole match.
+    pub fn items(&self) -> impl Iterator<Item = BlockItem<'tcx>> + '_ {
+        self.parts.iter().flatten().copied()
+    }
+
+    /// Gets the span from the first to the last matched item. Returns `None` if no item is
+    /// matched, which only happens if all patterns are `StmtPat::Unrelated`.
+    pub fn span(&self) -> Option<Span> {
+        let mut items = self.items();
+        let first = items.next()?.span();
+        Some(items.last().map_or(first, |last| first.to(last.span())))
+    }
+}
+
+/// Finds the non-overlapping matches of `pats` in `block`, in order. Each match starts at the first
+/// item after the previous match where the whole sequence matches.
+pub fn find_stmt_seqs<'tcx>(
+    cx: &LateContext<'tcx>,
+    block: &'tcx Block<'tcx>,
+    pats: &[StmtPat<'_, 'tcx>],
+) -> Vec<StmtSeqMatch<'tcx>> {
+    let items: Vec<_> = block
+        .stmts
+        .iter()
+        .map(BlockItem::Stmt)
+        .chain(block.expr.map(BlockItem::Tail))
+        .collect();
+
+    let mut matches = Vec::new();
+    let mut start = 0;
+    while start < items.len() {
+        let mut parts = Vec::with_capacity(pats.len());
+        if let Some((end, captures)) = match_at(cx, &items, start, pats, Captures::default(), &mut parts)
+            && end > start
+        {
+            matches.push(StmtSeqMatch { parts, captures });
+            start = end;
+        } else {
+            start += 1;
+        }
+    }
+    matches
+}
+
+/// Matches `pats` against the items starting at `start`, returning the index after the last
+/// matched item and the captures. The items matched by each pattern are pushed to `parts`.
+fn match_at<'tcx>(
+    cx: &LateContext<'tcx>,
+    items: &[BlockItem<'tcx>],
+    start: usize,
+    pats: &[StmtPat<'_, 'tcx>],
+    captures: Captures,
+    parts: &mut Vec<Vec<BlockItem<'tcx>>>,
+) -> Option<(usize, Captures)> {
+    let Some((pat, rest)) = pats.split_first() else {
+        return Some((start, captures));
+    };
+    let try_rest = |end: usize, captures: Captures, parts: &mut Vec<Vec<BlockItem<'tcx>>>| {
+        parts.push(items[start..end].to_vec());
+        let res = match_at(cx, items, end, rest, captures, parts);
+        if res.is_none() {
+            parts.pop();
+        }
+        res
+    };
+
+    match pat {
+        StmtPat::One(f) => {
+            let mut captures = captures;
+            let item = *items.get(start)?;
+            if f(&mut captures, item) {
+                try_rest(start + 1, captures, parts)
+            } else {
+                None
+            }
+        },
+        StmtPat::Many(f) => {
+            // Find the longest run of matching items, then backtrack to shorter ones.
+            let mut runs = Vec::new();
+            let mut captures = captures;
+            for (i, &item) in items.iter().enumerate().skip(start) {
+                let mut next = captures.clone();
+                if !f(&mut next, item) {
+                    break;
+                }
+                runs.push((i + 1, next.clone()));
+                captures = next;
+            }
+            runs.into_iter()
+                .rev()
+                .find_map(|(end, captures)| try_rest(end, captures, parts))
+        },
+        StmtPat::Unrelated => {
+            let mut end = start;
+            loop {
+                if let Some(res) = try_rest(end, captures.clone(), parts) {
+                    return Some(res);
+                }
+                match items.get(end) {
+                    Some(&item @ BlockItem::Stmt(_))
+                        if !captures.locals.iter().any(|&(_, id)| item.is_local_used(cx, id)) =>
+                    {
+                        end += 1;
+                    },
+                    _ => return None,
+                }
+            }
+        },
+    }
+}
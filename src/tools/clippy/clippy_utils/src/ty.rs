@@ -247,6 +247,33 @@ pub fn implements_trait_with_env<'tcx>(
         .must_apply_modulo_regions()
 }
 
+/// Caches the results of [`implements_trait`] for the lifetime of a lint pass.
+///
+/// Each query creates a new inference context, which adds up for lints checking every expression
+/// of a given kind. Results depend on the `ParamEnv`, which is part of the key, so a single cache
+/// can be kept for the whole crate.
+#[derive(Default)]
+pub struct ImplementsTraitCache<'tcx> {
+    results: FxHashMap<(Ty<'tcx>, DefId, SubstsRef<'tcx>, ParamEnv<'tcx>), bool>,
+}
+
+impl<'tcx> ImplementsTraitCache<'tcx> {
+    /// Same as [`implements_trait`], but reuses the result of previous identical queries.
+    pub fn implements_trait(
+        &mut self,
+        cx: &LateContext<'tcx>,
+        ty: Ty<'tcx>,
+        trait_id: DefId,
+        ty_params: &[GenericArg<'tcx>],
+    ) -> bool {
+        let key = (ty, trait_id, cx.tcx.mk_substs(ty_params), cx.param_env);
+        *self
+            .results
+            .entry(key)
+            .or_insert_with(|| implements_trait(cx, ty, trait_id, ty_params))
+    }
+}
+
 /// Checks whether this type implements `Drop`.
 pub fn has_drop<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>) -> bool {
     match ty.ty_adt_def() {
@@ -425,8 +452,8 @@ fn needs_ordered_drop_inner<'tcx>(cx: &LateContext<'tcx>, ty: Ty<'tcx>, seen: &m
                 get_type_diagnostic_name(cx, ty),
                 Some(sym::HashSet | sym::Rc | sym::Arc | sym::cstring_type)
             )
-            || match_type(cx, ty, &paths::WEAK_RC)
-            || match_type(cx, ty, &paths::WEAK_ARC)
+            || paths::WEAK_RC.matches_ty(cx, ty)
+            || paths::WEAK_ARC.matches_ty(cx, ty)
         {
             // Check all of the generic arguments.
             if let ty::Adt(_, subs) = ty.kind() {
@@ -0,0 +1,228 @@
+//! Utilities for inferring the range of values an integer expression can take.
+//!
+//! The analysis is flow-insensitive: the range is derived from constants, immutable locals and
+//! their initializers, `for` loops over constant ranges, values widened from a smaller type, and
+//! operations which bound their result, e.g. `x % 10`, `x & 0xff`, `x >> 24` or `x.min(100)`.
+
+use crate::comparisons::int_range;
+use crate::consts::{constant, Constant};
+use crate::{expr_or_init, higher, path_to_local, sext};
+use rustc_ast::ast::RangeLimits;
+use rustc_hir::{BinOpKind, BindingAnnotation, Expr, ExprKind, HirId, Node, PatKind};
+use rustc_lint::LateContext;
+use rustc_middle::ty::{self, Ty, TyCtxt};
+
+/// An inclusive range of integer values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ValueRange {
+    pub min: i128,
+    pub max: i128,
+    /// Whether both bounds are actually taken by the expression whenever it's evaluated often
+    /// enough, e.g. for a constant or the counter of a `for` loop over a constant range. Otherwise
+    /// the range only bounds the values.
+    pub reached: bool,
+}
+
+impl ValueRange {
+    fn reached(min: i128, max: i128) -> Self {
+        Self {
+            min,
+            max,
+            reached: true,
+        }
+    }
+
+    fn bounds(min: i128, max: i128) -> Self {
+        Self {
+            min,
+            max,
+            reached: false,
+        }
+    }
+
+    /// Gets the range of all values of the integer type `ty`.
+    pub fn of_ty(tcx: TyCtxt<'_>, ty: Ty<'_>) -> Option<Self> {
+        int_range(tcx, ty).map(|(min, max)| Self::bounds(min, max))
+    }
+
+    /// Checks if all values of the range fit into the integer type `ty`. `usize` and `isize` are
+    /// assumed to have 32 bits.
+    pub fn fits_into<'tcx>(self, tcx: TyCtxt<'tcx>, ty: Ty<'tcx>) -> bool {
+        let ty = match ty.kind() {
+            ty::Uint(ty::UintTy::Usize) => tcx.types.u32,
+            ty::Int(ty::IntTy::Isize) => tcx.types.i32,
+            _ => ty,
+        };
+        int_range(tcx, ty).map_or(false, |(min, max)| min <= self.min && self.max <= max)
+    }
+
+    fn intersect(self, other: Self) -> Self {
+        if self.min >= other.min && self.max <= other.max {
+            self
+        } else if other.min >= self.min && other.max <= self.max {
+            other
+        } else {
+            Self::bounds(self.min.max(other.min), self.max.min(other.max))
+        }
+    }
+}
+
+/// Infers the range of values of the integer expression `e`. Returns `None` if the range can't be
+/// inferred, in which case only the range of its type is known.
+pub fn value_range<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'tcx>) -> Option<ValueRange> {
+    let ty = cx.typeck_results().expr_ty(e);
+    let ty_range = ValueRange::of_ty(cx.tcx, ty)?;
+    if let Some(value) = int_constant(cx, e, ty) {
+        return Some(ValueRange::reached(value, value));
+    }
+
+    let init = expr_or_init(cx, e);
+    if init.hir_id != e.hir_id {
+        return value_range(cx, init);
+    }
+    if let Some(id) = path_to_local(e) {
+        return for_loop_counter_range(cx, id);
+    }
+    let range_or_ty =
+        |e: &'tcx Expr<'tcx>| value_range(cx, e).or_else(|| ValueRange::of_ty(cx.tcx, cx.typeck_results().expr_ty(e)));
+    let range = match e.kind {
+        ExprKind::Block(block, _) => return value_range(cx, block.expr?),
+        ExprKind::Cast(inner, _) => {
+            let range = range_or_ty(inner)?;
+            return (ty_range.min <= range.min && range.max <= ty_range.max).then_some(range);
+        },
+        ExprKind::Binary(op, lhs, rhs) => {
+            let range = binary_range(op.node, range_or_ty(lhs)?, range_or_ty(rhs)?)?;
+            // The result wraps around if it doesn't fit into the type, so it might take any value
+            if matches!(op.node, BinOpKind::Add | BinOpKind::Sub | BinOpKind::Mul)
+                && !(ty_range.min <= range.min && range.max <= ty_range.max)
+            {
+                return None;
+            }
+            range
+        },
+        ExprKind::MethodCall(path, recv, args, _) => {
+            let recv_ty = cx.typeck_results().expr_ty_adjusted(recv).peel_refs();
+            // The array is unsized to a slice to call `len`
+            if let ty::Array(_, len) = cx.typeck_results().expr_ty(recv).peel_refs().kind()
+                && path.ident.as_str() == "len"
+            {
+                let len = i128::try_from(len.try_eval_target_usize(cx.tcx, cx.param_env)?).ok()?;
+                return Some(ValueRange::reached(len, len));
+            }
+            let recv = range_or_ty(recv)?;
+            let args = args.iter().map(range_or_ty).collect::<Option<Vec<_>>>()?;
+            method_range(path.ident.as_str(), recv_ty, recv, &args)?
+        },
+        _ => return None,
+    };
+    Some(range.intersect(ty_range))
+}
+
+/// Gets the range of the result of `lhs op rhs`.
+fn binary_range(op: BinOpKind, lhs: ValueRange, rhs: ValueRange) -> Option<ValueRange> {
+    let rhs_value = (rhs.min == rhs.max).then_some(rhs.min);
+    let range = match op {
+        BinOpKind::Rem if rhs_value.map_or(false, |n| n != 0) => {
+            let limit = rhs.min.checked_abs()? - 1;
+            if lhs.min >= 0 {
+                ValueRange::bounds(0, limit)
+            } else {
+                ValueRange::bounds(-limit, limit)
+            }
+        },
+        BinOpKind::BitAnd if lhs.min >= 0 || rhs.min >= 0 => {
+            let max = [lhs, rhs]
+                .into_iter()
+                .filter(|range| range.min >= 0)
+                .map(|range| range.max)
+                .min()?;
+            ValueRange::bounds(0, max)
+        },
+        BinOpKind::Div if rhs_value.map_or(false, |n| n > 0) => {
+            ValueRange::bounds(lhs.min / rhs.min, lhs.max / rhs.min)
+        },
+        BinOpKind::Shr if lhs.min >= 0 && rhs_value.map_or(false, |n| (0..128).contains(&n)) => {
+            ValueRange::bounds(lhs.min >> rhs.min, lhs.max >> rhs.min)
+        },
+        BinOpKind::Add => ValueRange::bounds(lhs.min.checked_add(rhs.min)?, lhs.max.checked_add(rhs.max)?),
+        BinOpKind::Sub => ValueRange::bounds(lhs.min.checked_sub(rhs.max)?, lhs.max.checked_sub(rhs.min)?),
+        BinOpKind::Mul => {
+            let products = [
+                lhs.min.checked_mul(rhs.min)?,
+                lhs.min.checked_mul(rhs.max)?,
+                lhs.max.checked_mul(rhs.min)?,
+                lhs.max.checked_mul(rhs.max)?,
+            ];
+            ValueRange::bounds(products.into_iter().min()?, products.into_iter().max()?)
+        },
+        _ => return None,
+    };
+    Some(range)
+}
+
+/// Gets the range of the result of calling the integer method `name` on a receiver of type
+/// `recv_ty`.
+fn method_range(name: &str, recv_ty: Ty<'_>, recv: ValueRange, args: &[ValueRange]) -> Option<ValueRange> {
+    let range = match (name, args) {
+        ("min", [other]) => ValueRange::bounds(recv.min.min(other.min), recv.max.min(other.max)),
+        ("max", [other]) => ValueRange::bounds(recv.min.max(other.min), recv.max.max(other.max)),
+        ("clamp", [lo, hi]) => ValueRange::bounds(lo.min, hi.max),
+        ("rem_euclid", [n]) if n.min == n.max && n.min != 0 => ValueRange::bounds(0, n.min.checked_abs()? - 1),
+        ("signum", []) => ValueRange::bounds(-1, 1),
+        ("count_ones" | "count_zeros" | "leading_ones" | "leading_zeros" | "trailing_ones" | "trailing_zeros", []) => {
+            let bits = match recv_ty.kind() {
+                ty::Int(ity) => ity.bit_width(),
+                ty::Uint(uty) => uty.bit_width(),
+                _ => None,
+            };
+            ValueRange::bounds(0, i128::from(bits.unwrap_or(64)))
+        },
+        _ => return None,
+    };
+    Some(range)
+}
+
+/// Gets the range of an immutable counter of a `for` loop over a constant range, e.g. `i` in
+/// `for i in 0..10`.
+fn for_loop_counter_range<'tcx>(cx: &LateContext<'tcx>, id: HirId) -> Option<ValueRange> {
+    let Some(Node::Pat(pat)) = cx.tcx.hir().find(id) else {
+        return None;
+    };
+    if !matches!(pat.kind, PatKind::Binding(BindingAnnotation::NONE, ..)) {
+        return None;
+    }
+    let for_loop = cx.tcx.hir().parent_iter(id).find_map(|(_, node)| match node {
+        Node::Expr(e) => higher::ForLoop::hir(e),
+        _ => None,
+    })?;
+    if for_loop.pat.hir_id != id {
+        return None;
+    }
+    let higher::Range {
+        start: Some(start),
+        end: Some(end),
+        limits,
+    } = higher::Range::hir(for_loop.arg)?
+    else {
+        return None;
+    };
+    let ty = cx.typeck_results().expr_ty(start);
+    let start = int_constant(cx, start, ty)?;
+    let end = int_constant(cx, end, ty)?;
+    let max = match limits {
+        RangeLimits::HalfOpen => end.checked_sub(1)?,
+        RangeLimits::Closed => end,
+    };
+    (start <= max).then(|| ValueRange::reached(start, max))
+}
+
+/// Evaluates `e` to an integer constant of type `ty`.
+fn int_constant<'tcx>(cx: &LateContext<'tcx>, e: &'tcx Expr<'_>, ty: Ty<'tcx>) -> Option<i128> {
+    match (constant(cx, cx.typeck_results(), e)?.0, ty.kind()) {
+        (Constant::Int(value), &ty::Int(ity)) => Some(sext(cx.tcx, value, ity)),
+        (Constant::Int(value), ty::Uint(_)) => i128::try_from(value).ok(),
+        (Constant::Bool(value), ty::Bool) => Some(i128::from(value)),
+        _ => None,
+    }
+}
@@ -137,7 +137,7 @@ pub fn declare_clippy_lint(input: TokenStream) -> TokenStream {
         match category.as_str() {
             "correctness" => "Deny",
             "style" | "suspicious" | "complexity" | "perf" | "internal_warn" => "Warn",
-            "pedantic" | "restriction" | "cargo" | "nursery" | "internal" => "Allow",
+            "pedantic" | "restriction" | "cargo" | "nursery" | "semver" | "internal" => "Allow",
             _ => panic!("unknown category {category}"),
         },
     );
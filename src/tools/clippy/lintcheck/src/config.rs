@@ -1,5 +1,5 @@
 use clap::Parser;
-use std::{num::NonZeroUsize, path::PathBuf};
+use std::{fs, num::NonZeroUsize, path::PathBuf};
 
 #[derive(Clone, Debug, Parser)]
 pub(crate) struct LintcheckConfig {
@@ -28,6 +28,13 @@ pub(crate) struct LintcheckConfig {
     /// Only process a single crate on the list
     #[clap(long, value_name = "CRATE")]
     pub only: Option<String>,
+    /// Check the crate or workspace at PATH instead of the crates on the list
+    #[clap(long, value_name = "PATH", conflicts_with_all(["git", "only"]))]
+    pub path: Option<PathBuf>,
+    /// Check the crate or workspace in the git repository at URL, with the commit, branch or tag
+    /// REV checked out, instead of the crates on the list
+    #[clap(long, value_name = "URL@REV", value_parser = parse_git_source, conflicts_with("only"))]
+    pub git: Option<GitSource>,
     /// Runs cargo clippy --fix and checks if all suggestions apply
     #[clap(long, conflicts_with("max_jobs"))]
     pub fix: bool,
@@ -40,20 +47,80 @@ pub(crate) struct LintcheckConfig {
     /// Run clippy on the dependencies of crates specified in crates-toml
     #[clap(long, conflicts_with("max_jobs"))]
     pub recursive: bool,
+    /// Compare the warnings of another build of Clippy, whose `cargo-clippy` and `clippy-driver`
+    /// binaries are in DIR, with the ones of this build
+    #[clap(long, value_name = "DIR", conflicts_with("fix"))]
+    pub diff_base: Option<PathBuf>,
+    /// Use the binaries in DIR instead of this build as the candidate of `--diff-base`
+    #[clap(long, value_name = "DIR", requires("diff_base"))]
+    pub diff_candidate: Option<PathBuf>,
+    /// File to save the diff of `--diff-base` in, without the extension
+    #[clap(skip = "")]
+    pub diff_results_path: PathBuf, // Overridden in new()
+    /// File to save the timings and ICEs of each crate in, without the extension. With
+    /// `--diff-base` they're the ones of the candidate.
+    #[clap(skip = "")]
+    pub report_path: PathBuf, // Overridden in new()
+    /// File to save the timings and ICEs of each crate with the base of `--diff-base` in, without
+    /// the extension
+    #[clap(skip = "")]
+    pub base_report_path: PathBuf, // Overridden in new()
+    /// File to save the change of the timings of each crate from the base to the candidate of
+    /// `--diff-base` in, without the extension
+    #[clap(skip = "")]
+    pub report_delta_path: PathBuf, // Overridden in new()
+}
+
+/// The repository and revision given with `--git`
+#[derive(Clone, Debug)]
+pub(crate) struct GitSource {
+    pub url: String,
+    pub rev: String,
+}
+
+fn parse_git_source(arg: &str) -> Result<GitSource, String> {
+    // split at the last `@`, as the URL may contain one, e.g. `git@github.com:user/repo`
+    let Some((url, rev)) = arg.rsplit_once('@') else {
+        return Err(String::from(
+            "expected the URL of the repository and a revision, like `URL@REV`",
+        ));
+    };
+    // without a revision the `@` of the user is the last one, e.g. `ssh://git@host/repo` would be
+    // split into `ssh://git` and `host/repo`, and `git@host:repo` into `git` and `host:repo`
+    let is_missing_rev = rev.contains(':') || url.split_once("://").map_or(false, |(_, rest)| !rest.contains('/'));
+    if url.is_empty() || rev.is_empty() || is_missing_rev {
+        return Err(format!(
+            "expected a revision after the URL of the repository, like `{arg}@REV`"
+        ));
+    }
+    Ok(GitSource {
+        url: url.to_string(),
+        rev: rev.to_string(),
+    })
 }
 
 impl LintcheckConfig {
     pub fn new() -> Self {
         let mut config = LintcheckConfig::parse();
 
+        if let Some(path) = &mut config.path {
+            *path = fs::canonicalize(&*path).unwrap_or_else(|e| panic!("Failed to find {}: {e}", path.display()));
+        }
+
         // for the path where we save the lint results, get the filename without extension (so for
-        // wasd.toml, use "wasd"...)
-        let filename: PathBuf = config.sources_toml_path.file_stem().unwrap().into();
+        // wasd.toml, use "wasd"...), or the name of the crate given with `--path` or `--git`
+        let filename: PathBuf = config
+            .source_name()
+            .map_or_else(|| config.sources_toml_path.file_stem().unwrap().into(), PathBuf::from);
         config.lintcheck_results_path = PathBuf::from(format!(
             "lintcheck-logs/{}_logs.{}",
             filename.display(),
             if config.markdown { "md" } else { "txt" }
         ));
+        config.diff_results_path = PathBuf::from(format!("lintcheck-logs/{}_diff", filename.display()));
+        config.report_path = PathBuf::from(format!("lintcheck-logs/{}_report", filename.display()));
+        config.base_report_path = PathBuf::from(format!("lintcheck-logs/{}_base_report", filename.display()));
+        config.report_delta_path = PathBuf::from(format!("lintcheck-logs/{}_report_delta", filename.display()));
 
         // look at the --threads arg, if 0 is passed, use the threads count
         if config.max_jobs == 0 {
@@ -76,4 +143,51 @@ pub fn new() -> Self {
 
         config
     }
+
+    /// The name of the crate given with `--path` or `--git`: the name of its directory, or of the
+    /// repository
+    pub fn source_name(&self) -> Option<String> {
+        if let Some(path) = &self.path {
+            Some(path.file_name()?.to_string_lossy().into_owned())
+        } else {
+            let url = self.git.as_ref()?.url.trim_end_matches('/');
+            let name = url.rsplit(['/', ':']).next().unwrap_or(url);
+            Some(name.strip_suffix(".git").unwrap_or(name).to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_git_source;
+
+    fn parse(arg: &str) -> Option<(String, String)> {
+        parse_git_source(arg).ok().map(|source| (source.url, source.rev))
+    }
+
+    #[test]
+    fn git_source() {
+        let source = |url: &str, rev: &str| Some((url.to_string(), rev.to_string()));
+        assert_eq!(
+            parse("https://github.com/rust-lang/log@0.4.17"),
+            source("https://github.com/rust-lang/log", "0.4.17")
+        );
+        assert_eq!(
+            parse("ssh://git@github.com/rust-lang/log@feature/x"),
+            source("ssh://git@github.com/rust-lang/log", "feature/x")
+        );
+        assert_eq!(
+            parse("git@github.com:rust-lang/log@main"),
+            source("git@github.com:rust-lang/log", "main")
+        );
+        assert_eq!(parse("../log@02dd4a3"), source("../log", "02dd4a3"));
+    }
+
+    #[test]
+    fn git_source_without_rev() {
+        assert_eq!(parse("https://github.com/rust-lang/log"), None);
+        assert_eq!(parse("ssh://git@github.com/rust-lang/log"), None);
+        assert_eq!(parse("git@github.com:rust-lang/log"), None);
+        assert_eq!(parse("https://github.com/rust-lang/log@"), None);
+    }
 }
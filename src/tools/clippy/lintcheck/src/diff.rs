@@ -0,0 +1,327 @@
+//! In `--diff-base` mode the crates are linted with a base and a candidate build of Clippy, and
+//! the warnings of both are compared. The warnings of a lint in a file which are at the same
+//! position with the same message in both are unchanged. Each of the others is paired up with the
+//! closest warning of the other build with the same message, or else with overlapping lines, as a
+//! changed warning, and the rest are added or removed.
+
+use crate::ClippyWarning;
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::path::Path;
+
+use serde::Serialize;
+
+/// The number of warnings of each kind listed per lint in the summary
+const SAMPLES_PER_LINT: usize = 5;
+
+/// A warning whose position or message is different in the candidate
+#[derive(Debug, Serialize)]
+pub(crate) struct ChangedWarning<'a> {
+    base: &'a ClippyWarning,
+    candidate: &'a ClippyWarning,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct LintDiff<'a> {
+    added: Vec<&'a ClippyWarning>,
+    removed: Vec<&'a ClippyWarning>,
+    changed: Vec<ChangedWarning<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct Diff<'a> {
+    base_version: String,
+    candidate_version: String,
+    /// The differences of each lint, lints without any are left out
+    lints: BTreeMap<&'a str, LintDiff<'a>>,
+}
+
+impl<'a> Diff<'a> {
+    pub fn new(
+        base_version: String,
+        candidate_version: String,
+        base: &'a [ClippyWarning],
+        candidate: &'a [ClippyWarning],
+    ) -> Self {
+        // the warnings of both builds for each lint, crate and file
+        let mut groups: BTreeMap<(&str, &str, &str), (Vec<&ClippyWarning>, Vec<&ClippyWarning>)> = BTreeMap::new();
+        for warning in base {
+            groups.entry(warning.diff_key()).or_default().0.push(warning);
+        }
+        for warning in candidate {
+            groups.entry(warning.diff_key()).or_default().1.push(warning);
+        }
+
+        let mut lints: BTreeMap<&str, LintDiff<'_>> = BTreeMap::new();
+        for ((lint, ..), (mut base, mut candidate)) in groups {
+            base.retain(|warning| {
+                if let Some(i) = candidate.iter().position(|other| other.is_same(warning)) {
+                    candidate.swap_remove(i);
+                    false
+                } else {
+                    true
+                }
+            });
+            if base.is_empty() && candidate.is_empty() {
+                continue;
+            }
+
+            base.sort_by_key(|warning| (warning.line, warning.column));
+            candidate.sort_by_key(|warning| (warning.line, warning.column));
+
+            let lint_diff = lints.entry(lint).or_default();
+            pair_related(&mut base, &mut candidate, &mut lint_diff.changed, |base, candidate| {
+                base.message == candidate.message
+            });
+            pair_related(
+                &mut base,
+                &mut candidate,
+                &mut lint_diff.changed,
+                ClippyWarning::overlaps,
+            );
+            lint_diff.removed.extend(base);
+            lint_diff.added.extend(candidate);
+        }
+
+        Self {
+            base_version,
+            candidate_version,
+            lints,
+        }
+    }
+
+    /// Writes the diff as JSON to `path.json`, and its summary to `path.md`, which is also
+    /// printed
+    pub fn write(&self, path: &Path) {
+        let json_path = path.with_extension("json");
+        let summary_path = path.with_extension("md");
+        let summary = self.summary();
+
+        println!("{summary}");
+        println!(
+            "Writing the diff to {} and {}",
+            json_path.display(),
+            summary_path.display()
+        );
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(json_path, serde_json::to_string_pretty(self).unwrap()).unwrap();
+        fs::write(summary_path, summary).unwrap();
+    }
+
+    fn summary(&self) -> String {
+        let mut summary = String::new();
+        let _: fmt::Result = writeln!(summary, "base: {}", self.base_version.trim());
+        let _: fmt::Result = writeln!(summary, "candidate: {}", self.candidate_version.trim());
+
+        if self.lints.is_empty() {
+            summary.push_str("\nNo differences\n");
+            return summary;
+        }
+
+        summary.push_str("\n| lint | added | removed | changed |\n");
+        summary.push_str("| --- | ---: | ---: | ---: |\n");
+        for (lint, diff) in &self.lints {
+            let _: fmt::Result = writeln!(
+                summary,
+                "| `{lint}` | {} | {} | {} |",
+                diff.added.len(),
+                diff.removed.len(),
+                diff.changed.len()
+            );
+        }
+
+        for (lint, diff) in &self.lints {
+            let _: fmt::Result = writeln!(summary, "\n### `{lint}`");
+            write_samples(&mut summary, "Added", &diff.added);
+            write_samples(&mut summary, "Removed", &diff.removed);
+            if !diff.changed.is_empty() {
+                let _: fmt::Result = writeln!(summary, "\nChanged ({}):\n", diff.changed.len());
+                for changed in diff.changed.iter().take(SAMPLES_PER_LINT) {
+                    write_sample(&mut summary, changed.base);
+                    summary.push_str("  now:\n");
+                    write_sample(&mut summary, changed.candidate);
+                }
+            }
+        }
+
+        summary
+    }
+}
+
+/// Pairs up the warnings of `base` and `candidate` which are related as changed warnings, the
+/// closest first, and removes them
+fn pair_related<'a>(
+    base: &mut Vec<&'a ClippyWarning>,
+    candidate: &mut Vec<&'a ClippyWarning>,
+    changed: &mut Vec<ChangedWarning<'a>>,
+    is_related: impl Fn(&ClippyWarning, &ClippyWarning) -> bool,
+) {
+    let mut related: Vec<(usize, usize)> = (0..base.len())
+        .flat_map(|i| (0..candidate.len()).map(move |j| (i, j)))
+        .filter(|&(i, j)| is_related(base[i], candidate[j]))
+        .collect();
+    related.sort_by_key(|&(i, j)| base[i].line.abs_diff(candidate[j].line));
+
+    let mut base_paired = vec![false; base.len()];
+    let mut candidate_paired = vec![false; candidate.len()];
+    let mut pairs = Vec::new();
+    for (i, j) in related {
+        if !base_paired[i] && !candidate_paired[j] {
+            base_paired[i] = true;
+            candidate_paired[j] = true;
+            pairs.push((i, j));
+        }
+    }
+    // in the order of the base warnings
+    pairs.sort_unstable();
+    changed.extend(pairs.into_iter().map(|(i, j)| ChangedWarning {
+        base: base[i],
+        candidate: candidate[j],
+    }));
+
+    let mut base_paired = base_paired.into_iter();
+    base.retain(|_| !base_paired.next().unwrap());
+    let mut candidate_paired = candidate_paired.into_iter();
+    candidate.retain(|_| !candidate_paired.next().unwrap());
+}
+
+fn write_samples(summary: &mut String, title: &str, warnings: &[&ClippyWarning]) {
+    if warnings.is_empty() {
+        return;
+    }
+    let _: fmt::Result = writeln!(summary, "\n{title} ({}):\n", warnings.len());
+    for warning in warnings.iter().take(SAMPLES_PER_LINT) {
+        write_sample(summary, warning);
+    }
+}
+
+fn write_sample(summary: &mut String, warning: &ClippyWarning) {
+    let _: fmt::Result = writeln!(
+        summary,
+        "- `{}:{}:{}` \"{}\"",
+        warning.file, warning.line, warning.column, warning.message
+    );
+    if !warning.snippet.is_empty() {
+        let _: fmt::Result = writeln!(summary, "  ```rust\n{}\n  ```", indent(&warning.snippet));
+    }
+}
+
+fn indent(snippet: &str) -> String {
+    snippet
+        .lines()
+        .map(|line| format!("  {line}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl ClippyWarning {
+    fn diff_key(&self) -> (&str, &str, &str) {
+        (&self.lint_type, &self.crate_name, &self.file)
+    }
+
+    fn is_same(&self, other: &Self) -> bool {
+        (self.line, self.column, &self.message) == (other.line, other.column, &other.message)
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        self.line <= other.end_line && other.line <= self.end_line
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn warning(line: usize, end_line: usize, message: &str) -> ClippyWarning {
+        ClippyWarning {
+            crate_name: "krate".into(),
+            file: "src/lib.rs".into(),
+            line,
+            column: 5,
+            end_line,
+            lint_type: "clippy::lint".into(),
+            message: message.into(),
+            snippet: String::new(),
+            is_ice: false,
+        }
+    }
+
+    fn diff<'a>(base: &'a [ClippyWarning], candidate: &'a [ClippyWarning]) -> Diff<'a> {
+        Diff::new(String::new(), String::new(), base, candidate)
+    }
+
+    /// The `(base line, candidate line)` of the changed warnings of `clippy::lint`
+    fn changed_lines(diff: &Diff<'_>) -> Vec<(usize, usize)> {
+        diff.lints["clippy::lint"]
+            .changed
+            .iter()
+            .map(|changed| (changed.base.line, changed.candidate.line))
+            .collect()
+    }
+
+    #[test]
+    fn unchanged() {
+        let base = [warning(1, 1, "a"), warning(10, 12, "b")];
+        let candidate = [warning(10, 12, "b"), warning(1, 1, "a")];
+        assert!(diff(&base, &candidate).lints.is_empty());
+    }
+
+    #[test]
+    fn same_message() {
+        let base = [warning(10, 10, "a"), warning(20, 20, "b")];
+        let candidate = [warning(22, 22, "b"), warning(11, 11, "a")];
+        let diff = diff(&base, &candidate);
+        assert_eq!(changed_lines(&diff), [(10, 11), (20, 22)]);
+        assert!(diff.lints["clippy::lint"].added.is_empty());
+        assert!(diff.lints["clippy::lint"].removed.is_empty());
+    }
+
+    #[test]
+    fn closest_with_same_message() {
+        let base = [warning(10, 10, "a"), warning(30, 30, "a")];
+        let candidate = [warning(29, 29, "a")];
+        let diff = diff(&base, &candidate);
+        assert_eq!(changed_lines(&diff), [(30, 29)]);
+        assert_eq!(diff.lints["clippy::lint"].removed[0].line, 10);
+    }
+
+    #[test]
+    fn overlapping_lines() {
+        let base = [warning(10, 15, "a")];
+        let candidate = [warning(12, 12, "b")];
+        assert_eq!(changed_lines(&diff(&base, &candidate)), [(10, 12)]);
+    }
+
+    #[test]
+    fn same_message_before_overlapping_lines() {
+        let base = [warning(10, 10, "a")];
+        let candidate = [warning(10, 10, "b"), warning(40, 40, "a")];
+        let diff = diff(&base, &candidate);
+        assert_eq!(changed_lines(&diff), [(10, 40)]);
+        assert_eq!(diff.lints["clippy::lint"].added[0].message, "b");
+    }
+
+    #[test]
+    fn unrelated() {
+        let base = [warning(10, 10, "a")];
+        let candidate = [warning(20, 20, "b")];
+        let diff = diff(&base, &candidate);
+        let lint_diff = &diff.lints["clippy::lint"];
+        assert!(lint_diff.changed.is_empty());
+        assert_eq!(lint_diff.removed[0].message, "a");
+        assert_eq!(lint_diff.added[0].message, "b");
+    }
+
+    #[test]
+    fn other_files_are_unrelated() {
+        let base = [warning(10, 10, "a")];
+        let mut candidate = [warning(10, 10, "a")];
+        candidate[0].file = "src/main.rs".into();
+        let diff = diff(&base, &candidate);
+        let lint_diff = &diff.lints["clippy::lint"];
+        assert!(lint_diff.changed.is_empty());
+        assert_eq!((lint_diff.added.len(), lint_diff.removed.len()), (1, 1));
+    }
+}
@@ -1,13 +1,16 @@
 use crate::recursive::{deserialize_line, serialize_line, DriverInfo};
+use crate::report::{self, CrateStats};
 
+use std::fs::OpenOptions;
 use std::io::{self, BufReader, Write};
 use std::net::TcpStream;
-use std::process::{self, Command, Stdio};
+use std::path::Path;
+use std::process::{self, Command};
 use std::{env, mem};
 
 /// 1. Sends [`DriverInfo`] to the [`crate::recursive::LintcheckServer`] running on `addr`
 /// 2. Receives [bool] from the server, if `false` returns `None`
-/// 3. Otherwise sends the stderr of running `clippy-driver` to the server
+/// 3. Otherwise sends the [`CrateStats`] and the stderr of running `clippy-driver` to the server
 fn run_clippy(addr: &str) -> Option<i32> {
     let driver_info = DriverInfo {
         package_name: env::var("CARGO_PKG_NAME").ok()?,
@@ -35,12 +38,22 @@ fn run_clippy(addr: &str) -> Option<i32> {
         _ => mem::replace(&mut include_next, true),
     });
 
-    let output = Command::new(env::var("CLIPPY_DRIVER").expect("missing env CLIPPY_DRIVER"))
-        .args(args)
-        .stdout(Stdio::inherit())
-        .output()
-        .expect("failed to run clippy-driver");
+    let (output, duration, max_rss) = report::output_measured(
+        Command::new(env::var("CLIPPY_DRIVER").expect("missing env CLIPPY_DRIVER"))
+            .args(args)
+            .env("RUST_BACKTRACE", "1"),
+    )
+    .expect("failed to run clippy-driver");
+    io::stdout().write_all(&output.stdout).unwrap();
 
+    let stats = CrateStats {
+        name: driver_info.package_name.clone(),
+        version: driver_info.version.clone(),
+        duration,
+        max_rss,
+        ices: report::find_ices(&String::from_utf8_lossy(&output.stderr)),
+    };
+    serialize_line(&stats, stream.get_mut());
     stream
         .get_mut()
         .write_all(&output.stderr)
@@ -65,3 +78,41 @@ pub fn drive(addr: &str) {
             .unwrap()
     }))
 }
+
+/// Runs as the `RUSTC_WRAPPER` of `cargo clippy` outside of `--recursive` mode, which wraps
+/// `clippy-driver` for the crates checked by Clippy and `rustc` for their dependencies. The
+/// [`CrateStats`] of running `clippy-driver` are appended to the file at `stats_path`, the other
+/// programs are only run.
+pub fn measure(stats_path: &str) {
+    let mut args = env::args_os().skip(1);
+    let program = args.next().expect("missing the program to wrap");
+    let is_clippy_driver = Path::new(&program)
+        .file_stem()
+        .map_or(false, |stem| stem == "clippy-driver");
+    let mut cmd = Command::new(program);
+    cmd.args(args);
+    if !is_clippy_driver {
+        process::exit(cmd.status().unwrap().code().unwrap());
+    }
+
+    let (output, duration, max_rss) =
+        report::output_measured(cmd.env("RUST_BACKTRACE", "1")).expect("failed to run clippy-driver");
+    io::stdout().write_all(&output.stdout).unwrap();
+    io::stderr().write_all(&output.stderr).unwrap();
+
+    let stats = CrateStats {
+        name: env::var("CARGO_PKG_NAME").unwrap_or_default(),
+        version: env::var("CARGO_PKG_VERSION").unwrap_or_default(),
+        duration,
+        max_rss,
+        ices: report::find_ices(&String::from_utf8_lossy(&output.stderr)),
+    };
+    let mut stats_file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(stats_path)
+        .unwrap_or_else(|e| panic!("{e:?} opening {stats_path}"));
+    serialize_line(&stats, &mut stats_file);
+
+    process::exit(output.status.code().expect("killed by signal"));
+}
@@ -8,11 +8,14 @@
 #![allow(clippy::collapsible_else_if)]
 
 mod config;
+mod diff;
 mod driver;
 mod recursive;
+mod report;
 
 use crate::config::LintcheckConfig;
 use crate::recursive::LintcheckServer;
+use crate::report::CrateStats;
 
 use std::collections::{HashMap, HashSet};
 use std::env;
@@ -21,7 +24,7 @@
 use std::fs;
 use std::io::{self, ErrorKind};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, Stdio};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::thread;
 use std::time::Duration;
@@ -43,7 +46,7 @@ struct SourceList {
     recursive: RecursiveOptions,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 struct RecursiveOptions {
     ignore: HashSet<String>,
 }
@@ -92,15 +95,51 @@ struct Crate {
     options: Option<Vec<String>>,
 }
 
-/// A single warning that clippy issued while checking a `Crate`
+/// A build of Clippy to run on the crates
 #[derive(Debug)]
+struct Clippy {
+    cargo_clippy_path: PathBuf,
+    clippy_driver_path: PathBuf,
+    /// The directory in `target/lintcheck` in which the crates are checked
+    target_dir: &'static str,
+}
+
+impl Clippy {
+    /// Finds the binaries in `dir`
+    fn new(dir: &Path, target_dir: &'static str) -> Self {
+        let find = |name: &str| {
+            let path = dir.join(format!("{name}{EXE_SUFFIX}"));
+            fs::canonicalize(&path).unwrap_or_else(|_| panic!("{} binary not found!", path.display()))
+        };
+        Self {
+            cargo_clippy_path: find("cargo-clippy"),
+            clippy_driver_path: find("clippy-driver"),
+            target_dir,
+        }
+    }
+
+    fn version(&self) -> String {
+        Command::new(&self.cargo_clippy_path)
+            .arg("--version")
+            .output()
+            .map(|o| String::from_utf8_lossy(&o.stdout).into_owned())
+            .expect("could not get clippy version!")
+    }
+}
+
+/// A single warning that clippy issued while checking a `Crate`
+#[derive(Debug, Serialize)]
 struct ClippyWarning {
     crate_name: String,
     file: String,
     line: usize,
     column: usize,
+    /// The last line of the code the warning points to
+    end_line: usize,
     lint_type: String,
     message: String,
+    /// The first lines of the code the warning points to
+    snippet: String,
     is_ice: bool,
 }
 
@@ -125,13 +164,23 @@ fn new(diag: Diagnostic, crate_name: &str, crate_version: &str) -> Option<Self>
             )
         };
 
+        let snippet = span
+            .text
+            .iter()
+            .take(3)
+            .map(|line| line.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+
         Some(Self {
             crate_name: crate_name.to_owned(),
             file,
             line: span.line_start,
             column: span.column_start,
+            end_line: span.line_end,
             lint_type,
             message: diag.message,
+            snippet,
             is_ice: diag.level == DiagnosticLevel::Ice,
         })
     }
@@ -219,12 +268,28 @@ fn download_and_extract(&self) -> Crate {
             } => {
                 let repo_path = {
                     let mut repo_path = PathBuf::from(LINTCHECK_SOURCES);
-                    // add a -git suffix in case we have the same crate from crates.io and a git repo
-                    repo_path.push(format!("{name}-git"));
+                    // add a -git suffix in case we have the same crate from crates.io and a git repo, and
+                    // the URL in case we have crates with the same name from different repos
+                    let url_key: String = url
+                        .chars()
+                        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                        .collect();
+                    repo_path.push(format!("{name}-git-{url_key}"));
                     repo_path
                 };
-                // clone the repo if we have not done so
-                if !repo_path.is_dir() {
+                // clone the repo if we have not done so, otherwise fetch the new commits
+                if repo_path.is_dir() {
+                    println!("Fetching {url} and checking out {commit}");
+                    if !Command::new("git")
+                        .args(["fetch", "--tags", "origin"])
+                        .current_dir(&repo_path)
+                        .status()
+                        .expect("Failed to fetch git repo!")
+                        .success()
+                    {
+                        panic!("Failed to fetch {url} into {}", repo_path.display());
+                    }
+                } else {
                     println!("Cloning {url} and checking out {commit}");
                     if !Command::new("git")
                         .arg("clone")
@@ -234,20 +299,29 @@ fn download_and_extract(&self) -> Crate {
                         .expect("Failed to clone git repo!")
                         .success()
                     {
-                        eprintln!("Failed to clone {url} into {}", repo_path.display());
+                        panic!("Failed to clone {url} into {}", repo_path.display());
                     }
                 }
-                // check out the commit/branch/whatever
+                // check out the commit/branch/whatever, branches as they are on the remote, as the local
+                // ones aren't updated by fetching
+                let remote_branch = format!("origin/{commit}");
+                let is_branch = Command::new("git")
+                    .args(["rev-parse", "--verify", "--quiet", &remote_branch])
+                    .current_dir(&repo_path)
+                    .stdout(Stdio::null())
+                    .status()
+                    .expect("Failed to run git rev-parse")
+                    .success();
                 if !Command::new("git")
                     .args(["-c", "advice.detachedHead=false"])
                     .arg("checkout")
-                    .arg(commit)
+                    .arg(if is_branch { &remote_branch } else { commit })
                     .current_dir(&repo_path)
                     .status()
                     .expect("Failed to check out commit")
                     .success()
                 {
-                    eprintln!("Failed to checkout {commit} of repo at {}", repo_path.display());
+                    panic!("Failed to checkout {commit} of repo at {}", repo_path.display());
                 }
 
                 Crate {
@@ -302,18 +376,17 @@ fn is_cache_dir(entry: &DirEntry) -> bool {
 
 impl Crate {
     /// Run `cargo clippy` on the `Crate` and collect and return all the lint warnings that clippy
-    /// issued
-    #[allow(clippy::too_many_arguments)]
+    /// issued, and how checking the crate went, except in `--fix` mode. In `--recursive` mode the
+    /// warnings and stats are collected by the server instead.
     fn run_clippy_lints(
         &self,
-        cargo_clippy_path: &Path,
-        clippy_driver_path: &Path,
+        clippy: &Clippy,
         target_dir_index: &AtomicUsize,
         total_crates_to_lint: usize,
         config: &LintcheckConfig,
-        lint_filter: &Vec<String>,
+        lint_filter: &[String],
         server: &Option<LintcheckServer>,
-    ) -> Vec<ClippyWarning> {
+    ) -> (Vec<ClippyWarning>, Option<CrateStats>) {
         // advance the atomic index by one
         let index = target_dir_index.fetch_add(1, Ordering::SeqCst);
         // "loop" the index within 0..thread_limit
@@ -332,9 +405,7 @@ fn run_clippy_lints(
             );
         }
 
-        let cargo_clippy_path = std::fs::canonicalize(cargo_clippy_path).unwrap();
-
-        let shared_target_dir = clippy_project_root().join("target/lintcheck/shared_target_dir");
+        let shared_target_dir = clippy_project_root().join("target/lintcheck").join(clippy.target_dir);
 
         let mut cargo_clippy_args = if config.fix {
             vec!["--fix", "--"]
@@ -378,28 +449,38 @@ fn run_clippy_lints(
                 .env("RUSTC_WRAPPER", env::current_exe().unwrap())
                 // Pass the absolute path so `crate::driver` can find `clippy-driver`, as it's executed in various
                 // different working directories
-                .env("CLIPPY_DRIVER", clippy_driver_path)
+                .env("CLIPPY_DRIVER", &clippy.clippy_driver_path)
                 .env("LINTCHECK_SERVER", server.local_addr.to_string())
                 .status()
                 .expect("failed to run cargo");
 
             assert_eq!(status.code(), Some(0));
 
-            return Vec::new();
+            return (Vec::new(), None);
         }
 
         cargo_clippy_args.extend(clippy_args);
 
-        let all_output = Command::new(&cargo_clippy_path)
-            // use the looping index to create individual target dirs
-            .env("CARGO_TARGET_DIR", shared_target_dir.join(format!("_{thread_index:?}")))
+        // use the looping index to create individual target dirs
+        let target_dir = shared_target_dir.join(format!("_{thread_index:?}"));
+        let stats_path = target_dir.join("lintcheck_stats.jsonl");
+        if let Err(error) = fs::remove_file(&stats_path) {
+            assert_eq!(error.kind(), ErrorKind::NotFound, "cannot remove the previous stats");
+        }
+
+        let all_output = Command::new(&clippy.cargo_clippy_path)
+            .env("CARGO_TARGET_DIR", &target_dir)
+            // only the time of `clippy-driver` is measured, not the time building the dependencies
+            // takes, see `crate::driver::measure`
+            .env("RUSTC_WRAPPER", env::current_exe().unwrap())
+            .env("LINTCHECK_STATS", &stats_path)
             .args(&cargo_clippy_args)
             .current_dir(&self.path)
             .output()
             .unwrap_or_else(|error| {
                 panic!(
                     "Encountered error:\n{error:?}\ncargo_clippy_path: {}\ncrate path:{}\n",
-                    &cargo_clippy_path.display(),
+                    &clippy.cargo_clippy_path.display(),
                     &self.path.display()
                 );
             });
@@ -426,7 +507,7 @@ fn run_clippy_lints(
                 );
             }
             // fast path, we don't need the warnings anyway
-            return Vec::new();
+            return (Vec::new(), None);
         }
 
         // get all clippy warnings and ICEs
@@ -437,7 +518,8 @@ fn run_clippy_lints(
             })
             .collect();
 
-        warnings
+        let stats = report::read_measured(&stats_path, &self.name, &self.version);
+        (warnings, Some(stats))
     }
 }
 
@@ -516,6 +598,26 @@ fn read_crates(toml_path: &Path) -> (Vec<CrateSource>, RecursiveOptions) {
     (crate_sources, crate_list.recursive)
 }
 
+/// The crate given with `--path` or `--git`, if any
+fn cli_source(config: &LintcheckConfig) -> Option<CrateSource> {
+    let name = config.source_name()?;
+    if let Some(path) = &config.path {
+        Some(CrateSource::Path {
+            name,
+            path: path.clone(),
+            options: None,
+        })
+    } else {
+        let git = config.git.as_ref()?;
+        Some(CrateSource::Git {
+            name,
+            url: git.url.clone(),
+            commit: git.rev.clone(),
+            options: None,
+        })
+    }
+}
+
 /// Generate a short list of occurring lints-types and their count
 fn gather_stats(clippy_warnings: &[ClippyWarning]) -> (String, HashMap<&String, usize>) {
     // count lint type occurrences
@@ -549,6 +651,10 @@ fn main() {
     if let Ok(addr) = env::var("LINTCHECK_SERVER") {
         driver::drive(&addr);
     }
+    // We're being executed as the `RUSTC_WRAPPER` of `cargo clippy` to measure `clippy-driver`
+    if let Ok(stats_path) = env::var("LINTCHECK_STATS") {
+        driver::measure(&stats_path);
+    }
 
     // assert that we launch lintcheck from the repo root (via cargo lintcheck)
     if std::fs::metadata("lintcheck/Cargo.toml").is_err() {
@@ -562,29 +668,22 @@ fn main() {
     build_clippy();
     println!("Done compiling");
 
-    let cargo_clippy_path = fs::canonicalize(format!("target/debug/cargo-clippy{EXE_SUFFIX}")).unwrap();
-    let clippy_driver_path = fs::canonicalize(format!("target/debug/clippy-driver{EXE_SUFFIX}")).unwrap();
-
-    // assert that clippy is found
-    assert!(
-        cargo_clippy_path.is_file(),
-        "target/debug/cargo-clippy binary not found! {}",
-        cargo_clippy_path.display()
+    let clippy = Clippy::new(
+        config.diff_candidate.as_deref().unwrap_or(Path::new("target/debug")),
+        "shared_target_dir",
     );
-
-    let clippy_ver = std::process::Command::new(&cargo_clippy_path)
-        .arg("--version")
-        .output()
-        .map(|o| String::from_utf8_lossy(&o.stdout).into_owned())
-        .expect("could not get clippy version!");
+    let clippy_ver = clippy.version();
 
     // download and extract the crates, then run clippy on them and collect clippy's warnings
     // flatten into one big list of warnings
 
-    let (crates, recursive_options) = read_crates(&config.sources_toml_path);
+    let (crates, recursive_options) = if let Some(source) = cli_source(&config) {
+        (vec![source], RecursiveOptions::default())
+    } else {
+        read_crates(&config.sources_toml_path)
+    };
     let old_stats = read_stats_from_file(&config.lintcheck_results_path);
 
-    let counter = AtomicUsize::new(1);
     let lint_filter: Vec<String> = config
         .lint_filter
         .iter()
@@ -631,36 +730,31 @@ fn main() {
         .build_global()
         .unwrap();
 
-    let server = config.recursive.then(|| {
-        let _: io::Result<()> = fs::remove_dir_all("target/lintcheck/shared_target_dir/recursive");
-
-        LintcheckServer::spawn(recursive_options)
-    });
-
-    let mut clippy_warnings: Vec<ClippyWarning> = crates
-        .par_iter()
-        .flat_map(|krate| {
-            krate.run_clippy_lints(
-                &cargo_clippy_path,
-                &clippy_driver_path,
-                &counter,
-                crates.len(),
-                &config,
-                &lint_filter,
-                &server,
-            )
-        })
-        .collect();
-
-    if let Some(server) = server {
-        clippy_warnings.extend(server.warnings());
+    if let Some(base_dir) = &config.diff_base {
+        let base = Clippy::new(base_dir, "base_target_dir");
+        println!("Linting with the base Clippy");
+        let (base_warnings, mut base_stats) = lint_crates(&crates, &base, &config, &lint_filter, &recursive_options);
+        println!("Linting with the candidate Clippy");
+        let (candidate_warnings, mut candidate_stats) =
+            lint_crates(&crates, &clippy, &config, &lint_filter, &recursive_options);
+
+        let diff = diff::Diff::new(base.version(), clippy_ver, &base_warnings, &candidate_warnings);
+        diff.write(&config.diff_results_path);
+        report::write_report(&config.base_report_path, &mut base_stats);
+        report::write_report(&config.report_path, &mut candidate_stats);
+        report::write_delta(&config.report_delta_path, &base_stats, &candidate_stats);
+        return;
     }
 
+    let (clippy_warnings, mut crate_stats) = lint_crates(&crates, &clippy, &config, &lint_filter, &recursive_options);
+
     // if we are in --fix mode, don't change the log files, terminate here
     if config.fix {
         return;
     }
 
+    report::write_report(&config.report_path, &mut crate_stats);
+
     // generate some stats
     let (stats_formatted, new_stats) = gather_stats(&clippy_warnings);
 
@@ -699,6 +793,40 @@ fn main() {
     print_stats(old_stats, new_stats, &config.lint_filter);
 }
 
+/// Runs the build of Clippy on all the crates, and collects the warnings and how checking each
+/// crate went
+fn lint_crates(
+    crates: &[Crate],
+    clippy: &Clippy,
+    config: &LintcheckConfig,
+    lint_filter: &[String],
+    recursive_options: &RecursiveOptions,
+) -> (Vec<ClippyWarning>, Vec<CrateStats>) {
+    let counter = AtomicUsize::new(1);
+
+    let server = config.recursive.then(|| {
+        let _: io::Result<()> =
+            fs::remove_dir_all(Path::new("target/lintcheck").join(clippy.target_dir).join("recursive"));
+
+        LintcheckServer::spawn(recursive_options.clone())
+    });
+
+    let (clippy_warnings, crate_stats): (Vec<_>, Vec<_>) = crates
+        .par_iter()
+        .map(|krate| krate.run_clippy_lints(clippy, &counter, crates.len(), config, lint_filter, &server))
+        .unzip();
+    let mut clippy_warnings: Vec<ClippyWarning> = clippy_warnings.into_iter().flatten().collect();
+    let mut crate_stats: Vec<CrateStats> = crate_stats.into_iter().flatten().collect();
+
+    if let Some(server) = server {
+        let (warnings, stats) = server.finish();
+        clippy_warnings.extend(warnings);
+        crate_stats.extend(stats);
+    }
+
+    (clippy_warnings, crate_stats)
+}
+
 /// read the previous stats from the lintcheck-log file
 fn read_stats_from_file(file_path: &Path) -> HashMap<String, usize> {
     let file_content: String = match std::fs::read_to_string(file_path).ok() {
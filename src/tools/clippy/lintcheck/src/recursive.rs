@@ -1,8 +1,9 @@
 //! In `--recursive` mode we set the `lintcheck` binary as the `RUSTC_WRAPPER` of `cargo check`,
 //! this allows [`crate::driver`] to be run for every dependency. The driver connects to
-//! [`LintcheckServer`] to ask if it should be skipped, and if not sends the stderr of running
-//! clippy on the crate to the server
+//! [`LintcheckServer`] to ask if it should be skipped, and if not sends the [`CrateStats`] and the
+//! stderr of running clippy on the crate to the server
 
+use crate::report::CrateStats;
 use crate::ClippyWarning;
 use crate::RecursiveOptions;
 
@@ -47,6 +48,7 @@ pub(crate) fn deserialize_line<T, R>(reader: &mut R) -> T
 fn process_stream(
     stream: TcpStream,
     sender: &Sender<ClippyWarning>,
+    stats_sender: &Sender<CrateStats>,
     options: &RecursiveOptions,
     seen: &Mutex<HashSet<DriverInfo>>,
 ) {
@@ -59,6 +61,12 @@ fn process_stream(
     let should_run = unseen && !ignored;
 
     serialize_line(&should_run, stream.get_mut());
+    if !should_run {
+        return;
+    }
+
+    let stats: CrateStats = deserialize_line(&mut stream);
+    stats_sender.send(stats).unwrap();
 
     let mut stderr = String::new();
     stream.read_to_string(&mut stderr).unwrap();
@@ -77,6 +85,8 @@ pub(crate) struct LintcheckServer {
     pub local_addr: SocketAddr,
     receiver: Receiver<ClippyWarning>,
     sender: Arc<Sender<ClippyWarning>>,
+    stats_receiver: Receiver<CrateStats>,
+    stats_sender: Arc<Sender<CrateStats>>,
 }
 
 impl LintcheckServer {
@@ -89,6 +99,9 @@ pub fn spawn(options: RecursiveOptions) -> Self {
         // The spawned threads hold a `Weak<Sender>` so that they don't keep the channel connected
         // indefinitely
         let sender_weak = Arc::downgrade(&sender);
+        let (stats_sender, stats_receiver) = crossbeam_channel::unbounded::<CrateStats>();
+        let stats_sender = Arc::new(stats_sender);
+        let stats_sender_weak = Arc::downgrade(&stats_sender);
 
         // Ignore dependencies multiple times, e.g. for when it's both checked and compiled for a
         // build dependency
@@ -99,9 +112,12 @@ pub fn spawn(options: RecursiveOptions) -> Self {
                 s.spawn(|| {
                     while let Ok((stream, _)) = listener.accept() {
                         let sender = sender_weak.upgrade().expect("received connection after server closed");
+                        let stats_sender = stats_sender_weak
+                            .upgrade()
+                            .expect("received connection after server closed");
                         let options = &options;
                         let seen = &seen;
-                        s.spawn(move || process_stream(stream, &sender, options, seen));
+                        s.spawn(move || process_stream(stream, &sender, &stats_sender, options, seen));
                     }
                 });
             });
@@ -111,13 +127,20 @@ pub fn spawn(options: RecursiveOptions) -> Self {
             local_addr,
             receiver,
             sender,
+            stats_receiver,
+            stats_sender,
         }
     }
 
-    pub fn warnings(self) -> impl Iterator<Item = ClippyWarning> {
-        // causes the channel to become disconnected so that the receiver iterator ends
+    /// Returns the warnings and the [`CrateStats`] sent by the drivers
+    pub fn finish(self) -> (Vec<ClippyWarning>, Vec<CrateStats>) {
+        // causes the channels to become disconnected so that the receiver iterators end
         drop(self.sender);
+        drop(self.stats_sender);
 
-        self.receiver.into_iter()
+        (
+            self.receiver.into_iter().collect(),
+            self.stats_receiver.into_iter().collect(),
+        )
     }
 }
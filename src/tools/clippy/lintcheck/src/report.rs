@@ -0,0 +1,252 @@
+//! The time and memory it took to check each crate and the ICEs that occurred, and the report
+//! listing them, in which the ICEs are grouped by their panic message.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write as _};
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::{Child, Command, ExitStatus, Output, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+/// How checking a crate went
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct CrateStats {
+    pub name: String,
+    pub version: String,
+    pub duration: Duration,
+    /// The maximum resident set size of the processes checking the crate in KiB, if it is known
+    pub max_rss: Option<u64>,
+    pub ices: Vec<Ice>,
+}
+
+/// A panic of Clippy or rustc
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct Ice {
+    pub message: String,
+    /// The location of the panic and the backtrace
+    pub backtrace: String,
+}
+
+/// Runs the command like `Command::output`, and measures its wall time and the maximum resident
+/// set size of it and its descendants
+pub(crate) fn output_measured(cmd: &mut Command) -> io::Result<(Output, Duration, Option<u64>)> {
+    let start = Instant::now();
+    let mut child = cmd.stdout(Stdio::piped()).stderr(Stdio::piped()).spawn()?;
+
+    // read both pipes at the same time, so the child doesn't block on a full pipe
+    let mut stdout = child.stdout.take().unwrap();
+    let stdout_reader = thread::spawn(move || {
+        let mut buf = Vec::new();
+        stdout.read_to_end(&mut buf).map(|_| buf)
+    });
+    let mut stderr = Vec::new();
+    child.stderr.take().unwrap().read_to_end(&mut stderr)?;
+    let stdout = stdout_reader.join().unwrap()?;
+
+    let (status, max_rss) = wait(&mut child)?;
+    Ok((Output { status, stdout, stderr }, start.elapsed(), max_rss))
+}
+
+#[cfg(unix)]
+fn wait(child: &mut Child) -> io::Result<(ExitStatus, Option<u64>)> {
+    use std::os::unix::process::ExitStatusExt;
+
+    let pid = libc::pid_t::try_from(child.id()).unwrap();
+    let mut status = 0;
+    // SAFETY: `rusage` only contains integers
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    // SAFETY: the child hasn't been waited for, and the pointers are valid
+    if unsafe { libc::wait4(pid, &mut status, 0, &mut usage) } == -1 {
+        return Err(io::Error::last_os_error());
+    }
+
+    // `ru_maxrss` is in bytes on macOS, and in KiB on the other platforms
+    let max_rss = u64::try_from(usage.ru_maxrss).ok();
+    let max_rss = if cfg!(target_os = "macos") {
+        max_rss.map(|bytes| bytes / 1024)
+    } else {
+        max_rss
+    };
+    Ok((ExitStatus::from_raw(status), max_rss))
+}
+
+#[cfg(not(unix))]
+fn wait(child: &mut Child) -> io::Result<(ExitStatus, Option<u64>)> {
+    child.wait().map(|status| (status, None))
+}
+
+/// Combines the [`CrateStats`] of the `clippy-driver` invocations checking the crate, which
+/// [`crate::driver::measure`] appended to the file at `path`
+pub(crate) fn read_measured(path: &Path, name: &str, version: &str) -> CrateStats {
+    let mut stats = CrateStats {
+        name: name.to_owned(),
+        version: version.to_owned(),
+        duration: Duration::ZERO,
+        max_rss: None,
+        ices: Vec::new(),
+    };
+    // the file is missing if `clippy-driver` didn't run, e.g. since a dependency failed to build
+    let measured = fs::read_to_string(path).unwrap_or_default();
+    for line in measured.lines() {
+        let measured: CrateStats = serde_json::from_str(line).expect("failed to deserialize");
+        stats.duration += measured.duration;
+        stats.max_rss = stats.max_rss.max(measured.max_rss);
+        stats.ices.extend(measured.ices);
+    }
+    stats
+}
+
+/// Finds the panics in the stderr of `cargo` or `clippy-driver`, which should run with
+/// `RUST_BACKTRACE=1`
+pub(crate) fn find_ices(stderr: &str) -> Vec<Ice> {
+    let mut ices = Vec::new();
+    let mut ice_message = None;
+    let mut lines = stderr.lines().peekable();
+    while let Some(line) = lines.next() {
+        if let Some(message) = line.split_once("internal compiler error: ").map(|(_, message)| message) {
+            ice_message = Some(message);
+            continue;
+        }
+        let Some((_, panic)) = line.split_once(" panicked at ") else {
+            continue;
+        };
+
+        // `'message', location`
+        let (mut message, location) = panic
+            .strip_prefix('\'')
+            .and_then(|panic| panic.rsplit_once("', "))
+            .unwrap_or((panic, ""));
+        // `bug!` and `span_bug!` panic with a `Box<dyn Any>` after emitting the error
+        if message.starts_with("Box<dyn Any>") || message.starts_with("Box<Any>") {
+            message = ice_message.take().unwrap_or(message);
+        }
+
+        let mut backtrace = format!("{location}\n");
+        while let Some(line) = lines.next_if(|line| line.starts_with(' ') || *line == "stack backtrace:") {
+            backtrace.push_str(line);
+            backtrace.push('\n');
+        }
+        ices.push(Ice {
+            message: message.to_owned(),
+            backtrace,
+        });
+    }
+    ices
+}
+
+/// Writes the report as JSON to `path.json` and as markdown to `path.md`
+pub(crate) fn write_report(path: &Path, stats: &mut [CrateStats]) {
+    stats.sort_by(|a, b| b.duration.cmp(&a.duration).then_with(|| a.name.cmp(&b.name)));
+
+    let mut report = String::from("### Crates\n\n");
+    report.push_str("| crate | version | time | max RSS |\n");
+    report.push_str("| --- | --- | ---: | ---: |\n");
+    for krate in stats.iter() {
+        let _: fmt::Result = writeln!(
+            report,
+            "| {} | {} | {:.1}s | {} |",
+            krate.name,
+            krate.version,
+            krate.duration.as_secs_f64(),
+            format_max_rss(krate.max_rss)
+        );
+    }
+
+    // the crates in which each panic occurred, and the first backtrace of it
+    let mut ices: BTreeMap<&str, (Vec<&CrateStats>, &str)> = BTreeMap::new();
+    for krate in stats.iter() {
+        for ice in &krate.ices {
+            let (crates, _) = ices
+                .entry(ice.message.as_str())
+                .or_insert((Vec::new(), ice.backtrace.as_str()));
+            if !crates
+                .iter()
+                .any(|other| other.name == krate.name && other.version == krate.version)
+            {
+                crates.push(krate);
+            }
+        }
+    }
+
+    report.push_str("\n### ICEs\n");
+    if ices.is_empty() {
+        report.push_str("\nNone\n");
+    }
+    for (message, (crates, backtrace)) in ices {
+        let crates = crates
+            .iter()
+            .map(|krate| format!("{} {}", krate.name, krate.version))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let _: fmt::Result = writeln!(report, "\n#### {message}\n\nIn {crates}\n\n```\n{backtrace}```");
+    }
+
+    let json_path = path.with_extension("json");
+    let md_path = path.with_extension("md");
+    println!(
+        "Writing the report to {} and {}",
+        json_path.display(),
+        md_path.display()
+    );
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(json_path, serde_json::to_string_pretty(stats).unwrap()).unwrap();
+    fs::write(md_path, report).unwrap();
+}
+
+/// Writes how the time and memory it took to check each crate changed from the base to the
+/// candidate Clippy of `--diff-base` as markdown to `path.md`, the largest relative changes of the
+/// time first
+pub(crate) fn write_delta(path: &Path, base: &[CrateStats], candidate: &[CrateStats]) {
+    let mut crates: Vec<(&CrateStats, &CrateStats)> = candidate
+        .iter()
+        .filter_map(|candidate| {
+            base.iter()
+                .find(|base| (&base.name, &base.version) == (&candidate.name, &candidate.version))
+                .map(|base| (base, candidate))
+        })
+        .collect();
+    crates.sort_by(|a, b| time_change(*b).abs().total_cmp(&time_change(*a).abs()));
+
+    let mut delta = String::from("### Crates checked with both builds\n\n");
+    delta.push_str("| crate | version | base time | candidate time | change ");
+    delta.push_str("| base max RSS | candidate max RSS | base ICEs | candidate ICEs |\n");
+    delta.push_str("| --- | --- | ---: | ---: | ---: | ---: | ---: | ---: | ---: |\n");
+    for (base, candidate) in crates {
+        let _: fmt::Result = writeln!(
+            delta,
+            "| {} | {} | {:.1}s | {:.1}s | {:+.1}% | {} | {} | {} | {} |",
+            candidate.name,
+            candidate.version,
+            base.duration.as_secs_f64(),
+            candidate.duration.as_secs_f64(),
+            time_change((base, candidate)),
+            format_max_rss(base.max_rss),
+            format_max_rss(candidate.max_rss),
+            base.ices.len(),
+            candidate.ices.len()
+        );
+    }
+
+    let md_path = path.with_extension("md");
+    println!("Writing the change of the report to {}", md_path.display());
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(md_path, delta).unwrap();
+}
+
+/// The change of the time it took to check a crate from the base to the candidate in percent
+fn time_change((base, candidate): (&CrateStats, &CrateStats)) -> f64 {
+    let base = base.duration.as_secs_f64();
+    if base == 0.0 {
+        0.0
+    } else {
+        (candidate.duration.as_secs_f64() - base) / base * 100.0
+    }
+}
+
+fn format_max_rss(max_rss: Option<u64>) -> String {
+    max_rss.map_or_else(|| String::from("?"), |kib| format!("{} MiB", kib / 1024))
+}
@@ -72,6 +72,15 @@ fn track_clippy_args(parse_sess: &mut ParseSess, args_env_var: &Option<String>)
     ));
 }
 
+/// Track the directory set by `cargo clippy --emit-public-api` so that cargo will re-run clippy
+/// to write the public API
+fn track_emit_public_api(parse_sess: &mut ParseSess) {
+    parse_sess.env_depinfo.get_mut().insert((
+        Symbol::intern("CLIPPY_EMIT_PUBLIC_API"),
+        env::var("CLIPPY_EMIT_PUBLIC_API").ok().as_deref().map(Symbol::intern),
+    ));
+}
+
 /// Track files that may be accessed at runtime in `file_depinfo` so that cargo will re-run clippy
 /// when any of them are modified
 fn track_files(parse_sess: &mut ParseSess, conf_path_string: Option<String>) {
@@ -140,6 +149,7 @@ fn config(&mut self, config: &mut interface::Config) {
         let clippy_args_var = self.clippy_args_var.take();
         config.parse_sess_created = Some(Box::new(move |parse_sess| {
             track_clippy_args(parse_sess, &clippy_args_var);
+            track_emit_public_api(parse_sess);
             track_files(parse_sess, conf_path_string);
         }));
         config.register_lints = Some(Box::new(move |sess, lint_store| {
@@ -154,6 +164,14 @@ fn config(&mut self, config: &mut interface::Config) {
             clippy_lints::register_pre_expansion_lints(lint_store, sess, &conf);
             clippy_lints::register_renamed(lint_store);
         }));
+        config.override_queries = Some(|_sess, providers, _extern_providers| {
+            // Applies the `scope` and `deny-only-in` configurations however the lints are emitted
+            providers.shallow_lint_levels_on = |tcx, owner| {
+                let mut levels = (rustc_interface::DEFAULT_QUERY_PROVIDERS.shallow_lint_levels_on)(tcx, owner);
+                clippy_lints::allow_out_of_scope_lints(tcx, owner, &mut levels);
+                levels
+            };
+        });
 
         // FIXME: #4825; This is required, because Clippy lints that are based on MIR have to be
         // run on the unoptimized MIR. On the other hand this results in some false negatives. If
@@ -17,6 +17,8 @@
     -h, --help               Print this message
     -V, --version            Print version info and exit
     --explain LINT           Print the documentation for a given lint
+    --emit-public-api DIR    Write the public API of the linted library crates to `DIR/<crate>.json`,
+                             to be used as the `public-api-baseline` of the `clippy::semver` lints
 
 For the other options see `cargo check --help`.
 
@@ -73,6 +75,7 @@ struct ClippyCmd {
     cargo_subcommand: &'static str,
     args: Vec<String>,
     clippy_args: Vec<String>,
+    emit_public_api: Option<PathBuf>,
 }
 
 impl ClippyCmd {
@@ -83,8 +86,9 @@ fn new<I>(mut old_args: I) -> Self
         let mut cargo_subcommand = "check";
         let mut args = vec![];
         let mut clippy_args: Vec<String> = vec![];
+        let mut emit_public_api = None;
 
-        for arg in old_args.by_ref() {
+        while let Some(arg) = old_args.next() {
             match arg.as_str() {
                 "--fix" => {
                     cargo_subcommand = "fix";
@@ -94,6 +98,13 @@ fn new<I>(mut old_args: I) -> Self
                     clippy_args.push("--no-deps".into());
                     continue;
                 },
+                "--emit-public-api" => {
+                    // `clippy-driver` runs in the directory of each package
+                    emit_public_api = old_args
+                        .next()
+                        .map(|dir| env::current_dir().expect("current directory invalid").join(dir));
+                    continue;
+                },
                 "--" => break,
                 _ => {},
             }
@@ -110,6 +121,7 @@ fn new<I>(mut old_args: I) -> Self
             cargo_subcommand,
             args,
             clippy_args,
+            emit_public_api,
         }
     }
 
@@ -141,6 +153,9 @@ fn into_std_cmd(self) -> Command {
             .env("CLIPPY_TERMINAL_WIDTH", terminal_width.to_string())
             .arg(self.cargo_subcommand)
             .args(&self.args);
+        if let Some(dir) = self.emit_public_api {
+            cmd.env("CLIPPY_EMIT_PUBLIC_API", dir);
+        }
 
         cmd
     }
@@ -195,6 +210,16 @@ fn no_deps_not_duplicated_with_fix() {
         assert_eq!(cmd.clippy_args.iter().filter(|arg| *arg == "--no-deps").count(), 1);
     }
 
+    #[test]
+    fn emit_public_api() {
+        let args = "cargo clippy --emit-public-api api --all-features"
+            .split_whitespace()
+            .map(ToString::to_string);
+        let cmd = ClippyCmd::new(args);
+        assert!(cmd.emit_public_api.unwrap().ends_with("api"));
+        assert_eq!(cmd.args, ["--all-features"]);
+    }
+
     #[test]
     fn check() {
         let args = "cargo clippy".split_whitespace().map(ToString::to_string);
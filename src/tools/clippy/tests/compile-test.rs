@@ -7,12 +7,14 @@
 use compiletest_rs as compiletest;
 use compiletest_rs::common::Mode as TestMode;
 
-use std::collections::HashMap;
+use regex::Regex;
+use std::collections::{BTreeMap, HashMap};
 use std::env::{self, remove_var, set_var, var_os};
 use std::ffi::{OsStr, OsString};
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 use std::sync::LazyLock;
 use test_utils::IS_RUSTC_TEST_SUITE;
 
@@ -29,6 +31,8 @@
     "futures",
     "if_chain",
     "itertools",
+    "lazy_static",
+    "once_cell",
     "quote",
     "regex",
     "serde",
@@ -54,6 +58,10 @@
 #[allow(unused_extern_crates)]
 extern crate itertools;
 #[allow(unused_extern_crates)]
+extern crate lazy_static;
+#[allow(unused_extern_crates)]
+extern crate once_cell;
+#[allow(unused_extern_crates)]
 extern crate parking_lot;
 #[allow(unused_extern_crates)]
 extern crate quote;
@@ -179,7 +187,10 @@ fn run_ui() {
         }),
     );
     compiletest::run_tests(&config);
-    check_rustfix_coverage();
+    check_rustfix_coverage(&config);
+    if env::var_os("RUSTFIX_FIXPOINT").is_some() {
+        check_rustfix_fixpoint(&config);
+    }
 }
 
 fn run_internal_tests() {
@@ -401,13 +412,8 @@ fn compile_test() {
     "write_with_newline.rs",
 ];
 
-fn check_rustfix_coverage() {
-    let missing_coverage_path = Path::new("debug/test/ui/rustfix_missing_coverage.txt");
-    let missing_coverage_path = if let Ok(target_dir) = std::env::var("CARGO_TARGET_DIR") {
-        PathBuf::from(target_dir).join(missing_coverage_path)
-    } else {
-        missing_coverage_path.to_path_buf()
-    };
+fn check_rustfix_coverage(config: &compiletest::Config) {
+    let missing_coverage_path = config.build_base.join("rustfix_missing_coverage.txt");
 
     if let Ok(missing_coverage_contents) = std::fs::read_to_string(missing_coverage_path) {
         assert!(RUSTFIX_COVERAGE_KNOWN_EXCEPTIONS.iter().is_sorted_by_key(Path::new));
@@ -429,6 +435,296 @@ fn check_rustfix_coverage() {
             );
         }
     }
+
+    // every `run-rustfix` test needs a `.fixed` file, and every `.fixed` file a `run-rustfix` test
+    for test_dir in ["tests/ui", "tests/ui-internal", "tests/ui-toml"] {
+        for entry in walkdir::WalkDir::new(test_dir) {
+            let entry = entry.unwrap();
+            let path = entry.path();
+            if path.extension() == Some(OsStr::new("rs")) {
+                if path.components().any(|c| c.as_os_str() == "auxiliary") {
+                    continue;
+                }
+                let headers = TestHeaders::parse(path);
+                if headers.run_rustfix {
+                    for revision in headers.revisions() {
+                        let fixed_path = fixed_file(path, revision);
+                        assert!(
+                            fixed_path.exists(),
+                            "`{}` has a `run-rustfix` annotation, but `{}` is missing. \
+                            Run `cargo dev bless` after running the test to generate it.",
+                            path.display(),
+                            fixed_path.display(),
+                        );
+                    }
+                }
+            } else if path.extension() == Some(OsStr::new("fixed")) {
+                let file_name = path.file_name().unwrap().to_string_lossy();
+                let mut parts = file_name.split('.');
+                let rs_path = path.with_file_name(format!("{}.rs", parts.next().unwrap()));
+                let revision = parts.next().filter(|&part| part != "fixed");
+                let is_stale = !rs_path.exists() || {
+                    let headers = TestHeaders::parse(&rs_path);
+                    !headers.run_rustfix || !headers.revisions().contains(&revision)
+                };
+                assert!(
+                    !is_stale,
+                    "`{}` has no matching `run-rustfix` test, please remove it",
+                    path.display(),
+                );
+            }
+        }
+    }
+}
+
+/// Lints the test files of the `run-rustfix` tests and their `.fixed` files with `clippy::all`
+/// forced on, and checks that applying the suggestions didn't introduce new warnings, i.e. that
+/// no lint is emitted more often in the `.fixed` file than in the test file.
+///
+/// Tests using auxiliary crates are skipped, as those are only built by compiletest.
+fn check_rustfix_fixpoint(config: &compiletest::Config) {
+    let out_dir = config.build_base.join("rustfix_fixpoint");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let mut failures = Vec::new();
+    for entry in walkdir::WalkDir::new(&config.src_base) {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        if path.extension() != Some(OsStr::new("rs"))
+            || path.components().any(|c| c.as_os_str() == "auxiliary")
+            || (!config.filters.is_empty()
+                && !config
+                    .filters
+                    .iter()
+                    .any(|filter| path.to_string_lossy().contains(filter.as_str())))
+        {
+            continue;
+        }
+        let headers = TestHeaders::parse(path);
+        if !headers.run_rustfix || headers.has_aux_builds {
+            continue;
+        }
+        for revision in headers.revisions() {
+            let before = lint_counts(config, path, &headers, revision, &out_dir);
+            let fixed_path = fixed_file(path, revision);
+            let after = lint_counts(config, &fixed_path, &headers, revision, &out_dir);
+            let introduced: Vec<String> = after
+                .into_iter()
+                .filter(|(lint, count)| before.get(lint).map_or(true, |before| before < count))
+                .map(|(lint, _)| lint)
+                .collect();
+            if !introduced.is_empty() {
+                failures.push(format!("{}: {}", fixed_path.display(), introduced.join(", ")));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "applying the suggestions introduced new warnings:\n{}",
+        failures.join("\n")
+    );
+}
+
+/// Runs Clippy on the file of the revision of the test, and counts the warnings of each lint.
+fn lint_counts(
+    config: &compiletest::Config,
+    file: &Path,
+    headers: &TestHeaders,
+    revision: Option<&str>,
+    out_dir: &Path,
+) -> BTreeMap<String, usize> {
+    static LINT_CODE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#""code":\{"code":"(clippy::\w+)""#).unwrap());
+
+    let args = headers.args(revision, config.edition.as_deref().unwrap_or("2015"));
+    let mut cmd = Command::new(&config.rustc_path);
+    cmd.envs(headers.env(revision));
+    cmd.arg(file)
+        .args(
+            config
+                .target_rustcflags
+                .iter()
+                .flat_map(|flags| flags.split_whitespace())
+                .filter(|flag| *flag != "-Dwarnings"),
+        )
+        .args(&args)
+        .args(["--error-format=json", "--force-warn", "clippy::all", "--out-dir"])
+        .arg(out_dir);
+    if !args.iter().any(|arg| arg.starts_with("--crate-name")) {
+        // the crate name can't be inferred from `name.revision.fixed`
+        let file_name = file.file_name().unwrap().to_string_lossy();
+        let crate_name = file_name.split('.').next().unwrap().replace('-', "_");
+        cmd.args(["--crate-name", &crate_name]);
+    }
+    let output = cmd.output().unwrap();
+
+    let mut counts = BTreeMap::new();
+    for line in String::from_utf8_lossy(&output.stderr).lines() {
+        if let Some(captures) = LINT_CODE.captures(line) {
+            *counts.entry(captures[1].to_owned()).or_default() += 1;
+        }
+    }
+    counts
+}
+
+/// The compiletest headers needed to compile a UI test without compiletest.
+#[derive(Default)]
+struct TestHeaders {
+    run_rustfix: bool,
+    has_aux_builds: bool,
+    revisions: Vec<String>,
+    /// The compile flags, editions and environment variables, with the revision they are
+    /// restricted to.
+    compile_flags: Vec<(Option<String>, String)>,
+    editions: Vec<(Option<String>, String)>,
+    rustc_env: Vec<(Option<String>, (String, String))>,
+}
+
+impl TestHeaders {
+    fn parse(path: &Path) -> Self {
+        let mut headers = Self::default();
+        for line in fs::read_to_string(path).unwrap().lines() {
+            let Some(line) = line.strip_prefix("//") else {
+                continue;
+            };
+            let (revision, header) = match line.strip_prefix('[').and_then(|line| line.split_once(']')) {
+                Some((revision, header)) => (Some(revision.to_owned()), header.trim()),
+                None => (None, line.trim()),
+            };
+            if header == "run-rustfix" {
+                headers.run_rustfix = true;
+            } else if header.starts_with("aux-build") {
+                headers.has_aux_builds = true;
+            } else if let Some(revisions) = header.strip_prefix("revisions:") {
+                headers
+                    .revisions
+                    .extend(revisions.split_whitespace().map(str::to_owned));
+            } else if let Some(flags) = header.strip_prefix("compile-flags:") {
+                headers.compile_flags.push((revision, flags.trim().to_owned()));
+            } else if let Some(edition) = header.strip_prefix("edition:") {
+                headers.editions.push((revision, edition.trim().to_owned()));
+            } else if let Some((key, value)) = header.strip_prefix("rustc-env:").and_then(|env| env.split_once('=')) {
+                headers
+                    .rustc_env
+                    .push((revision, (key.trim().to_owned(), value.trim().to_owned())));
+            }
+        }
+        headers
+    }
+
+    /// The revisions the test is run for, `None` if it has none.
+    fn revisions(&self) -> Vec<Option<&str>> {
+        if self.revisions.is_empty() {
+            vec![None]
+        } else {
+            self.revisions.iter().map(|revision| Some(revision.as_str())).collect()
+        }
+    }
+
+    /// The arguments compiletest passes to compile the revision of the test.
+    fn args(&self, revision: Option<&str>, default_edition: &str) -> Vec<String> {
+        let applies = |rev: &Option<String>| rev.is_none() || rev.as_deref() == revision;
+        let edition = self
+            .editions
+            .iter()
+            .rev()
+            .find(|(rev, _)| applies(rev))
+            .map_or(default_edition, |(_, edition)| edition);
+        let mut args = vec![format!("--edition={edition}")];
+        args.extend(
+            self.compile_flags
+                .iter()
+                .filter(|(rev, _)| applies(rev))
+                .flat_map(|(_, flags)| flags.split_whitespace().map(str::to_owned)),
+        );
+        if let Some(revision) = revision {
+            args.extend(["--cfg".to_owned(), revision.to_owned()]);
+        }
+        args
+    }
+
+    /// The environment variables compiletest sets to compile the revision of the test.
+    fn env(&self, revision: Option<&str>) -> Vec<(&str, &str)> {
+        self.rustc_env
+            .iter()
+            .filter(|(rev, _)| rev.is_none() || rev.as_deref() == revision)
+            .map(|(_, (key, value))| (key.as_str(), value.as_str()))
+            .collect()
+    }
+}
+
+/// The `.fixed` file of the revision of the test.
+fn fixed_file(path: &Path, revision: Option<&str>) -> PathBuf {
+    match revision {
+        Some(revision) => path.with_extension(format!("{revision}.fixed")),
+        None => path.with_extension("fixed"),
+    }
+}
+
+/// Checks that the revisions named after an MSRV, e.g. `msrv_1_60`, set it, and that the
+/// configuration directories set by revisions contain a `clippy.toml`.
+#[test]
+fn revision_configurations() {
+    for test_dir in ["tests/ui", "tests/ui-internal", "tests/ui-toml"] {
+        for entry in walkdir::WalkDir::new(test_dir) {
+            let entry = entry.unwrap();
+            let path = entry.path();
+            if path.extension() != Some(OsStr::new("rs")) || path.components().any(|c| c.as_os_str() == "auxiliary") {
+                continue;
+            }
+            let headers = TestHeaders::parse(path);
+            for revision in &headers.revisions {
+                let env = headers.env(Some(revision));
+                let var = |name: &str| env.iter().find(|(key, _)| *key == name).map(|(_, value)| *value);
+                if let Some(version) = revision.strip_prefix("msrv_") {
+                    let version = version.replace('_', ".");
+                    assert_eq!(
+                        var("CARGO_PKG_RUST_VERSION"),
+                        Some(version.as_str()),
+                        "the `{revision}` revision of `{}` should set the MSRV with \
+                        `//[{revision}] rustc-env:CARGO_PKG_RUST_VERSION={version}`",
+                        path.display(),
+                    );
+                }
+                if let Some(conf_dir) = var("CLIPPY_CONF_DIR") {
+                    assert!(
+                        Path::new(conf_dir).join("clippy.toml").exists(),
+                        "the `{revision}` revision of `{}` uses the configuration in `{conf_dir}`, \
+                        which has no `clippy.toml`",
+                        path.display(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Checks that the auxiliary proc-macro crates are built as dynamic libraries, as the test
+/// flags only emit metadata.
+#[test]
+fn auxiliary_proc_macros() {
+    for test_dir in ["tests/ui", "tests/ui-internal", "tests/ui-toml"] {
+        for entry in walkdir::WalkDir::new(test_dir) {
+            let entry = entry.unwrap();
+            let path = entry.path();
+            if path.extension() != Some(OsStr::new("rs"))
+                || path.parent().and_then(Path::file_name) != Some(OsStr::new("auxiliary"))
+            {
+                continue;
+            }
+            let source = fs::read_to_string(path).unwrap();
+            if !source.lines().any(|line| line == r#"#![crate_type = "proc-macro"]"#) {
+                continue;
+            }
+            for header in ["// compile-flags: --emit=link", "// no-prefer-dynamic"] {
+                assert!(
+                    source.lines().any(|line| line == header),
+                    "`{}` is a proc-macro crate, add `{header}` at the top of the file",
+                    path.display(),
+                );
+            }
+        }
+    }
 }
 
 #[test]
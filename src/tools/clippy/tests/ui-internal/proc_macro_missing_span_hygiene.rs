@@ -0,0 +1,14 @@
+#![deny(clippy::internal)]
+
+extern crate proc_macro;
+
+use proc_macro::{Ident, Span, TokenStream};
+
+// not a proc-macro crate, but a helper building the tokens of one
+pub fn getter(name: &str) -> (Ident, TokenStream) {
+    let ident = Ident::new(&format!("get_{name}"), Span::call_site());
+    let tokens: TokenStream = format!("fn get_{name}() {{}}").parse().unwrap();
+    (ident, tokens)
+}
+
+fn main() {}
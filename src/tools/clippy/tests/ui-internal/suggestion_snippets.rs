@@ -0,0 +1,112 @@
+#![deny(clippy::internal)]
+#![allow(clippy::missing_clippy_version_attribute)]
+#![feature(rustc_private)]
+
+extern crate clippy_utils;
+extern crate rustc_ast;
+extern crate rustc_errors;
+extern crate rustc_lint;
+extern crate rustc_session;
+
+use clippy_utils::diagnostics::{span_lint_and_sugg, span_lint_and_then};
+use clippy_utils::source::{snippet, snippet_indent, snippet_with_applicability};
+use rustc_ast::ast::Expr;
+use rustc_errors::Applicability;
+use rustc_lint::{EarlyContext, EarlyLintPass};
+use rustc_session::{declare_lint_pass, declare_tool_lint};
+
+declare_tool_lint! {
+    pub clippy::TEST_LINT,
+    Warn,
+    "",
+    report_in_external_macro: true
+}
+
+declare_lint_pass!(Pass => [TEST_LINT]);
+
+impl EarlyLintPass for Pass {
+    fn check_expr(&mut self, cx: &EarlyContext, expr: &Expr) {
+        let body = "body";
+        span_lint_and_sugg(
+            cx,
+            TEST_LINT,
+            expr.span,
+            "lint message",
+            "help message",
+            format!(
+                "{{
+{body}
+}}"
+            ),
+            Applicability::MaybeIncorrect,
+        );
+        // not computed by an indentation helper
+        let indent = "    ";
+        span_lint_and_sugg(
+            cx,
+            TEST_LINT,
+            expr.span,
+            "lint message",
+            "help message",
+            format!(
+                "{{
+{indent}{body}
+}}"
+            ),
+            Applicability::MaybeIncorrect,
+        );
+        let indent = snippet_indent(cx, expr.span).unwrap_or_default();
+        span_lint_and_sugg(
+            cx,
+            TEST_LINT,
+            expr.span,
+            "lint message",
+            "help message",
+            format!("{{\n{indent}    {body}\n{indent}}}"),
+            Applicability::MaybeIncorrect,
+        );
+
+        let snip = snippet(cx, expr.span, "..");
+        span_lint_and_sugg(
+            cx,
+            TEST_LINT,
+            expr.span,
+            "lint message",
+            "help message",
+            format!("{snip}.len()"),
+            Applicability::MachineApplicable,
+        );
+        span_lint_and_then(cx, TEST_LINT, expr.span, "lint message", |diag| {
+            diag.span_suggestion(
+                expr.span,
+                "help message",
+                snippet(cx, expr.span, "..").to_string(),
+                Applicability::MachineApplicable,
+            );
+            diag.note("note message");
+        });
+
+        let mut app = Applicability::MachineApplicable;
+        let snip = snippet_with_applicability(cx, expr.span, "..", &mut app);
+        span_lint_and_sugg(
+            cx,
+            TEST_LINT,
+            expr.span,
+            "lint message",
+            "help message",
+            format!("{snip}.len()"),
+            app,
+        );
+        span_lint_and_sugg(
+            cx,
+            TEST_LINT,
+            expr.span,
+            "lint message",
+            "help message",
+            snippet(cx, expr.span, "..").to_string(),
+            Applicability::MaybeIncorrect,
+        );
+    }
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+// compile-flags: --test
+#![warn(clippy::unwrap_used, clippy::indexing_slicing, clippy::expect_used)]
+
+fn main() {
+    let opt = Some(0);
+    let _ = opt.unwrap();
+    let v = vec![1, 2, 3];
+    let _ = &v[1..];
+}
+
+#[test]
+fn test_fn() {
+    let opt = Some(0);
+    let _ = opt.unwrap();
+    let v = vec![1, 2, 3];
+    let _ = &v[1..];
+    // not listed in `allow-in-tests`
+    let _ = opt.expect("");
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper(v: &[u8]) -> u8 {
+        v.first().copied().unwrap() + v[1..].len() as u8
+    }
+
+    #[test]
+    fn nested() {
+        let f = || Some(1).unwrap();
+        let _ = helper(&[f()]);
+    }
+}
@@ -0,0 +1,44 @@
+#![warn(clippy::arithmetic_side_effects)]
+
+fn not_allowed(n: i32) -> i32 {
+    n + 1
+}
+
+mod tests {
+    fn allowed(n: i32) -> i32 {
+        n + 1
+    }
+
+    mod nested {
+        fn allowed(n: i32) -> i32 {
+            -n * 2
+        }
+    }
+}
+
+mod math {
+    mod fixed_point {
+        fn allowed(n: i32) -> i32 {
+            n * n
+        }
+    }
+
+    mod fixed_point_helpers {
+        fn not_allowed(n: i32) -> i32 {
+            n * n
+        }
+    }
+
+    fn not_allowed(n: i32) -> i32 {
+        n - 1
+    }
+}
+
+mod fixed_point {
+    // Only matches `fixed_point` modules one level deep
+    fn not_allowed(n: i32) -> i32 {
+        n * n
+    }
+}
+
+fn main() {}
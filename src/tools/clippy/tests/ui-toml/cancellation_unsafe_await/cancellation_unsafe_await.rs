@@ -0,0 +1,29 @@
+#![warn(clippy::cancellation_unsafe_await)]
+
+use futures::future::FutureExt;
+use futures::{select, select_biased};
+use tokio::io::AsyncWriteExt;
+
+async fn shutdown() {}
+
+async fn flush(out: &mut Vec<u8>) {
+    select_biased! {
+        res = out.flush().fuse() => res.unwrap(),
+        _ = shutdown().fuse() => {},
+    }
+    // Not in the configured macros
+    select! {
+        res = out.flush().fuse() => res.unwrap(),
+        _ = shutdown().fuse() => {},
+    }
+}
+
+async fn write(out: &mut Vec<u8>, buf: &[u8]) {
+    // Not in the configured methods
+    select_biased! {
+        res = out.write_all(buf).fuse() => res.unwrap(),
+        _ = shutdown().fuse() => {},
+    }
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+#![warn(clippy::cognitive_complexity)]
+
+fn one_if(a: u32) -> u32 {
+    if a > 1 { a } else { 0 }
+}
+
+fn one_match(a: u32) -> u32 {
+    match a {
+        0 => 1,
+        _ => a,
+    }
+}
+
+fn question_marks(a: Option<u32>) -> Option<u32> {
+    let a = a?;
+    let b = a.checked_add(1)?;
+    b.checked_mul(2)
+}
+
+fn main() {}
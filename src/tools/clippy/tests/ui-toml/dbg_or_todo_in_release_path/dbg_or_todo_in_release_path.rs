@@ -0,0 +1,22 @@
+#![warn(clippy::dbg_or_todo_in_release_path)]
+
+#[macro_export]
+macro_rules! trace {
+    ($e:expr) => {{
+        eprintln!("{:?}", $e);
+        $e
+    }};
+}
+
+fn checked(n: u32) {
+    match n {
+        0 => unreachable!("checked by the caller"),
+        1 => unreachable!(),
+        _ => {},
+    }
+}
+
+fn main() {
+    let _ = trace!(1);
+    checked(2);
+}
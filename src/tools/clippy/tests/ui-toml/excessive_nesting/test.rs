@@ -0,0 +1,17 @@
+#![warn(clippy::excessive_nesting)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    for x in &v {
+        if *x > 1 {
+            println!("{x}");
+        }
+    }
+    for x in &v {
+        for y in &v {
+            if x == y {
+                println!("{x}");
+            }
+        }
+    }
+}
@@ -0,0 +1,24 @@
+#![warn(clippy::expensive_constructor_in_loop)]
+
+extern crate regex;
+
+use regex::Regex;
+
+struct Pool;
+
+impl Pool {
+    fn new(_size: usize) -> Self {
+        Pool
+    }
+}
+
+fn main() {
+    for _ in 0..10 {
+        let _ = Pool::new(4);
+        // Not in the configured list
+        let _ = Regex::new("a+b");
+    }
+    for size in 0..10 {
+        let _ = Pool::new(size);
+    }
+}
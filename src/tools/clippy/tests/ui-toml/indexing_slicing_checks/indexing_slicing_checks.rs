@@ -0,0 +1,19 @@
+#![warn(clippy::indexing_slicing)]
+#![allow(clippy::no_effect, clippy::unnecessary_operation, clippy::redundant_slicing)]
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let i = 1;
+    let [first, ..] = [1, 2, 3];
+    let _ = first;
+
+    // Only string slicing is checked
+    v[i];
+    &v[i..];
+
+    let s = "hello";
+    let string = String::from("hello");
+    &s[i..];
+    &string[..i];
+    &s[..];
+}
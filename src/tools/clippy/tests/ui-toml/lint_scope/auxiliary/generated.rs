@@ -0,0 +1,7 @@
+pub fn generated() -> u8 {
+    let v = [1, 2, 3];
+    // An early lint
+    let x = 1i32;
+    let _ = --x;
+    Some(v[..2][0]).unwrap()
+}
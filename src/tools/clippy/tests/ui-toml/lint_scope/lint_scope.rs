@@ -0,0 +1,19 @@
+#![warn(clippy::unwrap_used, clippy::expect_used, clippy::indexing_slicing)]
+
+#[path = "auxiliary/generated.rs"]
+mod generated;
+
+// Attributes on the items of the file take precedence over the scopes
+#[warn(clippy::indexing_slicing)]
+fn first(v: &[i32]) -> i32 {
+    v[0]
+}
+
+fn main() {
+    let _ = Some(generated::generated()).unwrap();
+    // `expect_used` is only emitted in `core` directories
+    let _ = Some(1).expect("");
+    let v = vec![1, 2, 3];
+    let _ = &v[1..];
+    let _ = first(&v);
+}
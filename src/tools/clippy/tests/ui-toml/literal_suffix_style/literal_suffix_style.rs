@@ -0,0 +1,18 @@
+// run-rustfix
+#![warn(clippy::literal_suffix_style)]
+
+fn main() {
+    let _ = 123i32;
+    let _ = 123_i32;
+    let _ = 1.5_f32;
+    let _ = 1e3_f64;
+    let _ = 2.5E-3_f64;
+    let _ = 0xFF_u8;
+    let _ = 1_000_000u32;
+    let _ = 1000000;
+    let _ = 0xDEADBEEFu32;
+    let _ = 0xDEADBEEF_u32;
+    let _ = 12345.5f64;
+    let _ = 123456e3f64;
+    let _ = 1234;
+}
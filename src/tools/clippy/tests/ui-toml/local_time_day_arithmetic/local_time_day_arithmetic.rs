@@ -0,0 +1,42 @@
+#![warn(clippy::local_time_day_arithmetic)]
+#![allow(dead_code)]
+
+struct Local;
+struct Time;
+struct Duration;
+
+impl Local {
+    fn now() -> Time {
+        Time
+    }
+}
+
+impl Duration {
+    fn days(_: i64) -> Self {
+        Duration
+    }
+
+    fn hours(_: i64) -> Self {
+        Duration
+    }
+}
+
+fn tomorrow() -> (Time, Duration) {
+    (Local::now(), Duration::days(1))
+}
+
+fn in_closure() {
+    let _now = Local::now();
+    let _ = || Duration::days(7);
+}
+
+fn in_an_hour() -> (Time, Duration) {
+    // Not a duration of days
+    (Local::now(), Duration::hours(1))
+}
+
+fn days_only() -> Duration {
+    Duration::days(1)
+}
+
+fn main() {}
@@ -0,0 +1,29 @@
+#![warn(clippy::cyclic_module_imports)]
+#![allow(dead_code, unused_imports)]
+
+mod a {
+    use crate::b::B;
+    pub struct A;
+}
+
+mod b {
+    use crate::a::A;
+    pub struct B;
+}
+
+mod c {
+    use crate::d::D;
+    pub struct C;
+}
+
+mod d {
+    use crate::e::E;
+    pub struct D;
+}
+
+mod e {
+    use crate::c::C;
+    pub struct E;
+}
+
+fn main() {}
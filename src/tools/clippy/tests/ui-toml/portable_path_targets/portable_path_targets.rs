@@ -0,0 +1,11 @@
+#![warn(clippy::non_portable_path_literal)]
+
+use std::fs;
+
+const APP_CONFIG: &str = "config\\app.toml";
+
+fn main() {
+    // Only paths which don't work on Unix are linted
+    let _ = fs::read(APP_CONFIG);
+    let _ = fs::read("/tmp/app.log");
+}
@@ -0,0 +1,15 @@
+#![crate_type = "proc-macro"]
+#![warn(clippy::proc_macro_derive_missing_span_hygiene)]
+
+extern crate proc_macro;
+
+use proc_macro::{Ident, Span, TokenStream};
+
+#[proc_macro_derive(Getter)]
+pub fn derive_getter(input: TokenStream) -> TokenStream {
+    let name = input.to_string();
+    let _ = Ident::new(&format!("get_{name}"), Span::call_site());
+    // not configured
+    let _ = Ident::new(&name, Span::mixed_site());
+    format!("fn get_{name}() {{}}").parse().unwrap()
+}
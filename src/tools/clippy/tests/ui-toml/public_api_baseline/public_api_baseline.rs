@@ -0,0 +1,28 @@
+#![warn(clippy::semver)]
+
+pub struct Config {
+    pub verbose: bool,
+    pub jobs: u32,
+}
+
+pub enum Format {
+    Json,
+    Toml,
+}
+
+pub const LIMIT: usize = 10;
+
+pub fn parse(input: &str) -> Result<u32, std::num::ParseIntError> {
+    input.parse()
+}
+
+pub fn unchanged(x: u32) -> u32 {
+    x
+}
+
+// Not part of the public API
+mod private {
+    pub fn helper() {}
+}
+
+fn main() {}
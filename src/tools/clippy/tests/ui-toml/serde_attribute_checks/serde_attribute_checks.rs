@@ -0,0 +1,18 @@
+#![warn(clippy::serde_attribute_misuse)]
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
+struct Request {
+    #[serde(skip_serializing)]
+    id: u64,
+    #[serde(rename = "user_name")]
+    name: String,
+    #[serde(flatten)]
+    extra: HashMap<String, String>,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+#![warn(clippy::sleep_in_loop_without_backoff)]
+
+use std::time::Duration;
+
+fn pause(duration: Duration) {
+    std::thread::sleep(duration);
+}
+
+fn main() {
+    loop {
+        pause(Duration::ZERO);
+        // Not in the configured list
+        std::thread::sleep(Duration::ZERO);
+    }
+}
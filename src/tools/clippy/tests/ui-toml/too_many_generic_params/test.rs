@@ -0,0 +1,11 @@
+#![warn(clippy::too_many_generic_params)]
+
+fn ok<A, B>() {}
+
+fn bad<A, B, C>() {}
+
+struct Pair<A, B>(A, B);
+
+struct Triple<A, B, C>(A, B, C);
+
+fn main() {}
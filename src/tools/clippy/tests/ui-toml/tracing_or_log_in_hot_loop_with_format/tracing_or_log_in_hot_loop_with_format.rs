@@ -0,0 +1,41 @@
+#![warn(clippy::tracing_or_log_in_hot_loop_with_format)]
+#![allow(clippy::to_string_in_format_args)]
+
+pub fn log_message(_: std::fmt::Arguments<'_>) {}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::log_message(format_args!($($arg)+))
+    };
+}
+
+struct Item {
+    group: String,
+    name: String,
+    id: u32,
+}
+
+fn main() {
+    let items = [Item {
+        group: "a".into(),
+        name: "b".into(),
+        id: 1,
+    }];
+    for item in &items {
+        trace!("processing {}", format!("{}/{}", item.group, item.name));
+        trace!("id {}", item.id.to_string());
+        trace!("id {:>5}", item.id.to_string());
+        if item.id > 1 {
+            // not run on every iteration
+            trace!("unusual id {}", format!("#{}", item.id));
+        }
+    }
+    items.iter().for_each(|item| trace!("name {}", item.name.to_string()));
+    let mut n = 0;
+    while n < 3 {
+        trace!("n = {}", n.to_string());
+        n += 1;
+    }
+    trace!("done after {}", format!("{n} iterations"));
+}
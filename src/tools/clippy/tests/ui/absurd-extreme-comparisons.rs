@@ -1,6 +1,7 @@
 #![warn(clippy::absurd_extreme_comparisons)]
 #![allow(
     unused,
+    clippy::cast_lossy_in_comparison,
     clippy::eq_op,
     clippy::no_effect,
     clippy::unnecessary_operation,
@@ -59,3 +60,22 @@ pub fn bar(len: u64) -> bool {
     // This is OK as we are casting from target sized to fixed size
     len >= usize::MAX as u64
 }
+
+#[derive(Clone, Copy)]
+enum Level {
+    Low = 1,
+    High = 10,
+}
+
+const ALL_SET: u8 = !0;
+
+fn casts(x: u32, i: i8, level: Level) {
+    let _ = (x as u8) as u32 > 300;
+    let _ = i as i32 >= -200;
+    let _ = level as u8 > 10;
+    let _ = x as u8 > ALL_SET;
+    // ok
+    let _ = (x as u8) as u32 > 200;
+    let _ = level as u8 == 10;
+    let _ = x as u64 > 300;
+}
@@ -0,0 +1,27 @@
+#![allow(unused, clippy::iterator_collect_vec_then_index)]
+#![warn(clippy::args_positional_skip)]
+
+use std::env;
+
+fn main() {
+    let _ = env::args().nth(0);
+    let _ = env::args_os().nth(0);
+    let _ = &env::args().collect::<Vec<_>>()[1];
+    let args: Vec<String> = env::args().collect();
+    let _ = &args[2];
+    let _ = env::args_os().nth(1).unwrap().to_str().unwrap().to_owned();
+    for arg in env::args_os() {
+        let _ = arg.into_string().unwrap();
+    }
+    let _: Vec<String> = env::args_os().map(|arg| arg.to_str().unwrap().to_owned()).collect();
+
+    // no warning
+    let _ = env::args().nth(1);
+    let _ = env::args().next();
+    let checked: Vec<String> = env::args().collect();
+    if checked.len() > 2 {
+        let _ = &checked[2];
+    }
+    let _ = &env::args().collect::<Vec<_>>()[0];
+    let _ = env::args_os().nth(1).and_then(|arg| arg.into_string().ok());
+}
@@ -0,0 +1,30 @@
+#![allow(unused, clippy::assertions_on_constants, clippy::blocks_in_if_conditions)]
+#![warn(clippy::assert_on_constants_extended)]
+
+fn side_effect() -> bool {
+    println!("checked");
+    true
+}
+
+fn main() {
+    let mut queue = vec![1, 2, 3];
+    let mut count = 0;
+    let flag = true;
+
+    assert!(queue.pop().is_some());
+    assert!(queue.pop().is_some(), "with a message");
+    debug_assert!({
+        count += 1;
+        count > 0
+    });
+
+    // ok
+    // linted by `debug_assert_with_mut_call`
+    debug_assert!(queue.pop().is_some());
+    // the side effects of called functions aren't detected
+    assert!(side_effect());
+    assert!(!queue.is_empty());
+    debug_assert!(queue.len() < 10);
+    debug_assert!(flag);
+    assert!(true);
+}
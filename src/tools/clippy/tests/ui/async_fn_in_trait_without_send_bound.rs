@@ -0,0 +1,23 @@
+// run-rustfix
+#![feature(return_position_impl_trait_in_trait)]
+#![allow(incomplete_features, dead_code)]
+#![warn(clippy::async_fn_in_trait_without_send_bound)]
+
+use std::future::Future;
+
+pub trait Store {
+    fn put(&self, key: u64, value: Vec<u8>) -> impl Future<Output = ()>;
+
+    // ok, has a `Send` bound
+    fn remove(&self, key: u64) -> impl Future<Output = bool> + Send;
+
+    // ok, not a future
+    fn capacity(&self) -> usize;
+}
+
+// ok, not public
+trait PrivateStore {
+    fn get(&self, key: u64) -> impl Future<Output = Vec<u8>>;
+}
+
+fn main() {}
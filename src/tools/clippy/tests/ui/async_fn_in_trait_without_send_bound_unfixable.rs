@@ -0,0 +1,16 @@
+#![feature(async_fn_in_trait)]
+#![allow(incomplete_features, dead_code)]
+#![warn(clippy::async_fn_in_trait_without_send_bound)]
+
+pub trait Store {
+    async fn get(&self, key: u64) -> Vec<u8>;
+
+    async fn flush(&self);
+}
+
+// ok, not public
+trait PrivateStore {
+    async fn get(&self, key: u64) -> Vec<u8>;
+}
+
+fn main() {}
@@ -0,0 +1,53 @@
+#![warn(clippy::atomic_ordering_pairing)]
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static READY: AtomicBool = AtomicBool::new(false);
+static PAIRED: AtomicBool = AtomicBool::new(false);
+static WRITE_ONLY: AtomicBool = AtomicBool::new(false);
+
+struct Shared {
+    refs: AtomicUsize,
+    len: AtomicUsize,
+    next_id: AtomicUsize,
+}
+
+impl Shared {
+    fn set_len(&self, len: usize) {
+        self.len.store(len, Ordering::Release);
+    }
+
+    fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    fn release(&self) -> bool {
+        self.refs.fetch_sub(1, Ordering::Relaxed) == 1
+    }
+
+    fn release_ok(&self) -> bool {
+        self.refs.fetch_sub(1, Ordering::AcqRel) == 1
+    }
+
+    fn next_id(&self) -> usize {
+        // ok, the result isn't compared
+        self.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+fn publish() {
+    READY.store(true, Ordering::Release);
+    PAIRED.store(true, Ordering::Release);
+    // ok, never read in this crate
+    WRITE_ONLY.store(true, Ordering::Release);
+}
+
+fn is_ready() -> bool {
+    READY.load(Ordering::Relaxed)
+}
+
+fn is_paired() -> bool {
+    PAIRED.load(Ordering::Relaxed) || PAIRED.load(Ordering::Acquire)
+}
+
+fn main() {}
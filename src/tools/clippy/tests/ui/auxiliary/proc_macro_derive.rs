@@ -86,3 +86,22 @@ pub fn something<'c>() -> Self {
         }
     )
 }
+
+#[proc_macro_derive(CopyIterator)]
+pub fn derive_copy_iterator(_input: TokenStream) -> TokenStream {
+    quote! {
+        #[derive(Clone, Copy)]
+        struct DerivedCountdown(u8);
+
+        impl Iterator for DerivedCountdown {
+            type Item = u8;
+
+            fn next(&mut self) -> Option<u8> {
+                self.0.checked_sub(1).map(|c| {
+                    self.0 = c;
+                    c
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+// compile-flags: --emit=link
+// no-prefer-dynamic
+
+//! Proc-macros generating the code of common derive, attribute and function-like macros, to test
+//! that lints aren't emitted in their expansions. The generated code keeps the spans of the
+//! user's tokens it is built from, like the macros it imitates.
+
+#![crate_type = "proc-macro"]
+#![feature(let_chains)]
+
+extern crate proc_macro;
+extern crate quote;
+extern crate syn;
+
+use proc_macro::{Span, TokenStream, TokenTree};
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Fields, ImplItem, Item, ReturnType, Signature, TraitItem};
+
+/// Implements `Clone` by calling `clone()` on every field, like `#[derive(Clone)]` on a
+/// struct with named fields.
+#[proc_macro_derive(ManualClone)]
+pub fn manual_clone(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let fields: Vec<_> = named_fields(&input).into_iter().map(|(field, _)| field).collect();
+    quote! {
+        impl ::core::clone::Clone for #name {
+            fn clone(&self) -> Self {
+                Self {
+                    #(#fields: self.#fields.clone(),)*
+                }
+            }
+        }
+    }
+    .into()
+}
+
+/// Generates a `<Name>Builder` with a setter for every field, and a `build` method returning an
+/// error if a field wasn't set, like the `derive_builder` crate.
+#[proc_macro_derive(Builder)]
+pub fn builder(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let vis = &input.vis;
+    let name = &input.ident;
+    let builder = format_ident!("{}Builder", name);
+    let (fields, tys): (Vec<_>, Vec<_>) = named_fields(&input).into_iter().unzip();
+    quote! {
+        #vis struct #builder {
+            #(#fields: ::core::option::Option<#tys>,)*
+        }
+
+        impl #name {
+            #vis fn builder() -> #builder {
+                #builder {
+                    #(#fields: ::core::option::Option::None,)*
+                }
+            }
+        }
+
+        impl #builder {
+            #(
+                #vis fn #fields(mut self, #fields: #tys) -> Self {
+                    self.#fields = ::core::option::Option::Some(#fields);
+                    self
+                }
+            )*
+
+            #vis fn build(self) -> ::core::result::Result<#name, &'static str> {
+                ::core::result::Result::Ok(#name {
+                    #(#fields: self.#fields.ok_or(::core::stringify!(#fields))?,)*
+                })
+            }
+        }
+    }
+    .into()
+}
+
+/// Turns the `async fn`s of a trait or an impl into functions returning a boxed future, like
+/// the `async-trait` crate. Only `&self` receivers and arguments without references are
+/// supported.
+#[proc_macro_attribute]
+pub fn async_trait_like(_args: TokenStream, input: TokenStream) -> TokenStream {
+    let mut item = parse_macro_input!(input as Item);
+    match &mut item {
+        Item::Trait(item) => {
+            for item in &mut item.items {
+                if let TraitItem::Method(method) = item
+                    && method.sig.asyncness.is_some()
+                {
+                    desugar_async_sig(&mut method.sig);
+                    if let Some(block) = &method.default {
+                        method.default = Some(parse_quote!({ ::std::boxed::Box::pin(async move #block) }));
+                    }
+                }
+            }
+        },
+        Item::Impl(item) => {
+            for item in &mut item.items {
+                if let ImplItem::Method(method) = item
+                    && method.sig.asyncness.is_some()
+                {
+                    desugar_async_sig(&mut method.sig);
+                    let block = &method.block;
+                    method.block = parse_quote!({ ::std::boxed::Box::pin(async move #block) });
+                }
+            }
+        },
+        _ => panic!("`#[async_trait_like]` is only supported on traits and impls"),
+    }
+    quote!(#item).into()
+}
+
+/// Gives all the tokens the call site span, so they look like they were generated by the macro.
+#[proc_macro]
+pub fn call_site(input: TokenStream) -> TokenStream {
+    fn respan(input: TokenStream) -> TokenStream {
+        input
+            .into_iter()
+            .map(|tt| match tt {
+                TokenTree::Group(group) => {
+                    let mut new = proc_macro::Group::new(group.delimiter(), respan(group.stream()));
+                    new.set_span(Span::call_site());
+                    TokenTree::Group(new)
+                },
+                mut tt => {
+                    tt.set_span(Span::call_site());
+                    tt
+                },
+            })
+            .collect()
+    }
+    respan(input)
+}
+
+fn named_fields(input: &DeriveInput) -> Vec<(syn::Ident, syn::Type)> {
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .map(|field| (field.ident.clone().unwrap(), field.ty.clone()))
+                .collect(),
+            _ => panic!("only structs with named fields are supported"),
+        },
+        _ => panic!("only structs are supported"),
+    }
+}
+
+/// Changes `async fn f(&self, ..) -> T` to
+/// `fn f<'life0, 'async_trait>(&'life0 self, ..) -> Pin<Box<dyn Future<Output = T> + Send + 'async_trait>>`.
+fn desugar_async_sig(sig: &mut Signature) {
+    sig.asyncness = None;
+    let output = match &sig.output {
+        ReturnType::Default => quote!(()),
+        ReturnType::Type(_, ty) => quote!(#ty),
+    };
+    sig.generics.params.insert(0, parse_quote!('life0));
+    sig.generics.params.insert(1, parse_quote!('async_trait));
+    if let Some(syn::FnArg::Receiver(receiver)) = sig.inputs.first_mut()
+        && let Some((_, lifetime)) = &mut receiver.reference
+    {
+        *lifetime = Some(parse_quote!('life0));
+    }
+    let where_clause = sig.generics.make_where_clause();
+    where_clause.predicates.push(parse_quote!('life0: 'async_trait));
+    where_clause.predicates.push(parse_quote!(Self: ::core::marker::Sync + 'async_trait));
+    sig.output = parse_quote! {
+        -> ::core::pin::Pin<::std::boxed::Box<
+            dyn ::core::future::Future<Output = #output> + ::core::marker::Send + 'async_trait
+        >>
+    };
+}
@@ -0,0 +1,27 @@
+#![warn(clippy::bit_mask_with_shift_overflow)]
+#![allow(arithmetic_overflow)]
+
+fn shifts(x: u32, y: u64, n: u32) {
+    let _ = x >> 32;
+    for i in 0..=32 {
+        let _ = 1_u32 << i;
+    }
+    let amount = y % 64;
+    let _ = x << amount;
+    let mut z = x;
+    z <<= amount;
+    let _ = z;
+
+    // ok
+    let _ = y << amount;
+    let _ = x >> 31;
+    let _ = x << (n & 31);
+    let _ = x << n;
+    for i in 0..32 {
+        let _ = 1_u32 << i;
+    }
+}
+
+fn main() {
+    shifts(1, 2, 3);
+}
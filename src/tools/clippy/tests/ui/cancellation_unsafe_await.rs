@@ -0,0 +1,47 @@
+#![warn(clippy::cancellation_unsafe_await)]
+
+use futures::future::FutureExt;
+use futures::select;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+async fn shutdown() {}
+
+async fn write(out: &mut Vec<u8>, buf: &[u8]) {
+    loop {
+        select! {
+            res = out.write_all(buf).fuse() => res.unwrap(),
+            _ = shutdown().fuse() => break,
+        }
+    }
+}
+
+async fn read(input: &mut &[u8]) {
+    let mut buf = [0; 8];
+    select! {
+        res = input.read_exact(&mut buf).fuse() => {
+            res.unwrap();
+        },
+        _ = shutdown().fuse() => {},
+    }
+}
+
+async fn ok(out: &mut Vec<u8>, buf: &[u8]) {
+    // awaited in the handler, which runs to completion
+    select! {
+        _ = shutdown().fuse() => out.write_all(buf).await.unwrap(),
+        complete => {},
+    }
+
+    // pinned beforehand, so it can be resumed
+    let write = out.write_all(buf).fuse();
+    futures::pin_mut!(write);
+    select! {
+        res = write => res.unwrap(),
+        _ = shutdown().fuse() => {},
+    }
+
+    // not in a select
+    out.write_all(buf).await.unwrap();
+}
+
+fn main() {}
@@ -261,3 +261,16 @@ fn avoid_subtract_overflow(q: u32) {
     let c = (q / 1000) as u8;
     c as usize;
 }
+
+fn avoid_known_ranges(y: u64, b: u8, arr: [u8; 16]) {
+    // no lint, the values are known to fit
+    let _ = (y % 1000 + 1) as u16;
+    let _ = y.count_ones() as u8;
+    let _ = arr.len() as u8;
+    let _ = (b as u32 * 100) as u16;
+    for i in 0..200_u64 {
+        let _ = i as u8;
+    }
+
+    let _ = (y % 100_000) as u16;
+}
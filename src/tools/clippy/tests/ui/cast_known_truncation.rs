@@ -0,0 +1,48 @@
+#![warn(clippy::cast_known_truncation)]
+
+fn main() {
+    let mut buf: Vec<u8> = Vec::new();
+    for i in 0..300 {
+        buf.push(i as u8);
+    }
+    for i in -5..=5_i32 {
+        buf.push(i as u8);
+    }
+    let len = 70_000_u32;
+    let _ = len as u16;
+    let offset = -1_i64;
+    let _ = offset as u16;
+
+    // Every value fits
+    for i in 0..=255 {
+        buf.push(i as u8);
+    }
+    let _ = (len % 256) as u8;
+    // Not truncated, only reinterpreted
+    let minus_one = -1_i32;
+    let _ = minus_one as u32;
+    // Only bounds are known
+    let _ = (len & 0xff_ffff) as u8;
+    // Casting a constant is usually done for the bit pattern
+    let _ = 0xffff_ffff_u32 as i32;
+    const MASK: u32 = 0x1_0000;
+    let _ = MASK as u16;
+    // Only the values which fit are casted
+    for i in 0..1000 {
+        if i < 256 {
+            buf.push(i as u8);
+        }
+    }
+    for i in 0..1000 {
+        if i > 255 {
+            break;
+        }
+        buf.push(i as u8);
+    }
+    for i in 0..1000 {
+        match i {
+            0..=255 => buf.push(i as u8),
+            _ => break,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+#![warn(clippy::cast_lossy_in_comparison)]
+#![allow(clippy::cast_known_truncation)]
+
+fn main() {
+    let a: u32 = 300;
+    let b: u8 = 44;
+    let c: i64 = -1000;
+    let d: i8 = 3;
+    let len: usize = 1000;
+
+    let _ = a as u8 == b;
+    let _ = b < a as u8;
+    let _ = a as u8 == 44;
+    let _ = (a + 1) as u8 != b;
+    let _ = c as i8 >= d;
+    let _ = a as i8 == d;
+    let _ = len as u8 == b;
+    let _ = b > (a as u16) as u8;
+
+    // Not truncated
+    let _ = b as u32 == a;
+    let _ = (a & 0xff) as u8 == b;
+    let _ = (a % 100) as u8 == b;
+    let _ = len as u32 == a;
+    let _ = 300_u32 as u8 == b;
+    // Not compared
+    let _ = a as u8 + b;
+}
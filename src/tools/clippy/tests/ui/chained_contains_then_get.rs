@@ -0,0 +1,38 @@
+#![warn(clippy::chained_contains_then_get)]
+
+use std::collections::{BTreeMap, HashMap};
+
+fn hash_map(map: &HashMap<u32, String>, k: u32) {
+    if map.contains_key(&k) {
+        println!("{}", map.get(&k).unwrap());
+    }
+}
+
+fn btree_map(map: &BTreeMap<u32, String>, k: u32) -> usize {
+    if map.contains_key(&k) {
+        return map.get(&k).expect("checked above").len();
+    }
+    0
+}
+
+fn map_mutated(map: &mut HashMap<u32, String>, k: u32) {
+    if map.contains_key(&k) {
+        map.clear();
+        println!("{}", map.get(&k).unwrap());
+    }
+}
+
+fn key_mutated(map: &HashMap<u32, String>, mut k: u32) {
+    if map.contains_key(&k) {
+        k += 1;
+        println!("{}", map.get(&k).unwrap());
+    }
+}
+
+fn other_key(map: &HashMap<u32, String>, k: u32, j: u32) {
+    if map.contains_key(&k) {
+        println!("{}", map.get(&j).unwrap());
+    }
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// run-rustfix
+#![warn(clippy::char_indices_offset_misuse)]
+#![allow(unused)]
+
+fn counts(s: &str, t: String) {
+    let _ = s.chars().count() == s.len();
+    let _ = t.len() != t.chars().count();
+    // Different strings
+    let _ = s.chars().count() == t.len();
+}
+
+fn main() {}
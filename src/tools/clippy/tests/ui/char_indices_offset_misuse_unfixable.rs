@@ -0,0 +1,19 @@
+#![warn(clippy::char_indices_offset_misuse)]
+#![allow(unused)]
+
+fn offsets(a: &str, b: &str, owned: String) {
+    for (i, _) in a.char_indices() {
+        let _ = &b[i..];
+        let _ = b.get(..i);
+        let _ = owned.split_at(i);
+        // Same string
+        let _ = &a[i..];
+        let _ = a.get(i..);
+    }
+    for (i, c) in owned.char_indices() {
+        let _ = &owned.as_str()[..i];
+        let _ = &owned[i + c.len_utf8()..];
+    }
+}
+
+fn main() {}
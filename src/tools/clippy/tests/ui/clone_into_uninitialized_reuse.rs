@@ -0,0 +1,46 @@
+// run-rustfix
+#![warn(clippy::clone_into_uninitialized_reuse)]
+#![allow(unused_assignments, clippy::clone_on_copy, clippy::needless_late_init, clippy::ptr_arg)]
+
+use std::collections::HashMap;
+
+struct Config {
+    name: String,
+}
+
+fn reuse(mut a: Vec<u8>, b: &Vec<u8>, names: &[String], config: &mut Config, out: &mut String) {
+    a = b.clone();
+    config.name = names[0].clone();
+    *out = config.name.clone();
+    let mut map = HashMap::new();
+    map.insert(1, 2);
+    let other = map.clone();
+    map = other.clone();
+    drop((a, map, other));
+}
+
+// ok
+fn first_assignment(b: &Vec<u8>) -> Vec<u8> {
+    let a;
+    a = b.clone();
+    a
+}
+
+fn not_allocating(mut a: u32, b: &u32) -> u32 {
+    a = b.clone();
+    a
+}
+
+fn rooted_in_lhs(mut v: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+    v[1] = v[0].clone();
+    v
+}
+
+fn main() {
+    let mut config = Config { name: String::new() };
+    let mut out = String::new();
+    reuse(vec![], &vec![1], &[String::new()], &mut config, &mut out);
+    first_assignment(&vec![]);
+    not_allocating(1, &2);
+    rooted_in_lhs(vec![vec![], vec![]]);
+}
@@ -0,0 +1,21 @@
+#![warn(clippy::clone_into_uninitialized_reuse)]
+#![allow(unused_assignments, clippy::ptr_arg)]
+
+fn moved_out(mut a: Vec<u8>, b: &Vec<u8>) -> Vec<u8> {
+    drop(a);
+    a = b.clone();
+    a
+}
+
+fn moved_in_loop(b: &Vec<u8>) {
+    let mut a = Vec::new();
+    for _ in 0..2 {
+        a = b.clone();
+        drop(a);
+    }
+}
+
+fn main() {
+    moved_out(vec![], &vec![1]);
+    moved_in_loop(&vec![1]);
+}
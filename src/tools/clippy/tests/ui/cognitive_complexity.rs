@@ -409,3 +409,38 @@ pub async fn async_method() {
         }
     }
 }
+
+// Branches expanded from external macros don't count towards the complexity
+#[clippy::cognitive_complexity = "1"]
+fn external_macros(a: u32) {
+    assert!(a > 1);
+    assert_eq!(a, 2);
+    debug_assert!(a < 10);
+}
+
+// ...but the code passed to them does
+#[clippy::cognitive_complexity = "1"]
+fn external_macro_args(a: u32) {
+    println!("{}", if a > 1 { "big" } else { "small" });
+}
+
+#[clippy::cognitive_complexity = "1"]
+fn question_marks(a: Option<u32>) -> Option<u32> {
+    let a = a?;
+    let b = a.checked_add(1)?;
+    b.checked_mul(2)
+}
+
+#[clippy::cognitive_complexity = "1"]
+fn while_loop(mut a: u32) {
+    while a > 0 {
+        a -= 1;
+    }
+}
+
+// `.await` doesn't count towards the complexity
+#[clippy::cognitive_complexity = "1"]
+async fn awaits() {
+    issue9300::S::async_method().await;
+    async {}.await;
+}
@@ -0,0 +1,83 @@
+#![warn(clippy::collection_resized_in_index_loop)]
+#![allow(clippy::needless_range_loop)]
+
+use std::collections::VecDeque;
+
+fn remove_even(v: &mut Vec<i32>) {
+    for i in 0..v.len() {
+        if v[i] % 2 == 0 {
+            v.remove(i);
+        }
+    }
+}
+
+fn duplicate(mut v: Vec<i32>) -> Vec<i32> {
+    for i in 0..v.len() {
+        let x = v[i];
+        v.push(x);
+    }
+    v
+}
+
+fn clear(mut q: VecDeque<i32>) {
+    for i in 0..q.len() {
+        println!("{}", q[i]);
+        q.clear();
+    }
+}
+
+// The `break` only leaves the inner loop
+fn remove_after_search(v: &mut Vec<i32>, w: &[i32]) {
+    for i in 0..v.len() {
+        for x in w {
+            if *x == v[i] {
+                break;
+            }
+        }
+        v.remove(i);
+    }
+}
+
+// ok
+fn remove_first(v: &mut Vec<i32>) {
+    for i in 0..v.len() {
+        if v[i] == 0 {
+            v.remove(i);
+            break;
+        }
+    }
+}
+
+fn other_collection(v: &[i32], w: &mut Vec<i32>) {
+    for i in 0..v.len() {
+        w.push(v[i]);
+    }
+}
+
+fn modify_in_place(v: &mut Vec<i32>) {
+    for i in 0..v.len() {
+        v[i] += 1;
+    }
+}
+
+fn remove_first_nested(vs: &mut [Vec<i32>]) {
+    'outer: for v in vs {
+        for i in 0..v.len() {
+            if v[i] == 0 {
+                v.remove(i);
+                break 'outer;
+            }
+        }
+    }
+}
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+    remove_even(&mut v);
+    remove_first(&mut v);
+    other_collection(&duplicate(v.clone()), &mut v);
+    modify_in_place(&mut v);
+    clear(VecDeque::new());
+    remove_after_search(&mut v, &[1]);
+    remove_first_nested(&mut [v]);
+}
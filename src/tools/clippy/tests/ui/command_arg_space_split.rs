@@ -0,0 +1,20 @@
+#![warn(clippy::command_arg_space_split)]
+
+use std::process::Command;
+
+fn main() {
+    let path = "my file.txt";
+    let flags = String::from("-l -a");
+
+    Command::new("ls").args("-l -a".split(' '));
+    Command::new("ls").args(flags.split(' '));
+    Command::new("ls -l");
+    Command::new(format!("cat -n {path}"));
+
+    // No lint
+    Command::new("ls").args(flags.split_whitespace());
+    Command::new("ls").args(["-l", "-a"]);
+    Command::new("C:\\Program Files\\app.exe");
+    Command::new(format!("{path}.exe"));
+    Command::new(format!("prefix-{path} -l"));
+}
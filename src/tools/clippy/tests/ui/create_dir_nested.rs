@@ -0,0 +1,32 @@
+#![warn(clippy::create_dir_nested)]
+
+use std::fs;
+use std::path::Path;
+
+fn nested(base: &Path) -> std::io::Result<()> {
+    let cache = base.join("cache");
+    fs::create_dir(cache.join("images"))?;
+
+    fs::create_dir(base.join("a").join("b"))?;
+    Ok(())
+}
+
+fn no_lint(base: &Path) -> std::io::Result<()> {
+    // The parent isn't built here
+    fs::create_dir(base.join("cache"))?;
+
+    // The parent is created
+    let logs = base.join("logs");
+    fs::create_dir(&logs)?;
+    fs::create_dir(logs.join("old"))?;
+
+    let tmp = base.join("tmp");
+    fs::create_dir_all(&tmp)?;
+    fs::create_dir(tmp.join("a"))?;
+
+    // `create_dir_all`
+    fs::create_dir_all(base.join("a").join("b"))?;
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,68 @@
+#![warn(clippy::cyclic_module_imports)]
+#![allow(dead_code, unused_imports)]
+
+mod a {
+    use crate::b::B;
+    pub struct A;
+}
+
+mod b {
+    use crate::a::A;
+    pub struct B;
+}
+
+mod c {
+    use crate::d::D;
+    pub struct C;
+}
+
+mod d {
+    use crate::e::E;
+    pub struct D;
+}
+
+mod e {
+    use crate::c::C;
+    pub struct E;
+}
+
+// Importing from the parent module doesn't count as a cycle
+mod parent {
+    pub use self::child::Child;
+    pub struct Parent;
+
+    mod child {
+        use super::Parent;
+        pub struct Child;
+    }
+}
+
+mod allowed {
+    #[allow(clippy::cyclic_module_imports)]
+    use crate::allowed_too::Y;
+    pub struct X;
+}
+
+mod allowed_too {
+    use crate::allowed::X;
+    pub struct Y;
+}
+
+fn main() {}
+
+// Overlapping cycles are reported separately: `f` -> `g` -> `h` -> `f` and `g` -> `h` -> `g`
+mod f {
+    use crate::g::G;
+    pub struct F;
+}
+
+mod g {
+    use crate::h::H;
+    pub struct G;
+}
+
+mod h {
+    use crate::f::F;
+    use crate::g::G;
+    pub struct H;
+}
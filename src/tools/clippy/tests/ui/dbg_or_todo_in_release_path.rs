@@ -0,0 +1,40 @@
+// compile-flags: --test
+#![allow(unused, clippy::no_effect)]
+#![warn(clippy::dbg_or_todo_in_release_path)]
+
+fn debug(n: u32) -> u32 {
+    dbg!(n)
+}
+
+fn unfinished() {
+    todo!()
+}
+
+fn not_implemented() {
+    unimplemented!("soon")
+}
+
+fn checked(n: u32) {
+    if n > 2 {
+        unreachable!("checked above");
+    }
+}
+
+// only reported by `todo`
+#[warn(clippy::todo)]
+fn reported_by_todo() {
+    todo!()
+}
+
+#[test]
+fn in_test() {
+    dbg!(1);
+    todo!();
+}
+
+#[cfg(test)]
+mod tests {
+    fn helper() {
+        unimplemented!();
+    }
+}
@@ -18,5 +18,6 @@
 #![warn(clippy::filter_map)]
 #![warn(clippy::pub_enum_variant_names)]
 #![warn(clippy::wrong_pub_self_convention)]
+#![warn(clippy::separated_literal_suffix)]
 
 fn main() {}
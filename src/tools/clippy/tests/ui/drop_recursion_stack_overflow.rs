@@ -0,0 +1,61 @@
+#![warn(clippy::drop_recursion_stack_overflow)]
+#![allow(dead_code)]
+
+struct Tree {
+    value: i32,
+    children: Vec<Tree>,
+}
+
+struct BinaryTree {
+    left: Option<Box<BinaryTree>>,
+    right: Option<Box<BinaryTree>>,
+}
+
+enum Expr {
+    Lit(i32),
+    Neg(Box<Expr>),
+    Add(Box<Expr>, Box<Expr>),
+}
+
+// ok, chains are linted by `recursive_type_without_indirection_hint`
+struct Node {
+    value: i32,
+    next: Option<Box<Node>>,
+}
+
+enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+struct GenericNode<T> {
+    value: T,
+    next: Option<Box<GenericNode<T>>>,
+}
+
+// ok
+struct WithDrop {
+    children: Vec<WithDrop>,
+}
+
+impl Drop for WithDrop {
+    fn drop(&mut self) {
+        let mut stack = std::mem::take(&mut self.children);
+        while let Some(mut node) = stack.pop() {
+            stack.append(&mut node.children);
+        }
+    }
+}
+
+struct Indirect {
+    children: Vec<Link>,
+}
+
+struct Link(Box<Indirect>);
+
+struct Flat {
+    values: Vec<i32>,
+    other: Option<Box<Tree>>,
+}
+
+fn main() {}
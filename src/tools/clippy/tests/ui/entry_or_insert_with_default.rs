@@ -0,0 +1,40 @@
+// run-rustfix
+#![warn(clippy::entry_or_insert_with_default)]
+#![allow(unused, clippy::redundant_closure)]
+
+use std::collections::{BTreeMap, HashMap};
+
+fn expensive() -> Vec<u32> {
+    vec![1, 2, 3]
+}
+
+fn make(n: u32) -> Vec<u32> {
+    vec![n]
+}
+
+fn main() {
+    let mut map: HashMap<u32, Vec<u32>> = HashMap::new();
+    map.entry(0).or_insert(Vec::new()).push(1);
+    map.entry(0).or_insert(Default::default()).push(1);
+    map.entry(0).or_insert_with(Default::default).push(1);
+    map.entry(0).or_insert_with(Vec::new).push(1);
+    map.entry(0).or_insert_with(|| Vec::new()).push(1);
+    map.entry(0).or_insert(expensive()).push(1);
+    map.entry(0).or_insert(make(5)).push(1);
+
+    let mut btree: BTreeMap<u32, String> = BTreeMap::new();
+    btree.entry(0).or_insert(String::new()).push('a');
+
+    // Don't lint
+    map.entry(0).or_default().push(1);
+    map.entry(0).or_insert_with(expensive).push(1);
+    map.entry(0).or_insert_with(|| vec![1]).push(1);
+    let v = vec![1];
+    map.entry(0).or_insert(v).push(1);
+    // Method arguments from macros are skipped
+    map.entry(0).or_insert(vec![]).push(1);
+    let mut counts: HashMap<u32, u32> = HashMap::new();
+    *counts.entry(0).or_insert(0) += 1;
+    let mut opt: Option<Vec<u32>> = None;
+    opt.get_or_insert(Vec::new()).push(1);
+}
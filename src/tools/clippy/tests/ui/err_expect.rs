@@ -1,3 +1,6 @@
+// revisions: msrv_1_16 msrv_1_17
+//[msrv_1_16] rustc-env:CARGO_PKG_RUST_VERSION=1.16
+//[msrv_1_17] rustc-env:CARGO_PKG_RUST_VERSION=1.17
 // run-rustfix
 
 #![allow(unused)]
@@ -14,15 +17,3 @@ fn main() {
     let test_non_debug: Result<MyTypeNonDebug, u32> = Ok(MyTypeNonDebug);
     test_non_debug.err().expect("Testing non debug type");
 }
-
-#[clippy::msrv = "1.16"]
-fn msrv_1_16() {
-    let x: Result<u32, &str> = Ok(16);
-    x.err().expect("16");
-}
-
-#[clippy::msrv = "1.17"]
-fn msrv_1_17() {
-    let x: Result<u32, &str> = Ok(17);
-    x.err().expect("17");
-}
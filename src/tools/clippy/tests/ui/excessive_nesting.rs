@@ -0,0 +1,59 @@
+#![warn(clippy::excessive_nesting)]
+#![allow(clippy::never_loop, clippy::while_immutable_condition, clippy::collapsible_if, clippy::single_match, unused)]
+
+fn too_deep(x: Option<u32>, v: &[u32]) {
+    for _ in v {
+        if x.is_some() {
+            match x {
+                Some(y) => {
+                    while y > 0 {
+                        let f = || {
+                            loop {
+                                if y == 1 {
+                                    return;
+                                }
+                            }
+                        };
+                    }
+                },
+                None => {},
+            }
+        }
+    }
+}
+
+// `else if` branches don't add to the nesting level
+fn else_if_chain(x: u32) -> u32 {
+    for _ in 0..x {
+        for _ in 0..x {
+            for _ in 0..x {
+                for _ in 0..x {
+                    for _ in 0..x {
+                        if x == 0 {
+                            return 0;
+                        } else if x == 1 {
+                            return 1;
+                        } else if x == 2 {
+                            return 2;
+                        } else {
+                            return 3;
+                        }
+                    }
+                }
+            }
+        }
+    }
+    0
+}
+
+fn nested_fn() {
+    if true {
+        if true {
+            fn inner() {
+                if true {}
+            }
+        }
+    }
+}
+
+fn main() {}
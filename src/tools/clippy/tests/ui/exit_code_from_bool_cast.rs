@@ -0,0 +1,36 @@
+#![allow(unused)]
+#![warn(clippy::exit_code_from_bool_cast)]
+
+use std::process::exit;
+
+fn success(success: bool) {
+    exit(success as i32);
+}
+
+fn is_err(res: Result<(), ()>) {
+    std::process::exit(res.is_err() as i32);
+}
+
+fn not_failed(failed: bool) {
+    exit(!failed as i32);
+}
+
+#[clippy::msrv = "1.60"]
+fn msrv_1_60(ok: bool) {
+    exit(ok as i32);
+}
+
+fn not_valid(valid: bool) {
+    exit(!valid as i32);
+}
+
+// Not a success condition, e.g. for the result of a search
+fn is_some(found: Option<usize>) {
+    exit(found.is_some() as i32);
+}
+
+fn main() {
+    // no warning
+    exit(if true { 1 } else { 0 });
+    exit(2);
+}
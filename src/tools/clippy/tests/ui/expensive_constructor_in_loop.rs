@@ -0,0 +1,32 @@
+#![warn(clippy::expensive_constructor_in_loop)]
+
+extern crate regex;
+
+use regex::Regex;
+
+fn main() {
+    let lines = ["1", "a"];
+    for line in lines {
+        let re = Regex::new("^[0-9]+$").unwrap();
+        let _ = re.is_match(line);
+    }
+    let mut i = 0;
+    while i < 2 {
+        let _ = Regex::new("a+b");
+        i += 1;
+    }
+    let _ = lines.iter().filter(|line| Regex::new("a+b").unwrap().is_match(line)).count();
+
+    // Don't lint
+    let re = Regex::new("^[0-9]+$").unwrap();
+    for line in lines {
+        // Depends on the loop
+        let _ = Regex::new(line);
+        let pattern = format!("{line}+");
+        let _ = Regex::new(&pattern);
+    }
+    // Not known to be called repeatedly
+    let make = || Regex::new("a+b");
+    let _ = make();
+    let _ = lines.iter().all(|line| re.is_match(line));
+}
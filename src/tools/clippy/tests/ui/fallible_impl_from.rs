@@ -74,4 +74,21 @@ fn from(s: String) -> Unreachable {
     }
 }
 
+struct Checked(u32);
+
+impl From<u32> for Checked {
+    fn from(i: u32) -> Checked {
+        assert!(i < 42);
+        Checked(i)
+    }
+}
+
+struct Unfinished;
+
+impl From<u32> for Unfinished {
+    fn from(_: u32) -> Unfinished {
+        todo!()
+    }
+}
+
 fn main() {}
@@ -0,0 +1,44 @@
+// compile-flags: --cfg feature="serde" --cfg feature="json"
+#![allow(unused)]
+#![warn(clippy::feature_gated_pub_api_without_doc_cfg)]
+
+#[cfg(feature = "serde")]
+pub struct Config;
+
+#[cfg(all(feature = "serde", feature = "json"))]
+pub trait ToJson {}
+
+pub fn load() -> Config {
+    Config
+}
+
+pub struct Settings {
+    pub config: Config,
+    private: Config,
+}
+
+pub struct Plain;
+
+impl ToJson for Plain {}
+
+#[cfg(feature = "serde")]
+impl ToJson for Config {}
+
+#[cfg(feature = "serde")]
+pub mod serde_support {
+    // ok, gated through the parent module
+    pub fn load() -> super::Config {
+        super::Config
+    }
+}
+
+// ok, gated on the same feature
+#[cfg(feature = "serde")]
+pub fn save(_: Config) {}
+
+// ok, not public
+fn load_private() -> Config {
+    Config
+}
+
+fn main() {}
@@ -0,0 +1,60 @@
+#![warn(clippy::float_accumulation_in_loop)]
+#![allow(unused_assignments)]
+
+fn sums(values: &[f64], small: &[f32], n: usize) {
+    let mut sum = 0.0;
+    for v in values {
+        sum += v;
+    }
+
+    let mut narrow = 0.0_f32;
+    for v in values {
+        narrow += *v as f32;
+    }
+
+    let mut total = 0.0_f32;
+    let mut i = 0;
+    while i < n {
+        total += small[i];
+        i += 1;
+    }
+
+    // Few iterations
+    let mut short = 0.0;
+    for i in 0..10 {
+        short += i as f64;
+    }
+    let mut array = 0.0;
+    for v in [1.0, 2.0, 3.0] {
+        array += v;
+    }
+
+    // Not accumulated across iterations
+    for v in values {
+        let mut local = 0.0;
+        local += v;
+    }
+
+    // Reported once, by the innermost loop
+    let mut nested = 0.0;
+    for _ in values {
+        for v in values {
+            nested += v;
+        }
+    }
+    // The inner loop is too short to be linted on its own
+    let mut outer = 0.0;
+    for v in values {
+        for _ in 0..3 {
+            outer += v;
+        }
+    }
+
+    // Integers
+    let mut count = 0;
+    for _ in values {
+        count += 2;
+    }
+}
+
+fn main() {}
@@ -51,3 +51,13 @@ fn n(_: bool, _: u32, _: bool, _: Box<u32>, _: bool, _: bool) {
         fn nn(_: bool, _: bool, _: bool, _: bool) {}
     }
 }
+
+/// Some documentation
+fn named(is_round: bool, is_hot: bool, has_lid: bool, _weight: u32, is_full: bool) {}
+
+struct Cup;
+impl Cup {
+    fn fill(&mut self, is_hot: bool, has_sugar: bool, has_milk: bool, to_go: bool) {}
+}
+
+fn partly_named(is_round: bool, is_hot: bool, has_lid: bool, _: bool) {}
@@ -0,0 +1,43 @@
+// aux-build:proc_macro_derive.rs
+
+#![allow(dead_code)]
+#![warn(clippy::copy_iterator)]
+
+#[macro_use]
+extern crate proc_macro_derive;
+
+#[derive(Copy, Clone)]
+struct Countdown(u8);
+
+// the `style` and `pedantic` lints aren't emitted in `#[automatically_derived]` items
+#[automatically_derived]
+impl Iterator for Countdown {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        self.0.checked_sub(1).map(|c| {
+            self.0 = c;
+            c
+        })
+    }
+}
+
+// nor in code expanded from derive macros of other crates
+#[derive(CopyIterator)]
+struct Derived;
+
+#[derive(Copy, Clone)]
+struct Countup(u8);
+
+impl Iterator for Countup {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        self.0.checked_add(1).map(|c| {
+            self.0 = c;
+            c
+        })
+    }
+}
+
+fn main() {}
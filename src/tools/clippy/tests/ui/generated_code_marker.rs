@@ -0,0 +1,19 @@
+// @generated by a code generator, do not edit
+#![allow(unused)]
+#![warn(clippy::needless_return, clippy::redundant_field_names)]
+
+struct Point {
+    x: u8,
+}
+
+// style lints aren't emitted in generated code
+fn style(x: u8) -> Point {
+    return Point { x: x };
+}
+
+// other lints still are
+fn correctness(x: u8) -> bool {
+    x != x
+}
+
+fn main() {}
@@ -47,3 +47,16 @@ fn main() {
     let nested = [[1]];
     let _ = nested[0].get(nested[0].len() - 1);
 }
+
+fn pure_receiver(v: Vec<u32>, s: &S) {
+    let _ = v.as_slice().get(v.as_slice().len() - 1);
+
+    // `field` isn't known to be free of side effects
+    let _ = s.field().get(s.field().len() - 1);
+}
+
+impl S {
+    fn field(&self) -> &[usize] {
+        &self.field
+    }
+}
@@ -0,0 +1,115 @@
+#![warn(clippy::hash_uses_field_ignored_in_eq)]
+
+use std::hash::{Hash, Hasher};
+
+struct User {
+    id: u64,
+    name: String,
+    visits: u32,
+}
+
+impl PartialEq for User {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id && self.visits == other.visits
+    }
+}
+
+impl Hash for User {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+        self.name.hash(state);
+    }
+}
+
+struct Pair(u8, u8);
+
+impl PartialEq for Pair {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Hash for Pair {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.0, self.1).hash(state);
+    }
+}
+
+// Comparing fields which aren't hashed is fine
+struct Point {
+    x: i32,
+    y: i32,
+    label: &'static str,
+}
+
+impl PartialEq for Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y && self.label == other.label
+    }
+}
+
+impl Hash for Point {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.x.hash(state);
+    }
+}
+
+// `eq` doesn't only access fields of `self`, so we can't tell what it compares
+struct Name {
+    raw: String,
+    len: usize,
+}
+
+impl Name {
+    fn normalized(&self) -> String {
+        self.raw.to_lowercase()
+    }
+}
+
+impl PartialEq for Name {
+    fn eq(&self, other: &Self) -> bool {
+        self.normalized() == other.normalized()
+    }
+}
+
+impl Hash for Name {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+        self.len.hash(state);
+    }
+}
+
+// The derived `PartialEq` compares all fields
+#[derive(PartialEq)]
+struct Derived {
+    a: u8,
+    b: u8,
+}
+
+impl Hash for Derived {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.a.hash(state);
+        self.b.hash(state);
+    }
+}
+
+struct Allowed {
+    a: u8,
+    b: u8,
+}
+
+impl PartialEq for Allowed {
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a
+    }
+}
+
+impl Hash for Allowed {
+    #[allow(clippy::hash_uses_field_ignored_in_eq)]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.a.hash(state);
+        self.b.hash(state);
+    }
+}
+
+fn main() {}
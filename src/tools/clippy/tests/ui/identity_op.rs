@@ -121,3 +121,11 @@ fn f(_: i32) {
 pub fn decide(a: bool, b: bool) -> u32 {
     0 + if a { 1 } else { 2 } + if b { 3 } else { 5 }
 }
+
+pub fn folded_consts(x: u32) {
+    x + 0u8 as u32;
+    x * 2u32.pow(0);
+    x + (0, 1).0;
+    x * [0, 1][1];
+    x + i32::abs(0) as u32;
+}
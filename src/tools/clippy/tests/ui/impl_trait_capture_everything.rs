@@ -0,0 +1,41 @@
+#![warn(clippy::impl_trait_capture_everything)]
+#![allow(clippy::needless_lifetimes)]
+
+use std::fmt::Display;
+
+fn owned(name: &str) -> impl Display + '_ {
+    name.to_uppercase()
+}
+
+fn first<'a: 'b, 'b>(x: &'a [u8], _y: &'b [u8]) -> impl Iterator<Item = &'a u8> + 'a + 'b {
+    x.iter()
+}
+
+fn leading<'a>(x: &'a str) -> impl 'a + Display {
+    x.len()
+}
+
+// the returned value borrows from the argument
+fn borrowed(name: &str) -> impl Display + '_ {
+    name
+}
+
+fn closure<'a>(x: &'a [u8]) -> impl Fn() -> usize + 'a {
+    move || x.len()
+}
+
+// the lifetime is needed by the trait bound
+fn in_trait_bound<'a>(_: &'a u8) -> impl PartialEq<&'a u8> + 'a {
+    &0u8
+}
+
+async fn asynchronous(name: &str) -> usize {
+    name.len()
+}
+
+#[allow(clippy::impl_trait_capture_everything)]
+fn allowed(name: &str) -> impl Display + '_ {
+    name.len()
+}
+
+fn main() {}
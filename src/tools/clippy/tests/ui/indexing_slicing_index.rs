@@ -45,4 +45,12 @@ fn main() {
     x[M]; // Ok, should not produce stderr.
     v[N];
     v[M];
+
+    x[index % 4]; // Ok, the index is always in bounds.
+    x[index % x.len()]; // Ok, the index is always in bounds.
+    y[index % y.len()]; // Ok, the index is always in bounds.
+    x[index & 3]; // Ok, the index is always in bounds.
+    x[index % 5];
+    x[index & 4];
+    v[index % v.len()]; // Panics if `v` is empty.
 }
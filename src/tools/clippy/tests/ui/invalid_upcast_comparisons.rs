@@ -4,7 +4,8 @@
     clippy::eq_op,
     clippy::no_effect,
     clippy::unnecessary_operation,
-    clippy::cast_lossless
+    clippy::cast_lossless,
+    clippy::absurd_extreme_comparisons
 )]
 
 fn mk_value<T>() -> T {
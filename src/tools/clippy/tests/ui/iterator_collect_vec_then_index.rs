@@ -0,0 +1,28 @@
+// run-rustfix
+#![warn(clippy::iterator_collect_vec_then_index)]
+#![allow(unused, clippy::iter_cloned_collect)]
+
+use std::collections::VecDeque;
+
+fn main() {
+    let v = [1u32, 2, 3];
+
+    let _ = v.iter().map(|x| x * 2).collect::<Vec<_>>()[1];
+    let _ = v.iter().map(|x| x * 2).collect::<Vec<u32>>().get(2).copied();
+    let _ = v.iter().map(|x| x * 2).collect::<Vec<_>>().first().copied();
+    let _ = v.iter().map(|x| x * 2).collect::<Vec<_>>().last().copied();
+
+    // `last` still runs the side effects of every element
+    let _ = v.iter().inspect(|x| println!("{x}")).collect::<Vec<_>>().last().copied();
+
+    // The vector is used more than once
+    let doubled = v.iter().map(|x| x * 2).collect::<Vec<_>>();
+    let _ = doubled[1];
+    let _ = doubled.len();
+
+    // Not a `Vec`
+    let _ = v.iter().copied().collect::<VecDeque<_>>()[1];
+
+    // More than one element is used
+    let _ = &v.iter().copied().collect::<Vec<_>>()[1..];
+}
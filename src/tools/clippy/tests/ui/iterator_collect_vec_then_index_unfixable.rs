@@ -0,0 +1,11 @@
+#![warn(clippy::iterator_collect_vec_then_index)]
+
+fn main() {
+    let v = [1u32, 2, 3];
+
+    // The element is returned by reference, or side effects would be skipped
+    let _ = v.iter().map(|x| x * 2).collect::<Vec<u32>>().get(2);
+    let _ = &v.iter().map(|x| x * 2).collect::<Vec<_>>()[1];
+    let _ = v.iter().inspect(|x| println!("{x}")).collect::<Vec<_>>()[1];
+    let _ = v.iter().inspect(|x| println!("{x}")).collect::<Vec<_>>().first().copied();
+}
@@ -20,9 +20,40 @@ fn main() {
     // check const eval
     let v1 = vec![1, 2, 3];
     let _ = v1.iter().step_by(2 / 3);
+
+    // check consts and enum discriminants
+    const ZERO: usize = 0;
+    let _ = (0..1).step_by(ZERO);
+    let _ = (0..1).step_by(usize::MIN);
+    let _ = (0..1).step_by(Stride::Zero as usize);
+    const STEPS: (usize, [usize; 2]) = (1, [0, 1]);
+    let _ = (0..1).step_by(STEPS.1[0]);
+    let _ = (0..1).step_by(NotIterator::STEPS[0]);
+
+    // No error, the discriminant isn't zero
+    let _ = (0..1).step_by(Stride::One as usize);
+
+    // No error, the impl overrides the default of the trait constant
+    let _ = (0..1).step_by(<Overridden as Steps>::STEPS[0]);
+}
+
+enum Stride {
+    Zero,
+    One,
 }
 
 struct NotIterator;
 impl NotIterator {
+    const STEPS: [usize; 2] = [0, 1];
+
     fn step_by(&self, _: u32) {}
 }
+
+trait Steps {
+    const STEPS: [usize; 2] = [0, 1];
+}
+
+struct Overridden;
+impl Steps for Overridden {
+    const STEPS: [usize; 2] = [1, 2];
+}
@@ -0,0 +1,43 @@
+#![warn(clippy::iterator_step_by_zero_runtime)]
+
+fn every_nth(values: &[u32], n: usize) -> Vec<u32> {
+    values.iter().copied().step_by(n).collect()
+}
+
+fn in_closure(values: &[u32]) -> Vec<u32> {
+    let f = |step| (0..values.len()).step_by(step).count();
+    (0..3).map(f).map(|n| n as u32).collect()
+}
+
+// No error, the parameter is checked
+fn asserted(values: &[u32], n: usize) -> Vec<u32> {
+    assert!(n > 0);
+    values.iter().copied().step_by(n).collect()
+}
+
+fn asserted_ne(values: &[u32], n: usize) -> Vec<u32> {
+    assert_ne!(n, 0);
+    values.iter().copied().step_by(n).collect()
+}
+
+fn early_return(values: &[u32], n: usize) -> Vec<u32> {
+    if n == 0 {
+        return Vec::new();
+    }
+    values.iter().copied().step_by(n).collect()
+}
+
+fn matched(values: &[u32], n: usize) -> Vec<u32> {
+    match n {
+        0 => Vec::new(),
+        n => values.iter().copied().step_by(n).collect(),
+    }
+}
+
+// No error, the step isn't a parameter
+fn not_a_param(values: &[u32]) -> Vec<u32> {
+    let n = values.len() / 2 + 1;
+    values.iter().copied().step_by(n).collect()
+}
+
+fn main() {}
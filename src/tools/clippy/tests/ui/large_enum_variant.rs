@@ -154,6 +154,11 @@ enum LargeEnumOfConst {
     Error(PossiblyLargeEnumWithConst<256>),
 }
 
+enum LargeEnum10 {
+    A(i32),
+    B(u8, [i32; 8000]),
+}
+
 fn main() {
     large_enum_variant!();
 }
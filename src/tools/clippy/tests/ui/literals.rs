@@ -2,8 +2,7 @@
 
 #![warn(clippy::mixed_case_hex_literals)]
 #![warn(clippy::zero_prefixed_literal)]
-#![warn(clippy::unseparated_literal_suffix)]
-#![warn(clippy::separated_literal_suffix)]
+#![warn(clippy::literal_suffix_style)]
 #![allow(dead_code, overflowing_literals)]
 
 fn main() {
@@ -0,0 +1,15 @@
+#![allow(unused)]
+#![warn(clippy::main_without_result)]
+
+use std::fs::File;
+use std::io::Read;
+
+fn main() {
+    let mut file = File::open("Cargo.toml").unwrap();
+    let mut contents = String::new();
+    file.read_to_string(&mut contents).expect("reading failed");
+}
+
+fn not_main() {
+    let _ = File::open("Cargo.toml").unwrap();
+}
@@ -0,0 +1,31 @@
+#![warn(clippy::manual_fs_rename)]
+#![allow(clippy::needless_borrow)]
+
+use std::fs;
+use std::path::Path;
+
+fn with_try(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::copy(from, to)?;
+    fs::remove_file(from)?;
+    Ok(())
+}
+
+fn with_unwrap() {
+    let from = Path::new("a.txt");
+    fs::copy(from, "b.txt").unwrap();
+    fs::remove_file(&from).expect("failed to remove");
+}
+
+fn no_lint(from: &Path, to: &Path) -> std::io::Result<()> {
+    // Removing another file
+    fs::copy(from, to)?;
+    fs::remove_file(to)?;
+
+    // Not directly after the copy
+    fs::copy(from, to)?;
+    println!("copied");
+    fs::remove_file(from)?;
+    Ok(())
+}
+
+fn main() {}
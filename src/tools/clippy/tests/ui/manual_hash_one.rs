@@ -0,0 +1,67 @@
+// run-rustfix
+
+#![feature(build_hasher_simple_hash_one)]
+#![warn(clippy::manual_hash_one)]
+#![allow(dead_code, clippy::needless_borrow)]
+
+use std::hash::{BuildHasher, Hash, Hasher};
+
+fn returned(b: impl BuildHasher) -> u64 {
+    let mut hasher = b.build_hasher();
+    true.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn reference_value(b: impl BuildHasher, s: &str) {
+    let mut hasher = b.build_hasher();
+    s.hash(&mut hasher);
+    let _ = hasher.finish();
+}
+
+fn owned_value(b: impl BuildHasher, v: Vec<u32>) {
+    let mut hasher = b.build_hasher();
+    v.hash(&mut hasher);
+    let hash = hasher.finish();
+    println!("{hash}");
+}
+
+fn no_lint(b: impl BuildHasher) {
+    // The hasher is used more than once
+    let mut hasher = b.build_hasher();
+    true.hash(&mut hasher);
+    false.hash(&mut hasher);
+    let _ = hasher.finish();
+
+    let mut hasher = b.build_hasher();
+    true.hash(&mut hasher);
+    let _ = hasher.finish();
+    let _ = hasher.finish();
+
+    let mut hasher = b.build_hasher();
+    true.hash(&mut hasher);
+    let _ = hasher.finish();
+    let _ = || hasher.write_u8(1);
+}
+
+fn unsized_receiver(b: &dyn BuildHasher<Hasher = std::collections::hash_map::DefaultHasher>) -> u64 {
+    // `hash_one` requires `Self: Sized`
+    let mut hasher = (*b).build_hasher();
+    true.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[clippy::msrv = "1.70"]
+fn msrv_1_70(b: impl BuildHasher) -> u64 {
+    let mut hasher = b.build_hasher();
+    true.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[clippy::msrv = "1.71"]
+fn msrv_1_71(b: impl BuildHasher) -> u64 {
+    let mut hasher = b.build_hasher();
+    true.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn main() {}
@@ -259,4 +259,8 @@ macro_rules! macro_call {
         };
         1
     };
+
+    // The bindings of the `let` statement are used in the suggestion
+    let mut v = if let Some(v_some) = g() { v_some } else { return };
+    let (a, b) = if let Some((ref x, y)) = Some((0, 1)) { (x, y) } else { return };
 }
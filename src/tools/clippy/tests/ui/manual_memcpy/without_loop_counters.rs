@@ -1,4 +1,5 @@
 #![warn(clippy::needless_range_loop, clippy::manual_memcpy)]
+#![allow(clippy::clone_into_uninitialized_reuse)]
 
 const LOOP_OFFSET: usize = 5000;
 
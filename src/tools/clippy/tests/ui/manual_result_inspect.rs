@@ -0,0 +1,44 @@
+// run-rustfix
+#![feature(result_option_inspect)]
+#![warn(clippy::manual_result_inspect)]
+#![allow(clippy::needless_borrow, clippy::redundant_clone)]
+
+fn log(_: &u32) {}
+
+fn main() {
+    let res: Result<u32, String> = Ok(1);
+
+    let _ = res.clone().map(|x| {
+        log(&x);
+        x
+    });
+    let _ = res.clone().map_err(|e| {
+        eprintln!("error: {e}");
+        e
+    });
+    let prefix = String::from("error");
+    let _ = res.clone().map_err(move |e| {
+        eprintln!("{prefix}: {e}");
+        e
+    });
+
+    // Don't lint
+    let _ = res.clone().map(|x| x + 1);
+    let _ = res.clone().map(|x| {
+        log(&x);
+        x + 1
+    });
+    let _ = res.clone().map(|mut x| {
+        x += 1;
+        x
+    });
+    let _ = res.clone().map(|x| {
+        log(&x);
+        log(&x);
+        x
+    });
+    let _ = Some(1).map(|x| {
+        log(&x);
+        x
+    });
+}
@@ -0,0 +1,100 @@
+#![warn(clippy::manual_retain_with_index_loop)]
+#![allow(clippy::ptr_arg)]
+
+use std::collections::VecDeque;
+
+fn is_bad(s: &str) -> bool {
+    s.starts_with('x')
+}
+
+fn remove_even(v: &mut Vec<i32>) {
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] % 2 == 0 {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+fn remove_empty(v: &mut Vec<String>) {
+    let mut i = 0;
+    while i < v.len() {
+        if !v[i].is_empty() {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+fn remove_bad(v: &mut Vec<String>) {
+    let mut i = 0;
+    while i < v.len() {
+        if is_bad(&v[i]) {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+fn remove_large(mut q: VecDeque<u32>) -> VecDeque<u32> {
+    let mut i = 0;
+    while i < q.len() {
+        if q[i] > 10 {
+            q.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    q
+}
+
+// ok
+fn index_used_later(v: &mut Vec<i32>) -> usize {
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] == 0 {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+    i
+}
+
+fn index_in_condition(v: &mut Vec<i32>) {
+    let mut i = 0;
+    while i < v.len() {
+        if i % 2 == 0 {
+            v.remove(i);
+        } else {
+            i += 1;
+        }
+    }
+}
+
+fn skip_two(v: &mut Vec<i32>) {
+    let mut i = 0;
+    while i < v.len() {
+        if v[i] == 0 {
+            v.remove(i);
+        } else {
+            i += 2;
+        }
+    }
+}
+
+fn main() {
+    let mut v = vec![1, 2, 3];
+    remove_even(&mut v);
+    index_used_later(&mut v);
+    index_in_condition(&mut v);
+    skip_two(&mut v);
+    let mut s = vec![String::new()];
+    remove_empty(&mut s);
+    remove_bad(&mut s);
+    remove_large(VecDeque::new());
+}
@@ -5,7 +5,8 @@
     unreachable_patterns,
     dead_code,
     clippy::equatable_if_let,
-    clippy::needless_borrowed_reference
+    clippy::needless_borrowed_reference,
+    clippy::redundant_pattern_matching_in_matches_macro
 )]
 
 fn main() {
@@ -215,7 +216,7 @@ enum E {
     }
 
     let x = ' ';
-    // ignore if match block contains comment
+    // ignore if the comments in the match block can't be kept
     let _line_comments = match x {
         // numbers are bad!
         '1' | '2' | '3' => true,
@@ -255,3 +256,11 @@ fn msrv_1_42() {
         _ => false,
     };
 }
+
+fn comments_in_front_of_matches(x: char) -> bool {
+    match x {
+        // numbers are bad!
+        '1' | '2' | '3' => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,26 @@
+#![warn(clippy::metadata_existence_check)]
+
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    let path = Path::new("config.toml");
+    if fs::metadata(path).is_ok() {}
+    if path.metadata().is_err() {}
+    let _ = std::fs::metadata("a.txt").is_ok();
+
+    // No lint: the metadata is used
+    if let Ok(metadata) = fs::metadata(path) {
+        let _ = metadata.len();
+    }
+}
+
+#[clippy::msrv = "1.62"]
+fn msrv_1_62(path: &Path) {
+    if fs::metadata(path).is_ok() {}
+}
+
+#[clippy::msrv = "1.63"]
+fn msrv_1_63(path: &Path) {
+    if fs::metadata(path).is_ok() {}
+}
@@ -55,6 +55,10 @@ fn vxworks() {}
 #[cfg(all(not(any(solaris, linux)), freebsd))]
 fn list() {}
 
+// condition of `cfg_attr`
+#[cfg_attr(linux, inline)]
+fn cfg_attr() {}
+
 // correct use, should be ignored
 #[cfg(target_os = "freebsd")]
 fn correct() {}
@@ -0,0 +1,40 @@
+#![warn(clippy::mismatched_target_os)]
+#![allow(unused)]
+
+#[cfg(wasm)]
+fn wasm() {}
+
+#[cfg(all(target_os = "linux", target_os = "windows"))]
+fn linux_and_windows() {}
+
+#[cfg(any(unix, all(target_env = "gnu", target_arch = "x86_64", target_env = "musl")))]
+fn nested() {}
+
+#[cfg(target_os = "linx")]
+fn typo_os() {}
+
+#[cfg(target_family = "wasn")]
+fn typo_family() {}
+
+// the predicates which parse are still checked
+#[cfg(any(linux, foo(bar)))]
+fn malformed() {}
+
+// ok
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+fn linux_or_windows() {}
+
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+fn linux_gnu() {}
+
+#[cfg(all(unix, target_family = "wasm"))]
+fn unix_wasm() {}
+
+#[cfg(target_os = "a_custom_os_nothing_like_any_other")]
+fn custom() {}
+
+// could be a custom `--cfg`
+#[cfg(musl)]
+fn musl() {}
+
+fn main() {}
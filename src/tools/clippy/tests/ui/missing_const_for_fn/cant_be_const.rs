@@ -122,6 +122,38 @@ fn const_fn_stabilized_after_msrv(byte: u8) {
     }
 }
 
+mod const_features_stabilized_after_msrv {
+    union U {
+        a: u32,
+        b: f32,
+    }
+
+    // Do not lint this because union field accesses are stable in const fn since 1.56.
+    #[clippy::msrv = "1.55"]
+    fn union_field(u: U) -> u32 {
+        unsafe { u.a }
+    }
+
+    // Do not lint this because function pointers are stable in const fn since 1.61.
+    #[clippy::msrv = "1.60"]
+    fn fn_pointer(f: fn()) -> fn() {
+        f
+    }
+
+    // Do not lint this because `transmute` is stable in const fn since 1.56.
+    #[clippy::msrv = "1.55"]
+    #[allow(clippy::transmute_int_to_float)]
+    fn transmute_float(x: u32) -> f32 {
+        unsafe { std::mem::transmute(x) }
+    }
+}
+
+// Do not lint this because calling trait methods in const fn is unstable, even if the impl is
+// `const` on nightly.
+fn calls_trait_method() -> u32 {
+    <u32 as Default>::default()
+}
+
 with_span! {
     span
     fn dont_check_in_proc_macro() {}
@@ -85,5 +85,22 @@ fn msrv_1_46() -> i32 {
     46
 }
 
+mod const_features_stabilized_before_msrv {
+    union U {
+        a: u32,
+        b: f32,
+    }
+
+    #[clippy::msrv = "1.56"]
+    fn union_field(u: U) -> u32 {
+        unsafe { u.a }
+    }
+
+    #[clippy::msrv = "1.61"]
+    fn fn_pointer(f: fn()) -> fn() {
+        f
+    }
+}
+
 // Should not be const
 fn main() {}
@@ -0,0 +1,70 @@
+#![warn(clippy::missing_debug_for_public_error_types)]
+
+use std::fmt;
+
+pub struct ParseError;
+
+pub fn parse(input: &str) -> Result<u32, ParseError> {
+    input.parse().map_err(|_| ParseError)
+}
+
+#[derive(Debug)]
+pub enum ReadError {
+    Closed,
+}
+
+pub struct Reader;
+
+impl Reader {
+    pub fn read(&self) -> Result<u8, ReadError> {
+        Err(ReadError::Closed)
+    }
+}
+
+pub struct FetchError;
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("fetch failed")
+    }
+}
+
+pub trait Source {
+    fn fetch(&self) -> Result<String, FetchError>;
+}
+
+#[derive(Debug)]
+pub struct Complete;
+
+impl fmt::Display for Complete {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("complete")
+    }
+}
+
+pub fn complete() -> Result<(), Complete> {
+    Ok(())
+}
+
+struct Private;
+
+fn private() -> Result<(), Private> {
+    Err(Private)
+}
+
+mod inner {
+    pub struct Hidden;
+
+    pub fn hidden() -> Result<(), Hidden> {
+        Err(Hidden)
+    }
+}
+
+#[allow(clippy::missing_debug_for_public_error_types)]
+pub struct Allowed;
+
+pub fn allowed() -> Result<(), Allowed> {
+    Err(Allowed)
+}
+
+fn main() {}
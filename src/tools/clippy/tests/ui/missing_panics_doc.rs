@@ -151,3 +151,26 @@ pub fn debug_assertions() {
     debug_assert_eq!(1, 2);
     debug_assert_ne!(1, 2);
 }
+
+/// This is okay because `debug_assert!` only panics in debug builds
+pub fn debug_assert_unwrap(opt: Option<u32>) {
+    debug_assert!(opt.unwrap() > 0);
+}
+
+fn unwrap_helper(opt: Option<u32>) -> u32 {
+    opt.unwrap()
+}
+
+fn calls_unwrap_helper(opt: Option<u32>) -> u32 {
+    unwrap_helper(opt)
+}
+
+/// This needs to be documented
+pub fn calls_panicking_function(opt: Option<u32>) -> u32 {
+    unwrap_helper(opt) + 1
+}
+
+/// This is okay because only one level of calls is checked
+pub fn calls_panicking_function_indirectly(opt: Option<u32>) -> u32 {
+    calls_unwrap_helper(opt)
+}
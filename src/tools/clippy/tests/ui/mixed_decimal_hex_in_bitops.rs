@@ -0,0 +1,18 @@
+// run-rustfix
+#![warn(clippy::mixed_decimal_hex_in_bitops)]
+
+fn main() {
+    let flags = 0u32;
+    let _ = flags & 16 | 0x40;
+    let _ = flags | 0xFF00 | 255;
+    let _ = flags & 0b1000_0000 | 12;
+    let _ = (flags ^ 0x1_0000) & 65535;
+    let _ = flags & 256_u32 | 0x1;
+
+    // single digits are the same in every radix
+    let _ = flags & 0x40 | 1;
+    // no hexadecimal or binary literals
+    let _ = flags & 16 | 32;
+    // not a bitwise operation
+    let _ = 0x10 + 16;
+}
@@ -0,0 +1,30 @@
+#![warn(clippy::modulo_then_compare_range)]
+#![allow(clippy::absurd_extreme_comparisons, unused_comparisons)]
+
+const N: u32 = 8;
+
+fn main() {
+    let x: u32 = 42;
+    let y: i32 = -42;
+    let n: u32 = 7;
+
+    let _ = x % n == n;
+    let _ = x % n != n;
+    let _ = x % 10 > 10;
+    let _ = x % 10 >= 10;
+    let _ = 10 <= x % 10;
+    let _ = x % N < 8;
+    let _ = y % 10 == -10;
+    let _ = y % -10 > 9;
+
+    // Possible results
+    let _ = x % 10 == 9;
+    let _ = x % 10 < 9;
+    let _ = y % 10 < 0;
+    let _ = x % n < 3;
+    // Linted by `absurd_extreme_comparisons`
+    let _ = x % 10 < 0;
+    // Linted by `modulo_one`
+    #[allow(clippy::modulo_one)]
+    let _ = x % 1 == 1;
+}
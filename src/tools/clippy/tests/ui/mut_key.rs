@@ -83,3 +83,24 @@ fn main() {
     let _map = HashMap::<Rc<Cell<usize>>, usize>::new();
     let _map = HashMap::<Arc<Cell<usize>>, usize>::new();
 }
+
+// Interior mutability is found through the fields of custom types, even behind an `Arc`
+struct Shared(Inner);
+
+struct Inner {
+    id: usize,
+    hits: Arc<AtomicUsize>,
+}
+
+enum Either {
+    Left(usize),
+    Right(Rc<Cell<usize>>),
+}
+
+// Recursive types are ok as long as they don't contain interior mutability
+struct List {
+    val: usize,
+    next: Option<Box<List>>,
+}
+
+fn transitive(_m: &mut HashMap<Shared, usize>, _s: HashSet<Either>, _l: HashSet<List>) {}
@@ -3,15 +3,116 @@
 #![warn(clippy::mutex_atomic)]
 #![allow(clippy::borrow_as_ptr)]
 
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+static FLAG: Mutex<bool> = Mutex::new(false);
+
+fn set_flag() {
+    *FLAG.lock().unwrap() = true;
+}
+
+fn flag() -> bool {
+    *FLAG.lock().unwrap()
+}
+
+struct Stats {
+    hits: Mutex<u64>,
+    total: Mutex<usize>,
+}
+
+impl Stats {
+    fn new() -> Self {
+        Stats {
+            hits: Mutex::new(0),
+            total: Mutex::new(0),
+        }
+    }
+
+    fn hit(&self) {
+        *self.hits.lock().unwrap() += 1;
+    }
+
+    fn hits(&self) -> u64 {
+        *self.hits.lock().unwrap()
+    }
+
+    fn add(&self, n: usize) {
+        // the lock guards more than a single access
+        let mut total = self.total.lock().unwrap();
+        let old = *total;
+        *total = old + n;
+    }
+}
+
 fn main() {
-    use std::sync::Mutex;
-    Mutex::new(true);
-    Mutex::new(5usize);
-    Mutex::new(9isize);
+    let b = Mutex::new(true);
+    *b.lock().unwrap() = false;
+
+    let u = Mutex::new(5usize);
+    {
+        let mut guard = u.lock().unwrap();
+        *guard += 1;
+    }
+
+    let i = Arc::new(Mutex::new(9isize));
+    let i2 = Arc::clone(&i);
+    thread::spawn(move || *i2.lock().unwrap() -= 1);
+    let _ = *i.lock().unwrap();
+
     let mut x = 4u32;
-    Mutex::new(&x as *const u32);
-    Mutex::new(&mut x as *mut u32);
-    Mutex::new(0u32);
-    Mutex::new(0i32);
-    Mutex::new(0f32); // there are no float atomics, so this should not lint
+    let p = Mutex::new(&mut x as *mut u32);
+    let _ = *p.lock().unwrap();
+
+    let n = Mutex::new(0i32);
+    *n.lock().unwrap() |= 1;
+
+    let f = Mutex::new(0f32); // there are no float atomics, so this should not lint
+    *f.lock().unwrap() = 1.0;
+
+    // the lock guards more than a single access
+    let m = Mutex::new(0u32);
+    {
+        let mut guard = m.lock().unwrap();
+        *guard += 1;
+        println!("{}", *guard);
+    }
+
+    // the guard is held across other work
+    let w = Mutex::new(false);
+    {
+        let mut guard = w.lock().unwrap();
+        println!("working");
+        *guard = true;
+    }
+
+    // the temporary guard lives for the whole `match`
+    let s = Mutex::new(0u8);
+    match *s.lock().unwrap() {
+        0 => println!("zero"),
+        _ => println!("other"),
+    }
+
+    // the temporary guard lives for the whole `if let`
+    let l = Mutex::new(0u16);
+    if let 0 = *l.lock().unwrap() {
+        println!("zero");
+    }
+
+    // the guard is dropped before the body of the `if`
+    let c = Mutex::new(0i64);
+    if *c.lock().unwrap() == 0 {
+        println!("zero");
+    }
+
+    // locked somewhere else
+    let e = Mutex::new(true);
+    lock_elsewhere(&e);
+
+    // never locked
+    let _unused = Mutex::new(1usize);
+}
+
+fn lock_elsewhere(m: &Mutex<bool>) {
+    let _ = *m.lock().unwrap();
 }
@@ -25,7 +25,6 @@ fn main() {
         println!("true")
     }
 
-    // BELOW: lints we hope to catch as `Expr::can_have_side_effects` improves.
     if y & !const_returns_bool() {
         println!("true") // This is a const function, in an UnOp
     }
@@ -37,4 +36,9 @@ fn main() {
     if y & (0 < 1) {
         println!("true") // This is a BinOp with no side effects
     }
+
+    let s = String::from("abc");
+    if y & s[1..].is_empty() {
+        println!("true") // Indexing a `String` has no side effects
+    }
 }
@@ -0,0 +1,15 @@
+#![warn(clippy::needless_bool)]
+#![allow(dead_code)]
+
+// The suggestion would remove the `cfg`'d out statement, so it is not machine applicable
+fn not_applied(x: bool) -> bool {
+    if x {
+        #[cfg(any())]
+        println!("never printed");
+        true
+    } else {
+        false
+    }
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+// run-rustfix
+#![warn(clippy::needless_string_allocation_in_comparison)]
+#![allow(clippy::cmp_owned, clippy::useless_format)]
+
+fn main() {
+    let name = "foo";
+    let owned = String::from("foo");
+
+    let _ = format!("{name}") == owned;
+    let _ = owned == format!("{}", name);
+    let _ = format!("{owned}") != name;
+    let _ = format!("bar") == owned;
+    let _ = name == format!("b{{a}}r");
+
+    // Don't lint
+    let n = 1;
+    let _ = format!("{n}") == owned;
+    let _ = format!("{name:?}") == owned;
+    let _ = format!("{name}!") == owned;
+    let _ = format!("{name}{name}") == owned;
+    let _ = format!("{name}") < owned;
+}
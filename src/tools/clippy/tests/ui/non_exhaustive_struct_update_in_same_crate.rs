@@ -0,0 +1,61 @@
+#![warn(clippy::non_exhaustive_struct_update_in_same_crate)]
+#![allow(clippy::needless_update)]
+
+#[derive(Default)]
+#[non_exhaustive]
+pub struct Options {
+    pub verbose: bool,
+    pub jobs: usize,
+}
+
+pub enum Event {
+    #[non_exhaustive]
+    Click { x: i32, y: i32 },
+    Close,
+}
+
+#[non_exhaustive]
+pub(crate) struct Internal {
+    pub value: u8,
+}
+
+#[non_exhaustive]
+enum Private {
+    A,
+    #[non_exhaustive]
+    B,
+}
+
+mod inner {
+    #[non_exhaustive]
+    pub struct NotReexported;
+}
+
+#[derive(Default)]
+pub struct Exhaustive {
+    pub verbose: bool,
+}
+
+pub fn options() -> Options {
+    Options {
+        verbose: true,
+        ..Default::default()
+    }
+}
+
+pub fn exhaustive() -> Exhaustive {
+    Exhaustive {
+        verbose: true,
+        ..Default::default()
+    }
+}
+
+#[allow(clippy::non_exhaustive_struct_update_in_same_crate)]
+fn allowed() -> Options {
+    Options {
+        jobs: 1,
+        ..Default::default()
+    }
+}
+
+fn main() {}
@@ -0,0 +1,42 @@
+#![warn(clippy::non_portable_path_literal)]
+#![allow(dead_code)]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+// Backslashes in paths are tested through constants, so they don't show up in the output
+const APP_CONFIG: &str = "config\\app.toml";
+const IMAGES: &str = "cache\\images";
+
+fn takes_path(_: &Path) {}
+
+fn main() {
+    let _ = fs::read(APP_CONFIG);
+    let _ = fs::File::open("C:/Users/app/config.toml");
+    let _ = Path::new("/tmp/app.log");
+    let _ = PathBuf::from("/home/user/.config");
+    let _: PathBuf = "/etc/app.conf".into();
+    let _ = Path::new("data").join(IMAGES);
+    takes_path(Path::new("/var/log"));
+    Command::new("ls").current_dir("/usr/share");
+
+    // Portable paths
+    let _ = fs::read("config/app.toml");
+    let _ = Path::new("/tmpfiles");
+    let _ = Path::new("data").join("images");
+    // Not used as paths
+    let _ = "C:\\Users".len();
+    println!("/tmp/app.log");
+    let _ = String::from("/home/user");
+}
+
+#[cfg(windows)]
+fn windows_only() {
+    let _ = fs::read("C:\\Windows\\win.ini");
+}
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+fn unix_only() {
+    let _ = fs::read("/etc/hosts");
+}
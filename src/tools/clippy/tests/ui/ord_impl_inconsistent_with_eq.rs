@@ -0,0 +1,131 @@
+#![warn(clippy::ord_impl_inconsistent_with_eq)]
+#![allow(clippy::derive_ord_xor_partial_ord)]
+
+use std::cmp::Ordering;
+
+#[derive(PartialEq, Eq)]
+struct Version {
+    major: u32,
+    minor: u32,
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.major.cmp(&other.major)
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+struct Task {
+    id: u32,
+    priority: u8,
+}
+
+impl PartialEq for Task {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Task {}
+
+impl Ord for Task {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority).then(self.id.cmp(&other.id))
+    }
+}
+
+impl PartialOrd for Task {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd)]
+struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Ord for Date {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let day = self.day.cmp(&other.day);
+        day.then(self.month.cmp(&other.month)).then(self.year.cmp(&other.year))
+    }
+}
+
+// Consistent with the derived implementations
+#[derive(PartialEq, Eq, PartialOrd)]
+struct Time {
+    hour: u8,
+    minute: u8,
+}
+
+impl Ord for Time {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.hour.cmp(&other.hour).then(self.minute.cmp(&other.minute))
+    }
+}
+
+// `partial_cmp` uses `self` other than to access a field, so we can't tell what it compares
+struct Score(u32, &'static str);
+
+impl PartialEq for Score {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl PartialOrd for Score {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.key().partial_cmp(&other.key())
+    }
+}
+
+impl Score {
+    fn key(&self) -> (u32, &'static str) {
+        (self.0, self.1)
+    }
+}
+
+#[derive(PartialEq, Eq)]
+struct Point {
+    x: i32,
+    y: i32,
+    z: i32,
+}
+
+impl Ord for Point {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.x.cmp(&other.x)
+    }
+}
+
+impl PartialOrd for Point {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Consistent with the derived implementations, the comparisons are combined in declaration order
+#[derive(PartialEq, Eq, PartialOrd)]
+struct Release {
+    major: u32,
+    minor: u32,
+}
+
+impl Ord for Release {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let minor = self.minor.cmp(&other.minor);
+        let major = self.major.cmp(&other.major);
+        major.then(minor)
+    }
+}
+
+fn main() {}
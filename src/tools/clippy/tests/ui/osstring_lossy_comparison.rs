@@ -0,0 +1,25 @@
+// run-rustfix
+#![allow(unused)]
+#![warn(clippy::osstring_lossy_comparison)]
+
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let path = Path::new("Cargo.toml");
+    let path_buf = PathBuf::from("src/lib.rs");
+    let os_str = OsStr::new("foo");
+
+    let _ = path.to_string_lossy() == "Cargo.toml";
+    let _ = "src/lib.rs" != path_buf.to_string_lossy();
+    let _ = os_str.to_string_lossy() == "foo";
+    let _ = path.display().to_string() == "Cargo.toml";
+    let _ = format!("{}", path.display()) == "Cargo.toml";
+    let _ = path_buf.to_string_lossy().ends_with("lib.rs");
+
+    // no warning
+    let _ = path.as_os_str() == "Cargo.toml";
+    let _ = path.to_string_lossy().contains("toml");
+    let _ = format!("{}/", path.display()) == "Cargo.toml/";
+    let _ = os_str.to_string_lossy().ends_with("oo");
+}
@@ -0,0 +1,13 @@
+#![warn(clippy::osstring_lossy_comparison)]
+
+use std::path::Path;
+
+fn main() {
+    let path = Path::new("Cargo.toml");
+
+    match path.to_string_lossy().as_ref() {
+        "Cargo.toml" => println!("manifest"),
+        "Cargo.lock" => println!("lockfile"),
+        _ => {},
+    }
+}
@@ -0,0 +1,19 @@
+// run-rustfix
+
+#![warn(clippy::parse_then_unwrap_radix_suggestion)]
+
+fn main() {
+    let _ = "1.5".parse::<f64>().unwrap();
+    let _: f32 = "2".parse().unwrap();
+    let _ = "1e-3".parse::<f64>().expect("valid float");
+
+    // No lint: not float literals
+    let _ = "inf".parse::<f64>().unwrap();
+    let _ = "-1.5".parse::<f64>().unwrap();
+    let _ = "1.".parse::<f64>().unwrap();
+    let _ = "1".parse::<u32>().unwrap();
+
+    // No lint: out of range
+    let _ = "1e400".parse::<f64>().unwrap();
+    let _: f32 = "1e39".parse().unwrap();
+}
@@ -0,0 +1,16 @@
+#![warn(clippy::parse_then_unwrap_radix_suggestion)]
+#![allow(clippy::from_str_radix_10)]
+
+fn main() {
+    let n: u32 = 42;
+    let _ = u32::from_str_radix(&format!("{:x}", n), 16);
+    let _ = u64::from_str_radix(&format!("{n:X}"), 16);
+    let _ = i8::from_str_radix(&format!("{:o}", n), 8);
+    let _ = u32::from_str_radix(&format!("{:b}", n + 1), 2);
+
+    // No lint: different radix or format
+    let _ = u32::from_str_radix(&format!("{:x}", n), 8);
+    let _ = u32::from_str_radix(&format!("{:#x}", n), 16);
+    let _ = u32::from_str_radix(&format!("{:x}0", n), 16);
+    let _ = u32::from_str_radix(&format!("{}", n), 10);
+}
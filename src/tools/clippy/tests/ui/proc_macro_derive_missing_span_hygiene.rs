@@ -0,0 +1,25 @@
+#![crate_type = "proc-macro"]
+#![warn(clippy::proc_macro_derive_missing_span_hygiene)]
+
+extern crate proc_macro;
+
+use proc_macro::{Ident, Span, TokenStream};
+
+const NAME: &str = "getter";
+
+#[proc_macro_derive(Getter)]
+pub fn derive_getter(input: TokenStream) -> TokenStream {
+    let name = input.to_string();
+    let _ = Ident::new(&format!("get_{name}"), Span::call_site());
+    let tokens: TokenStream = format!("fn get_{name}() {{}}").parse().unwrap();
+
+    // known at compile time
+    let _ = Ident::new("getter", Span::call_site());
+    let _ = Ident::new(NAME, Span::call_site());
+    let _: TokenStream = "fn getter() {}".parse().unwrap();
+
+    // another span, or not unwrapped
+    let _ = Ident::new(&name, Span::mixed_site());
+    let _: Result<TokenStream, _> = name.parse();
+    tokens
+}
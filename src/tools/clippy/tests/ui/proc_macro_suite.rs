@@ -0,0 +1,53 @@
+// aux-build:proc_macro_suite.rs
+
+//! No lint should be emitted in the expansions of the common macros of `proc_macro_suite`. Lints
+//! with false positives in macro expansions should add their cases here.
+
+#![allow(dead_code)]
+
+extern crate proc_macro_suite;
+
+use proc_macro_suite::{async_trait_like, call_site, Builder, ManualClone};
+
+#[derive(ManualClone, Builder)]
+struct Config {
+    name: String,
+    retries: u32,
+    verbose: bool,
+}
+
+#[async_trait_like]
+trait Service {
+    async fn call(&self, request: u32) -> u32;
+
+    async fn ready(&self) -> bool {
+        true
+    }
+}
+
+struct Echo;
+
+#[async_trait_like]
+impl Service for Echo {
+    async fn call(&self, request: u32) -> u32 {
+        request
+    }
+}
+
+fn take(_: Config, _: Config) {}
+
+fn main() {
+    let config = Config::builder()
+        .name(String::from("echo"))
+        .retries(3)
+        .verbose(false)
+        .build()
+        .unwrap();
+    let copy = config.clone();
+    take(config, copy);
+
+    call_site! {
+        let x = 1u32;
+        let _ = x.clone();
+    }
+}
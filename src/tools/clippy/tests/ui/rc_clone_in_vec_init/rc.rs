@@ -67,3 +67,23 @@ fn should_not_warn_vec_from_elem_but_not_rc() {
 fn should_not_warn_vec_macro_but_not_from_elem() {
     let v = vec![Rc::new("x".to_string())];
 }
+
+macro_rules! new_rc {
+    ($e:expr) => {
+        Rc::new($e)
+    };
+}
+
+fn should_warn_rc_from_macro() {
+    let v = vec![new_rc!(1); 2];
+}
+
+macro_rules! rc_vec {
+    ($e:expr, $n:expr) => {
+        vec![Rc::new($e); $n]
+    };
+}
+
+fn should_warn_rc_vec_from_macro() {
+    let v = rc_vec!(1, 2);
+}
@@ -0,0 +1,48 @@
+// run-rustfix
+
+#![warn(clippy::read_line_without_trim)]
+
+use std::io::{self, BufRead};
+
+fn main() {
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    let _: i32 = input.parse().unwrap();
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    let _ = input.parse::<f64>().unwrap();
+
+    let mut input = String::new();
+    io::stdin().lock().read_line(&mut input).unwrap();
+    let _ = input.parse::<bool>().unwrap();
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    if input == "yes" {}
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    if "no" != input {}
+
+    // No lint: trimmed
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    let _: i32 = input.trim().parse().unwrap();
+
+    // No lint: parsing a `String` keeps the newline on purpose
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    let _: String = input.parse().unwrap();
+
+    // No lint: comparing to a string with a newline
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    if input == "yes\n" {}
+
+    // No lint: only the first use is checked
+    let mut input = String::new();
+    io::stdin().read_line(&mut input).unwrap();
+    input.truncate(input.trim_end().len());
+    let _: i32 = input.parse().unwrap();
+}
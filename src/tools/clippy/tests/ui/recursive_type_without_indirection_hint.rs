@@ -0,0 +1,58 @@
+#![warn(clippy::recursive_type_without_indirection_hint)]
+#![allow(dead_code)]
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+struct Node {
+    value: i32,
+    next: Option<Box<Node>>,
+}
+
+enum List {
+    Cons(i32, Box<List>),
+    Nil,
+}
+
+struct Shared {
+    value: i32,
+    next: Option<Rc<RefCell<Shared>>>,
+    prev: Option<Weak<RefCell<Shared>>>,
+}
+
+struct Link<T>(Box<Chain<T>>);
+
+struct Chain<T> {
+    value: T,
+    next: Option<Link<T>>,
+}
+
+// ok
+struct Tree {
+    left: Option<Box<Tree>>,
+    right: Option<Box<Tree>>,
+}
+
+struct NaryTree {
+    children: Vec<NaryTree>,
+}
+
+struct Mixed {
+    next: Option<Box<Mixed>>,
+    children: Vec<Mixed>,
+}
+
+struct WithDrop {
+    next: Option<Box<WithDrop>>,
+}
+
+impl Drop for WithDrop {
+    fn drop(&mut self) {
+        let mut next = self.next.take();
+        while let Some(mut node) = next {
+            next = node.next.take();
+        }
+    }
+}
+
+fn main() {}
@@ -62,6 +62,7 @@ fn main() {
     clone_then_move_cloned();
     hashmap_neg();
     false_negative_5707();
+    reassigned_after_clone();
 }
 
 #[derive(Clone)]
@@ -239,3 +240,11 @@ fn foo(_x: &Alpha, _y: &mut Alpha) {}
     let _z = x.clone(); // pr 7346 can't lint on `x`
     drop(y);
 }
+
+fn reassigned_after_clone() {
+    let mut s = String::from("foo");
+    let t = s.clone();
+    s = String::from("bar");
+    drop(t);
+    println!("{s}");
+}
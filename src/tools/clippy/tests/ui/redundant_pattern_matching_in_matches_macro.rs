@@ -0,0 +1,49 @@
+// run-rustfix
+
+#![warn(clippy::redundant_pattern_matching_in_matches_macro)]
+#![allow(dead_code)]
+
+use std::net::{IpAddr, Ipv4Addr};
+use std::task::Poll;
+
+fn main() {
+    let x = Some(42);
+    let r = Ok::<i32, i32>(42);
+
+    let _ = matches!(x, Some(_));
+    let _ = matches!(x, None);
+    let _ = matches!(r, Ok(_));
+    let _ = matches!(r, Err(_));
+    let _ = matches!(Poll::Ready(42), Poll::Ready(_));
+    let _ = matches!(Poll::<i32>::Pending, Poll::Pending);
+    let _ = matches!(IpAddr::V4(Ipv4Addr::LOCALHOST), IpAddr::V4(_));
+
+    // through references
+    let y = &x;
+    let _ = matches!(y, Some(_));
+    let _ = matches!(&x, None);
+    let _ = matches!(y, &None);
+    let _ = matches!(*y, Some(_));
+
+    // don't lint
+    let _ = matches!(x, Some(42));
+    let _ = matches!(x, Some(_) if true);
+    let _ = matches!(r, Ok(_) | Err(_));
+    let _ = matches!(x, Some(v) if v > 0);
+}
+
+mod custom {
+    // Not std's `matches!`
+    macro_rules! matches {
+        ($e:expr, $p:pat) => {
+            match $e {
+                $p => true,
+                _ => false,
+            }
+        };
+    }
+
+    fn f(x: Option<u32>) {
+        let _ = matches!(x, Some(_));
+    }
+}
@@ -0,0 +1,20 @@
+// run-rustfix
+
+#![allow(unused, clippy::redundant_clone)]
+#![warn(clippy::redundant_utf8_roundtrip)]
+
+fn main() {
+    let s = String::from("foo");
+    let t: &str = "bar";
+
+    let _ = String::from_utf8(s.clone().into_bytes()).unwrap();
+    let _ = std::str::from_utf8(s.as_bytes()).expect("valid UTF-8");
+    let _ = std::str::from_utf8(t.as_bytes()).unwrap();
+    let _ = String::from_utf8_lossy(s.as_bytes());
+    let _ = String::from_utf8_lossy(t.as_bytes());
+
+    // no warning, these are bytes to begin with
+    let v = vec![b'a'];
+    let _ = std::str::from_utf8(&v).unwrap();
+    let _ = String::from_utf8_lossy(&v);
+}
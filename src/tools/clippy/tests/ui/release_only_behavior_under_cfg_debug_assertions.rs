@@ -0,0 +1,48 @@
+#![allow(unused)]
+#![warn(clippy::release_only_behavior_under_cfg_debug_assertions)]
+
+fn validate_all() {}
+fn validate_sample() {}
+
+fn main() {
+    if cfg!(debug_assertions) {
+        validate_all();
+    } else {
+        validate_sample();
+    }
+
+    let limit = if !cfg!(debug_assertions) { 100 } else { 10 };
+
+    if cfg!(not(debug_assertions)) {
+        validate_sample();
+    } else {
+        validate_all();
+    }
+
+    // ok, only logging in one branch
+    if cfg!(debug_assertions) {
+        println!("validating");
+        validate_all();
+    } else {
+        eprintln!("skipping validation");
+    }
+
+    // ok, no `else`
+    if cfg!(debug_assertions) {
+        validate_all();
+    }
+
+    // ok, a different `cfg!`
+    if cfg!(unix) {
+        validate_all();
+    } else {
+        validate_sample();
+    }
+
+    // ok, not only `debug_assertions`
+    if cfg!(any(debug_assertions, test)) {
+        validate_all();
+    } else {
+        validate_sample();
+    }
+}
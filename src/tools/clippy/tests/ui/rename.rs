@@ -23,6 +23,7 @@
 #![allow(clippy::map_unwrap_or)]
 #![allow(clippy::unwrap_used)]
 #![allow(clippy::needless_borrow)]
+#![allow(clippy::literal_suffix_style)]
 #![allow(clippy::single_char_add_str)]
 #![allow(clippy::module_name_repetitions)]
 #![allow(clippy::recursive_format_impl)]
@@ -66,6 +67,7 @@
 #![warn(clippy::single_char_push_str)]
 #![warn(clippy::stutter)]
 #![warn(clippy::to_string_in_display)]
+#![warn(clippy::unseparated_literal_suffix)]
 #![warn(clippy::zero_width_space)]
 #![warn(clippy::drop_bounds)]
 #![warn(clippy::for_loop_over_option)]
@@ -0,0 +1,75 @@
+#![warn(clippy::serde_attribute_misuse)]
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize)]
+struct Config {
+    name: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    plugins: Vec<String>,
+    #[serde(skip_serializing)]
+    secret: String,
+    // `Option` fields are optional anyway
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(skip)]
+    cache: Vec<u8>,
+}
+
+// Not deserialized
+#[derive(Serialize)]
+struct Report {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    lines: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+struct Settings {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    paths: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Request {
+    id: u64,
+    #[serde(flatten)]
+    extra: HashMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct User {
+    user_name: String,
+    #[serde(rename = "e_mail")]
+    email: String,
+    #[serde(rename = "phoneNumber")]
+    phone: String,
+    #[serde(rename(serialize = "Address", deserialize = "address"))]
+    address: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+enum Level {
+    Info,
+    #[serde(rename = "warn")]
+    Warning,
+    #[serde(rename = "ERR")]
+    Error,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Legacy {
+    #[allow(clippy::serde_attribute_misuse)]
+    #[serde(rename = "legacy_name")]
+    name: String,
+}
+
+fn main() {}
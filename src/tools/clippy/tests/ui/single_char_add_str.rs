@@ -1,5 +1,6 @@
 // run-rustfix
 #![warn(clippy::single_char_add_str)]
+#![allow(clippy::unnecessary_to_owned)]
 
 macro_rules! get_string {
     () => {
@@ -42,4 +43,24 @@ fn main() {
     string.insert_str(Y, r##"'"##);
 
     get_string!().insert_str(1, "?");
+
+    // `+=` tests
+
+    let c = 'c';
+    let mut string = String::new();
+    string += &c.to_string();
+    string += &'x'.to_string();
+    let s = &mut string;
+    *s += &c.to_string();
+    string += &"a".to_string();
+    string += "b";
+
+    // `format!` tests
+
+    let (a, b) = ('a', 'b');
+    let _ = format!("{a}{b}");
+    let _ = format!("{}{}", a, 'z');
+    let _ = format!("{a}-{b}");
+    let _ = format!("{a:?}{b}");
+    let _ = format!("{a}{}", "b");
 }
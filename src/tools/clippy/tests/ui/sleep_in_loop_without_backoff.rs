@@ -0,0 +1,69 @@
+#![warn(clippy::sleep_in_loop_without_backoff)]
+
+use std::fs::File;
+use std::thread::sleep;
+use std::time::Duration;
+
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+fn open_retrying(path: &str) -> File {
+    loop {
+        match File::open(path) {
+            Ok(file) => break file,
+            Err(_) => sleep(Duration::from_millis(500)),
+        }
+    }
+}
+
+fn open_retrying_while(path: &str) -> Option<File> {
+    let mut file = None;
+    while file.is_none() {
+        file = File::open(path).ok();
+        std::thread::sleep(RETRY_DELAY);
+    }
+    file
+}
+
+fn busy_wait(done: &std::sync::atomic::AtomicBool) {
+    while !done.load(std::sync::atomic::Ordering::Relaxed) {
+        sleep(Duration::from_millis(0));
+    }
+    for _ in 0..10 {
+        sleep(Duration::ZERO);
+    }
+}
+
+fn ok(path: &str) {
+    // backs off
+    let mut delay = Duration::from_millis(100);
+    loop {
+        if File::open(path).is_ok() {
+            break;
+        }
+        sleep(delay);
+        delay *= 2;
+    }
+
+    // bounded number of attempts
+    for _ in 0..5 {
+        if File::open(path).is_ok() {
+            break;
+        }
+        sleep(Duration::from_millis(500));
+    }
+
+    // nothing fallible
+    let mut ticks = 0;
+    loop {
+        ticks += 1;
+        if ticks == 10 {
+            break;
+        }
+        sleep(Duration::from_millis(10));
+    }
+
+    // not in a loop
+    sleep(Duration::from_millis(0));
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+// run-rustfix
+#![warn(clippy::split_whitespace_manual)]
+#![allow(unused)]
+
+fn main() {
+    let s = "a  b\tc";
+    let owned = String::from(s);
+
+    let _ = s.split(char::is_whitespace).filter(|p| !p.is_empty());
+    let _ = s.split(|c: char| c.is_whitespace()).filter(|p| !p.is_empty());
+    let _ = owned.split(|c: char| c.is_ascii_whitespace()).filter(|&p| !p.is_empty());
+    let _ = s.split(' ').filter(|p| !p.is_empty());
+
+    // Don't lint
+    let _ = s.split(char::is_whitespace);
+    let _ = s.split(',').filter(|p| !p.is_empty());
+    let _ = s.split(char::is_whitespace).filter(|p| p.len() > 1);
+}
@@ -0,0 +1,60 @@
+#![feature(once_cell)]
+#![warn(clippy::static_lazy_initialization_modernization)]
+
+use lazy_static::lazy_static;
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+
+static NAMES: Lazy<Vec<String>> = Lazy::new(|| vec!["a".to_string()]);
+
+static LENGTH: once_cell::sync::Lazy<usize> = once_cell::sync::Lazy::new(|| NAMES.len());
+
+fn init() -> u32 {
+    1
+}
+
+static WITH_FN: Lazy<u32> = Lazy::new(init);
+
+lazy_static! {
+    /// The numbers
+    static ref NUMBERS: Vec<u32> = vec![1, 2, 3];
+    pub static ref MAP: HashMap<u32, &'static str> = {
+        let mut map = HashMap::new();
+        map.insert(1, "one");
+        map
+    };
+}
+
+mod inner {
+    lazy_static::lazy_static! {
+        static ref INNER: u32 = 1;
+    }
+}
+
+#[clippy::msrv = "1.70"]
+mod msrv_1_70 {
+    use once_cell::sync::Lazy;
+
+    static NAMES: Lazy<Vec<String>> = Lazy::new(Vec::new);
+
+    lazy_static::lazy_static! {
+        static ref NUMBERS: Vec<u32> = Vec::new();
+    }
+}
+
+#[clippy::msrv = "1.69"]
+mod msrv_1_69 {
+    use once_cell::sync::Lazy;
+
+    // Do not lint, `OnceLock` is stable since 1.70
+    static NAMES: Lazy<Vec<String>> = Lazy::new(Vec::new);
+
+    lazy_static::lazy_static! {
+        static ref NUMBERS: Vec<u32> = Vec::new();
+    }
+}
+
+// Do not lint `LazyLock` itself or other types
+static ONCE: std::sync::OnceLock<u32> = std::sync::OnceLock::new();
+
+fn main() {}
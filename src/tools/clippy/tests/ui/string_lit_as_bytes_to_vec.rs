@@ -0,0 +1,17 @@
+// run-rustfix
+
+#![allow(unused)]
+#![warn(clippy::string_lit_as_bytes_to_vec)]
+
+fn main() {
+    let _ = Vec::from("lit".as_bytes());
+    let _ = "lit to string".to_string().into_bytes();
+    let _ = "lit to owned".to_owned().into_bytes();
+    let _ = String::from_utf8(b"lit".to_vec()).unwrap();
+    let _ = String::from_utf8(br"raw\n".to_vec()).expect("valid UTF-8");
+
+    // no warning, because these cannot be written as literals of the other kind:
+    let _ = Vec::from("☃".as_bytes());
+    let _ = Vec::from("hello there! this is a very long string".as_bytes());
+    let _ = String::from_utf8(b"\xE2\x98\x83".to_vec()).unwrap();
+}
@@ -57,7 +57,7 @@ fn unswappable_slice() {
     foo[1][0] = temp;
 
     // swap(foo[0][1], foo[1][0]) would fail
-    // this could use split_at_mut and mem::swap, but that is not much simpler.
+    // this is linted with a suggestion using split_at_mut and mem::swap
 }
 
 fn vec() {
@@ -0,0 +1,60 @@
+#![warn(clippy::swap_with_temporary)]
+#![allow(clippy::redundant_clone)]
+
+#[derive(Default)]
+struct State {
+    name: String,
+    items: Vec<u32>,
+}
+
+impl State {
+    fn rename(&mut self, name: String) -> String {
+        let old = self.name.clone();
+        self.name = name;
+        old
+    }
+
+    fn reset(&mut self) -> Vec<u32> {
+        let old = self.items.clone();
+        println!("resetting");
+        self.items = Vec::new();
+        old
+    }
+}
+
+fn through_ref(name: &mut String) -> String {
+    let old = name.clone();
+    *name = String::from("new");
+    old
+}
+
+// ok
+fn local(mut name: String) -> String {
+    let old = name.clone();
+    name = String::from("new");
+    println!("{name}");
+    old
+}
+
+fn used_between(name: &mut String) -> String {
+    let old = name.clone();
+    println!("{name}");
+    *name = String::from("new");
+    old
+}
+
+fn uses_old_value(state: &mut State) -> String {
+    let old = state.name.clone();
+    state.name = format!("{}!", state.name);
+    old
+}
+
+fn main() {
+    let mut state = State::default();
+    state.rename(String::new());
+    state.reset();
+    uses_old_value(&mut state);
+    let mut name = local(String::new());
+    through_ref(&mut name);
+    used_between(&mut name);
+}
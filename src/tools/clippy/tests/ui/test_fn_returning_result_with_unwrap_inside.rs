@@ -0,0 +1,38 @@
+// run-rustfix
+// compile-flags: --test
+#![allow(unused)]
+#![warn(clippy::test_fn_returning_result_with_unwrap_inside)]
+
+use std::num::ParseIntError;
+
+#[test]
+fn returns_result() -> Result<(), ParseIntError> {
+    let n: u32 = "12".parse().unwrap();
+    let m: u32 = "13".parse().expect("not a number");
+    assert_eq!(n + 1, m);
+    Ok(())
+}
+
+// ok, the error can't be converted
+#[test]
+fn returns_other_error() -> Result<(), String> {
+    let n: u32 = "12".parse().unwrap();
+    assert_eq!(n, 12);
+    Ok(())
+}
+
+// ok, in a closure
+#[test]
+fn in_closure() -> Result<(), ParseIntError> {
+    let parse = |s: &str| s.parse::<u32>().unwrap();
+    assert_eq!(parse("1"), 1);
+    Ok(())
+}
+
+// ok, not a test
+fn not_a_test() -> Result<(), ParseIntError> {
+    let n: u32 = "12".parse().unwrap();
+    Ok(())
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+// compile-flags: --test
+#![allow(unused)]
+#![warn(clippy::test_fn_returning_result_with_unwrap_inside)]
+
+#[test]
+fn unwraps_many() {
+    let a: u32 = "1".parse().unwrap();
+    let b: u32 = "2".parse().unwrap();
+    let c: u32 = "3".parse().unwrap();
+    let d: u32 = "4".parse().unwrap();
+    assert_eq!(a + b + c, d + 2);
+}
+
+// ok, below the threshold
+#[test]
+fn unwraps_few() {
+    let a: u32 = "1".parse().unwrap();
+    assert_eq!(a, 1);
+}
+
+// ok, expected to panic
+#[test]
+#[should_panic]
+fn panics() {
+    let a: u32 = "1".parse().unwrap();
+    let b: u32 = "2".parse().unwrap();
+    let c: u32 = "3".parse().unwrap();
+    let d: u32 = "x".parse().unwrap();
+    assert_eq!(a + b + c, d + 2);
+}
+
+fn main() {}
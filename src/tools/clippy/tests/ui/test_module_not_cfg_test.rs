@@ -0,0 +1,38 @@
+// run-rustfix
+// compile-flags: --test
+#![allow(unused)]
+#![warn(clippy::test_module_not_cfg_test)]
+
+mod tests {
+    #[test]
+    fn it_works() {}
+}
+
+mod outer {
+    pub mod test {
+        #[test]
+        fn it_works() {}
+    }
+}
+
+// ok, gated
+#[cfg(test)]
+mod gated {
+    mod tests {
+        #[test]
+        fn it_works() {}
+    }
+}
+
+// ok, no tests
+mod test_helpers {
+    pub fn helper() {}
+}
+
+// ok, not a test module name
+mod checks {
+    #[test]
+    fn it_works() {}
+}
+
+fn main() {}
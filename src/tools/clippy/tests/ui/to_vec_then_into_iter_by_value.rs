@@ -0,0 +1,28 @@
+// run-rustfix
+#![warn(clippy::to_vec_then_into_iter_by_value)]
+#![allow(clippy::redundant_clone, clippy::unnecessary_to_owned)]
+
+use std::collections::VecDeque;
+
+fn main() {
+    let v = vec![1, 2, 3];
+    let _: i32 = v.clone().into_iter().sum();
+    let names = vec![String::from("a")];
+    let _: Vec<String> = names.clone().into_iter().map(|s| s + "!").collect();
+    let slice: &[u8] = &[1, 2];
+    let _ = slice.to_vec().into_iter().max();
+    let q: VecDeque<u8> = VecDeque::new();
+    for x in q.clone().into_iter() {
+        println!("{x}");
+    }
+
+    // ok
+    let mut v = v;
+    for x in v.clone().into_iter() {
+        v.push(x);
+    }
+    let _: Vec<i32> = v.clone().into_iter().map(|x| x + v.len() as i32).collect();
+    let iter = v.clone().into_iter();
+    v.clear();
+    drop(iter);
+}
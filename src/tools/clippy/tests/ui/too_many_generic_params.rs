@@ -0,0 +1,39 @@
+#![warn(clippy::too_many_generic_params)]
+#![allow(clippy::needless_lifetimes)]
+
+fn ok<A, B, C, D, E>() {}
+
+fn bad<A, B, C, D, E, F>() {}
+
+// lifetimes and `impl Trait` parameters are not counted
+fn lifetimes<'a, 'b, A, B, C, D, E>(_: &'a A, _: &'b B, _: impl Copy) {}
+
+fn with_const<A, B, C, D, E, const N: usize>() {}
+
+struct Struct<A, B, C, D, E, F>(A, B, C, D, E, F);
+
+enum Enum<A, B, C, D, E, F> {
+    Variant(A, B, C, D, E, F),
+}
+
+type Alias<A, B, C, D, E, F> = (A, B, C, D, E, F);
+
+trait Trait<A, B, C, D, E, F> {
+    fn method<G, H, I, J, K, L>();
+}
+
+struct S;
+
+impl S {
+    fn method<A, B, C, D, E, F>() {}
+}
+
+// the generics of trait implementations are dictated by the trait
+impl<A, B, C, D, E, F> Trait<A, B, C, D, E, F> for S {
+    fn method<G, H, I, J, K, L>() {}
+}
+
+#[allow(clippy::too_many_generic_params)]
+fn allowed<A, B, C, D, E, F>() {}
+
+fn main() {}
@@ -0,0 +1,26 @@
+#![warn(clippy::try_into_unwrap_on_provably_fitting_values)]
+
+fn conversions(x: u64) {
+    let small: u8 = 7;
+    let _ = u8::try_from(x % 10).unwrap();
+    let _ = u16::try_from(x & 0xfff).expect("masked");
+    let _: u64 = small.try_into().unwrap();
+    for i in 0..100_i32 {
+        let _ = u8::try_from(i).unwrap();
+    }
+    let n = 200_i64;
+    let _: u8 = n.try_into().unwrap();
+
+    // Can fail
+    let _ = u8::try_from(x).unwrap();
+    let _ = u8::try_from(x % 1000).unwrap();
+    let _ = u8::try_from(x % 10 - 5).unwrap();
+    for i in -1..100_i32 {
+        let _ = u8::try_from(i).unwrap();
+    }
+    let _: Result<u8, _> = u8::try_from(x % 10);
+}
+
+fn main() {
+    conversions(1234);
+}
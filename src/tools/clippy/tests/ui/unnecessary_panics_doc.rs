@@ -0,0 +1,68 @@
+#![warn(clippy::unnecessary_panics_doc)]
+
+pub enum Wrapper {
+    Value(u32),
+}
+
+/// # Panics
+///
+/// Panics if `x` is negative.
+pub fn is_positive(x: i32) -> bool {
+    x > 0
+}
+
+fn is_positive_helper(x: i32) -> bool {
+    x > 0
+}
+
+/// # Panics
+///
+/// Never.
+pub fn calls_non_panicking_function(x: i32) -> bool {
+    is_positive_helper(x) && x != 10
+}
+
+/// # Panics
+///
+/// Constructors can't panic.
+pub fn wrap(x: u32) -> Wrapper {
+    Wrapper::Value(x)
+}
+
+/// # Panics
+///
+/// Panics if `x` is `i32::MAX`.
+pub fn add(x: i32) -> i32 {
+    x + 1
+}
+
+/// # Panics
+///
+/// Panics if `i` is out of bounds.
+pub fn index(v: &[u8], i: usize) -> u8 {
+    v[i]
+}
+
+/// # Panics
+///
+/// Calls to other crates may panic.
+pub fn len(v: &[u8]) -> usize {
+    v.len()
+}
+
+/// # Panics
+///
+/// Panics in debug builds if `x` is zero.
+pub fn debug_assert(x: u32) -> u32 {
+    debug_assert!(x != 0);
+    x
+}
+
+/// # Panics
+///
+/// This is okay because it is private
+fn private(x: i32) -> bool {
+    x > 0
+}
+
+fn main() {}
@@ -1,7 +1,7 @@
 // run-rustfix
 // aux-build:proc_macro_derive.rs
 
-#![warn(clippy::unseparated_literal_suffix)]
+#![warn(clippy::literal_suffix_style)]
 #![allow(dead_code)]
 
 #[macro_use]
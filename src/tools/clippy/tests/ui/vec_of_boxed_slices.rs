@@ -0,0 +1,42 @@
+#![warn(clippy::vec_of_boxed_slices)]
+#![allow(unused)]
+
+struct Grid {
+    cells: Vec<Vec<u8>>,
+    rows: Vec<Box<[u32]>>,
+    names: Box<[Box<str>]>,
+    nested: Option<Vec<Box<[Box<str>]>>>,
+}
+
+const WIDTH: usize = 8;
+
+fn new_grid(n: usize) -> Grid {
+    Grid {
+        cells: vec![vec![0; WIDTH]; n],
+        rows: vec![vec![1, 2, 3].into_boxed_slice(), vec![4, 5, 6].into_boxed_slice()],
+        names: Box::new([]),
+        nested: None,
+    }
+}
+
+fn no_lint(n: usize, m: usize) -> Grid {
+    Grid {
+        // Inner length not constant
+        cells: vec![vec![0; m]; n],
+        // Inner lengths differ
+        rows: vec![vec![1, 2, 3].into_boxed_slice(), vec![4].into_boxed_slice()],
+        names: Box::new([]),
+        nested: None,
+    }
+}
+
+fn no_lint_empty(n: usize) -> Grid {
+    Grid {
+        cells: vec![vec![]; n],
+        rows: Vec::new(),
+        names: Box::new([]),
+        nested: None,
+    }
+}
+
+fn main() {}